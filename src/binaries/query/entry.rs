@@ -32,13 +32,16 @@ use databend_query::api::RpcService;
 use databend_query::clusters::ClusterDiscovery;
 use databend_query::local;
 use databend_query::metrics::MetricService;
+use databend_query::pipes::PipeScheduler;
 use databend_query::servers::FlightSQLServer;
 use databend_query::servers::HttpHandler;
 use databend_query::servers::HttpHandlerKind;
 use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
+use databend_query::servers::PostgresHandler;
 use databend_query::servers::Server;
 use databend_query::servers::ShutdownHandle;
+use databend_query::tasks::TaskScheduler;
 use databend_query::GlobalServices;
 use log::info;
 
@@ -149,6 +152,25 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         );
     }
 
+    // PostgreSQL handler.
+    {
+        let hostname = conf.query.postgres_handler_host.clone();
+        let listening = format!("{}:{}", hostname, conf.query.postgres_handler_port);
+        let tcp_keepalive_timeout_secs = conf.query.postgres_handler_tcp_keepalive_timeout_secs;
+        let max_message_size = conf.query.postgres_handler_max_message_size as usize;
+
+        let mut handler = PostgresHandler::create(tcp_keepalive_timeout_secs, max_message_size)?;
+        let listening = handler.start(listening.parse()?).await?;
+        shutdown_handle.add_service("PostgresHandler", handler);
+
+        info!(
+            "Listening for PostgreSQL compatibility protocol: {}, Usage: psql -h{} -p{} -Uroot -ddefault",
+            listening,
+            listening.ip(),
+            listening.port(),
+        );
+    }
+
     // ClickHouse HTTP handler.
     {
         let hostname = conf.query.clickhouse_http_handler_host.clone();
@@ -297,6 +319,15 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         "    connect via: mysql -u${{USER}} -p${{PASSWORD}} -h{} -P{}",
         conf.query.mysql_handler_host, conf.query.mysql_handler_port
     );
+    println!("PostgreSQL");
+    println!(
+        "    listened at {}:{}",
+        conf.query.postgres_handler_host, conf.query.postgres_handler_port
+    );
+    println!(
+        "    connect via: psql -U${{USER}} -h{} -p{} -ddefault",
+        conf.query.postgres_handler_host, conf.query.postgres_handler_port
+    );
     println!("Clickhouse(http)");
     println!(
         "    listened at {}:{}",
@@ -342,6 +373,10 @@ pub async fn start_services(conf: &InnerConfig) -> Result<()> {
         start_time.elapsed().as_secs_f32()
     );
 
+    // Run the auto ingest pipes.
+    PipeScheduler::instance().start();
+    TaskScheduler::instance().start();
+
     if conf.background.enable {
         println!("Start background service");
         get_background_service_handler().start().await?;
@@ -13,6 +13,8 @@
 // limitations under the License.
 
 pub mod lru;
+pub mod policy;
+pub mod s3fifo;
 
 use std::borrow::Borrow;
 use std::hash::BuildHasher;
@@ -0,0 +1,197 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::borrow::Borrow;
+use std::fmt;
+use std::hash::BuildHasher;
+use std::hash::Hash;
+
+use hashbrown::hash_map::DefaultHashBuilder;
+
+use crate::cache::lru::LruCache;
+use crate::cache::s3fifo::S3FifoCache;
+use crate::cache::Cache;
+use crate::meter::count_meter::Count;
+use crate::meter::count_meter::CountableMeter;
+
+/// The eviction policies that caches can be configured with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum EvictionPolicy {
+    /// Least recently used.
+    #[default]
+    Lru,
+    /// Scan-resistant, see [`S3FifoCache`].
+    S3Fifo,
+}
+
+impl fmt::Display for EvictionPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EvictionPolicy::Lru => write!(f, "lru"),
+            EvictionPolicy::S3Fifo => write!(f, "s3fifo"),
+        }
+    }
+}
+
+/// A cache whose eviction policy is chosen at runtime.
+pub enum PolicyCache<
+    K: Eq + Hash,
+    V,
+    S: BuildHasher = DefaultHashBuilder,
+    M: CountableMeter<K, V> = Count,
+> {
+    Lru(LruCache<K, V, S, M>),
+    S3Fifo(S3FifoCache<K, V, S, M>),
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Clone, M: CountableMeter<K, V>> PolicyCache<K, V, S, M> {
+    pub fn with_policy(policy: EvictionPolicy, capacity: u64, meter: M, hash_builder: S) -> Self {
+        match policy {
+            EvictionPolicy::Lru => PolicyCache::Lru(LruCache::with_meter_and_hasher(
+                capacity,
+                meter,
+                hash_builder,
+            )),
+            EvictionPolicy::S3Fifo => PolicyCache::S3Fifo(S3FifoCache::with_meter_and_hasher(
+                capacity,
+                meter,
+                hash_builder,
+            )),
+        }
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher, M: CountableMeter<K, V>> PolicyCache<K, V, S, M> {
+    pub fn policy(&self) -> EvictionPolicy {
+        match self {
+            PolicyCache::Lru(_) => EvictionPolicy::Lru,
+            PolicyCache::S3Fifo(_) => EvictionPolicy::S3Fifo,
+        }
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Clone, M: CountableMeter<K, V>> Cache<K, V, S, M>
+    for PolicyCache<K, V, S, M>
+{
+    /// Create a cache with the default policy, which is LRU.
+    fn with_meter_and_hasher(capacity: u64, meter: M, hash_builder: S) -> Self {
+        Self::with_policy(EvictionPolicy::default(), capacity, meter, hash_builder)
+    }
+
+    fn get<'a, Q>(&'a mut self, k: &Q) -> Option<&'a V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self {
+            PolicyCache::Lru(cache) => cache.get(k),
+            PolicyCache::S3Fifo(cache) => cache.get(k),
+        }
+    }
+
+    fn peek<'a, Q>(&'a self, k: &Q) -> Option<&'a V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self {
+            PolicyCache::Lru(cache) => cache.peek(k),
+            PolicyCache::S3Fifo(cache) => cache.peek(k),
+        }
+    }
+
+    fn peek_by_policy(&self) -> Option<(&K, &V)> {
+        match self {
+            PolicyCache::Lru(cache) => cache.peek_by_policy(),
+            PolicyCache::S3Fifo(cache) => cache.peek_by_policy(),
+        }
+    }
+
+    fn put(&mut self, k: K, v: V) -> Option<V> {
+        match self {
+            PolicyCache::Lru(cache) => cache.put(k, v),
+            PolicyCache::S3Fifo(cache) => cache.put(k, v),
+        }
+    }
+
+    fn pop<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self {
+            PolicyCache::Lru(cache) => cache.pop(k),
+            PolicyCache::S3Fifo(cache) => cache.pop(k),
+        }
+    }
+
+    fn pop_by_policy(&mut self) -> Option<(K, V)> {
+        match self {
+            PolicyCache::Lru(cache) => cache.pop_by_policy(),
+            PolicyCache::S3Fifo(cache) => cache.pop_by_policy(),
+        }
+    }
+
+    fn contains<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        match self {
+            PolicyCache::Lru(cache) => cache.contains(k),
+            PolicyCache::S3Fifo(cache) => cache.contains(k),
+        }
+    }
+
+    fn len(&self) -> usize {
+        match self {
+            PolicyCache::Lru(cache) => cache.len(),
+            PolicyCache::S3Fifo(cache) => cache.len(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        match self {
+            PolicyCache::Lru(cache) => cache.is_empty(),
+            PolicyCache::S3Fifo(cache) => cache.is_empty(),
+        }
+    }
+
+    fn capacity(&self) -> u64 {
+        match self {
+            PolicyCache::Lru(cache) => cache.capacity(),
+            PolicyCache::S3Fifo(cache) => cache.capacity(),
+        }
+    }
+
+    fn set_capacity(&mut self, capacity: u64) {
+        match self {
+            PolicyCache::Lru(cache) => cache.set_capacity(capacity),
+            PolicyCache::S3Fifo(cache) => cache.set_capacity(capacity),
+        }
+    }
+
+    fn size(&self) -> u64 {
+        match self {
+            PolicyCache::Lru(cache) => cache.size(),
+            PolicyCache::S3Fifo(cache) => cache.size(),
+        }
+    }
+
+    fn clear(&mut self) {
+        match self {
+            PolicyCache::Lru(cache) => cache.clear(),
+            PolicyCache::S3Fifo(cache) => cache.clear(),
+        }
+    }
+}
@@ -0,0 +1,316 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+//! A scan-resistant cache based on [S3-FIFO](https://s3fifo.com).
+//!
+//! Newly inserted pairs are kept in a small probationary FIFO queue, which takes about 10% of
+//! the capacity. Pairs that are accessed again before leaving the small queue are promoted to
+//! the main FIFO queue, others are evicted and only their key hashes are remembered in a ghost
+//! queue. If an evicted key is inserted again while its hash is still in the ghost queue, the
+//! pair goes to the main queue directly.
+//!
+//! Pairs in the main queue are re-inserted (lazy promotion) if they are accessed since the last
+//! time they were examined by the eviction. Since one-hit wonders never reach the main queue,
+//! a large scan only churns the small queue, and the hot working set is kept.
+//!
+//! # Examples
+//!
+//! ```rust,ignore
+//! use databend_common_cache::{Cache, S3FifoCache};
+//!
+//! let mut cache = S3FifoCache::new(10);
+//!
+//! cache.put(1, 10);
+//! assert_eq!(*cache.get(&1).unwrap(), 10);
+//!
+//! // scan through the cache
+//! for i in 100..200 {
+//!     cache.put(i, i);
+//! }
+//! assert_eq!(*cache.get(&1).unwrap(), 10);
+//! ```
+
+use std::borrow::Borrow;
+use std::hash::BuildHasher;
+use std::hash::Hash;
+
+use hashbrown::hash_map::DefaultHashBuilder;
+use hashlink::LinkedHashMap;
+use hashlink::LinkedHashSet;
+
+use crate::cache::Cache;
+use crate::meter::count_meter::Count;
+use crate::meter::count_meter::CountableMeter;
+
+/// Max access frequency tracked for each pair.
+const MAX_FREQUENCY: u8 = 3;
+
+/// Percentage of the capacity taken by the small queue.
+const SMALL_QUEUE_PERCENTAGE: u64 = 10;
+
+struct Entry<V> {
+    value: V,
+    freq: u8,
+}
+
+impl<V> Entry<V> {
+    fn new(value: V) -> Self {
+        Entry { value, freq: 0 }
+    }
+
+    fn touch(&mut self) {
+        self.freq = std::cmp::min(self.freq + 1, MAX_FREQUENCY);
+    }
+}
+
+pub struct S3FifoCache<
+    K: Eq + Hash,
+    V,
+    S: BuildHasher = DefaultHashBuilder,
+    M: CountableMeter<K, V> = Count,
+> {
+    small: LinkedHashMap<K, Entry<V>, S>,
+    main: LinkedHashMap<K, Entry<V>, S>,
+    ghost: LinkedHashSet<u64>,
+    hash_builder: S,
+    small_measure: M::Measure,
+    main_measure: M::Measure,
+    max_capacity: u64,
+    meter: M,
+}
+
+impl<K: Eq + Hash, V> S3FifoCache<K, V> {
+    pub fn new(capacity: u64) -> Self {
+        Self::with_meter_and_hasher(capacity, Count, DefaultHashBuilder::default())
+    }
+}
+
+impl<K: Eq + Hash, V, M: CountableMeter<K, V>> S3FifoCache<K, V, DefaultHashBuilder, M> {
+    pub fn with_meter(capacity: u64, meter: M) -> S3FifoCache<K, V, DefaultHashBuilder, M> {
+        Self::with_meter_and_hasher(capacity, meter, DefaultHashBuilder::default())
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Clone, M: CountableMeter<K, V>> S3FifoCache<K, V, S, M> {
+    fn small_size(&self) -> u64 {
+        self.meter
+            .size(self.small_measure)
+            .unwrap_or(self.small.len() as u64)
+    }
+
+    fn small_capacity(&self) -> u64 {
+        self.max_capacity * SMALL_QUEUE_PERCENTAGE / 100
+    }
+
+    /// Returns `true` if the next pair to be examined by the eviction is in the small queue.
+    fn evict_from_small(&self) -> bool {
+        !self.small.is_empty()
+            && (self.small_size() > self.small_capacity() || self.main.is_empty())
+    }
+
+    fn hash_of<Q>(&self, k: &Q) -> u64
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.hash_builder.hash_one(k)
+    }
+
+    fn remember_evicted(&mut self, hash: u64) {
+        self.ghost.insert(hash);
+        // the ghost queue tracks as many keys as the cached pairs
+        while self.ghost.len() > std::cmp::max(self.len(), 1) {
+            self.ghost.pop_front();
+        }
+    }
+}
+
+impl<K: Eq + Hash, V, S: BuildHasher + Clone, M: CountableMeter<K, V>> Cache<K, V, S, M>
+    for S3FifoCache<K, V, S, M>
+{
+    fn with_meter_and_hasher(capacity: u64, meter: M, hash_builder: S) -> Self {
+        S3FifoCache {
+            small: LinkedHashMap::with_hasher(hash_builder.clone()),
+            main: LinkedHashMap::with_hasher(hash_builder.clone()),
+            ghost: LinkedHashSet::new(),
+            hash_builder,
+            small_measure: Default::default(),
+            main_measure: Default::default(),
+            max_capacity: capacity,
+            meter,
+        }
+    }
+
+    fn get<'a, Q>(&'a mut self, k: &Q) -> Option<&'a V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if let Some(entry) = self.small.get_mut(k) {
+            entry.touch();
+            return Some(&entry.value);
+        }
+        self.main.get_mut(k).map(|entry| {
+            entry.touch();
+            &entry.value
+        })
+    }
+
+    fn peek<'a, Q>(&'a self, k: &Q) -> Option<&'a V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.small
+            .get(k)
+            .or_else(|| self.main.get(k))
+            .map(|entry| &entry.value)
+    }
+
+    /// Returns the pair that will be examined next by the eviction, it might be promoted
+    /// instead of being evicted if it has been accessed.
+    fn peek_by_policy(&self) -> Option<(&K, &V)> {
+        let front = if self.evict_from_small() {
+            self.small.front()
+        } else {
+            self.main.front()
+        };
+        front.map(|(k, entry)| (k, &entry.value))
+    }
+
+    fn put(&mut self, k: K, v: V) -> Option<V> {
+        let new_size = self.meter.measure(&k, &v);
+        let hash = self.hash_of(&k);
+        let old_val = if let Some(entry) = self.small.get_mut(&k) {
+            let old_size = self.meter.measure(&k, &entry.value);
+            self.small_measure = self.meter.add(self.small_measure, new_size);
+            self.small_measure = self.meter.sub(self.small_measure, old_size);
+            Some(std::mem::replace(&mut entry.value, v))
+        } else if let Some(entry) = self.main.get_mut(&k) {
+            let old_size = self.meter.measure(&k, &entry.value);
+            self.main_measure = self.meter.add(self.main_measure, new_size);
+            self.main_measure = self.meter.sub(self.main_measure, old_size);
+            Some(std::mem::replace(&mut entry.value, v))
+        } else if self.ghost.remove(&hash) {
+            // evicted recently, it deserves a place in the main queue
+            self.main_measure = self.meter.add(self.main_measure, new_size);
+            self.main.insert(k, Entry::new(v));
+            None
+        } else {
+            self.small_measure = self.meter.add(self.small_measure, new_size);
+            self.small.insert(k, Entry::new(v));
+            None
+        };
+
+        while self.size() > self.capacity() {
+            if self.pop_by_policy().is_none() {
+                break;
+            }
+        }
+        old_val
+    }
+
+    fn pop<Q>(&mut self, k: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        if let Some(entry) = self.small.remove(k) {
+            self.small_measure = self
+                .meter
+                .sub(self.small_measure, self.meter.measure(k, &entry.value));
+            return Some(entry.value);
+        }
+        self.main.remove(k).map(|entry| {
+            self.main_measure = self
+                .meter
+                .sub(self.main_measure, self.meter.measure(k, &entry.value));
+            entry.value
+        })
+    }
+
+    fn pop_by_policy(&mut self) -> Option<(K, V)> {
+        loop {
+            if self.evict_from_small() {
+                let (k, entry) = self.small.pop_front()?;
+                let size = self.meter.measure(&k, &entry.value);
+                self.small_measure = self.meter.sub(self.small_measure, size);
+                if entry.freq > 0 {
+                    // accessed while in the small queue, promote it to the main queue
+                    self.main_measure = self.meter.add(self.main_measure, size);
+                    self.main.insert(k, Entry::new(entry.value));
+                    continue;
+                }
+                let hash = self.hash_of(&k);
+                self.remember_evicted(hash);
+                return Some((k, entry.value));
+            }
+
+            let (k, mut entry) = self.main.pop_front()?;
+            if entry.freq > 0 {
+                // accessed since last examined, give it another round
+                entry.freq -= 1;
+                self.main.insert(k, entry);
+                continue;
+            }
+            self.main_measure = self
+                .meter
+                .sub(self.main_measure, self.meter.measure(&k, &entry.value));
+            return Some((k, entry.value));
+        }
+    }
+
+    fn contains<Q>(&self, k: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        self.small.contains_key(k) || self.main.contains_key(k)
+    }
+
+    fn len(&self) -> usize {
+        self.small.len() + self.main.len()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.small.is_empty() && self.main.is_empty()
+    }
+
+    fn capacity(&self) -> u64 {
+        self.max_capacity
+    }
+
+    fn set_capacity(&mut self, capacity: u64) {
+        self.max_capacity = capacity;
+        while self.size() > capacity {
+            if self.pop_by_policy().is_none() {
+                break;
+            }
+        }
+    }
+
+    fn size(&self) -> u64 {
+        let current_measure = self.meter.add(self.small_measure, self.main_measure);
+        self.meter
+            .size(current_measure)
+            .unwrap_or_else(|| self.len() as u64)
+    }
+
+    fn clear(&mut self) {
+        self.small.clear();
+        self.main.clear();
+        self.ghost.clear();
+        self.small_measure = Default::default();
+        self.main_measure = Default::default();
+    }
+}
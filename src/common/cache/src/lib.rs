@@ -22,6 +22,9 @@ mod cache;
 mod meter;
 
 pub use cache::lru::LruCache;
+pub use cache::policy::EvictionPolicy;
+pub use cache::policy::PolicyCache;
+pub use cache::s3fifo::S3FifoCache;
 pub use cache::Cache;
 pub use hashbrown::hash_map::DefaultHashBuilder;
 pub use meter::bytes_meter::BytesMeter;
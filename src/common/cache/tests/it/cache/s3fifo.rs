@@ -0,0 +1,185 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::borrow::Borrow;
+
+use databend_common_cache::Cache;
+use databend_common_cache::EvictionPolicy;
+use databend_common_cache::LruCache;
+use databend_common_cache::Meter;
+use databend_common_cache::PolicyCache;
+use databend_common_cache::S3FifoCache;
+
+#[test]
+fn test_put_and_get() {
+    let mut cache = S3FifoCache::new(2);
+    cache.put(1, 10);
+    cache.put(2, 20);
+    assert_eq!(cache.get(&1), Some(&10));
+    assert_eq!(cache.get(&2), Some(&20));
+    assert_eq!(cache.len(), 2);
+    assert_eq!(cache.size(), 2);
+}
+
+#[test]
+fn test_put_update() {
+    let mut cache = S3FifoCache::new(1);
+    cache.put("1", 10);
+    cache.put("1", 19);
+    assert_eq!(cache.get("1"), Some(&19));
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+fn test_pop() {
+    let mut cache = S3FifoCache::new(2);
+    cache.put(1, 10);
+    cache.put(2, 20);
+    assert_eq!(cache.pop(&1), Some(10));
+    assert!(cache.get(&1).is_none());
+    assert!(!cache.contains(&1));
+    assert_eq!(cache.len(), 1);
+}
+
+#[test]
+fn test_evict_fifo() {
+    let mut cache = S3FifoCache::new(2);
+    cache.put(1, 10);
+    cache.put(2, 20);
+    cache.put(3, 30);
+    // not accessed since inserted, evicted in insertion order
+    assert!(!cache.contains(&1));
+    assert!(cache.contains(&2));
+    assert!(cache.contains(&3));
+}
+
+#[test]
+fn test_scan_resistant() {
+    let mut lru = LruCache::new(10);
+    let mut s3fifo = S3FifoCache::new(10);
+    for i in 0..5 {
+        lru.put(i, i);
+        s3fifo.put(i, i);
+        lru.get(&i);
+        s3fifo.get(&i);
+    }
+
+    // a large scan that accesses each key only once
+    for i in 100..1000 {
+        lru.put(i, i);
+        s3fifo.put(i, i);
+    }
+
+    for i in 0..5 {
+        assert!(lru.get(&i).is_none());
+        assert_eq!(s3fifo.get(&i), Some(&i));
+    }
+    assert_eq!(s3fifo.len(), 10);
+}
+
+#[test]
+fn test_ghost_hit() {
+    let mut cache = S3FifoCache::new(10);
+    cache.put(0, 0);
+    for i in 1..=10 {
+        cache.put(i, i);
+    }
+    // evicted from the small queue, but remembered by the ghost queue
+    assert!(!cache.contains(&0));
+
+    // goes to the main queue directly, and survives the following scan
+    cache.put(0, 0);
+    for i in 100..1000 {
+        cache.put(i, i);
+    }
+    assert_eq!(cache.get(&0), Some(&0));
+}
+
+#[test]
+fn test_change_capacity() {
+    let mut cache = S3FifoCache::new(2);
+    cache.put(1, 10);
+    cache.put(2, 20);
+    cache.set_capacity(1);
+    assert_eq!(cache.len(), 1);
+    assert!(!cache.contains(&1));
+    assert_eq!(cache.capacity(), 1);
+}
+
+#[test]
+fn test_clear() {
+    let mut cache = S3FifoCache::new(2);
+    cache.put(1, 10);
+    cache.put(2, 20);
+    cache.clear();
+    assert!(cache.is_empty());
+    assert_eq!(cache.size(), 0);
+    assert!(cache.get(&1).is_none());
+}
+
+struct VecLen;
+
+impl<K, T> Meter<K, Vec<T>> for VecLen {
+    type Measure = usize;
+    fn measure<Q: ?Sized>(&self, _: &Q, v: &Vec<T>) -> usize
+    where K: Borrow<Q> {
+        v.len()
+    }
+}
+
+#[test]
+fn test_metered_cache() {
+    let mut cache = S3FifoCache::with_meter(5, VecLen);
+    cache.put("foo1", vec![1, 2]);
+    assert_eq!(cache.size(), 2);
+    cache.put("foo2", vec![3, 4]);
+    cache.put("foo3", vec![5, 6]);
+    assert_eq!(cache.size(), 4);
+    assert!(!cache.contains("foo1"));
+    cache.put("foo2", vec![7, 8, 9]);
+    assert_eq!(cache.size(), 5);
+    assert_eq!(cache.get("foo2"), Some(&vec![7, 8, 9]));
+}
+
+#[test]
+fn test_metered_cache_oversize() {
+    let mut cache = S3FifoCache::with_meter(2, VecLen);
+    cache.put("foo1", vec![1, 2]);
+    cache.put("foo2", vec![3, 4, 5, 6]);
+    assert_eq!(cache.size(), 0);
+    assert!(!cache.contains("foo1"));
+    assert!(!cache.contains("foo2"));
+}
+
+#[test]
+fn test_policy_cache() {
+    let mut cache = PolicyCache::with_policy(
+        EvictionPolicy::S3Fifo,
+        2,
+        databend_common_cache::Count,
+        databend_common_cache::DefaultHashBuilder::default(),
+    );
+    assert_eq!(cache.policy(), EvictionPolicy::S3Fifo);
+    cache.put(1, 10);
+    cache.put(2, 20);
+    cache.put(3, 30);
+    assert_eq!(cache.len(), 2);
+    assert!(!cache.contains(&1));
+
+    let cache: PolicyCache<i32, i32> = PolicyCache::with_meter_and_hasher(
+        2,
+        databend_common_cache::Count,
+        databend_common_cache::DefaultHashBuilder::default(),
+    );
+    assert_eq!(cache.policy(), EvictionPolicy::Lru);
+}
@@ -20,6 +20,7 @@ use chrono::DateTime;
 use chrono::Utc;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal as mt;
 
 use crate::pb::schedule_options::ScheduleType;
 use crate::pb::ScheduleOptions;
@@ -314,3 +315,88 @@ impl TryFrom<crate::pb::TaskRun> for TaskRun {
         Ok(tr)
     }
 }
+
+fn format_time(t: &DateTime<Utc>) -> String {
+    t.to_rfc3339()
+}
+
+fn make_schedule_options(s: mt::ScheduleOptions) -> ScheduleOptions {
+    ScheduleOptions {
+        interval: s.interval,
+        cron: s.cron,
+        time_zone: s.time_zone,
+        schedule_type: match s.schedule_type {
+            mt::ScheduleType::IntervalType => ScheduleType::IntervalType as i32,
+            mt::ScheduleType::CronType => ScheduleType::CronType as i32,
+        },
+    }
+}
+
+fn make_warehouse_options(w: mt::WarehouseOptions) -> WarehouseOptions {
+    WarehouseOptions {
+        warehouse: w.warehouse,
+        using_warehouse_size: w.using_warehouse_size,
+    }
+}
+
+// convert from the task stored in meta service to crate::pb::Task
+impl From<mt::Task> for crate::pb::Task {
+    fn from(value: mt::Task) -> Self {
+        crate::pb::Task {
+            task_id: value.task_id,
+            task_name: value.task_name,
+            query_text: value.query_text,
+            comment: value.comment,
+            owner: value.owner,
+            schedule_options: value.schedule_options.map(make_schedule_options),
+            warehouse_options: value.warehouse_options.map(make_warehouse_options),
+            next_scheduled_at: value.next_scheduled_at.as_ref().map(format_time),
+            suspend_task_after_num_failures: value
+                .suspend_task_after_num_failures
+                .map(|v| v as i32),
+            status: match value.status {
+                mt::Status::Suspended => Status::Suspended as i32,
+                mt::Status::Started => Status::Started as i32,
+            },
+            created_at: format_time(&value.created_at),
+            updated_at: format_time(&value.updated_at),
+            last_suspended_at: value.last_suspended_at.as_ref().map(format_time),
+            after: value.after,
+            when_condition: value.when_condition,
+            session_parameters: value.session_params,
+        }
+    }
+}
+
+// convert from the task run stored in meta service to crate::pb::TaskRun
+impl From<mt::TaskRun> for crate::pb::TaskRun {
+    fn from(value: mt::TaskRun) -> Self {
+        let task = value.task;
+        crate::pb::TaskRun {
+            task_id: task.task_id,
+            task_name: task.task_name,
+            query_text: task.query_text,
+            comment: task.comment,
+            owner: task.owner,
+            schedule_options: task.schedule_options.map(make_schedule_options),
+            run_id: value.run_id.to_string(),
+            attempt_number: value.attempt_number,
+            warehouse_options: task.warehouse_options.map(make_warehouse_options),
+            state: match value.state {
+                mt::State::Scheduled => State::SCHEDULED as i32,
+                mt::State::Executing => State::EXECUTING as i32,
+                mt::State::Succeeded => State::SUCCEEDED as i32,
+                mt::State::Failed => State::FAILED as i32,
+                mt::State::Cancelled => State::CANCELLED as i32,
+            },
+            error_code: value.error_code,
+            error_message: value.error_message,
+            scheduled_time: format_time(&value.scheduled_at),
+            completed_time: value.completed_at.as_ref().map(format_time),
+            query_id: value.query_id,
+            condition_text: task.when_condition.unwrap_or_default(),
+            root_task_id: value.root_task_id.to_string(),
+            session_parameters: task.session_params,
+        }
+    }
+}
@@ -172,6 +172,11 @@ build_exceptions! {
     ///
     /// For example: try to with 3 columns into a table with 4 columns.
     TableSchemaMismatch(1303),
+    /// CheckConstraintViolation is used when a written row does not satisfy
+    /// a CHECK constraint of the table.
+    ///
+    /// For example: insert `-1` into a table with `CHECK (a > 0)`.
+    CheckConstraintViolation(1304),
 
     // License related errors starts here
 
@@ -199,7 +204,11 @@ build_exceptions! {
     IllegalCloudControlMessageFormat(1703),
 
     // Geometry errors.
-    GeometryError(1801)
+    GeometryError(1801),
+
+    // Transaction errors.
+    CurrentTransactionIsAborted(1901),
+    StatementNotAllowedInTransaction(1902),
 }
 
 // Meta service errors [2001, 3000].
@@ -298,6 +307,7 @@ build_exceptions! {
     UDFSchemaMismatch(2605),
     UnsupportedDataType(2606),
     UDFDataError(2607),
+    UDFRuntimeError(2608),
 
     // Database error codes.
     UnknownDatabaseEngine(2701),
@@ -335,6 +345,23 @@ build_exceptions! {
     IllegalStream(2733),
     StreamVersionMismatched(2734),
 
+    // Pipe error codes.
+    UnknownPipe(2740),
+    PipeAlreadyExists(2741),
+    IllegalPipe(2742),
+
+    // Task error codes.
+    UnknownTask(2750),
+    TaskAlreadyExists(2751),
+    IllegalTask(2752),
+
+    // Workload group error codes.
+    UnknownWorkloadGroup(2760),
+    WorkloadGroupAlreadyExists(2761),
+    IllegalWorkloadGroup(2762),
+    WorkloadGroupQueueFull(2763),
+    WorkloadGroupQueueTimeout(2764),
+
     // Variable error codes.
     UnknownVariable(2801),
     OnlySupportAsciiChars(2802),
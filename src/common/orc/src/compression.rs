@@ -0,0 +1,189 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::io::Read;
+use std::io::Write;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::proto;
+
+/// Default size of a compression chunk, the same as the Java writer.
+pub const DEFAULT_COMPRESSION_BLOCK_SIZE: u64 = 256 * 1024;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CompressionKind {
+    None,
+    Zlib,
+    Snappy,
+    Lzo,
+    Lz4,
+    Zstd,
+}
+
+impl From<proto::CompressionKind> for CompressionKind {
+    fn from(kind: proto::CompressionKind) -> Self {
+        match kind {
+            proto::CompressionKind::None => CompressionKind::None,
+            proto::CompressionKind::Zlib => CompressionKind::Zlib,
+            proto::CompressionKind::Snappy => CompressionKind::Snappy,
+            proto::CompressionKind::Lzo => CompressionKind::Lzo,
+            proto::CompressionKind::Lz4 => CompressionKind::Lz4,
+            proto::CompressionKind::Zstd => CompressionKind::Zstd,
+        }
+    }
+}
+
+impl From<CompressionKind> for proto::CompressionKind {
+    fn from(kind: CompressionKind) -> Self {
+        match kind {
+            CompressionKind::None => proto::CompressionKind::None,
+            CompressionKind::Zlib => proto::CompressionKind::Zlib,
+            CompressionKind::Snappy => proto::CompressionKind::Snappy,
+            CompressionKind::Lzo => proto::CompressionKind::Lzo,
+            CompressionKind::Lz4 => proto::CompressionKind::Lz4,
+            CompressionKind::Zstd => proto::CompressionKind::Zstd,
+        }
+    }
+}
+
+/// How the streams, stripe footers and file footer of an ORC file are compressed.
+///
+/// Everything except the postscript is cut into chunks of at most `block_size`
+/// bytes, each one prefixed by a 3 bytes little endian header holding
+/// `compressed_length << 1 | is_original`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Compression {
+    pub kind: CompressionKind,
+    pub block_size: u64,
+}
+
+impl Compression {
+    pub fn new(kind: CompressionKind, block_size: u64) -> Self {
+        Self { kind, block_size }
+    }
+
+    pub fn decompress(&self, data: &[u8]) -> Result<Vec<u8>> {
+        if self.kind == CompressionKind::None {
+            return Ok(data.to_vec());
+        }
+
+        let mut out = Vec::with_capacity(data.len() * 2);
+        let mut pos = 0;
+        while pos < data.len() {
+            if pos + 3 > data.len() {
+                return Err(corrupted("truncated compression chunk header"));
+            }
+            let header =
+                data[pos] as usize | (data[pos + 1] as usize) << 8 | (data[pos + 2] as usize) << 16;
+            pos += 3;
+            let is_original = header & 1 == 1;
+            let chunk_len = header >> 1;
+            if pos + chunk_len > data.len() {
+                return Err(corrupted("truncated compression chunk"));
+            }
+            let chunk = &data[pos..pos + chunk_len];
+            pos += chunk_len;
+
+            if is_original {
+                out.extend_from_slice(chunk);
+            } else {
+                self.decompress_chunk(chunk, &mut out)?;
+            }
+        }
+        Ok(out)
+    }
+
+    fn decompress_chunk(&self, chunk: &[u8], out: &mut Vec<u8>) -> Result<()> {
+        match self.kind {
+            CompressionKind::None => out.extend_from_slice(chunk),
+            CompressionKind::Zlib => {
+                flate2::read::DeflateDecoder::new(chunk)
+                    .read_to_end(out)
+                    .map_err(|e| corrupted(format!("zlib: {e}")))?;
+            }
+            CompressionKind::Snappy => {
+                let decoded = snap::raw::Decoder::new()
+                    .decompress_vec(chunk)
+                    .map_err(|e| corrupted(format!("snappy: {e}")))?;
+                out.extend_from_slice(&decoded);
+            }
+            CompressionKind::Lz4 => {
+                let decoded = lz4::block::decompress(chunk, Some(self.block_size as i32))
+                    .map_err(|e| corrupted(format!("lz4: {e}")))?;
+                out.extend_from_slice(&decoded);
+            }
+            CompressionKind::Zstd => {
+                let decoded =
+                    zstd::decode_all(chunk).map_err(|e| corrupted(format!("zstd: {e}")))?;
+                out.extend_from_slice(&decoded);
+            }
+            CompressionKind::Lzo => {
+                return Err(ErrorCode::Unimplemented(
+                    "LZO compressed ORC files are not supported",
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn compress(&self, data: &[u8]) -> Result<Vec<u8>> {
+        if self.kind == CompressionKind::None {
+            return Ok(data.to_vec());
+        }
+
+        let mut out = Vec::with_capacity(data.len() / 2 + 3);
+        for chunk in data.chunks(self.block_size as usize) {
+            let compressed = self.compress_chunk(chunk)?;
+            let (is_original, body) = if compressed.len() < chunk.len() {
+                (0, compressed.as_slice())
+            } else {
+                (1, chunk)
+            };
+            let header = body.len() << 1 | is_original;
+            out.extend_from_slice(&[header as u8, (header >> 8) as u8, (header >> 16) as u8]);
+            out.extend_from_slice(body);
+        }
+        Ok(out)
+    }
+
+    fn compress_chunk(&self, chunk: &[u8]) -> Result<Vec<u8>> {
+        match self.kind {
+            CompressionKind::None => Ok(chunk.to_vec()),
+            CompressionKind::Zlib => {
+                let mut encoder = flate2::write::DeflateEncoder::new(
+                    Vec::with_capacity(chunk.len()),
+                    flate2::Compression::default(),
+                );
+                encoder.write_all(chunk)?;
+                Ok(encoder.finish()?)
+            }
+            CompressionKind::Snappy => snap::raw::Encoder::new()
+                .compress_vec(chunk)
+                .map_err(|e| ErrorCode::Internal(format!("snappy: {e}"))),
+            CompressionKind::Lz4 => Ok(lz4::block::compress(chunk, None, false)?),
+            CompressionKind::Zstd => Ok(zstd::encode_all(chunk, 0)?),
+            CompressionKind::Lzo => Err(ErrorCode::Unimplemented(
+                "LZO compressed ORC files are not supported",
+            )),
+        }
+    }
+}
+
+pub(crate) fn corrupted(msg: impl AsRef<str>) -> ErrorCode {
+    ErrorCode::BadBytes(format!("Corrupted ORC file: {}", msg.as_ref()))
+}
@@ -0,0 +1,471 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Run length encodings of ORC streams.
+//!
+//! See <https://orc.apache.org/specification/ORCv1/> for the layouts.
+
+use databend_common_exception::Result;
+
+use crate::compression::corrupted;
+
+/// Integer run length encoding used by a column, decided by its `ColumnEncoding`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RleVersion {
+    V1,
+    V2,
+}
+
+pub struct ByteReader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> ByteReader<'a> {
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pos >= self.data.len()
+    }
+
+    pub fn read_u8(&mut self) -> Result<u8> {
+        let b = *self
+            .data
+            .get(self.pos)
+            .ok_or_else(|| corrupted("unexpected end of stream"))?;
+        self.pos += 1;
+        Ok(b)
+    }
+
+    pub fn read_bytes(&mut self, n: usize) -> Result<&'a [u8]> {
+        if self.pos + n > self.data.len() {
+            return Err(corrupted("unexpected end of stream"));
+        }
+        let bytes = &self.data[self.pos..self.pos + n];
+        self.pos += n;
+        Ok(bytes)
+    }
+
+    pub fn read_varint_u64(&mut self) -> Result<u64> {
+        let mut result = 0u64;
+        let mut shift = 0;
+        loop {
+            let b = self.read_u8()?;
+            if shift >= 64 {
+                return Err(corrupted("varint is too long"));
+            }
+            result |= ((b & 0x7f) as u64) << shift;
+            if b & 0x80 == 0 {
+                return Ok(result);
+            }
+            shift += 7;
+        }
+    }
+
+    pub fn read_varint_i64(&mut self) -> Result<i64> {
+        Ok(unzigzag(self.read_varint_u64()?))
+    }
+
+    /// Decimal values are stored as unbounded zigzag encoded varints.
+    pub fn read_varint_i128(&mut self) -> Result<i128> {
+        let mut result = 0u128;
+        let mut shift = 0;
+        loop {
+            let b = self.read_u8()?;
+            if shift >= 128 {
+                return Err(corrupted("decimal value is too large"));
+            }
+            result |= ((b & 0x7f) as u128) << shift;
+            if b & 0x80 == 0 {
+                return Ok((result >> 1) as i128 ^ -((result & 1) as i128));
+            }
+            shift += 7;
+        }
+    }
+}
+
+#[inline]
+pub fn unzigzag(v: u64) -> i64 {
+    (v >> 1) as i64 ^ -((v & 1) as i64)
+}
+
+#[inline]
+pub fn zigzag(v: i64) -> u64 {
+    ((v << 1) ^ (v >> 63)) as u64
+}
+
+pub fn write_varint_u64(out: &mut Vec<u8>, mut v: u64) {
+    while v >= 0x80 {
+        out.push((v as u8 & 0x7f) | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+pub fn write_varint_i128(out: &mut Vec<u8>, v: i128) {
+    let mut v = ((v << 1) ^ (v >> 127)) as u128;
+    while v >= 0x80 {
+        out.push((v as u8 & 0x7f) | 0x80);
+        v >>= 7;
+    }
+    out.push(v as u8);
+}
+
+pub fn decode_byte_rle(data: &[u8], num_values: usize) -> Result<Vec<u8>> {
+    let mut reader = ByteReader::new(data);
+    let mut values = Vec::with_capacity(num_values);
+    while values.len() < num_values {
+        let control = reader.read_u8()?;
+        if control < 0x80 {
+            let len = control as usize + 3;
+            let value = reader.read_u8()?;
+            values.extend(std::iter::repeat(value).take(len));
+        } else {
+            let len = 0x100 - control as usize;
+            values.extend_from_slice(reader.read_bytes(len)?);
+        }
+    }
+    values.truncate(num_values);
+    Ok(values)
+}
+
+pub fn decode_bool_rle(data: &[u8], num_values: usize) -> Result<Vec<bool>> {
+    let bytes = decode_byte_rle(data, (num_values + 7) / 8)?;
+    let mut values = Vec::with_capacity(num_values);
+    for i in 0..num_values {
+        values.push(bytes[i / 8] & (0x80 >> (i % 8)) != 0);
+    }
+    Ok(values)
+}
+
+pub fn decode_int_rle(
+    data: &[u8],
+    num_values: usize,
+    signed: bool,
+    version: RleVersion,
+) -> Result<Vec<i64>> {
+    let mut reader = ByteReader::new(data);
+    let mut values = Vec::with_capacity(num_values);
+    while values.len() < num_values {
+        match version {
+            RleVersion::V1 => decode_int_rle_v1_run(&mut reader, signed, &mut values)?,
+            RleVersion::V2 => decode_int_rle_v2_run(&mut reader, signed, &mut values)?,
+        }
+    }
+    values.truncate(num_values);
+    Ok(values)
+}
+
+fn read_int(reader: &mut ByteReader, signed: bool) -> Result<i64> {
+    if signed {
+        reader.read_varint_i64()
+    } else {
+        Ok(reader.read_varint_u64()? as i64)
+    }
+}
+
+fn decode_int_rle_v1_run(reader: &mut ByteReader, signed: bool, out: &mut Vec<i64>) -> Result<()> {
+    let control = reader.read_u8()?;
+    if control < 0x80 {
+        let len = control as i64 + 3;
+        let delta = reader.read_u8()? as i8 as i64;
+        let base = read_int(reader, signed)?;
+        out.extend((0..len).map(|i| base.wrapping_add(i.wrapping_mul(delta))));
+    } else {
+        let len = 0x100 - control as usize;
+        for _ in 0..len {
+            out.push(read_int(reader, signed)?);
+        }
+    }
+    Ok(())
+}
+
+/// Maps the 5 bits width code of RLE v2 headers to a bit width.
+fn decode_bit_width(code: u8) -> usize {
+    match code {
+        0..=23 => code as usize + 1,
+        24 => 26,
+        25 => 28,
+        26 => 30,
+        27 => 32,
+        28 => 40,
+        29 => 48,
+        30 => 56,
+        _ => 64,
+    }
+}
+
+/// Rounds a bit width up to one that the width code can represent.
+fn closest_fixed_bits(width: usize) -> usize {
+    match width {
+        0 => 1,
+        1..=24 => width,
+        25..=26 => 26,
+        27..=28 => 28,
+        29..=30 => 30,
+        31..=32 => 32,
+        33..=40 => 40,
+        41..=48 => 48,
+        49..=56 => 56,
+        _ => 64,
+    }
+}
+
+fn read_bit_packed(
+    reader: &mut ByteReader,
+    width: usize,
+    count: usize,
+    out: &mut Vec<u64>,
+) -> Result<()> {
+    let mut current = 0u64;
+    let mut bits_left = 0usize;
+    for _ in 0..count {
+        let mut value = 0u64;
+        let mut bits_needed = width;
+        while bits_needed > 0 {
+            if bits_left == 0 {
+                current = reader.read_u8()? as u64;
+                bits_left = 8;
+            }
+            let take = bits_needed.min(bits_left);
+            let bits = (current >> (bits_left - take)) & ((1u64 << take) - 1);
+            value = value << take | bits;
+            bits_left -= take;
+            bits_needed -= take;
+        }
+        out.push(value);
+    }
+    Ok(())
+}
+
+fn read_big_endian(reader: &mut ByteReader, num_bytes: usize) -> Result<u64> {
+    let mut value = 0u64;
+    for b in reader.read_bytes(num_bytes)? {
+        value = value << 8 | *b as u64;
+    }
+    Ok(value)
+}
+
+fn decode_int_rle_v2_run(reader: &mut ByteReader, signed: bool, out: &mut Vec<i64>) -> Result<()> {
+    let first = reader.read_u8()?;
+    let decode = |v: u64| if signed { unzigzag(v) } else { v as i64 };
+    match first >> 6 {
+        // SHORT_REPEAT
+        0 => {
+            let num_bytes = ((first >> 3) & 0x07) as usize + 1;
+            let len = (first & 0x07) as usize + 3;
+            let value = decode(read_big_endian(reader, num_bytes)?);
+            out.extend(std::iter::repeat(value).take(len));
+        }
+        // DIRECT
+        1 => {
+            let width = decode_bit_width((first >> 1) & 0x1f);
+            let len = (((first & 0x01) as usize) << 8 | reader.read_u8()? as usize) + 1;
+            let mut packed = Vec::with_capacity(len);
+            read_bit_packed(reader, width, len, &mut packed)?;
+            out.extend(packed.into_iter().map(decode));
+        }
+        // PATCHED_BASE
+        2 => {
+            let width = decode_bit_width((first >> 1) & 0x1f);
+            let len = (((first & 0x01) as usize) << 8 | reader.read_u8()? as usize) + 1;
+            let third = reader.read_u8()?;
+            let base_bytes = ((third >> 5) & 0x07) as usize + 1;
+            let patch_width = decode_bit_width(third & 0x1f);
+            let fourth = reader.read_u8()?;
+            let gap_width = ((fourth >> 5) & 0x07) as usize + 1;
+            let patch_list_len = (fourth & 0x1f) as usize;
+
+            // The base value is stored in sign-magnitude form.
+            let base = read_big_endian(reader, base_bytes)?;
+            let sign_mask = 1u64 << (base_bytes * 8 - 1);
+            let base = if base & sign_mask != 0 {
+                -((base & !sign_mask) as i64)
+            } else {
+                base as i64
+            };
+
+            let mut unpacked = Vec::with_capacity(len);
+            read_bit_packed(reader, width, len, &mut unpacked)?;
+            let mut patches = Vec::with_capacity(patch_list_len);
+            read_bit_packed(
+                reader,
+                closest_fixed_bits(gap_width + patch_width),
+                patch_list_len,
+                &mut patches,
+            )?;
+
+            let patch_mask = if patch_width >= 64 {
+                u64::MAX
+            } else {
+                (1u64 << patch_width) - 1
+            };
+            let mut position = 0usize;
+            let mut patches = patches.into_iter();
+            while let Some(mut entry) = patches.next() {
+                // A 64-bit patch leaves no room for the gap in the entry.
+                let mut gap = entry.checked_shr(patch_width as u32).unwrap_or(0) as usize;
+                let mut patch = entry & patch_mask;
+                // A gap larger than 255 is split into entries with a zero patch.
+                while gap == 255 && patch == 0 {
+                    position += 255;
+                    entry = patches
+                        .next()
+                        .ok_or_else(|| corrupted("truncated patch list"))?;
+                    gap = entry.checked_shr(patch_width as u32).unwrap_or(0) as usize;
+                    patch = entry & patch_mask;
+                }
+                position += gap;
+                let value = unpacked
+                    .get_mut(position)
+                    .ok_or_else(|| corrupted("patch position out of range"))?;
+                if width < 64 {
+                    *value |= patch << width;
+                }
+            }
+            out.extend(unpacked.into_iter().map(|v| base.wrapping_add(v as i64)));
+        }
+        // DELTA
+        _ => {
+            let code = (first >> 1) & 0x1f;
+            let width = if code == 0 { 0 } else { decode_bit_width(code) };
+            let len = (((first & 0x01) as usize) << 8 | reader.read_u8()? as usize) + 1;
+            let base = read_int(reader, signed)?;
+            let delta_base = reader.read_varint_i64()?;
+
+            out.push(base);
+            if len == 1 {
+                return Ok(());
+            }
+            let mut value = base.wrapping_add(delta_base);
+            out.push(value);
+            if width == 0 {
+                for _ in 2..len {
+                    value = value.wrapping_add(delta_base);
+                    out.push(value);
+                }
+            } else {
+                let mut deltas = Vec::with_capacity(len - 2);
+                read_bit_packed(reader, width, len - 2, &mut deltas)?;
+                for delta in deltas {
+                    value = if delta_base < 0 {
+                        value.wrapping_sub(delta as i64)
+                    } else {
+                        value.wrapping_add(delta as i64)
+                    };
+                    out.push(value);
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+pub fn encode_byte_rle(values: &[u8], out: &mut Vec<u8>) {
+    let mut literals: Vec<u8> = Vec::with_capacity(128);
+    let mut i = 0;
+    while i < values.len() {
+        let mut run = 1;
+        while i + run < values.len() && run < 130 && values[i + run] == values[i] {
+            run += 1;
+        }
+        if run >= 3 {
+            flush_byte_literals(&mut literals, out);
+            out.push((run - 3) as u8);
+            out.push(values[i]);
+            i += run;
+        } else {
+            literals.push(values[i]);
+            if literals.len() == 128 {
+                flush_byte_literals(&mut literals, out);
+            }
+            i += 1;
+        }
+    }
+    flush_byte_literals(&mut literals, out);
+}
+
+fn flush_byte_literals(literals: &mut Vec<u8>, out: &mut Vec<u8>) {
+    if !literals.is_empty() {
+        out.push((-(literals.len() as i16)) as u8);
+        out.append(literals);
+    }
+}
+
+pub fn encode_bool_rle(values: impl IntoIterator<Item = bool>, out: &mut Vec<u8>) {
+    let mut bytes = vec![];
+    for (i, v) in values.into_iter().enumerate() {
+        if i % 8 == 0 {
+            bytes.push(0u8);
+        }
+        if v {
+            *bytes.last_mut().unwrap() |= 0x80 >> (i % 8);
+        }
+    }
+    encode_byte_rle(&bytes, out);
+}
+
+/// Encodes integers with RLE v1, which every ORC reader understands.
+pub fn encode_int_rle_v1(values: &[i64], signed: bool, out: &mut Vec<u8>) {
+    let write = |out: &mut Vec<u8>, v: i64| {
+        if signed {
+            write_varint_u64(out, zigzag(v))
+        } else {
+            write_varint_u64(out, v as u64)
+        }
+    };
+
+    let mut literals: Vec<i64> = Vec::with_capacity(128);
+    let flush = |literals: &mut Vec<i64>, out: &mut Vec<u8>| {
+        if !literals.is_empty() {
+            out.push((-(literals.len() as i16)) as u8);
+            for v in literals.drain(..) {
+                write(out, v);
+            }
+        }
+    };
+
+    let mut i = 0;
+    while i < values.len() {
+        let delta = values
+            .get(i + 1)
+            .and_then(|next| next.checked_sub(values[i]))
+            .filter(|delta| (-128..=127).contains(delta));
+        let mut run = 1;
+        if let Some(delta) = delta {
+            while i + run < values.len()
+                && run < 130
+                && values[i + run].checked_sub(values[i + run - 1]) == Some(delta)
+            {
+                run += 1;
+            }
+        }
+        if run >= 3 {
+            flush(&mut literals, out);
+            out.push((run - 3) as u8);
+            out.push(delta.unwrap_or_default() as i8 as u8);
+            write(out, values[i]);
+            i += run;
+        } else {
+            literals.push(values[i]);
+            if literals.len() == 128 {
+                flush(&mut literals, out);
+            }
+            i += 1;
+        }
+    }
+    flush(&mut literals, out);
+}
@@ -0,0 +1,52 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Reader and writer of the Apache ORC file format.
+//!
+//! Files are split by stripes, the reader decodes the columns of one stripe
+//! at a time and the writer emits one stripe per data block.
+
+#![allow(clippy::uninlined_format_args)]
+
+mod compression;
+mod encoding;
+mod reader;
+mod schema;
+mod statistics;
+mod writer;
+
+pub mod proto {
+    #![allow(clippy::all)]
+    include!(concat!(env!("OUT_DIR"), "/orc.proto.rs"));
+}
+
+pub use compression::Compression;
+pub use compression::CompressionKind;
+pub use encoding::decode_int_rle;
+pub use encoding::encode_int_rle_v1;
+pub use encoding::RleVersion;
+pub use reader::adapt_column;
+pub use reader::decode_timestamp;
+pub use reader::encode_timestamp;
+pub use reader::orc_tail_size;
+pub use reader::parse_orc_tail;
+pub use reader::read_orc_metadata;
+pub use reader::OrcFileMeta;
+pub use reader::OrcStripeInfo;
+pub use reader::OrcStripeReader;
+pub use schema::OrcSchema;
+pub use schema::OrcType;
+pub use schema::OrcTypeKind;
+pub use statistics::OrcColumnStatistics;
+pub use writer::OrcWriter;
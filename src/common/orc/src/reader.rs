@@ -0,0 +1,633 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::ops::Range;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::array::ArrayColumn;
+use databend_common_expression::types::binary::BinaryColumn;
+use databend_common_expression::types::decimal::DecimalColumn;
+use databend_common_expression::types::nullable::NullableColumn;
+use databend_common_expression::types::string::StringColumn;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::DecimalSize;
+use databend_common_expression::types::NumberColumn;
+use databend_common_expression::types::F32;
+use databend_common_expression::types::F64;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use opendal::Operator;
+use prost::Message;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::compression::corrupted;
+use crate::compression::Compression;
+use crate::encoding::decode_bool_rle;
+use crate::encoding::decode_byte_rle;
+use crate::encoding::decode_int_rle;
+use crate::encoding::ByteReader;
+use crate::encoding::RleVersion;
+use crate::proto;
+use crate::proto::column_encoding;
+use crate::proto::stream::Kind as StreamKind;
+use crate::schema::OrcSchema;
+use crate::schema::OrcTypeKind;
+
+pub const ORC_MAGIC: &[u8] = b"ORC";
+
+/// Seconds between the unix epoch and 2015-01-01 00:00:00, the base of ORC timestamps.
+pub const ORC_TIMESTAMP_BASE_SECONDS: i64 = 1_420_070_400;
+
+/// Bytes read from the end of a file at first, enough for the tail of most files.
+const DEFAULT_TAIL_SIZE: u64 = 16 * 1024;
+
+/// Location of a stripe in its file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcStripeInfo {
+    pub offset: u64,
+    pub index_length: u64,
+    pub data_length: u64,
+    pub footer_length: u64,
+    pub num_rows: u64,
+}
+
+impl OrcStripeInfo {
+    /// Length of the whole stripe: index streams, data streams and footer.
+    pub fn length(&self) -> u64 {
+        self.index_length + self.data_length + self.footer_length
+    }
+}
+
+/// The decoded file tail of an ORC file.
+#[derive(Clone, Debug)]
+pub struct OrcFileMeta {
+    pub compression: Compression,
+    pub schema: OrcSchema,
+    pub num_rows: u64,
+    pub stripes: Vec<OrcStripeInfo>,
+    /// Statistics of every column in every stripe, empty if the writer left them out.
+    pub stripe_statistics: Vec<Vec<proto::ColumnStatistics>>,
+}
+
+/// Reads the tail of an ORC file: postscript, footer and stripe statistics.
+#[async_backtrace::framed]
+pub async fn read_orc_metadata(
+    operator: &Operator,
+    path: &str,
+    file_size: Option<u64>,
+) -> Result<OrcFileMeta> {
+    let file_size = match file_size {
+        Some(size) => size,
+        None => operator.stat(path).await?.content_length(),
+    };
+    if file_size <= ORC_MAGIC.len() as u64 {
+        return Err(corrupted(format!("{path} is too small to be an ORC file")));
+    }
+
+    let read_size = file_size.min(DEFAULT_TAIL_SIZE);
+    let mut tail = operator
+        .read_with(path)
+        .range(file_size - read_size..file_size)
+        .await?;
+    let tail_size = orc_tail_size(&tail)? as u64;
+    if tail_size > file_size {
+        return Err(corrupted(format!("invalid file tail of {path}")));
+    }
+    if tail_size > read_size {
+        tail = operator
+            .read_with(path)
+            .range(file_size - tail_size..file_size)
+            .await?;
+    }
+    parse_orc_tail(&tail)
+}
+
+fn read_postscript(tail: &[u8]) -> Result<(proto::PostScript, usize)> {
+    let ps_len = *tail.last().ok_or_else(|| corrupted("empty file tail"))? as usize;
+    if ps_len + 1 > tail.len() {
+        return Err(corrupted("truncated postscript"));
+    }
+    let ps_start = tail.len() - 1 - ps_len;
+    let postscript = proto::PostScript::decode(&tail[ps_start..tail.len() - 1])
+        .map_err(|e| corrupted(format!("invalid postscript: {e}")))?;
+    if postscript
+        .magic
+        .as_ref()
+        .is_some_and(|m| m.as_bytes() != ORC_MAGIC)
+    {
+        return Err(corrupted("missing ORC magic in postscript"));
+    }
+    Ok((postscript, ps_len))
+}
+
+/// Size of the file tail, `tail` must end with the last byte of the file.
+pub fn orc_tail_size(tail: &[u8]) -> Result<usize> {
+    let (postscript, ps_len) = read_postscript(tail)?;
+    Ok(1 + ps_len + postscript.footer_length() as usize + postscript.metadata_length() as usize)
+}
+
+/// Decodes the file tail, `tail` must hold at least [`orc_tail_size`] bytes.
+pub fn parse_orc_tail(tail: &[u8]) -> Result<OrcFileMeta> {
+    let (postscript, ps_len) = read_postscript(tail)?;
+    let compression = Compression::new(
+        postscript.compression().into(),
+        postscript.compression_block_size(),
+    );
+
+    let footer_len = postscript.footer_length() as usize;
+    let metadata_len = postscript.metadata_length() as usize;
+    if 1 + ps_len + footer_len + metadata_len > tail.len() {
+        return Err(corrupted("truncated file tail"));
+    }
+    let footer_end = tail.len() - 1 - ps_len;
+    let footer_start = footer_end - footer_len;
+    let metadata_start = footer_start - metadata_len;
+
+    let footer = proto::Footer::decode(
+        compression
+            .decompress(&tail[footer_start..footer_end])?
+            .as_slice(),
+    )
+    .map_err(|e| corrupted(format!("invalid footer: {e}")))?;
+    let stripe_statistics = if metadata_len > 0 {
+        let metadata = proto::Metadata::decode(
+            compression
+                .decompress(&tail[metadata_start..footer_start])?
+                .as_slice(),
+        )
+        .map_err(|e| corrupted(format!("invalid metadata: {e}")))?;
+        metadata
+            .stripe_stats
+            .into_iter()
+            .map(|s| s.col_stats)
+            .collect()
+    } else {
+        vec![]
+    };
+
+    let schema = OrcSchema::from_proto(&footer.types)?;
+    let stripes = footer
+        .stripes
+        .iter()
+        .map(|s| OrcStripeInfo {
+            offset: s.offset(),
+            index_length: s.index_length(),
+            data_length: s.data_length(),
+            footer_length: s.footer_length(),
+            num_rows: s.number_of_rows(),
+        })
+        .collect();
+
+    Ok(OrcFileMeta {
+        compression,
+        schema,
+        num_rows: footer.number_of_rows(),
+        stripes,
+        stripe_statistics,
+    })
+}
+
+/// Decodes the columns of one stripe.
+///
+/// `data` holds the bytes of the whole stripe, as located by [`OrcStripeInfo`].
+pub struct OrcStripeReader<'a> {
+    compression: Compression,
+    schema: &'a OrcSchema,
+    data: &'a [u8],
+    num_rows: usize,
+    streams: HashMap<(u32, i32), Range<usize>>,
+    encodings: Vec<proto::ColumnEncoding>,
+}
+
+impl<'a> OrcStripeReader<'a> {
+    pub fn try_create(
+        compression: Compression,
+        schema: &'a OrcSchema,
+        stripe: &OrcStripeInfo,
+        data: &'a [u8],
+    ) -> Result<Self> {
+        if data.len() as u64 != stripe.length() {
+            return Err(corrupted(format!(
+                "expect {} bytes of stripe at {}, got {}",
+                stripe.length(),
+                stripe.offset,
+                data.len()
+            )));
+        }
+        let footer_start = (stripe.index_length + stripe.data_length) as usize;
+        let footer =
+            proto::StripeFooter::decode(compression.decompress(&data[footer_start..])?.as_slice())
+                .map_err(|e| corrupted(format!("invalid stripe footer: {e}")))?;
+
+        // Streams are stored back to back in the order of the footer.
+        let mut streams = HashMap::with_capacity(footer.streams.len());
+        let mut offset = 0usize;
+        for stream in footer.streams.iter() {
+            let end = offset + stream.length() as usize;
+            if end > footer_start {
+                return Err(corrupted("stream exceeds the stripe"));
+            }
+            streams.insert((stream.column(), stream.kind), offset..end);
+            offset = end;
+        }
+
+        Ok(Self {
+            compression,
+            schema,
+            data,
+            num_rows: stripe.num_rows as usize,
+            streams,
+            encodings: footer.columns,
+        })
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Decodes the top level field at `index` into a nullable column.
+    pub fn read_field(&self, index: usize) -> Result<Column> {
+        let column_id = self.schema.field_column_id(index);
+        self.read_column(column_id, self.num_rows, true)
+    }
+
+    fn stream(&self, column_id: u32, kind: StreamKind) -> Result<Option<Vec<u8>>> {
+        match self.streams.get(&(column_id, kind as i32)) {
+            Some(range) => Ok(Some(
+                self.compression.decompress(&self.data[range.clone()])?,
+            )),
+            None => Ok(None),
+        }
+    }
+
+    /// Missing streams are allowed only if there is nothing to read from them.
+    fn required_stream(
+        &self,
+        column_id: u32,
+        kind: StreamKind,
+        num_values: usize,
+    ) -> Result<Vec<u8>> {
+        match self.stream(column_id, kind)? {
+            Some(data) => Ok(data),
+            None if num_values == 0 => Ok(vec![]),
+            None => Err(corrupted(format!(
+                "missing {:?} stream of column {column_id}",
+                kind
+            ))),
+        }
+    }
+
+    fn encoding(&self, column_id: u32) -> column_encoding::Kind {
+        self.encodings
+            .get(column_id as usize)
+            .map(|e| e.kind())
+            .unwrap_or(column_encoding::Kind::Direct)
+    }
+
+    fn rle_version(&self, column_id: u32) -> RleVersion {
+        match self.encoding(column_id) {
+            column_encoding::Kind::Direct | column_encoding::Kind::Dictionary => RleVersion::V1,
+            column_encoding::Kind::DirectV2 | column_encoding::Kind::DictionaryV2 => RleVersion::V2,
+        }
+    }
+
+    fn read_ints(
+        &self,
+        column_id: u32,
+        kind: StreamKind,
+        num_values: usize,
+        signed: bool,
+    ) -> Result<Vec<i64>> {
+        let data = self.required_stream(column_id, kind, num_values)?;
+        decode_int_rle(&data, num_values, signed, self.rle_version(column_id))
+    }
+
+    fn read_column(&self, column_id: u32, num_rows: usize, nullable: bool) -> Result<Column> {
+        let present = match self.stream(column_id, StreamKind::Present)? {
+            Some(data) => Some(Bitmap::from_iter(decode_bool_rle(&data, num_rows)?)),
+            None => None,
+        };
+        let num_values = num_rows - present.as_ref().map(|p| p.unset_bits()).unwrap_or(0);
+        let values = self.read_values(column_id, num_values)?;
+
+        if !nullable {
+            if num_values != num_rows {
+                return Err(corrupted(format!(
+                    "unexpected null values in column {column_id}"
+                )));
+            }
+            return Ok(values);
+        }
+
+        let (column, validity) = match present {
+            Some(validity) if num_values != num_rows => {
+                let data_type = values.data_type();
+                let column = if num_values == 0 {
+                    let mut builder = ColumnBuilder::with_capacity(&data_type, num_rows);
+                    for _ in 0..num_rows {
+                        builder.push_default();
+                    }
+                    builder.build()
+                } else {
+                    // Values are only stored for present rows, spread them to their rows.
+                    let last = num_values as u32 - 1;
+                    let mut next = 0u32;
+                    let indices = validity
+                        .iter()
+                        .map(|is_present| {
+                            let index = next.min(last);
+                            if is_present {
+                                next += 1;
+                            }
+                            index
+                        })
+                        .collect::<Vec<_>>();
+                    values.take(&indices, &mut None)
+                };
+                (column, validity)
+            }
+            _ => (values, Bitmap::new_constant(true, num_rows)),
+        };
+        Ok(Column::Nullable(Box::new(NullableColumn::<AnyType> {
+            column,
+            validity,
+        })))
+    }
+
+    fn read_values(&self, column_id: u32, num_values: usize) -> Result<Column> {
+        let ty = &self.schema.types[column_id as usize];
+        let column = match ty.kind {
+            OrcTypeKind::Boolean => {
+                let data = self.required_stream(column_id, StreamKind::Data, num_values)?;
+                Column::Boolean(Bitmap::from_iter(decode_bool_rle(&data, num_values)?))
+            }
+            OrcTypeKind::Byte => {
+                let data = self.required_stream(column_id, StreamKind::Data, num_values)?;
+                let values = decode_byte_rle(&data, num_values)?;
+                let values = values.into_iter().map(|v| v as i8).collect::<Vec<_>>();
+                Column::Number(NumberColumn::Int8(values.into()))
+            }
+            OrcTypeKind::Short => {
+                let values = self.read_ints(column_id, StreamKind::Data, num_values, true)?;
+                let values = values.into_iter().map(|v| v as i16).collect::<Vec<_>>();
+                Column::Number(NumberColumn::Int16(values.into()))
+            }
+            OrcTypeKind::Int => {
+                let values = self.read_ints(column_id, StreamKind::Data, num_values, true)?;
+                let values = values.into_iter().map(|v| v as i32).collect::<Vec<_>>();
+                Column::Number(NumberColumn::Int32(values.into()))
+            }
+            OrcTypeKind::Long => {
+                let values = self.read_ints(column_id, StreamKind::Data, num_values, true)?;
+                Column::Number(NumberColumn::Int64(values.into()))
+            }
+            OrcTypeKind::Float => {
+                let data = self.required_stream(column_id, StreamKind::Data, num_values)?;
+                let values = read_fixed::<4>(&data, num_values)?
+                    .map(|b| F32::from(f32::from_le_bytes(b)))
+                    .collect::<Vec<_>>();
+                Column::Number(NumberColumn::Float32(values.into()))
+            }
+            OrcTypeKind::Double => {
+                let data = self.required_stream(column_id, StreamKind::Data, num_values)?;
+                let values = read_fixed::<8>(&data, num_values)?
+                    .map(|b| F64::from(f64::from_le_bytes(b)))
+                    .collect::<Vec<_>>();
+                Column::Number(NumberColumn::Float64(values.into()))
+            }
+            OrcTypeKind::String | OrcTypeKind::Varchar | OrcTypeKind::Char => {
+                let column = self.read_binary(column_id, num_values)?;
+                Column::String(StringColumn::try_from(column)?)
+            }
+            OrcTypeKind::Binary => Column::Binary(self.read_binary(column_id, num_values)?),
+            OrcTypeKind::Date => {
+                let values = self.read_ints(column_id, StreamKind::Data, num_values, true)?;
+                let values = values.into_iter().map(|v| v as i32).collect::<Vec<_>>();
+                Column::Date(values.into())
+            }
+            OrcTypeKind::Timestamp | OrcTypeKind::TimestampInstant => {
+                let seconds = self.read_ints(column_id, StreamKind::Data, num_values, true)?;
+                let nanos = self.read_ints(column_id, StreamKind::Secondary, num_values, false)?;
+                let values = seconds
+                    .into_iter()
+                    .zip(nanos)
+                    .map(|(seconds, nanos)| decode_timestamp(seconds, nanos as u64))
+                    .collect::<Vec<_>>();
+                Column::Timestamp(values.into())
+            }
+            OrcTypeKind::Decimal => {
+                let data = self.required_stream(column_id, StreamKind::Data, num_values)?;
+                let scales = self.read_ints(column_id, StreamKind::Secondary, num_values, true)?;
+                let target_scale = ty.scale as i64;
+                let mut reader = ByteReader::new(&data);
+                let mut values = Vec::with_capacity(num_values);
+                for scale in scales {
+                    let value = reader.read_varint_i128()?;
+                    let value = match target_scale - scale {
+                        0 => value,
+                        diff if diff > 0 => value * 10i128.pow(diff as u32),
+                        diff => value / 10i128.pow((-diff) as u32),
+                    };
+                    values.push(value);
+                }
+                let size = DecimalSize {
+                    precision: ty.precision as u8,
+                    scale: ty.scale as u8,
+                };
+                Column::Decimal(DecimalColumn::Decimal128(values.into(), size))
+            }
+            OrcTypeKind::List => {
+                let offsets = self.read_offsets(column_id, num_values)?;
+                let num_children = *offsets.last().unwrap() as usize;
+                let values = self.read_column(ty.subtypes[0], num_children, true)?;
+                Column::Array(Box::new(ArrayColumn::<AnyType> {
+                    values,
+                    offsets: offsets.into(),
+                }))
+            }
+            OrcTypeKind::Map => {
+                let offsets = self.read_offsets(column_id, num_values)?;
+                let num_children = *offsets.last().unwrap() as usize;
+                let keys = self.read_column(ty.subtypes[0], num_children, false)?;
+                let values = self.read_column(ty.subtypes[1], num_children, true)?;
+                Column::Map(Box::new(ArrayColumn::<AnyType> {
+                    values: Column::Tuple(vec![keys, values]),
+                    offsets: offsets.into(),
+                }))
+            }
+            OrcTypeKind::Struct => {
+                // Children only hold values for rows where the struct is present.
+                let fields = ty
+                    .subtypes
+                    .iter()
+                    .map(|child| self.read_column(*child, num_values, true))
+                    .collect::<Result<Vec<_>>>()?;
+                Column::Tuple(fields)
+            }
+            OrcTypeKind::Union => {
+                return Err(ErrorCode::Unimplemented(
+                    "ORC union types are not supported",
+                ));
+            }
+        };
+        Ok(column)
+    }
+
+    fn read_offsets(&self, column_id: u32, num_values: usize) -> Result<Vec<u64>> {
+        let lengths = self.read_ints(column_id, StreamKind::Length, num_values, false)?;
+        let mut offsets = Vec::with_capacity(num_values + 1);
+        let mut offset = 0u64;
+        offsets.push(offset);
+        for len in lengths {
+            offset += len as u64;
+            offsets.push(offset);
+        }
+        Ok(offsets)
+    }
+
+    fn read_binary(&self, column_id: u32, num_values: usize) -> Result<BinaryColumn> {
+        match self.encoding(column_id) {
+            column_encoding::Kind::Direct | column_encoding::Kind::DirectV2 => {
+                let offsets = self.read_offsets(column_id, num_values)?;
+                let data = self.required_stream(column_id, StreamKind::Data, num_values)?;
+                if (*offsets.last().unwrap() as usize) > data.len() {
+                    return Err(corrupted(format!(
+                        "string data of column {column_id} is truncated"
+                    )));
+                }
+                Ok(BinaryColumn::new(data.into(), offsets.into()))
+            }
+            column_encoding::Kind::Dictionary | column_encoding::Kind::DictionaryV2 => {
+                let dict_size = self.encodings[column_id as usize].dictionary_size() as usize;
+                let dict_offsets = self.read_offsets(column_id, dict_size)?;
+                let dict_data =
+                    self.required_stream(column_id, StreamKind::DictionaryData, dict_size)?;
+                if (*dict_offsets.last().unwrap() as usize) > dict_data.len() {
+                    return Err(corrupted(format!(
+                        "dictionary of column {column_id} is truncated"
+                    )));
+                }
+                let indices = self.read_ints(column_id, StreamKind::Data, num_values, false)?;
+
+                let mut data = Vec::new();
+                let mut offsets = Vec::with_capacity(num_values + 1);
+                offsets.push(0u64);
+                for index in indices {
+                    let index = index as usize;
+                    if index >= dict_size {
+                        return Err(corrupted(format!(
+                            "dictionary index {index} out of range in column {column_id}"
+                        )));
+                    }
+                    let start = dict_offsets[index] as usize;
+                    let end = dict_offsets[index + 1] as usize;
+                    data.extend_from_slice(&dict_data[start..end]);
+                    offsets.push(data.len() as u64);
+                }
+                Ok(BinaryColumn::new(data.into(), offsets.into()))
+            }
+        }
+    }
+}
+
+fn read_fixed<const N: usize>(
+    data: &[u8],
+    num_values: usize,
+) -> Result<impl Iterator<Item = [u8; N]> + '_> {
+    if data.len() < N * num_values {
+        return Err(corrupted("floating point data is truncated"));
+    }
+    Ok(data[..N * num_values]
+        .chunks_exact(N)
+        .map(|b| b.try_into().unwrap()))
+}
+
+/// Decodes the nanoseconds of the SECONDARY stream, the low 3 bits tell how
+/// many trailing decimal zeros were dropped.
+fn decode_nanos(encoded: u64) -> i64 {
+    let zeros = encoded & 0x07;
+    let mut nanos = (encoded >> 3) as i64;
+    if zeros != 0 {
+        nanos *= 10i64.pow(zeros as u32 + 1);
+    }
+    nanos
+}
+
+/// Columns of ORC files are nullable, drop the validity for non-nullable
+/// columns of the target schema if there is no null value.
+pub fn adapt_column(column: Column, data_type: &DataType) -> Result<Column> {
+    let column_type = column.data_type();
+    if &column_type == data_type {
+        return Ok(column);
+    }
+    match column {
+        Column::Nullable(c) if &c.column.data_type() == data_type => {
+            let NullableColumn { column, validity } = *c;
+            if validity.unset_bits() > 0 {
+                return Err(ErrorCode::BadBytes(format!(
+                    "{} null values can not be loaded into non-nullable type {}",
+                    validity.unset_bits(),
+                    data_type
+                )));
+            }
+            Ok(column)
+        }
+        _ => Err(ErrorCode::TableSchemaMismatch(format!(
+            "ORC type {} does not match type {}",
+            column_type, data_type
+        ))),
+    }
+}
+
+/// Converts ORC seconds and nanoseconds into microseconds since the unix epoch.
+///
+/// Timestamps are read as wall clock time in UTC, regardless of the writer time zone.
+pub fn decode_timestamp(seconds: i64, encoded_nanos: u64) -> i64 {
+    let nanos = decode_nanos(encoded_nanos);
+    let mut seconds = seconds + ORC_TIMESTAMP_BASE_SECONDS;
+    // Writers truncate negative timestamps toward zero instead of flooring them.
+    if seconds < 0 && nanos > 999_999 {
+        seconds -= 1;
+    }
+    seconds * 1_000_000 + nanos / 1_000
+}
+
+/// The inverse of [`decode_timestamp`], returns ORC seconds and encoded nanoseconds.
+pub fn encode_timestamp(micros: i64) -> (i64, u64) {
+    let mut seconds = micros.div_euclid(1_000_000);
+    let nanos = micros.rem_euclid(1_000_000) * 1_000;
+    if seconds < 0 && nanos > 999_999 {
+        seconds += 1;
+    }
+
+    let encoded = if nanos == 0 {
+        0
+    } else if nanos % 100 != 0 {
+        (nanos as u64) << 3
+    } else {
+        let mut nanos = nanos / 100;
+        let mut zeros = 1;
+        while nanos % 10 == 0 && zeros < 7 {
+            nanos /= 10;
+            zeros += 1;
+        }
+        (nanos as u64) << 3 | zeros
+    };
+    (seconds - ORC_TIMESTAMP_BASE_SECONDS, encoded)
+}
@@ -0,0 +1,356 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::DecimalSize;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use serde::Deserialize;
+use serde::Serialize;
+
+use crate::compression::corrupted;
+use crate::proto;
+
+/// ORC defaults to the maximum precision when the writer leaves it out.
+const DEFAULT_DECIMAL_PRECISION: u32 = 38;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OrcTypeKind {
+    Boolean,
+    Byte,
+    Short,
+    Int,
+    Long,
+    Float,
+    Double,
+    String,
+    Binary,
+    Timestamp,
+    List,
+    Map,
+    Struct,
+    Union,
+    Decimal,
+    Date,
+    Varchar,
+    Char,
+    TimestampInstant,
+}
+
+/// One node of the flattened ORC type tree, its position in
+/// [`OrcSchema::types`] is the column id used by streams and statistics.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcType {
+    pub kind: OrcTypeKind,
+    pub subtypes: Vec<u32>,
+    pub field_names: Vec<String>,
+    pub precision: u32,
+    pub scale: u32,
+}
+
+impl OrcType {
+    fn new(kind: OrcTypeKind) -> Self {
+        Self {
+            kind,
+            subtypes: vec![],
+            field_names: vec![],
+            precision: 0,
+            scale: 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct OrcSchema {
+    pub types: Vec<OrcType>,
+}
+
+impl OrcSchema {
+    pub fn from_proto(types: &[proto::Type]) -> Result<Self> {
+        let types = types
+            .iter()
+            .map(|ty| {
+                use proto::r#type::Kind;
+                let kind = match ty.kind() {
+                    Kind::Boolean => OrcTypeKind::Boolean,
+                    Kind::Byte => OrcTypeKind::Byte,
+                    Kind::Short => OrcTypeKind::Short,
+                    Kind::Int => OrcTypeKind::Int,
+                    Kind::Long => OrcTypeKind::Long,
+                    Kind::Float => OrcTypeKind::Float,
+                    Kind::Double => OrcTypeKind::Double,
+                    Kind::String => OrcTypeKind::String,
+                    Kind::Binary => OrcTypeKind::Binary,
+                    Kind::Timestamp => OrcTypeKind::Timestamp,
+                    Kind::List => OrcTypeKind::List,
+                    Kind::Map => OrcTypeKind::Map,
+                    Kind::Struct => OrcTypeKind::Struct,
+                    Kind::Union => OrcTypeKind::Union,
+                    Kind::Decimal => OrcTypeKind::Decimal,
+                    Kind::Date => OrcTypeKind::Date,
+                    Kind::Varchar => OrcTypeKind::Varchar,
+                    Kind::Char => OrcTypeKind::Char,
+                    Kind::TimestampInstant => OrcTypeKind::TimestampInstant,
+                };
+                OrcType {
+                    kind,
+                    subtypes: ty.subtypes.clone(),
+                    field_names: ty.field_names.clone(),
+                    precision: ty.precision.unwrap_or(DEFAULT_DECIMAL_PRECISION),
+                    scale: ty.scale(),
+                }
+            })
+            .collect::<Vec<_>>();
+
+        let schema = Self { types };
+        schema.check()?;
+        Ok(schema)
+    }
+
+    fn check(&self) -> Result<()> {
+        if self.types.first().map(|ty| ty.kind) != Some(OrcTypeKind::Struct) {
+            return Err(corrupted("the root type must be a struct"));
+        }
+        for (id, ty) in self.types.iter().enumerate() {
+            // Types are stored in pre-order, children always follow their parent.
+            if ty
+                .subtypes
+                .iter()
+                .any(|c| *c as usize <= id || *c as usize >= self.types.len())
+            {
+                return Err(corrupted(format!("invalid children of column {id}")));
+            }
+            let expected = match ty.kind {
+                OrcTypeKind::List => Some(1),
+                OrcTypeKind::Map => Some(2),
+                OrcTypeKind::Struct => Some(ty.field_names.len()),
+                OrcTypeKind::Union => None,
+                _ => Some(0),
+            };
+            if expected.is_some_and(|n| n != ty.subtypes.len()) {
+                return Err(corrupted(format!("invalid children of column {id}")));
+            }
+        }
+        Ok(())
+    }
+
+    pub fn to_proto(&self) -> Vec<proto::Type> {
+        use proto::r#type::Kind;
+        self.types
+            .iter()
+            .map(|ty| {
+                let kind = match ty.kind {
+                    OrcTypeKind::Boolean => Kind::Boolean,
+                    OrcTypeKind::Byte => Kind::Byte,
+                    OrcTypeKind::Short => Kind::Short,
+                    OrcTypeKind::Int => Kind::Int,
+                    OrcTypeKind::Long => Kind::Long,
+                    OrcTypeKind::Float => Kind::Float,
+                    OrcTypeKind::Double => Kind::Double,
+                    OrcTypeKind::String => Kind::String,
+                    OrcTypeKind::Binary => Kind::Binary,
+                    OrcTypeKind::Timestamp => Kind::Timestamp,
+                    OrcTypeKind::List => Kind::List,
+                    OrcTypeKind::Map => Kind::Map,
+                    OrcTypeKind::Struct => Kind::Struct,
+                    OrcTypeKind::Union => Kind::Union,
+                    OrcTypeKind::Decimal => Kind::Decimal,
+                    OrcTypeKind::Date => Kind::Date,
+                    OrcTypeKind::Varchar => Kind::Varchar,
+                    OrcTypeKind::Char => Kind::Char,
+                    OrcTypeKind::TimestampInstant => Kind::TimestampInstant,
+                };
+                let is_decimal = ty.kind == OrcTypeKind::Decimal;
+                proto::Type {
+                    kind: Some(kind as i32),
+                    subtypes: ty.subtypes.clone(),
+                    field_names: ty.field_names.clone(),
+                    maximum_length: None,
+                    precision: is_decimal.then_some(ty.precision),
+                    scale: is_decimal.then_some(ty.scale),
+                    attributes: vec![],
+                }
+            })
+            .collect()
+    }
+
+    pub fn root(&self) -> &OrcType {
+        &self.types[0]
+    }
+
+    pub fn num_fields(&self) -> usize {
+        self.root().subtypes.len()
+    }
+
+    /// Column id of the top level field at `index`.
+    pub fn field_column_id(&self, index: usize) -> u32 {
+        self.root().subtypes[index]
+    }
+
+    /// Top level fields become nullable columns, the same as Parquet.
+    pub fn to_table_schema(&self) -> Result<TableSchema> {
+        let root = self.root();
+        let fields = root
+            .field_names
+            .iter()
+            .zip(root.subtypes.iter())
+            .map(|(name, id)| Ok(TableField::new(name, self.table_type(*id, true)?)))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(TableSchema::new(fields))
+    }
+
+    pub fn table_type(&self, column_id: u32, nullable: bool) -> Result<TableDataType> {
+        let ty = &self.types[column_id as usize];
+        let data_type = match ty.kind {
+            OrcTypeKind::Boolean => TableDataType::Boolean,
+            OrcTypeKind::Byte => TableDataType::Number(NumberDataType::Int8),
+            OrcTypeKind::Short => TableDataType::Number(NumberDataType::Int16),
+            OrcTypeKind::Int => TableDataType::Number(NumberDataType::Int32),
+            OrcTypeKind::Long => TableDataType::Number(NumberDataType::Int64),
+            OrcTypeKind::Float => TableDataType::Number(NumberDataType::Float32),
+            OrcTypeKind::Double => TableDataType::Number(NumberDataType::Float64),
+            OrcTypeKind::String | OrcTypeKind::Varchar | OrcTypeKind::Char => TableDataType::String,
+            OrcTypeKind::Binary => TableDataType::Binary,
+            OrcTypeKind::Timestamp | OrcTypeKind::TimestampInstant => TableDataType::Timestamp,
+            OrcTypeKind::Date => TableDataType::Date,
+            OrcTypeKind::Decimal => {
+                let size = DecimalSize {
+                    precision: u8::try_from(ty.precision).unwrap_or(u8::MAX),
+                    scale: u8::try_from(ty.scale).unwrap_or(u8::MAX),
+                };
+                TableDataType::Decimal(DecimalDataType::from_size(size)?)
+            }
+            OrcTypeKind::List => {
+                TableDataType::Array(Box::new(self.table_type(ty.subtypes[0], true)?))
+            }
+            OrcTypeKind::Map => TableDataType::Map(Box::new(TableDataType::Tuple {
+                fields_name: vec!["key".to_string(), "value".to_string()],
+                fields_type: vec![
+                    self.table_type(ty.subtypes[0], false)?,
+                    self.table_type(ty.subtypes[1], true)?,
+                ],
+            })),
+            OrcTypeKind::Struct if !ty.subtypes.is_empty() => TableDataType::Tuple {
+                fields_name: ty.field_names.clone(),
+                fields_type: ty
+                    .subtypes
+                    .iter()
+                    .map(|id| self.table_type(*id, true))
+                    .collect::<Result<Vec<_>>>()?,
+            },
+            OrcTypeKind::Struct | OrcTypeKind::Union => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "ORC column {column_id} of type {:?} is not supported",
+                    ty.kind
+                )));
+            }
+        };
+        Ok(if nullable {
+            data_type.wrap_nullable()
+        } else {
+            data_type
+        })
+    }
+
+    /// Builds the ORC schema used to unload rows of `schema`.
+    ///
+    /// Unsigned integers are widened to the next signed type, since ORC only
+    /// has signed ones, and variants are written as JSON strings.
+    pub fn from_table_schema(schema: &TableSchema) -> Result<Self> {
+        let mut types = vec![OrcType::new(OrcTypeKind::Struct)];
+        let mut subtypes = Vec::with_capacity(schema.num_fields());
+        for field in schema.fields() {
+            subtypes.push(Self::add_type(&mut types, field.data_type())?);
+        }
+        types[0].subtypes = subtypes;
+        types[0].field_names = schema.fields().iter().map(|f| f.name().clone()).collect();
+        Ok(Self { types })
+    }
+
+    fn add_type(types: &mut Vec<OrcType>, data_type: &TableDataType) -> Result<u32> {
+        let id = types.len();
+        let kind = match data_type.remove_nullable() {
+            TableDataType::Boolean => OrcTypeKind::Boolean,
+            TableDataType::Number(NumberDataType::Int8) => OrcTypeKind::Byte,
+            TableDataType::Number(NumberDataType::Int16 | NumberDataType::UInt8) => {
+                OrcTypeKind::Short
+            }
+            TableDataType::Number(NumberDataType::Int32 | NumberDataType::UInt16) => {
+                OrcTypeKind::Int
+            }
+            TableDataType::Number(NumberDataType::Int64 | NumberDataType::UInt32) => {
+                OrcTypeKind::Long
+            }
+            TableDataType::Number(NumberDataType::UInt64) => {
+                let mut ty = OrcType::new(OrcTypeKind::Decimal);
+                ty.precision = 20;
+                types.push(ty);
+                return Ok(id as u32);
+            }
+            TableDataType::Number(NumberDataType::Float32) => OrcTypeKind::Float,
+            TableDataType::Number(NumberDataType::Float64) => OrcTypeKind::Double,
+            TableDataType::String | TableDataType::Variant => OrcTypeKind::String,
+            TableDataType::Binary => OrcTypeKind::Binary,
+            TableDataType::Timestamp => OrcTypeKind::Timestamp,
+            TableDataType::Date => OrcTypeKind::Date,
+            TableDataType::Decimal(DecimalDataType::Decimal128(size)) => {
+                let mut ty = OrcType::new(OrcTypeKind::Decimal);
+                ty.precision = size.precision as u32;
+                ty.scale = size.scale as u32;
+                types.push(ty);
+                return Ok(id as u32);
+            }
+            TableDataType::Array(inner) => {
+                types.push(OrcType::new(OrcTypeKind::List));
+                let child = Self::add_type(types, &inner)?;
+                types[id].subtypes = vec![child];
+                return Ok(id as u32);
+            }
+            TableDataType::Map(inner) => {
+                let TableDataType::Tuple { fields_type, .. } = *inner else {
+                    unreachable!("map must be a tuple of key and value");
+                };
+                types.push(OrcType::new(OrcTypeKind::Map));
+                let key = Self::add_type(types, &fields_type[0])?;
+                let value = Self::add_type(types, &fields_type[1])?;
+                types[id].subtypes = vec![key, value];
+                return Ok(id as u32);
+            }
+            TableDataType::Tuple {
+                fields_name,
+                fields_type,
+            } => {
+                types.push(OrcType::new(OrcTypeKind::Struct));
+                let mut subtypes = Vec::with_capacity(fields_type.len());
+                for ty in fields_type.iter() {
+                    subtypes.push(Self::add_type(types, ty)?);
+                }
+                types[id].subtypes = subtypes;
+                types[id].field_names = fields_name;
+                return Ok(id as u32);
+            }
+            other => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Type {other} can not be written to ORC files"
+                )));
+            }
+        };
+        types.push(OrcType::new(kind));
+        Ok(id as u32)
+    }
+}
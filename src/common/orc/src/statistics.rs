@@ -0,0 +1,181 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::types::decimal::DecimalScalar;
+use databend_common_expression::types::DecimalSize;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::types::F32;
+use databend_common_expression::types::F64;
+use databend_common_expression::Scalar;
+
+use crate::proto;
+use crate::reader::OrcFileMeta;
+use crate::schema::OrcType;
+use crate::schema::OrcTypeKind;
+
+const MICROS_PER_DAY: i64 = 86_400_000_000;
+
+/// Min, max and null count of a column in a stripe, in databend scalars.
+#[derive(Clone, Debug, PartialEq)]
+pub struct OrcColumnStatistics {
+    pub min: Scalar,
+    pub max: Scalar,
+    pub null_count: u64,
+}
+
+impl OrcFileMeta {
+    /// Statistics of the top level field at `field_index` in the stripe, `None`
+    /// if the writer did not record usable ones.
+    pub fn stripe_field_statistics(
+        &self,
+        stripe_index: usize,
+        field_index: usize,
+    ) -> Option<OrcColumnStatistics> {
+        let column_id = self.schema.field_column_id(field_index);
+        let stats = self
+            .stripe_statistics
+            .get(stripe_index)?
+            .get(column_id as usize)?;
+        let num_rows = self.stripes.get(stripe_index)?.num_rows;
+        to_column_statistics(&self.schema.types[column_id as usize], stats, num_rows)
+    }
+}
+
+fn to_column_statistics(
+    ty: &OrcType,
+    stats: &proto::ColumnStatistics,
+    num_rows: u64,
+) -> Option<OrcColumnStatistics> {
+    let num_values = stats.number_of_values?;
+    // Without a non-null value there is no min and max to compare with.
+    if num_values == 0 {
+        return None;
+    }
+    let null_count = num_rows.saturating_sub(num_values);
+
+    let (min, max) = match ty.kind {
+        OrcTypeKind::Byte | OrcTypeKind::Short | OrcTypeKind::Int | OrcTypeKind::Long => {
+            let int = stats.int_statistics.as_ref()?;
+            let (min, max) = (int.minimum?, int.maximum?);
+            let to_scalar = |v: i64| {
+                Scalar::Number(match ty.kind {
+                    OrcTypeKind::Byte => NumberScalar::Int8(v as i8),
+                    OrcTypeKind::Short => NumberScalar::Int16(v as i16),
+                    OrcTypeKind::Int => NumberScalar::Int32(v as i32),
+                    _ => NumberScalar::Int64(v),
+                })
+            };
+            (to_scalar(min), to_scalar(max))
+        }
+        OrcTypeKind::Float | OrcTypeKind::Double => {
+            let double = stats.double_statistics.as_ref()?;
+            let (min, max) = (double.minimum?, double.maximum?);
+            if min.is_nan() || max.is_nan() {
+                return None;
+            }
+            let to_scalar = |v: f64| {
+                Scalar::Number(match ty.kind {
+                    OrcTypeKind::Float => NumberScalar::Float32(F32::from(v as f32)),
+                    _ => NumberScalar::Float64(F64::from(v)),
+                })
+            };
+            (to_scalar(min), to_scalar(max))
+        }
+        OrcTypeKind::String | OrcTypeKind::Varchar | OrcTypeKind::Char => {
+            let string = stats.string_statistics.as_ref()?;
+            // Long values are replaced by bounds, which are fine for pruning.
+            let min = string.minimum.as_ref().or(string.lower_bound.as_ref())?;
+            let max = string.maximum.as_ref().or(string.upper_bound.as_ref())?;
+            (Scalar::String(min.clone()), Scalar::String(max.clone()))
+        }
+        OrcTypeKind::Date => {
+            let date = stats.date_statistics.as_ref()?;
+            (Scalar::Date(date.minimum?), Scalar::Date(date.maximum?))
+        }
+        OrcTypeKind::Timestamp | OrcTypeKind::TimestampInstant => {
+            let ts = stats.timestamp_statistics.as_ref()?;
+            let min = ts.minimum.or(ts.minimum_utc)?;
+            let max = ts.maximum.or(ts.maximum_utc)?;
+            // Writers disagree on the time zone of the statistics, widen the range
+            // by a day so that pruning stays on the safe side.
+            (
+                Scalar::Timestamp(min.saturating_mul(1000).saturating_sub(MICROS_PER_DAY)),
+                Scalar::Timestamp(max.saturating_mul(1000).saturating_add(MICROS_PER_DAY)),
+            )
+        }
+        OrcTypeKind::Decimal => {
+            let decimal = stats.decimal_statistics.as_ref()?;
+            let min = parse_decimal(decimal.minimum.as_ref()?, ty.scale)?;
+            let max = parse_decimal(decimal.maximum.as_ref()?, ty.scale)?;
+            let size = DecimalSize {
+                precision: ty.precision as u8,
+                scale: ty.scale as u8,
+            };
+            (
+                Scalar::Decimal(DecimalScalar::Decimal128(min, size)),
+                Scalar::Decimal(DecimalScalar::Decimal128(max, size)),
+            )
+        }
+        OrcTypeKind::Boolean => {
+            let true_count = *stats.bucket_statistics.as_ref()?.count.first()?;
+            (
+                Scalar::Boolean(true_count == num_values),
+                Scalar::Boolean(true_count > 0),
+            )
+        }
+        OrcTypeKind::Binary
+        | OrcTypeKind::List
+        | OrcTypeKind::Map
+        | OrcTypeKind::Struct
+        | OrcTypeKind::Union => return None,
+    };
+
+    Some(OrcColumnStatistics {
+        min,
+        max,
+        null_count,
+    })
+}
+
+/// Parses a decimal statistic such as `-12.340` into an integer of `scale` digits.
+pub(crate) fn parse_decimal(value: &str, scale: u32) -> Option<i128> {
+    let (negative, digits) = match value.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, value),
+    };
+    let (int_part, frac_part) = digits.split_once('.').unwrap_or((digits, ""));
+    let frac_part = frac_part.trim_end_matches('0');
+    if frac_part.len() > scale as usize {
+        return None;
+    }
+    let mut result = 0i128;
+    for c in int_part.chars().chain(frac_part.chars()) {
+        result = result
+            .checked_mul(10)?
+            .checked_add(c.to_digit(10)? as i128)?;
+    }
+    result = result.checked_mul(10i128.checked_pow(scale - frac_part.len() as u32)?)?;
+    Some(if negative { -result } else { result })
+}
+
+pub(crate) fn format_decimal(value: i128, scale: u32) -> String {
+    if scale == 0 {
+        return value.to_string();
+    }
+    let digits = value.unsigned_abs().to_string();
+    let digits = format!("{:0>width$}", digits, width = scale as usize + 1);
+    let (int_part, frac_part) = digits.split_at(digits.len() - scale as usize);
+    let sign = if value < 0 { "-" } else { "" };
+    format!("{sign}{int_part}.{frac_part}")
+}
@@ -0,0 +1,525 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::decimal::DecimalColumn;
+use databend_common_expression::types::NumberColumn;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableSchema;
+use prost::Message;
+
+use crate::compression::Compression;
+use crate::compression::CompressionKind;
+use crate::compression::DEFAULT_COMPRESSION_BLOCK_SIZE;
+use crate::encoding::encode_bool_rle;
+use crate::encoding::encode_byte_rle;
+use crate::encoding::encode_int_rle_v1;
+use crate::encoding::write_varint_i128;
+use crate::proto;
+use crate::proto::column_encoding;
+use crate::proto::stream::Kind as StreamKind;
+use crate::reader::encode_timestamp;
+use crate::reader::ORC_MAGIC;
+use crate::schema::OrcSchema;
+use crate::schema::OrcTypeKind;
+use crate::statistics::format_decimal;
+
+/// Writes data blocks into an ORC file, one stripe per block.
+///
+/// Columns are written with the `DIRECT` encoding and RLE v1, which every
+/// reader since Hive 0.11 understands.
+pub struct OrcWriter {
+    schema: OrcSchema,
+    compression: Compression,
+    buf: Vec<u8>,
+    num_rows: u64,
+    stripes: Vec<proto::StripeInformation>,
+    stripe_statistics: Vec<proto::StripeStatistics>,
+    file_statistics: Vec<proto::ColumnStatistics>,
+}
+
+impl OrcWriter {
+    pub fn try_create(schema: &TableSchema, compression: CompressionKind) -> Result<Self> {
+        let schema = OrcSchema::from_table_schema(schema)?;
+        let file_statistics = vec![proto::ColumnStatistics::default(); schema.types.len()];
+        Ok(Self {
+            schema,
+            compression: Compression::new(compression, DEFAULT_COMPRESSION_BLOCK_SIZE),
+            buf: ORC_MAGIC.to_vec(),
+            num_rows: 0,
+            stripes: vec![],
+            stripe_statistics: vec![],
+            file_statistics,
+        })
+    }
+
+    pub fn write(&mut self, block: &DataBlock) -> Result<()> {
+        let num_rows = block.num_rows();
+        if num_rows == 0 {
+            return Ok(());
+        }
+
+        let mut stripe = StripeBuilder::new(self.schema.types.len());
+        stripe.statistics[0].number_of_values = Some(num_rows as u64);
+        for (index, entry) in block.columns().iter().enumerate() {
+            let column = entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows);
+            let column_id = self.schema.field_column_id(index);
+            self.write_column(&mut stripe, column_id, &column)?;
+        }
+
+        let offset = self.buf.len() as u64;
+        let mut streams = Vec::with_capacity(stripe.streams.len());
+        for (stream, data) in stripe.streams {
+            let data = self.compression.compress(&data)?;
+            streams.push(proto::Stream {
+                kind: stream.kind,
+                column: stream.column,
+                length: Some(data.len() as u64),
+            });
+            self.buf.extend_from_slice(&data);
+        }
+        let data_length = self.buf.len() as u64 - offset;
+
+        let footer = proto::StripeFooter {
+            streams,
+            columns: stripe.encodings,
+            writer_timezone: Some("UTC".to_string()),
+        };
+        let footer = self.compression.compress(&footer.encode_to_vec())?;
+        self.buf.extend_from_slice(&footer);
+
+        self.stripes.push(proto::StripeInformation {
+            offset: Some(offset),
+            index_length: Some(0),
+            data_length: Some(data_length),
+            footer_length: Some(footer.len() as u64),
+            number_of_rows: Some(num_rows as u64),
+        });
+        for (file_stats, stripe_stats) in self
+            .file_statistics
+            .iter_mut()
+            .zip(stripe.statistics.iter())
+        {
+            merge_statistics(file_stats, stripe_stats);
+        }
+        self.stripe_statistics.push(proto::StripeStatistics {
+            col_stats: stripe.statistics,
+        });
+        self.num_rows += num_rows as u64;
+        Ok(())
+    }
+
+    pub fn finish(mut self) -> Result<Vec<u8>> {
+        let content_length = self.buf.len() as u64;
+
+        let metadata = proto::Metadata {
+            stripe_stats: std::mem::take(&mut self.stripe_statistics),
+        };
+        let metadata = self.compression.compress(&metadata.encode_to_vec())?;
+        self.buf.extend_from_slice(&metadata);
+
+        let footer = proto::Footer {
+            header_length: Some(ORC_MAGIC.len() as u64),
+            content_length: Some(content_length),
+            stripes: std::mem::take(&mut self.stripes),
+            types: self.schema.to_proto(),
+            metadata: vec![],
+            number_of_rows: Some(self.num_rows),
+            statistics: std::mem::take(&mut self.file_statistics),
+            row_index_stride: Some(0),
+            writer: None,
+            software_version: None,
+        };
+        let footer = self.compression.compress(&footer.encode_to_vec())?;
+        self.buf.extend_from_slice(&footer);
+
+        let postscript = proto::PostScript {
+            footer_length: Some(footer.len() as u64),
+            compression: Some(proto::CompressionKind::from(self.compression.kind) as i32),
+            compression_block_size: Some(self.compression.block_size),
+            version: vec![0, 12],
+            metadata_length: Some(metadata.len() as u64),
+            writer_version: Some(1),
+            stripe_statistics_length: None,
+            magic: Some(String::from_utf8_lossy(ORC_MAGIC).to_string()),
+        };
+        let postscript = postscript.encode_to_vec();
+        self.buf.extend_from_slice(&postscript);
+        self.buf.push(postscript.len() as u8);
+        Ok(self.buf)
+    }
+
+    fn write_column(
+        &self,
+        stripe: &mut StripeBuilder,
+        column_id: u32,
+        column: &Column,
+    ) -> Result<()> {
+        let (values, validity) = match column {
+            Column::Nullable(c) => (&c.column, Some(&c.validity)),
+            c => (c, None),
+        };
+
+        // Only present values are stored, nulls are marked in the PRESENT stream.
+        let values = match validity {
+            Some(validity) if validity.unset_bits() > 0 => {
+                let mut present = vec![];
+                encode_bool_rle(validity.iter(), &mut present);
+                stripe.add_stream(column_id, StreamKind::Present, present);
+                stripe.statistics[column_id as usize].has_null = Some(true);
+                values.filter(validity)
+            }
+            _ => values.clone(),
+        };
+        let stats = &mut stripe.statistics[column_id as usize];
+        stats.number_of_values = Some(values.len() as u64);
+        if stats.has_null.is_none() {
+            stats.has_null = Some(false);
+        }
+
+        let ty = &self.schema.types[column_id as usize];
+        match (ty.kind, &values) {
+            (OrcTypeKind::Boolean, Column::Boolean(bitmap)) => {
+                let mut data = vec![];
+                encode_bool_rle(bitmap.iter(), &mut data);
+                stripe.statistics[column_id as usize].bucket_statistics =
+                    Some(proto::BucketStatistics {
+                        count: vec![(bitmap.len() - bitmap.unset_bits()) as u64],
+                    });
+                stripe.add_stream(column_id, StreamKind::Data, data);
+            }
+            (OrcTypeKind::Byte, Column::Number(NumberColumn::Int8(values))) => {
+                let mut data = vec![];
+                let bytes = values.iter().map(|v| *v as u8).collect::<Vec<_>>();
+                encode_byte_rle(&bytes, &mut data);
+                let ints = values.iter().map(|v| *v as i64).collect::<Vec<_>>();
+                stripe.statistics[column_id as usize].int_statistics = int_statistics(&ints);
+                stripe.add_stream(column_id, StreamKind::Data, data);
+            }
+            (OrcTypeKind::Short | OrcTypeKind::Int | OrcTypeKind::Long, column) => {
+                let ints = to_i64s(column)?;
+                stripe.statistics[column_id as usize].int_statistics = int_statistics(&ints);
+                let mut data = vec![];
+                encode_int_rle_v1(&ints, true, &mut data);
+                stripe.add_stream(column_id, StreamKind::Data, data);
+            }
+            (OrcTypeKind::Date, Column::Date(days)) => {
+                let ints = days.iter().map(|v| *v as i64).collect::<Vec<_>>();
+                let mut data = vec![];
+                encode_int_rle_v1(&ints, true, &mut data);
+                stripe.statistics[column_id as usize].date_statistics =
+                    Some(proto::DateStatistics {
+                        minimum: days.iter().min().copied(),
+                        maximum: days.iter().max().copied(),
+                    });
+                stripe.add_stream(column_id, StreamKind::Data, data);
+            }
+            (OrcTypeKind::Float, Column::Number(NumberColumn::Float32(values))) => {
+                let doubles = values.iter().map(|v| v.0 as f64).collect::<Vec<_>>();
+                let data = values.iter().flat_map(|v| v.0.to_le_bytes()).collect();
+                stripe.statistics[column_id as usize].double_statistics =
+                    double_statistics(&doubles);
+                stripe.add_stream(column_id, StreamKind::Data, data);
+            }
+            (OrcTypeKind::Double, Column::Number(NumberColumn::Float64(values))) => {
+                let doubles = values.iter().map(|v| v.0).collect::<Vec<_>>();
+                let data = doubles.iter().flat_map(|v| v.to_le_bytes()).collect();
+                stripe.statistics[column_id as usize].double_statistics =
+                    double_statistics(&doubles);
+                stripe.add_stream(column_id, StreamKind::Data, data);
+            }
+            (OrcTypeKind::String, Column::String(strings)) => {
+                let strings = strings.iter().collect::<Vec<_>>();
+                stripe.statistics[column_id as usize].string_statistics =
+                    string_statistics(&strings);
+                stripe.add_binary(column_id, strings.iter().map(|s| s.as_bytes()));
+            }
+            (OrcTypeKind::String, Column::Variant(values)) => {
+                let strings = values.iter().map(jsonb::to_string).collect::<Vec<_>>();
+                let refs = strings.iter().map(|s| s.as_str()).collect::<Vec<_>>();
+                stripe.statistics[column_id as usize].string_statistics = string_statistics(&refs);
+                stripe.add_binary(column_id, refs.iter().map(|s| s.as_bytes()));
+            }
+            (OrcTypeKind::Binary, Column::Binary(values)) => {
+                stripe.add_binary(column_id, values.iter());
+            }
+            (OrcTypeKind::Timestamp, Column::Timestamp(micros)) => {
+                let (seconds, nanos): (Vec<_>, Vec<_>) = micros
+                    .iter()
+                    .map(|v| {
+                        let (seconds, nanos) = encode_timestamp(*v);
+                        (seconds, nanos as i64)
+                    })
+                    .unzip();
+                let mut data = vec![];
+                encode_int_rle_v1(&seconds, true, &mut data);
+                let mut secondary = vec![];
+                encode_int_rle_v1(&nanos, false, &mut secondary);
+                let min = micros.iter().min().map(|v| v.div_euclid(1000));
+                let max = micros.iter().max().map(|v| v.div_euclid(1000));
+                stripe.statistics[column_id as usize].timestamp_statistics =
+                    Some(proto::TimestampStatistics {
+                        minimum: min,
+                        maximum: max,
+                        minimum_utc: min,
+                        maximum_utc: max,
+                        ..Default::default()
+                    });
+                stripe.add_stream(column_id, StreamKind::Data, data);
+                stripe.add_stream(column_id, StreamKind::Secondary, secondary);
+            }
+            (OrcTypeKind::Decimal, column) => {
+                let decimals = match column {
+                    Column::Decimal(DecimalColumn::Decimal128(values, _)) => values.to_vec(),
+                    Column::Number(NumberColumn::UInt64(values)) => {
+                        values.iter().map(|v| *v as i128).collect()
+                    }
+                    other => return Err(type_mismatch(ty.kind, other)),
+                };
+                let mut data = vec![];
+                for v in decimals.iter() {
+                    write_varint_i128(&mut data, *v);
+                }
+                let mut secondary = vec![];
+                encode_int_rle_v1(&vec![ty.scale as i64; decimals.len()], true, &mut secondary);
+                stripe.statistics[column_id as usize].decimal_statistics =
+                    Some(proto::DecimalStatistics {
+                        minimum: decimals.iter().min().map(|v| format_decimal(*v, ty.scale)),
+                        maximum: decimals.iter().max().map(|v| format_decimal(*v, ty.scale)),
+                        sum: None,
+                    });
+                stripe.add_stream(column_id, StreamKind::Data, data);
+                stripe.add_stream(column_id, StreamKind::Secondary, secondary);
+            }
+            (OrcTypeKind::List, Column::Array(array)) | (OrcTypeKind::Map, Column::Map(array)) => {
+                let offsets = &array.offsets;
+                let lengths = offsets
+                    .windows(2)
+                    .map(|w| (w[1] - w[0]) as i64)
+                    .collect::<Vec<_>>();
+                let mut data = vec![];
+                encode_int_rle_v1(&lengths, false, &mut data);
+                stripe.add_stream(column_id, StreamKind::Length, data);
+
+                let start = *offsets.first().unwrap_or(&0) as usize;
+                let end = *offsets.last().unwrap_or(&0) as usize;
+                let children = array.values.slice(start..end);
+                match (ty.kind, children) {
+                    (OrcTypeKind::Map, Column::Tuple(kv)) => {
+                        self.write_column(stripe, ty.subtypes[0], &kv[0])?;
+                        self.write_column(stripe, ty.subtypes[1], &kv[1])?;
+                    }
+                    (_, children) => self.write_column(stripe, ty.subtypes[0], &children)?,
+                }
+            }
+            (OrcTypeKind::Struct, Column::Tuple(fields)) => {
+                for (child, field) in ty.subtypes.iter().zip(fields.iter()) {
+                    self.write_column(stripe, *child, field)?;
+                }
+            }
+            (kind, other) => return Err(type_mismatch(kind, other)),
+        }
+        Ok(())
+    }
+}
+
+struct StripeBuilder {
+    streams: Vec<(proto::Stream, Vec<u8>)>,
+    encodings: Vec<proto::ColumnEncoding>,
+    statistics: Vec<proto::ColumnStatistics>,
+}
+
+impl StripeBuilder {
+    fn new(num_columns: usize) -> Self {
+        let encoding = proto::ColumnEncoding {
+            kind: Some(column_encoding::Kind::Direct as i32),
+            dictionary_size: None,
+            bloom_encoding: None,
+        };
+        Self {
+            streams: vec![],
+            encodings: vec![encoding; num_columns],
+            statistics: vec![proto::ColumnStatistics::default(); num_columns],
+        }
+    }
+
+    fn add_stream(&mut self, column_id: u32, kind: StreamKind, data: Vec<u8>) {
+        let stream = proto::Stream {
+            kind: Some(kind as i32),
+            column: Some(column_id),
+            length: None,
+        };
+        self.streams.push((stream, data));
+    }
+
+    fn add_binary<'a>(&mut self, column_id: u32, values: impl Iterator<Item = &'a [u8]>) {
+        let mut data = vec![];
+        let mut lengths = vec![];
+        for value in values {
+            data.extend_from_slice(value);
+            lengths.push(value.len() as i64);
+        }
+        let mut length = vec![];
+        encode_int_rle_v1(&lengths, false, &mut length);
+        self.add_stream(column_id, StreamKind::Data, data);
+        self.add_stream(column_id, StreamKind::Length, length);
+    }
+}
+
+fn type_mismatch(kind: OrcTypeKind, column: &Column) -> ErrorCode {
+    ErrorCode::Internal(format!(
+        "can not write column of type {} as ORC {:?}",
+        column.data_type(),
+        kind
+    ))
+}
+
+fn to_i64s(column: &Column) -> Result<Vec<i64>> {
+    let ints = match column {
+        Column::Number(NumberColumn::Int8(v)) => v.iter().map(|v| *v as i64).collect(),
+        Column::Number(NumberColumn::Int16(v)) => v.iter().map(|v| *v as i64).collect(),
+        Column::Number(NumberColumn::Int32(v)) => v.iter().map(|v| *v as i64).collect(),
+        Column::Number(NumberColumn::Int64(v)) => v.to_vec(),
+        Column::Number(NumberColumn::UInt8(v)) => v.iter().map(|v| *v as i64).collect(),
+        Column::Number(NumberColumn::UInt16(v)) => v.iter().map(|v| *v as i64).collect(),
+        Column::Number(NumberColumn::UInt32(v)) => v.iter().map(|v| *v as i64).collect(),
+        other => {
+            return Err(ErrorCode::Internal(format!(
+                "can not write column of type {} as ORC integers",
+                other.data_type()
+            )));
+        }
+    };
+    Ok(ints)
+}
+
+fn int_statistics(values: &[i64]) -> Option<proto::IntegerStatistics> {
+    Some(proto::IntegerStatistics {
+        minimum: Some(*values.iter().min()?),
+        maximum: Some(*values.iter().max()?),
+        sum: None,
+    })
+}
+
+fn double_statistics(values: &[f64]) -> Option<proto::DoubleStatistics> {
+    let mut values = values.iter().filter(|v| !v.is_nan()).copied();
+    let first = values.next()?;
+    let (min, max) = values.fold((first, first), |(min, max), v| (min.min(v), max.max(v)));
+    Some(proto::DoubleStatistics {
+        minimum: Some(min),
+        maximum: Some(max),
+        sum: None,
+    })
+}
+
+fn string_statistics(values: &[&str]) -> Option<proto::StringStatistics> {
+    Some(proto::StringStatistics {
+        minimum: Some(values.iter().min()?.to_string()),
+        maximum: Some(values.iter().max()?.to_string()),
+        ..Default::default()
+    })
+}
+
+/// Folds the statistics of a stripe into those of the file.
+fn merge_statistics(file: &mut proto::ColumnStatistics, stripe: &proto::ColumnStatistics) {
+    let first = file.number_of_values.is_none();
+    file.number_of_values = Some(file.number_of_values() + stripe.number_of_values());
+    file.has_null = Some(file.has_null() || stripe.has_null());
+    if first {
+        file.int_statistics = stripe.int_statistics.clone();
+        file.double_statistics = stripe.double_statistics.clone();
+        file.string_statistics = stripe.string_statistics.clone();
+        file.bucket_statistics = stripe.bucket_statistics.clone();
+        file.date_statistics = stripe.date_statistics.clone();
+        file.timestamp_statistics = stripe.timestamp_statistics.clone();
+        file.decimal_statistics = stripe.decimal_statistics.clone();
+        return;
+    }
+
+    fn merge<T: Clone>(file: &mut Option<T>, stripe: &Option<T>, f: impl FnOnce(&mut T, &T)) {
+        match (file.as_mut(), stripe) {
+            (Some(file), Some(stripe)) => f(file, stripe),
+            (None, Some(stripe)) => *file = Some(stripe.clone()),
+            _ => {}
+        }
+    }
+    fn min<T: PartialOrd + Clone>(a: &mut Option<T>, b: &Option<T>) {
+        if let Some(b) = b {
+            if a.as_ref().map_or(true, |a| b < a) {
+                *a = Some(b.clone());
+            }
+        }
+    }
+    fn max<T: PartialOrd + Clone>(a: &mut Option<T>, b: &Option<T>) {
+        if let Some(b) = b {
+            if a.as_ref().map_or(true, |a| b > a) {
+                *a = Some(b.clone());
+            }
+        }
+    }
+
+    merge(&mut file.int_statistics, &stripe.int_statistics, |a, b| {
+        min(&mut a.minimum, &b.minimum);
+        max(&mut a.maximum, &b.maximum);
+    });
+    merge(
+        &mut file.double_statistics,
+        &stripe.double_statistics,
+        |a, b| {
+            min(&mut a.minimum, &b.minimum);
+            max(&mut a.maximum, &b.maximum);
+        },
+    );
+    merge(
+        &mut file.string_statistics,
+        &stripe.string_statistics,
+        |a, b| {
+            min(&mut a.minimum, &b.minimum);
+            max(&mut a.maximum, &b.maximum);
+        },
+    );
+    merge(
+        &mut file.date_statistics,
+        &stripe.date_statistics,
+        |a, b| {
+            min(&mut a.minimum, &b.minimum);
+            max(&mut a.maximum, &b.maximum);
+        },
+    );
+    merge(
+        &mut file.timestamp_statistics,
+        &stripe.timestamp_statistics,
+        |a, b| {
+            min(&mut a.minimum, &b.minimum);
+            max(&mut a.maximum, &b.maximum);
+            min(&mut a.minimum_utc, &b.minimum_utc);
+            max(&mut a.maximum_utc, &b.maximum_utc);
+        },
+    );
+    merge(
+        &mut file.bucket_statistics,
+        &stripe.bucket_statistics,
+        |a, b| {
+            for (a, b) in a.count.iter_mut().zip(b.count.iter()) {
+                *a += *b;
+            }
+        },
+    );
+    // Decimal bounds are strings, comparing them needs the scale, leave them out.
+    file.decimal_statistics = None;
+}
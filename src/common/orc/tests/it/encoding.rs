@@ -0,0 +1,107 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_orc::decode_int_rle;
+use databend_common_orc::decode_timestamp;
+use databend_common_orc::encode_int_rle_v1;
+use databend_common_orc::encode_timestamp;
+use databend_common_orc::RleVersion;
+
+// Examples from https://orc.apache.org/specification/ORCv1/
+#[test]
+fn test_int_rle_v2_spec_examples() -> Result<()> {
+    let short_repeat = [0x0a, 0x27, 0x10];
+    assert_eq!(
+        decode_int_rle(&short_repeat, 5, false, RleVersion::V2)?,
+        vec![10000; 5]
+    );
+
+    let direct = [0x5e, 0x03, 0x5c, 0xa1, 0xab, 0x1e, 0xde, 0xad, 0xbe, 0xef];
+    assert_eq!(decode_int_rle(&direct, 4, false, RleVersion::V2)?, vec![
+        23713, 43806, 57005, 48879
+    ]);
+
+    let patched_base = [
+        0x8e, 0x13, 0x2b, 0x21, 0x07, 0xd0, 0x1e, 0x00, 0x14, 0x70, 0x28, 0x32, 0x3c, 0x46, 0x50,
+        0x5a, 0x64, 0x6e, 0x78, 0x82, 0x8c, 0x96, 0xa0, 0xaa, 0xb4, 0xbe, 0xfc, 0xe8,
+    ];
+    assert_eq!(
+        decode_int_rle(&patched_base, 20, false, RleVersion::V2)?,
+        vec![
+            2030, 2000, 2020, 1000000, 2040, 2050, 2060, 2070, 2080, 2090, 2100, 2110, 2120, 2130,
+            2140, 2150, 2160, 2170, 2180, 2190
+        ]
+    );
+
+    let delta = [0xc6, 0x09, 0x02, 0x02, 0x22, 0x42, 0x42, 0x46];
+    assert_eq!(decode_int_rle(&delta, 10, false, RleVersion::V2)?, vec![
+        2, 3, 5, 7, 11, 13, 17, 19, 23, 29
+    ]);
+    Ok(())
+}
+
+#[test]
+fn test_int_rle_v2_patched_base_64_bit_patch() -> Result<()> {
+    // Two 8-bit values 5 and 7 over a zero base, the first patched by a 64-bit patch of 1.
+    let patched_base = [
+        0x8e, 0x01, 0x1f, 0x01, 0x00, 0x05, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x01,
+    ];
+    assert_eq!(
+        decode_int_rle(&patched_base, 2, false, RleVersion::V2)?,
+        vec![261, 7]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_int_rle_v1() -> Result<()> {
+    let run = [0x61, 0x00, 0x07];
+    assert_eq!(decode_int_rle(&run, 100, false, RleVersion::V1)?, vec![
+        7;
+        100
+    ]);
+
+    let literals = [0xfb, 0x02, 0x03, 0x06, 0x07, 0x0b];
+    assert_eq!(decode_int_rle(&literals, 5, false, RleVersion::V1)?, vec![
+        2, 3, 6, 7, 11
+    ]);
+
+    let values = (0..300)
+        .map(|i| if i < 150 { i * 3 - 100 } else { i * i * 7919 })
+        .chain([i64::MIN, i64::MAX, 0, 0, 0, -1])
+        .collect::<Vec<_>>();
+    let mut encoded = vec![];
+    encode_int_rle_v1(&values, true, &mut encoded);
+    assert_eq!(
+        decode_int_rle(&encoded, values.len(), true, RleVersion::V1)?,
+        values
+    );
+    Ok(())
+}
+
+#[test]
+fn test_timestamp_encoding() {
+    for micros in [
+        0,
+        1,
+        1_420_070_400_000_000,
+        1_700_000_000_123_456,
+        -1_500_000,
+        -86_400_000_001,
+    ] {
+        let (seconds, nanos) = encode_timestamp(micros);
+        assert_eq!(decode_timestamp(seconds, nanos), micros, "{micros}");
+    }
+}
@@ -0,0 +1,161 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::array::ArrayColumn;
+use databend_common_expression::types::decimal::DecimalColumn;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::DateType;
+use databend_common_expression::types::DecimalDataType;
+use databend_common_expression::types::DecimalSize;
+use databend_common_expression::types::Float64Type;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_orc::parse_orc_tail;
+use databend_common_orc::CompressionKind;
+use databend_common_orc::OrcColumnStatistics;
+use databend_common_orc::OrcStripeReader;
+use databend_common_orc::OrcWriter;
+
+fn test_schema() -> TableSchema {
+    let decimal = DecimalDataType::Decimal128(DecimalSize {
+        precision: 10,
+        scale: 2,
+    });
+    TableSchema::new(vec![
+        TableField::new(
+            "id",
+            TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::Int32))),
+        ),
+        TableField::new("name", TableDataType::String),
+        TableField::new("score", TableDataType::Number(NumberDataType::Float64)),
+        TableField::new("ts", TableDataType::Timestamp),
+        TableField::new("day", TableDataType::Date),
+        TableField::new("flag", TableDataType::Boolean),
+        TableField::new("amount", TableDataType::Decimal(decimal)),
+        TableField::new(
+            "tags",
+            TableDataType::Array(Box::new(TableDataType::Number(NumberDataType::Int64))),
+        ),
+        TableField::new("point", TableDataType::Tuple {
+            fields_name: vec!["x".to_string(), "label".to_string()],
+            fields_type: vec![
+                TableDataType::Number(NumberDataType::Int32),
+                TableDataType::String,
+            ],
+        }),
+    ])
+}
+
+fn test_block(offset: i32) -> DataBlock {
+    let size = DecimalSize {
+        precision: 10,
+        scale: 2,
+    };
+    DataBlock::new_from_columns(vec![
+        Int32Type::from_opt_data(vec![Some(offset + 1), None, Some(offset + 3)]),
+        StringType::from_data(vec!["alice", "", "bob"]),
+        Float64Type::from_data(vec![1.5f64, -2.25, 1e10]),
+        TimestampType::from_data(vec![0, -1_500_000, 1_700_000_000_123_456]),
+        DateType::from_data(vec![19000, -1, 0]),
+        BooleanType::from_data(vec![true, false, true]),
+        Column::Decimal(DecimalColumn::Decimal128(
+            vec![12345i128, -1, 0].into(),
+            size,
+        )),
+        Column::Array(Box::new(ArrayColumn {
+            values: Int64Type::from_data(vec![1i64, 2, 3]),
+            offsets: vec![0u64, 2, 2, 3].into(),
+        })),
+        Column::Tuple(vec![
+            Int32Type::from_data(vec![7, 8, 9]),
+            StringType::from_data(vec!["a", "b", "c"]),
+        ]),
+    ])
+}
+
+#[test]
+fn test_write_and_read() -> Result<()> {
+    for compression in [
+        CompressionKind::None,
+        CompressionKind::Zlib,
+        CompressionKind::Snappy,
+        CompressionKind::Zstd,
+    ] {
+        let schema = test_schema();
+        let blocks = vec![test_block(0), test_block(100)];
+        let mut writer = OrcWriter::try_create(&schema, compression)?;
+        for block in blocks.iter() {
+            writer.write(block)?;
+        }
+        let data = writer.finish()?;
+
+        let meta = parse_orc_tail(&data)?;
+        assert_eq!(meta.num_rows, 6);
+        assert_eq!(meta.stripes.len(), 2);
+        assert_eq!(
+            meta.schema.to_table_schema()?.num_fields(),
+            schema.num_fields()
+        );
+
+        for (index, (stripe, block)) in meta.stripes.iter().zip(blocks.iter()).enumerate() {
+            let start = stripe.offset as usize;
+            let bytes = &data[start..start + stripe.length() as usize];
+            let reader =
+                OrcStripeReader::try_create(meta.compression, &meta.schema, stripe, bytes)?;
+            assert_eq!(reader.num_rows(), 3);
+            for field in 0..schema.num_fields() {
+                let actual = reader.read_field(field)?;
+                let expected = block.get_by_offset(field).value.as_column().unwrap();
+                for row in 0..3 {
+                    assert_eq!(
+                        actual.index(row).unwrap().to_string(),
+                        expected.index(row).unwrap().to_string(),
+                        "{compression:?} stripe {index} field {field} row {row}"
+                    );
+                }
+            }
+
+            let offset = index as i32 * 100;
+            assert_eq!(
+                meta.stripe_field_statistics(index, 0),
+                Some(OrcColumnStatistics {
+                    min: Scalar::Number(NumberScalar::Int32(offset + 1)),
+                    max: Scalar::Number(NumberScalar::Int32(offset + 3)),
+                    null_count: 1,
+                })
+            );
+            assert_eq!(
+                meta.stripe_field_statistics(index, 1),
+                Some(OrcColumnStatistics {
+                    min: Scalar::String("".to_string()),
+                    max: Scalar::String("bob".to_string()),
+                    null_count: 0,
+                })
+            );
+        }
+    }
+    Ok(())
+}
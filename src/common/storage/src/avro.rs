@@ -0,0 +1,522 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for Avro object container files.
+//!
+//! A container file is a header (magic, metadata and a sync marker) followed by
+//! data blocks, each of them holds a number of records encoded with the writer
+//! schema embedded in the header, and is optionally compressed with the codec
+//! named in the header.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::decimal::DecimalDataType;
+use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use opendal::Operator;
+use serde_json::Value as JsonValue;
+
+pub const AVRO_MAGIC: &[u8] = b"Obj\x01";
+pub const AVRO_SYNC_MARKER_SIZE: usize = 16;
+
+const AVRO_SCHEMA_KEY: &str = "avro.schema";
+const AVRO_CODEC_KEY: &str = "avro.codec";
+
+/// The Avro schema of a file, named types are resolved in place.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum AvroSchema {
+    Null,
+    Boolean,
+    Int,
+    Long,
+    Float,
+    Double,
+    Bytes,
+    String,
+    Fixed(usize),
+    Enum(Vec<String>),
+    Decimal { precision: usize, scale: usize },
+    Date,
+    TimeMillis,
+    TimeMicros,
+    TimestampMillis,
+    TimestampMicros,
+    Uuid,
+    Record(Vec<(String, AvroSchema)>),
+    Array(Box<AvroSchema>),
+    Map(Box<AvroSchema>),
+    Union(Vec<AvroSchema>),
+}
+
+impl AvroSchema {
+    pub fn parse_str(schema: &str) -> Result<AvroSchema> {
+        let json: JsonValue = serde_json::from_str(schema)
+            .map_err(|e| ErrorCode::BadBytes(format!("Invalid Avro schema '{}': {}", schema, e)))?;
+        AvroSchemaParser::default().parse(&json, None)
+    }
+
+    /// The table schema of a file with this writer schema, the top-level record
+    /// fields become the columns.
+    pub fn to_table_schema(&self) -> Result<TableSchema> {
+        match self {
+            AvroSchema::Record(fields) => {
+                let fields = fields
+                    .iter()
+                    .map(|(name, schema)| Ok(TableField::new(name, schema.to_table_type()?)))
+                    .collect::<Result<Vec<_>>>()?;
+                Ok(TableSchema::new(fields))
+            }
+            other => Err(ErrorCode::BadBytes(format!(
+                "The schema of an Avro file must be a record, but got {:?}",
+                other
+            ))),
+        }
+    }
+
+    pub fn to_table_type(&self) -> Result<TableDataType> {
+        let ty = match self {
+            AvroSchema::Null => TableDataType::Null,
+            AvroSchema::Boolean => TableDataType::Boolean,
+            AvroSchema::Int | AvroSchema::TimeMillis => {
+                TableDataType::Number(NumberDataType::Int32)
+            }
+            AvroSchema::Long | AvroSchema::TimeMicros => {
+                TableDataType::Number(NumberDataType::Int64)
+            }
+            AvroSchema::Float => TableDataType::Number(NumberDataType::Float32),
+            AvroSchema::Double => TableDataType::Number(NumberDataType::Float64),
+            AvroSchema::Bytes | AvroSchema::Fixed(_) => TableDataType::Binary,
+            AvroSchema::String | AvroSchema::Enum(_) | AvroSchema::Uuid => TableDataType::String,
+            AvroSchema::Decimal { precision, scale } => {
+                let size = DecimalSize {
+                    precision: u8::try_from(*precision).unwrap_or(u8::MAX),
+                    scale: u8::try_from(*scale).unwrap_or(u8::MAX),
+                };
+                TableDataType::Decimal(DecimalDataType::from_size(size)?)
+            }
+            AvroSchema::Date => TableDataType::Date,
+            AvroSchema::TimestampMillis | AvroSchema::TimestampMicros => TableDataType::Timestamp,
+            // A tuple can not be empty.
+            AvroSchema::Record(fields) if fields.is_empty() => TableDataType::Variant,
+            AvroSchema::Record(fields) => TableDataType::Tuple {
+                fields_name: fields.iter().map(|(name, _)| name.clone()).collect(),
+                fields_type: fields
+                    .iter()
+                    .map(|(_, schema)| schema.to_table_type())
+                    .collect::<Result<Vec<_>>>()?,
+            },
+            AvroSchema::Array(items) => TableDataType::Array(Box::new(items.to_table_type()?)),
+            AvroSchema::Map(values) => TableDataType::Map(Box::new(TableDataType::Tuple {
+                fields_name: vec!["key".to_string(), "value".to_string()],
+                fields_type: vec![TableDataType::String, values.to_table_type()?],
+            })),
+            AvroSchema::Union(branches) => {
+                let has_null = branches.iter().any(|b| *b == AvroSchema::Null);
+                let non_null = branches
+                    .iter()
+                    .filter(|b| **b != AvroSchema::Null)
+                    .collect::<Vec<_>>();
+                // The common `["null", T]` is a nullable T, other unions are variants.
+                let ty = match non_null.as_slice() {
+                    [] => return Ok(TableDataType::Null),
+                    [branch] => branch.to_table_type()?,
+                    _ => TableDataType::Variant,
+                };
+                if has_null { ty.wrap_nullable() } else { ty }
+            }
+        };
+        Ok(ty)
+    }
+}
+
+#[derive(Default)]
+struct AvroSchemaParser {
+    // Named types (records, enums and fixed) by their full names.
+    names: HashMap<String, AvroSchema>,
+    // Records that are being parsed, to reject recursive types.
+    parsing: HashSet<String>,
+}
+
+impl AvroSchemaParser {
+    fn parse(&mut self, json: &JsonValue, namespace: Option<&str>) -> Result<AvroSchema> {
+        match json {
+            JsonValue::String(name) => self.parse_name(name, namespace),
+            JsonValue::Array(branches) => Ok(AvroSchema::Union(
+                branches
+                    .iter()
+                    .map(|b| self.parse(b, namespace))
+                    .collect::<Result<Vec<_>>>()?,
+            )),
+            JsonValue::Object(obj) => {
+                let typ = obj.get("type").ok_or_else(|| {
+                    ErrorCode::BadBytes(format!("Avro schema {json} has no type"))
+                })?;
+                let typ = match typ {
+                    JsonValue::String(typ) => typ.as_str(),
+                    // e.g. `{"type": {"type": "array", ...}}`
+                    other => return self.parse(other, namespace),
+                };
+
+                if let Some(JsonValue::String(logical_type)) = obj.get("logicalType") {
+                    if let Some(schema) = Self::parse_logical(logical_type, typ, obj) {
+                        return Ok(schema);
+                    }
+                }
+
+                match typ {
+                    "record" | "error" => {
+                        let full_name = Self::full_name(obj, namespace)?;
+                        if !self.parsing.insert(full_name.clone()) {
+                            return Err(ErrorCode::BadBytes(format!(
+                                "Recursive Avro type {full_name} is not supported"
+                            )));
+                        }
+                        let namespace = full_name.rsplit_once('.').map(|(ns, _)| ns.to_string());
+                        let fields =
+                            obj.get("fields")
+                                .and_then(|f| f.as_array())
+                                .ok_or_else(|| {
+                                    ErrorCode::BadBytes(format!(
+                                        "Avro record {full_name} has no fields"
+                                    ))
+                                })?;
+                        let mut parsed = Vec::with_capacity(fields.len());
+                        for field in fields {
+                            let name =
+                                field.get("name").and_then(|n| n.as_str()).ok_or_else(|| {
+                                    ErrorCode::BadBytes(format!(
+                                        "Avro record {full_name} has a field without name"
+                                    ))
+                                })?;
+                            let typ = field.get("type").ok_or_else(|| {
+                                ErrorCode::BadBytes(format!(
+                                    "Field {name} of Avro record {full_name} has no type"
+                                ))
+                            })?;
+                            parsed.push((name.to_string(), self.parse(typ, namespace.as_deref())?));
+                        }
+                        self.parsing.remove(&full_name);
+                        Ok(self.register(full_name, AvroSchema::Record(parsed)))
+                    }
+                    "enum" => {
+                        let full_name = Self::full_name(obj, namespace)?;
+                        let symbols = obj
+                            .get("symbols")
+                            .and_then(|s| s.as_array())
+                            .map(|symbols| {
+                                symbols
+                                    .iter()
+                                    .filter_map(|s| s.as_str().map(|s| s.to_string()))
+                                    .collect()
+                            })
+                            .unwrap_or_default();
+                        Ok(self.register(full_name, AvroSchema::Enum(symbols)))
+                    }
+                    "fixed" => {
+                        let full_name = Self::full_name(obj, namespace)?;
+                        let size = obj.get("size").and_then(|s| s.as_u64()).ok_or_else(|| {
+                            ErrorCode::BadBytes(format!("Avro fixed {full_name} has no size"))
+                        })?;
+                        Ok(self.register(full_name, AvroSchema::Fixed(size as usize)))
+                    }
+                    "array" => {
+                        let items = obj.get("items").ok_or_else(|| {
+                            ErrorCode::BadBytes(format!("Avro array {json} has no items"))
+                        })?;
+                        Ok(AvroSchema::Array(Box::new(self.parse(items, namespace)?)))
+                    }
+                    "map" => {
+                        let values = obj.get("values").ok_or_else(|| {
+                            ErrorCode::BadBytes(format!("Avro map {json} has no values"))
+                        })?;
+                        Ok(AvroSchema::Map(Box::new(self.parse(values, namespace)?)))
+                    }
+                    other => self.parse_name(other, namespace),
+                }
+            }
+            other => Err(ErrorCode::BadBytes(format!("Invalid Avro schema {other}"))),
+        }
+    }
+
+    fn parse_name(&self, name: &str, namespace: Option<&str>) -> Result<AvroSchema> {
+        let schema = match name {
+            "null" => AvroSchema::Null,
+            "boolean" => AvroSchema::Boolean,
+            "int" => AvroSchema::Int,
+            "long" => AvroSchema::Long,
+            "float" => AvroSchema::Float,
+            "double" => AvroSchema::Double,
+            "bytes" => AvroSchema::Bytes,
+            "string" => AvroSchema::String,
+            _ => {
+                let resolved = match namespace {
+                    Some(ns) if !name.contains('.') => self.names.get(&format!("{ns}.{name}")),
+                    _ => None,
+                };
+                match resolved.or_else(|| self.names.get(name)) {
+                    Some(schema) => schema.clone(),
+                    None if self
+                        .parsing
+                        .iter()
+                        .any(|n| Self::matches(n, name, namespace)) =>
+                    {
+                        return Err(ErrorCode::BadBytes(format!(
+                            "Recursive Avro type {name} is not supported"
+                        )));
+                    }
+                    None => {
+                        return Err(ErrorCode::BadBytes(format!("Unknown Avro type {name}")));
+                    }
+                }
+            }
+        };
+        Ok(schema)
+    }
+
+    // Logical types annotate an underlying type, unknown or invalid ones are ignored.
+    fn parse_logical(
+        logical_type: &str,
+        typ: &str,
+        obj: &serde_json::Map<String, JsonValue>,
+    ) -> Option<AvroSchema> {
+        match (logical_type, typ) {
+            ("decimal", "bytes" | "fixed") => {
+                let precision = obj.get("precision").and_then(|p| p.as_u64())? as usize;
+                let scale = obj.get("scale").and_then(|s| s.as_u64()).unwrap_or(0) as usize;
+                if precision == 0 || scale > precision {
+                    return None;
+                }
+                Some(AvroSchema::Decimal { precision, scale })
+            }
+            ("date", "int") => Some(AvroSchema::Date),
+            ("time-millis", "int") => Some(AvroSchema::TimeMillis),
+            ("time-micros", "long") => Some(AvroSchema::TimeMicros),
+            ("timestamp-millis", "long") => Some(AvroSchema::TimestampMillis),
+            ("timestamp-micros", "long") => Some(AvroSchema::TimestampMicros),
+            ("uuid", "string") => Some(AvroSchema::Uuid),
+            _ => None,
+        }
+    }
+
+    fn full_name(
+        obj: &serde_json::Map<String, JsonValue>,
+        namespace: Option<&str>,
+    ) -> Result<String> {
+        let name = obj
+            .get("name")
+            .and_then(|n| n.as_str())
+            .ok_or_else(|| ErrorCode::BadBytes("Avro named type has no name"))?;
+        if name.contains('.') {
+            return Ok(name.to_string());
+        }
+        let namespace = obj
+            .get("namespace")
+            .and_then(|n| n.as_str())
+            .or(namespace)
+            .filter(|ns| !ns.is_empty());
+        Ok(match namespace {
+            Some(ns) => format!("{ns}.{name}"),
+            None => name.to_string(),
+        })
+    }
+
+    fn matches(full_name: &str, name: &str, namespace: Option<&str>) -> bool {
+        full_name == name || namespace.is_some_and(|ns| full_name == format!("{ns}.{name}"))
+    }
+
+    fn register(&mut self, full_name: String, schema: AvroSchema) -> AvroSchema {
+        self.names.insert(full_name, schema.clone());
+        schema
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct AvroFileHeader {
+    pub schema: AvroSchema,
+    pub codec: String,
+    pub sync_marker: [u8; AVRO_SYNC_MARKER_SIZE],
+    /// The number of bytes of the header.
+    pub size: usize,
+}
+
+impl AvroFileHeader {
+    /// Decode the header at the start of an object container file.
+    ///
+    /// Returns `None` if `buf` does not hold the whole header yet.
+    pub fn try_decode(buf: &[u8]) -> Result<Option<AvroFileHeader>> {
+        if buf.len() < AVRO_MAGIC.len() {
+            return Ok(None);
+        }
+        if &buf[..AVRO_MAGIC.len()] != AVRO_MAGIC {
+            return Err(ErrorCode::BadBytes(
+                "Invalid Avro object container file: wrong magic bytes",
+            ));
+        }
+
+        let mut pos = AVRO_MAGIC.len();
+        let mut metadata = HashMap::new();
+        loop {
+            let Some(count) = read_long(buf, &mut pos)? else {
+                return Ok(None);
+            };
+            if count == 0 {
+                break;
+            }
+            // A negative count is followed by the size of the block in bytes.
+            if count < 0 && read_long(buf, &mut pos)?.is_none() {
+                return Ok(None);
+            }
+            for _ in 0..count.unsigned_abs() {
+                let Some(key) = read_bytes(buf, &mut pos)? else {
+                    return Ok(None);
+                };
+                let Some(value) = read_bytes(buf, &mut pos)? else {
+                    return Ok(None);
+                };
+                metadata.insert(String::from_utf8(key.to_vec())?, value);
+            }
+        }
+        if buf.len() < pos + AVRO_SYNC_MARKER_SIZE {
+            return Ok(None);
+        }
+        let mut sync_marker = [0u8; AVRO_SYNC_MARKER_SIZE];
+        sync_marker.copy_from_slice(&buf[pos..pos + AVRO_SYNC_MARKER_SIZE]);
+        pos += AVRO_SYNC_MARKER_SIZE;
+
+        let schema = match metadata.get(AVRO_SCHEMA_KEY) {
+            Some(schema) => AvroSchema::parse_str(std::str::from_utf8(schema)?)?,
+            None => {
+                return Err(ErrorCode::BadBytes(
+                    "Invalid Avro object container file: no avro.schema in the header",
+                ));
+            }
+        };
+        let codec = match metadata.get(AVRO_CODEC_KEY) {
+            Some(codec) => String::from_utf8(codec.to_vec())?,
+            None => "null".to_string(),
+        };
+        Ok(Some(AvroFileHeader {
+            schema,
+            codec,
+            sync_marker,
+            size: pos,
+        }))
+    }
+}
+
+/// The object count and the size of a data block, followed by its data and the sync marker.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AvroBlockHeader {
+    pub num_rows: usize,
+    pub data_size: usize,
+    /// The number of bytes of the count and the size.
+    pub size: usize,
+}
+
+impl AvroBlockHeader {
+    /// Returns `None` if `buf` does not hold the whole block header yet.
+    pub fn try_decode(buf: &[u8]) -> Result<Option<AvroBlockHeader>> {
+        let mut pos = 0;
+        let Some(num_rows) = read_long(buf, &mut pos)? else {
+            return Ok(None);
+        };
+        let Some(data_size) = read_long(buf, &mut pos)? else {
+            return Ok(None);
+        };
+        if num_rows < 0 || data_size < 0 {
+            return Err(ErrorCode::BadBytes(format!(
+                "Invalid Avro data block with {num_rows} objects of {data_size} bytes"
+            )));
+        }
+        Ok(Some(AvroBlockHeader {
+            num_rows: num_rows as usize,
+            data_size: data_size as usize,
+            size: pos,
+        }))
+    }
+}
+
+/// Read a zig-zag encoded variable-length long, `None` if `buf` ends in the middle.
+fn read_long(buf: &[u8], pos: &mut usize) -> Result<Option<i64>> {
+    let mut value: u64 = 0;
+    let mut shift = 0;
+    loop {
+        let Some(byte) = buf.get(*pos) else {
+            return Ok(None);
+        };
+        *pos += 1;
+        value |= ((byte & 0x7f) as u64) << shift;
+        if byte & 0x80 == 0 {
+            return Ok(Some((value >> 1) as i64 ^ -((value & 1) as i64)));
+        }
+        shift += 7;
+        if shift > 63 {
+            return Err(ErrorCode::BadBytes("Invalid Avro long: overflow"));
+        }
+    }
+}
+
+fn read_bytes<'a>(buf: &'a [u8], pos: &mut usize) -> Result<Option<&'a [u8]>> {
+    let Some(len) = read_long(buf, pos)? else {
+        return Ok(None);
+    };
+    if len < 0 {
+        return Err(ErrorCode::BadBytes(format!(
+            "Invalid Avro bytes with length {len}"
+        )));
+    }
+    let end = *pos + len as usize;
+    if buf.len() < end {
+        return Ok(None);
+    }
+    let bytes = &buf[*pos..end];
+    *pos = end;
+    Ok(Some(bytes))
+}
+
+/// Read the header of an Avro object container file and infer the table schema
+/// from its writer schema.
+#[async_backtrace::framed]
+pub async fn read_avro_schema_async(
+    operator: &Operator,
+    path: &str,
+    file_size: Option<u64>,
+) -> Result<TableSchema> {
+    let file_size = match file_size {
+        Some(size) => size,
+        None => operator.stat(path).await?.content_length(),
+    };
+
+    // The header is usually small, read more only if the schema is huge.
+    let mut read_size = file_size.min(64 * 1024);
+    loop {
+        let buf = operator.read_with(path).range(0..read_size).await?;
+        if let Some(header) = AvroFileHeader::try_decode(&buf)? {
+            return header.schema.to_table_schema();
+        }
+        if read_size >= file_size {
+            return Err(ErrorCode::BadBytes(format!(
+                "Invalid Avro object container file '{}': incomplete header",
+                path
+            )));
+        }
+        read_size = file_size.min(read_size * 4);
+    }
+}
@@ -49,6 +49,13 @@ mod column_node;
 pub use column_node::ColumnNode;
 pub use column_node::ColumnNodes;
 
+mod avro;
+pub use avro::read_avro_schema_async;
+pub use avro::AvroBlockHeader;
+pub use avro::AvroFileHeader;
+pub use avro::AvroSchema;
+pub use avro::AVRO_SYNC_MARKER_SIZE;
+
 mod parquet2;
 pub use parquet2::infer_schema_with_extension;
 pub use parquet2::read_parquet_metas_in_parallel;
@@ -12,9 +12,85 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use ndarray::ArrayView;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// The distance functions that can be accelerated by a vector index.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum VectorDistance {
+    Cosine,
+    L2,
+}
+
+impl VectorDistance {
+    /// Map a scalar function name to the distance it computes.
+    pub fn from_function_name(name: &str) -> Option<Self> {
+        match name {
+            "cosine_distance" => Some(VectorDistance::Cosine),
+            "l2_distance" => Some(VectorDistance::L2),
+            _ => None,
+        }
+    }
+
+    pub fn function_name(&self) -> &'static str {
+        match self {
+            VectorDistance::Cosine => "cosine_distance",
+            VectorDistance::L2 => "l2_distance",
+        }
+    }
+
+    /// Evaluate the distance of two vectors with the same dimension.
+    pub fn eval(&self, from: &[f32], to: &[f32]) -> f32 {
+        debug_assert_eq!(from.len(), to.len());
+        match self {
+            VectorDistance::Cosine => {
+                let (mut ab, mut aa, mut bb) = (0.0f32, 0.0f32, 0.0f32);
+                for (a, b) in from.iter().zip(to.iter()) {
+                    ab += a * b;
+                    aa += a * a;
+                    bb += b * b;
+                }
+                1.0 - ab / (aa.sqrt() * bb.sqrt())
+            }
+            VectorDistance::L2 => from
+                .iter()
+                .zip(to.iter())
+                .map(|(a, b)| (a - b).powi(2))
+                .sum::<f32>()
+                .sqrt(),
+        }
+    }
+}
+
+impl FromStr for VectorDistance {
+    type Err = ErrorCode;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s.to_lowercase().as_str() {
+            "cosine" => Ok(VectorDistance::Cosine),
+            "l2" => Ok(VectorDistance::L2),
+            _ => Err(ErrorCode::InvalidArgument(format!(
+                "Unsupported vector distance '{s}', expected 'cosine' or 'l2'"
+            ))),
+        }
+    }
+}
+
+impl Display for VectorDistance {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            VectorDistance::Cosine => write!(f, "cosine"),
+            VectorDistance::L2 => write!(f, "l2"),
+        }
+    }
+}
 
 pub fn cosine_distance(from: &[f32], to: &[f32]) -> Result<f32> {
     if from.len() != to.len() {
@@ -158,6 +158,8 @@ use databend_common_meta_app::schema::UndropTableReply;
 use databend_common_meta_app::schema::UndropTableReq;
 use databend_common_meta_app::schema::UpdateIndexReply;
 use databend_common_meta_app::schema::UpdateIndexReq;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReply;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
 use databend_common_meta_app::schema::UpdateTableMetaReply;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReply;
@@ -2899,6 +2901,192 @@ impl<KV: kvapi::KVApi<Error = MetaError> + ?Sized> SchemaApi for KV {
         }
     }
 
+    #[logcall::logcall("debug")]
+    #[minitrace::trace]
+    async fn update_multi_table_meta(
+        &self,
+        req: UpdateMultiTableMetaReq,
+    ) -> Result<UpdateMultiTableMetaReply, KVAppError> {
+        debug!(req = as_debug!(&req); "SchemaApi: {}", func_name!());
+
+        let truncated_tables = req.truncated_tables.iter().copied().collect::<HashSet<_>>();
+        let fail_if_duplicated = req.update_table_metas.iter().any(|req| {
+            req.copied_files
+                .as_ref()
+                .map(|v| v.fail_if_duplicated)
+                .unwrap_or(false)
+        });
+
+        loop {
+            let mut txn_req = TxnRequest {
+                condition: vec![],
+                if_then: vec![],
+                else_then: vec![],
+            };
+            let mut table_metas = Vec::with_capacity(req.update_table_metas.len());
+
+            for req in &req.update_table_metas {
+                let tbid = TableId {
+                    table_id: req.table_id,
+                };
+                let (tb_meta_seq, table_meta): (_, Option<TableMeta>) =
+                    get_pb_value(self, &tbid).await?;
+
+                if tb_meta_seq == 0 || table_meta.is_none() {
+                    return Err(KVAppError::AppError(AppError::UnknownTableId(
+                        UnknownTableId::new(req.table_id, "update_multi_table_meta"),
+                    )));
+                }
+                if req.seq.match_seq(tb_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            req.table_id,
+                            req.seq,
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+
+                txn_req.condition.push(txn_cond_seq(&tbid, Eq, tb_meta_seq));
+                txn_req
+                    .if_then
+                    .push(txn_op_put(&tbid, serialize_struct(&req.new_table_meta)?));
+                txn_req.else_then.push(TxnOp {
+                    request: Some(Request::Get(TxnGetRequest {
+                        key: tbid.to_string_key(),
+                    })),
+                });
+
+                // The copied files listed here are consistent with `tb_meta_seq`,
+                // which is asserted by the condition of the transaction.
+                let truncated = truncated_tables.contains(&req.table_id);
+                if truncated {
+                    let copied_files = list_table_copied_files(self, req.table_id).await?;
+                    for copied_file in copied_files {
+                        let recopied = req
+                            .copied_files
+                            .as_ref()
+                            .is_some_and(|r| r.file_info.contains_key(&copied_file.file));
+                        if !recopied {
+                            txn_req
+                                .if_then
+                                .push(TxnOp::delete(copied_file.to_string_key()));
+                        }
+                    }
+                }
+
+                if let Some(req) = &req.copied_files {
+                    // The files copied again after the truncation do exist.
+                    let (conditions, match_operations) =
+                        build_upsert_table_copied_file_info_conditions(
+                            &tbid,
+                            req,
+                            tb_meta_seq,
+                            req.fail_if_duplicated && !truncated,
+                        )?;
+                    txn_req.condition.extend(conditions);
+                    txn_req.if_then.extend(match_operations)
+                }
+
+                for req in &req.update_stream_meta {
+                    let stream_id = TableId {
+                        table_id: req.stream_id,
+                    };
+                    let (stream_meta_seq, stream_meta): (_, Option<TableMeta>) =
+                        get_pb_value(self, &stream_id).await?;
+
+                    if stream_meta_seq == 0 || stream_meta.is_none() {
+                        return Err(KVAppError::AppError(AppError::UnknownStreamId(
+                            UnknownStreamId::new(req.stream_id, "update_multi_table_meta"),
+                        )));
+                    }
+
+                    if req.seq.match_seq(stream_meta_seq).is_err() {
+                        return Err(KVAppError::AppError(AppError::from(
+                            StreamVersionMismatched::new(
+                                req.stream_id,
+                                req.seq,
+                                stream_meta_seq,
+                                "update_multi_table_meta",
+                            ),
+                        )));
+                    }
+
+                    let mut new_stream_meta = stream_meta.unwrap();
+                    new_stream_meta.options = req.options.clone();
+                    new_stream_meta.updated_on = Utc::now();
+
+                    txn_req
+                        .condition
+                        .push(txn_cond_seq(&stream_id, Eq, stream_meta_seq));
+                    txn_req
+                        .if_then
+                        .push(txn_op_put(&stream_id, serialize_struct(&new_stream_meta)?));
+                }
+
+                if let Some(deduplicated_label) = req.deduplicated_label.clone() {
+                    txn_req
+                        .if_then
+                        .push(build_upsert_table_deduplicated_label(deduplicated_label))
+                }
+
+                table_metas.push(table_meta.unwrap());
+            }
+
+            let (succ, responses) = send_txn(self, txn_req).await?;
+
+            debug!(
+                succ = succ;
+                "update_multi_table_meta"
+            );
+
+            if succ {
+                let mut share_table_info = None;
+                for table_meta in table_metas.iter() {
+                    if let Some(infos) = get_share_table_info_map(self, table_meta).await? {
+                        share_table_info.get_or_insert_with(Vec::new).extend(infos);
+                    }
+                }
+                return Ok(UpdateMultiTableMetaReply { share_table_info });
+            }
+
+            // one response for each table, in the same order of the requests
+            for (req, resp) in req.update_table_metas.iter().zip(responses.iter()) {
+                let Some(Response::Get(get_resp)) = &resp.response else {
+                    unreachable!(
+                        "internal error: expect some TxnGetResponseGet, but got {:?}",
+                        resp.response
+                    );
+                };
+                let tb_meta_seq = get_resp.value.as_ref().map_or(0, |seq_v| seq_v.seq);
+                if req.seq.match_seq(tb_meta_seq).is_err() {
+                    return Err(KVAppError::AppError(AppError::from(
+                        TableVersionMismatched::new(
+                            req.table_id,
+                            req.seq,
+                            tb_meta_seq,
+                            "update_multi_table_meta",
+                        ),
+                    )));
+                }
+            }
+
+            // all the table versions do match, but tx failed
+            if fail_if_duplicated {
+                let table_id = req
+                    .update_table_metas
+                    .iter()
+                    .find(|req| req.copied_files.is_some())
+                    .map_or(0, |req| req.table_id);
+                return Err(KVAppError::AppError(AppError::from(
+                    DuplicatedUpsertFiles::new(table_id, "update_multi_table_meta"),
+                )));
+            }
+            // continue and try update the "table copied files"
+        }
+    }
+
     #[logcall::logcall("debug")]
     #[minitrace::trace]
     async fn set_table_column_mask_policy(
@@ -102,6 +102,7 @@ use databend_common_meta_app::schema::TableStatistics;
 use databend_common_meta_app::schema::TruncateTableReq;
 use databend_common_meta_app::schema::UndropDatabaseReq;
 use databend_common_meta_app::schema::UndropTableReq;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
 use databend_common_meta_app::schema::UpdateTableMetaReq;
 use databend_common_meta_app::schema::UpdateVirtualColumnReq;
 use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
@@ -295,6 +296,9 @@ impl SchemaApiTestSuite {
             .await?;
         suite.table_rename(&b.build().await).await?;
         suite.table_update_meta(&b.build().await).await?;
+        suite
+            .table_update_multi_table_meta(&b.build().await)
+            .await?;
         suite.table_update_mask_policy(&b.build().await).await?;
         suite.table_upsert_option(&b.build().await).await?;
         suite.table_list(&b.build().await).await?;
@@ -2653,6 +2657,190 @@ impl SchemaApiTestSuite {
         Ok(())
     }
 
+    #[minitrace::trace]
+    async fn table_update_multi_table_meta<MT: SchemaApi>(&self, mt: &MT) -> anyhow::Result<()> {
+        let tenant = "tenant1";
+        let db_name = "db1";
+        let tbl_names = ["tb1", "tb2"];
+
+        let table_meta = || TableMeta {
+            schema: Arc::new(TableSchema::new(vec![TableField::new(
+                "number",
+                TableDataType::Number(NumberDataType::UInt64),
+            )])),
+            engine: "JSON".to_string(),
+            options: Default::default(),
+            created_on: Utc::now(),
+            ..TableMeta::default()
+        };
+
+        info!("--- prepare db and tables");
+        {
+            let plan = CreateDatabaseReq {
+                create_option: CreateOption::CreateIfNotExists(false),
+                name_ident: DatabaseNameIdent {
+                    tenant: tenant.to_string(),
+                    db_name: db_name.to_string(),
+                },
+                meta: DatabaseMeta {
+                    engine: "".to_string(),
+                    ..DatabaseMeta::default()
+                },
+            };
+            mt.create_database(plan).await?;
+
+            for tbl_name in tbl_names {
+                let req = CreateTableReq {
+                    create_option: CreateOption::CreateIfNotExists(false),
+                    name_ident: TableNameIdent {
+                        tenant: tenant.to_string(),
+                        db_name: db_name.to_string(),
+                        table_name: tbl_name.to_string(),
+                    },
+                    table_meta: table_meta(),
+                };
+                mt.create_table(req).await?;
+            }
+        }
+
+        let update_req = |table: &TableInfo, seq: u64, data_bytes: u64| {
+            let mut new_table_meta = table.meta.clone();
+            new_table_meta.statistics = TableStatistics {
+                data_bytes,
+                ..Default::default()
+            };
+            UpdateTableMetaReq {
+                table_id: table.ident.table_id,
+                seq: MatchSeq::Exact(seq),
+                new_table_meta,
+                copied_files: None,
+                deduplicated_label: None,
+                update_stream_meta: vec![],
+            }
+        };
+
+        info!("--- update multi table meta, normal case");
+        {
+            let mut reqs = vec![];
+            for tbl_name in tbl_names {
+                let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+                reqs.push(update_req(&table, table.ident.seq, 1));
+            }
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                update_table_metas: reqs,
+                truncated_tables: vec![],
+            })
+            .await?;
+
+            for tbl_name in tbl_names {
+                let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+                assert_eq!(table.meta.statistics.data_bytes, 1);
+            }
+        }
+
+        info!("--- update multi table meta, version mismatch of one table updates nothing");
+        {
+            let tb1 = mt.get_table((tenant, db_name, tbl_names[0]).into()).await?;
+            let tb2 = mt.get_table((tenant, db_name, tbl_names[1]).into()).await?;
+            let res = mt
+                .update_multi_table_meta(UpdateMultiTableMetaReq {
+                    update_table_metas: vec![
+                        update_req(&tb1, tb1.ident.seq, 2),
+                        update_req(&tb2, tb2.ident.seq + 1, 2),
+                    ],
+                    truncated_tables: vec![],
+                })
+                .await;
+
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
+
+            for tbl_name in tbl_names {
+                let table = mt.get_table((tenant, db_name, tbl_name).into()).await?;
+                assert_eq!(table.meta.statistics.data_bytes, 1);
+            }
+        }
+
+        let copied_files = |files: &[&str]| {
+            let file_info = files
+                .iter()
+                .map(|file| {
+                    (file.to_string(), TableCopiedFileInfo {
+                        etag: Some("tag".to_string()),
+                        content_length: 1,
+                        last_modified: None,
+                    })
+                })
+                .collect::<BTreeMap<_, _>>();
+            UpsertTableCopiedFileReq {
+                file_info,
+                expire_at: None,
+                fail_if_duplicated: true,
+            }
+        };
+        let get_copied_files = move |table_id: u64| {
+            mt.get_table_copied_file_info(GetTableCopiedFileReq {
+                table_id,
+                files: vec!["f1".to_string(), "f2".to_string(), "f3".to_string()],
+            })
+        };
+
+        info!("--- update multi table meta, record copied files");
+        {
+            let tb1 = mt.get_table((tenant, db_name, tbl_names[0]).into()).await?;
+            let mut req = update_req(&tb1, tb1.ident.seq, 3);
+            req.copied_files = Some(copied_files(&["f1", "f2"]));
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                update_table_metas: vec![req],
+                truncated_tables: vec![],
+            })
+            .await?;
+
+            let resp = get_copied_files(tb1.ident.table_id).await?;
+            assert_eq!(resp.file_info.len(), 2);
+        }
+
+        info!(
+            "--- update multi table meta, version mismatch of a truncated table keeps its copied files"
+        );
+        {
+            let tb1 = mt.get_table((tenant, db_name, tbl_names[0]).into()).await?;
+            let res = mt
+                .update_multi_table_meta(UpdateMultiTableMetaReq {
+                    update_table_metas: vec![update_req(&tb1, tb1.ident.seq + 1, 0)],
+                    truncated_tables: vec![tb1.ident.table_id],
+                })
+                .await;
+
+            let err = ErrorCode::from(res.unwrap_err());
+            assert_eq!(ErrorCode::TABLE_VERSION_MISMATCHED, err.code());
+
+            let resp = get_copied_files(tb1.ident.table_id).await?;
+            assert_eq!(resp.file_info.len(), 2);
+        }
+
+        info!(
+            "--- update multi table meta, truncated table keeps only the files copied after the truncation"
+        );
+        {
+            let tb1 = mt.get_table((tenant, db_name, tbl_names[0]).into()).await?;
+            let mut req = update_req(&tb1, tb1.ident.seq, 1);
+            req.copied_files = Some(copied_files(&["f2", "f3"]));
+            mt.update_multi_table_meta(UpdateMultiTableMetaReq {
+                update_table_metas: vec![req],
+                truncated_tables: vec![tb1.ident.table_id],
+            })
+            .await?;
+
+            let resp = get_copied_files(tb1.ident.table_id).await?;
+            assert_eq!(resp.file_info.keys().cloned().collect::<Vec<_>>(), vec![
+                "f2".to_string(),
+                "f3".to_string()
+            ]);
+        }
+        Ok(())
+    }
+
     #[minitrace::trace]
     async fn table_update_mask_policy<
         MT: SchemaApi + DatamaskApi + kvapi::AsKVApi<Error = MetaError>,
@@ -3653,6 +3841,8 @@ impl SchemaApiTestSuite {
                 original_query: "select sum(number) from tb1".to_string(),
                 query: "select sum(number) from tb1".to_string(),
                 sync_creation: false,
+                options: BTreeMap::new(),
+                options: BTreeMap::new(),
             },
         };
 
@@ -5666,6 +5856,7 @@ impl SchemaApiTestSuite {
             original_query: "SELECT a, SUM(b) FROM tb1 WHERE a > 1 GROUP BY b".to_string(),
             query: "SELECT a, SUM(b) FROM tb1 WHERE a > 1 GROUP BY b".to_string(),
             sync_creation: false,
+            options: BTreeMap::new(),
         };
 
         let index_name_2 = "idx2";
@@ -5678,6 +5869,7 @@ impl SchemaApiTestSuite {
             original_query: "SELECT a, SUM(b) FROM tb1 WHERE b > 1 GROUP BY b".to_string(),
             query: "SELECT a, SUM(b) FROM tb1 WHERE b > 1 GROUP BY b".to_string(),
             sync_creation: false,
+            options: BTreeMap::new(),
         };
 
         let name_ident_1 = IndexNameIdent {
@@ -19,8 +19,10 @@ mod file_format;
 mod network_policy;
 mod ownership_info;
 mod password_policy;
+mod pipe;
 mod principal_identity;
 mod role_info;
+mod task;
 mod user_auth;
 mod user_defined_file_format;
 mod user_defined_function;
@@ -31,15 +33,25 @@ mod user_privilege;
 mod user_quota;
 mod user_setting;
 mod user_stage;
+mod workload_group;
 
 pub use connection::*;
 pub use file_format::*;
 pub use network_policy::NetworkPolicy;
 pub use ownership_info::OwnershipInfo;
 pub use password_policy::PasswordPolicy;
+pub use pipe::PipeInfo;
+pub use pipe::PipeLoad;
 pub use principal_identity::PrincipalIdentity;
 pub use role_info::RoleInfo;
 pub use role_info::RoleInfoSerdeError;
+pub use task::ScheduleOptions;
+pub use task::ScheduleType;
+pub use task::State;
+pub use task::Status;
+pub use task::Task;
+pub use task::TaskRun;
+pub use task::WarehouseOptions;
 pub use user_auth::AuthInfo;
 pub use user_auth::AuthType;
 pub use user_auth::PasswordHashMethod;
@@ -48,6 +60,7 @@ pub use user_defined_function::LambdaUDF;
 pub use user_defined_function::UDFDefinition;
 pub use user_defined_function::UDFServer;
 pub use user_defined_function::UserDefinedFunction;
+pub use user_defined_function::WasmUDF;
 pub use user_grant::GrantEntry;
 pub use user_grant::GrantObject;
 pub use user_grant::OwnershipObject;
@@ -62,3 +75,4 @@ pub use user_quota::UserQuota;
 pub use user_setting::UserSetting;
 pub use user_setting::UserSettingValue;
 pub use user_stage::*;
+pub use workload_group::WorkloadGroup;
@@ -0,0 +1,48 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// A pipe loads the files under a stage into a table continuously,
+/// by running the `COPY INTO <table>` statement of the pipe.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct PipeInfo {
+    pub name: String,
+    /// The `COPY INTO <table>` statement, with the database of the table resolved.
+    pub copy_sql: String,
+    /// If true, the pipe is run periodically to load the new files under the stage.
+    pub auto_ingest: bool,
+    pub execution_paused: bool,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+    /// The time the pipe was run last time.
+    pub last_load_on: Option<DateTime<Utc>>,
+    /// The error of the last run, `None` if it succeeded.
+    pub last_error: Option<String>,
+    /// The role that created the pipe, the pipe is run as this role.
+    pub owner: String,
+}
+
+/// The record of a run of a pipe.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct PipeLoad {
+    pub pipe_name: String,
+    pub start_on: DateTime<Utc>,
+    pub end_on: DateTime<Utc>,
+    pub files_loaded: u64,
+    pub rows_loaded: u64,
+    pub error: Option<String>,
+}
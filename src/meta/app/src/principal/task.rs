@@ -0,0 +1,173 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::fmt::Formatter;
+use std::str::FromStr;
+
+use chrono::DateTime;
+use chrono::Utc;
+use cron::Schedule;
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum ScheduleType {
+    #[default]
+    IntervalType = 0,
+    CronType = 1,
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct ScheduleOptions {
+    /// The interval in seconds between two runs of the task.
+    pub interval: Option<i32>,
+    pub cron: Option<String>,
+    pub time_zone: Option<String>,
+    pub schedule_type: ScheduleType,
+}
+
+impl ScheduleOptions {
+    /// Returns the first time the task should run after `after`.
+    pub fn next_scheduled_at(&self, after: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        match self.schedule_type {
+            ScheduleType::IntervalType => {
+                let secs = self.interval.unwrap_or_default().max(1);
+                Some(after + chrono::Duration::seconds(secs as i64))
+            }
+            ScheduleType::CronType => {
+                let schedule = Schedule::from_str(self.cron.as_ref()?).ok()?;
+                match self
+                    .time_zone
+                    .as_ref()
+                    .filter(|tz| !tz.is_empty())
+                    .and_then(|tz| chrono_tz::Tz::from_str(tz).ok())
+                {
+                    Some(tz) => schedule
+                        .after(&after.with_timezone(&tz))
+                        .next()
+                        .map(|t| t.with_timezone(&Utc)),
+                    None => schedule.after(&after).next(),
+                }
+            }
+        }
+    }
+}
+
+impl Display for ScheduleOptions {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self.schedule_type {
+            ScheduleType::IntervalType => {
+                write!(f, "INTERVAL {} SECOND", self.interval.unwrap_or_default())
+            }
+            ScheduleType::CronType => {
+                write!(f, "CRON {}", self.cron.clone().unwrap_or_default())?;
+                if let Some(tz) = &self.time_zone {
+                    write!(f, " TIMEZONE {}", tz)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct WarehouseOptions {
+    pub warehouse: Option<String>,
+    pub using_warehouse_size: Option<String>,
+}
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum Status {
+    #[default]
+    Suspended = 0,
+    Started = 1,
+}
+
+#[derive(
+    serde::Serialize,
+    serde::Deserialize,
+    Clone,
+    Debug,
+    Default,
+    Eq,
+    PartialEq,
+    num_derive::FromPrimitive,
+)]
+pub enum State {
+    #[default]
+    Scheduled = 0,
+    Executing = 1,
+    Succeeded = 2,
+    Failed = 3,
+    Cancelled = 4,
+}
+
+/// A task runs a SQL statement on a schedule, or after its predecessor tasks have
+/// finished in the same run of the task graph.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct Task {
+    pub task_id: u64,
+    pub task_name: String,
+    pub query_text: String,
+    pub when_condition: Option<String>,
+    /// The names of the predecessor tasks, empty for a root task.
+    pub after: Vec<String>,
+    pub comment: Option<String>,
+    /// The identity of the role that owns the task, the task is run as this role.
+    pub owner: String,
+    pub schedule_options: Option<ScheduleOptions>,
+    pub warehouse_options: Option<WarehouseOptions>,
+    pub next_scheduled_at: Option<DateTime<Utc>>,
+    pub suspend_task_after_num_failures: Option<u64>,
+    pub status: Status,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub last_suspended_at: Option<DateTime<Utc>>,
+    pub session_params: BTreeMap<String, String>,
+}
+
+/// The record of a run of a task.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Default, Eq, PartialEq)]
+pub struct TaskRun {
+    /// The task definition at the time it was run.
+    pub task: Task,
+    pub run_id: u64,
+    pub attempt_number: i32,
+    pub state: State,
+    pub scheduled_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub error_code: i64,
+    pub error_message: Option<String>,
+    /// The id of the root task of the task graph run this run belongs to.
+    pub root_task_id: u64,
+    pub query_id: String,
+}
@@ -34,10 +34,21 @@ pub struct UDFServer {
     pub return_type: DataType,
 }
 
+/// A user-defined function backed by a WebAssembly module that runs in-process.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WasmUDF {
+    /// The module, in the WebAssembly binary or text format.
+    pub code: Vec<u8>,
+    pub handler: String,
+    pub arg_types: Vec<DataType>,
+    pub return_type: DataType,
+}
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum UDFDefinition {
     LambdaUDF(LambdaUDF),
     UDFServer(UDFServer),
+    WasmUDF(WasmUDF),
 }
 
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -88,6 +99,27 @@ impl UserDefinedFunction {
             created_on: Utc::now(),
         }
     }
+
+    pub fn create_wasm_udf(
+        name: &str,
+        code: Vec<u8>,
+        handler: &str,
+        arg_types: Vec<DataType>,
+        return_type: DataType,
+        description: &str,
+    ) -> Self {
+        Self {
+            name: name.to_string(),
+            description: description.to_string(),
+            definition: UDFDefinition::WasmUDF(WasmUDF {
+                code,
+                handler: handler.to_string(),
+                arg_types,
+                return_type,
+            }),
+            created_on: Utc::now(),
+        }
+    }
 }
 
 impl Display for UDFDefinition {
@@ -124,6 +156,24 @@ impl Display for UDFDefinition {
                     ") RETURNS {return_type} LANGUAGE {language} HANDLER = {handler} ADDRESS = {address}"
                 )?;
             }
+            UDFDefinition::WasmUDF(WasmUDF {
+                code,
+                handler,
+                arg_types,
+                return_type,
+            }) => {
+                for (i, item) in arg_types.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, ", ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                write!(
+                    f,
+                    ") RETURNS {return_type} LANGUAGE wasm HANDLER = {handler} MODULE SIZE = {}",
+                    code.len()
+                )?;
+            }
         }
         Ok(())
     }
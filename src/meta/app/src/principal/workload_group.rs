@@ -0,0 +1,41 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use chrono::Utc;
+
+/// A workload group limits the resources of the queries it admits.
+///
+/// A limit of `0` means unlimited.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq, Default)]
+pub struct WorkloadGroup {
+    pub name: String,
+    /// The max number of queries of the group running at the same time on a node.
+    pub max_concurrency: u64,
+    /// The max number of queries of the group waiting for admission on a node.
+    pub max_queued: u64,
+    /// How long a query waits for admission before it fails.
+    pub queue_timeout_secs: u64,
+    /// The share of the max server memory a query of the group may use.
+    pub memory_percentage: u64,
+    /// The share of the max threads a query of the group may use.
+    pub cpu_percentage: u64,
+    /// The users whose queries are admitted by the group.
+    pub users: Vec<String>,
+    /// The roles whose queries are admitted by the group.
+    pub roles: Vec<String>,
+    pub comment: String,
+    pub create_on: DateTime<Utc>,
+    pub update_on: Option<DateTime<Utc>>,
+}
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fmt;
 use std::fmt::Display;
 use std::fmt::Formatter;
@@ -81,6 +82,8 @@ pub enum IndexType {
     #[default]
     AGGREGATING = 1,
     JOIN = 2,
+    VECTOR = 3,
+    INVERTED = 4,
 }
 
 impl Display for IndexType {
@@ -88,6 +91,8 @@ impl Display for IndexType {
         match self {
             IndexType::AGGREGATING => write!(f, "AGGREGATING"),
             IndexType::JOIN => write!(f, "JOIN"),
+            IndexType::VECTOR => write!(f, "VECTOR"),
+            IndexType::INVERTED => write!(f, "INVERTED"),
         }
     }
 }
@@ -106,6 +111,9 @@ pub struct IndexMeta {
     // if true, index will create after data written to databend,
     // no need execute refresh index manually.
     pub sync_creation: bool,
+    // index type specific options, e.g. the indexed column and
+    // the distance function of a vector index.
+    pub options: BTreeMap<String, String>,
 }
 
 impl Default for IndexMeta {
@@ -119,6 +127,7 @@ impl Default for IndexMeta {
             original_query: "".to_string(),
             query: "".to_string(),
             sync_creation: false,
+            options: BTreeMap::new(),
         }
     }
 }
@@ -243,6 +243,76 @@ pub struct TableMeta {
     // shared by share_id
     pub shared_by: BTreeSet<u64>,
     pub column_mask_policy: Option<BTreeMap<String, String>>,
+    // Constraints declared on the table, keyed by constraint name.
+    pub constraints: BTreeMap<String, TableConstraint>,
+}
+
+/// A constraint declared on a table.
+///
+/// Only `Check` is enforced when writing data, the key constraints are
+/// informational and are trusted by the optimizer.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, Eq, PartialEq)]
+pub enum TableConstraint {
+    /// The expression is stored as normalized SQL text.
+    Check {
+        expression: String,
+    },
+    PrimaryKey {
+        columns: Vec<String>,
+    },
+    Unique {
+        columns: Vec<String>,
+    },
+    ForeignKey {
+        columns: Vec<String>,
+        ref_database: String,
+        ref_table: String,
+        ref_columns: Vec<String>,
+    },
+}
+
+impl TableConstraint {
+    pub fn constraint_type(&self) -> &'static str {
+        match self {
+            TableConstraint::Check { .. } => "CHECK",
+            TableConstraint::PrimaryKey { .. } => "PRIMARY KEY",
+            TableConstraint::Unique { .. } => "UNIQUE",
+            TableConstraint::ForeignKey { .. } => "FOREIGN KEY",
+        }
+    }
+
+    /// The columns of a key constraint, empty for `Check`.
+    pub fn key_columns(&self) -> &[String] {
+        match self {
+            TableConstraint::Check { .. } => &[],
+            TableConstraint::PrimaryKey { columns }
+            | TableConstraint::Unique { columns }
+            | TableConstraint::ForeignKey { columns, .. } => columns,
+        }
+    }
+}
+
+impl Display for TableConstraint {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            TableConstraint::Check { expression } => write!(f, "CHECK ({expression})"),
+            TableConstraint::PrimaryKey { columns } => {
+                write!(f, "PRIMARY KEY ({})", columns.join(", "))
+            }
+            TableConstraint::Unique { columns } => write!(f, "UNIQUE ({})", columns.join(", ")),
+            TableConstraint::ForeignKey {
+                columns,
+                ref_database,
+                ref_table,
+                ref_columns,
+            } => write!(
+                f,
+                "FOREIGN KEY ({}) REFERENCES {ref_database}.{ref_table} ({})",
+                columns.join(", "),
+                ref_columns.join(", ")
+            ),
+        }
+    }
 }
 
 impl TableMeta {
@@ -357,6 +427,7 @@ impl Default for TableMeta {
             statistics: Default::default(),
             shared_by: BTreeSet::new(),
             column_mask_policy: None,
+            constraints: BTreeMap::new(),
         }
     }
 }
@@ -633,6 +704,16 @@ pub struct UpdateTableMetaReq {
     pub deduplicated_label: Option<String>,
 }
 
+/// Update the meta of multiple tables in a single transaction,
+/// either all of them are updated or none of them.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReq {
+    pub update_table_metas: Vec<UpdateTableMetaReq>,
+    /// Ids of the truncated tables, their copied files are removed in the same
+    /// transaction, before the copied files of `update_table_metas` are recorded.
+    pub truncated_tables: Vec<u64>,
+}
+
 impl UpsertTableOptionReq {
     pub fn new(
         table_ident: &TableIdent,
@@ -689,6 +770,11 @@ pub struct UpdateTableMetaReply {
     pub share_table_info: Option<Vec<ShareTableInfoMap>>,
 }
 
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct UpdateMultiTableMetaReply {
+    pub share_table_info: Option<Vec<ShareTableInfoMap>>,
+}
+
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
 pub struct GetTableReq {
     pub inner: TableNameIdent,
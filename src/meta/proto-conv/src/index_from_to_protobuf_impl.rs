@@ -84,6 +84,7 @@ impl FromToProto for mt::IndexMeta {
             original_query: p.original_query,
             query: p.query,
             sync_creation: p.sync_creation,
+            options: p.options,
         };
         Ok(v)
     }
@@ -106,6 +107,7 @@ impl FromToProto for mt::IndexMeta {
             original_query: self.original_query.clone(),
             query: self.query.clone(),
             sync_creation: self.sync_creation,
+            options: self.options.clone(),
         };
         Ok(p)
     }
@@ -218,6 +218,11 @@ impl FromToProto for mt::TableMeta {
             } else {
                 Some(p.column_mask_policy)
             },
+            constraints: p
+                .constraints
+                .into_iter()
+                .map(|(name, constraint)| Ok((name, mt::TableConstraint::from_pb(constraint)?)))
+                .collect::<Result<_, Incompatible>>()?,
         };
         Ok(v)
     }
@@ -254,11 +259,90 @@ impl FromToProto for mt::TableMeta {
             statistics: Some(self.statistics.to_pb()?),
             shared_by: Vec::from_iter(self.shared_by.clone()),
             column_mask_policy: self.column_mask_policy.clone().unwrap_or_default(),
+            constraints: self
+                .constraints
+                .iter()
+                .map(|(name, constraint)| Ok((name.clone(), constraint.to_pb()?)))
+                .collect::<Result<_, Incompatible>>()?,
         };
         Ok(p)
     }
 }
 
+impl FromToProto for mt::TableConstraint {
+    type PB = pb::TableConstraint;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::TableConstraint) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let v = match p.constraint {
+            Some(pb::table_constraint::Constraint::Check(check)) => mt::TableConstraint::Check {
+                expression: check.expression,
+            },
+            Some(pb::table_constraint::Constraint::PrimaryKey(key)) => {
+                mt::TableConstraint::PrimaryKey {
+                    columns: key.columns,
+                }
+            }
+            Some(pb::table_constraint::Constraint::Unique(key)) => mt::TableConstraint::Unique {
+                columns: key.columns,
+            },
+            Some(pb::table_constraint::Constraint::ForeignKey(fk)) => {
+                mt::TableConstraint::ForeignKey {
+                    columns: fk.columns,
+                    ref_database: fk.ref_database,
+                    ref_table: fk.ref_table,
+                    ref_columns: fk.ref_columns,
+                }
+            }
+            None => {
+                return Err(Incompatible {
+                    reason: "TableConstraint.constraint can not be None".to_string(),
+                });
+            }
+        };
+        Ok(v)
+    }
+
+    fn to_pb(&self) -> Result<pb::TableConstraint, Incompatible> {
+        let constraint = match self {
+            mt::TableConstraint::Check { expression } => {
+                pb::table_constraint::Constraint::Check(pb::table_constraint::Check {
+                    expression: expression.clone(),
+                })
+            }
+            mt::TableConstraint::PrimaryKey { columns } => {
+                pb::table_constraint::Constraint::PrimaryKey(pb::table_constraint::Key {
+                    columns: columns.clone(),
+                })
+            }
+            mt::TableConstraint::Unique { columns } => {
+                pb::table_constraint::Constraint::Unique(pb::table_constraint::Key {
+                    columns: columns.clone(),
+                })
+            }
+            mt::TableConstraint::ForeignKey {
+                columns,
+                ref_database,
+                ref_table,
+                ref_columns,
+            } => pb::table_constraint::Constraint::ForeignKey(pb::table_constraint::ForeignKey {
+                columns: columns.clone(),
+                ref_database: ref_database.clone(),
+                ref_table: ref_table.clone(),
+                ref_columns: ref_columns.clone(),
+            }),
+        };
+        Ok(pb::TableConstraint {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            constraint: Some(constraint),
+        })
+    }
+}
+
 impl FromToProto for mt::TableStatistics {
     type PB = pb::TableStatistics;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -106,6 +106,60 @@ impl FromToProto for mt::UDFServer {
     }
 }
 
+impl FromToProto for mt::WasmUDF {
+    type PB = pb::WasmUdf;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::WasmUdf) -> Result<Self, Incompatible> {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+
+        let mut arg_types = Vec::with_capacity(p.arg_types.len());
+        for arg_type in p.arg_types {
+            let arg_type = DataType::from(&TableDataType::from_pb(arg_type)?);
+            arg_types.push(arg_type);
+        }
+        let return_type = DataType::from(&TableDataType::from_pb(p.return_type.ok_or_else(
+            || Incompatible {
+                reason: "WasmUdf.return_type can not be None".to_string(),
+            },
+        )?)?);
+
+        Ok(mt::WasmUDF {
+            code: p.code,
+            handler: p.handler,
+            arg_types,
+            return_type,
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::WasmUdf, Incompatible> {
+        let mut arg_types = Vec::with_capacity(self.arg_types.len());
+        for arg_type in self.arg_types.iter() {
+            let arg_type = infer_schema_type(arg_type)
+                .map_err(|e| Incompatible {
+                    reason: format!("Convert DataType to TableDataType failed: {}", e.message()),
+                })?
+                .to_pb()?;
+            arg_types.push(arg_type);
+        }
+        let return_type = infer_schema_type(&self.return_type)
+            .map_err(|e| Incompatible {
+                reason: format!("Convert DataType to TableDataType failed: {}", e.message()),
+            })?
+            .to_pb()?;
+
+        Ok(pb::WasmUdf {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            code: self.code.clone(),
+            handler: self.handler.clone(),
+            arg_types,
+            return_type: Some(return_type),
+        })
+    }
+}
+
 impl FromToProto for mt::UserDefinedFunction {
     type PB = pb::UserDefinedFunction;
     fn get_pb_ver(p: &Self::PB) -> u64 {
@@ -120,6 +174,9 @@ impl FromToProto for mt::UserDefinedFunction {
             Some(pb::user_defined_function::Definition::UdfServer(udf_server)) => {
                 mt::UDFDefinition::UDFServer(mt::UDFServer::from_pb(udf_server)?)
             }
+            Some(pb::user_defined_function::Definition::WasmUdf(wasm_udf)) => {
+                mt::UDFDefinition::WasmUDF(mt::WasmUDF::from_pb(wasm_udf)?)
+            }
             None => {
                 return Err(Incompatible {
                     reason: "UserDefinedFunction.definition cannot be None".to_string(),
@@ -146,6 +203,9 @@ impl FromToProto for mt::UserDefinedFunction {
             mt::UDFDefinition::UDFServer(udf_server) => {
                 pb::user_defined_function::Definition::UdfServer(udf_server.to_pb()?)
             }
+            mt::UDFDefinition::WasmUDF(wasm_udf) => {
+                pb::user_defined_function::Definition::WasmUdf(wasm_udf.to_pb()?)
+            }
         };
 
         Ok(pb::UserDefinedFunction {
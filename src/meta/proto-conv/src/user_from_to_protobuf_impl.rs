@@ -450,6 +450,54 @@ impl FromToProto for mt::principal::NetworkPolicy {
     }
 }
 
+impl FromToProto for mt::principal::WorkloadGroup {
+    type PB = pb::WorkloadGroup;
+    fn get_pb_ver(p: &Self::PB) -> u64 {
+        p.ver
+    }
+    fn from_pb(p: pb::WorkloadGroup) -> Result<Self, Incompatible>
+    where Self: Sized {
+        reader_check_msg(p.ver, p.min_reader_ver)?;
+        Ok(mt::principal::WorkloadGroup {
+            name: p.name,
+            max_concurrency: p.max_concurrency,
+            max_queued: p.max_queued,
+            queue_timeout_secs: p.queue_timeout_secs,
+            memory_percentage: p.memory_percentage,
+            cpu_percentage: p.cpu_percentage,
+            users: p.users,
+            roles: p.roles,
+            comment: p.comment,
+            create_on: DateTime::<Utc>::from_pb(p.create_on)?,
+            update_on: match p.update_on {
+                Some(t) => Some(DateTime::<Utc>::from_pb(t)?),
+                None => None,
+            },
+        })
+    }
+
+    fn to_pb(&self) -> Result<pb::WorkloadGroup, Incompatible> {
+        Ok(pb::WorkloadGroup {
+            ver: VER,
+            min_reader_ver: MIN_READER_VER,
+            name: self.name.clone(),
+            max_concurrency: self.max_concurrency,
+            max_queued: self.max_queued,
+            queue_timeout_secs: self.queue_timeout_secs,
+            memory_percentage: self.memory_percentage,
+            cpu_percentage: self.cpu_percentage,
+            users: self.users.clone(),
+            roles: self.roles.clone(),
+            comment: self.comment.clone(),
+            create_on: self.create_on.to_pb()?,
+            update_on: match &self.update_on {
+                Some(t) => Some(t.to_pb()?),
+                None => None,
+            },
+        })
+    }
+}
+
 impl FromToProto for mt::principal::PasswordPolicy {
     type PB = pb::PasswordPolicy;
     fn get_pb_ver(p: &Self::PB) -> u64 {
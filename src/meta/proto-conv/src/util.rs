@@ -109,7 +109,17 @@ const META_CHANGE_LOG: &[(u64, &str)] = &[
     (77, "2024-01-22: Remove: allow_anonymous in S3 Config", ),
     (78, "2024-01-29: Refactor: GrantEntry::UserPrivilegeType and ShareGrantEntry::ShareGrantObjectPrivilege use from_bits_truncate deserialize", ),
     (79, "2024-01-31: Add: udf.proto/UserDefinedFunction add created_on field", ),
-    (80, "2024-02-01: Add: Add: datatype.proto/DataType Geometry type")
+    (80, "2024-02-01: Add: Add: datatype.proto/DataType Geometry type"),
+    (81, "2024-02-06: Add: file_format.proto/FileFormatParams add Avro"),
+    (82, "2024-02-08: Add: file_format.proto/FileFormatParams add Orc"),
+    (83, "2024-02-12: Add: pipe.proto/PipeInfo and PipeLoad"),
+    (84, "2024-02-14: Add: task.proto/Task and TaskRun"),
+    (85, "2024-02-16: Add: user.proto/WorkloadGroup"),
+    (86, "2024-02-18: Add: index.proto/IndexMeta::options and IndexType::VECTOR"),
+    (87, "2024-02-20: Add: index.proto/IndexType::INVERTED"),
+    (88, "2024-02-22: Add: udf.proto/WasmUDF"),
+    (89, "2024-02-24: Add: table.proto/TableMeta::constraints"),
+    (90, "2024-02-26: Add: pipe.proto/PipeInfo::owner"),
     // Dear developer:
     //      If you're gonna add a new metadata version, you'll have to add a test for it.
     //      You could just copy an existing test file(e.g., `../tests/it/v024_table_meta.rs`)
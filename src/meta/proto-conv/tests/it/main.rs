@@ -84,3 +84,13 @@ mod v077_s3_remove_allow_anonymous;
 mod v078_grantentry;
 mod v079_udf_created_on;
 mod v080_geometry_datatype;
+mod v081_avro_file_format_params;
+mod v082_orc_file_format_params;
+mod v083_pipe;
+mod v084_task;
+mod v085_workload_group;
+mod v086_vector_index_meta;
+mod v087_inverted_index_meta;
+mod v088_wasm_udf;
+mod v089_table_constraint;
+mod v090_pipe_owner;
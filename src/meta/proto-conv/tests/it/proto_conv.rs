@@ -213,6 +213,10 @@ fn new_table_meta() -> mt::TableMeta {
         statistics: Default::default(),
         shared_by: btreeset! {1},
         column_mask_policy: Some(btreemap! {s("a") => s("b")}),
+        constraints: btreemap! {
+            s("c1") => mt::TableConstraint::Check { expression: s("(a > 0)") },
+            s("pk") => mt::TableConstraint::PrimaryKey { columns: vec![s("a")] },
+        },
     }
 }
 
@@ -226,6 +230,7 @@ fn new_index_meta() -> mt::IndexMeta {
         original_query: "SELECT a, sum(b) FROM default.t1 WHERE a > 3 GROUP BY b".to_string(),
         query: "SELECT a, SUM(b) FROM default.t1 WHERE a > 3 GROUP BY b".to_string(),
         sync_creation: false,
+        options: BTreeMap::new(),
     }
 }
 
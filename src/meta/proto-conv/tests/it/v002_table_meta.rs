@@ -143,6 +143,7 @@ fn test_decode_v2_table_meta() -> anyhow::Result<()> {
         statistics: Default::default(),
         shared_by: BTreeSet::new(),
         column_mask_policy: None,
+        constraints: Default::default(),
     };
 
     common::test_pb_from_to(func_name!(), want())?;
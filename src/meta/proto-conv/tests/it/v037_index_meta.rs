@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use chrono::TimeZone;
 use chrono::Utc;
 use databend_common_meta_app::schema::IndexMeta;
@@ -56,6 +58,7 @@ fn test_decode_v37_index() -> anyhow::Result<()> {
             query,
             updated_on: None,
             sync_creation: false,
+            options: BTreeMap::new(),
         }
     };
 
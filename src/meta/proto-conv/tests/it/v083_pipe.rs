@@ -0,0 +1,81 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_meta_app::principal::PipeLoad;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v83_pipe_info() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 2, 112, 49, 18, 29, 67, 79, 80, 89, 32, 73, 78, 84, 79, 32, 100, 101, 102, 97, 117,
+        108, 116, 46, 116, 49, 32, 70, 82, 79, 77, 32, 64, 115, 49, 24, 1, 42, 7, 108, 111, 97,
+        100, 32, 116, 49, 50, 23, 50, 48, 50, 52, 45, 48, 50, 45, 49, 50, 32, 49, 48, 58, 48, 48,
+        58, 48, 48, 32, 85, 84, 67, 58, 23, 50, 48, 50, 52, 45, 48, 50, 45, 49, 50, 32, 49, 48, 58,
+        48, 48, 58, 48, 48, 32, 85, 84, 67, 66, 23, 50, 48, 50, 52, 45, 48, 50, 45, 49, 50, 32, 49,
+        48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 74, 7, 110, 111, 32, 102, 105, 108, 101, 160,
+        6, 83, 168, 6, 24,
+    ];
+
+    let want = || PipeInfo {
+        name: "p1".to_string(),
+        copy_sql: "COPY INTO default.t1 FROM @s1".to_string(),
+        auto_ingest: true,
+        execution_paused: false,
+        comment: "load t1".to_string(),
+        create_on: Utc.with_ymd_and_hms(2024, 2, 12, 10, 0, 0).unwrap(),
+        update_on: Some(Utc.with_ymd_and_hms(2024, 2, 12, 10, 0, 0).unwrap()),
+        last_load_on: Some(Utc.with_ymd_and_hms(2024, 2, 12, 10, 0, 0).unwrap()),
+        last_error: Some("no file".to_string()),
+        owner: "".to_string(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 83, want())
+}
+
+#[test]
+fn test_decode_v83_pipe_load() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 2, 112, 49, 18, 23, 50, 48, 50, 52, 45, 48, 50, 45, 49, 50, 32, 49, 48, 58, 48, 48, 58,
+        48, 48, 32, 85, 84, 67, 26, 23, 50, 48, 50, 52, 45, 48, 50, 45, 49, 50, 32, 49, 48, 58, 48,
+        48, 58, 48, 49, 32, 85, 84, 67, 32, 2, 40, 10, 160, 6, 83, 168, 6, 24,
+    ];
+
+    let want = || PipeLoad {
+        pipe_name: "p1".to_string(),
+        start_on: Utc.with_ymd_and_hms(2024, 2, 12, 10, 0, 0).unwrap(),
+        end_on: Utc.with_ymd_and_hms(2024, 2, 12, 10, 0, 1).unwrap(),
+        files_loaded: 2,
+        rows_loaded: 10,
+        error: None,
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 83, want())
+}
@@ -0,0 +1,125 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::principal::ScheduleOptions;
+use databend_common_meta_app::principal::ScheduleType;
+use databend_common_meta_app::principal::State;
+use databend_common_meta_app::principal::Status;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_app::principal::WarehouseOptions;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v84_task() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        8, 7, 18, 2, 116, 49, 26, 24, 73, 78, 83, 69, 82, 84, 32, 73, 78, 84, 79, 32, 116, 32, 86,
+        65, 76, 85, 69, 83, 32, 40, 49, 41, 34, 5, 49, 32, 61, 32, 49, 50, 10, 101, 118, 101, 114,
+        121, 32, 104, 111, 117, 114, 58, 13, 97, 99, 99, 111, 117, 110, 116, 95, 97, 100, 109, 105,
+        110, 66, 30, 18, 11, 48, 32, 48, 32, 42, 32, 42, 32, 42, 32, 42, 26, 13, 65, 115, 105, 97,
+        47, 83, 104, 97, 110, 103, 104, 97, 105, 32, 1, 74, 5, 10, 3, 119, 104, 49, 82, 23, 50, 48,
+        50, 52, 45, 48, 50, 45, 49, 52, 32, 49, 48, 58, 48, 49, 58, 48, 48, 32, 85, 84, 67, 88, 3,
+        96, 1, 106, 23, 50, 48, 50, 52, 45, 48, 50, 45, 49, 52, 32, 49, 48, 58, 48, 48, 58, 48, 48,
+        32, 85, 84, 67, 114, 23, 50, 48, 50, 52, 45, 48, 50, 45, 49, 52, 32, 49, 48, 58, 48, 48,
+        58, 48, 48, 32, 85, 84, 67, 130, 1, 15, 10, 8, 116, 105, 109, 101, 122, 111, 110, 101, 18,
+        3, 85, 84, 67, 160, 6, 84, 168, 6, 24,
+    ];
+
+    let want = || Task {
+        task_id: 7,
+        task_name: "t1".to_string(),
+        query_text: "INSERT INTO t VALUES (1)".to_string(),
+        when_condition: Some("1 = 1".to_string()),
+        after: vec![],
+        comment: Some("every hour".to_string()),
+        owner: "account_admin".to_string(),
+        schedule_options: Some(ScheduleOptions {
+            interval: None,
+            cron: Some("0 0 * * * *".to_string()),
+            time_zone: Some("Asia/Shanghai".to_string()),
+            schedule_type: ScheduleType::CronType,
+        }),
+        warehouse_options: Some(WarehouseOptions {
+            warehouse: Some("wh1".to_string()),
+            using_warehouse_size: None,
+        }),
+        next_scheduled_at: Some(Utc.with_ymd_and_hms(2024, 2, 14, 10, 1, 0).unwrap()),
+        suspend_task_after_num_failures: Some(3),
+        status: Status::Started,
+        created_at: Utc.with_ymd_and_hms(2024, 2, 14, 10, 0, 0).unwrap(),
+        updated_at: Utc.with_ymd_and_hms(2024, 2, 14, 10, 0, 0).unwrap(),
+        last_suspended_at: None,
+        session_params: BTreeMap::from([("timezone".to_string(), "UTC".to_string())]),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 84, want())
+}
+
+#[test]
+fn test_decode_v84_task_run() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 96, 8, 8, 18, 2, 116, 50, 26, 13, 68, 69, 76, 69, 84, 69, 32, 70, 82, 79, 77, 32, 116,
+        42, 2, 116, 49, 58, 13, 97, 99, 99, 111, 117, 110, 116, 95, 97, 100, 109, 105, 110, 106,
+        23, 50, 48, 50, 52, 45, 48, 50, 45, 49, 52, 32, 49, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84,
+        67, 114, 23, 50, 48, 50, 52, 45, 48, 50, 45, 49, 52, 32, 49, 48, 58, 48, 48, 58, 48, 48,
+        32, 85, 84, 67, 160, 6, 84, 168, 6, 24, 16, 100, 24, 1, 32, 3, 42, 23, 50, 48, 50, 52, 45,
+        48, 50, 45, 49, 52, 32, 49, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67, 50, 23, 50, 48, 50,
+        52, 45, 48, 50, 45, 49, 52, 32, 49, 48, 58, 48, 49, 58, 48, 48, 32, 85, 84, 67, 56, 129, 8,
+        66, 15, 85, 110, 107, 110, 111, 119, 110, 32, 116, 97, 98, 108, 101, 32, 116, 72, 7, 82, 3,
+        113, 105, 100, 160, 6, 84, 168, 6, 24,
+    ];
+
+    let want = || TaskRun {
+        task: Task {
+            task_id: 8,
+            task_name: "t2".to_string(),
+            query_text: "DELETE FROM t".to_string(),
+            after: vec!["t1".to_string()],
+            owner: "account_admin".to_string(),
+            status: Status::Suspended,
+            created_at: Utc.with_ymd_and_hms(2024, 2, 14, 10, 0, 0).unwrap(),
+            updated_at: Utc.with_ymd_and_hms(2024, 2, 14, 10, 0, 0).unwrap(),
+            ..Default::default()
+        },
+        run_id: 100,
+        attempt_number: 1,
+        state: State::Failed,
+        scheduled_at: Utc.with_ymd_and_hms(2024, 2, 14, 10, 0, 0).unwrap(),
+        completed_at: Some(Utc.with_ymd_and_hms(2024, 2, 14, 10, 1, 0).unwrap()),
+        error_code: 1025,
+        error_message: Some("Unknown table t".to_string()),
+        root_task_id: 7,
+        query_id: "qid".to_string(),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 84, want())
+}
@@ -0,0 +1,57 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::TimeZone;
+use chrono::Utc;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v85_workload_group() -> anyhow::Result<()> {
+    let bytes: Vec<u8> = vec![
+        10, 3, 119, 103, 49, 16, 10, 24, 100, 32, 60, 40, 50, 48, 30, 58, 2, 117, 49, 66, 2, 114,
+        49, 66, 2, 114, 50, 74, 11, 101, 116, 108, 32, 113, 117, 101, 114, 105, 101, 115, 82, 23,
+        50, 48, 50, 52, 45, 48, 50, 45, 49, 54, 32, 49, 48, 58, 48, 48, 58, 48, 48, 32, 85, 84, 67,
+        90, 23, 50, 48, 50, 52, 45, 48, 50, 45, 49, 54, 32, 49, 48, 58, 48, 48, 58, 48, 48, 32, 85,
+        84, 67, 160, 6, 85, 168, 6, 24,
+    ];
+
+    let want = || databend_common_meta_app::principal::WorkloadGroup {
+        name: "wg1".to_string(),
+        max_concurrency: 10,
+        max_queued: 100,
+        queue_timeout_secs: 60,
+        memory_percentage: 50,
+        cpu_percentage: 30,
+        users: vec!["u1".to_string()],
+        roles: vec!["r1".to_string(), "r2".to_string()],
+        comment: "etl queries".to_string(),
+        create_on: Utc.with_ymd_and_hms(2024, 2, 16, 10, 0, 0).unwrap(),
+        update_on: Some(Utc.with_ymd_and_hms(2024, 2, 16, 10, 0, 0).unwrap()),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), bytes.as_slice(), 85, want())
+}
@@ -0,0 +1,68 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::schema::IndexMeta;
+use databend_common_meta_app::schema::IndexType;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v86_vector_index() -> anyhow::Result<()> {
+    let index_v086 = vec![
+        8, 7, 16, 3, 26, 23, 50, 48, 50, 52, 45, 48, 50, 45, 49, 56, 32, 49, 48, 58, 48, 48, 58,
+        48, 48, 32, 85, 84, 67, 42, 13, 100, 101, 102, 97, 117, 108, 116, 46, 116, 49, 40, 118, 41,
+        56, 1, 66, 13, 100, 101, 102, 97, 117, 108, 116, 46, 116, 49, 40, 118, 41, 74, 11, 10, 6,
+        99, 111, 108, 117, 109, 110, 18, 1, 118, 74, 18, 10, 8, 100, 105, 115, 116, 97, 110, 99,
+        101, 18, 6, 99, 111, 115, 105, 110, 101, 74, 21, 10, 15, 101, 102, 95, 99, 111, 110, 115,
+        116, 114, 117, 99, 116, 105, 111, 110, 18, 2, 54, 52, 74, 7, 10, 1, 109, 18, 2, 49, 54,
+        160, 6, 86, 168, 6, 24,
+    ];
+
+    let want = || IndexMeta {
+        table_id: 7,
+        index_type: IndexType::VECTOR,
+        created_on: Utc.with_ymd_and_hms(2024, 2, 18, 10, 0, 0).unwrap(),
+        dropped_on: None,
+        updated_on: None,
+        original_query: "default.t1(v)".to_string(),
+        query: "default.t1(v)".to_string(),
+        sync_creation: true,
+        options: BTreeMap::from([
+            ("column".to_string(), "v".to_string()),
+            ("distance".to_string(), "cosine".to_string()),
+            ("ef_construction".to_string(), "64".to_string()),
+            ("m".to_string(), "16".to_string()),
+        ]),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), index_v086.as_slice(), 86, want())?;
+
+    Ok(())
+}
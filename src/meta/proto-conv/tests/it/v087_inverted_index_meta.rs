@@ -0,0 +1,61 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use chrono::TimeZone;
+use chrono::Utc;
+use databend_common_meta_app::schema::IndexMeta;
+use databend_common_meta_app::schema::IndexType;
+use minitrace::func_name;
+
+use crate::common;
+
+// These bytes are built when a new version in introduced,
+// and are kept for backward compatibility test.
+//
+// *************************************************************
+// * These messages should never be updated,                   *
+// * only be added when a new version is added,                *
+// * or be removed when an old version is no longer supported. *
+// *************************************************************
+//
+// The message bytes are built from the output of `test_pb_from_to()`
+#[test]
+fn test_decode_v87_inverted_index() -> anyhow::Result<()> {
+    let index_v087 = vec![
+        8, 7, 16, 4, 26, 23, 50, 48, 50, 52, 45, 48, 50, 45, 50, 48, 32, 49, 48, 58, 48, 48, 58,
+        48, 48, 32, 85, 84, 67, 42, 16, 100, 101, 102, 97, 117, 108, 116, 46, 116, 49, 40, 98, 111,
+        100, 121, 41, 56, 1, 66, 16, 100, 101, 102, 97, 117, 108, 116, 46, 116, 49, 40, 98, 111,
+        100, 121, 41, 74, 14, 10, 6, 99, 111, 108, 117, 109, 110, 18, 4, 98, 111, 100, 121, 160, 6,
+        87, 168, 6, 24,
+    ];
+
+    let want = || IndexMeta {
+        table_id: 7,
+        index_type: IndexType::INVERTED,
+        created_on: Utc.with_ymd_and_hms(2024, 2, 20, 10, 0, 0).unwrap(),
+        dropped_on: None,
+        updated_on: None,
+        original_query: "default.t1(body)".to_string(),
+        query: "default.t1(body)".to_string(),
+        sync_creation: true,
+        options: BTreeMap::from([("column".to_string(), "body".to_string())]),
+    };
+
+    common::test_pb_from_to(func_name!(), want())?;
+    common::test_load_old(func_name!(), index_v087.as_slice(), 87, want())?;
+
+    Ok(())
+}
@@ -181,6 +181,8 @@ pub enum Expr {
     },
     /// A literal value, such as string, number, date or NULL
     Literal { span: Span, lit: Literal },
+    /// A `?` placeholder of a prepared statement, bound to a parameter on execution
+    Placeholder { span: Span },
     /// `COUNT(*)` expression
     CountAll { span: Span, window: Option<Window> },
     /// `(foo, bar)`
@@ -322,6 +324,7 @@ pub enum TypeName {
         fields_type: Vec<TypeName>,
     },
     Variant,
+    Geometry,
     Nullable(Box<TypeName>),
     NotNull(Box<TypeName>),
 }
@@ -575,6 +578,7 @@ impl Expr {
             | Expr::Substring { span, .. }
             | Expr::Trim { span, .. }
             | Expr::Literal { span, .. }
+            | Expr::Placeholder { span }
             | Expr::CountAll { span, .. }
             | Expr::Tuple { span, .. }
             | Expr::FunctionCall { span, .. }
@@ -899,6 +903,9 @@ impl Display for TypeName {
             TypeName::Variant => {
                 write!(f, "VARIANT")?;
             }
+            TypeName::Geometry => {
+                write!(f, "GEOMETRY")?;
+            }
             TypeName::Nullable(ty) => {
                 write!(f, "{} NULL", ty)?;
             }
@@ -1198,6 +1205,9 @@ impl Display for Expr {
             Expr::Literal { lit, .. } => {
                 write!(f, "{lit}")?;
             }
+            Expr::Placeholder { .. } => {
+                write!(f, "?")?;
+            }
             Expr::CountAll { window, .. } => {
                 write!(f, "COUNT(*)")?;
                 if let Some(window) = window {
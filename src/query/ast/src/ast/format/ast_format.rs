@@ -422,6 +422,13 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_placeholder(&mut self, _span: Span) {
+        let name = "Placeholder".to_string();
+        let format_ctx = AstFormatContext::new(name);
+        let node = FormatTreeNode::new(format_ctx);
+        self.children.push(node);
+    }
+
     fn visit_count_all(&mut self, _span: Span, _window: &'ast Option<Window>) {
         let name = "Function CountAll".to_string();
         let format_ctx = AstFormatContext::new(name);
@@ -1347,7 +1354,10 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 self.visit_expr(cluster_by);
                 cluster_by_children.push(self.children.pop().unwrap());
             }
-            let cluster_by_name = "ClusterByList".to_string();
+            let cluster_by_name = match stmt.cluster_type {
+                ClusterType::Linear => "ClusterByList".to_string(),
+                cluster_type => format!("ClusterByList {cluster_type}"),
+            };
             let cluster_by_format_ctx =
                 AstFormatContext::with_children(cluster_by_name, cluster_by_children.len());
             let cluster_by_node =
@@ -1383,12 +1393,17 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
 
     fn visit_create_table_source(&mut self, source: &'ast CreateTableSource) {
         match source {
-            CreateTableSource::Columns(columns) => {
-                let mut children = Vec::with_capacity(columns.len());
+            CreateTableSource::Columns(columns, constraints) => {
+                let mut children = Vec::with_capacity(columns.len() + constraints.len());
                 for column in columns.iter() {
                     self.visit_column_definition(column);
                     children.push(self.children.pop().unwrap());
                 }
+                for constraint in constraints.iter() {
+                    let name = format!("TableConstraint {}", constraint);
+                    let format_ctx = AstFormatContext::new(name);
+                    children.push(FormatTreeNode::new(format_ctx));
+                }
                 let name = "ColumnsDefinition".to_string();
                 let format_ctx = AstFormatContext::with_children(name, children.len());
                 let node = FormatTreeNode::with_children(format_ctx, children);
@@ -1506,13 +1521,19 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 let action_format_ctx = AstFormatContext::new(action_name);
                 FormatTreeNode::new(action_format_ctx)
             }
-            AlterTableAction::AlterTableClusterKey { cluster_by } => {
+            AlterTableAction::AlterTableClusterKey {
+                cluster_type,
+                cluster_by,
+            } => {
                 let mut cluster_by_children = Vec::with_capacity(cluster_by.len());
                 for cluster_by_expr in cluster_by.iter() {
                     self.visit_expr(cluster_by_expr);
                     cluster_by_children.push(self.children.pop().unwrap());
                 }
-                let cluster_by_name = "Action ClusterByList".to_string();
+                let cluster_by_name = match cluster_type {
+                    ClusterType::Linear => "Action ClusterByList".to_string(),
+                    cluster_type => format!("Action ClusterByList {cluster_type}"),
+                };
                 let cluster_by_format_ctx =
                     AstFormatContext::with_children(cluster_by_name, cluster_by_children.len());
                 FormatTreeNode::with_children(cluster_by_format_ctx, cluster_by_children)
@@ -1689,6 +1710,38 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_materialized_view(&mut self, stmt: &'ast CreateMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let view_child = self.children.pop().unwrap();
+        self.visit_query(&stmt.query);
+        let query_child = self.children.pop().unwrap();
+
+        let name = "CreateMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 2);
+        let node = FormatTreeNode::with_children(format_ctx, vec![view_child, query_child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_materialized_view(&mut self, stmt: &'ast DropMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let child = self.children.pop().unwrap();
+
+        let name = "DropMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_materialized_view(&mut self, stmt: &'ast RefreshMaterializedViewStmt) {
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.view);
+        let child = self.children.pop().unwrap();
+
+        let name = "RefreshMaterializedView".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
     fn visit_create_stream(&mut self, stmt: &'ast CreateStreamStmt) {
         let mut children = Vec::new();
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.stream);
@@ -1783,6 +1836,68 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.children.push(node);
     }
 
+    fn visit_create_vector_index(&mut self, stmt: &'ast CreateVectorIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+        self.visit_identifier(&stmt.column);
+        let column_child = self.children.pop().unwrap();
+
+        let name = "CreateVectorIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 3);
+        let node =
+            FormatTreeNode::with_children(format_ctx, vec![index_child, table_child, column_child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_vector_index(&mut self, stmt: &'ast RefreshVectorIndexStmt) {
+        let mut children = Vec::new();
+        self.visit_index_ref(&stmt.index);
+        children.push(self.children.pop().unwrap());
+        if let Some(limit) = stmt.limit {
+            let name = format!("Refresh index limit {}", limit);
+            let limit_format_ctx = AstFormatContext::new(name);
+            children.push(FormatTreeNode::new(limit_format_ctx));
+        }
+
+        let name = "RefreshVectorIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
+    fn visit_create_inverted_index(&mut self, stmt: &'ast CreateInvertedIndexStmt) {
+        self.visit_index_ref(&stmt.index_name);
+        let index_child = self.children.pop().unwrap();
+        self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
+        let table_child = self.children.pop().unwrap();
+        self.visit_identifier(&stmt.column);
+        let column_child = self.children.pop().unwrap();
+
+        let name = "CreateInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 3);
+        let node =
+            FormatTreeNode::with_children(format_ctx, vec![index_child, table_child, column_child]);
+        self.children.push(node);
+    }
+
+    fn visit_refresh_inverted_index(&mut self, stmt: &'ast RefreshInvertedIndexStmt) {
+        let mut children = Vec::new();
+        self.visit_index_ref(&stmt.index);
+        children.push(self.children.pop().unwrap());
+        if let Some(limit) = stmt.limit {
+            let name = format!("Refresh index limit {}", limit);
+            let limit_format_ctx = AstFormatContext::new(name);
+            children.push(FormatTreeNode::new(limit_format_ctx));
+        }
+
+        let name = "RefreshInvertedIndex".to_string();
+        let format_ctx = AstFormatContext::with_children(name, children.len());
+        let node = FormatTreeNode::with_children(format_ctx, children);
+        self.children.push(node);
+    }
+
     fn visit_create_virtual_column(&mut self, stmt: &'ast CreateVirtualColumnStmt) {
         self.visit_table_ref(&stmt.catalog, &stmt.database, &stmt.table);
         let table_child = self.children.pop().unwrap();
@@ -2163,6 +2278,39 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     AstFormatContext::new(format!("UdfServerAddress {address}"));
                 children.push(FormatTreeNode::new(address_format_ctx));
             }
+            UDFDefinition::WasmUDF {
+                arg_types,
+                return_type,
+                handler,
+                code,
+            } => {
+                if !arg_types.is_empty() {
+                    let mut arg_types_children = Vec::with_capacity(arg_types.len());
+                    for arg_type in arg_types.iter() {
+                        let type_format_ctx = AstFormatContext::new(format!("DataType {arg_type}"));
+                        arg_types_children.push(FormatTreeNode::new(type_format_ctx));
+                    }
+                    let arg_format_ctx = AstFormatContext::with_children(
+                        "UdfArgTypes".to_string(),
+                        arg_types_children.len(),
+                    );
+                    children.push(FormatTreeNode::with_children(
+                        arg_format_ctx,
+                        arg_types_children,
+                    ));
+                }
+
+                let return_type_format_ctx =
+                    AstFormatContext::new(format!("UdfReturnType {return_type}"));
+                children.push(FormatTreeNode::new(return_type_format_ctx));
+
+                let handler_format_ctx = AstFormatContext::new(format!("UdfWasmHandler {handler}"));
+                children.push(FormatTreeNode::new(handler_format_ctx));
+
+                let code_format_ctx =
+                    AstFormatContext::new(format!("UdfWasmModuleSize {}", code.len()));
+                children.push(FormatTreeNode::new(code_format_ctx));
+            }
         }
 
         if let Some(description) = &stmt.description {
@@ -2259,6 +2407,39 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     AstFormatContext::new(format!("UdfServerAddress {address}"));
                 children.push(FormatTreeNode::new(address_format_ctx));
             }
+            UDFDefinition::WasmUDF {
+                arg_types,
+                return_type,
+                handler,
+                code,
+            } => {
+                if !arg_types.is_empty() {
+                    let mut arg_types_children = Vec::with_capacity(arg_types.len());
+                    for arg_type in arg_types.iter() {
+                        let type_format_ctx = AstFormatContext::new(format!("DataType {arg_type}"));
+                        arg_types_children.push(FormatTreeNode::new(type_format_ctx));
+                    }
+                    let arg_format_ctx = AstFormatContext::with_children(
+                        "UdfArgTypes".to_string(),
+                        arg_types_children.len(),
+                    );
+                    children.push(FormatTreeNode::with_children(
+                        arg_format_ctx,
+                        arg_types_children,
+                    ));
+                }
+
+                let return_type_format_ctx =
+                    AstFormatContext::new(format!("UdfReturnType {return_type}"));
+                children.push(FormatTreeNode::new(return_type_format_ctx));
+
+                let handler_format_ctx = AstFormatContext::new(format!("UdfWasmHandler {handler}"));
+                children.push(FormatTreeNode::new(handler_format_ctx));
+
+                let code_format_ctx =
+                    AstFormatContext::new(format!("UdfWasmModuleSize {}", code.len()));
+                children.push(FormatTreeNode::new(code_format_ctx));
+            }
         }
 
         if let Some(description) = &stmt.description {
@@ -2655,6 +2836,40 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
         self.visit_show_options(show_options, "ShowPasswordPolicies".to_string());
     }
 
+    fn visit_create_workload_group(&mut self, stmt: &'ast CreateWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "CreateWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_alter_workload_group(&mut self, stmt: &'ast AlterWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "AlterWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_drop_workload_group(&mut self, stmt: &'ast DropWorkloadGroupStmt) {
+        let ctx = AstFormatContext::new(format!("WorkloadGroupName {}", stmt.name));
+        let child = FormatTreeNode::new(ctx);
+
+        let name = "DropWorkloadGroup".to_string();
+        let format_ctx = AstFormatContext::with_children(name, 1);
+        let node = FormatTreeNode::with_children(format_ctx, vec![child]);
+        self.children.push(node);
+    }
+
+    fn visit_show_workload_groups(&mut self, show_options: &'ast Option<ShowOptions>) {
+        self.visit_show_options(show_options, "ShowWorkloadGroups".to_string());
+    }
+
     fn visit_with(&mut self, with: &'ast With) {
         let mut children = Vec::with_capacity(with.ctes.len());
         for cte in with.ctes.iter() {
@@ -2925,6 +3140,7 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                 table,
                 alias,
                 travel_point,
+                changes,
                 pivot,
                 unpivot,
             } => {
@@ -2950,11 +3166,20 @@ impl<'ast> Visitor<'ast> for AstFormatVisitor {
                     name.push_str(&unpivot.to_string());
                 }
 
+                if let Some(changes) = changes {
+                    name.push(' ');
+                    name.push_str(&changes.to_string());
+                }
+
                 let mut children = Vec::new();
                 if let Some(travel_point) = travel_point {
                     self.visit_time_travel_point(travel_point);
                     children.push(self.children.pop().unwrap());
                 }
+                if let Some(end_point) = changes.as_ref().and_then(|c| c.end_point.as_ref()) {
+                    self.visit_time_travel_point(end_point);
+                    children.push(self.children.pop().unwrap());
+                }
                 let format_ctx = if let Some(alias) = alias {
                     AstFormatContext::with_children_alias(
                         name,
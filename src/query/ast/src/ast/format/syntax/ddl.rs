@@ -85,7 +85,7 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
         })
         .append(if !stmt.cluster_by.is_empty() {
             RcDoc::line()
-                .append(RcDoc::text("CLUSTER BY "))
+                .append(RcDoc::text(format!("CLUSTER BY {}", stmt.cluster_type)))
                 .append(parenthesized(
                     interweave_comma(stmt.cluster_by.into_iter().map(pretty_expr)).group(),
                 ))
@@ -120,11 +120,16 @@ pub(crate) fn pretty_create_table(stmt: CreateTableStmt) -> RcDoc<'static> {
 
 fn pretty_table_source(source: CreateTableSource) -> RcDoc<'static> {
     match source {
-        CreateTableSource::Columns(columns) => RcDoc::space().append(parenthesized(
+        CreateTableSource::Columns(columns, constraints) => RcDoc::space().append(parenthesized(
             interweave_comma(
                 columns
                     .into_iter()
-                    .map(|column| RcDoc::text(column.to_string())),
+                    .map(|column| RcDoc::text(column.to_string()))
+                    .chain(
+                        constraints
+                            .into_iter()
+                            .map(|constraint| RcDoc::text(constraint.to_string())),
+                    ),
             )
             .group(),
         )),
@@ -194,8 +199,11 @@ pub(crate) fn pretty_alter_table_action(action: AlterTableAction) -> RcDoc<'stat
         AlterTableAction::DropColumn { column } => RcDoc::line()
             .append(RcDoc::text("DROP COLUMN "))
             .append(RcDoc::text(column.to_string())),
-        AlterTableAction::AlterTableClusterKey { cluster_by } => RcDoc::line()
-            .append(RcDoc::text("CLUSTER BY "))
+        AlterTableAction::AlterTableClusterKey {
+            cluster_type,
+            cluster_by,
+        } => RcDoc::line()
+            .append(RcDoc::text(format!("CLUSTER BY {cluster_type}")))
             .append(parenthesized(
                 interweave_comma(cluster_by.into_iter().map(pretty_expr)).group(),
             )),
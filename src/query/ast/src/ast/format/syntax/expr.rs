@@ -238,6 +238,7 @@ pub(crate) fn pretty_expr(expr: Expr) -> RcDoc<'static> {
             .append(pretty_expr(*expr))
             .append(RcDoc::text(")")),
         Expr::Literal { lit, .. } => RcDoc::text(lit.to_string()),
+        Expr::Placeholder { .. } => RcDoc::text("?"),
         Expr::CountAll { window, .. } => {
             RcDoc::text("COUNT(*)").append(if let Some(window) = window {
                 RcDoc::text(" OVER (")
@@ -320,6 +320,7 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
             table,
             alias,
             travel_point,
+            changes,
             pivot,
             unpivot,
         } => if let Some(catalog) = catalog {
@@ -343,6 +344,11 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
         } else {
             RcDoc::nil()
         })
+        .append(if let Some(changes) = changes {
+            RcDoc::text(format!(" {changes}"))
+        } else {
+            RcDoc::nil()
+        })
         .append(if let Some(TimeTravelPoint::Snapshot(sid)) = travel_point {
             RcDoc::text(format!(" AT (SNAPSHOT => {sid})"))
         } else if let Some(TimeTravelPoint::Timestamp(ts)) = travel_point {
@@ -350,6 +356,13 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
         } else {
             RcDoc::nil()
         })
+        .append(
+            if let Some(end_point) = changes.as_ref().and_then(|c| c.end_point.as_ref()) {
+                RcDoc::text(format!(" END{end_point}"))
+            } else {
+                RcDoc::nil()
+            },
+        )
         .append(if let Some(alias) = alias {
             RcDoc::text(format!(" AS {alias}"))
         } else {
@@ -422,8 +435,17 @@ pub(crate) fn pretty_table(table: TableReference) -> RcDoc<'static> {
                 JoinOperator::RightAnti => RcDoc::text("RIGHT ANTI JOIN"),
                 JoinOperator::LeftSemi => RcDoc::text("LEFT SEMI JOIN"),
                 JoinOperator::RightSemi => RcDoc::text("RIGHT SEMI JOIN"),
+                JoinOperator::Asof => RcDoc::text("ASOF JOIN"),
+                JoinOperator::LeftAsof => RcDoc::text("ASOF LEFT JOIN"),
             })
             .append(RcDoc::space().append(pretty_table(*join.right)))
+            .append(match join.match_condition {
+                Some(expr) => RcDoc::space()
+                    .append(RcDoc::text("MATCH_CONDITION("))
+                    .append(pretty_expr(*expr))
+                    .append(RcDoc::text(")")),
+                None => RcDoc::nil(),
+            })
             .append(match &join.condition {
                 JoinCondition::On(expr) => RcDoc::space()
                     .append(RcDoc::text("ON"))
@@ -245,6 +245,13 @@ pub enum TimeTravelPoint {
     Timestamp(Box<Expr>),
 }
 
+/// Change tracking specification: `CHANGES(INFORMATION => {DEFAULT | APPEND_ONLY}) AT(...) [END(...)]`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChangesInterval {
+    pub append_only: bool,
+    pub end_point: Option<TimeTravelPoint>,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct Pivot {
     pub aggregate: Expr,
@@ -270,6 +277,8 @@ pub enum TableReference {
         table: Identifier,
         alias: Option<TableAlias>,
         travel_point: Option<TimeTravelPoint>,
+        /// `CHANGES(INFORMATION => ...)` clause, the start point is taken from `travel_point`
+        changes: Option<ChangesInterval>,
         pivot: Option<Box<Pivot>>,
         unpivot: Option<Box<Unpivot>>,
     },
@@ -336,6 +345,8 @@ pub struct TableAlias {
 pub struct Join {
     pub op: JoinOperator,
     pub condition: JoinCondition,
+    // The inequality of ASOF joins, e.g. `MATCH_CONDITION(t.ts >= q.ts)`
+    pub match_condition: Option<Box<Expr>>,
     pub left: Box<TableReference>,
     pub right: Box<TableReference>,
 }
@@ -353,6 +364,9 @@ pub enum JoinOperator {
     RightAnti,
     // CrossJoin can only work with `JoinCondition::None`
     CrossJoin,
+    // Asof joins must have a match condition
+    Asof,
+    LeftAsof,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -454,6 +468,7 @@ impl Display for TableReference {
                 table,
                 alias,
                 travel_point,
+                changes,
                 pivot,
                 unpivot,
             } => {
@@ -462,6 +477,10 @@ impl Display for TableReference {
                     catalog.iter().chain(database.iter()).chain(Some(table)),
                 )?;
 
+                if let Some(changes) = changes {
+                    write!(f, " {changes}")?;
+                }
+
                 if let Some(TimeTravelPoint::Snapshot(sid)) = travel_point {
                     write!(f, " AT (SNAPSHOT => {sid})")?;
                 }
@@ -470,6 +489,10 @@ impl Display for TableReference {
                     write!(f, " AT (TIMESTAMP => {ts})")?;
                 }
 
+                if let Some(end_point) = changes.as_ref().and_then(|c| c.end_point.as_ref()) {
+                    write!(f, " END{end_point}")?;
+                }
+
                 if let Some(alias) = alias {
                     write!(f, " AS {alias}")?;
                 }
@@ -555,8 +578,17 @@ impl Display for TableReference {
                     JoinOperator::CrossJoin => {
                         write!(f, " CROSS JOIN")?;
                     }
+                    JoinOperator::Asof => {
+                        write!(f, " ASOF JOIN")?;
+                    }
+                    JoinOperator::LeftAsof => {
+                        write!(f, " ASOF LEFT JOIN")?;
+                    }
                 }
                 write!(f, " {}", join.right)?;
+                if let Some(match_condition) = &join.match_condition {
+                    write!(f, " MATCH_CONDITION({match_condition})")?;
+                }
                 match &join.condition {
                     JoinCondition::On(expr) => {
                         write!(f, " ON {expr}")?;
@@ -796,6 +828,16 @@ impl Display for Query {
     }
 }
 
+impl Display for ChangesInterval {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        if self.append_only {
+            write!(f, "CHANGES (INFORMATION => APPEND_ONLY)")
+        } else {
+            write!(f, "CHANGES (INFORMATION => DEFAULT)")
+        }
+    }
+}
+
 impl Display for TimeTravelPoint {
     fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -134,6 +134,7 @@ pub struct CreateTableStmt {
     pub source: Option<CreateTableSource>,
     pub engine: Option<Engine>,
     pub uri_location: Option<UriLocation>,
+    pub cluster_type: ClusterType,
     pub cluster_by: Vec<Expr>,
     pub table_options: BTreeMap<String, String>,
     pub as_query: Option<Box<Query>>,
@@ -176,7 +177,7 @@ impl Display for CreateTableStmt {
         }
 
         if !self.cluster_by.is_empty() {
-            write!(f, " CLUSTER BY (")?;
+            write!(f, " CLUSTER BY {}(", self.cluster_type)?;
             write_comma_separated_list(f, &self.cluster_by)?;
             write!(f, ")")?
         }
@@ -223,7 +224,7 @@ impl Display for AttachTableStmt {
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum CreateTableSource {
-    Columns(Vec<ColumnDefinition>),
+    Columns(Vec<ColumnDefinition>, Vec<TableConstraint>),
     Like {
         catalog: Option<Identifier>,
         database: Option<Identifier>,
@@ -234,9 +235,13 @@ pub enum CreateTableSource {
 impl Display for CreateTableSource {
     fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
         match self {
-            CreateTableSource::Columns(columns) => {
+            CreateTableSource::Columns(columns, constraints) => {
                 write!(f, "(")?;
                 write_comma_separated_list(f, columns)?;
+                if !constraints.is_empty() {
+                    write!(f, ", ")?;
+                    write_comma_separated_list(f, constraints)?;
+                }
                 write!(f, ")")
             }
             CreateTableSource::Like {
@@ -358,6 +363,7 @@ pub enum AlterTableAction {
         column: Identifier,
     },
     AlterTableClusterKey {
+        cluster_type: ClusterType,
         cluster_by: Vec<Expr>,
     },
     DropTableClusterKey,
@@ -400,9 +406,18 @@ impl Display for AlterTableAction {
             AlterTableAction::DropColumn { column } => {
                 write!(f, "DROP COLUMN {column}")?;
             }
-            AlterTableAction::AlterTableClusterKey { cluster_by } => {
-                write!(f, "CLUSTER BY ")?;
-                write_comma_separated_list(f, cluster_by)?;
+            AlterTableAction::AlterTableClusterKey {
+                cluster_type,
+                cluster_by,
+            } => {
+                if *cluster_type == ClusterType::Linear {
+                    write!(f, "CLUSTER BY ")?;
+                    write_comma_separated_list(f, cluster_by)?;
+                } else {
+                    write!(f, "CLUSTER BY {cluster_type}(")?;
+                    write_comma_separated_list(f, cluster_by)?;
+                    write!(f, ")")?;
+                }
             }
             AlterTableAction::DropTableClusterKey => {
                 write!(f, "DROP CLUSTER KEY")?;
@@ -644,6 +659,29 @@ impl Display for Engine {
     }
 }
 
+/// The layout used to sort the rows by the cluster keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ClusterType {
+    /// Sort on the key tuple, the first key dominates the order.
+    #[default]
+    Linear,
+    /// Sort on the Hilbert curve index of the keys.
+    Hilbert,
+    /// Sort on the Z-order (Morton) index of the keys.
+    ZOrder,
+}
+
+impl Display for ClusterType {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            // Linear is the default layout and is written without a keyword.
+            ClusterType::Linear => Ok(()),
+            ClusterType::Hilbert => write!(f, "HILBERT"),
+            ClusterType::ZOrder => write!(f, "ZORDER"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum CompactTarget {
     Block,
@@ -766,6 +804,66 @@ impl Display for ColumnDefinition {
     }
 }
 
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableConstraint {
+    pub name: Option<Identifier>,
+    pub kind: TableConstraintKind,
+}
+
+impl Display for TableConstraint {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(name) = &self.name {
+            write!(f, "CONSTRAINT {name} ")?;
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TableConstraintKind {
+    Check(Expr),
+    PrimaryKey(Vec<Identifier>),
+    Unique(Vec<Identifier>),
+    ForeignKey {
+        columns: Vec<Identifier>,
+        ref_database: Option<Identifier>,
+        ref_table: Identifier,
+        ref_columns: Vec<Identifier>,
+    },
+}
+
+impl Display for TableConstraintKind {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            TableConstraintKind::Check(expr) => write!(f, "CHECK ({expr})"),
+            TableConstraintKind::PrimaryKey(columns) => {
+                write!(f, "PRIMARY KEY (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            TableConstraintKind::Unique(columns) => {
+                write!(f, "UNIQUE (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ")")
+            }
+            TableConstraintKind::ForeignKey {
+                columns,
+                ref_database,
+                ref_table,
+                ref_columns,
+            } => {
+                write!(f, "FOREIGN KEY (")?;
+                write_comma_separated_list(f, columns)?;
+                write!(f, ") REFERENCES ")?;
+                write_dot_separated_list(f, ref_database.iter().chain(Some(ref_table)))?;
+                write!(f, " (")?;
+                write_comma_separated_list(f, ref_columns)?;
+                write!(f, ")")
+            }
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ModifyColumnAction {
     // (column name id, masking policy name)
@@ -35,6 +35,13 @@ pub enum UDFDefinition {
         handler: String,
         language: String,
     },
+    WasmUDF {
+        arg_types: Vec<TypeName>,
+        return_type: TypeName,
+        handler: String,
+        /// The module as WAT text or base64-encoded WebAssembly binary.
+        code: String,
+    },
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -77,6 +84,19 @@ impl Display for UDFDefinition {
                     ") RETURNS {return_type} LANGUAGE {language} HANDLER = {handler} ADDRESS = {address}"
                 )?;
             }
+            UDFDefinition::WasmUDF {
+                arg_types,
+                return_type,
+                handler,
+                code,
+            } => {
+                write!(f, "(")?;
+                write_comma_separated_list(f, arg_types)?;
+                write!(
+                    f,
+                    ") RETURNS {return_type} LANGUAGE wasm HANDLER = '{handler}' AS '{code}'"
+                )?;
+            }
         }
         Ok(())
     }
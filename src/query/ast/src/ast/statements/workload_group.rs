@@ -0,0 +1,194 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct CreateWorkloadGroupStmt {
+    pub if_not_exists: bool,
+    pub name: String,
+    pub set_options: WorkloadGroupSetOptions,
+}
+
+impl Display for CreateWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "CREATE WORKLOAD GROUP ")?;
+        if self.if_not_exists {
+            write!(f, "IF NOT EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+        write!(f, "{}", self.set_options)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlterWorkloadGroupStmt {
+    pub if_exists: bool,
+    pub name: String,
+    pub action: AlterWorkloadGroupAction,
+}
+
+impl Display for AlterWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "ALTER WORKLOAD GROUP ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{} ", self.name)?;
+        write!(f, "{}", self.action)?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum AlterWorkloadGroupAction {
+    SetOptions(WorkloadGroupSetOptions),
+    UnSetOptions(WorkloadGroupUnSetOptions),
+}
+
+impl Display for AlterWorkloadGroupAction {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        match self {
+            AlterWorkloadGroupAction::SetOptions(set_options) => {
+                write!(f, "SET{}", set_options)?;
+            }
+            AlterWorkloadGroupAction::UnSetOptions(unset_options) => {
+                write!(f, "UNSET{}", unset_options)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct WorkloadGroupSetOptions {
+    pub max_concurrency: Option<u64>,
+    pub max_queued: Option<u64>,
+    pub queue_timeout_secs: Option<u64>,
+    pub memory_percentage: Option<u64>,
+    pub cpu_percentage: Option<u64>,
+    pub users: Option<Vec<String>>,
+    pub roles: Option<Vec<String>>,
+    pub comment: Option<String>,
+}
+
+impl Display for WorkloadGroupSetOptions {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if let Some(max_concurrency) = self.max_concurrency {
+            write!(f, " MAX_CONCURRENCY = {}", max_concurrency)?;
+        }
+        if let Some(max_queued) = self.max_queued {
+            write!(f, " MAX_QUEUED = {}", max_queued)?;
+        }
+        if let Some(queue_timeout_secs) = self.queue_timeout_secs {
+            write!(f, " QUEUE_TIMEOUT = {}", queue_timeout_secs)?;
+        }
+        if let Some(memory_percentage) = self.memory_percentage {
+            write!(f, " MEMORY_PERCENTAGE = {}", memory_percentage)?;
+        }
+        if let Some(cpu_percentage) = self.cpu_percentage {
+            write!(f, " CPU_PERCENTAGE = {}", cpu_percentage)?;
+        }
+        if let Some(users) = &self.users {
+            write!(f, " USERS = (")?;
+            for (i, name) in users.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ",")?;
+                }
+                write!(f, "'{}'", name)?;
+            }
+            write!(f, ")")?;
+        }
+        if let Some(roles) = &self.roles {
+            write!(f, " ROLES = (")?;
+            for (i, name) in roles.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ",")?;
+                }
+                write!(f, "'{}'", name)?;
+            }
+            write!(f, ")")?;
+        }
+        if let Some(comment) = &self.comment {
+            write!(f, " COMMENT = '{}'", comment)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkloadGroupUnSetOptions {
+    pub max_concurrency: bool,
+    pub max_queued: bool,
+    pub queue_timeout_secs: bool,
+    pub memory_percentage: bool,
+    pub cpu_percentage: bool,
+    pub users: bool,
+    pub roles: bool,
+    pub comment: bool,
+}
+
+impl Display for WorkloadGroupUnSetOptions {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        if self.max_concurrency {
+            write!(f, " MAX_CONCURRENCY")?;
+        }
+        if self.max_queued {
+            write!(f, " MAX_QUEUED")?;
+        }
+        if self.queue_timeout_secs {
+            write!(f, " QUEUE_TIMEOUT")?;
+        }
+        if self.memory_percentage {
+            write!(f, " MEMORY_PERCENTAGE")?;
+        }
+        if self.cpu_percentage {
+            write!(f, " CPU_PERCENTAGE")?;
+        }
+        if self.users {
+            write!(f, " USERS")?;
+        }
+        if self.roles {
+            write!(f, " ROLES")?;
+        }
+        if self.comment {
+            write!(f, " COMMENT")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DropWorkloadGroupStmt {
+    pub if_exists: bool,
+    pub name: String,
+}
+
+impl Display for DropWorkloadGroupStmt {
+    fn fmt(&self, f: &mut Formatter) -> std::fmt::Result {
+        write!(f, "DROP WORKLOAD GROUP ")?;
+        if self.if_exists {
+            write!(f, "IF EXISTS ")?;
+        }
+        write!(f, "{}", self.name)?;
+
+        Ok(())
+    }
+}
@@ -127,6 +127,18 @@ pub fn subexpr(min_precedence: u32) -> impl FnMut(Input) -> IResult<Expr> {
                     };
                 }
 
+                // replace `?` json operator to a placeholder of prepared statement, ...
+                if let ExprElement::JsonOp {
+                    op: JsonOperator::Question,
+                } = &expr_elements[curr as usize].elem
+                {
+                    let span = expr_elements[curr as usize].span;
+                    expr_elements[curr as usize] = WithSpan {
+                        span,
+                        elem: ExprElement::Placeholder,
+                    };
+                }
+
                 // and replace `.<number>` map access to floating point literal.
                 if let ExprElement::MapAccess {
                     accessor: MapAccessor::DotNumber { .. },
@@ -251,6 +263,8 @@ pub enum ExprElement {
     Literal {
         lit: Literal,
     },
+    /// A `?` placeholder of a prepared statement
+    Placeholder,
     /// `Count(*)` expression
     CountAll {
         window: Option<Window>,
@@ -474,6 +488,9 @@ impl<'a, I: Iterator<Item = WithSpan<'a, ExprElement>>> PrattParser<I> for ExprP
                 span: transform_span(elem.span.0),
                 lit,
             },
+            ExprElement::Placeholder => Expr::Placeholder {
+                span: transform_span(elem.span.0),
+            },
             ExprElement::CountAll { window } => Expr::CountAll {
                 span: transform_span(elem.span.0),
                 window,
@@ -1582,6 +1599,7 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
         rule! { ( STRING | VARCHAR | CHAR | CHARACTER | TEXT ) ~ ( "(" ~ ^#literal_u64 ~ ^")" )? },
     );
     let ty_variant = value(TypeName::Variant, rule! { VARIANT | JSON });
+    let ty_geometry = value(TypeName::Geometry, rule! { GEOMETRY });
     map_res(
         alt((
             rule! {
@@ -1610,6 +1628,7 @@ pub fn type_name(i: Input) -> IResult<TypeName> {
             | #ty_binary
             | #ty_string
             | #ty_variant
+            | #ty_geometry
             | #ty_nullable
             ) ~ #nullable? : "type name" },
         )),
@@ -30,6 +30,7 @@ pub use parser::parse_comma_separated_exprs;
 pub use parser::parse_comma_separated_idents;
 pub use parser::parse_expr;
 pub use parser::parse_sql;
+pub use parser::parse_values;
 pub use parser::parser_values_with_placeholder;
 pub use parser::run_parser;
 pub use parser::tokenize_sql;
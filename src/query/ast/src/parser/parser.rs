@@ -14,6 +14,7 @@
 
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use nom::combinator::map;
 
 use crate::ast::Expr;
 use crate::ast::Identifier;
@@ -23,10 +24,12 @@ use crate::input::Dialect;
 use crate::input::Input;
 use crate::parser::expr::expr;
 use crate::parser::expr::values_with_placeholder;
+use crate::parser::query::row_values;
 use crate::parser::statement::statement;
 use crate::parser::token::Token;
 use crate::parser::token::TokenKind;
 use crate::parser::token::Tokenizer;
+use crate::rule;
 use crate::util::comma_separated_list0;
 use crate::util::comma_separated_list1;
 use crate::util::ident;
@@ -65,6 +68,16 @@ pub fn parse_comma_separated_idents(
     })
 }
 
+/// Parse the rows of `INSERT INTO ... VALUES`.
+pub fn parse_values(sql_tokens: &[Token], dialect: Dialect) -> Result<Vec<Vec<Expr>>> {
+    run_parser(sql_tokens, dialect, false, |i| {
+        map(
+            rule! { #comma_separated_list1(row_values) ~ ";"? },
+            |(rows, _)| rows,
+        )(i)
+    })
+}
+
 pub fn parser_values_with_placeholder(
     sql_tokens: &[Token],
     dialect: Dialect,
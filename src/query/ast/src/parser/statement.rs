@@ -324,6 +324,12 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let begin = value(Statement::Begin, rule! {
+        ( BEGIN ~ TRANSACTION? | START ~ TRANSACTION )
+    });
+    let commit = value(Statement::Commit, rule! { ( COMMIT ~ WORK? | END ) });
+    let abort = value(Statement::Abort, rule! { ( ROLLBACK ~ WORK? | ABORT ) });
+
     let show_settings = map(
         rule! {
             SHOW ~ SETTINGS ~ #show_options?
@@ -711,7 +717,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             ~ #create_table_source?
             ~ ( #engine )?
             ~ ( #uri_location )?
-            ~ ( CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
+            ~ ( CLUSTER ~ ^BY ~ #cluster_type? ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" )?
             ~ ( #table_option )?
             ~ ( AS ~ ^#query )?
         },
@@ -739,8 +745,12 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
                 source,
                 engine,
                 uri_location,
+                cluster_type: opt_cluster_by
+                    .as_ref()
+                    .and_then(|(_, _, cluster_type, _, _, _)| *cluster_type)
+                    .unwrap_or_default(),
                 cluster_by: opt_cluster_by
-                    .map(|(_, _, _, exprs, _)| exprs)
+                    .map(|(_, _, _, _, exprs, _)| exprs)
                     .unwrap_or_default(),
                 table_options: opt_table_options.unwrap_or_default(),
                 as_query: opt_as_query.map(|(_, query)| Box::new(query)),
@@ -942,6 +952,48 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let create_materialized_view = map(
+        rule! {
+            CREATE ~ MATERIALIZED ~ VIEW ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #dot_separated_idents_1_to_3
+            ~ AS ~ #query
+        },
+        |(_, _, _, opt_if_not_exists, (catalog, database, view), _, query)| {
+            Statement::CreateMaterializedView(CreateMaterializedViewStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                catalog,
+                database,
+                view,
+                query: Box::new(query),
+            })
+        },
+    );
+    let drop_materialized_view = map(
+        rule! {
+            DROP ~ MATERIALIZED ~ VIEW ~ ( IF ~ ^EXISTS )? ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, opt_if_exists, (catalog, database, view))| {
+            Statement::DropMaterializedView(DropMaterializedViewStmt {
+                if_exists: opt_if_exists.is_some(),
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+    let refresh_materialized_view = map(
+        rule! {
+            REFRESH ~ MATERIALIZED ~ VIEW ~ #dot_separated_idents_1_to_3
+        },
+        |(_, _, _, (catalog, database, view))| {
+            Statement::RefreshMaterializedView(RefreshMaterializedViewStmt {
+                catalog,
+                database,
+                view,
+            })
+        },
+    );
+
     let create_index = map(
         rule! {
             CREATE ~ ASYNC? ~ AGGREGATING ~ INDEX ~ ( IF ~ ^NOT ~ ^EXISTS )?
@@ -983,6 +1035,119 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         },
     );
 
+    let create_vector_index = map(
+        rule! {
+            CREATE ~ ASYNC? ~ VECTOR ~ INDEX ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+            ~ "(" ~ #ident ~ ")"
+            ~ #table_option
+        },
+        |(
+            _,
+            opt_async,
+            _,
+            _,
+            opt_if_not_exists,
+            index_name,
+            _,
+            (catalog, database, table),
+            _,
+            column,
+            _,
+            index_options,
+        )| {
+            Statement::CreateVectorIndex(CreateVectorIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+                column,
+                index_options,
+                sync_creation: opt_async.is_none(),
+            })
+        },
+    );
+
+    let drop_vector_index = map(
+        rule! {
+            DROP ~ VECTOR ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, index)| {
+            Statement::DropIndex(DropIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index,
+            })
+        },
+    );
+
+    let refresh_vector_index = map(
+        rule! {
+            REFRESH ~ VECTOR ~ INDEX ~ #ident ~ ( LIMIT ~ #literal_u64 )?
+        },
+        |(_, _, _, index, opt_limit)| {
+            Statement::RefreshVectorIndex(RefreshVectorIndexStmt {
+                index,
+                limit: opt_limit.map(|(_, limit)| limit),
+            })
+        },
+    );
+
+    let create_inverted_index = map(
+        rule! {
+            CREATE ~ ASYNC? ~ INVERTED ~ INDEX ~ ( IF ~ ^NOT ~ ^EXISTS )?
+            ~ #ident ~ ON ~ #dot_separated_idents_1_to_3
+            ~ "(" ~ #ident ~ ")"
+        },
+        |(
+            _,
+            opt_async,
+            _,
+            _,
+            opt_if_not_exists,
+            index_name,
+            _,
+            (catalog, database, table),
+            _,
+            column,
+            _,
+        )| {
+            Statement::CreateInvertedIndex(CreateInvertedIndexStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                index_name,
+                catalog,
+                database,
+                table,
+                column,
+                sync_creation: opt_async.is_none(),
+            })
+        },
+    );
+
+    let drop_inverted_index = map(
+        rule! {
+            DROP ~ INVERTED ~ INDEX ~ ( IF ~ ^EXISTS )? ~ #ident
+        },
+        |(_, _, _, opt_if_exists, index)| {
+            Statement::DropIndex(DropIndexStmt {
+                if_exists: opt_if_exists.is_some(),
+                index,
+            })
+        },
+    );
+
+    let refresh_inverted_index = map(
+        rule! {
+            REFRESH ~ INVERTED ~ INDEX ~ #ident ~ ( LIMIT ~ #literal_u64 )?
+        },
+        |(_, _, _, index, opt_limit)| {
+            Statement::RefreshInvertedIndex(RefreshInvertedIndexStmt {
+                index,
+                limit: opt_limit.map(|(_, limit)| limit),
+            })
+        },
+    );
+
     let create_virtual_column = map(
         rule! {
             CREATE ~ VIRTUAL ~ COLUMN ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")" ~ FOR ~ #dot_separated_idents_1_to_3
@@ -1747,6 +1912,53 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
         |(_, _, _, show_options)| Statement::ShowPasswordPolicies { show_options },
     );
 
+    let create_workload_group = map(
+        rule! {
+            CREATE ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^NOT ~ ^EXISTS )? ~ ^#ident
+             ~ #workload_group_set_options
+        },
+        |(_, _, _, opt_if_not_exists, name, set_options)| {
+            let stmt = CreateWorkloadGroupStmt {
+                if_not_exists: opt_if_not_exists.is_some(),
+                name: name.to_string(),
+                set_options,
+            };
+            Statement::CreateWorkloadGroup(stmt)
+        },
+    );
+    let alter_workload_group = map(
+        rule! {
+            ALTER ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^EXISTS )? ~ ^#ident
+             ~ #alter_workload_group_action
+        },
+        |(_, _, _, opt_if_exists, name, action)| {
+            let stmt = AlterWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+                action,
+            };
+            Statement::AlterWorkloadGroup(stmt)
+        },
+    );
+    let drop_workload_group = map(
+        rule! {
+            DROP ~ WORKLOAD ~ ^GROUP ~ ( IF ~ ^EXISTS )? ~ ^#ident
+        },
+        |(_, _, _, opt_if_exists, name)| {
+            let stmt = DropWorkloadGroupStmt {
+                if_exists: opt_if_exists.is_some(),
+                name: name.to_string(),
+            };
+            Statement::DropWorkloadGroup(stmt)
+        },
+    );
+    let show_workload_groups = map(
+        rule! {
+            SHOW ~ WORKLOAD ~ ^GROUPS ~ ^#show_options?
+        },
+        |(_, _, _, show_options)| Statement::ShowWorkloadGroups { show_options },
+    );
+
     let create_pipe = map(
         rule! {
             CREATE ~ PIPE ~ ( IF ~ ^NOT ~ ^EXISTS )?
@@ -1836,7 +2048,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #alter_database : "`ALTER DATABASE [IF EXISTS] <action>`"
             | #use_database : "`USE <database>`"
         ),
-        // network policy / password policy
+        // network policy / password policy / workload group
         rule!(
             #create_network_policy: "`CREATE NETWORK POLICY [IF NOT EXISTS] name ALLOWED_IP_LIST = ('ip1' [, 'ip2']) [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
             | #alter_network_policy: "`ALTER NETWORK POLICY [IF EXISTS] name SET [ALLOWED_IP_LIST = ('ip1' [, 'ip2'])] [BLOCKED_IP_LIST = ('ip1' [, 'ip2'])] [COMMENT = '<string_literal>']`"
@@ -1848,6 +2060,10 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #drop_password_policy: "`DROP PASSWORD POLICY [IF EXISTS] name`"
             | #describe_password_policy: "`DESC PASSWORD POLICY name`"
             | #show_password_policies: "`SHOW PASSWORD POLICIES [<show_options>]`"
+            | #create_workload_group: "`CREATE WORKLOAD GROUP [IF NOT EXISTS] name [MAX_CONCURRENCY = <u64_literal>] ... [COMMENT = '<string_literal>']`"
+            | #alter_workload_group: "`ALTER WORKLOAD GROUP [IF EXISTS] name { SET [MAX_CONCURRENCY = <u64_literal>] ... | UNSET [MAX_CONCURRENCY] ... }`"
+            | #drop_workload_group: "`DROP WORKLOAD GROUP [IF EXISTS] name`"
+            | #show_workload_groups: "`SHOW WORKLOAD GROUPS [<show_options>]`"
         ),
         rule!(
             #insert : "`INSERT INTO [TABLE] <table> [(<column>, ...)] (FORMAT <format> | VALUES <values> | <query>)`"
@@ -1855,6 +2071,9 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #merge : "`MERGE INTO <target_table> USING <source> ON <join_expr> { matchedClause | notMatchedClause } [ ... ]`"
             | #delete : "`DELETE FROM <table> [WHERE ...]`"
             | #update : "`UPDATE <table> SET <column> = <expr> [, <column> = <expr> , ... ] [WHERE ...]`"
+            | #begin : "`BEGIN [TRANSACTION]`"
+            | #commit : "`COMMIT [WORK]`"
+            | #abort : "`ROLLBACK [WORK]`"
         ),
         rule!(
             #set_variable : "`SET <variable> = <value>`"
@@ -1887,10 +2106,19 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             #create_view : "`CREATE VIEW [IF NOT EXISTS] [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
             | #drop_view : "`DROP VIEW [IF EXISTS] [<database>.]<view>`"
             | #alter_view : "`ALTER VIEW [<database>.]<view> [(<column>, ...)] AS SELECT ...`"
+            | #create_materialized_view : "`CREATE MATERIALIZED VIEW [IF NOT EXISTS] [<database>.]<view> AS SELECT ...`"
+            | #drop_materialized_view : "`DROP MATERIALIZED VIEW [IF EXISTS] [<database>.]<view>`"
+            | #refresh_materialized_view : "`REFRESH MATERIALIZED VIEW [<database>.]<view>`"
             | #stream_table
             | #create_index: "`CREATE AGGREGATING INDEX [IF NOT EXISTS] <index> AS SELECT ...`"
             | #drop_index: "`DROP AGGREGATING INDEX [IF EXISTS] <index>`"
             | #refresh_index: "`REFRESH AGGREGATING INDEX <index> [LIMIT <limit>]`"
+            | #create_vector_index: "`CREATE [ASYNC] VECTOR INDEX [IF NOT EXISTS] <index> ON [<database>.]<table>(<column>) [<option> = <value> ...]`"
+            | #drop_vector_index: "`DROP VECTOR INDEX [IF EXISTS] <index>`"
+            | #refresh_vector_index: "`REFRESH VECTOR INDEX <index> [LIMIT <limit>]`"
+            | #create_inverted_index: "`CREATE [ASYNC] INVERTED INDEX [IF NOT EXISTS] <index> ON [<database>.]<table>(<column>)`"
+            | #drop_inverted_index: "`DROP INVERTED INDEX [IF EXISTS] <index>`"
+            | #refresh_inverted_index: "`REFRESH INVERTED INDEX <index> [LIMIT <limit>]`"
         ),
         rule!(
             #create_virtual_column: "`CREATE VIRTUAL COLUMN (expr, ...) FOR [<database>.]<table>`"
@@ -1907,7 +2135,7 @@ pub fn statement(i: Input) -> IResult<StatementWithFormat> {
             | #show_roles : "`SHOW ROLES`"
             | #create_role : "`CREATE ROLE [IF NOT EXISTS] <role_name>`"
             | #drop_role : "`DROP ROLE [IF EXISTS] <role_name>`"
-            | #create_udf : "`CREATE [OR REPLACE] FUNCTION [IF NOT EXISTS] <name> {AS (<parameter>, ...) -> <definition expr> | (<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address>} [DESC = <description>]`"
+            | #create_udf : "`CREATE [OR REPLACE] FUNCTION [IF NOT EXISTS] <name> {AS (<parameter>, ...) -> <definition expr> | (<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address> | (<arg_type>, ...) RETURNS <return_type> LANGUAGE WASM HANDLER=<handler> AS <module>} [DESC = <description>]`"
             | #drop_udf : "`DROP FUNCTION [IF EXISTS] <udf_name>`"
             | #alter_udf : "`ALTER FUNCTION <udf_name> (<parameter>, ...) -> <definition_expr> [DESC = <description>]`"
             | #set_role: "`SET [DEFAULT] ROLE <role>`"
@@ -2169,6 +2397,56 @@ pub fn rest_str(i: Input) -> IResult<(String, usize)> {
     ))
 }
 
+pub fn table_constraint(i: Input) -> IResult<TableConstraint> {
+    // The leading keywords are not followed by a cut, so that a column named
+    // `check` or `unique` can still be parsed as a column definition.
+    let check = map(
+        rule! {
+            CHECK ~ "(" ~ ^#expr ~ ^")"
+        },
+        |(_, _, expr, _)| TableConstraintKind::Check(expr),
+    );
+    let primary_key = map(
+        rule! {
+            PRIMARY ~ KEY ~ "(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, columns, _)| TableConstraintKind::PrimaryKey(columns),
+    );
+    let unique = map(
+        rule! {
+            UNIQUE ~ KEY? ~ "(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, columns, _)| TableConstraintKind::Unique(columns),
+    );
+    let foreign_key = map(
+        rule! {
+            FOREIGN ~ KEY ~ "(" ~ ^#comma_separated_list1(ident) ~ ^")"
+            ~ ^REFERENCES ~ ^#dot_separated_idents_1_to_2
+            ~ ^"(" ~ ^#comma_separated_list1(ident) ~ ^")"
+        },
+        |(_, _, _, columns, _, _, (ref_database, ref_table), _, ref_columns, _)| {
+            TableConstraintKind::ForeignKey {
+                columns,
+                ref_database,
+                ref_table,
+                ref_columns,
+            }
+        },
+    );
+    let kind = alt((check, primary_key, unique, foreign_key));
+
+    map(
+        rule! {
+            ( CONSTRAINT ~ #ident )? ~ #kind
+            : "`[CONSTRAINT <name>] { CHECK (<expr>) | PRIMARY KEY (<column>, ...) | UNIQUE (<column>, ...) | FOREIGN KEY (<column>, ...) REFERENCES <table> (<column>, ...) }`"
+        },
+        |(name, kind)| TableConstraint {
+            name: name.map(|(_, name)| name),
+            kind,
+        },
+    )(i)
+}
+
 pub fn column_def(i: Input) -> IResult<ColumnDefinition> {
     #[derive(Clone)]
     enum ColumnConstraint {
@@ -2568,11 +2846,31 @@ pub fn grant_option(i: Input) -> IResult<PrincipalIdentity> {
 }
 
 pub fn create_table_source(i: Input) -> IResult<CreateTableSource> {
+    #[derive(Clone)]
+    enum TableElement {
+        Column(ColumnDefinition),
+        Constraint(TableConstraint),
+    }
+
+    let element = alt((
+        map(table_constraint, TableElement::Constraint),
+        map(column_def, TableElement::Column),
+    ));
     let columns = map(
         rule! {
-            "(" ~ ^#comma_separated_list1(column_def) ~ ^")"
+            "(" ~ ^#comma_separated_list1(element) ~ ^")"
+        },
+        |(_, elements, _)| {
+            let mut columns = Vec::with_capacity(elements.len());
+            let mut constraints = vec![];
+            for element in elements {
+                match element {
+                    TableElement::Column(column) => columns.push(column),
+                    TableElement::Constraint(constraint) => constraints.push(constraint),
+                }
+            }
+            CreateTableSource::Columns(columns, constraints)
         },
-        |(_, columns, _)| CreateTableSource::Columns(columns),
     );
     let like = map(
         rule! {
@@ -2756,9 +3054,12 @@ pub fn alter_table_action(i: Input) -> IResult<AlterTableAction> {
     );
     let alter_table_cluster_key = map(
         rule! {
-            CLUSTER ~ ^BY ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+            CLUSTER ~ ^BY ~ #cluster_type? ~ ^"(" ~ ^#comma_separated_list1(expr) ~ ^")"
+        },
+        |(_, _, cluster_type, _, cluster_by, _)| AlterTableAction::AlterTableClusterKey {
+            cluster_type: cluster_type.unwrap_or_default(),
+            cluster_by,
         },
-        |(_, _, _, cluster_by, _)| AlterTableAction::AlterTableClusterKey { cluster_by },
     );
 
     let drop_table_cluster_key = map(
@@ -3219,6 +3520,14 @@ pub fn engine(i: Input) -> IResult<Engine> {
     )(i)
 }
 
+pub fn cluster_type(i: Input) -> IResult<ClusterType> {
+    alt((
+        value(ClusterType::Linear, rule! { LINEAR }),
+        value(ClusterType::Hilbert, rule! { HILBERT }),
+        value(ClusterType::ZOrder, rule! { ZORDER }),
+    ))(i)
+}
+
 pub fn database_engine(i: Input) -> IResult<DatabaseEngine> {
     value(DatabaseEngine::Default, rule! { DEFAULT })(i)
 }
@@ -3369,6 +3678,7 @@ pub fn table_reference_with_alias(i: Input) -> IResult<TableReference> {
                 columns: vec![],
             }),
             travel_point: None,
+            changes: None,
             pivot: None,
             unpivot: None,
         },
@@ -3387,6 +3697,7 @@ pub fn table_reference_only(i: Input) -> IResult<TableReference> {
             table,
             alias: None,
             travel_point: None,
+            changes: None,
             pivot: None,
             unpivot: None,
         },
@@ -3442,8 +3753,25 @@ pub fn udf_definition(i: Input) -> IResult<UDFDefinition> {
         },
     );
 
+    let wasm_udf = map(
+        rule! {
+            "(" ~ #comma_separated_list0(udf_arg_type) ~ ")"
+            ~ RETURNS ~ #udf_arg_type
+            ~ LANGUAGE ~ WASM
+            ~ HANDLER ~ ^"=" ~ ^#literal_string
+            ~ AS ~ ^#literal_string
+        },
+        |(_, arg_types, _, _, return_type, _, _, _, _, handler, _, code)| UDFDefinition::WasmUDF {
+            arg_types,
+            return_type,
+            handler,
+            code,
+        },
+    );
+
     rule!(
-        #udf_server: "(<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address>"
+        #wasm_udf: "(<arg_type>, ...) RETURNS <return_type> LANGUAGE WASM HANDLER=<handler> AS <module>"
+        | #udf_server: "(<arg_type>, ...) RETURNS <return_type> LANGUAGE <language> HANDLER=<handler> ADDRESS=<udf_server_address>"
         | #lambda_udf: "AS (<parameter>, ...) -> <definition expr>"
     )(i)
 }
@@ -3570,3 +3898,95 @@ pub fn alter_password_action(i: Input) -> IResult<AlterPasswordAction> {
         | #unset_options
     )(i)
 }
+
+pub fn workload_group_set_options(i: Input) -> IResult<WorkloadGroupSetOptions> {
+    map(
+        rule! {
+             ( MAX_CONCURRENCY ~ Eq ~ ^#literal_u64 ) ?
+             ~ ( MAX_QUEUED ~ Eq ~ ^#literal_u64 ) ?
+             ~ ( QUEUE_TIMEOUT ~ Eq ~ ^#literal_u64 ) ?
+             ~ ( MEMORY_PERCENTAGE ~ Eq ~ ^#literal_u64 ) ?
+             ~ ( CPU_PERCENTAGE ~ Eq ~ ^#literal_u64 ) ?
+             ~ ( USERS ~ Eq ~ ^"(" ~ ^#comma_separated_list0(literal_string) ~ ^")" ) ?
+             ~ ( ROLES ~ Eq ~ ^"(" ~ ^#comma_separated_list0(literal_string) ~ ^")" ) ?
+             ~ ( COMMENT ~ Eq ~ ^#literal_string)?
+        },
+        |(
+            opt_max_concurrency,
+            opt_max_queued,
+            opt_queue_timeout,
+            opt_memory_percentage,
+            opt_cpu_percentage,
+            opt_users,
+            opt_roles,
+            opt_comment,
+        )| {
+            WorkloadGroupSetOptions {
+                max_concurrency: opt_max_concurrency.map(|opt| opt.2),
+                max_queued: opt_max_queued.map(|opt| opt.2),
+                queue_timeout_secs: opt_queue_timeout.map(|opt| opt.2),
+                memory_percentage: opt_memory_percentage.map(|opt| opt.2),
+                cpu_percentage: opt_cpu_percentage.map(|opt| opt.2),
+                users: opt_users.map(|opt| opt.3),
+                roles: opt_roles.map(|opt| opt.3),
+                comment: opt_comment.map(|opt| opt.2),
+            }
+        },
+    )(i)
+}
+
+pub fn workload_group_unset_options(i: Input) -> IResult<WorkloadGroupUnSetOptions> {
+    map(
+        rule! {
+             MAX_CONCURRENCY ?
+             ~ MAX_QUEUED ?
+             ~ QUEUE_TIMEOUT ?
+             ~ MEMORY_PERCENTAGE ?
+             ~ CPU_PERCENTAGE ?
+             ~ USERS ?
+             ~ ROLES ?
+             ~ COMMENT ?
+        },
+        |(
+            opt_max_concurrency,
+            opt_max_queued,
+            opt_queue_timeout,
+            opt_memory_percentage,
+            opt_cpu_percentage,
+            opt_users,
+            opt_roles,
+            opt_comment,
+        )| {
+            WorkloadGroupUnSetOptions {
+                max_concurrency: opt_max_concurrency.is_some(),
+                max_queued: opt_max_queued.is_some(),
+                queue_timeout_secs: opt_queue_timeout.is_some(),
+                memory_percentage: opt_memory_percentage.is_some(),
+                cpu_percentage: opt_cpu_percentage.is_some(),
+                users: opt_users.is_some(),
+                roles: opt_roles.is_some(),
+                comment: opt_comment.is_some(),
+            }
+        },
+    )(i)
+}
+
+pub fn alter_workload_group_action(i: Input) -> IResult<AlterWorkloadGroupAction> {
+    let set_options = map(
+        rule! {
+           SET ~ #workload_group_set_options
+        },
+        |(_, set_options)| AlterWorkloadGroupAction::SetOptions(set_options),
+    );
+    let unset_options = map(
+        rule! {
+           UNSET ~ #workload_group_unset_options
+        },
+        |(_, unset_options)| AlterWorkloadGroupAction::UnSetOptions(unset_options),
+    );
+
+    rule!(
+        #set_options
+        | #unset_options
+    )(i)
+}
@@ -321,6 +321,8 @@ pub enum TokenKind {
     ALLOWED_IP_LIST,
     #[token("ADD", ignore(ascii_case))]
     ADD,
+    #[token("ABORT", ignore(ascii_case))]
+    ABORT,
     #[token("AFTER", ignore(ascii_case))]
     AFTER,
     #[token("AGGREGATING", ignore(ascii_case))]
@@ -353,6 +355,8 @@ pub enum TokenKind {
     AT,
     #[token("ASC", ignore(ascii_case))]
     ASC,
+    #[token("ASOF", ignore(ascii_case))]
+    ASOF,
     #[token("ANTI", ignore(ascii_case))]
     ANTI,
     #[token("ASYNC", ignore(ascii_case))]
@@ -361,6 +365,8 @@ pub enum TokenKind {
     ATTACH,
     #[token("BEFORE", ignore(ascii_case))]
     BEFORE,
+    #[token("BEGIN", ignore(ascii_case))]
+    BEGIN,
     #[token("BETWEEN", ignore(ascii_case))]
     BETWEEN,
     #[token("BIGINT", ignore(ascii_case))]
@@ -411,16 +417,24 @@ pub enum TokenKind {
     COMMENT,
     #[token("COMMENTS", ignore(ascii_case))]
     COMMENTS,
+    #[token("COMMIT", ignore(ascii_case))]
+    COMMIT,
     #[token("COMPACT", ignore(ascii_case))]
     COMPACT,
     #[token("CONNECTION", ignore(ascii_case))]
     CONNECTION,
     #[token("CONNECTIONS", ignore(ascii_case))]
     CONNECTIONS,
+    #[token("CONSTRAINT", ignore(ascii_case))]
+    CONSTRAINT,
     #[token("CONTENT_TYPE", ignore(ascii_case))]
     CONTENT_TYPE,
+    #[token("CHANGES", ignore(ascii_case))]
+    CHANGES,
     #[token("CHAR", ignore(ascii_case))]
     CHAR,
+    #[token("CHECK", ignore(ascii_case))]
+    CHECK,
     #[token("COLUMN", ignore(ascii_case))]
     COLUMN,
     #[token("COLUMNS", ignore(ascii_case))]
@@ -437,6 +451,8 @@ pub enum TokenKind {
     COPY,
     #[token("COUNT", ignore(ascii_case))]
     COUNT,
+    #[token("CPU_PERCENTAGE", ignore(ascii_case))]
+    CPU_PERCENTAGE,
     #[token("CREATE", ignore(ascii_case))]
     CREATE,
     #[token("CREDENTIALS", ignore(ascii_case))]
@@ -571,6 +587,8 @@ pub enum TokenKind {
     FOR,
     #[token("FORCE", ignore(ascii_case))]
     FORCE,
+    #[token("FOREIGN", ignore(ascii_case))]
+    FOREIGN,
     #[token("FORMAT", ignore(ascii_case))]
     FORMAT,
     #[token("FOLLOWING", ignore(ascii_case))]
@@ -597,16 +615,22 @@ pub enum TokenKind {
     FUSE,
     #[token("GENERATED", ignore(ascii_case))]
     GENERATED,
+    #[token("GEOMETRY", ignore(ascii_case))]
+    GEOMETRY,
     #[token("GLOBAL", ignore(ascii_case))]
     GLOBAL,
     #[token("GRAPH", ignore(ascii_case))]
     GRAPH,
     #[token("GROUP", ignore(ascii_case))]
     GROUP,
+    #[token("GROUPS", ignore(ascii_case))]
+    GROUPS,
     #[token("GZIP", ignore(ascii_case))]
     GZIP,
     #[token("HAVING", ignore(ascii_case))]
     HAVING,
+    #[token("HILBERT", ignore(ascii_case))]
+    HILBERT,
     #[token("HISTORY", ignore(ascii_case))]
     HISTORY,
     #[token("HIVE", ignore(ascii_case))]
@@ -627,6 +651,8 @@ pub enum TokenKind {
     IN,
     #[token("INDEX", ignore(ascii_case))]
     INDEX,
+    #[token("INFORMATION", ignore(ascii_case))]
+    INFORMATION,
     #[token("INNER", ignore(ascii_case))]
     INNER,
     #[token("INSERT", ignore(ascii_case))]
@@ -647,6 +673,8 @@ pub enum TokenKind {
     INTERVAL,
     #[token("INTO", ignore(ascii_case))]
     INTO,
+    #[token("INVERTED", ignore(ascii_case))]
+    INVERTED,
     #[token("IS", ignore(ascii_case))]
     IS,
     #[token("ISODOW", ignore(ascii_case))]
@@ -688,6 +716,8 @@ pub enum TokenKind {
     LIKE,
     #[token("LIMIT", ignore(ascii_case))]
     LIMIT,
+    #[token("LINEAR", ignore(ascii_case))]
+    LINEAR,
     #[token("LIST", ignore(ascii_case))]
     LIST,
     #[token("LZO", ignore(ascii_case))]
@@ -698,12 +728,18 @@ pub enum TokenKind {
     MAP,
     #[token("MAX_FILE_SIZE", ignore(ascii_case))]
     MAX_FILE_SIZE,
+    #[token("MAX_CONCURRENCY", ignore(ascii_case))]
+    MAX_CONCURRENCY,
+    #[token("MAX_QUEUED", ignore(ascii_case))]
+    MAX_QUEUED,
     #[token("MASTER_KEY", ignore(ascii_case))]
     MASTER_KEY,
     #[token("MEMO", ignore(ascii_case))]
     MEMO,
     #[token("MEMORY", ignore(ascii_case))]
     MEMORY,
+    #[token("MEMORY_PERCENTAGE", ignore(ascii_case))]
+    MEMORY_PERCENTAGE,
     #[token("METRICS", ignore(ascii_case))]
     METRICS,
     #[token("MICROSECONDS", ignore(ascii_case))]
@@ -814,6 +850,8 @@ pub enum TokenKind {
     QUARTER,
     #[token("QUERY", ignore(ascii_case))]
     QUERY,
+    #[token("QUEUE_TIMEOUT", ignore(ascii_case))]
+    QUEUE_TIMEOUT,
     #[token("QUOTE", ignore(ascii_case))]
     QUOTE,
     #[token("RANGE", ignore(ascii_case))]
@@ -828,6 +866,8 @@ pub enum TokenKind {
     RECORD_DELIMITER,
     #[token("REFERENCE_USAGE", ignore(ascii_case))]
     REFERENCE_USAGE,
+    #[token("REFERENCES", ignore(ascii_case))]
+    REFERENCES,
     #[token("REFRESH", ignore(ascii_case))]
     REFRESH,
     #[token("REGEXP", ignore(ascii_case))]
@@ -842,6 +882,8 @@ pub enum TokenKind {
     MERGE,
     #[token("MATCHED", ignore(ascii_case))]
     MATCHED,
+    #[token("MATCH_CONDITION", ignore(ascii_case))]
+    MATCH_CONDITION,
     #[token("MISSING_FIELD_AS", ignore(ascii_case))]
     MISSING_FIELD_AS,
     #[token("NULL_FIELD_AS", ignore(ascii_case))]
@@ -864,6 +906,8 @@ pub enum TokenKind {
     PRECISION,
     #[token("PRESIGN", ignore(ascii_case))]
     PRESIGN,
+    #[token("PRIMARY", ignore(ascii_case))]
+    PRIMARY,
     #[token("PRIVILEGES", ignore(ascii_case))]
     PRIVILEGES,
     #[token("QUALIFY", ignore(ascii_case))]
@@ -914,6 +958,8 @@ pub enum TokenKind {
     SETTINGS,
     #[token("STAGES", ignore(ascii_case))]
     STAGES,
+    #[token("START", ignore(ascii_case))]
+    START,
     #[token("STATISTIC", ignore(ascii_case))]
     STATISTIC,
     #[token("SHA256_PASSWORD", ignore(ascii_case))]
@@ -1012,6 +1058,8 @@ pub enum TokenKind {
     TOKEN,
     #[token("TRAILING", ignore(ascii_case))]
     TRAILING,
+    #[token("TRANSACTION", ignore(ascii_case))]
+    TRANSACTION,
     #[token("TRANSIENT", ignore(ascii_case))]
     TRANSIENT,
     #[token("TRIM", ignore(ascii_case))]
@@ -1042,6 +1090,8 @@ pub enum TokenKind {
     UINT8,
     #[token("UNDROP", ignore(ascii_case))]
     UNDROP,
+    #[token("UNIQUE", ignore(ascii_case))]
+    UNIQUE,
     #[token("UNSIGNED", ignore(ascii_case))]
     UNSIGNED,
     #[token("URL", ignore(ascii_case))]
@@ -1066,6 +1116,8 @@ pub enum TokenKind {
     VARCHAR,
     #[token("VARIANT", ignore(ascii_case))]
     VARIANT,
+    #[token("VECTOR", ignore(ascii_case))]
+    VECTOR,
     #[token("VIEW", ignore(ascii_case))]
     VIEW,
     #[token("VIRTUAL", ignore(ascii_case))]
@@ -1086,6 +1138,8 @@ pub enum TokenKind {
     XZ,
     #[token("YEAR", ignore(ascii_case))]
     YEAR,
+    #[token("ZORDER", ignore(ascii_case))]
+    ZORDER,
     #[token("ZSTD", ignore(ascii_case))]
     ZSTD,
     #[token("NULLIF", ignore(ascii_case))]
@@ -1110,6 +1164,8 @@ pub enum TokenKind {
     SETS,
     #[token("CUBE", ignore(ascii_case))]
     CUBE,
+    #[token("ROLLBACK", ignore(ascii_case))]
+    ROLLBACK,
     #[token("ROLLUP", ignore(ascii_case))]
     ROLLUP,
     #[token("INDEXES", ignore(ascii_case))]
@@ -1120,6 +1176,10 @@ pub enum TokenKind {
     OWNERSHIP,
     #[token("READ", ignore(ascii_case))]
     READ,
+    #[token("WORK", ignore(ascii_case))]
+    WORK,
+    #[token("WORKLOAD", ignore(ascii_case))]
+    WORKLOAD,
     #[token("WRITE", ignore(ascii_case))]
     WRITE,
     #[token("UDF", ignore(ascii_case))]
@@ -1128,6 +1188,8 @@ pub enum TokenKind {
     HANDLER,
     #[token("LANGUAGE", ignore(ascii_case))]
     LANGUAGE,
+    #[token("WASM", ignore(ascii_case))]
+    WASM,
     #[token("TASK", ignore(ascii_case))]
     TASK,
     #[token("TASKS", ignore(ascii_case))]
@@ -1394,6 +1456,7 @@ impl TokenKind {
             | TokenKind::FUNCTION
             | TokenKind::ASC
             | TokenKind::ANTI
+            | TokenKind::ASOF
             // | TokenKind::ASYMMETRIC
             // | TokenKind::AUTHORIZATION
             // | TokenKind::BINARY
@@ -1455,6 +1518,7 @@ impl TokenKind {
             // | TokenKind::SIMILAR
             | TokenKind::SOME
             | TokenKind::SEMI
+            | TokenKind::MATCH_CONDITION
             // | TokenKind::SYMMETRIC
             // | TokenKind::TABLE
             // | TokenKind::TABLESAMPLE
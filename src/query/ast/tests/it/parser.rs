@@ -132,6 +132,15 @@ fn test_statement() {
         r#"drop view v;"#,
         r#"create view v1(c1) as select number % 3 as a from numbers(1000);"#,
         r#"alter view v1(c2) as select number % 3 as a from numbers(1000);"#,
+        r#"create materialized view if not exists db.mv as select number % 3 as a from numbers(1000);"#,
+        r#"drop materialized view if exists db.mv;"#,
+        r#"refresh materialized view mv;"#,
+        r#"create vector index if not exists idx1 on db.t(v) distance = 'cosine' m = 16;"#,
+        r#"drop vector index if exists idx1;"#,
+        r#"refresh vector index idx1 limit 10;"#,
+        r#"create async inverted index idx2 on t(body);"#,
+        r#"drop inverted index idx2;"#,
+        r#"refresh inverted index idx2;"#,
         r#"create stream test2.s1 on table test.t append_only = false;"#,
         r#"create stream if not exists test2.s2 on table test.t at (stream => test1.s1) comment = 'this is a stream';"#,
         r#"show full streams from default.test2 like 's%';"#,
@@ -146,6 +155,7 @@ fn test_statement() {
         r#"CREATE TABLE t(c1 int not null, c2 bigint not null, c3 varchar not null);"#,
         r#"CREATE TABLE t(c1 varbinary, c2 binary(10));"#,
         r#"CREATE TABLE t(c1 int default 1);"#,
+        r#"create table t (a int, b int, constraint c1 check (a > 0), primary key (a), unique (b), foreign key (b) references db.t2 (id));"#,
         r#"create table abc as (select * from xyz limit 10)"#,
         r#"ALTER USER u1 IDENTIFIED BY '123456';"#,
         r#"ALTER USER u1 WITH DEFAULT_ROLE = role1;"#,
@@ -211,6 +221,7 @@ fn test_statement() {
         r#"OPTIMIZE TABLE t PURGE BEFORE (SNAPSHOT => '9828b23f74664ff3806f44bbc1925ea5') LIMIT 10;"#,
         r#"OPTIMIZE TABLE t PURGE BEFORE (TIMESTAMP => '2023-06-26 09:49:02.038483'::TIMESTAMP) LIMIT 10;"#,
         r#"ALTER TABLE t CLUSTER BY(c1);"#,
+        r#"ALTER TABLE t CLUSTER BY HILBERT(c1, c2);"#,
         r#"ALTER TABLE t DROP CLUSTER KEY;"#,
         r#"ALTER TABLE t RECLUSTER FINAL WHERE c1 > 0 LIMIT 10;"#,
         r#"ALTER TABLE t ADD c int null;"#,
@@ -550,6 +561,12 @@ fn test_statement() {
         r#"ALTER PIPE mypipe SET PIPE_EXECUTION_PAUSED = true"#,
         r#"DROP PIPE mypipe"#,
         r#"DESC PIPE mypipe"#,
+        // workload groups
+        r#"CREATE WORKLOAD GROUP IF NOT EXISTS etl MAX_CONCURRENCY = 4 MAX_QUEUED = 16 QUEUE_TIMEOUT = 60 MEMORY_PERCENTAGE = 50 CPU_PERCENTAGE = 30 USERS = ('u1') ROLES = ('r1','r2') COMMENT = 'etl queries'"#,
+        r#"ALTER WORKLOAD GROUP IF EXISTS etl SET MAX_CONCURRENCY = 8 COMMENT = 'nightly etl'"#,
+        r#"ALTER WORKLOAD GROUP etl UNSET MAX_QUEUED USERS COMMENT"#,
+        r#"DROP WORKLOAD GROUP IF EXISTS etl"#,
+        r#"SHOW WORKLOAD GROUPS"#,
         "--各环节转各环节转各环节转各环节转各\n  select 34343",
         "-- 96477300355	31379974136	3.074486292973661\nselect 34343",
         "-- xxxxx\n  select 34343;",
@@ -561,8 +578,17 @@ fn test_statement() {
         "CREATE OR REPLACE FUNCTION isnotempty_test_replace AS(p) -> not(is_null(p))  DESC = 'This is a description';",
         "CREATE FUNCTION binary_reverse (BINARY) RETURNS BINARY LANGUAGE python HANDLER = 'binary_reverse' ADDRESS = 'http://0.0.0.0:8815';",
         "CREATE OR REPLACE FUNCTION binary_reverse (BINARY) RETURNS BINARY LANGUAGE python HANDLER = 'binary_reverse' ADDRESS = 'http://0.0.0.0:8815';",
+        "CREATE FUNCTION wasm_add (INT, INT) RETURNS BIGINT LANGUAGE wasm HANDLER = 'add' AS 'AGFzbQEAAAA=';",
         "DROP FUNCTION binary_reverse;",
         "DROP FUNCTION isnotempty;",
+        "BEGIN;",
+        "BEGIN TRANSACTION;",
+        "START TRANSACTION;",
+        "COMMIT;",
+        "COMMIT WORK;",
+        "END;",
+        "ROLLBACK;",
+        "ABORT;",
     ];
 
     for case in cases {
@@ -727,6 +753,10 @@ fn test_query() {
         r#"VALUES(1,'a'),(2,'b'),(null,'c') order by col0 limit 2"#,
         r#"select * from t left join lateral(select 1) on true, lateral(select 2)"#,
         r#"select * from t, lateral flatten(input => u.col) f"#,
+        r#"select * from t asof join q match_condition(t.ts >= q.ts) on t.id = q.id"#,
+        r#"select * from t asof left join q using(id) match_condition(t.ts < q.ts)"#,
+        r#"select * from t changes(information => default) at (snapshot => 'a') end (snapshot => 'b')"#,
+        r#"select * from t changes(information => append_only) at (snapshot => 'a') as c"#,
     ];
 
     for case in cases {
@@ -789,6 +819,7 @@ fn test_expr() {
         r#"1 - -(- - -1)"#,
         r#"1 + a * c.d"#,
         r#"number % 2"#,
+        r#"number % ?"#,
         r#""t":k1.k2"#,
         r#""t":k1.k2.0"#,
         r#"t.0"#,
@@ -0,0 +1,26 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+/// An inverted index that can be used to skip the blocks without any term of a `match` query.
+///
+/// It only narrows down the blocks to read, the rows are still filtered by the `match` function.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct InvertedIndexInfo {
+    pub index_id: u64,
+    pub index_name: String,
+    /// The indexed column.
+    pub column_name: String,
+    /// The query text of the `match` function.
+    pub query: String,
+}
@@ -15,19 +15,23 @@
 mod agg_index;
 mod datasource;
 mod internal_column;
+mod inverted_index;
 mod partition;
 mod partition_statistics;
 mod projection;
 mod pruning_statistics;
 mod pushdown;
 mod stream_column;
+mod vector_index;
 
 pub use agg_index::*;
 pub use datasource::*;
 pub use internal_column::*;
+pub use inverted_index::*;
 pub use partition::*;
 pub use partition_statistics::PartStatistics;
 pub use projection::Projection;
 pub use pruning_statistics::PruningStatistics;
 pub use pushdown::*;
 pub use stream_column::*;
+pub use vector_index::*;
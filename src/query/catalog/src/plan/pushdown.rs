@@ -23,6 +23,8 @@ use databend_common_expression::TableSchema;
 use databend_storages_common_table_meta::table::ChangeType;
 
 use super::AggIndexInfo;
+use super::InvertedIndexInfo;
+use super::VectorIndexInfo;
 use crate::plan::Projection;
 
 /// Information of Virtual Columns.
@@ -96,6 +98,10 @@ pub struct PushDownInfo {
     pub agg_index: Option<AggIndexInfo>,
     /// Identifies the type of data change we are looking for
     pub change_type: Option<ChangeType>,
+    /// Vector index information.
+    pub vector_index: Option<VectorIndexInfo>,
+    /// Inverted index information.
+    pub inverted_index: Option<InvertedIndexInfo>,
 }
 
 #[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
@@ -0,0 +1,31 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use databend_common_expression::types::F32;
+use databend_common_vector::VectorDistance;
+
+/// A vector index that can be used to find the top-k nearest rows of a query vector.
+///
+/// It only narrows down the blocks to read, the rows are still sorted by the exact distance.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct VectorIndexInfo {
+    pub index_id: u64,
+    pub index_name: String,
+    /// The indexed column.
+    pub column_name: String,
+    pub distance: VectorDistance,
+    /// The constant vector to compare with.
+    pub query: Vec<F32>,
+    /// Number of the nearest rows to find.
+    pub limit: usize,
+}
@@ -37,6 +37,7 @@ use databend_common_pipeline_core::Pipeline;
 use databend_common_storage::StorageMetrics;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::meta::TableSnapshot;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
 
 use crate::lock::Lock;
 use crate::plan::DataSourceInfo;
@@ -302,6 +303,29 @@ pub trait Table: Sync + Send {
         )))
     }
 
+    /// Whether the table can read the rows appended between two navigation points
+    /// without change tracking, see [`Table::navigate_appends`].
+    fn support_navigate_appends(&self) -> bool {
+        false
+    }
+
+    /// Returns a read-only table over the rows appended after `start`, up to `end`
+    /// (or the latest version if `end` is None).
+    #[async_backtrace::framed]
+    async fn navigate_appends(
+        &self,
+        start: &NavigationPoint,
+        end: Option<&NavigationPoint>,
+    ) -> Result<Arc<dyn Table>> {
+        let (_, _) = (start, end);
+
+        Err(ErrorCode::Unimplemented(format!(
+            "Incremental read is not supported for the table '{}', which uses the '{}' engine.",
+            self.name(),
+            self.get_table_info().engine(),
+        )))
+    }
+
     fn get_block_thresholds(&self) -> BlockThresholds {
         BlockThresholds {
             max_rows_per_block: DEFAULT_BLOCK_MAX_ROWS,
@@ -403,6 +427,9 @@ pub trait TableExt: Table {
         let name = table_info.name.clone();
         let tid = table_info.ident.table_id;
         let catalog = ctx.get_catalog(table_info.catalog()).await?;
+        if let Some(table_info) = ctx.txn_mgr().lock().get_table_from_buffer(tid) {
+            return catalog.get_table_by_info(&table_info);
+        }
         let (ident, meta) = catalog.get_table_meta_by_id(tid).await?;
         let table_info = TableInfo {
             ident,
@@ -426,6 +453,23 @@ pub trait TableExt: Table {
             Ok(())
         }
     }
+
+    fn is_materialized_view(&self) -> bool {
+        self.options().contains_key(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+    }
+
+    /// The data of a materialized view is only written by its refresh.
+    fn check_not_materialized_view(&self) -> Result<()> {
+        if self.is_materialized_view() {
+            let table_info = self.get_table_info();
+            Err(ErrorCode::InvalidOperation(format!(
+                "Modification not permitted: Table '{}' is a materialized view, use `REFRESH MATERIALIZED VIEW` to update it.",
+                table_info.name
+            )))
+        } else {
+            Ok(())
+        }
+    }
 }
 impl<T: ?Sized> TableExt for T where T: Table {}
 
@@ -29,15 +29,18 @@ use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
 use databend_common_io::prelude::FormatSettings;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::OnErrorMode;
 use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::principal::UserDefinedConnection;
 use databend_common_meta_app::principal::UserInfo;
+use databend_common_meta_app::schema::TableInfo;
 use databend_common_pipeline_core::processors::profile::PlanProfile;
 use databend_common_pipeline_core::processors::profile::Profile;
 use databend_common_pipeline_core::InputError;
+use databend_common_pipeline_core::LockGuard;
 use databend_common_settings::Settings;
 use databend_common_storage::CopyStatus;
 use databend_common_storage::DataOperator;
@@ -60,6 +63,7 @@ use crate::query_kind::QueryKind;
 use crate::runtime_filter_info::RuntimeFilterInfo;
 use crate::statistics::data_cache_statistics::DataCacheMetrics;
 use crate::table::Table;
+use crate::txn::TxnManagerRef;
 
 pub type MaterializedCtesBlocks = Arc<RwLock<HashMap<(usize, usize), Arc<RwLock<Vec<DataBlock>>>>>>;
 
@@ -80,11 +84,13 @@ pub struct ProcessInfo {
     pub mysql_connection_id: Option<u32>,
     pub created_time: SystemTime,
     pub status_info: Option<String>,
+    pub workload_group: Option<String>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum ProcessInfoState {
     Query,
+    Queued,
     Aborting,
     Idle,
 }
@@ -93,6 +99,7 @@ impl Display for ProcessInfoState {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             ProcessInfoState::Query => write!(f, "Query"),
+            ProcessInfoState::Queued => write!(f, "Queued"),
             ProcessInfoState::Aborting => write!(f, "Aborting"),
             ProcessInfoState::Idle => write!(f, "Idle"),
         }
@@ -181,6 +188,8 @@ pub trait TableContext: Send + Sync {
     fn get_processes_info(&self) -> Vec<ProcessInfo>;
     fn get_queries_profile(&self) -> HashMap<String, Vec<Arc<Profile>>>;
     fn get_stage_attachment(&self) -> Option<StageAttachment>;
+    /// Get the value bound to the `?` placeholder of a prepared statement starting at `position`.
+    fn get_placeholder_value(&self, position: usize) -> Option<Scalar>;
     fn get_last_query_id(&self, index: i32) -> String;
     fn get_query_id_history(&self) -> HashSet<String>;
     fn get_result_cache_key(&self, query_id: &str) -> Option<String>;
@@ -257,4 +266,13 @@ pub trait TableContext: Send + Sync {
     fn get_min_max_runtime_filter_with_id(&self, id: usize) -> Vec<Expr<String>>;
 
     fn has_bloom_runtime_filters(&self, id: usize) -> bool;
+
+    /// The transaction manager of the session the query belongs to.
+    fn txn_mgr(&self) -> TxnManagerRef;
+
+    /// Acquires the table lock of the table, which is held until the guard is dropped.
+    async fn acquire_table_lock(
+        self: Arc<Self>,
+        table_info: &TableInfo,
+    ) -> Result<Option<LockGuard>>;
 }
@@ -0,0 +1,215 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
+use databend_common_meta_app::schema::UpdateStreamMetaReq;
+use databend_common_meta_app::schema::UpdateTableMetaReq;
+use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
+use parking_lot::Mutex;
+use uuid::Uuid;
+
+pub type TxnManagerRef = Arc<Mutex<TxnManager>>;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum TxnState {
+    /// Every statement is committed on its own.
+    AutoCommit,
+    /// Inside an explicit transaction, started by `BEGIN`.
+    Active,
+    /// A statement of the explicit transaction failed,
+    /// only `COMMIT` or `ROLLBACK` is accepted until the transaction ends.
+    Fail,
+}
+
+/// The table mutations made by the statements of an explicit transaction.
+///
+/// Nothing is written to the meta service until `COMMIT`, which commits
+/// the mutated tables of a catalog in a single meta-service transaction.
+#[derive(Clone, Debug, Default)]
+pub struct TxnBuffer {
+    /// table id -> the table info with the uncommitted table meta
+    table_infos: HashMap<u64, TableInfo>,
+    /// table id -> the request that commits the table
+    update_table_metas: HashMap<u64, UpdateTableMetaReq>,
+    /// ids of the tables truncated by the transaction, whose copied files
+    /// are cleared by the commit
+    truncated_tables: HashSet<u64>,
+}
+
+impl TxnBuffer {
+    fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    fn update_table_meta(&mut self, table_info: &TableInfo, req: UpdateTableMetaReq) {
+        let table_id = req.table_id;
+        let mut new_table_info = table_info.clone();
+        new_table_info.meta = req.new_table_meta.clone();
+        self.table_infos.insert(table_id, new_table_info);
+
+        match self.update_table_metas.get_mut(&table_id) {
+            // The seq of the first mutation is kept, the table must not be
+            // changed by others since the transaction first touched it.
+            Some(buffered) => {
+                buffered.new_table_meta = req.new_table_meta;
+                merge_copied_files(&mut buffered.copied_files, req.copied_files);
+                merge_stream_meta(&mut buffered.update_stream_meta, req.update_stream_meta);
+                if req.deduplicated_label.is_some() {
+                    buffered.deduplicated_label = req.deduplicated_label;
+                }
+            }
+            None => {
+                self.update_table_metas.insert(table_id, req);
+            }
+        }
+    }
+
+    fn truncate_table(&mut self, table_info: &TableInfo, req: UpdateTableMetaReq) {
+        let table_id = req.table_id;
+        // the files copied by the transaction so far went away with the data
+        if let Some(buffered) = self.update_table_metas.get_mut(&table_id) {
+            buffered.copied_files = None;
+        }
+        self.update_table_meta(table_info, req);
+        self.truncated_tables.insert(table_id);
+    }
+}
+
+fn merge_copied_files(
+    buffered: &mut Option<UpsertTableCopiedFileReq>,
+    incoming: Option<UpsertTableCopiedFileReq>,
+) {
+    let Some(incoming) = incoming else {
+        return;
+    };
+    match buffered {
+        Some(buffered) => {
+            buffered.file_info.extend(incoming.file_info);
+            buffered.expire_at = match (buffered.expire_at, incoming.expire_at) {
+                (Some(a), Some(b)) => Some(a.max(b)),
+                (a, b) => a.or(b),
+            };
+            buffered.fail_if_duplicated |= incoming.fail_if_duplicated;
+        }
+        None => *buffered = Some(incoming),
+    }
+}
+
+fn merge_stream_meta(buffered: &mut Vec<UpdateStreamMetaReq>, incoming: Vec<UpdateStreamMetaReq>) {
+    for req in incoming {
+        match buffered.iter_mut().find(|r| r.stream_id == req.stream_id) {
+            // keep the seq of the first consumption of the stream
+            Some(r) => r.options = req.options,
+            None => buffered.push(req),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct TxnManager {
+    state: TxnState,
+    txn_id: String,
+    txn_buffer: TxnBuffer,
+}
+
+impl TxnManager {
+    pub fn init() -> TxnManagerRef {
+        Arc::new(Mutex::new(TxnManager {
+            state: TxnState::AutoCommit,
+            txn_id: "".to_string(),
+            txn_buffer: TxnBuffer::default(),
+        }))
+    }
+
+    pub fn begin(&mut self) {
+        if let TxnState::AutoCommit = self.state {
+            self.state = TxnState::Active;
+            self.txn_id = Uuid::new_v4().to_string();
+        }
+    }
+
+    pub fn set_fail(&mut self) {
+        if let TxnState::Active = self.state {
+            self.state = TxnState::Fail;
+        }
+    }
+
+    /// Ends the transaction, the buffered mutations are discarded.
+    pub fn clear(&mut self) {
+        self.state = TxnState::AutoCommit;
+        self.txn_id = "".to_string();
+        self.txn_buffer.clear();
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.state == TxnState::Active
+    }
+
+    pub fn is_fail(&self) -> bool {
+        self.state == TxnState::Fail
+    }
+
+    pub fn state(&self) -> TxnState {
+        self.state
+    }
+
+    pub fn txn_id(&self) -> &str {
+        &self.txn_id
+    }
+
+    pub fn update_table_meta(&mut self, table_info: &TableInfo, req: UpdateTableMetaReq) {
+        self.txn_buffer.update_table_meta(table_info, req);
+    }
+
+    /// Buffers the table meta of a truncated table, the copied files of the table
+    /// are cleared by the commit, see [`UpdateMultiTableMetaReq::truncated_tables`].
+    pub fn truncate_table(&mut self, table_info: &TableInfo, req: UpdateTableMetaReq) {
+        self.txn_buffer.truncate_table(table_info, req);
+    }
+
+    /// Returns the table info carrying the uncommitted mutations of the transaction.
+    pub fn get_table_from_buffer(&self, table_id: u64) -> Option<TableInfo> {
+        self.txn_buffer.table_infos.get(&table_id).cloned()
+    }
+
+    /// The tables mutated by the transaction.
+    pub fn table_infos(&self) -> Vec<TableInfo> {
+        self.txn_buffer.table_infos.values().cloned().collect()
+    }
+
+    /// The requests to commit the transaction, grouped by the catalog name.
+    pub fn reqs(&self) -> BTreeMap<String, UpdateMultiTableMetaReq> {
+        let mut reqs: BTreeMap<String, UpdateMultiTableMetaReq> = BTreeMap::new();
+        for (table_id, req) in self.txn_buffer.update_table_metas.iter() {
+            let catalog = self.txn_buffer.table_infos[table_id].catalog().to_string();
+            let entry = reqs
+                .entry(catalog)
+                .or_insert_with(|| UpdateMultiTableMetaReq {
+                    update_table_metas: vec![],
+                    truncated_tables: vec![],
+                });
+            entry.update_table_metas.push(req.clone());
+            if self.txn_buffer.truncated_tables.contains(table_id) {
+                entry.truncated_tables.push(*table_id);
+            }
+        }
+        reqs
+    }
+}
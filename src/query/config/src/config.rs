@@ -1395,6 +1395,19 @@ pub struct QueryConfig {
     #[clap(long, value_name = "VALUE", default_value_t)]
     pub mysql_tls_server_key: String,
 
+    #[clap(long, value_name = "VALUE", default_value = "127.0.0.1")]
+    pub postgres_handler_host: String,
+
+    #[clap(long, value_name = "VALUE", default_value = "5433")]
+    pub postgres_handler_port: u16,
+
+    #[clap(long, value_name = "VALUE", default_value = "120")]
+    pub postgres_handler_tcp_keepalive_timeout_secs: u64,
+
+    /// The max size in bytes of a message sent by an authenticated PostgreSQL client.
+    #[clap(long, value_name = "VALUE", default_value = "134217728")]
+    pub postgres_handler_max_message_size: u64,
+
     #[clap(long, value_name = "VALUE", default_value = "256")]
     pub max_active_sessions: u64,
 
@@ -1676,6 +1689,11 @@ impl TryInto<InnerQueryConfig> for QueryConfig {
             mysql_handler_tcp_keepalive_timeout_secs: self.mysql_handler_tcp_keepalive_timeout_secs,
             mysql_tls_server_cert: self.mysql_tls_server_cert,
             mysql_tls_server_key: self.mysql_tls_server_key,
+            postgres_handler_host: self.postgres_handler_host,
+            postgres_handler_port: self.postgres_handler_port,
+            postgres_handler_tcp_keepalive_timeout_secs: self
+                .postgres_handler_tcp_keepalive_timeout_secs,
+            postgres_handler_max_message_size: self.postgres_handler_max_message_size,
             max_active_sessions: self.max_active_sessions,
             max_server_memory_usage: self.max_server_memory_usage,
             max_memory_limit_enabled: self.max_memory_limit_enabled,
@@ -1755,6 +1773,11 @@ impl From<InnerQueryConfig> for QueryConfig {
                 .mysql_handler_tcp_keepalive_timeout_secs,
             mysql_tls_server_cert: inner.mysql_tls_server_cert,
             mysql_tls_server_key: inner.mysql_tls_server_key,
+            postgres_handler_host: inner.postgres_handler_host,
+            postgres_handler_port: inner.postgres_handler_port,
+            postgres_handler_tcp_keepalive_timeout_secs: inner
+                .postgres_handler_tcp_keepalive_timeout_secs,
+            postgres_handler_max_message_size: inner.postgres_handler_max_message_size,
             max_active_sessions: inner.max_active_sessions,
             max_server_memory_usage: inner.max_server_memory_usage,
             max_memory_limit_enabled: inner.max_memory_limit_enabled,
@@ -2703,6 +2726,15 @@ pub struct CacheConfig {
     )]
     pub table_meta_snapshot_count: u64,
 
+    /// Eviction policy of the table snapshot cache, available options: [lru|s3fifo]
+    #[clap(
+        long = "cache-table-meta-snapshot-policy",
+        value_name = "VALUE",
+        value_enum,
+        default_value_t
+    )]
+    pub table_meta_snapshot_policy: CacheEvictionPolicyConfig,
+
     /// Max bytes of cached table segment
     #[clap(
         long = "cache-table-meta-segment-bytes",
@@ -2711,6 +2743,15 @@ pub struct CacheConfig {
     )]
     pub table_meta_segment_bytes: u64,
 
+    /// Eviction policy of the table segment cache, available options: [lru|s3fifo]
+    #[clap(
+        long = "cache-table-meta-segment-policy",
+        value_name = "VALUE",
+        value_enum,
+        default_value_t
+    )]
+    pub table_meta_segment_policy: CacheEvictionPolicyConfig,
+
     /// Max number of cached table statistic meta
     #[clap(
         long = "cache-table-meta-statistic-count",
@@ -2719,6 +2760,15 @@ pub struct CacheConfig {
     )]
     pub table_meta_statistic_count: u64,
 
+    /// Eviction policy of the table statistic meta cache, available options: [lru|s3fifo]
+    #[clap(
+        long = "cache-table-meta-statistic-policy",
+        value_name = "VALUE",
+        value_enum,
+        default_value_t
+    )]
+    pub table_meta_statistic_policy: CacheEvictionPolicyConfig,
+
     /// Enable bloom index cache. Default is enabled. Set it to false to disable all the bloom index caches
     #[clap(
         long = "cache-enable-table-bloom-index-cache",
@@ -2736,6 +2786,15 @@ pub struct CacheConfig {
     )]
     pub table_bloom_index_meta_count: u64,
 
+    /// Eviction policy of the bloom index meta cache, available options: [lru|s3fifo]
+    #[clap(
+        long = "cache-table-bloom-index-meta-policy",
+        value_name = "VALUE",
+        value_enum,
+        default_value_t
+    )]
+    pub table_bloom_index_meta_policy: CacheEvictionPolicyConfig,
+
     /// DEPRECATING, will be deprecated in the next prduction release.
     ///
     /// Max number of cached bloom index filters. Set it to 0 to disable it.
@@ -2759,6 +2818,15 @@ pub struct CacheConfig {
     )]
     pub table_bloom_index_filter_size: u64,
 
+    /// Eviction policy of the bloom index filter cache, available options: [lru|s3fifo]
+    #[clap(
+        long = "cache-table-bloom-index-filter-policy",
+        value_name = "VALUE",
+        value_enum,
+        default_value_t
+    )]
+    pub table_bloom_index_filter_policy: CacheEvictionPolicyConfig,
+
     #[clap(
         long = "cache-table-prune-partitions-count",
         value_name = "VALUE",
@@ -2766,6 +2834,24 @@ pub struct CacheConfig {
     )]
     pub table_prune_partitions_count: u64,
 
+    /// Eviction policy of the prune partitions cache, available options: [lru|s3fifo]
+    #[clap(
+        long = "cache-table-prune-partitions-policy",
+        value_name = "VALUE",
+        value_enum,
+        default_value_t
+    )]
+    pub table_prune_partitions_policy: CacheEvictionPolicyConfig,
+
+    /// Eviction policy of the parquet file meta cache, available options: [lru|s3fifo]
+    #[clap(
+        long = "cache-parquet-file-meta-policy",
+        value_name = "VALUE",
+        value_enum,
+        default_value_t
+    )]
+    pub parquet_file_meta_policy: CacheEvictionPolicyConfig,
+
     /// Type of data cache storage
     #[clap(
         long = "cache-data-cache-storage",
@@ -2814,6 +2900,15 @@ pub struct CacheConfig {
     )]
     pub table_data_deserialized_data_bytes: u64,
 
+    /// Eviction policy of the table column object cache, available options: [lru|s3fifo]
+    #[clap(
+        long = "cache-table-data-deserialized-data-policy",
+        value_name = "VALUE",
+        value_enum,
+        default_value_t
+    )]
+    pub table_data_deserialized_data_policy: CacheEvictionPolicyConfig,
+
     // ----- the following options/args are all deprecated               ----
     /// Max number of cached table segment
     #[clap(long = "cache-table-meta-segment-count", value_name = "VALUE")]
@@ -2832,6 +2927,14 @@ fn bool_true() -> bool {
     true
 }
 
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum CacheEvictionPolicyConfig {
+    #[default]
+    Lru,
+    S3fifo,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, ValueEnum)]
 #[serde(rename_all = "lowercase")]
 pub enum CacheStorageTypeConfig {
@@ -2864,6 +2967,23 @@ pub struct DiskCacheConfig {
         default_value = "./.databend/_cache"
     )]
     pub path: String,
+
+    /// Remove all the cached files on start, instead of reloading them. Default false.
+    #[clap(
+        long = "cache-disk-reset-on-start",
+        value_name = "VALUE",
+        default_value = "false"
+    )]
+    pub reset_on_start: bool,
+
+    /// Eviction policy of the cached files, available options: [lru|s3fifo]
+    #[clap(
+        long = "cache-disk-policy",
+        value_name = "VALUE",
+        value_enum,
+        default_value_t
+    )]
+    pub policy: CacheEvictionPolicyConfig,
 }
 
 mod cache_config_converters {
@@ -2933,18 +3053,28 @@ mod cache_config_converters {
             Ok(Self {
                 enable_table_meta_cache: value.enable_table_meta_cache,
                 table_meta_snapshot_count: value.table_meta_snapshot_count,
+                table_meta_snapshot_policy: value.table_meta_snapshot_policy.into(),
                 table_meta_segment_bytes: value.table_meta_segment_bytes,
+                table_meta_segment_policy: value.table_meta_segment_policy.into(),
                 table_meta_statistic_count: value.table_meta_statistic_count,
+                table_meta_statistic_policy: value.table_meta_statistic_policy.into(),
                 enable_table_index_bloom: value.enable_table_bloom_index_cache,
                 table_bloom_index_meta_count: value.table_bloom_index_meta_count,
+                table_bloom_index_meta_policy: value.table_bloom_index_meta_policy.into(),
                 table_bloom_index_filter_count: value.table_bloom_index_filter_count,
                 table_bloom_index_filter_size: value.table_bloom_index_filter_size,
+                table_bloom_index_filter_policy: value.table_bloom_index_filter_policy.into(),
                 table_prune_partitions_count: value.table_prune_partitions_count,
+                table_prune_partitions_policy: value.table_prune_partitions_policy.into(),
+                parquet_file_meta_policy: value.parquet_file_meta_policy.into(),
                 data_cache_storage: value.data_cache_storage.try_into()?,
                 table_data_cache_population_queue_size: value
                     .table_data_cache_population_queue_size,
                 disk_cache_config: value.disk_cache_config.try_into()?,
                 table_data_deserialized_data_bytes: value.table_data_deserialized_data_bytes,
+                table_data_deserialized_data_policy: value
+                    .table_data_deserialized_data_policy
+                    .into(),
             })
         }
     }
@@ -2954,18 +3084,28 @@ mod cache_config_converters {
             Self {
                 enable_table_meta_cache: value.enable_table_meta_cache,
                 table_meta_snapshot_count: value.table_meta_snapshot_count,
+                table_meta_snapshot_policy: value.table_meta_snapshot_policy.into(),
                 table_meta_segment_bytes: value.table_meta_segment_bytes,
+                table_meta_segment_policy: value.table_meta_segment_policy.into(),
                 table_meta_statistic_count: value.table_meta_statistic_count,
+                table_meta_statistic_policy: value.table_meta_statistic_policy.into(),
                 enable_table_bloom_index_cache: value.enable_table_index_bloom,
                 table_bloom_index_meta_count: value.table_bloom_index_meta_count,
+                table_bloom_index_meta_policy: value.table_bloom_index_meta_policy.into(),
                 table_bloom_index_filter_count: value.table_bloom_index_filter_count,
                 table_bloom_index_filter_size: value.table_bloom_index_filter_size,
+                table_bloom_index_filter_policy: value.table_bloom_index_filter_policy.into(),
                 table_prune_partitions_count: value.table_prune_partitions_count,
+                table_prune_partitions_policy: value.table_prune_partitions_policy.into(),
+                parquet_file_meta_policy: value.parquet_file_meta_policy.into(),
                 data_cache_storage: value.data_cache_storage.into(),
                 table_data_cache_population_queue_size: value
                     .table_data_cache_population_queue_size,
                 disk_cache_config: value.disk_cache_config.into(),
                 table_data_deserialized_data_bytes: value.table_data_deserialized_data_bytes,
+                table_data_deserialized_data_policy: value
+                    .table_data_deserialized_data_policy
+                    .into(),
                 table_meta_segment_count: None,
             }
         }
@@ -2977,6 +3117,8 @@ mod cache_config_converters {
             Ok(Self {
                 max_bytes: value.max_bytes,
                 path: value.path,
+                reset_on_start: value.reset_on_start,
+                policy: value.policy.into(),
             })
         }
     }
@@ -2986,6 +3128,26 @@ mod cache_config_converters {
             Self {
                 max_bytes: value.max_bytes,
                 path: value.path,
+                reset_on_start: value.reset_on_start,
+                policy: value.policy.into(),
+            }
+        }
+    }
+
+    impl From<CacheEvictionPolicyConfig> for inner::CacheEvictionPolicyConfig {
+        fn from(value: CacheEvictionPolicyConfig) -> Self {
+            match value {
+                CacheEvictionPolicyConfig::Lru => inner::CacheEvictionPolicyConfig::Lru,
+                CacheEvictionPolicyConfig::S3fifo => inner::CacheEvictionPolicyConfig::S3Fifo,
+            }
+        }
+    }
+
+    impl From<inner::CacheEvictionPolicyConfig> for CacheEvictionPolicyConfig {
+        fn from(value: inner::CacheEvictionPolicyConfig) -> Self {
+            match value {
+                inner::CacheEvictionPolicyConfig::Lru => CacheEvictionPolicyConfig::Lru,
+                inner::CacheEvictionPolicyConfig::S3Fifo => CacheEvictionPolicyConfig::S3fifo,
             }
         }
     }
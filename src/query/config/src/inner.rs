@@ -159,6 +159,10 @@ pub struct QueryConfig {
     pub mysql_handler_tcp_keepalive_timeout_secs: u64,
     pub mysql_tls_server_cert: String,
     pub mysql_tls_server_key: String,
+    pub postgres_handler_host: String,
+    pub postgres_handler_port: u16,
+    pub postgres_handler_tcp_keepalive_timeout_secs: u64,
+    pub postgres_handler_max_message_size: u64,
     pub max_active_sessions: u64,
     pub max_server_memory_usage: u64,
     pub max_memory_limit_enabled: bool,
@@ -243,6 +247,10 @@ impl Default for QueryConfig {
             mysql_handler_tcp_keepalive_timeout_secs: 120,
             mysql_tls_server_cert: "".to_string(),
             mysql_tls_server_key: "".to_string(),
+            postgres_handler_host: "127.0.0.1".to_string(),
+            postgres_handler_port: 5433,
+            postgres_handler_tcp_keepalive_timeout_secs: 120,
+            postgres_handler_max_message_size: 128 * 1024 * 1024,
             max_active_sessions: 256,
             max_server_memory_usage: 0,
             max_memory_limit_enabled: false,
@@ -511,21 +519,39 @@ pub struct CacheConfig {
     /// Max number of cached table snapshot
     pub table_meta_snapshot_count: u64,
 
+    /// Eviction policy of the table snapshot cache
+    pub table_meta_snapshot_policy: CacheEvictionPolicyConfig,
+
     /// Max size(in bytes) of cached table segment
     pub table_meta_segment_bytes: u64,
 
+    /// Eviction policy of the table segment cache
+    pub table_meta_segment_policy: CacheEvictionPolicyConfig,
+
     /// Max number of cached table segment
     pub table_meta_statistic_count: u64,
 
+    /// Eviction policy of the table statistic meta cache
+    pub table_meta_statistic_policy: CacheEvictionPolicyConfig,
+
     /// Enable bloom index cache. Default is enabled. Set it to false to disable all the bloom index caches
     pub enable_table_index_bloom: bool,
 
     /// Max number of cached bloom index meta objects. Set it to 0 to disable it.
     pub table_bloom_index_meta_count: u64,
 
+    /// Eviction policy of the bloom index meta cache
+    pub table_bloom_index_meta_policy: CacheEvictionPolicyConfig,
+
     /// Max number of cached prune partitions objects. Set it to 0 to disable it.
     pub table_prune_partitions_count: u64,
 
+    /// Eviction policy of the prune partitions cache
+    pub table_prune_partitions_policy: CacheEvictionPolicyConfig,
+
+    /// Eviction policy of the parquet file meta cache
+    pub parquet_file_meta_policy: CacheEvictionPolicyConfig,
+
     /// Max number of cached bloom index filters. Set it to 0 to disable it.
     // One bloom index filter per column of data block being indexed will be generated if necessary.
     //
@@ -537,6 +563,9 @@ pub struct CacheConfig {
     // One bloom index filter per column of data block being indexed will be generated if necessary.
     pub table_bloom_index_filter_size: u64,
 
+    /// Eviction policy of the bloom index filter cache
+    pub table_bloom_index_filter_policy: CacheEvictionPolicyConfig,
+
     pub data_cache_storage: CacheStorageTypeConfig,
 
     /// Max size of external cache population queue length
@@ -562,6 +591,18 @@ pub struct CacheConfig {
     /// Only if query nodes have plenty of un-utilized memory, the working set can be fitted into,
     /// and the access pattern will benefit from caching, consider enabled this cache.
     pub table_data_deserialized_data_bytes: u64,
+
+    /// Eviction policy of the table column object cache
+    pub table_data_deserialized_data_policy: CacheEvictionPolicyConfig,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CacheEvictionPolicyConfig {
+    /// Least recently used
+    #[default]
+    Lru,
+    /// Scan-resistant S3-FIFO
+    S3Fifo,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -593,6 +634,12 @@ pub struct DiskCacheConfig {
 
     /// Table disk cache root path
     pub path: String,
+
+    /// Remove all the cached files when the query node starts, instead of reloading them.
+    pub reset_on_start: bool,
+
+    /// Eviction policy of the cached files
+    pub policy: CacheEvictionPolicyConfig,
 }
 
 impl Default for DiskCacheConfig {
@@ -600,6 +647,8 @@ impl Default for DiskCacheConfig {
         Self {
             max_bytes: 21474836480,
             path: "./.databend/_cache".to_owned(),
+            reset_on_start: false,
+            policy: Default::default(),
         }
     }
 }
@@ -609,17 +658,25 @@ impl Default for CacheConfig {
         Self {
             enable_table_meta_cache: true,
             table_meta_snapshot_count: 256,
+            table_meta_snapshot_policy: Default::default(),
             table_meta_segment_bytes: 1073741824,
+            table_meta_segment_policy: Default::default(),
             table_meta_statistic_count: 256,
+            table_meta_statistic_policy: Default::default(),
             enable_table_index_bloom: true,
             table_bloom_index_meta_count: 3000,
+            table_bloom_index_meta_policy: Default::default(),
             table_bloom_index_filter_count: 0,
             table_bloom_index_filter_size: 2147483648,
+            table_bloom_index_filter_policy: Default::default(),
             table_prune_partitions_count: 256,
+            table_prune_partitions_policy: Default::default(),
+            parquet_file_meta_policy: Default::default(),
             data_cache_storage: Default::default(),
             table_data_cache_population_queue_size: 0,
             disk_cache_config: Default::default(),
             table_data_deserialized_data_bytes: 0,
+            table_data_deserialized_data_policy: Default::default(),
         }
     }
 }
@@ -36,6 +36,7 @@ mod mask;
 mod obsolete;
 mod version;
 
+pub use config::CacheEvictionPolicyConfig;
 pub use config::CacheStorageTypeConfig;
 pub use config::Commands;
 pub use config::Config;
@@ -43,6 +44,7 @@ pub use config::QueryConfig;
 pub use config::StorageConfig;
 pub use global::GlobalConfig;
 pub use inner::CacheConfig;
+pub use inner::CacheEvictionPolicyConfig as CacheEvictionPolicyInnerConfig;
 pub use inner::CacheStorageTypeConfig as CacheStorageTypeInnerConfig;
 pub use inner::CatalogConfig;
 pub use inner::CatalogHiveConfig;
@@ -19,6 +19,7 @@ use z3::Context;
 use z3::SatResult;
 use z3::Solver;
 
+use crate::declare::is_false;
 use crate::declare::is_true;
 use crate::declare::var_bool;
 use crate::declare::var_int;
@@ -102,3 +103,57 @@ pub fn variable_must_not_null(assertion: &MirExpr, variable: &str) -> bool {
 
     result == SatResult::Sat
 }
+
+/// Given the assumptions are not false, check whether the assertion can never be true.
+///
+/// The assumptions follow the semantic of CHECK constraints, where a row is accepted
+/// unless the constraint evaluates to false. The function tries to prove:
+///
+///    ¬∃x. (assumption(x) is not false) ∧ (assertion(x) is true)
+///
+/// ```
+/// use databend_common_constraint::mir::*;
+/// use databend_common_constraint::problem::assertion_is_unsatisfiable;
+///
+/// let a = || {
+///     Box::new(MirExpr::Variable {
+///         name: "a".to_string(),
+///         data_type: MirDataType::Int,
+///     })
+/// };
+///
+/// // a > 0
+/// let assumption = MirExpr::BinaryOperator {
+///     op: MirBinaryOperator::Gt,
+///     left: a(),
+///     right: Box::new(MirExpr::Constant(MirConstant::Int(0))),
+/// };
+/// // a < 0
+/// let assertion = MirExpr::BinaryOperator {
+///     op: MirBinaryOperator::Lt,
+///     left: a(),
+///     right: Box::new(MirExpr::Constant(MirConstant::Int(0))),
+/// };
+///
+/// // a > 0 => a < 0 is never true
+/// assert!(assertion_is_unsatisfiable(&[assumption], &assertion));
+/// ```
+pub fn assertion_is_unsatisfiable(assumptions: &[MirExpr], assertion: &MirExpr) -> bool {
+    let ctx = &Context::new(&Config::new());
+    let solver = Solver::new(ctx);
+
+    for assumption in assumptions {
+        if let Ok(assumption) = assumption.as_z3_ast(ctx, MirDataType::Bool) {
+            solver.assert(&is_false(ctx, &assumption).not());
+        }
+    }
+
+    let assertion = if let Ok(assertion) = assertion.as_z3_ast(ctx, MirDataType::Bool) {
+        is_true(ctx, &assertion)
+    } else {
+        return false;
+    };
+    solver.assert(&assertion);
+
+    solver.check() == SatResult::Unsat
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use databend_common_constraint::mir::MirDataType;
+use databend_common_constraint::problem::assertion_is_unsatisfiable;
 use databend_common_constraint::problem::variable_must_not_null;
 
 use crate::parser::parse_mir_expr;
@@ -66,3 +67,39 @@ fn test_assert_int_is_not_null_multiple_variable() {
         "b",
     ));
 }
+
+#[test]
+fn test_assertion_is_unsatisfiable() {
+    let variables = &[
+        ("a".to_string(), MirDataType::Int),
+        ("b".to_string(), MirDataType::Int),
+    ]
+    .into_iter()
+    .collect();
+
+    assert!(assertion_is_unsatisfiable(
+        &[parse_mir_expr("a > 0", variables)],
+        &parse_mir_expr("a < 0", variables),
+    ));
+    assert!(assertion_is_unsatisfiable(
+        &[
+            parse_mir_expr("a > 0", variables),
+            parse_mir_expr("b > a", variables)
+        ],
+        &parse_mir_expr("b = 0", variables),
+    ));
+
+    assert!(!assertion_is_unsatisfiable(
+        &[parse_mir_expr("a > 0", variables)],
+        &parse_mir_expr("a > 1", variables),
+    ));
+    // A constraint evaluated to NULL is satisfied.
+    assert!(!assertion_is_unsatisfiable(
+        &[parse_mir_expr("a > 0", variables)],
+        &parse_mir_expr("a is null", variables),
+    ));
+    assert!(!assertion_is_unsatisfiable(
+        &[parse_mir_expr("a > 0", variables)],
+        &parse_mir_expr("b < 0", variables),
+    ));
+}
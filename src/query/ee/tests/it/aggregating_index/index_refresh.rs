@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 use std::path::PathBuf;
@@ -559,6 +560,7 @@ async fn create_index(
                 original_query: original_query.to_string(),
                 query: query.to_string(),
                 sync_creation,
+                options: BTreeMap::new(),
             },
         };
 
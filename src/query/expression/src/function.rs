@@ -106,6 +106,9 @@ pub struct FunctionContext {
 
     pub external_server_connect_timeout_secs: u64,
     pub external_server_request_timeout_secs: u64,
+
+    pub wasm_udf_max_memory: u64,
+    pub wasm_udf_max_fuel: u64,
 }
 
 #[derive(Clone)]
@@ -15,9 +15,16 @@
 use std::cmp::Ordering;
 use std::ops::Range;
 
+use geo::BoundingRect;
+use geo::Geometry;
+use geo::Point;
+use geozero::wkb::Ewkb;
 use geozero::wkb::FromWkb;
 use geozero::wkb::WkbDialect;
 use geozero::wkt::Ewkt;
+use geozero::CoordDimensions;
+use geozero::ToGeo;
+use geozero::ToWkb;
 
 use super::binary::BinaryColumn;
 use super::binary::BinaryColumnBuilder;
@@ -35,11 +42,129 @@ use crate::ColumnBuilder;
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct GeometryType;
 
+/// The bounding box that covers all non-empty geometries of a value.
+///
+/// Empty or unparsable geometries have no bounding box and are not covered,
+/// a domain without any covered geometry has `min_x > max_x`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct GeometryDomain {
+    pub min_x: f64,
+    pub min_y: f64,
+    pub max_x: f64,
+    pub max_y: f64,
+}
+
+impl GeometryDomain {
+    pub fn full() -> Self {
+        GeometryDomain {
+            min_x: f64::NEG_INFINITY,
+            min_y: f64::NEG_INFINITY,
+            max_x: f64::INFINITY,
+            max_y: f64::INFINITY,
+        }
+    }
+
+    pub fn empty() -> Self {
+        GeometryDomain {
+            min_x: f64::INFINITY,
+            min_y: f64::INFINITY,
+            max_x: f64::NEG_INFINITY,
+            max_y: f64::NEG_INFINITY,
+        }
+    }
+
+    pub fn from_ewkb(buf: &[u8]) -> Self {
+        match Ewkb(buf.to_vec())
+            .to_geo()
+            .ok()
+            .and_then(|geo| geo.bounding_rect())
+        {
+            Some(rect) => GeometryDomain {
+                min_x: rect.min().x,
+                min_y: rect.min().y,
+                max_x: rect.max().x,
+                max_y: rect.max().y,
+            },
+            None => GeometryDomain::empty(),
+        }
+    }
+
+    pub fn from_column(col: &BinaryColumn) -> Self {
+        col.iter()
+            .map(GeometryDomain::from_ewkb)
+            .fold(GeometryDomain::empty(), |acc, domain| acc.merge(&domain))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.min_x > self.max_x || self.min_y > self.max_y
+    }
+
+    pub fn merge(&self, other: &GeometryDomain) -> Self {
+        GeometryDomain {
+            min_x: self.min_x.min(other.min_x),
+            min_y: self.min_y.min(other.min_y),
+            max_x: self.max_x.max(other.max_x),
+            max_y: self.max_y.max(other.max_y),
+        }
+    }
+
+    /// Grow the bounding box by `distance` in every direction.
+    pub fn expand(&self, distance: f64) -> Self {
+        if self.is_empty() || distance.is_nan() {
+            return *self;
+        }
+        let distance = distance.max(0.0);
+        GeometryDomain {
+            min_x: self.min_x - distance,
+            min_y: self.min_y - distance,
+            max_x: self.max_x + distance,
+            max_y: self.max_y + distance,
+        }
+    }
+
+    pub fn intersects(&self, other: &GeometryDomain) -> bool {
+        !self.is_empty()
+            && !other.is_empty()
+            && self.min_x <= other.max_x
+            && other.min_x <= self.max_x
+            && self.min_y <= other.max_y
+            && other.min_y <= self.max_y
+    }
+
+    /// Encode the bounding box as the min/max statistics of a geometry column,
+    /// that is, the lower-left and upper-right corner points.
+    pub fn to_min_max(&self) -> Option<(Scalar, Scalar)> {
+        if self.is_empty() {
+            return None;
+        }
+        let corner = |x: f64, y: f64| {
+            Geometry::from(Point::new(x, y))
+                .to_ewkb(CoordDimensions::xy(), None)
+                .ok()
+                .map(Scalar::Geometry)
+        };
+        Some((
+            corner(self.min_x, self.min_y)?,
+            corner(self.max_x, self.max_y)?,
+        ))
+    }
+
+    /// Decode the bounding box from the min/max statistics of a geometry column.
+    pub fn from_min_max(min: &[u8], max: &[u8]) -> Option<Self> {
+        let min = GeometryDomain::from_ewkb(min);
+        let max = GeometryDomain::from_ewkb(max);
+        if min.is_empty() || max.is_empty() {
+            return None;
+        }
+        Some(min.merge(&max))
+    }
+}
+
 impl ValueType for GeometryType {
     type Scalar = Vec<u8>;
     type ScalarRef<'a> = &'a [u8];
     type Column = BinaryColumn;
-    type Domain = ();
+    type Domain = GeometryDomain;
     type ColumnIterator<'a> = BinaryIterator<'a>;
     type ColumnBuilder = BinaryColumnBuilder;
 
@@ -65,11 +190,7 @@ impl ValueType for GeometryType {
     }
 
     fn try_downcast_domain(domain: &Domain) -> Option<Self::Domain> {
-        if domain.is_undefined() {
-            Some(())
-        } else {
-            None
-        }
+        domain.as_geometry().cloned()
     }
 
     fn try_downcast_builder(builder: &mut ColumnBuilder) -> Option<&mut Self::ColumnBuilder> {
@@ -98,8 +219,8 @@ impl ValueType for GeometryType {
         Column::Geometry(col)
     }
 
-    fn upcast_domain(_domain: Self::Domain) -> Domain {
-        Domain::Undefined
+    fn upcast_domain(domain: Self::Domain) -> Domain {
+        Domain::Geometry(domain)
     }
 
     fn column_len(col: &Self::Column) -> usize {
@@ -166,7 +287,9 @@ impl ArgType for GeometryType {
         DataType::Geometry
     }
 
-    fn full_domain() -> Self::Domain {}
+    fn full_domain() -> Self::Domain {
+        GeometryDomain::full()
+    }
 
     fn create_builder(capacity: usize, _: &GenericMap) -> Self::ColumnBuilder {
         BinaryColumnBuilder::with_capacity(capacity, 0)
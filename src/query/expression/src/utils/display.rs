@@ -43,6 +43,7 @@ use crate::types::decimal::DecimalColumn;
 use crate::types::decimal::DecimalDataType;
 use crate::types::decimal::DecimalDomain;
 use crate::types::decimal::DecimalScalar;
+use crate::types::geometry::GeometryDomain;
 use crate::types::map::KvPair;
 use crate::types::nullable::NullableDomain;
 use crate::types::number::NumberColumn;
@@ -1001,6 +1002,16 @@ impl<T: Display> Display for SimpleDomain<T> {
     }
 }
 
+impl Display for GeometryDomain {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(
+            f,
+            "{{x: {}..={}, y: {}..={}}}",
+            self.min_x, self.max_x, self.min_y, self.max_y
+        )
+    }
+}
+
 impl Display for Domain {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         match self {
@@ -1030,6 +1041,7 @@ impl Display for Domain {
                 let val_domain = &inner_domain[1];
                 write!(f, "{{[{key_domain}], [{val_domain}]}}")
             }
+            Domain::Geometry(domain) => write!(f, "{domain}"),
             Domain::Undefined => write!(f, "Undefined"),
         }
     }
@@ -0,0 +1,226 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! In-process runtime for WebAssembly UDFs.
+//!
+//! A module is sandboxed: it cannot import anything from the host, its linear
+//! memory is capped and every call is metered with fuel. The module must export:
+//!
+//! - `memory`: its linear memory.
+//! - `alloc(len: i32) -> i32`: allocates `len` bytes and returns the offset.
+//! - `dealloc(ptr: i32, len: i32)`: frees a region returned by `alloc` or a handler.
+//! - the handler, `(ptr: i32, len: i32) -> i64`: reads an Arrow IPC stream holding one
+//!   record batch whose columns are the arguments, and returns `(ptr << 32) | len` of an
+//!   Arrow IPC stream holding the results as the first column of each batch.
+
+use std::io::Cursor;
+
+use arrow_array::RecordBatch;
+use arrow_ipc::reader::StreamReader;
+use arrow_ipc::writer::StreamWriter;
+use arrow_select::concat::concat_batches;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use wasmtime::Config;
+use wasmtime::Engine;
+use wasmtime::Instance;
+use wasmtime::Memory;
+use wasmtime::Module;
+use wasmtime::Store;
+use wasmtime::StoreLimits;
+use wasmtime::StoreLimitsBuilder;
+use wasmtime::TypedFunc;
+
+const WASM_UDF_MEMORY: &str = "memory";
+const WASM_UDF_ALLOC: &str = "alloc";
+const WASM_UDF_DEALLOC: &str = "dealloc";
+
+#[derive(Debug, Clone, Copy)]
+pub struct WasmLimits {
+    /// The maximum size of the linear memory in bytes.
+    pub max_memory: u64,
+    /// The fuel granted to a single call.
+    pub max_fuel: u64,
+}
+
+pub struct WasmRuntime {
+    engine: Engine,
+    module: Module,
+    handler: String,
+}
+
+struct WasmInstance {
+    store: Store<StoreLimits>,
+    memory: Memory,
+    alloc: TypedFunc<i32, i32>,
+    dealloc: TypedFunc<(i32, i32), ()>,
+    handler: TypedFunc<(i32, i32), i64>,
+}
+
+impl WasmRuntime {
+    /// Compiles the module, `code` can be either a WebAssembly binary or WAT text.
+    pub fn try_create(code: &[u8], handler: &str) -> Result<Self> {
+        let mut config = Config::new();
+        config.consume_fuel(true);
+        let engine = Engine::new(&config).map_err(|err| {
+            ErrorCode::UDFRuntimeError(format!("Cannot create wasm engine: {err}"))
+        })?;
+        let module = Module::new(&engine, code).map_err(|err| {
+            ErrorCode::IllegalUDFFormat(format!("Invalid WebAssembly module: {err}"))
+        })?;
+        if module.imports().next().is_some() {
+            return Err(ErrorCode::IllegalUDFFormat(
+                "WebAssembly UDF module must not import anything from the host",
+            ));
+        }
+        Ok(WasmRuntime {
+            engine,
+            module,
+            handler: handler.to_string(),
+        })
+    }
+
+    /// Checks that the module exports everything required to call the handler.
+    pub fn validate(&self, limits: WasmLimits) -> Result<()> {
+        self.instantiate(limits).map(|_| ())
+    }
+
+    /// Calls the handler with a batch of arguments and returns the result batch.
+    pub fn call(&self, limits: WasmLimits, input_batch: RecordBatch) -> Result<RecordBatch> {
+        let mut instance = self.instantiate(limits)?;
+        let store = &mut instance.store;
+
+        let input = encode_batch(&input_batch)?;
+        let input_len = i32::try_from(input.len()).map_err(|_| {
+            ErrorCode::UDFDataError(format!(
+                "Input of WebAssembly UDF is too large: {} bytes",
+                input.len()
+            ))
+        })?;
+        let input_ptr = instance
+            .alloc
+            .call(&mut *store, input_len)
+            .map_err(runtime_error)?;
+        instance
+            .memory
+            .write(&mut *store, input_ptr as u32 as usize, &input)
+            .map_err(|err| {
+                ErrorCode::UDFRuntimeError(format!("Cannot write wasm memory: {err}"))
+            })?;
+
+        let ret = instance
+            .handler
+            .call(&mut *store, (input_ptr, input_len))
+            .map_err(runtime_error)?;
+        instance
+            .dealloc
+            .call(&mut *store, (input_ptr, input_len))
+            .map_err(runtime_error)?;
+
+        // The returned region is untrusted, check it before allocating the buffer on the host.
+        let output_ptr = (ret as u64 >> 32) as u32;
+        let output_len = ret as u64 as u32;
+        let memory_size = instance.memory.data_size(&*store) as u64;
+        if output_ptr as u64 + output_len as u64 > memory_size
+            || output_len as u64 > limits.max_memory
+        {
+            return Err(ErrorCode::UDFDataError(format!(
+                "WebAssembly UDF returned an invalid output region: ptr={output_ptr}, len={output_len}, memory size={memory_size}"
+            )));
+        }
+        let mut output = vec![0; output_len as usize];
+        instance
+            .memory
+            .read(&*store, output_ptr as usize, &mut output)
+            .map_err(|err| ErrorCode::UDFRuntimeError(format!("Cannot read wasm memory: {err}")))?;
+        instance
+            .dealloc
+            .call(&mut *store, (output_ptr as i32, output_len as i32))
+            .map_err(runtime_error)?;
+
+        decode_batch(&output)
+    }
+
+    fn instantiate(&self, limits: WasmLimits) -> Result<WasmInstance> {
+        let store_limits = StoreLimitsBuilder::new()
+            .memory_size(usize::try_from(limits.max_memory).unwrap_or(usize::MAX))
+            .instances(1)
+            .build();
+        let mut store = Store::new(&self.engine, store_limits);
+        store.limiter(|limits| limits);
+        store.set_fuel(limits.max_fuel).map_err(runtime_error)?;
+
+        let instance = Instance::new(&mut store, &self.module, &[]).map_err(runtime_error)?;
+        let memory = instance
+            .get_memory(&mut store, WASM_UDF_MEMORY)
+            .ok_or_else(|| {
+                ErrorCode::IllegalUDFFormat(format!(
+                    "WebAssembly UDF module must export '{WASM_UDF_MEMORY}'"
+                ))
+            })?;
+        let alloc = instance
+            .get_typed_func::<i32, i32>(&mut store, WASM_UDF_ALLOC)
+            .map_err(|err| export_error(WASM_UDF_ALLOC, err))?;
+        let dealloc = instance
+            .get_typed_func::<(i32, i32), ()>(&mut store, WASM_UDF_DEALLOC)
+            .map_err(|err| export_error(WASM_UDF_DEALLOC, err))?;
+        let handler = instance
+            .get_typed_func::<(i32, i32), i64>(&mut store, &self.handler)
+            .map_err(|err| export_error(&self.handler, err))?;
+
+        Ok(WasmInstance {
+            store,
+            memory,
+            alloc,
+            dealloc,
+            handler,
+        })
+    }
+}
+
+fn export_error(name: &str, err: wasmtime::Error) -> ErrorCode {
+    ErrorCode::IllegalUDFFormat(format!(
+        "WebAssembly UDF module does not export a valid '{name}': {err}"
+    ))
+}
+
+fn runtime_error(err: wasmtime::Error) -> ErrorCode {
+    ErrorCode::UDFRuntimeError(format!("WebAssembly UDF failed: {err}"))
+}
+
+fn encode_batch(batch: &RecordBatch) -> Result<Vec<u8>> {
+    let mut writer = StreamWriter::try_new(Vec::new(), &batch.schema())
+        .map_err(|err| ErrorCode::UDFDataError(format!("Encode record batch error: {err}")))?;
+    writer
+        .write(batch)
+        .map_err(|err| ErrorCode::UDFDataError(format!("Encode record batch error: {err}")))?;
+    writer
+        .into_inner()
+        .map_err(|err| ErrorCode::UDFDataError(format!("Encode record batch error: {err}")))
+}
+
+fn decode_batch(bytes: &[u8]) -> Result<RecordBatch> {
+    let reader = StreamReader::try_new(Cursor::new(bytes), None)
+        .map_err(|err| ErrorCode::UDFDataError(format!("Decode record batch error: {err}")))?;
+    let schema = reader.schema();
+    let batches = reader
+        .collect::<std::result::Result<Vec<_>, _>>()
+        .map_err(|err| ErrorCode::UDFDataError(format!("Decode record batch error: {err}")))?;
+    if batches.is_empty() {
+        return Err(ErrorCode::EmptyDataFromServer(
+            "Get empty data from WebAssembly UDF",
+        ));
+    }
+    concat_batches(&schema, batches.iter()).map_err(|err| ErrorCode::UDFDataError(err.to_string()))
+}
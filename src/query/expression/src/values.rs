@@ -61,6 +61,7 @@ use crate::types::decimal::DecimalScalar;
 use crate::types::decimal::DecimalSize;
 use crate::types::decimal::DecimalType;
 use crate::types::geometry::compare_geometry;
+use crate::types::geometry::GeometryDomain;
 use crate::types::geometry::GeometryType;
 use crate::types::nullable::NullableColumn;
 use crate::types::nullable::NullableColumnBuilder;
@@ -544,10 +545,10 @@ impl<'a> ScalarRef<'a> {
                         .collect(),
                 )
             }
-            ScalarRef::Binary(_)
-            | ScalarRef::Bitmap(_)
-            | ScalarRef::Variant(_)
-            | ScalarRef::Geometry(_) => Domain::Undefined,
+            ScalarRef::Geometry(buf) => Domain::Geometry(GeometryDomain::from_ewkb(buf)),
+            ScalarRef::Binary(_) | ScalarRef::Bitmap(_) | ScalarRef::Variant(_) => {
+                Domain::Undefined
+            }
         }
     }
 
@@ -1077,9 +1078,8 @@ impl Column {
                 let domains = fields.iter().map(|col| col.domain()).collect::<Vec<_>>();
                 Domain::Tuple(domains)
             }
-            Column::Binary(_) | Column::Bitmap(_) | Column::Variant(_) | Column::Geometry(_) => {
-                Domain::Undefined
-            }
+            Column::Geometry(col) => Domain::Geometry(GeometryDomain::from_column(col)),
+            Column::Binary(_) | Column::Bitmap(_) | Column::Variant(_) => Domain::Undefined,
         }
     }
 
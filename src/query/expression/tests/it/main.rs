@@ -35,6 +35,7 @@ mod row;
 mod schema;
 mod serde;
 mod sort;
+mod udf_wasm;
 
 fn rand_block_for_all_types(num_rows: usize) -> DataBlock {
     let types = get_all_test_data_types();
@@ -0,0 +1,116 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use arrow_array::ArrayRef;
+use arrow_array::Int32Array;
+use arrow_array::RecordBatch;
+use databend_common_exception::ErrorCode;
+use databend_common_expression::udf_wasm::WasmLimits;
+use databend_common_expression::udf_wasm::WasmRuntime;
+
+// A bump allocator, an `identity` handler that echoes its input batch back,
+// two handlers that exceed the memory and fuel limits, and a handler that
+// returns a region outside of its memory.
+const MODULE: &str = r#"
+(module
+  (memory (export "memory") 1)
+  (global $next (mut i32) (i32.const 1024))
+  (func $alloc (export "alloc") (param $len i32) (result i32)
+    (local $ptr i32)
+    (local.set $ptr (global.get $next))
+    (global.set $next (i32.add (global.get $next) (local.get $len)))
+    (block $done
+      (loop $grow
+        (br_if $done
+          (i32.le_u (global.get $next) (i32.mul (memory.size) (i32.const 65536))))
+        (if (i32.eq (memory.grow (i32.const 1)) (i32.const -1))
+          (then unreachable))
+        (br $grow)))
+    (local.get $ptr))
+  (func (export "dealloc") (param i32 i32))
+  (func (export "identity") (param $ptr i32) (param $len i32) (result i64)
+    (local $out i32)
+    (local.set $out (call $alloc (local.get $len)))
+    (memory.copy (local.get $out) (local.get $ptr) (local.get $len))
+    (i64.or
+      (i64.shl (i64.extend_i32_u (local.get $out)) (i64.const 32))
+      (i64.extend_i32_u (local.get $len))))
+  (func (export "grow") (param i32 i32) (result i64)
+    (if (i32.eq (memory.grow (i32.const 1024)) (i32.const -1))
+      (then unreachable))
+    (i64.const 0))
+  (func (export "spin") (param i32 i32) (result i64)
+    (loop $forever (br $forever))
+    (i64.const 0))
+  (func (export "oversize") (param i32 i32) (result i64)
+    (i64.const 0xFFFFFFFF)))
+"#;
+
+const LIMITS: WasmLimits = WasmLimits {
+    max_memory: 16 * 1024 * 1024,
+    max_fuel: 10_000_000,
+};
+
+fn input_batch() -> RecordBatch {
+    let column: ArrayRef = Arc::new(Int32Array::from(vec![Some(1), None, Some(3)]));
+    RecordBatch::try_from_iter(vec![("arg1", column)]).unwrap()
+}
+
+#[test]
+fn test_wasm_udf_call() {
+    let runtime = WasmRuntime::try_create(MODULE.as_bytes(), "identity").unwrap();
+    runtime.validate(LIMITS).unwrap();
+
+    let input = input_batch();
+    let output = runtime.call(LIMITS, input.clone()).unwrap();
+    assert_eq!(output, input);
+}
+
+#[test]
+fn test_wasm_udf_invalid_module() {
+    let err = WasmRuntime::try_create(b"not a module", "identity")
+        .err()
+        .unwrap();
+    assert_eq!(err.code(), ErrorCode::ILLEGAL_U_D_F_FORMAT);
+
+    let module = r#"(module (import "env" "f" (func)))"#;
+    let err = WasmRuntime::try_create(module.as_bytes(), "f")
+        .err()
+        .unwrap();
+    assert_eq!(err.code(), ErrorCode::ILLEGAL_U_D_F_FORMAT);
+
+    let runtime = WasmRuntime::try_create(MODULE.as_bytes(), "missing").unwrap();
+    let err = runtime.validate(LIMITS).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::ILLEGAL_U_D_F_FORMAT);
+}
+
+#[test]
+fn test_wasm_udf_limits() {
+    let runtime = WasmRuntime::try_create(MODULE.as_bytes(), "grow").unwrap();
+    let err = runtime.call(LIMITS, input_batch()).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::U_D_F_RUNTIME_ERROR);
+
+    let runtime = WasmRuntime::try_create(MODULE.as_bytes(), "spin").unwrap();
+    let err = runtime.call(LIMITS, input_batch()).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::U_D_F_RUNTIME_ERROR);
+}
+
+#[test]
+fn test_wasm_udf_invalid_output() {
+    let runtime = WasmRuntime::try_create(MODULE.as_bytes(), "oversize").unwrap();
+    let err = runtime.call(LIMITS, input_batch()).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::U_D_F_DATA_ERROR);
+}
@@ -26,12 +26,14 @@ use databend_common_expression::types::NumberColumn;
 use databend_common_expression::types::ValueType;
 use databend_common_expression::Column;
 use databend_common_io::constants::FALSE_BYTES_NUM;
+use databend_common_io::constants::FALSE_BYTES_SHORT;
 use databend_common_io::constants::INF_BYTES_LONG;
 use databend_common_io::constants::INF_BYTES_LOWER;
 use databend_common_io::constants::NAN_BYTES_LOWER;
 use databend_common_io::constants::NAN_BYTES_SNAKE;
 use databend_common_io::constants::NULL_BYTES_UPPER;
 use databend_common_io::constants::TRUE_BYTES_NUM;
+use databend_common_io::constants::TRUE_BYTES_SHORT;
 use geozero::wkb::Ewkb;
 use geozero::CoordDimensions;
 use geozero::ToWkb;
@@ -100,6 +102,23 @@ impl FieldEncoderValues {
         }
     }
 
+    // PostgreSQL text format encodes booleans as 't' / 'f', and float8in only
+    // accepts "NaN" and "Infinity" for the special values.
+    pub fn create_for_postgres_handler(timezone: Tz) -> Self {
+        FieldEncoderValues {
+            common_settings: OutputCommonSettings {
+                true_bytes: TRUE_BYTES_SHORT.as_bytes().to_vec(),
+                false_bytes: FALSE_BYTES_SHORT.as_bytes().to_vec(),
+                null_bytes: NULL_BYTES_UPPER.as_bytes().to_vec(),
+                nan_bytes: NAN_BYTES_SNAKE.as_bytes().to_vec(),
+                inf_bytes: INF_BYTES_LONG.as_bytes().to_vec(),
+                timezone,
+                binary_format: Default::default(),
+            },
+            quote_char: b'\'',
+        }
+    }
+
     pub fn write_field(
         &self,
         column: &Column,
@@ -17,6 +17,7 @@ use databend_common_expression::DataBlock;
 pub mod csv;
 pub mod json;
 pub mod ndjson;
+mod orc;
 pub mod parquet;
 pub mod tsv;
 
@@ -25,6 +26,7 @@ pub use csv::CSVWithNamesAndTypesOutputFormat;
 pub use csv::CSVWithNamesOutputFormat;
 pub use json::JSONOutputFormat;
 pub use ndjson::NDJSONOutputFormatBase;
+pub use orc::OrcOutputFormat;
 pub use parquet::ParquetOutputFormat;
 pub use tsv::TSVOutputFormat;
 pub use tsv::TSVWithNamesAndTypesOutputFormat;
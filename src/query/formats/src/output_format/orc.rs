@@ -0,0 +1,73 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableSchemaRef;
+use databend_common_orc::CompressionKind;
+use databend_common_orc::OrcWriter;
+
+use crate::output_format::OutputFormat;
+use crate::FileFormatOptionsExt;
+
+/// Blocks are merged into stripes of about this size before being encoded.
+const STRIPE_SIZE: usize = 64 * 1024 * 1024;
+
+#[derive(Default)]
+pub struct OrcOutputFormat {
+    schema: TableSchemaRef,
+    data_blocks: Vec<DataBlock>,
+}
+
+impl OrcOutputFormat {
+    pub fn create(schema: TableSchemaRef, _options: &FileFormatOptionsExt) -> Self {
+        Self {
+            schema,
+            data_blocks: vec![],
+        }
+    }
+}
+
+impl OutputFormat for OrcOutputFormat {
+    fn serialize_block(&mut self, block: &DataBlock) -> Result<Vec<u8>> {
+        self.data_blocks.push(block.clone());
+        Ok(vec![])
+    }
+
+    fn buffer_size(&mut self) -> usize {
+        self.data_blocks.iter().map(|b| b.memory_size()).sum()
+    }
+
+    fn finalize(&mut self) -> Result<Vec<u8>> {
+        let blocks = std::mem::take(&mut self.data_blocks);
+        if blocks.is_empty() {
+            return Ok(vec![]);
+        }
+        let mut writer = OrcWriter::try_create(&self.schema, CompressionKind::Zlib)?;
+        let mut stripe = vec![];
+        let mut stripe_size = 0;
+        for block in blocks {
+            stripe_size += block.memory_size();
+            stripe.push(block);
+            if stripe_size >= STRIPE_SIZE {
+                writer.write(&DataBlock::concat(&std::mem::take(&mut stripe))?)?;
+                stripe_size = 0;
+            }
+        }
+        if !stripe.is_empty() {
+            writer.write(&DataBlock::concat(&stripe)?)?;
+        }
+        writer.finish()
+    }
+}
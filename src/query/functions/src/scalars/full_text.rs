@@ -0,0 +1,149 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+use databend_common_expression::types::Float32Type;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::F32;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+
+/// BM25 term frequency saturation parameter.
+pub const BM25_K1: f32 = 1.2;
+/// BM25 document length normalization parameter.
+pub const BM25_B: f32 = 0.75;
+
+/// Split a text into lowercase alphanumeric terms.
+///
+/// Both the inverted index and the `match` function use this tokenizer,
+/// so a term found by one is always found by the other.
+pub fn tokenize(text: &str) -> impl Iterator<Item = String> + '_ {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|term| !term.is_empty())
+        .map(|term| term.to_lowercase())
+}
+
+/// The BM25 inverse document frequency of a term found in `doc_freq` of the
+/// `num_docs` documents of the collection. It is always positive.
+pub fn bm25_idf(num_docs: u32, doc_freq: u32) -> f32 {
+    let num_docs = num_docs as f32;
+    let doc_freq = doc_freq as f32;
+    (1.0 + (num_docs - doc_freq + 0.5) / (doc_freq + 0.5)).ln()
+}
+
+/// The BM25 weight of a term that occurs `tf` times in a document of `doc_len` terms,
+/// where `avg_doc_len` is the average document length of the collection.
+pub fn bm25_term_weight(tf: u32, doc_len: u32, avg_doc_len: f32) -> f32 {
+    let tf = tf as f32;
+    let norm = if avg_doc_len > 0.0 {
+        1.0 - BM25_B + BM25_B * doc_len as f32 / avg_doc_len
+    } else {
+        1.0
+    };
+    tf * (BM25_K1 + 1.0) / (tf + BM25_K1 * norm)
+}
+
+/// BM25 score of each text against the query of its row, zero if no query term occurs
+/// in the text. `queries` holds either one query per text or a single query for all texts.
+///
+/// The texts are the collection: the document frequencies and the average length are
+/// taken over the texts having at least one term, as the inverted index of a block does,
+/// so the scores of the rows of a block can be compared with each other.
+pub fn match_scores(texts: &[&str], queries: &[&str]) -> Vec<f32> {
+    let queries = queries
+        .iter()
+        .map(|query| {
+            let mut terms = tokenize(query).collect::<Vec<_>>();
+            terms.sort();
+            terms.dedup();
+            terms
+        })
+        .collect::<Vec<_>>();
+    let all_terms = queries.iter().flatten().collect::<HashSet<_>>();
+
+    let mut num_docs = 0;
+    let mut total_len = 0;
+    let mut doc_freqs = HashMap::new();
+    let mut docs = Vec::with_capacity(texts.len());
+    for text in texts {
+        let mut doc_len = 0;
+        let mut term_freqs = HashMap::new();
+        for term in tokenize(text) {
+            doc_len += 1;
+            if all_terms.contains(&term) {
+                *term_freqs.entry(term).or_insert(0) += 1;
+            }
+        }
+        if doc_len > 0 {
+            num_docs += 1;
+            total_len += doc_len as u64;
+        }
+        for term in term_freqs.keys() {
+            *doc_freqs.entry(term.clone()).or_insert(0) += 1;
+        }
+        docs.push((doc_len, term_freqs));
+    }
+
+    let avg_doc_len = total_len as f32 / num_docs.max(1) as f32;
+    docs.iter()
+        .enumerate()
+        .map(|(row, (doc_len, term_freqs))| {
+            let query_terms = if queries.len() == 1 {
+                &queries[0]
+            } else {
+                &queries[row]
+            };
+            query_terms
+                .iter()
+                .filter_map(|term| {
+                    let tf = term_freqs.get(term)?;
+                    let idf = bm25_idf(num_docs, doc_freqs[term]);
+                    Some(idf * bm25_term_weight(*tf, *doc_len, avg_doc_len))
+                })
+                .sum()
+        })
+        .collect()
+}
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_passthrough_nullable_2_arg::<StringType, StringType, Float32Type, _, _>(
+        "match",
+        |_, _, _| FunctionDomain::Full,
+        |texts, queries, ctx| {
+            // The score of a row depends on the other rows, so the texts are scored together.
+            let is_scalar = matches!(
+                (&texts, &queries),
+                (ValueRef::Scalar(_), ValueRef::Scalar(_))
+            );
+            let texts = match texts {
+                ValueRef::Scalar(text) => vec![text; ctx.num_rows.max(1)],
+                ValueRef::Column(col) => col.iter().collect(),
+            };
+            let queries = match queries {
+                ValueRef::Scalar(query) => vec![query],
+                ValueRef::Column(col) => col.iter().collect(),
+            };
+            let scores = match_scores(&texts, &queries);
+            if is_scalar {
+                Value::Scalar(F32::from(scores[0]))
+            } else {
+                Value::Column(scores.into_iter().map(F32::from).collect::<Vec<_>>().into())
+            }
+        },
+    );
+}
@@ -12,27 +12,80 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_expression::types::boolean::BooleanDomain;
+use databend_common_expression::types::geometry::GeometryDomain;
 use databend_common_expression::types::geometry::GeometryType;
+use databend_common_expression::types::ArgType;
+use databend_common_expression::types::BinaryType;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::Int32Type;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringType;
+use databend_common_expression::types::VariantType;
 use databend_common_expression::types::F64;
 use databend_common_expression::vectorize_with_builder_1_arg;
 use databend_common_expression::vectorize_with_builder_2_arg;
+use databend_common_expression::vectorize_with_builder_3_arg;
+use databend_common_expression::EvalContext;
 use databend_common_expression::FunctionDomain;
 use databend_common_expression::FunctionRegistry;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+use geo::Area;
+use geo::BooleanOps;
+use geo::BoundingRect;
+use geo::Centroid;
+use geo::Coord;
+use geo::EuclideanDistance;
+use geo::EuclideanLength;
 use geo::Geometry;
+use geo::GeometryCollection;
+use geo::Line;
+use geo::LineString;
+use geo::MultiPolygon;
 use geo::Point;
+use geo::Polygon;
+use geo::Relate;
+use geozero::geojson::GeoJson;
 use geozero::wkb::Ewkb;
+use geozero::wkt::WktStr;
 use geozero::CoordDimensions;
+use geozero::ToGeo;
+use geozero::ToJson;
 use geozero::ToWkb;
 use geozero::ToWkt;
+use jsonb::parse_value;
+
+/// Number of segments used to approximate a full circle in `st_buffer`.
+const BUFFER_CIRCLE_SEGMENTS: usize = 32;
+
+const ALL_FALSE_DOMAIN: BooleanDomain = BooleanDomain {
+    has_false: true,
+    has_true: false,
+};
 
 pub fn register(registry: &mut FunctionRegistry) {
     registry.register_aliases("st_makepoint", &["st_point"]);
+    registry.register_aliases("st_geomfromwkt", &[
+        "st_geometryfromwkt",
+        "st_geomfromtext",
+        "st_geometryfromtext",
+    ]);
+    registry.register_aliases("st_geomfromwkb", &["st_geometryfromwkb"]);
+    registry.register_aliases("st_geomfromgeojson", &["st_geometryfromgeojson"]);
+    registry.register_aliases("st_aswkt", &["st_astext"]);
+    registry.register_aliases("st_aswkb", &["st_asbinary"]);
 
     registry.register_passthrough_nullable_2_arg::<NumberType<F64>, NumberType<F64>, GeometryType, _, _>(
         "st_makepoint",
-        |_,_, _| FunctionDomain::Full,
+        |_, longitude, latitude| {
+            FunctionDomain::Domain(GeometryDomain {
+                min_x: longitude.min.0,
+                min_y: latitude.min.0,
+                max_x: longitude.max.0,
+                max_y: latitude.max.0,
+            })
+        },
         vectorize_with_builder_2_arg::<NumberType<F64>, NumberType<F64>, GeometryType>(|longitude, latitude, builder, ctx| {
             if let Some(validity) = &ctx.validity {
                 if !validity.get_bit(builder.len()) {
@@ -74,4 +127,497 @@ pub fn register(registry: &mut FunctionRegistry) {
             builder.commit_row();
         }),
     );
+
+    // Constructors.
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "st_geomfromwkt",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_geometry_1_arg::<StringType, GeometryType>(|wkt| {
+            let (geo, srid) = parse_ewkt(wkt)?;
+            geometry_to_ewkb(&geo, srid)
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<StringType, Int32Type, GeometryType, _, _>(
+        "st_geomfromwkt",
+        |_, _, _| FunctionDomain::MayThrow,
+        vectorize_geometry_2_arg::<StringType, Int32Type, GeometryType>(|wkt, srid| {
+            let (geo, _) = parse_ewkt(wkt)?;
+            geometry_to_ewkb(&geo, Some(srid))
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<BinaryType, GeometryType, _, _>(
+        "st_geomfromwkb",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_geometry_1_arg::<BinaryType, GeometryType>(|wkb| {
+            let geo = parse_ewkb(wkb)?;
+            geometry_to_ewkb(&geo, read_ewkb_srid(wkb))
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "st_geomfromwkb",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_geometry_1_arg::<StringType, GeometryType>(|hex_wkb| {
+            let wkb = hex::decode(hex_wkb.trim()).map_err(|e| format!("invalid hex WKB: {e}"))?;
+            let geo = parse_ewkb(&wkb)?;
+            geometry_to_ewkb(&geo, read_ewkb_srid(&wkb))
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<StringType, GeometryType, _, _>(
+        "st_geomfromgeojson",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_geometry_1_arg::<StringType, GeometryType>(|json| {
+            let geo = GeoJson(json)
+                .to_geo()
+                .map_err(|e| format!("invalid GeoJSON: {e}"))?;
+            geometry_to_ewkb(&geo, None)
+        }),
+    );
+
+    // Serializers.
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_aswkt",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_geometry_1_arg::<GeometryType, StringType>(|ewkb| {
+            parse_ewkb(ewkb)?.to_wkt().map_err(|e| e.to_string())
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, StringType, _, _>(
+        "st_asewkt",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_geometry_1_arg::<GeometryType, StringType>(|ewkb| {
+            Ewkb(ewkb.to_vec()).to_ewkt(None).map_err(|e| e.to_string())
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, BinaryType, _, _>(
+        "st_aswkb",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_geometry_1_arg::<GeometryType, BinaryType>(|ewkb| {
+            parse_ewkb(ewkb)?
+                .to_wkb(CoordDimensions::xy())
+                .map_err(|e| e.to_string())
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, BinaryType, _, _>(
+        "st_asewkb",
+        |_, _| FunctionDomain::Full,
+        vectorize_geometry_1_arg::<GeometryType, BinaryType>(|ewkb| Ok(ewkb.to_vec())),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, VariantType, _, _>(
+        "st_asgeojson",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_geometry_1_arg::<GeometryType, VariantType>(|ewkb| {
+            let json = parse_ewkb(ewkb)?.to_json().map_err(|e| e.to_string())?;
+            let value = parse_value(json.as_bytes()).map_err(|e| e.to_string())?;
+            let mut buf = Vec::new();
+            value.write_to_vec(&mut buf);
+            Ok(buf)
+        }),
+    );
+
+    // Predicates, two geometries can only be related if their bounding boxes intersect.
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_contains",
+        |_, l, r| calc_intersects_domain(l, r),
+        vectorize_geometry_2_arg::<GeometryType, GeometryType, BooleanType>(|l, r| {
+            let (l, r) = parse_ewkb_pair(l, r)?;
+            Ok(l.relate(&r).is_contains())
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_intersects",
+        |_, l, r| calc_intersects_domain(l, r),
+        vectorize_geometry_2_arg::<GeometryType, GeometryType, BooleanType>(|l, r| {
+            let (l, r) = parse_ewkb_pair(l, r)?;
+            Ok(l.relate(&r).is_intersects())
+        }),
+    );
+
+    registry.register_passthrough_nullable_2_arg::<GeometryType, GeometryType, BooleanType, _, _>(
+        "st_within",
+        |_, l, r| calc_intersects_domain(l, r),
+        vectorize_geometry_2_arg::<GeometryType, GeometryType, BooleanType>(|l, r| {
+            let (l, r) = parse_ewkb_pair(l, r)?;
+            Ok(l.relate(&r).is_within())
+        }),
+    );
+
+    registry.register_passthrough_nullable_3_arg::<GeometryType, GeometryType, NumberType<F64>, BooleanType, _, _>(
+        "st_dwithin",
+        |_, l, r, distance| calc_intersects_domain(&l.expand(distance.max.0), r),
+        vectorize_geometry_3_arg::<GeometryType, GeometryType, NumberType<F64>, BooleanType>(
+            |l, r, distance| {
+                let (l, r) = parse_ewkb_pair(l, r)?;
+                Ok(geometry_distance(&l, &r)? <= distance.0)
+            },
+        ),
+    );
+
+    // Measures.
+    registry.register_passthrough_nullable_1_arg::<GeometryType, NumberType<F64>, _, _>(
+        "st_area",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_geometry_1_arg::<GeometryType, NumberType<F64>>(|ewkb| {
+            Ok(F64::from(parse_ewkb(ewkb)?.unsigned_area()))
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, NumberType<F64>, _, _>(
+        "st_length",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_geometry_1_arg::<GeometryType, NumberType<F64>>(|ewkb| {
+            Ok(F64::from(geometry_length(&parse_ewkb(ewkb)?)))
+        }),
+    );
+
+    registry
+        .register_passthrough_nullable_2_arg::<GeometryType, GeometryType, NumberType<F64>, _, _>(
+            "st_distance",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_geometry_2_arg::<GeometryType, GeometryType, NumberType<F64>>(|l, r| {
+                let (l, r) = parse_ewkb_pair(l, r)?;
+                Ok(F64::from(geometry_distance(&l, &r)?))
+            }),
+        );
+
+    // Transforms, the results keep the SRID of the input geometry.
+    registry
+        .register_passthrough_nullable_2_arg::<GeometryType, NumberType<F64>, GeometryType, _, _>(
+            "st_buffer",
+            |_, _, _| FunctionDomain::MayThrow,
+            vectorize_geometry_2_arg::<GeometryType, NumberType<F64>, GeometryType>(
+                |ewkb, distance| {
+                    let geo = geometry_buffer(&parse_ewkb(ewkb)?, distance.0)?;
+                    geometry_to_ewkb(&geo, read_ewkb_srid(ewkb))
+                },
+            ),
+        );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, GeometryType, _, _>(
+        "st_centroid",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_geometry_1_arg::<GeometryType, GeometryType>(|ewkb| {
+            let geo = match parse_ewkb(ewkb)?.centroid() {
+                Some(point) => Geometry::Point(point),
+                None => Geometry::GeometryCollection(GeometryCollection::default()),
+            };
+            geometry_to_ewkb(&geo, read_ewkb_srid(ewkb))
+        }),
+    );
+
+    registry.register_passthrough_nullable_1_arg::<GeometryType, GeometryType, _, _>(
+        "st_envelope",
+        |_, _| FunctionDomain::MayThrow,
+        vectorize_geometry_1_arg::<GeometryType, GeometryType>(|ewkb| {
+            let geo = match parse_ewkb(ewkb)?.bounding_rect() {
+                Some(rect) if rect.min() == rect.max() => Geometry::Point(rect.min().into()),
+                Some(rect) if rect.width() == 0.0 || rect.height() == 0.0 => {
+                    Geometry::LineString(LineString::new(vec![rect.min(), rect.max()]))
+                }
+                Some(rect) => Geometry::Polygon(rect.to_polygon()),
+                None => Geometry::GeometryCollection(GeometryCollection::default()),
+            };
+            geometry_to_ewkb(&geo, read_ewkb_srid(ewkb))
+        }),
+    );
+}
+
+fn vectorize_geometry_1_arg<I1: ArgType, O: ArgType>(
+    func: impl Fn(I1::ScalarRef<'_>) -> Result<O::Scalar, String> + Copy + Send + Sync,
+) -> impl Fn(ValueRef<I1>, &mut EvalContext) -> Value<O> + Copy + Send + Sync {
+    vectorize_with_builder_1_arg::<I1, O>(move |arg1, builder, ctx| {
+        push_geometry_result::<O>(builder, ctx, || func(arg1))
+    })
+}
+
+fn vectorize_geometry_2_arg<I1: ArgType, I2: ArgType, O: ArgType>(
+    func: impl Fn(I1::ScalarRef<'_>, I2::ScalarRef<'_>) -> Result<O::Scalar, String>
+    + Copy
+    + Send
+    + Sync,
+) -> impl Fn(ValueRef<I1>, ValueRef<I2>, &mut EvalContext) -> Value<O> + Copy + Send + Sync {
+    vectorize_with_builder_2_arg::<I1, I2, O>(move |arg1, arg2, builder, ctx| {
+        push_geometry_result::<O>(builder, ctx, || func(arg1, arg2))
+    })
+}
+
+fn vectorize_geometry_3_arg<I1: ArgType, I2: ArgType, I3: ArgType, O: ArgType>(
+    func: impl Fn(I1::ScalarRef<'_>, I2::ScalarRef<'_>, I3::ScalarRef<'_>) -> Result<O::Scalar, String>
+    + Copy
+    + Send
+    + Sync,
+) -> impl Fn(ValueRef<I1>, ValueRef<I2>, ValueRef<I3>, &mut EvalContext) -> Value<O> + Copy + Send + Sync
+{
+    vectorize_with_builder_3_arg::<I1, I2, I3, O>(move |arg1, arg2, arg3, builder, ctx| {
+        push_geometry_result::<O>(builder, ctx, || func(arg1, arg2, arg3))
+    })
+}
+
+/// Evaluate one row and push the result, NULL rows are skipped without evaluation.
+fn push_geometry_result<O: ArgType>(
+    builder: &mut O::ColumnBuilder,
+    ctx: &mut EvalContext,
+    func: impl FnOnce() -> Result<O::Scalar, String>,
+) {
+    let row = O::builder_len(builder);
+    if let Some(validity) = &ctx.validity {
+        if !validity.get_bit(row) {
+            O::push_default(builder);
+            return;
+        }
+    }
+    match func() {
+        Ok(value) => O::push_item(builder, O::to_scalar_ref(&value)),
+        Err(err) => {
+            ctx.set_error(row, err);
+            O::push_default(builder);
+        }
+    }
+}
+
+fn calc_intersects_domain(l: &GeometryDomain, r: &GeometryDomain) -> FunctionDomain<BooleanType> {
+    if l.intersects(r) {
+        FunctionDomain::Full
+    } else {
+        FunctionDomain::Domain(ALL_FALSE_DOMAIN)
+    }
+}
+
+fn parse_ewkb(ewkb: &[u8]) -> Result<Geometry<f64>, String> {
+    Ewkb(ewkb.to_vec())
+        .to_geo()
+        .map_err(|e| format!("invalid geometry: {e}"))
+}
+
+fn parse_ewkb_pair(l: &[u8], r: &[u8]) -> Result<(Geometry<f64>, Geometry<f64>), String> {
+    let (l_srid, r_srid) = (read_ewkb_srid(l), read_ewkb_srid(r));
+    if l_srid.is_some() && r_srid.is_some() && l_srid != r_srid {
+        return Err(format!(
+            "incompatible SRID of geometries: {} and {}",
+            l_srid.unwrap(),
+            r_srid.unwrap()
+        ));
+    }
+    Ok((parse_ewkb(l)?, parse_ewkb(r)?))
+}
+
+/// Parse WKT or EWKT, e.g. `SRID=4326;POINT(1 2)`.
+fn parse_ewkt(ewkt: &str) -> Result<(Geometry<f64>, Option<i32>), String> {
+    let ewkt = ewkt.trim();
+    let (srid, wkt) = match ewkt.get(..5) {
+        Some(prefix) if prefix.eq_ignore_ascii_case("srid=") => {
+            let (srid, wkt) = ewkt[5..]
+                .split_once(';')
+                .ok_or_else(|| format!("invalid EWKT: {ewkt}"))?;
+            let srid = srid
+                .trim()
+                .parse::<i32>()
+                .map_err(|_| format!("invalid SRID: {srid}"))?;
+            (Some(srid), wkt)
+        }
+        _ => (None, ewkt),
+    };
+    let geo = WktStr(wkt)
+        .to_geo()
+        .map_err(|e| format!("invalid WKT: {e}"))?;
+    Ok((geo, srid))
+}
+
+fn geometry_to_ewkb(geo: &Geometry<f64>, srid: Option<i32>) -> Result<Vec<u8>, String> {
+    geo.to_ewkb(CoordDimensions::xy(), srid)
+        .map_err(|e| e.to_string())
+}
+
+/// Read the SRID from the header of EWKB, which is only present if the SRID flag of the type is set.
+fn read_ewkb_srid(ewkb: &[u8]) -> Option<i32> {
+    const EWKB_SRID_FLAG: u32 = 0x2000_0000;
+    if ewkb.len() < 9 {
+        return None;
+    }
+    let read_u32 = |bytes: &[u8]| {
+        let bytes = bytes.try_into().unwrap();
+        if ewkb[0] == 1 {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        }
+    };
+    if read_u32(&ewkb[1..5]) & EWKB_SRID_FLAG == 0 {
+        return None;
+    }
+    Some(read_u32(&ewkb[5..9]) as i32)
+}
+
+/// The points, segments and polygons a geometry is made of.
+#[derive(Default)]
+struct GeometryParts {
+    points: Vec<Point<f64>>,
+    lines: Vec<Line<f64>>,
+    polygons: Vec<Polygon<f64>>,
+}
+
+impl GeometryParts {
+    fn collect(geo: &Geometry<f64>) -> Self {
+        let mut parts = GeometryParts::default();
+        parts.add(geo);
+        parts
+    }
+
+    fn add(&mut self, geo: &Geometry<f64>) {
+        match geo {
+            Geometry::Point(point) => self.points.push(*point),
+            Geometry::Line(line) => self.lines.push(*line),
+            Geometry::LineString(line_string) => self.add_line_string(line_string),
+            Geometry::Polygon(polygon) => self.add_polygon(polygon),
+            Geometry::MultiPoint(points) => self.points.extend(points.iter().cloned()),
+            Geometry::MultiLineString(line_strings) => {
+                line_strings.iter().for_each(|l| self.add_line_string(l))
+            }
+            Geometry::MultiPolygon(polygons) => polygons.iter().for_each(|p| self.add_polygon(p)),
+            Geometry::GeometryCollection(geos) => geos.iter().for_each(|g| self.add(g)),
+            Geometry::Rect(rect) => self.add_polygon(&rect.to_polygon()),
+            Geometry::Triangle(triangle) => self.add_polygon(&triangle.to_polygon()),
+        }
+    }
+
+    fn add_line_string(&mut self, line_string: &LineString<f64>) {
+        match line_string.0.len() {
+            0 => {}
+            1 => self.points.push(Point::from(line_string.0[0])),
+            _ => self.lines.extend(line_string.lines()),
+        }
+    }
+
+    fn add_polygon(&mut self, polygon: &Polygon<f64>) {
+        self.add_line_string(polygon.exterior());
+        polygon
+            .interiors()
+            .iter()
+            .for_each(|l| self.add_line_string(l));
+        self.polygons.push(polygon.clone());
+    }
+
+    fn is_empty(&self) -> bool {
+        self.points.is_empty() && self.lines.is_empty()
+    }
+}
+
+fn geometry_length(geo: &Geometry<f64>) -> f64 {
+    match geo {
+        Geometry::Line(line) => line.euclidean_length(),
+        Geometry::LineString(line_string) => line_string.euclidean_length(),
+        Geometry::MultiLineString(line_strings) => line_strings.euclidean_length(),
+        Geometry::GeometryCollection(geos) => geos.iter().map(geometry_length).sum(),
+        _ => 0.0,
+    }
+}
+
+fn geometry_distance(l: &Geometry<f64>, r: &Geometry<f64>) -> Result<f64, String> {
+    let (l_parts, r_parts) = (GeometryParts::collect(l), GeometryParts::collect(r));
+    if l_parts.is_empty() || r_parts.is_empty() {
+        return Err("cannot compute the distance of an empty geometry".to_string());
+    }
+    if l.relate(r).is_intersects() {
+        return Ok(0.0);
+    }
+    // Disjoint geometries are closest at their boundaries.
+    let mut distance = f64::INFINITY;
+    for p in &l_parts.points {
+        for q in &r_parts.points {
+            distance = distance.min(p.euclidean_distance(q));
+        }
+        for line in &r_parts.lines {
+            distance = distance.min(p.euclidean_distance(line));
+        }
+    }
+    for line in &l_parts.lines {
+        for q in &r_parts.points {
+            distance = distance.min(q.euclidean_distance(line));
+        }
+        for other in &r_parts.lines {
+            distance = distance.min(line.euclidean_distance(other));
+        }
+    }
+    Ok(distance)
+}
+
+/// Buffer a geometry by the union of its polygons, a circle around every vertex
+/// and a rectangle along every segment, circles are approximated by polygons.
+fn geometry_buffer(geo: &Geometry<f64>, distance: f64) -> Result<Geometry<f64>, String> {
+    if !distance.is_finite() || distance < 0.0 {
+        return Err(format!(
+            "buffer distance must be a non-negative number, but got {distance}"
+        ));
+    }
+    if distance == 0.0 {
+        return Ok(geo.clone());
+    }
+
+    let parts = GeometryParts::collect(geo);
+    let mut pieces = parts.polygons.clone();
+    for point in &parts.points {
+        pieces.push(buffer_circle(point.0, distance));
+    }
+    for line in &parts.lines {
+        pieces.push(buffer_circle(line.start, distance));
+        pieces.push(buffer_circle(line.end, distance));
+        if let Some(rect) = buffer_segment(line, distance) {
+            pieces.push(rect);
+        }
+    }
+
+    let buffer = pieces
+        .into_iter()
+        .fold(MultiPolygon::new(vec![]), |acc, piece| {
+            acc.union(&MultiPolygon::new(vec![piece]))
+        });
+    Ok(match buffer.0.len() {
+        0 => Geometry::GeometryCollection(GeometryCollection::default()),
+        1 => Geometry::Polygon(buffer.0.into_iter().next().unwrap()),
+        _ => Geometry::MultiPolygon(buffer),
+    })
+}
+
+fn buffer_circle(center: Coord<f64>, radius: f64) -> Polygon<f64> {
+    let mut coords = (0..BUFFER_CIRCLE_SEGMENTS)
+        .map(|i| {
+            let angle = 2.0 * std::f64::consts::PI * i as f64 / BUFFER_CIRCLE_SEGMENTS as f64;
+            Coord {
+                x: center.x + radius * angle.cos(),
+                y: center.y + radius * angle.sin(),
+            }
+        })
+        .collect::<Vec<_>>();
+    coords.push(coords[0]);
+    Polygon::new(LineString::new(coords), vec![])
+}
+
+fn buffer_segment(line: &Line<f64>, distance: f64) -> Option<Polygon<f64>> {
+    let delta = line.delta();
+    let length = delta.x.hypot(delta.y);
+    if length == 0.0 {
+        return None;
+    }
+    let offset = Coord {
+        x: -delta.y / length * distance,
+        y: delta.x / length * distance,
+    };
+    Some(Polygon::new(
+        LineString::new(vec![
+            line.start + offset,
+            line.end + offset,
+            line.end - offset,
+            line.start - offset,
+            line.start + offset,
+        ]),
+        vec![],
+    ))
 }
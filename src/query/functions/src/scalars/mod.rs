@@ -24,6 +24,7 @@ mod comparison;
 mod control;
 mod datetime;
 mod decimal;
+mod full_text;
 mod geo;
 mod geo_h3;
 mod geometry;
@@ -31,6 +32,7 @@ mod hash;
 mod map;
 mod math;
 mod other;
+mod space_filling_curve;
 mod string;
 mod string_multi_args;
 mod tuple;
@@ -41,6 +43,10 @@ pub use comparison::check_pattern_type;
 pub use comparison::is_like_pattern_escape;
 pub use comparison::PatternType;
 pub use comparison::ALL_COMP_FUNC_NAMES;
+pub use full_text::bm25_idf;
+pub use full_text::bm25_term_weight;
+pub use full_text::match_scores;
+pub use full_text::tokenize;
 
 pub fn register(registry: &mut FunctionRegistry) {
     variant::register(registry);
@@ -64,4 +70,6 @@ pub fn register(registry: &mut FunctionRegistry) {
     vector::register(registry);
     bitmap::register(registry);
     geometry::register(registry);
+    full_text::register(registry);
+    space_filling_curve::register(registry);
 }
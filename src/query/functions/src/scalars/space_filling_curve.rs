@@ -0,0 +1,309 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Keys of the space-filling curves used to cluster a table on several columns.
+//!
+//! Every argument is mapped to an unsigned 64-bit integer which preserves the order of
+//! the values, the integers are then interleaved bit by bit into a binary key. Sorting by
+//! the key keeps the rows that are close in every dimension close to each other, so the
+//! min/max statistics of the blocks stay selective on all the cluster columns rather
+//! than on the first one only.
+//!
+//! The interleaving only balances the dimensions if their values spread over the same
+//! bits, a column holding small integers would otherwise be ordered by the low bits of
+//! the key only. `curve_coordinate(x, min, max)` scales a value linearly from its range
+//! to [0, 2^32), the clustered tables wrap every key of which the range is known into it.
+
+use std::sync::Arc;
+
+use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::types::decimal::DecimalScalar;
+use databend_common_expression::types::number::NumberDomain;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::number::SimpleDomain;
+use databend_common_expression::types::number::UInt64Type;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::Column;
+use databend_common_expression::Domain;
+use databend_common_expression::EvalContext;
+use databend_common_expression::FromData;
+use databend_common_expression::Function;
+use databend_common_expression::FunctionDomain;
+use databend_common_expression::FunctionEval;
+use databend_common_expression::FunctionRegistry;
+use databend_common_expression::FunctionSignature;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_expression::Value;
+use databend_common_expression::ValueRef;
+use ethnum::i256;
+
+/// The number of bits of a coordinate scaled by `curve_coordinate`.
+const COORDINATE_BITS: u32 = 32;
+
+pub fn register(registry: &mut FunctionRegistry) {
+    registry.register_function_factory("curve_coordinate", |_, args_type| {
+        curve_coordinate_function(args_type)
+    });
+    registry.register_function_factory("hilbert_key", |_, args_type| {
+        curve_key_function("hilbert_key", args_type, hilbert_index)
+    });
+    registry.register_function_factory("zorder_key", |_, args_type| {
+        curve_key_function("zorder_key", args_type, interleave_bits)
+    });
+}
+
+fn curve_key_function(
+    name: &str,
+    args_type: &[DataType],
+    encode: fn(&mut [u64], &mut Vec<u8>),
+) -> Option<Arc<Function>> {
+    if args_type.is_empty()
+        || !args_type
+            .iter()
+            .all(|ty| is_supported_type(ty.remove_nullable()))
+    {
+        return None;
+    }
+
+    Some(Arc::new(Function {
+        signature: FunctionSignature {
+            name: name.to_string(),
+            args_type: args_type.to_vec(),
+            return_type: DataType::Binary,
+        },
+        eval: FunctionEval::Scalar {
+            calc_domain: Box::new(|_, _| FunctionDomain::Full),
+            eval: Box::new(move |args, ctx| curve_key_fn(args, ctx, encode)),
+        },
+    }))
+}
+
+fn curve_coordinate_function(args_type: &[DataType]) -> Option<Arc<Function>> {
+    if args_type.len() != 3
+        || !args_type
+            .iter()
+            .all(|ty| is_supported_type(ty.remove_nullable()))
+    {
+        return None;
+    }
+
+    Some(Arc::new(Function {
+        signature: FunctionSignature {
+            name: "curve_coordinate".to_string(),
+            args_type: args_type.to_vec(),
+            return_type: DataType::Number(NumberDataType::UInt64),
+        },
+        eval: FunctionEval::Scalar {
+            calc_domain: Box::new(|_, _| {
+                FunctionDomain::Domain(Domain::Number(NumberDomain::UInt64(SimpleDomain {
+                    min: 0,
+                    max: (1 << COORDINATE_BITS) - 1,
+                })))
+            }),
+            eval: Box::new(curve_coordinate_fn),
+        },
+    }))
+}
+
+fn is_supported_type(ty: DataType) -> bool {
+    matches!(
+        ty,
+        DataType::Null
+            | DataType::Number(_)
+            | DataType::Decimal(_)
+            | DataType::Boolean
+            | DataType::String
+            | DataType::Binary
+            | DataType::Timestamp
+            | DataType::Date
+    )
+}
+
+fn curve_key_fn(
+    args: &[ValueRef<AnyType>],
+    _: &mut EvalContext,
+    encode: fn(&mut [u64], &mut Vec<u8>),
+) -> Value<AnyType> {
+    let len = args.iter().find_map(|arg| match arg {
+        ValueRef::Column(col) => Some(col.len()),
+        _ => None,
+    });
+
+    let size = len.unwrap_or(1);
+    let mut builder = BinaryColumnBuilder::with_capacity(size, size * args.len() * 8);
+    let mut point = vec![0u64; args.len()];
+    let mut key = Vec::with_capacity(args.len() * 8);
+    for idx in 0..size {
+        for (coordinate, arg) in point.iter_mut().zip(args) {
+            *coordinate = arg.index(idx).map(order_preserving_u64).unwrap_or(0);
+        }
+        key.clear();
+        encode(&mut point, &mut key);
+        builder.put_slice(&key);
+        builder.commit_row();
+    }
+
+    match len {
+        Some(_) => Value::Column(Column::Binary(builder.build())),
+        _ => Value::Scalar(Scalar::Binary(builder.build_scalar())),
+    }
+}
+
+fn curve_coordinate_fn(args: &[ValueRef<AnyType>], _: &mut EvalContext) -> Value<AnyType> {
+    let len = args.iter().find_map(|arg| match arg {
+        ValueRef::Column(col) => Some(col.len()),
+        _ => None,
+    });
+
+    let size = len.unwrap_or(1);
+    let mut builder = Vec::with_capacity(size);
+    for idx in 0..size {
+        let [value, min, max] = [&args[0], &args[1], &args[2]]
+            .map(|arg| arg.index(idx).map(order_preserving_u64).unwrap_or(0));
+        builder.push(scale_coordinate(value, min, max));
+    }
+
+    match len {
+        Some(_) => Value::Column(UInt64Type::from_data(builder)),
+        _ => Value::Scalar(Scalar::Number(NumberScalar::UInt64(builder[0]))),
+    }
+}
+
+/// Scales the value linearly from [min, max] to [0, 2^COORDINATE_BITS), the values out of
+/// the range are clamped to it.
+fn scale_coordinate(value: u64, min: u64, max: u64) -> u64 {
+    if max <= min {
+        return 0;
+    }
+    let offset = (value.clamp(min, max) - min) as u128;
+    let coordinate_max = (1u128 << COORDINATE_BITS) - 1;
+    (offset * coordinate_max / (max - min) as u128) as u64
+}
+
+/// Maps the value to an unsigned integer with the same order, NULL is the smallest value.
+///
+/// Strings and binaries keep their first 8 bytes, decimals are saturated to `i64`.
+fn order_preserving_u64(value: ScalarRef) -> u64 {
+    match value {
+        ScalarRef::Null => 0,
+        ScalarRef::Boolean(v) => (v as u64) << 63,
+        ScalarRef::Number(v) => match v {
+            NumberScalar::UInt8(v) => v as u64,
+            NumberScalar::UInt16(v) => v as u64,
+            NumberScalar::UInt32(v) => v as u64,
+            NumberScalar::UInt64(v) => v,
+            NumberScalar::Int8(v) => flip_sign(v as i64),
+            NumberScalar::Int16(v) => flip_sign(v as i64),
+            NumberScalar::Int32(v) => flip_sign(v as i64),
+            NumberScalar::Int64(v) => flip_sign(v),
+            NumberScalar::Float32(v) => float_bits(v.0 as f64),
+            NumberScalar::Float64(v) => float_bits(v.0),
+        },
+        ScalarRef::Decimal(v) => match v {
+            DecimalScalar::Decimal128(v, _) => {
+                flip_sign(v.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+            }
+            DecimalScalar::Decimal256(v, _) => {
+                flip_sign(v.clamp(i256::from(i64::MIN), i256::from(i64::MAX)).as_i64())
+            }
+        },
+        ScalarRef::Timestamp(v) => flip_sign(v),
+        ScalarRef::Date(v) => flip_sign(v as i64),
+        ScalarRef::String(v) => prefix_bytes(v.as_bytes()),
+        ScalarRef::Binary(v) => prefix_bytes(v),
+        _ => 0,
+    }
+}
+
+fn flip_sign(v: i64) -> u64 {
+    (v as u64) ^ (1 << 63)
+}
+
+fn float_bits(v: f64) -> u64 {
+    let bits = v.to_bits();
+    if bits >> 63 == 1 {
+        !bits
+    } else {
+        bits | (1 << 63)
+    }
+}
+
+fn prefix_bytes(v: &[u8]) -> u64 {
+    let mut bytes = [0u8; 8];
+    let len = v.len().min(8);
+    bytes[..len].copy_from_slice(&v[..len]);
+    u64::from_be_bytes(bytes)
+}
+
+/// Z-order (Morton) key: the bits of the coordinates are interleaved from the most
+/// significant one, the first argument takes the highest bit of each group.
+fn interleave_bits(point: &mut [u64], key: &mut Vec<u8>) {
+    let mut byte = 0u8;
+    let mut filled = 0;
+    for bit in (0..64).rev() {
+        for coordinate in point.iter() {
+            byte = (byte << 1) | ((coordinate >> bit) & 1) as u8;
+            filled += 1;
+            if filled == 8 {
+                key.push(byte);
+                byte = 0;
+                filled = 0;
+            }
+        }
+    }
+}
+
+/// Hilbert curve key, computed with the transform of John Skilling, "Programming the
+/// Hilbert curve" (AIP Conference Proceedings 707, 2004). The coordinates are converted
+/// to the transposed Hilbert index in place, which is then interleaved like a Z-order key.
+fn hilbert_index(point: &mut [u64], key: &mut Vec<u8>) {
+    let n = point.len();
+    if n > 1 {
+        // Inverse undo excess work.
+        let mut q = 1u64 << 63;
+        while q > 1 {
+            let p = q - 1;
+            for i in 0..n {
+                if point[i] & q != 0 {
+                    point[0] ^= p;
+                } else {
+                    let t = (point[0] ^ point[i]) & p;
+                    point[0] ^= t;
+                    point[i] ^= t;
+                }
+            }
+            q >>= 1;
+        }
+
+        // Gray encode.
+        for i in 1..n {
+            point[i] ^= point[i - 1];
+        }
+        let mut t = 0;
+        let mut q = 1u64 << 63;
+        while q > 1 {
+            if point[n - 1] & q != 0 {
+                t ^= q - 1;
+            }
+            q >>= 1;
+        }
+        for coordinate in point.iter_mut() {
+            *coordinate ^= t;
+        }
+    }
+    interleave_bits(point, key);
+}
@@ -27,6 +27,21 @@ fn test_geometry() {
 
     test_st_makepoint(file);
     test_to_string(file);
+    test_st_geomfromwkt(file);
+    test_st_geomfromwkb(file);
+    test_st_geomfromgeojson(file);
+    test_st_asewkt(file);
+    test_st_asgeojson(file);
+    test_st_contains(file);
+    test_st_intersects(file);
+    test_st_within(file);
+    test_st_dwithin(file);
+    test_st_area(file);
+    test_st_length(file);
+    test_st_distance(file);
+    test_st_buffer(file);
+    test_st_centroid(file);
+    test_st_envelope(file);
 }
 
 fn test_st_makepoint(file: &mut impl Write) {
@@ -45,3 +60,163 @@ fn test_to_string(file: &mut impl Write) {
         ("b", Float64Type::from_data(vec![1.0, 2.0, 3.0])),
     ]);
 }
+
+fn test_st_geomfromwkt(file: &mut impl Write) {
+    run_ast(file, "st_geomfromwkt('POINT(1 2)')", &[]);
+    run_ast(file, "st_geomfromwkt('SRID=4326;LINESTRING(0 0,3 4)')", &[]);
+    run_ast(file, "st_geomfromwkt('POINT(1 2)', 4326)", &[]);
+}
+
+fn test_st_geomfromwkb(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_aswkt(st_geomfromwkb('0101000000000000000000F03F0000000000000040'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_aswkt(st_geomfromwkb(st_aswkb(st_geomfromwkt('LINESTRING(0 0,3 4)'))))",
+        &[],
+    );
+}
+
+fn test_st_geomfromgeojson(file: &mut impl Write) {
+    run_ast(
+        file,
+        r#"st_aswkt(st_geomfromgeojson('{"type":"Point","coordinates":[1.5,2.5]}'))"#,
+        &[],
+    );
+}
+
+fn test_st_asewkt(file: &mut impl Write) {
+    run_ast(file, "st_asewkt(st_geomfromwkt('POINT(1 2)', 4326))", &[]);
+}
+
+fn test_st_asgeojson(file: &mut impl Write) {
+    run_ast(
+        file,
+        "to_string(st_asgeojson(st_geomfromwkt('POINT(1.5 2.5)')))",
+        &[],
+    );
+}
+
+fn test_st_contains(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_contains(st_geomfromwkt('POLYGON((0 0,4 0,4 4,0 4,0 0))'), st_geomfromwkt('POINT(1 1)'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_contains(st_geomfromwkt('POLYGON((0 0,4 0,4 4,0 4,0 0))'), st_geomfromwkt('POINT(5 5)'))",
+        &[],
+    );
+}
+
+fn test_st_intersects(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_intersects(st_geomfromwkt('LINESTRING(0 0,2 2)'), st_geomfromwkt('LINESTRING(0 2,2 0)'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_intersects(st_makepoint(a, b), st_geomfromwkt('POINT(10 10)'))",
+        &[
+            ("a", Float64Type::from_data(vec![1.0, 2.0, 3.0])),
+            ("b", Float64Type::from_data(vec![1.0, 2.0, 3.0])),
+        ],
+    );
+    run_ast(
+        file,
+        "st_intersects(st_makepoint(a, b), st_geomfromwkt('POLYGON((0 0,2 0,2 2,0 2,0 0))'))",
+        &[
+            ("a", Float64Type::from_data(vec![1.0, 2.0, 3.0])),
+            ("b", Float64Type::from_data(vec![1.0, 2.0, 3.0])),
+        ],
+    );
+}
+
+fn test_st_within(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_within(st_geomfromwkt('POINT(1 1)'), st_geomfromwkt('POLYGON((0 0,4 0,4 4,0 4,0 0))'))",
+        &[],
+    );
+}
+
+fn test_st_dwithin(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_dwithin(st_geomfromwkt('POINT(0 0)'), st_geomfromwkt('POINT(3 4)'), 5)",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_dwithin(st_geomfromwkt('POINT(0 0)'), st_geomfromwkt('POINT(3 4)'), 4)",
+        &[],
+    );
+}
+
+fn test_st_area(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_area(st_geomfromwkt('POLYGON((0 0,4 0,4 4,0 4,0 0))'))",
+        &[],
+    );
+}
+
+fn test_st_length(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_length(st_geomfromwkt('LINESTRING(0 0,3 4,3 10)'))",
+        &[],
+    );
+}
+
+fn test_st_distance(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_distance(st_geomfromwkt('POINT(0 0)'), st_geomfromwkt('LINESTRING(3 4,3 10)'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_distance(st_geomfromwkt('POINT(1 1)'), st_geomfromwkt('POLYGON((0 0,4 0,4 4,0 4,0 0))'))",
+        &[],
+    );
+}
+
+fn test_st_buffer(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_contains(st_buffer(st_geomfromwkt('POINT(0 0)'), 1), st_geomfromwkt('POINT(0.5 0.5)'))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_contains(st_buffer(st_geomfromwkt('LINESTRING(0 0,4 0)'), 1), st_geomfromwkt('POINT(2 0.9)'))",
+        &[],
+    );
+}
+
+fn test_st_centroid(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_aswkt(st_centroid(st_geomfromwkt('POLYGON((0 0,4 0,4 4,0 4,0 0))')))",
+        &[],
+    );
+}
+
+fn test_st_envelope(file: &mut impl Write) {
+    run_ast(
+        file,
+        "st_area(st_envelope(st_geomfromwkt('LINESTRING(0 0,3 4)')))",
+        &[],
+    );
+    run_ast(
+        file,
+        "st_aswkt(st_envelope(st_geomfromwkt('POINT(1 2)')))",
+        &[],
+    );
+}
@@ -0,0 +1,47 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::time::Duration;
+
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::SeqV;
+
+#[async_trait::async_trait]
+pub trait TaskApi: Sync + Send {
+    /// Adds the task with a newly allocated task id, returns the task id.
+    async fn add_task(&self, task: Task) -> Result<u64>;
+
+    async fn update_task(&self, task: Task, seq: MatchSeq) -> Result<u64>;
+
+    async fn drop_task(&self, name: &str, seq: MatchSeq) -> Result<()>;
+
+    async fn get_task(&self, name: &str, seq: MatchSeq) -> Result<SeqV<Task>>;
+
+    async fn get_tasks(&self) -> Result<Vec<Task>>;
+
+    /// Records a run of the task, the record is removed after `ttl`.
+    ///
+    /// A run with the same `run_id` of the task overrides the previous record.
+    async fn add_task_run(&self, run: TaskRun, ttl: Duration) -> Result<()>;
+
+    /// Returns the runs of the task in time order, or the runs of all the tasks if `name` is `None`.
+    async fn get_task_runs(&self, name: Option<&str>) -> Result<Vec<TaskRun>>;
+
+    /// Tries to hold or renew the lease to run the tasks of the tenant for `ttl`, returns
+    /// false if the lease is held by others.
+    async fn try_acquire_task_lease(&self, holder: &str, ttl: Duration) -> Result<bool>;
+}
@@ -0,0 +1,256 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::escape_for_key;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_kvapi::kvapi;
+use databend_common_meta_kvapi::kvapi::UpsertKVReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_meta_types::MatchSeqExt;
+use databend_common_meta_types::MetaError;
+use databend_common_meta_types::MetaSpec;
+use databend_common_meta_types::Operation;
+use databend_common_meta_types::SeqV;
+
+use crate::serde::deserialize_struct;
+use crate::serde::serialize_struct;
+use crate::task::task_api::TaskApi;
+
+static TASK_API_KEY_PREFIX: &str = "__fd_tasks";
+static TASK_RUN_API_KEY_PREFIX: &str = "__fd_task_runs";
+static TASK_LEASE_API_KEY_PREFIX: &str = "__fd_task_leases";
+static TASK_ID_GEN_KEY: &str = "__fd_id_gen/task_id";
+
+pub struct TaskMgr {
+    kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+    task_prefix: String,
+    task_run_prefix: String,
+    task_lease_key: String,
+}
+
+impl TaskMgr {
+    pub fn create(
+        kv_api: Arc<dyn kvapi::KVApi<Error = MetaError>>,
+        tenant: &str,
+    ) -> Result<Self, ErrorCode> {
+        if tenant.is_empty() {
+            return Err(ErrorCode::TenantIsEmpty(
+                "Tenant can not empty (while create task)",
+            ));
+        }
+
+        Ok(TaskMgr {
+            kv_api,
+            task_prefix: format!("{}/{}", TASK_API_KEY_PREFIX, tenant),
+            task_run_prefix: format!("{}/{}", TASK_RUN_API_KEY_PREFIX, tenant),
+            task_lease_key: format!("{}/{}", TASK_LEASE_API_KEY_PREFIX, tenant),
+        })
+    }
+
+    fn make_task_key(&self, name: &str) -> Result<String> {
+        Ok(format!("{}/{}", self.task_prefix, escape_for_key(name)?))
+    }
+
+    fn make_task_run_prefix(&self, name: &str) -> Result<String> {
+        Ok(format!(
+            "{}/{}/",
+            self.task_run_prefix,
+            escape_for_key(name)?
+        ))
+    }
+
+    #[async_backtrace::framed]
+    async fn generate_task_id(&self) -> Result<u64> {
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                TASK_ID_GEN_KEY,
+                MatchSeq::GE(0),
+                Operation::Update(vec![]),
+                None,
+            ))
+            .await?;
+        // MatchSeq::GE(0) always succeeds, the seq of the key is increased on every update.
+        Ok(res.result.map(|v| v.seq).unwrap_or_default())
+    }
+}
+
+#[async_trait::async_trait]
+impl TaskApi for TaskMgr {
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add_task(&self, mut task: Task) -> Result<u64> {
+        let key = self.make_task_key(task.task_name.as_str())?;
+        // Check the existence first, to not waste a task id.
+        if self.kv_api.get_kv(&key).await?.is_some() {
+            return Err(ErrorCode::TaskAlreadyExists(format!(
+                "Task '{}' already exists.",
+                task.task_name
+            )));
+        }
+
+        task.task_id = self.generate_task_id().await?;
+        let value = Operation::Update(serialize_struct(&task, ErrorCode::IllegalTask, || "")?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api.upsert_kv(UpsertKVReq::new(&key, MatchSeq::Exact(0), value, None));
+
+        upsert_kv.await?.added_seq_or_else(|_v| {
+            ErrorCode::TaskAlreadyExists(format!("Task '{}' already exists.", task.task_name))
+        })?;
+
+        Ok(task.task_id)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn update_task(&self, task: Task, match_seq: MatchSeq) -> Result<u64> {
+        let key = self.make_task_key(task.task_name.as_str())?;
+        let value = Operation::Update(serialize_struct(&task, ErrorCode::IllegalTask, || "")?);
+
+        let kv_api = self.kv_api.clone();
+        let upsert_kv = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, match_seq, value, None))
+            .await?;
+
+        match upsert_kv.result {
+            Some(SeqV { seq: s, .. }) if upsert_kv.prev.as_ref().map(|p| p.seq) != Some(s) => Ok(s),
+            _ => Err(ErrorCode::UnknownTask(format!(
+                "Task '{}' does not exist or has been changed.",
+                task.task_name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn drop_task(&self, name: &str, seq: MatchSeq) -> Result<()> {
+        let key = self.make_task_key(name)?;
+        let kv_api = self.kv_api.clone();
+        let res = kv_api
+            .upsert_kv(UpsertKVReq::new(&key, seq, Operation::Delete, None))
+            .await?;
+        if res.prev.is_some() && res.result.is_none() {
+            Ok(())
+        } else {
+            Err(ErrorCode::UnknownTask(format!(
+                "Cannot delete task '{}'. It may not exist.",
+                name
+            )))
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_task(&self, name: &str, seq: MatchSeq) -> Result<SeqV<Task>> {
+        let key = self.make_task_key(name)?;
+        let res = self.kv_api.get_kv(&key).await?;
+        let seq_value =
+            res.ok_or_else(|| ErrorCode::UnknownTask(format!("Task '{}' does not exist.", name)))?;
+
+        match seq.match_seq(&seq_value) {
+            Ok(_) => Ok(SeqV::new(
+                seq_value.seq,
+                deserialize_struct(&seq_value.data, ErrorCode::IllegalTask, || "")?,
+            )),
+            Err(_) => Err(ErrorCode::UnknownTask(format!(
+                "Task '{}' does not exist.",
+                name
+            ))),
+        }
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_tasks(&self) -> Result<Vec<Task>> {
+        let values = self
+            .kv_api
+            .prefix_list_kv(&format!("{}/", self.task_prefix))
+            .await?;
+
+        let mut tasks = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let task = deserialize_struct(&value.data, ErrorCode::IllegalTask, || "")?;
+            tasks.push(task);
+        }
+        Ok(tasks)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn add_task_run(&self, run: TaskRun, ttl: Duration) -> Result<()> {
+        // The run id is zero padded to keep the records of a task in time order.
+        let key = format!(
+            "{}{:020}",
+            self.make_task_run_prefix(&run.task.task_name)?,
+            run.run_id
+        );
+        let value = Operation::Update(serialize_struct(&run, ErrorCode::IllegalTask, || "")?);
+
+        self.kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &key,
+                MatchSeq::GE(0),
+                value,
+                Some(MetaSpec::new_ttl(ttl)),
+            ))
+            .await?;
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn get_task_runs(&self, name: Option<&str>) -> Result<Vec<TaskRun>> {
+        let prefix = match name {
+            Some(name) => self.make_task_run_prefix(name)?,
+            None => format!("{}/", self.task_run_prefix),
+        };
+        let values = self.kv_api.prefix_list_kv(&prefix).await?;
+
+        let mut runs = Vec::with_capacity(values.len());
+        for (_, value) in values {
+            let run = deserialize_struct(&value.data, ErrorCode::IllegalTask, || "")?;
+            runs.push(run);
+        }
+        Ok(runs)
+    }
+
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn try_acquire_task_lease(&self, holder: &str, ttl: Duration) -> Result<bool> {
+        let match_seq = match self.kv_api.get_kv(&self.task_lease_key).await? {
+            None => MatchSeq::Exact(0),
+            // Renew the lease held by the holder.
+            Some(v) if v.data == holder.as_bytes() => MatchSeq::Exact(v.seq),
+            Some(_) => return Ok(false),
+        };
+
+        let res = self
+            .kv_api
+            .upsert_kv(UpsertKVReq::new(
+                &self.task_lease_key,
+                match_seq,
+                Operation::Update(holder.as_bytes().to_vec()),
+                Some(MetaSpec::new_ttl(ttl)),
+            ))
+            .await?;
+        Ok(res.is_changed())
+    }
+}
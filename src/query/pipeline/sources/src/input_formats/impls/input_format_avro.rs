@@ -0,0 +1,853 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::mem;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use apache_avro::types::Value;
+use chrono_tz::Tz;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::array::ArrayColumnBuilder;
+use databend_common_expression::types::binary::BinaryColumnBuilder;
+use databend_common_expression::types::date::check_date;
+use databend_common_expression::types::date::date_to_string;
+use databend_common_expression::types::decimal::Decimal;
+use databend_common_expression::types::decimal::DecimalColumnBuilder;
+use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::number::Number;
+use databend_common_expression::types::string::StringColumnBuilder;
+use databend_common_expression::types::timestamp::check_timestamp;
+use databend_common_expression::types::timestamp::timestamp_to_string;
+use databend_common_expression::types::AnyType;
+use databend_common_expression::types::NumberColumnBuilder;
+use databend_common_expression::with_decimal_type;
+use databend_common_expression::with_number_mapped_type;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_meta_app::principal::AvroFileFormatParams;
+use databend_common_meta_app::principal::NullAs;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_settings::Settings;
+use databend_common_storage::AvroBlockHeader;
+use databend_common_storage::AvroFileHeader;
+use databend_common_storage::AvroSchema;
+use databend_common_storage::FileParseError;
+use databend_common_storage::FileStatus;
+use databend_common_storage::StageFileInfo;
+use databend_common_storage::AVRO_SYNC_MARKER_SIZE;
+use ethnum::i256;
+use log::debug;
+use num_traits::NumCast;
+use opendal::Operator;
+use serde_json::Value as JsonValue;
+
+use crate::input_formats::error_utils::truncate_column_data;
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+pub struct InputFormatAvro;
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatAvro {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        _op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        // Data blocks can only be located after reading the header, so each file is one split.
+        let infos = file_infos
+            .into_iter()
+            .map(|info| {
+                let size = info.size as usize;
+                let file = Arc::new(FileInfo {
+                    path: info.path,
+                    size,
+                    num_splits: 1,
+                    compress_alg: None,
+                });
+                Arc::new(SplitInfo {
+                    file,
+                    seq_in_file: 0,
+                    offset: 0,
+                    size,
+                    num_file_splits: 1,
+                    format_info: None,
+                })
+            })
+            .collect();
+        Ok(infos)
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        AvroFormatPipe::execute_copy_with_aligner(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        AvroFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+pub struct AvroFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for AvroFormatPipe {
+    type SplitMeta = ();
+    type ReadBatch = Vec<u8>;
+    type RowBatch = AvroBlockBatch;
+    type AligningState = AvroAligningState;
+    type BlockBuilder = AvroBlockBuilder;
+
+    fn try_create_align_state(
+        _ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<AvroAligningState> {
+        Ok(AvroAligningState {
+            split_info: split_info.clone(),
+            buf: vec![],
+            header: None,
+            batch_id: 0,
+            rows: 0,
+        })
+    }
+
+    fn try_create_block_builder(ctx: &Arc<InputContext>) -> Result<AvroBlockBuilder> {
+        Ok(AvroBlockBuilder::create(ctx.clone()))
+    }
+}
+
+/// One data block of an Avro file, prefixed with the file header so that it
+/// can be decoded on its own.
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct AvroBlockBatch {
+    pub data: Vec<u8>,
+    pub num_rows: usize,
+
+    pub split_info: Arc<SplitInfo>,
+    // for error info
+    pub batch_id: usize,
+    pub start_row_in_split: usize,
+}
+
+impl RowBatchTrait for AvroBlockBatch {
+    fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    fn rows(&self) -> usize {
+        self.num_rows
+    }
+}
+
+#[typetag::serde(name = "row_batch_avro")]
+impl BlockMetaInfo for AvroBlockBatch {
+    fn equals(&self, _info: &Box<dyn BlockMetaInfo>) -> bool {
+        unreachable!("AvroBlockBatch as BlockMetaInfo is not expected to be compared.")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unreachable!("AvroBlockBatch as BlockMetaInfo is not expected to be cloned.")
+    }
+}
+
+pub struct AvroAligningState {
+    split_info: Arc<SplitInfo>,
+    buf: Vec<u8>,
+    // the raw bytes of the file header, once it is complete.
+    header: Option<(Vec<u8>, [u8; AVRO_SYNC_MARKER_SIZE])>,
+    batch_id: usize,
+    rows: usize,
+}
+
+impl AvroAligningState {
+    fn align_blocks(&mut self) -> Result<Vec<AvroBlockBatch>> {
+        let mut consumed = 0;
+        if self.header.is_none() {
+            match AvroFileHeader::try_decode(&self.buf)? {
+                Some(header) => {
+                    let raw_header = self.buf[..header.size].to_vec();
+                    self.header = Some((raw_header, header.sync_marker));
+                    consumed = header.size;
+                }
+                None => return Ok(vec![]),
+            }
+        }
+        let (raw_header, sync_marker) = self.header.as_ref().expect("must success");
+
+        let mut batches = vec![];
+        while let Some(block) = AvroBlockHeader::try_decode(&self.buf[consumed..])? {
+            let block_size = block.size + block.data_size + AVRO_SYNC_MARKER_SIZE;
+            if self.buf.len() - consumed < block_size {
+                break;
+            }
+            let block_data = &self.buf[consumed..consumed + block_size];
+            if &block_data[block_size - AVRO_SYNC_MARKER_SIZE..] != sync_marker {
+                return Err(ErrorCode::BadBytes(format!(
+                    "Invalid Avro data block at offset {} of {}: sync marker mismatch",
+                    self.split_info.offset + consumed,
+                    self.split_info.file.path
+                )));
+            }
+
+            let mut data = Vec::with_capacity(raw_header.len() + block_size);
+            data.extend_from_slice(raw_header);
+            data.extend_from_slice(block_data);
+            batches.push(AvroBlockBatch {
+                data,
+                num_rows: block.num_rows,
+                split_info: self.split_info.clone(),
+                batch_id: self.batch_id,
+                start_row_in_split: self.rows,
+            });
+            self.batch_id += 1;
+            self.rows += block.num_rows;
+            consumed += block_size;
+        }
+        self.buf.drain(..consumed);
+        Ok(batches)
+    }
+}
+
+impl AligningStateTrait for AvroAligningState {
+    type Pipe = AvroFormatPipe;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<AvroBlockBatch>> {
+        match read_batch {
+            Some(data) => {
+                self.buf.extend_from_slice(&data);
+                self.align_blocks()
+            }
+            None => {
+                if !self.buf.is_empty() {
+                    let what = if self.header.is_none() {
+                        "header"
+                    } else {
+                        "data block"
+                    };
+                    return Err(ErrorCode::BadBytes(format!(
+                        "Invalid Avro object container file {}: incomplete {} of {} bytes at the end",
+                        self.split_info.file.path,
+                        what,
+                        self.buf.len()
+                    )));
+                }
+                debug!(
+                    "align avro file {} to {} blocks of {} rows",
+                    self.split_info.file.path, self.batch_id, self.rows
+                );
+                Ok(vec![])
+            }
+        }
+    }
+}
+
+pub struct AvroBlockBuilder {
+    ctx: Arc<InputContext>,
+    mutable_columns: Vec<ColumnBuilder>,
+    num_rows: usize,
+    file_status: FileStatus,
+    decoder: AvroDecoder,
+}
+
+impl AvroBlockBuilder {
+    fn create(ctx: Arc<InputContext>) -> Self {
+        let columns = ctx
+            .schema
+            .fields()
+            .iter()
+            .map(|f| ColumnBuilder::with_capacity_hint(&f.data_type().into(), 1024, false))
+            .collect();
+        let decoder = AvroDecoder {
+            timezone: ctx.file_format_options_ext.timezone,
+        };
+        AvroBlockBuilder {
+            ctx,
+            mutable_columns: columns,
+            num_rows: 0,
+            file_status: Default::default(),
+            decoder,
+        }
+    }
+
+    fn read_batch(&mut self, batch: &AvroBlockBatch) -> Result<()> {
+        let header = AvroFileHeader::try_decode(&batch.data)?.expect("must success");
+        let AvroSchema::Record(avro_fields) = &header.schema else {
+            return Err(ErrorCode::BadBytes(format!(
+                "The schema of Avro file {} must be a record",
+                batch.split_info.file.path
+            )));
+        };
+
+        // map the table columns to the fields of the records.
+        let ident_case_sensitive = self.ctx.file_format_options_ext.ident_case_sensitive;
+        let field_indexes = self
+            .ctx
+            .schema
+            .fields()
+            .iter()
+            .map(|f| {
+                avro_fields.iter().position(|(name, _)| {
+                    if ident_case_sensitive {
+                        name == f.name()
+                    } else {
+                        name.eq_ignore_ascii_case(f.name())
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let missing_field_as =
+            AvroFileFormatParams::downcast_unchecked(&self.ctx.file_format_params)
+                .missing_field_as
+                .clone();
+        let reader = apache_avro::Reader::new(batch.data.as_slice()).map_err(|e| {
+            ErrorCode::BadBytes(format!(
+                "Invalid Avro file {}: {}",
+                batch.split_info.file.path, e
+            ))
+        })?;
+        for (i, record) in reader.enumerate() {
+            let record = record.map_err(|e| {
+                ErrorCode::BadBytes(format!(
+                    "Invalid Avro data block {} of {}: {}",
+                    batch.batch_id, batch.split_info.file.path, e
+                ))
+            })?;
+            if let Err(e) = self.read_row(avro_fields, &field_indexes, &missing_field_as, &record) {
+                self.ctx.on_error(
+                    e,
+                    Some((&mut self.mutable_columns, self.num_rows)),
+                    &mut self.file_status,
+                    &batch.split_info.file.path,
+                    batch.start_row_in_split + i,
+                )?
+            } else {
+                self.num_rows += 1;
+                self.file_status.num_rows_loaded += 1;
+            }
+        }
+        Ok(())
+    }
+
+    fn read_row(
+        &mut self,
+        avro_fields: &[(String, AvroSchema)],
+        field_indexes: &[Option<usize>],
+        missing_field_as: &NullAs,
+        record: &Value,
+    ) -> std::result::Result<(), FileParseError> {
+        let Value::Record(values) = record else {
+            return Err(FileParseError::ColumnDecodeError {
+                column_index: 0,
+                column_name: "".to_string(),
+                column_type: "".to_string(),
+                decode_error: "Avro data must be records".to_string(),
+                column_data: truncate_column_data(format!("{:?}", record)),
+            });
+        };
+
+        let schema = self.ctx.schema.clone();
+        for (column_index, ((field, column), field_index)) in schema
+            .fields()
+            .iter()
+            .zip(self.mutable_columns.iter_mut())
+            .zip(field_indexes)
+            .enumerate()
+        {
+            let Some(field_index) = field_index else {
+                match missing_field_as {
+                    NullAs::Error => {
+                        return Err(FileParseError::ColumnMissingError {
+                            column_index,
+                            column_name: field.name().to_owned(),
+                            column_type: field.data_type.to_string(),
+                        });
+                    }
+                    NullAs::Null => {
+                        if field.is_nullable_or_null() {
+                            column.push_default();
+                        } else {
+                            return Err(FileParseError::ColumnMissingError {
+                                column_index,
+                                column_name: field.name().to_owned(),
+                                column_type: field.data_type.to_string(),
+                            });
+                        }
+                    }
+                    NullAs::FieldDefault => {
+                        if let Some(values) = &self.ctx.default_values {
+                            column.push(values[column_index].as_ref());
+                        } else {
+                            column.push_default();
+                        }
+                    }
+                }
+                continue;
+            };
+
+            let avro_schema = &avro_fields[*field_index].1;
+            let value = &values[*field_index].1;
+            self.decoder
+                .read_field(column, avro_schema, value)
+                .map_err(|e| FileParseError::ColumnDecodeError {
+                    column_index,
+                    column_name: field.name().to_owned(),
+                    column_type: field.data_type.to_string(),
+                    decode_error: e.message(),
+                    column_data: truncate_column_data(format!("{:?}", value)),
+                })?;
+        }
+        Ok(())
+    }
+
+    fn flush(&mut self) -> Result<Vec<DataBlock>> {
+        let columns: Vec<Column> = self
+            .mutable_columns
+            .iter_mut()
+            .map(|col| {
+                let empty_builder =
+                    ColumnBuilder::with_capacity_hint(&col.data_type(), 1024, false);
+                mem::replace(col, empty_builder).build()
+            })
+            .collect();
+        self.num_rows = 0;
+
+        if columns.is_empty() || columns[0].len() == 0 {
+            Ok(vec![])
+        } else {
+            Ok(vec![DataBlock::new_from_columns(columns)])
+        }
+    }
+
+    fn memory_size(&self) -> usize {
+        self.mutable_columns.iter().map(|x| x.memory_size()).sum()
+    }
+}
+
+impl BlockBuilderTrait for AvroBlockBuilder {
+    type Pipe = AvroFormatPipe;
+
+    fn deserialize(&mut self, batch: Option<AvroBlockBatch>) -> Result<Vec<DataBlock>> {
+        if let Some(b) = batch {
+            self.read_batch(&b)?;
+            let file_status = mem::take(&mut self.file_status);
+            self.ctx
+                .table_context
+                .add_file_status(&b.split_info.file.path, file_status)?;
+            if self.num_rows >= self.ctx.block_compact_thresholds.min_rows_per_block
+                || self.memory_size() > self.ctx.block_compact_thresholds.max_bytes_per_block
+            {
+                self.flush()
+            } else {
+                Ok(vec![])
+            }
+        } else {
+            self.flush()
+        }
+    }
+}
+
+/// Decode Avro values into columns, guided by the writer schema for logical types.
+struct AvroDecoder {
+    timezone: Tz,
+}
+
+impl AvroDecoder {
+    fn read_field(
+        &self,
+        column: &mut ColumnBuilder,
+        schema: &AvroSchema,
+        value: &Value,
+    ) -> Result<()> {
+        let (schema, value) = resolve_union(schema, value);
+        match column {
+            ColumnBuilder::Null { len } => match value {
+                Value::Null => {
+                    *len += 1;
+                    Ok(())
+                }
+                _ => Err(Self::unexpected(value, "null")),
+            },
+            ColumnBuilder::Nullable(c) => {
+                match value {
+                    Value::Null => c.push_null(),
+                    _ => {
+                        self.read_field(&mut c.builder, schema, value)?;
+                        c.validity.push(true);
+                    }
+                }
+                Ok(())
+            }
+            ColumnBuilder::Boolean(c) => match value {
+                Value::Boolean(v) => {
+                    c.push(*v);
+                    Ok(())
+                }
+                _ => Err(Self::unexpected(value, "boolean")),
+            },
+            ColumnBuilder::Number(c) => with_number_mapped_type!(|NUM_TYPE| match c {
+                NumberColumnBuilder::NUM_TYPE(c) => Self::read_number(c, value),
+            }),
+            ColumnBuilder::Decimal(c) => with_decimal_type!(|DECIMAL_TYPE| match c {
+                DecimalColumnBuilder::DECIMAL_TYPE(c, size) => {
+                    Self::read_decimal(c, *size, schema, value)
+                }
+            }),
+            ColumnBuilder::Date(c) => {
+                let days = match value {
+                    Value::Date(v) | Value::Int(v) => *v as i64,
+                    _ => return Err(Self::unexpected(value, "date")),
+                };
+                c.push(check_date(days)?);
+                Ok(())
+            }
+            ColumnBuilder::Timestamp(c) => {
+                let micros = match value {
+                    Value::TimestampMicros(v) | Value::Long(v) => Some(*v),
+                    Value::TimestampMillis(v) => v.checked_mul(1_000),
+                    Value::Date(v) => (*v as i64).checked_mul(24 * 3600 * 1_000_000),
+                    _ => return Err(Self::unexpected(value, "timestamp")),
+                };
+                let micros = micros.ok_or_else(|| {
+                    ErrorCode::BadBytes(format!("Timestamp {:?} is out of range", value))
+                })?;
+                c.push(check_timestamp(micros)?);
+                Ok(())
+            }
+            ColumnBuilder::String(c) => self.read_string(c, schema, value),
+            ColumnBuilder::Binary(c) => {
+                match value {
+                    Value::Bytes(v) | Value::Fixed(_, v) => c.put_slice(v),
+                    Value::String(v) => c.put_str(v),
+                    _ => return Err(Self::unexpected(value, "bytes")),
+                }
+                c.commit_row();
+                Ok(())
+            }
+            ColumnBuilder::Array(c) => self.read_array(c, schema, value),
+            ColumnBuilder::Map(c) => self.read_map(c, schema, value),
+            ColumnBuilder::Tuple(fields) => self.read_tuple(fields, schema, value),
+            ColumnBuilder::Variant(c) => self.read_variant(c, schema, value),
+            _ => Err(ErrorCode::BadBytes(format!(
+                "Loading Avro data into {} is not supported",
+                column.data_type()
+            ))),
+        }
+    }
+
+    fn read_number<T>(column: &mut Vec<T>, value: &Value) -> Result<()>
+    where
+        T: Number + From<T::Native>,
+        T::Native: NumCast,
+    {
+        let v: Option<T::Native> = match value {
+            Value::Int(v) | Value::Date(v) | Value::TimeMillis(v) => num_traits::cast(*v),
+            Value::Long(v)
+            | Value::TimeMicros(v)
+            | Value::TimestampMillis(v)
+            | Value::TimestampMicros(v) => num_traits::cast(*v),
+            Value::Float(v) => num_traits::cast(*v),
+            Value::Double(v) => num_traits::cast(*v),
+            _ => return Err(Self::unexpected(value, "number")),
+        };
+        match v {
+            Some(v) => {
+                column.push(v.into());
+                Ok(())
+            }
+            None => Err(ErrorCode::BadBytes(format!(
+                "Number {:?} is out of range",
+                value
+            ))),
+        }
+    }
+
+    fn read_decimal<D: Decimal>(
+        column: &mut Vec<D>,
+        size: DecimalSize,
+        schema: &AvroSchema,
+        value: &Value,
+    ) -> Result<()> {
+        let (unscaled, scale) = match (value, schema) {
+            (Value::Decimal(v), AvroSchema::Decimal { scale, .. }) => {
+                let bytes = Vec::<u8>::try_from(v)
+                    .map_err(|e| ErrorCode::BadBytes(format!("Invalid Avro decimal: {e}")))?;
+                (decimal_from_be_bytes::<D>(&bytes)?, *scale as u32)
+            }
+            (Value::Int(v), _) => (D::from_i128(*v), 0),
+            (Value::Long(v), _) => (D::from_i128(*v), 0),
+            _ => return Err(Self::unexpected(value, "decimal")),
+        };
+
+        let target_scale = size.scale as u32;
+        let v = if target_scale >= scale {
+            unscaled.checked_mul(D::e(target_scale - scale))
+        } else {
+            unscaled.checked_div(D::e(scale - target_scale))
+        };
+        match v {
+            Some(v)
+                if v >= D::min_for_precision(size.precision)
+                    && v <= D::max_for_precision(size.precision) =>
+            {
+                column.push(v);
+                Ok(())
+            }
+            _ => Err(ErrorCode::BadBytes(format!(
+                "Decimal {} with scale {} overflows Decimal({}, {})",
+                unscaled, scale, size.precision, size.scale
+            ))),
+        }
+    }
+
+    fn read_string(
+        &self,
+        column: &mut StringColumnBuilder,
+        schema: &AvroSchema,
+        value: &Value,
+    ) -> Result<()> {
+        match value {
+            Value::String(v) | Value::Enum(_, v) => column.put_str(v),
+            Value::Uuid(v) => column.put_str(&v.to_string()),
+            Value::Bytes(v) | Value::Fixed(_, v) => column.put_str(std::str::from_utf8(v)?),
+            _ => match self.to_json(schema, value)? {
+                JsonValue::String(v) => column.put_str(&v),
+                v => column.put_str(&v.to_string()),
+            },
+        }
+        column.commit_row();
+        Ok(())
+    }
+
+    fn read_array(
+        &self,
+        column: &mut ArrayColumnBuilder<AnyType>,
+        schema: &AvroSchema,
+        value: &Value,
+    ) -> Result<()> {
+        match (value, schema) {
+            (Value::Array(values), AvroSchema::Array(item_schema)) => {
+                for v in values {
+                    self.read_field(&mut column.builder, item_schema, v)?;
+                }
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(Self::unexpected(value, "array")),
+        }
+    }
+
+    fn read_map(
+        &self,
+        column: &mut ArrayColumnBuilder<AnyType>,
+        schema: &AvroSchema,
+        value: &Value,
+    ) -> Result<()> {
+        const KEY: usize = 0;
+        const VALUE: usize = 1;
+        let map_builder = column.builder.as_tuple_mut().unwrap();
+        match (value, schema) {
+            (Value::Map(entries), AvroSchema::Map(value_schema)) => {
+                // keep the output stable, the entries are in a hash map.
+                let mut entries = entries.iter().collect::<Vec<_>>();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                for (key, v) in entries {
+                    let key = Value::String(key.clone());
+                    self.read_field(&mut map_builder[KEY], &AvroSchema::String, &key)?;
+                    self.read_field(&mut map_builder[VALUE], value_schema, v)?;
+                }
+                column.commit_row();
+                Ok(())
+            }
+            _ => Err(Self::unexpected(value, "map")),
+        }
+    }
+
+    fn read_tuple(
+        &self,
+        fields: &mut [ColumnBuilder],
+        schema: &AvroSchema,
+        value: &Value,
+    ) -> Result<()> {
+        match (value, schema) {
+            (Value::Record(values), AvroSchema::Record(field_schemas)) => {
+                if fields.len() != values.len() {
+                    return Err(ErrorCode::BadBytes(format!(
+                        "Incorrect Avro record, expect {} fields, but get {} fields",
+                        fields.len(),
+                        values.len()
+                    )));
+                }
+                for ((field, (_, v)), (_, field_schema)) in
+                    fields.iter_mut().zip(values).zip(field_schemas)
+                {
+                    self.read_field(field, field_schema, v)?;
+                }
+                Ok(())
+            }
+            _ => Err(Self::unexpected(value, "record")),
+        }
+    }
+
+    fn read_variant(
+        &self,
+        column: &mut BinaryColumnBuilder,
+        schema: &AvroSchema,
+        value: &Value,
+    ) -> Result<()> {
+        let v = self.to_json(schema, value)?;
+        jsonb::Value::from(&v).write_to_vec(&mut column.data);
+        column.commit_row();
+        Ok(())
+    }
+
+    fn to_json(&self, schema: &AvroSchema, value: &Value) -> Result<JsonValue> {
+        let (schema, value) = resolve_union(schema, value);
+        let v = match value {
+            Value::Null => JsonValue::Null,
+            Value::Boolean(v) => JsonValue::Bool(*v),
+            Value::Int(v) | Value::TimeMillis(v) => JsonValue::from(*v),
+            Value::Long(v) | Value::TimeMicros(v) => JsonValue::from(*v),
+            Value::Float(v) => JsonValue::from(*v),
+            Value::Double(v) => JsonValue::from(*v),
+            Value::String(v) | Value::Enum(_, v) => JsonValue::String(v.clone()),
+            Value::Bytes(v) | Value::Fixed(_, v) => JsonValue::String(hex::encode(v)),
+            Value::Uuid(v) => JsonValue::String(v.to_string()),
+            Value::Date(v) => JsonValue::String(date_to_string(*v, self.timezone).to_string()),
+            Value::TimestampMillis(v) => JsonValue::String(
+                timestamp_to_string(v.saturating_mul(1_000), self.timezone).to_string(),
+            ),
+            Value::TimestampMicros(v) => {
+                JsonValue::String(timestamp_to_string(*v, self.timezone).to_string())
+            }
+            Value::Decimal(v) => {
+                let AvroSchema::Decimal { scale, .. } = schema else {
+                    return Err(Self::unexpected(value, "decimal"));
+                };
+                let bytes = Vec::<u8>::try_from(v)
+                    .map_err(|e| ErrorCode::BadBytes(format!("Invalid Avro decimal: {e}")))?;
+                let display = if bytes.len() <= i128::mem_size() {
+                    decimal_from_be_bytes::<i128>(&bytes)?.display(*scale as u8)
+                } else {
+                    decimal_from_be_bytes::<i256>(&bytes)?.display(*scale as u8)
+                };
+                match serde_json::Number::from_str(&display) {
+                    Ok(n) => JsonValue::Number(n),
+                    Err(_) => JsonValue::String(display),
+                }
+            }
+            Value::Array(values) => {
+                let AvroSchema::Array(item_schema) = schema else {
+                    return Err(Self::unexpected(value, "array"));
+                };
+                JsonValue::Array(
+                    values
+                        .iter()
+                        .map(|v| self.to_json(item_schema, v))
+                        .collect::<Result<Vec<_>>>()?,
+                )
+            }
+            Value::Map(entries) => {
+                let AvroSchema::Map(value_schema) = schema else {
+                    return Err(Self::unexpected(value, "map"));
+                };
+                let mut entries = entries.iter().collect::<Vec<_>>();
+                entries.sort_by(|a, b| a.0.cmp(b.0));
+                let mut obj = serde_json::Map::with_capacity(entries.len());
+                for (key, v) in entries {
+                    obj.insert(key.clone(), self.to_json(value_schema, v)?);
+                }
+                JsonValue::Object(obj)
+            }
+            Value::Record(values) => {
+                let AvroSchema::Record(field_schemas) = schema else {
+                    return Err(Self::unexpected(value, "record"));
+                };
+                let mut obj = serde_json::Map::with_capacity(values.len());
+                for ((name, v), (_, field_schema)) in values.iter().zip(field_schemas) {
+                    obj.insert(name.clone(), self.to_json(field_schema, v)?);
+                }
+                JsonValue::Object(obj)
+            }
+            _ => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "Unsupported Avro value {:?}",
+                    value
+                )));
+            }
+        };
+        Ok(v)
+    }
+
+    fn unexpected(value: &Value, expected: &str) -> ErrorCode {
+        ErrorCode::BadBytes(format!(
+            "Incorrect Avro value {:?}, expect {}",
+            value, expected
+        ))
+    }
+}
+
+// Take the branch of a union value, with its schema.
+fn resolve_union<'a>(schema: &'a AvroSchema, value: &'a Value) -> (&'a AvroSchema, &'a Value) {
+    let mut schema = schema;
+    let mut value = value;
+    while let (AvroSchema::Union(branches), Value::Union(index, inner)) = (schema, value) {
+        match branches.get(*index as usize) {
+            Some(branch) => {
+                schema = branch;
+                value = inner.as_ref();
+            }
+            None => break,
+        }
+    }
+    (schema, value)
+}
+
+/// Avro decimals are big-endian two's-complement integers of any length.
+fn decimal_from_be_bytes<D: Decimal>(bytes: &[u8]) -> Result<D> {
+    let size = D::mem_size();
+    let negative = bytes.first().is_some_and(|b| b & 0x80 != 0);
+    let sign_byte = if negative { 0xff } else { 0x00 };
+
+    // leading sign-extension bytes beyond the size of D are redundant.
+    let mut bytes = bytes;
+    while bytes.len() > size && bytes[0] == sign_byte {
+        bytes = &bytes[1..];
+    }
+    if bytes.len() > size {
+        return Err(ErrorCode::BadBytes(format!(
+            "Avro decimal of {} bytes is too large",
+            bytes.len()
+        )));
+    }
+
+    let mut le_bytes = vec![sign_byte; size];
+    for (i, b) in bytes.iter().rev().enumerate() {
+        le_bytes[i] = *b;
+    }
+    Ok(D::de_binary(&mut le_bytes.as_slice()))
+}
@@ -0,0 +1,380 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::string::StringColumnBuilder;
+use databend_common_expression::types::DataType;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::Column;
+use databend_common_expression::ColumnBuilder;
+use databend_common_expression::DataBlock;
+use databend_common_expression::Scalar;
+use databend_common_meta_app::principal::NullAs;
+use databend_common_meta_app::principal::OrcFileFormatParams;
+use databend_common_meta_app::principal::StageInfo;
+use databend_common_orc::adapt_column;
+use databend_common_orc::parse_orc_tail;
+use databend_common_orc::read_orc_metadata;
+use databend_common_orc::Compression;
+use databend_common_orc::OrcFileMeta;
+use databend_common_orc::OrcSchema;
+use databend_common_orc::OrcStripeInfo;
+use databend_common_orc::OrcStripeReader;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_settings::Settings;
+use databend_common_storage::FileStatus;
+use databend_common_storage::StageFileInfo;
+use futures::StreamExt;
+use futures::TryStreamExt;
+use log::debug;
+use opendal::Operator;
+
+use crate::input_formats::input_pipeline::AligningStateTrait;
+use crate::input_formats::input_pipeline::BlockBuilderTrait;
+use crate::input_formats::input_pipeline::InputFormatPipe;
+use crate::input_formats::input_pipeline::RowBatchTrait;
+use crate::input_formats::input_split::DynData;
+use crate::input_formats::input_split::FileInfo;
+use crate::input_formats::InputContext;
+use crate::input_formats::InputFormat;
+use crate::input_formats::SplitInfo;
+
+/// Number of file tails fetched at the same time when splitting files.
+const MAX_CONCURRENT_TAIL_READS: usize = 16;
+
+pub struct InputFormatOrc;
+
+impl InputFormatOrc {
+    /// Splits ORC files into one split per stripe.
+    ///
+    /// `keep_stripe` is called with the file metadata and the index of every
+    /// stripe, stripes it rejects (e.g. by statistics) are not read at all.
+    #[async_backtrace::framed]
+    pub async fn get_stripe_splits(
+        file_infos: Vec<StageFileInfo>,
+        op: &Operator,
+        keep_stripe: impl Fn(&OrcFileMeta, usize) -> bool,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        let metas = futures::stream::iter(file_infos.into_iter())
+            .map(|info| async move {
+                let meta = read_orc_metadata(op, &info.path, Some(info.size)).await?;
+                Ok::<_, ErrorCode>((info, meta))
+            })
+            .buffered(MAX_CONCURRENT_TAIL_READS)
+            .try_collect::<Vec<_>>()
+            .await?;
+
+        let mut infos = vec![];
+        for (info, meta) in metas {
+            let stripes = (0..meta.stripes.len())
+                .filter(|i| keep_stripe(&meta, *i))
+                .collect::<Vec<_>>();
+            let num_splits = stripes.len();
+            let file = Arc::new(FileInfo {
+                path: info.path,
+                size: info.size as usize,
+                num_splits,
+                compress_alg: None,
+            });
+            let schema = Arc::new(meta.schema.clone());
+            for (seq_in_file, index) in stripes.into_iter().enumerate() {
+                let stripe = meta.stripes[index];
+                infos.push(Arc::new(SplitInfo {
+                    file: file.clone(),
+                    seq_in_file,
+                    offset: stripe.offset as usize,
+                    size: stripe.length() as usize,
+                    num_file_splits: num_splits,
+                    format_info: Some(Arc::new(OrcSplitMeta {
+                        compression: meta.compression,
+                        schema: schema.clone(),
+                        stripe,
+                    })),
+                }));
+            }
+        }
+        Ok(infos)
+    }
+}
+
+#[async_trait::async_trait]
+impl InputFormat for InputFormatOrc {
+    #[async_backtrace::framed]
+    async fn get_splits(
+        &self,
+        file_infos: Vec<StageFileInfo>,
+        _stage_info: &StageInfo,
+        op: &Operator,
+        _settings: &Arc<Settings>,
+    ) -> Result<Vec<Arc<SplitInfo>>> {
+        Self::get_stripe_splits(file_infos, op, |_, _| true).await
+    }
+
+    fn exec_copy(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_copy_with_aligner(ctx, pipeline)
+    }
+
+    fn exec_stream(&self, ctx: Arc<InputContext>, pipeline: &mut Pipeline) -> Result<()> {
+        OrcFormatPipe::execute_stream(ctx, pipeline)
+    }
+}
+
+/// Everything needed to decode a stripe without reading the file tail again.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone)]
+pub struct OrcSplitMeta {
+    pub compression: Compression,
+    pub schema: Arc<OrcSchema>,
+    pub stripe: OrcStripeInfo,
+}
+
+#[typetag::serde(name = "orc_split")]
+impl DynData for OrcSplitMeta {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
+pub struct OrcFormatPipe;
+
+#[async_trait::async_trait]
+impl InputFormatPipe for OrcFormatPipe {
+    type SplitMeta = OrcSplitMeta;
+    type ReadBatch = Vec<u8>;
+    type RowBatch = OrcStripeBatch;
+    type AligningState = OrcAligningState;
+    type BlockBuilder = OrcBlockBuilder;
+
+    fn try_create_align_state(
+        _ctx: &Arc<InputContext>,
+        split_info: &Arc<SplitInfo>,
+    ) -> Result<OrcAligningState> {
+        Ok(OrcAligningState {
+            split_info: split_info.clone(),
+            buf: vec![],
+        })
+    }
+
+    fn try_create_block_builder(ctx: &Arc<InputContext>) -> Result<OrcBlockBuilder> {
+        Ok(OrcBlockBuilder { ctx: ctx.clone() })
+    }
+}
+
+/// The raw bytes of one stripe.
+#[derive(serde::Serialize, serde::Deserialize, Debug)]
+pub struct OrcStripeBatch {
+    pub data: Vec<u8>,
+    pub compression: Compression,
+    pub schema: Arc<OrcSchema>,
+    pub stripe: OrcStripeInfo,
+
+    pub split_info: Arc<SplitInfo>,
+}
+
+impl RowBatchTrait for OrcStripeBatch {
+    fn size(&self) -> usize {
+        self.data.len()
+    }
+
+    fn rows(&self) -> usize {
+        self.stripe.num_rows as usize
+    }
+}
+
+#[typetag::serde(name = "row_batch_orc")]
+impl BlockMetaInfo for OrcStripeBatch {
+    fn equals(&self, _info: &Box<dyn BlockMetaInfo>) -> bool {
+        unreachable!("OrcStripeBatch as BlockMetaInfo is not expected to be compared.")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unreachable!("OrcStripeBatch as BlockMetaInfo is not expected to be cloned.")
+    }
+}
+
+/// Collects the bytes of a split.
+///
+/// A split of COPY is a single stripe. Streaming loads have no split meta and
+/// deliver the whole file, whose tail is parsed once all bytes arrived.
+pub struct OrcAligningState {
+    split_info: Arc<SplitInfo>,
+    buf: Vec<u8>,
+}
+
+impl AligningStateTrait for OrcAligningState {
+    type Pipe = OrcFormatPipe;
+
+    fn align(&mut self, read_batch: Option<Vec<u8>>) -> Result<Vec<OrcStripeBatch>> {
+        if let Some(data) = read_batch {
+            self.buf.extend_from_slice(&data);
+            return Ok(vec![]);
+        }
+
+        let data = std::mem::take(&mut self.buf);
+        let batches = match OrcFormatPipe::get_split_meta(&self.split_info) {
+            Some(meta) => vec![OrcStripeBatch {
+                data,
+                compression: meta.compression,
+                schema: meta.schema.clone(),
+                stripe: meta.stripe,
+                split_info: self.split_info.clone(),
+            }],
+            None => {
+                let meta = parse_orc_tail(&data).map_err(|e| {
+                    e.add_message_back(format!(" (file: {})", self.split_info.file.path))
+                })?;
+                let schema = Arc::new(meta.schema);
+                let mut batches = Vec::with_capacity(meta.stripes.len());
+                for stripe in meta.stripes {
+                    let start = stripe.offset as usize;
+                    let end = start + stripe.length() as usize;
+                    if end > data.len() {
+                        return Err(ErrorCode::BadBytes(format!(
+                            "Invalid ORC file {}: stripe at {} exceeds the file",
+                            self.split_info.file.path, start
+                        )));
+                    }
+                    batches.push(OrcStripeBatch {
+                        data: data[start..end].to_vec(),
+                        compression: meta.compression,
+                        schema: schema.clone(),
+                        stripe,
+                        split_info: self.split_info.clone(),
+                    });
+                }
+                batches
+            }
+        };
+        debug!(
+            "align orc split {} to {} stripes",
+            self.split_info,
+            batches.len()
+        );
+        Ok(batches)
+    }
+}
+
+pub struct OrcBlockBuilder {
+    ctx: Arc<InputContext>,
+}
+
+impl OrcBlockBuilder {
+    fn read_stripe(&self, batch: &OrcStripeBatch) -> Result<DataBlock> {
+        let path = &batch.split_info.file.path;
+        let reader = OrcStripeReader::try_create(
+            batch.compression,
+            &batch.schema,
+            &batch.stripe,
+            &batch.data,
+        )
+        .map_err(|e| e.add_message_back(format!(" (file: {path})")))?;
+        let num_rows = reader.num_rows();
+
+        let ident_case_sensitive = self.ctx.file_format_options_ext.ident_case_sensitive;
+        let orc_fields = &batch.schema.root().field_names;
+        let missing_field_as =
+            &OrcFileFormatParams::downcast_unchecked(&self.ctx.file_format_params).missing_field_as;
+
+        let mut columns = Vec::with_capacity(self.ctx.schema.num_fields());
+        for (index, field) in self.ctx.schema.fields().iter().enumerate() {
+            let data_type: DataType = field.data_type().into();
+            if self
+                .ctx
+                .projection
+                .as_ref()
+                .is_some_and(|projection| !projection.contains(&index))
+            {
+                // Columns out of the projection are never read, keep the shape of the block.
+                columns.push(Column::String(
+                    StringColumnBuilder {
+                        need_estimated: false,
+                        data: vec![],
+                        offsets: vec![0; num_rows + 1],
+                    }
+                    .build(),
+                ));
+                continue;
+            }
+
+            let position = orc_fields.iter().position(|name| {
+                if ident_case_sensitive {
+                    name == field.name()
+                } else {
+                    name.eq_ignore_ascii_case(field.name())
+                }
+            });
+            let column = match position {
+                Some(position) => {
+                    let column = reader.read_field(position).map_err(|e| {
+                        e.add_message_back(format!(" (column: {}, file: {path})", field.name()))
+                    })?;
+                    adapt_column(column, &data_type).map_err(|e| {
+                        e.add_message_back(format!(" (column: {}, file: {path})", field.name()))
+                    })?
+                }
+                None => {
+                    let default = match missing_field_as {
+                        NullAs::Null if field.is_nullable_or_null() => Scalar::Null,
+                        NullAs::FieldDefault => match &self.ctx.default_values {
+                            Some(values) => values[index].clone(),
+                            None => Scalar::default_value(&data_type),
+                        },
+                        _ => {
+                            return Err(ErrorCode::BadBytes(format!(
+                                "Column {} of type {} is missing in ORC file {}",
+                                field.name(),
+                                field.data_type(),
+                                path
+                            )));
+                        }
+                    };
+                    ColumnBuilder::repeat(&default.as_ref(), num_rows, &data_type).build()
+                }
+            };
+            columns.push(column);
+        }
+
+        let file_status = FileStatus {
+            num_rows_loaded: num_rows,
+            ..Default::default()
+        };
+        self.ctx.table_context.add_file_status(path, file_status)?;
+        Ok(DataBlock::new_from_columns(columns))
+    }
+}
+
+impl BlockBuilderTrait for OrcBlockBuilder {
+    type Pipe = OrcFormatPipe;
+
+    fn deserialize(&mut self, batch: Option<OrcStripeBatch>) -> Result<Vec<DataBlock>> {
+        let Some(batch) = batch else {
+            return Ok(vec![]);
+        };
+        if batch.stripe.num_rows == 0 {
+            return Ok(vec![]);
+        }
+
+        let block = self.read_stripe(&batch)?;
+        // Stripes are usually much larger than a block.
+        let max_rows = self.ctx.block_compact_thresholds.max_rows_per_block.max(1);
+        let num_rows = block.num_rows();
+        Ok((0..num_rows)
+            .step_by(max_rows)
+            .map(|start| block.slice(start..(start + max_rows).min(num_rows)))
+            .collect())
+    }
+}
@@ -17,6 +17,7 @@ mod database_context;
 mod database_factory;
 mod default;
 mod information_schema;
+mod pg_catalog;
 mod share;
 mod system;
 
@@ -24,4 +25,5 @@ pub use database::Database;
 pub use database_context::DatabaseContext;
 pub use database_factory::DatabaseFactory;
 pub use information_schema::InformationSchemaDatabase;
+pub use pg_catalog::PgCatalogDatabase;
 pub use system::SystemDatabase;
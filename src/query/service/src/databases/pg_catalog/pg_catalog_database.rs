@@ -0,0 +1,192 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::Arc;
+
+use databend_common_expression::types::decimal::DecimalDataType;
+use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::number::ALL_NUMERICS_TYPES;
+use databend_common_expression::types::DataType;
+use databend_common_meta_app::schema::DatabaseIdent;
+use databend_common_meta_app::schema::DatabaseInfo;
+use databend_common_meta_app::schema::DatabaseMeta;
+use databend_common_meta_app::schema::DatabaseNameIdent;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_storages_view::view_table::ViewTable;
+use databend_common_storages_view::view_table::QUERY;
+
+use crate::catalogs::InMemoryMetas;
+use crate::databases::Database;
+use crate::servers::pg_type_of;
+use crate::servers::PG_BUILTIN_TYPES;
+use crate::storages::Table;
+
+/// The PostgreSQL system catalog, which drivers and tools like psql query to list
+/// the schemas, tables and columns.
+///
+/// The tables are read-only views of `system.databases`, `system.tables` and
+/// `system.columns`, a database is reported as a schema.
+#[derive(Clone)]
+pub struct PgCatalogDatabase {
+    db_info: DatabaseInfo,
+}
+
+impl PgCatalogDatabase {
+    pub fn create(sys_db_meta: &mut InMemoryMetas) -> Self {
+        let db = "pg_catalog";
+        let db_id = sys_db_meta.next_db_id();
+
+        let pg_namespace = "SELECT
+            database_id AS oid,
+            name AS nspname,
+            10 AS nspowner
+        FROM system.databases;"
+            .to_string();
+
+        let pg_class = "SELECT
+            t.table_id AS oid,
+            t.name AS relname,
+            d.database_id AS relnamespace,
+            CASE WHEN t.engine = 'VIEW' THEN 'v' ELSE 'r' END AS relkind,
+            10 AS relowner,
+            0 AS relam,
+            t.num_rows AS reltuples,
+            'p' AS relpersistence,
+            false AS relhasindex
+        FROM system.tables t
+        JOIN system.databases d ON t.catalog = d.catalog AND t.database = d.name;"
+            .to_string();
+
+        // `system.columns` keeps the columns of a table in their order.
+        let pg_attribute = format!(
+            "SELECT
+            t.table_id AS attrelid,
+            c.name AS attname,
+            {} AS atttypid,
+            row_number() OVER (PARTITION BY c.database, c.table) AS attnum,
+            -1 AS atttypmod,
+            c.is_nullable = 'NO' AS attnotnull,
+            c.default_kind <> '' AS atthasdef,
+            false AS attisdropped
+        FROM system.columns c
+        JOIN system.tables t ON c.database = t.database AND c.table = t.name;",
+            type_oid_expr("c.data_type")
+        );
+
+        let pg_type = PG_BUILTIN_TYPES
+            .iter()
+            .map(|(oid, name, len)| {
+                format!(
+                    "SELECT {oid} AS oid, '{name}' AS typname, {db_id} AS typnamespace, \
+                     {len} AS typlen, 'b' AS typtype, 0 AS typbasetype, 0 AS typrelid"
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(" UNION ALL ");
+
+        for (name, query) in [
+            ("pg_namespace", pg_namespace),
+            ("pg_class", pg_class),
+            ("pg_attribute", pg_attribute),
+            ("pg_type", pg_type),
+        ] {
+            let view = Self::create_view(sys_db_meta.next_table_id(), name, query);
+            sys_db_meta.insert(db, view);
+        }
+
+        let db_info = DatabaseInfo {
+            ident: DatabaseIdent { db_id, seq: 0 },
+            name_ident: DatabaseNameIdent {
+                tenant: "".to_string(),
+                db_name: db.to_string(),
+            },
+            meta: DatabaseMeta {
+                engine: "SYSTEM".to_string(),
+                ..Default::default()
+            },
+        };
+
+        Self { db_info }
+    }
+
+    fn create_view(table_id: u64, name: &str, query: String) -> Arc<dyn Table> {
+        let mut options = BTreeMap::new();
+        options.insert(QUERY.to_string(), query);
+        let table_info = TableInfo {
+            desc: format!("'pg_catalog'.'{name}'"),
+            name: name.to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                options,
+                engine: "VIEW".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        ViewTable::create(table_info)
+    }
+}
+
+// Maps the type name of `system.columns.data_type` to the OID of the type the values
+// are reported as by the PostgreSQL handler.
+fn type_oid_expr(data_type: &str) -> String {
+    let mut types = vec![
+        DataType::Boolean,
+        DataType::Binary,
+        DataType::String,
+        DataType::Date,
+        DataType::Timestamp,
+        DataType::Variant,
+    ];
+    types.extend(ALL_NUMERICS_TYPES.iter().map(|ty| DataType::Number(*ty)));
+
+    let mut expr = "CASE".to_string();
+    for ty in types {
+        let _ = write!(
+            expr,
+            " WHEN {data_type} = '{}' THEN {}",
+            ty.sql_name(),
+            pg_type_of(&ty).0
+        );
+    }
+    // The precision and scale are part of the name of a decimal, the other types are
+    // reported as text.
+    let decimal = DataType::Decimal(DecimalDataType::Decimal128(DecimalSize {
+        precision: 38,
+        scale: 0,
+    }));
+    let _ = write!(
+        expr,
+        " WHEN {data_type} LIKE 'DECIMAL(%' THEN {} ELSE {} END",
+        pg_type_of(&decimal).0,
+        pg_type_of(&DataType::EmptyArray).0
+    );
+    expr
+}
+
+#[async_trait::async_trait]
+impl Database for PgCatalogDatabase {
+    fn name(&self) -> &str {
+        "pg_catalog"
+    }
+
+    fn get_db_info(&self) -> &DatabaseInfo {
+        &self.db_info
+    }
+}
@@ -40,8 +40,11 @@ use crate::auth::AuthMgr;
 use crate::catalogs::DatabaseCatalog;
 use crate::clusters::ClusterDiscovery;
 use crate::locks::LockManager;
+use crate::pipes::PipeScheduler;
 use crate::servers::http::v1::HttpQueryManager;
 use crate::sessions::SessionManager;
+use crate::tasks::TaskScheduler;
+use crate::workload_groups::WorkloadGroupManager;
 
 pub struct GlobalServices;
 
@@ -99,6 +102,9 @@ impl GlobalServices {
         DataExchangeManager::init()?;
         SessionManager::init(config)?;
         LockManager::init()?;
+        PipeScheduler::init(config)?;
+        TaskScheduler::init(config)?;
+        WorkloadGroupManager::init()?;
         AuthMgr::init(config)?;
         UserApiProvider::init(
             config.meta.to_meta_grpc_client_conf(),
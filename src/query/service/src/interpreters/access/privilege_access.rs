@@ -277,7 +277,8 @@ impl PrivilegeAccess {
 
         // skip checking the privilege on system tables.
         if ((db_name == "system" && SYSTEM_TABLES_ALLOW_LIST.iter().any(|x| x == &table_name))
-            || db_name == "information_schema")
+            || db_name == "information_schema"
+            || db_name == "pg_catalog")
             && privileges == [UserPrivilegeType::Select]
         {
             return Ok(());
@@ -560,7 +561,11 @@ impl AccessChecker for PrivilegeAccess {
             Plan::ShowCreateDatabase(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Select]).await?
             }
-            Plan::CreateUDF(_) | Plan::CreateDatabase(_) | Plan::CreateIndex(_) => {
+            Plan::CreateUDF(_)
+            | Plan::CreateDatabase(_)
+            | Plan::CreateIndex(_)
+            | Plan::CreateVectorIndex(_)
+            | Plan::CreateInvertedIndex(_) => {
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Create])
                     .await?;
             }
@@ -804,6 +809,18 @@ impl AccessChecker for PrivilegeAccess {
             Plan::DropView(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Drop]).await?
             }
+            Plan::CreateMaterializedView(plan) => {
+                self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Create]).await?;
+                for (catalog, database, table) in &plan.base_tables {
+                    self.validate_table_access(catalog, database, table, vec![UserPrivilegeType::Select]).await?;
+                }
+            }
+            Plan::DropMaterializedView(plan) => {
+                self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Drop]).await?
+            }
+            Plan::RefreshMaterializedView(plan) => {
+                self.validate_table_access(&plan.catalog, &plan.database, &plan.view_name, vec![UserPrivilegeType::Insert]).await?
+            }
             Plan::CreateStream(plan) => {
                 self.validate_db_access(&plan.catalog, &plan.database, vec![UserPrivilegeType::Create]).await?
             }
@@ -859,7 +876,9 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::RevertTable(_)
             | Plan::AlterUDF(_)
             | Plan::AlterShareTenants(_)
-            | Plan::RefreshIndex(_) => {
+            | Plan::RefreshIndex(_)
+            | Plan::RefreshVectorIndex(_)
+            | Plan::RefreshInvertedIndex(_) => {
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Alter])
                     .await?;
             }
@@ -902,6 +921,9 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::AlterPasswordPolicy(_)
             | Plan::DropPasswordPolicy(_)
             | Plan::DescPasswordPolicy(_)
+            | Plan::CreateWorkloadGroup(_)
+            | Plan::AlterWorkloadGroup(_)
+            | Plan::DropWorkloadGroup(_)
             | Plan::CreateConnection(_)
             | Plan::ShowConnections(_)
             | Plan::DescConnection(_)
@@ -911,7 +933,11 @@ impl AccessChecker for PrivilegeAccess {
             | Plan::DescribeTask(_) // TODO: need to build ownership info for task
             | Plan::ExecuteTask(_)  // TODO: need to build ownership info for task
             | Plan::DropTask(_)     // TODO: need to build ownership info for task
-            | Plan::AlterTask(_) => {
+            | Plan::AlterTask(_)
+            | Plan::CreatePipe(_)
+            | Plan::AlterPipe(_)
+            | Plan::DropPipe(_)
+            | Plan::DescribePipe(_) => {
                 self.validate_access(&GrantObject::Global, vec![UserPrivilegeType::Super])
                     .await?;
             }
@@ -927,6 +953,8 @@ impl AccessChecker for PrivilegeAccess {
             Plan::SetRole(_) => {}
             Plan::SetSecondaryRoles(_) => {}
             Plan::ShowRoles(_) => {}
+            // BEGIN / COMMIT / ROLLBACK only act on the transaction of the session
+            Plan::Begin | Plan::Commit | Plan::Abort => {}
             Plan::Presign(plan) => {
                 let privilege = match &plan.action {
                     PresignAction::Upload => UserPrivilegeType::Write,
@@ -954,7 +982,10 @@ async fn has_priv(
     table_id: Option<u64>,
     grant_set: UserGrantSet,
 ) -> Result<bool> {
-    if db_name.to_lowercase() == "information_schema" {
+    if matches!(
+        db_name.to_lowercase().as_str(),
+        "information_schema" | "pg_catalog"
+    ) {
         return Ok(true);
     }
     Ok(RoleCacheManager::instance()
@@ -0,0 +1,90 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::table::Table;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::UpsertTableOptionReq;
+use databend_common_meta_types::MatchSeq;
+use databend_common_sql::plans::materialized_view_stream_prefix;
+use databend_common_storages_stream::stream_table::STREAM_ENGINE;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEWS;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Lists the internal streams of the materialized view, one stream for each base table.
+pub async fn list_materialized_view_streams(
+    ctx: &QueryContext,
+    catalog: &str,
+    database: &str,
+    view_id: u64,
+) -> Result<Vec<Arc<dyn Table>>> {
+    let catalog = ctx.get_catalog(catalog).await?;
+    let prefix = materialized_view_stream_prefix(view_id);
+    let tables = catalog.list_tables(&ctx.get_tenant(), database).await?;
+    Ok(tables
+        .into_iter()
+        .filter(|table| table.engine() == STREAM_ENGINE && table.name().starts_with(&prefix))
+        .collect())
+}
+
+/// Adds (or removes) the materialized view to the `materialized_views` option of the base table,
+/// which is used to find the views to refresh after writing the base table.
+pub async fn update_materialized_views_option(
+    ctx: &QueryContext,
+    catalog: &str,
+    database: &str,
+    table: &str,
+    view_id: u64,
+    attach: bool,
+) -> Result<()> {
+    let tenant = ctx.get_tenant();
+    let catalog = ctx.get_catalog(catalog).await?;
+    let table = catalog.get_table(&tenant, database, table).await?;
+    let table_info = table.get_table_info();
+
+    let mut view_ids = table_info
+        .options()
+        .get(OPT_KEY_MATERIALIZED_VIEWS)
+        .map(|ids| {
+            ids.split(',')
+                .filter(|id| !id.is_empty())
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    let view_id = view_id.to_string();
+    let exists = view_ids.contains(&view_id);
+    match (attach, exists) {
+        (true, false) => view_ids.push(view_id),
+        (false, true) => view_ids.retain(|id| id != &view_id),
+        _ => return Ok(()),
+    }
+
+    let value = if view_ids.is_empty() {
+        None
+    } else {
+        Some(view_ids.join(","))
+    };
+    let req = UpsertTableOptionReq {
+        table_id: table_info.ident.table_id,
+        seq: MatchSeq::Exact(table_info.ident.seq),
+        options: HashMap::from([(OPT_KEY_MATERIALIZED_VIEWS.to_string(), value)]),
+    };
+    catalog.upsert_table_option(&tenant, database, req).await?;
+    Ok(())
+}
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 mod grant;
+mod materialized_view;
 mod metrics;
 mod query_log;
 mod stream;
@@ -21,11 +22,17 @@ mod task;
 mod util;
 
 pub use grant::validate_grant_object_exists;
+pub use materialized_view::list_materialized_view_streams;
+pub use materialized_view::update_materialized_views_option;
 pub use query_log::InterpreterQueryLog;
+pub use stream::build_update_stream_meta;
 pub use stream::build_update_stream_meta_seq;
 pub use table::check_referenced_computed_columns;
+pub use table::drop_referenced_constraints;
+pub use table::rename_constraint_columns;
 pub use task::get_client_config;
 pub use task::make_schedule_options;
+pub use task::make_task_schedule_options;
 pub use task::make_warehouse_options;
 pub use util::check_deduplicate_label;
 pub use util::create_push_down_filters;
@@ -35,6 +35,15 @@ pub async fn build_update_stream_meta_seq(
     metadata: &MetadataRef,
 ) -> Result<Vec<UpdateStreamMetaReq>> {
     let tables = get_stream_table(metadata)?;
+    build_update_stream_meta(ctx, tables).await
+}
+
+/// Builds the requests to move the offsets of the given streams to the current
+/// snapshots of their source tables.
+pub async fn build_update_stream_meta(
+    ctx: Arc<QueryContext>,
+    tables: Vec<Arc<dyn Table>>,
+) -> Result<Vec<UpdateStreamMetaReq>> {
     if tables.is_empty() {
         return Ok(vec![]);
     }
@@ -74,6 +83,10 @@ fn get_stream_table(metadata: &MetadataRef) -> Result<Vec<Arc<dyn Table>>> {
     for t in tables {
         let table = t.table();
         if table.engine() == STREAM_ENGINE {
+            // The streams built for the CHANGES clause are read-only.
+            if !StreamTable::try_from_table(table.as_ref())?.is_consumable() {
+                continue;
+            }
             streams.push(table);
         }
     }
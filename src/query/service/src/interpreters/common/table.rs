@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use databend_common_catalog::table_context::TableContext;
@@ -19,6 +20,7 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataSchemaRef;
+use databend_common_meta_app::schema::TableConstraint;
 use databend_common_sql::parse_computed_expr;
 
 pub fn check_referenced_computed_columns(
@@ -55,3 +57,55 @@ pub fn check_referenced_computed_columns(
     }
     Ok(())
 }
+
+/// Drop the constraints that reference the dropped column: the key constraints
+/// containing the column, and the CHECK constraints that can not be resolved
+/// against the schema without it.
+pub fn drop_referenced_constraints(
+    ctx: Arc<dyn TableContext>,
+    schema: DataSchemaRef,
+    constraints: &mut BTreeMap<String, TableConstraint>,
+    column: &str,
+) {
+    constraints.retain(|_, constraint| match constraint {
+        TableConstraint::Check { expression } => {
+            parse_computed_expr(ctx.clone(), schema.clone(), expression).is_ok()
+        }
+        _ => !constraint.key_columns().iter().any(|c| c == column),
+    });
+}
+
+/// Rename the column in the key constraints.
+///
+/// CHECK constraints are saved as SQL text, so renaming a column referenced by
+/// them is not allowed.
+pub fn rename_constraint_columns(
+    ctx: Arc<dyn TableContext>,
+    schema: DataSchemaRef,
+    constraints: &mut BTreeMap<String, TableConstraint>,
+    old_column: &str,
+    new_column: &str,
+) -> Result<()> {
+    for (name, constraint) in constraints.iter_mut() {
+        match constraint {
+            TableConstraint::Check { expression } => {
+                if parse_computed_expr(ctx.clone(), schema.clone(), expression).is_err() {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "column `{}` is referenced by CHECK constraint `{}`",
+                        old_column, name
+                    )));
+                }
+            }
+            TableConstraint::PrimaryKey { columns }
+            | TableConstraint::Unique { columns }
+            | TableConstraint::ForeignKey { columns, .. } => {
+                for column in columns.iter_mut() {
+                    if column == old_column {
+                        *column = new_column.to_string();
+                    }
+                }
+            }
+        }
+    }
+    Ok(())
+}
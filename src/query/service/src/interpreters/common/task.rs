@@ -22,6 +22,7 @@ use databend_common_cloud_control::client_config::build_client_config;
 use databend_common_cloud_control::client_config::ClientConfig;
 use databend_common_cloud_control::pb::schedule_options::ScheduleType;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal as mt;
 
 use crate::sessions::QueryContext;
 
@@ -47,6 +48,25 @@ pub fn make_schedule_options(
     }
 }
 
+/// Builds the schedule options of the task stored in the meta service, which is used if
+/// the cloud control is not enabled.
+pub fn make_task_schedule_options(opt: ScheduleOptions) -> mt::ScheduleOptions {
+    match opt {
+        ScheduleOptions::IntervalSecs(secs) => mt::ScheduleOptions {
+            interval: Some(secs as i32),
+            cron: None,
+            time_zone: None,
+            schedule_type: mt::ScheduleType::IntervalType,
+        },
+        ScheduleOptions::CronExpression(expr, timezone) => mt::ScheduleOptions {
+            interval: None,
+            cron: Some(expr),
+            time_zone: timezone,
+            schedule_type: mt::ScheduleType::CronType,
+        },
+    }
+}
+
 pub fn make_warehouse_options(
     opt: WarehouseOptions,
 ) -> databend_common_cloud_control::pb::WarehouseOptions {
@@ -16,27 +16,37 @@ use std::sync::Arc;
 
 use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_catalog::catalog::CatalogManager;
+use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_meta_app::schema::IndexMeta;
+use databend_common_meta_app::schema::IndexType;
 use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_common_meta_app::schema::ListVirtualColumnsReq;
 use databend_common_meta_types::MetaId;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::plans::RefreshIndexPlan;
+use databend_common_sql::plans::RefreshInvertedIndexPlan;
+use databend_common_sql::plans::RefreshMaterializedViewPlan;
+use databend_common_sql::plans::RefreshVectorIndexPlan;
 use databend_common_sql::plans::RefreshVirtualColumnPlan;
 use databend_common_sql::BindContext;
 use databend_common_sql::Binder;
 use databend_common_sql::Metadata;
 use databend_common_sql::NameResolutionContext;
 use databend_storages_common_table_meta::meta::Location;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEWS;
 use log::info;
 use parking_lot::RwLock;
 
 use crate::interpreters::Interpreter;
 use crate::interpreters::RefreshIndexInterpreter;
+use crate::interpreters::RefreshInvertedIndexInterpreter;
+use crate::interpreters::RefreshMaterializedViewInterpreter;
+use crate::interpreters::RefreshVectorIndexInterpreter;
 use crate::interpreters::RefreshVirtualColumnInterpreter;
 use crate::pipelines::executor::ExecutorSettings;
 use crate::pipelines::executor::PipelineCompleteExecutor;
@@ -72,10 +82,10 @@ pub async fn hook_refresh(ctx: Arc<QueryContext>, pipeline: &mut Pipeline, desc:
 }
 
 async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
-    let table_id = ctx
+    let table = ctx
         .get_table(&desc.catalog, &desc.database, &desc.table)
-        .await?
-        .get_id();
+        .await?;
+    let table_id = table.get_id();
 
     let mut plans = Vec::new();
 
@@ -89,6 +99,11 @@ async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
         plans.extend_from_slice(&agg_index_plans);
     }
 
+    // Generate sync vector and inverted indexes.
+    let table_index_plans =
+        generate_refresh_table_index_plan(ctx.clone(), &desc.catalog, table.as_ref()).await?;
+    plans.extend_from_slice(&table_index_plans);
+
     // Generate virtual columns.
     if ctx
         .get_settings()
@@ -100,6 +115,16 @@ async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
         }
     }
 
+    // Generate materialized views.
+    if ctx
+        .get_settings()
+        .get_enable_refresh_materialized_view_after_write()?
+    {
+        let materialized_view_plans =
+            generate_refresh_materialized_view_plan(ctx.clone(), &desc, table.as_ref()).await?;
+        plans.extend_from_slice(&materialized_view_plans);
+    }
+
     let mut tasks = Vec::with_capacity(std::cmp::min(
         ctx.get_settings().get_max_threads()? as usize,
         plans.len(),
@@ -134,6 +159,62 @@ async fn do_refresh(ctx: Arc<QueryContext>, desc: RefreshDesc) -> Result<()> {
                         Ok(())
                     }
                 }
+                Plan::RefreshMaterializedView(materialized_view_plan) => {
+                    let refresh_materialized_view_interpreter =
+                        RefreshMaterializedViewInterpreter::try_create(
+                            ctx_cloned.clone(),
+                            *materialized_view_plan,
+                        )?;
+                    let mut build_res = refresh_materialized_view_interpreter.execute2().await?;
+                    if build_res.main_pipeline.is_empty() {
+                        return Ok(());
+                    }
+
+                    let settings = ctx_cloned.get_settings();
+                    let query_id = ctx_cloned.get_id();
+                    build_res.set_max_threads(settings.get_max_threads()? as usize);
+                    let settings = ExecutorSettings::try_create(&settings, query_id)?;
+
+                    if build_res.main_pipeline.is_complete_pipeline()? {
+                        let mut pipelines = build_res.sources_pipelines;
+                        pipelines.push(build_res.main_pipeline);
+
+                        let complete_executor =
+                            PipelineCompleteExecutor::from_pipelines(pipelines, settings)?;
+                        ctx_cloned.set_executor(complete_executor.get_inner())?;
+                        complete_executor.execute()
+                    } else {
+                        Ok(())
+                    }
+                }
+                Plan::RefreshVectorIndex(vector_index_plan) => {
+                    let refresh_vector_index_interpreter =
+                        RefreshVectorIndexInterpreter::try_create(
+                            ctx_cloned.clone(),
+                            *vector_index_plan,
+                        )?;
+                    let build_res = refresh_vector_index_interpreter.execute2().await?;
+                    if !build_res.main_pipeline.is_empty() {
+                        return Err(ErrorCode::Internal(
+                            "Logical error, refresh vector index is an empty pipeline.",
+                        ));
+                    }
+                    Ok(())
+                }
+                Plan::RefreshInvertedIndex(inverted_index_plan) => {
+                    let refresh_inverted_index_interpreter =
+                        RefreshInvertedIndexInterpreter::try_create(
+                            ctx_cloned.clone(),
+                            *inverted_index_plan,
+                        )?;
+                    let build_res = refresh_inverted_index_interpreter.execute2().await?;
+                    if !build_res.main_pipeline.is_empty() {
+                        return Err(ErrorCode::Internal(
+                            "Logical error, refresh inverted index is an empty pipeline.",
+                        ));
+                    }
+                    Ok(())
+                }
                 Plan::RefreshVirtualColumn(virtual_column_plan) => {
                     let refresh_virtual_column_interpreter =
                         RefreshVirtualColumnInterpreter::try_create(
@@ -174,7 +255,7 @@ async fn generate_refresh_index_plan(
 
     let sync_indexes = indexes
         .into_iter()
-        .filter(|(_, _, meta)| meta.sync_creation)
+        .filter(|(_, _, meta)| meta.sync_creation && meta.index_type == IndexType::AGGREGATING)
         .collect::<Vec<_>>();
 
     for (index_id, index_name, index_meta) in sync_indexes {
@@ -192,6 +273,53 @@ async fn generate_refresh_index_plan(
     Ok(plans)
 }
 
+async fn generate_refresh_table_index_plan(
+    ctx: Arc<QueryContext>,
+    catalog: &str,
+    table: &dyn Table,
+) -> Result<Vec<Plan>> {
+    let segment_locs = ctx.get_segment_locations()?;
+    let catalog = ctx.get_catalog(catalog).await?;
+    let indexes = catalog
+        .list_indexes_by_table_id(ListIndexesByIdReq {
+            tenant: ctx.get_tenant(),
+            table_id: table.get_id(),
+        })
+        .await?;
+
+    let plans = indexes
+        .into_iter()
+        .filter(|(_, _, meta)| meta.sync_creation)
+        .filter_map(
+            |(index_id, index_name, index_meta)| match index_meta.index_type {
+                IndexType::VECTOR => {
+                    Some(Plan::RefreshVectorIndex(Box::new(RefreshVectorIndexPlan {
+                        index_id,
+                        index_name,
+                        index_meta,
+                        limit: None,
+                        table_info: table.get_table_info().clone(),
+                        segment_locs: Some(segment_locs.clone()),
+                    })))
+                }
+                IndexType::INVERTED => Some(Plan::RefreshInvertedIndex(Box::new(
+                    RefreshInvertedIndexPlan {
+                        index_id,
+                        index_name,
+                        index_meta,
+                        limit: None,
+                        table_info: table.get_table_info().clone(),
+                        segment_locs: Some(segment_locs.clone()),
+                    },
+                ))),
+                _ => None,
+            },
+        )
+        .collect();
+
+    Ok(plans)
+}
+
 async fn build_refresh_index_plan(
     ctx: Arc<QueryContext>,
     index_id: u64,
@@ -253,3 +381,38 @@ async fn generate_refresh_virtual_column_plan(
 
     Ok(Some(Plan::RefreshVirtualColumn(Box::new(plan))))
 }
+
+async fn generate_refresh_materialized_view_plan(
+    ctx: Arc<QueryContext>,
+    desc: &RefreshDesc,
+    table: &dyn Table,
+) -> Result<Vec<Plan>> {
+    let Some(view_ids) = table.options().get(OPT_KEY_MATERIALIZED_VIEWS) else {
+        return Ok(vec![]);
+    };
+    // The written table is changed, the views must read the latest version.
+    ctx.evict_table_from_cache(&desc.catalog, &desc.database, &desc.table)?;
+
+    let catalog = ctx.get_catalog(&desc.catalog).await?;
+    let mut plans = vec![];
+    for view_id in view_ids.split(',') {
+        let Ok(view_id) = view_id.parse::<u64>() else {
+            continue;
+        };
+        let (_, view_meta) = catalog.get_table_meta_by_id(view_id).await?;
+        let Some(db_id) = view_meta.options.get(OPT_KEY_DATABASE_ID) else {
+            continue;
+        };
+        let database = catalog.get_db_name_by_id(db_id.parse::<u64>()?).await?;
+        let view_name = catalog.get_table_name_by_id(view_id).await?;
+        let plan = RefreshMaterializedViewPlan {
+            tenant: ctx.get_tenant(),
+            catalog: desc.catalog.clone(),
+            database,
+            view_name,
+        };
+        plans.push(Plan::RefreshMaterializedView(Box::new(plan)));
+    }
+
+    Ok(plans)
+}
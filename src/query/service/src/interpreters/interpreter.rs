@@ -39,6 +39,7 @@ use crate::sessions::SessionManager;
 use crate::stream::DataBlockStream;
 use crate::stream::ProgressStream;
 use crate::stream::PullingExecutorStream;
+use crate::workload_groups::WorkloadGroupManager;
 
 #[async_trait::async_trait]
 /// Interpreter is a trait for different PlanNode
@@ -59,6 +60,14 @@ pub trait Interpreter: Sync + Send {
             log_query_finished(&ctx, Some(err.clone()), false);
             return Err(err);
         }
+        let workload_group = match WorkloadGroupManager::instance().assign(&ctx).await {
+            Ok(workload_group) => workload_group,
+            Err(err) => {
+                InterpreterMetrics::record_query_error(&ctx);
+                log_query_finished(&ctx, Some(err.clone()), false);
+                return Err(err);
+            }
+        };
         let mut build_res = match self.execute2().await {
             Ok(build_res) => build_res,
             Err(build_error) => {
@@ -75,8 +84,29 @@ pub trait Interpreter: Sync + Send {
             return Ok(Box::pin(DataBlockStream::create(None, vec![])));
         }
 
+        // Queue the query in its workload group before the executor starts.
+        let workload_group_permit = match workload_group {
+            None => None,
+            Some(workload_group) => {
+                match WorkloadGroupManager::instance()
+                    .admit(&ctx, &workload_group)
+                    .await
+                {
+                    Ok(permit) => Some(permit),
+                    Err(err) => {
+                        InterpreterMetrics::record_query_error(&ctx);
+                        log_query_finished(&ctx, Some(err.clone()), false);
+                        return Err(err);
+                    }
+                }
+            }
+        };
+
         let query_ctx = ctx.clone();
         build_res.main_pipeline.set_on_finished(move |may_error| {
+            // The query leaves its workload group once the pipeline finishes.
+            drop(workload_group_permit);
+
             let mut has_profiles = false;
             if let Ok(profiles) = may_error {
                 query_ctx.add_query_profiles(
@@ -185,6 +215,11 @@ fn log_query_finished(ctx: &QueryContext, error: Option<ErrorCode>, has_profiles
     let now = SystemTime::now();
     let session = ctx.get_current_session();
 
+    // a failed statement aborts the explicit transaction it belongs to
+    if error.is_some() {
+        ctx.txn_mgr().lock().set_fail();
+    }
+
     session.get_status().write().query_finish();
     if session.get_type().is_user_session() {
         SessionManager::instance().status.write().query_finish(now)
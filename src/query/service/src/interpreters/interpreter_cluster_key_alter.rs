@@ -49,7 +49,7 @@ impl Interpreter for AlterTableClusterKeyInterpreter {
             .get_table(tenant.as_str(), &plan.database, &plan.table)
             .await?;
 
-        let cluster_key_str = format!("({})", plan.cluster_keys.join(", "));
+        let cluster_key_str = format!("{}({})", plan.cluster_type, plan.cluster_keys.join(", "));
 
         table
             .alter_table_cluster_keys(self.ctx.clone(), cluster_key_str)
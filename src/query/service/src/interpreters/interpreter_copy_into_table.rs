@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use databend_common_catalog::plan::StageTableInfo;
+use databend_common_catalog::table::TableExt;
 use databend_common_exception::Result;
 use databend_common_expression::types::Int32Type;
 use databend_common_expression::types::StringType;
@@ -120,6 +121,7 @@ impl CopyIntoTableInterpreter {
                 &plan.table_name,
             )
             .await?;
+        to_table.check_not_materialized_view()?;
         let files = plan.collect_files(self.ctx.as_ref()).await?;
         let mut seq = vec![];
         let source = if let Some(ref query) = plan.query {
@@ -117,6 +117,7 @@ impl Interpreter for DeleteInterpreter {
 
         // check mutability
         tbl.check_mutable()?;
+        tbl.check_not_materialized_view()?;
 
         let selection = if !self.plan.subquery_desc.is_empty() {
             let support_row_id = tbl.supported_internal_column(ROW_ID_COLUMN_ID);
@@ -15,6 +15,8 @@
 use std::sync::Arc;
 
 use databend_common_ast::ast::ExplainKind;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use log::error;
 
@@ -68,6 +70,18 @@ pub struct InterpreterFactory;
 impl InterpreterFactory {
     #[async_backtrace::framed]
     pub async fn get(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
+        let res = Self::get_checked(ctx.clone(), plan).await;
+        // a rejected statement aborts the explicit transaction like a failed one
+        if res.is_err() {
+            ctx.txn_mgr().lock().set_fail();
+        }
+        res
+    }
+
+    #[async_backtrace::framed]
+    async fn get_checked(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
+        Self::check_txn(&ctx, plan)?;
+
         // Check the access permission.
         let access_checker = Accessor::create(ctx.clone());
         access_checker.check(plan).await.map_err(|e| {
@@ -77,6 +91,69 @@ impl InterpreterFactory {
         Self::get_inner(ctx, plan)
     }
 
+    /// Check whether the plan is able to run in the current transaction state of the session.
+    fn check_txn(ctx: &QueryContext, plan: &Plan) -> Result<()> {
+        let txn_mgr = ctx.txn_mgr();
+        let txn_mgr = txn_mgr.lock();
+        if txn_mgr.is_fail() && !matches!(plan, Plan::Commit | Plan::Abort) {
+            return Err(ErrorCode::CurrentTransactionIsAborted(
+                "current transaction is aborted, commands ignored until end of transaction block",
+            ));
+        }
+        // Only the table mutations of DML are buffered in the transaction,
+        // DDL would take effect immediately and can not be rolled back.
+        // Statements only reading the metadata are fine.
+        let allowed = matches!(
+            plan,
+            Plan::Query { .. }
+                | Plan::ShowCreateCatalog(_)
+                | Plan::ShowCreateDatabase(_)
+                | Plan::ShowCreateTable(_)
+                | Plan::DescribeTable(_)
+                | Plan::ExistsTable(_)
+                | Plan::ShowFileFormats(_)
+                | Plan::ShowGrants(_)
+                | Plan::ShowRoles(_)
+                | Plan::DescConnection(_)
+                | Plan::ShowConnections(_)
+                | Plan::DescShare(_)
+                | Plan::ShowShares(_)
+                | Plan::ShowShareEndpoint(_)
+                | Plan::ShowObjectGrantPrivileges(_)
+                | Plan::ShowGrantTenantsOfShare(_)
+                | Plan::DescDatamaskPolicy(_)
+                | Plan::DescNetworkPolicy(_)
+                | Plan::ShowNetworkPolicies(_)
+                | Plan::DescPasswordPolicy(_)
+                | Plan::DescribeTask(_)
+                | Plan::ShowTasks(_)
+                | Plan::DescribePipe(_)
+                | Plan::Explain { .. }
+                | Plan::ExplainAst { .. }
+                | Plan::ExplainSyntax { .. }
+                | Plan::ExplainAnalyze { .. }
+                | Plan::Insert(_)
+                | Plan::Replace(_)
+                | Plan::Delete(_)
+                | Plan::Update(_)
+                | Plan::MergeInto(_)
+                | Plan::CopyIntoTable(_)
+                | Plan::UseDatabase(_)
+                | Plan::SetVariable(_)
+                | Plan::UnSetVariable(_)
+                | Plan::Begin
+                | Plan::Commit
+                | Plan::Abort
+        );
+        if txn_mgr.is_active() && !allowed {
+            return Err(ErrorCode::StatementNotAllowedInTransaction(format!(
+                "{} is not allowed in an explicit transaction",
+                plan.format_indent()?
+            )));
+        }
+        Ok(())
+    }
+
     pub fn get_inner(ctx: Arc<QueryContext>, plan: &Plan) -> Result<InterpreterPtr> {
         match plan {
             Plan::Query {
@@ -238,6 +315,17 @@ impl InterpreterFactory {
                 *drop_view.clone(),
             )?)),
 
+            // Materialized Views
+            Plan::CreateMaterializedView(create_view) => Ok(Arc::new(
+                CreateMaterializedViewInterpreter::try_create(ctx, *create_view.clone())?,
+            )),
+            Plan::DropMaterializedView(drop_view) => Ok(Arc::new(
+                DropMaterializedViewInterpreter::try_create(ctx, *drop_view.clone())?,
+            )),
+            Plan::RefreshMaterializedView(refresh_view) => Ok(Arc::new(
+                RefreshMaterializedViewInterpreter::try_create(ctx, *refresh_view.clone())?,
+            )),
+
             // Streams
             Plan::CreateStream(create_stream) => Ok(Arc::new(CreateStreamInterpreter::try_create(
                 ctx,
@@ -262,6 +350,18 @@ impl InterpreterFactory {
                 ctx,
                 *index.clone(),
             )?)),
+            Plan::CreateVectorIndex(index) => Ok(Arc::new(
+                CreateVectorIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
+            Plan::RefreshVectorIndex(index) => Ok(Arc::new(
+                RefreshVectorIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
+            Plan::CreateInvertedIndex(index) => Ok(Arc::new(
+                CreateInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
+            Plan::RefreshInvertedIndex(index) => Ok(Arc::new(
+                RefreshInvertedIndexInterpreter::try_create(ctx, *index.clone())?,
+            )),
             // Virtual columns
             Plan::CreateVirtualColumn(create_virtual_column) => Ok(Arc::new(
                 CreateVirtualColumnInterpreter::try_create(ctx, *create_virtual_column.clone())?,
@@ -399,6 +499,11 @@ impl InterpreterFactory {
             )?)),
             Plan::Kill(p) => Ok(Arc::new(KillInterpreter::try_create(ctx, *p.clone())?)),
 
+            // transactions
+            Plan::Begin => Ok(Arc::new(BeginInterpreter::try_create(ctx)?)),
+            Plan::Commit => Ok(Arc::new(CommitInterpreter::try_create(ctx)?)),
+            Plan::Abort => Ok(Arc::new(AbortInterpreter::try_create(ctx)?)),
+
             // share plans
             Plan::CreateShareEndpoint(p) => Ok(Arc::new(
                 CreateShareEndpointInterpreter::try_create(ctx, *p.clone())?,
@@ -485,6 +590,17 @@ impl InterpreterFactory {
                 ctx,
                 *p.clone(),
             )?)),
+            Plan::CreateWorkloadGroup(p) => Ok(Arc::new(
+                CreateWorkloadGroupInterpreter::try_create(ctx, *p.clone())?,
+            )),
+            Plan::AlterWorkloadGroup(p) => Ok(Arc::new(AlterWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::DropWorkloadGroup(p) => Ok(Arc::new(DropWorkloadGroupInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
 
             Plan::CreateTask(p) => Ok(Arc::new(CreateTaskInterpreter::try_create(
                 ctx,
@@ -501,6 +617,16 @@ impl InterpreterFactory {
                 *p.clone(),
             )?)),
             Plan::ShowTasks(p) => Ok(Arc::new(ShowTasksInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::CreatePipe(p) => Ok(Arc::new(CreatePipeInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
+            Plan::AlterPipe(p) => Ok(Arc::new(AlterPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DropPipe(p) => Ok(Arc::new(DropPipeInterpreter::try_create(ctx, *p.clone())?)),
+            Plan::DescribePipe(p) => Ok(Arc::new(DescribePipeInterpreter::try_create(
+                ctx,
+                *p.clone(),
+            )?)),
 
             Plan::CreateConnection(p) => Ok(Arc::new(CreateConnectionInterpreter::try_create(
                 ctx,
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
 use chrono::Utc;
@@ -78,6 +79,7 @@ impl Interpreter for CreateIndexInterpreter {
                 original_query: self.plan.original_query.clone(),
                 query: self.plan.query.clone(),
                 sync_creation: self.plan.sync_creation,
+                options: BTreeMap::new(),
             },
         };
 
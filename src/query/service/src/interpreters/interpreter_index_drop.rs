@@ -18,7 +18,9 @@ use databend_common_exception::Result;
 use databend_common_license::license::Feature;
 use databend_common_license::license_manager::get_license_manager;
 use databend_common_meta_app::schema::DropIndexReq;
+use databend_common_meta_app::schema::GetIndexReq;
 use databend_common_meta_app::schema::IndexNameIdent;
+use databend_common_meta_app::schema::IndexType;
 use databend_common_sql::plans::DropIndexPlan;
 use databend_enterprise_aggregating_index::get_agg_index_handler;
 
@@ -47,21 +49,32 @@ impl Interpreter for DropIndexInterpreter {
     #[async_backtrace::framed]
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let tenant = self.ctx.get_tenant();
-        let license_manager = get_license_manager();
-        license_manager
-            .manager
-            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::AggregateIndex)?;
-
         let index_name = self.plan.index.clone();
         let catalog = self
             .ctx
             .get_catalog(&self.ctx.get_current_catalog())
             .await?;
+        let name_ident = IndexNameIdent { tenant, index_name };
         let drop_index_req = DropIndexReq {
             if_exists: self.plan.if_exists,
-            name_ident: IndexNameIdent { tenant, index_name },
+            name_ident: name_ident.clone(),
         };
 
+        // Vector and inverted indexes are not enterprise features.
+        let index = catalog.get_index(GetIndexReq { name_ident }).await.ok();
+        if matches!(index, Some(index) if matches!(
+            index.index_meta.index_type,
+            IndexType::VECTOR | IndexType::INVERTED
+        )) {
+            let _ = catalog.drop_index(drop_index_req).await?;
+            return Ok(PipelineBuildResult::create());
+        }
+
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::AggregateIndex)?;
+
         let handler = get_agg_index_handler();
         let _ = handler.do_drop_index(catalog, drop_index_req).await?;
 
@@ -90,6 +90,7 @@ impl Interpreter for InsertInterpreter {
 
         // check mutability
         table.check_mutable()?;
+        table.check_not_materialized_view()?;
 
         let mut build_res = PipelineBuildResult::create();
 
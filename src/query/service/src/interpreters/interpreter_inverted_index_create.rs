@@ -0,0 +1,80 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::CreateIndexReq;
+use databend_common_meta_app::schema::IndexMeta;
+use databend_common_meta_app::schema::IndexNameIdent;
+use databend_common_meta_app::schema::IndexType;
+use databend_common_sql::plans::CreateInvertedIndexPlan;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateInvertedIndexInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateInvertedIndexPlan,
+}
+
+impl CreateInvertedIndexInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateInvertedIndexPlan) -> Result<Self> {
+        Ok(CreateInvertedIndexInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateInvertedIndexInterpreter {
+    fn name(&self) -> &str {
+        "CreateInvertedIndexInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let tenant = self.ctx.get_tenant();
+        let index_name = self.plan.index_name.clone();
+        let catalog = self.ctx.get_current_catalog();
+        if catalog != "default" {
+            return Err(ErrorCode::CatalogNotSupported(
+                "Only allow creating inverted index in default catalog",
+            ));
+        }
+
+        let catalog = self.ctx.get_catalog(&catalog).await?;
+
+        let create_index_req = CreateIndexReq {
+            if_not_exists: self.plan.if_not_exists,
+            name_ident: IndexNameIdent { tenant, index_name },
+            meta: IndexMeta {
+                table_id: self.plan.table_id,
+                index_type: IndexType::INVERTED,
+                created_on: Utc::now(),
+                dropped_on: None,
+                updated_on: None,
+                original_query: self.plan.query.clone(),
+                query: self.plan.query.clone(),
+                sync_creation: self.plan.sync_creation,
+                options: self.plan.options.clone(),
+            },
+        };
+
+        let _ = catalog.create_index(create_index_req).await?;
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,128 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::get_license_manager;
+use databend_common_sql::plans::materialized_view_stream_name;
+use databend_common_sql::plans::CreateMaterializedViewPlan;
+use databend_common_sql::plans::CreateStreamPlan;
+use databend_common_sql::plans::RefreshMaterializedViewPlan;
+use databend_enterprise_stream_handler::get_stream_handler;
+
+use crate::interpreters::common::update_materialized_views_option;
+use crate::interpreters::CreateTableInterpreter;
+use crate::interpreters::Interpreter;
+use crate::interpreters::RefreshMaterializedViewInterpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct CreateMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: CreateMaterializedViewPlan,
+}
+
+impl CreateMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: CreateMaterializedViewPlan) -> Result<Self> {
+        Ok(CreateMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CreateMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "CreateMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::Stream)?;
+
+        let tenant = self.plan.tenant.clone();
+        let catalog = self.ctx.get_catalog(&self.plan.catalog).await?;
+        if catalog
+            .exists_table(&tenant, &self.plan.database, &self.plan.view_name)
+            .await?
+        {
+            return if self.plan.if_not_exists {
+                Ok(PipelineBuildResult::create())
+            } else {
+                Err(ErrorCode::TableAlreadyExists(format!(
+                    "Materialized view '{}.{}' already exists",
+                    &self.plan.database, &self.plan.view_name,
+                )))
+            };
+        }
+
+        // Create the fuse table that holds the rows of the view.
+        CreateTableInterpreter::try_create(self.ctx.clone(), self.plan.create_table_plan.clone())?
+            .execute2()
+            .await?;
+        let view_id = catalog
+            .get_table(&tenant, &self.plan.database, &self.plan.view_name)
+            .await?
+            .get_id();
+
+        // Track the changes of each base table by an internal stream.
+        let handler = get_stream_handler();
+        for (catalog_name, database, table_name) in &self.plan.base_tables {
+            let table = catalog.get_table(&tenant, database, table_name).await?;
+            let stream_plan = CreateStreamPlan {
+                if_not_exists: false,
+                tenant: tenant.clone(),
+                catalog: catalog_name.clone(),
+                database: self.plan.database.clone(),
+                stream_name: materialized_view_stream_name(view_id, table.get_id()),
+                table_database: database.clone(),
+                table_name: table_name.clone(),
+                navigation: None,
+                append_only: true,
+                comment: None,
+            };
+            handler
+                .do_create_stream(self.ctx.clone(), &stream_plan)
+                .await?;
+
+            update_materialized_views_option(
+                &self.ctx,
+                catalog_name,
+                database,
+                table_name,
+                view_id,
+                true,
+            )
+            .await?;
+            self.ctx
+                .evict_table_from_cache(catalog_name, database, table_name)?;
+        }
+
+        // Populate the view.
+        let refresh_plan = RefreshMaterializedViewPlan {
+            tenant,
+            catalog: self.plan.catalog.clone(),
+            database: self.plan.database.clone(),
+            view_name: self.plan.view_name.clone(),
+        };
+        RefreshMaterializedViewInterpreter::try_create(self.ctx.clone(), refresh_plan)?
+            .execute2()
+            .await
+    }
+}
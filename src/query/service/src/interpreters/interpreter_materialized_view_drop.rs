@@ -0,0 +1,145 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::RoleApi;
+use databend_common_meta_app::principal::OwnershipObject;
+use databend_common_meta_app::schema::DropTableByIdReq;
+use databend_common_sql::plans::DropMaterializedViewPlan;
+use databend_common_sql::plans::DropStreamPlan;
+use databend_common_storages_stream::stream_table::StreamTable;
+use databend_common_users::RoleCacheManager;
+use databend_common_users::UserApiProvider;
+use databend_enterprise_stream_handler::get_stream_handler;
+
+use crate::interpreters::common::list_materialized_view_streams;
+use crate::interpreters::common::update_materialized_views_option;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+pub struct DropMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: DropMaterializedViewPlan,
+}
+
+impl DropMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: DropMaterializedViewPlan) -> Result<Self> {
+        Ok(DropMaterializedViewInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for DropMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "DropMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let view_name = self.plan.view_name.as_str();
+        let view = match self.ctx.get_table(catalog_name, db_name, view_name).await {
+            Ok(table) => table,
+            Err(error) => {
+                if (error.code() == ErrorCode::UNKNOWN_TABLE
+                    || error.code() == ErrorCode::UNKNOWN_CATALOG
+                    || error.code() == ErrorCode::UNKNOWN_DATABASE)
+                    && self.plan.if_exists
+                {
+                    return Ok(PipelineBuildResult::create());
+                } else {
+                    return Err(error);
+                }
+            }
+        };
+        if !view.is_materialized_view() {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} is not a materialized view",
+                db_name, view_name
+            )));
+        }
+        let view_id = view.get_id();
+
+        let tenant = self.ctx.get_tenant();
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        let db = catalog.get_database(&tenant, db_name).await?;
+        catalog
+            .drop_table_by_id(DropTableByIdReq {
+                if_exists: self.plan.if_exists,
+                tenant: tenant.clone(),
+                table_name: view_name.to_string(),
+                tb_id: view_id,
+                db_id: db.get_db_info().ident.db_id,
+            })
+            .await?;
+
+        // drop the ownership
+        let role_api = UserApiProvider::instance().get_role_api_client(&self.plan.tenant)?;
+        let owner_object = OwnershipObject::Table {
+            catalog_name: self.plan.catalog.clone(),
+            db_id: db.get_db_info().ident.db_id,
+            table_id: view_id,
+        };
+        role_api.revoke_ownership(&owner_object).await?;
+        RoleCacheManager::instance().invalidate_cache(&tenant);
+
+        // Drop the internal streams, and detach the view from the base tables.
+        let handler = get_stream_handler();
+        let streams =
+            list_materialized_view_streams(&self.ctx, catalog_name, db_name, view_id).await?;
+        for stream in streams {
+            let stream_table = StreamTable::try_from_table(stream.as_ref())?;
+            let drop_stream_plan = DropStreamPlan {
+                if_exists: true,
+                tenant: tenant.clone(),
+                catalog: self.plan.catalog.clone(),
+                database: self.plan.database.clone(),
+                stream_name: stream.name().to_string(),
+            };
+            handler
+                .do_drop_stream(self.ctx.clone(), &drop_stream_plan)
+                .await?;
+
+            let table_database = stream_table.source_table_database();
+            let table_name = stream_table.source_table_name();
+            match update_materialized_views_option(
+                &self.ctx,
+                catalog_name,
+                table_database,
+                table_name,
+                view_id,
+                false,
+            )
+            .await
+            {
+                Ok(_) => {
+                    self.ctx
+                        .evict_table_from_cache(catalog_name, table_database, table_name)?
+                }
+                // The base table may be dropped or renamed.
+                Err(e) if e.code() == ErrorCode::UNKNOWN_TABLE => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -0,0 +1,262 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::VisitorMut;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::DataSchema;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::get_license_manager;
+use databend_common_sql::executor::physical_plans::DistributedInsertSelect;
+use databend_common_sql::executor::PhysicalPlan;
+use databend_common_sql::executor::PhysicalPlanBuilder;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::plans::RefreshMaterializedViewPlan;
+use databend_common_sql::MaterializedViewStreamRewriter;
+use databend_common_sql::NameResolutionContext;
+use databend_common_sql::Planner;
+use databend_common_storages_fuse::FuseTable;
+use databend_common_storages_stream::stream_table::StreamTable;
+use databend_storages_common_table_meta::table::MATERIALIZED_VIEW_REFRESH_INCREMENTAL;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE;
+
+use crate::interpreters::common::build_update_stream_meta;
+use crate::interpreters::common::list_materialized_view_streams;
+use crate::interpreters::HookOperator;
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::schedulers::build_query_pipeline_without_render_result_set;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// Refreshes a materialized view.
+///
+/// The changes of the base tables since the last refresh are tracked by the internal streams
+/// of the view. If the view is refreshed incrementally, the query of the view is evaluated on
+/// the rows appended to the base table, and the result is appended to the view. Otherwise the
+/// view is overwritten by the result of the query. In both cases the streams are consumed in
+/// the same commit.
+pub struct RefreshMaterializedViewInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: RefreshMaterializedViewPlan,
+}
+
+impl RefreshMaterializedViewInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: RefreshMaterializedViewPlan) -> Result<Self> {
+        Ok(RefreshMaterializedViewInterpreter { ctx, plan })
+    }
+
+    /// Replaces the base table in the query with the stream, `None` if the query can't read
+    /// the changes from the stream.
+    fn rewrite_query_with_stream(
+        &self,
+        query: &str,
+        stream: &StreamTable,
+    ) -> Result<Option<String>> {
+        let settings = self.ctx.get_settings();
+        let tokens = tokenize_sql(query)?;
+        let (stmt, _) = parse_sql(&tokens, settings.get_sql_dialect()?)?;
+        let Statement::Query(mut query) = stmt else {
+            return Ok(None);
+        };
+
+        let mut rewriter = MaterializedViewStreamRewriter {
+            name_resolution_ctx: NameResolutionContext::try_from(settings.as_ref())?,
+            database: stream.source_table_database().to_string(),
+            table: stream.source_table_name().to_string(),
+            stream_database: self.plan.database.clone(),
+            stream_name: stream.name().to_string(),
+            replaced: 0,
+        };
+        rewriter.visit_query(&mut query);
+        if rewriter.replaced != 1 {
+            return Ok(None);
+        }
+        Ok(Some(format!("{}", query)))
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for RefreshMaterializedViewInterpreter {
+    fn name(&self) -> &str {
+        "RefreshMaterializedViewInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::Stream)?;
+
+        let catalog_name = self.plan.catalog.as_str();
+        let db_name = self.plan.database.as_str();
+        let view_name = self.plan.view_name.as_str();
+        self.ctx
+            .evict_table_from_cache(catalog_name, db_name, view_name)?;
+        let view = self.ctx.get_table(catalog_name, db_name, view_name).await?;
+        if !view.is_materialized_view() {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} is not a materialized view",
+                db_name, view_name
+            )));
+        }
+        let query = view
+            .options()
+            .get(OPT_KEY_MATERIALIZED_VIEW_QUERY)
+            .ok_or_else(|| {
+                ErrorCode::Internal(
+                    "Logical error, Materialized View must have a SelectQuery inside.",
+                )
+            })?;
+        let view_snapshot = FuseTable::try_from_table(view.as_ref())?
+            .snapshot_loc()
+            .await?;
+
+        // The view is fresh if all the streams are consumed to the current snapshots.
+        let streams =
+            list_materialized_view_streams(&self.ctx, catalog_name, db_name, view.get_id()).await?;
+        let mut fresh = !streams.is_empty();
+        for stream in streams.iter() {
+            self.ctx
+                .evict_table_from_cache(catalog_name, db_name, stream.name())?;
+            let stream = StreamTable::try_from_table(stream.as_ref())?;
+            self.ctx.evict_table_from_cache(
+                catalog_name,
+                stream.source_table_database(),
+                stream.source_table_name(),
+            )?;
+            let source_table = stream.source_table(self.ctx.clone()).await?;
+            let source_snapshot = FuseTable::try_from_table(source_table.as_ref())?
+                .snapshot_loc()
+                .await?;
+            fresh &= stream.snapshot_loc() == source_snapshot;
+        }
+        if fresh && view_snapshot.is_some() {
+            return Ok(PipelineBuildResult::create());
+        }
+
+        // The changes can be applied incrementally only if the rows are appended to the base table.
+        let mut sql = query.clone();
+        let mut overwrite = true;
+        let refresh_mode = view.options().get(OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE);
+        if refresh_mode.map(|mode| mode.as_str()) == Some(MATERIALIZED_VIEW_REFRESH_INCREMENTAL)
+            && view_snapshot.is_some()
+            && streams.len() == 1
+        {
+            let stream = StreamTable::try_from_table(streams[0].as_ref())?;
+            if !stream.has_removed_blocks(self.ctx.clone()).await? {
+                if let Some(incremental_sql) = self.rewrite_query_with_stream(query, stream)? {
+                    sql = incremental_sql;
+                    overwrite = false;
+                }
+            }
+        }
+
+        let mut planner = Planner::new(self.ctx.clone());
+        let (plan, _) = planner.plan_sql(&sql).await?;
+        let (mut select_plan, select_column_bindings) = match &plan {
+            Plan::Query {
+                s_expr,
+                metadata,
+                bind_context,
+                ..
+            } => {
+                let mut builder =
+                    PhysicalPlanBuilder::new(metadata.clone(), self.ctx.clone(), false);
+                (
+                    builder.build(s_expr, bind_context.column_set()).await?,
+                    bind_context.columns.clone(),
+                )
+            }
+            _ => {
+                return Err(ErrorCode::Internal(
+                    "Logical error, Materialized View must have a SelectQuery inside.",
+                ));
+            }
+        };
+
+        let update_stream_meta = build_update_stream_meta(self.ctx.clone(), streams).await?;
+
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        let select_schema = plan.schema();
+        let insert_schema = Arc::new(DataSchema::from(view.schema().as_ref()));
+        let cast_needed = select_schema != insert_schema;
+        let insert_select_plan = match select_plan {
+            PhysicalPlan::Exchange(ref mut exchange) => {
+                let input = exchange.input.clone();
+                exchange.input = Box::new(PhysicalPlan::DistributedInsertSelect(Box::new(
+                    DistributedInsertSelect {
+                        plan_id: exchange.plan_id,
+                        input,
+                        catalog_info: catalog.info(),
+                        table_info: view.get_table_info().clone(),
+                        select_schema,
+                        select_column_bindings,
+                        insert_schema,
+                        cast_needed,
+                    },
+                )));
+                select_plan
+            }
+            other_plan => {
+                PhysicalPlan::DistributedInsertSelect(Box::new(DistributedInsertSelect {
+                    plan_id: other_plan.get_id(),
+                    input: Box::new(other_plan),
+                    catalog_info: catalog.info(),
+                    table_info: view.get_table_info().clone(),
+                    select_schema,
+                    select_column_bindings,
+                    insert_schema,
+                    cast_needed,
+                }))
+            }
+        };
+
+        let mut build_res =
+            build_query_pipeline_without_render_result_set(&self.ctx, &insert_select_plan).await?;
+        view.commit_insertion(
+            self.ctx.clone(),
+            &mut build_res.main_pipeline,
+            None,
+            update_stream_meta,
+            overwrite,
+            None,
+            None,
+        )?;
+
+        //  Execute the hook operator.
+        {
+            let hook_operator = HookOperator::create(
+                self.ctx.clone(),
+                self.plan.catalog.clone(),
+                self.plan.database.clone(),
+                self.plan.view_name.clone(),
+                "refresh_materialized_view".to_owned(),
+                true,
+            );
+            hook_operator.execute(&mut build_res.main_pipeline).await;
+        }
+
+        Ok(build_res)
+    }
+}
@@ -203,6 +203,7 @@ impl MergeIntoInterpreter {
         // check mutability
         let check_table = self.ctx.get_table(catalog, database, table_name).await?;
         check_table.check_mutable()?;
+        check_table.check_not_materialized_view()?;
         // check change tracking
         if check_table.change_tracking_enabled() {
             return Err(ErrorCode::Unimplemented(format!(
@@ -0,0 +1,100 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::AlterPipeOptions;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_sql::plans::AlterPipePlan;
+use databend_common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::pipes::run_pipe;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+#[derive(Debug)]
+pub struct AlterPipeInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterPipePlan,
+}
+
+impl AlterPipeInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterPipePlan) -> Result<Self> {
+        Ok(AlterPipeInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterPipeInterpreter {
+    fn name(&self) -> &str {
+        "AlterPipeInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "alter_pipe_execute");
+
+        let plan = &self.plan;
+        let user_mgr = UserApiProvider::instance();
+        match &plan.options {
+            AlterPipeOptions::Set {
+                execution_paused,
+                comments,
+            } => {
+                user_mgr
+                    .update_pipe(
+                        &plan.tenant,
+                        &plan.pipe_name,
+                        *execution_paused,
+                        comments.clone(),
+                        plan.if_exists,
+                    )
+                    .await?;
+            }
+            AlterPipeOptions::Refresh {
+                prefix,
+                modified_after,
+            } => {
+                let pipe = match user_mgr.get_pipe(&plan.tenant, &plan.pipe_name).await {
+                    Ok(pipe) => pipe,
+                    Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_PIPE => {
+                        return Ok(PipelineBuildResult::create());
+                    }
+                    Err(e) => return Err(e),
+                };
+                if pipe.execution_paused {
+                    return Err(ErrorCode::InvalidOperation(format!(
+                        "Pipe '{}' is paused, can not be refreshed",
+                        pipe.name
+                    )));
+                }
+
+                // The pipe is run as a separate query in the session.
+                let ctx = self
+                    .ctx
+                    .get_current_session()
+                    .create_query_context()
+                    .await?;
+                run_pipe(ctx, &pipe, prefix.as_deref(), modified_after.as_deref()).await?;
+            }
+        }
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -128,6 +128,7 @@ impl ReplaceInterpreter {
 
         // check mutability
         table.check_mutable()?;
+        table.check_not_materialized_view()?;
         // check change tracking
         if table.change_tracking_enabled() {
             return Err(ErrorCode::Unimplemented(format!(
@@ -76,6 +76,7 @@ impl Interpreter for AddTableColumnInterpreter {
         if let Some(table) = &tbl {
             // check mutability
             table.check_mutable()?;
+            table.check_not_materialized_view()?;
 
             let table_info = table.get_table_info();
             let engine = table_info.engine();
@@ -82,6 +82,12 @@ impl Interpreter for DropTableInterpreter {
                 &self.plan.table
             )));
         }
+        if tbl.is_materialized_view() {
+            return Err(ErrorCode::TableEngineNotSupported(format!(
+                "{}.{} is a materialized view that doesn't support drop, use `DROP MATERIALIZED VIEW {}.{}` instead",
+                &self.plan.database, &self.plan.table, &self.plan.database, &self.plan.table
+            )));
+        }
         let catalog = self.ctx.get_catalog(catalog_name).await?;
 
         // Although even if data is in READ_ONLY mode,
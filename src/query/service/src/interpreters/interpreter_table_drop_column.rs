@@ -29,6 +29,7 @@ use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::drop_referenced_constraints;
 use crate::interpreters::interpreter_table_add_column::generate_new_snapshot;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -66,6 +67,7 @@ impl Interpreter for DropTableColumnInterpreter {
 
         // check mutability
         table.check_mutable()?;
+        table.check_not_materialized_view()?;
 
         let table_info = table.get_table_info();
         let engine = table_info.engine();
@@ -97,6 +99,13 @@ impl Interpreter for DropTableColumnInterpreter {
         let catalog = self.ctx.get_catalog(catalog_name).await?;
         let mut new_table_meta = table.get_table_info().meta.clone();
         new_table_meta.drop_column(&self.plan.column)?;
+        let schema: DataSchema = new_table_meta.schema.clone().into();
+        drop_referenced_constraints(
+            self.ctx.clone(),
+            Arc::new(schema),
+            &mut new_table_meta.constraints,
+            self.plan.column.as_str(),
+        );
 
         // update table options
         let opts = &mut new_table_meta.options;
@@ -534,6 +534,7 @@ impl Interpreter for ModifyTableColumnInterpreter {
         let table = if let Some(table) = &tbl {
             // check mutability
             table.check_mutable()?;
+            table.check_not_materialized_view()?;
             table
         } else {
             return Ok(PipelineBuildResult::create());
@@ -110,6 +110,17 @@ impl Interpreter for ReclusterTableInterpreter {
         // check mutability
         table.check_mutable()?;
 
+        // The hilbert and z-order layouts scale the cluster keys by their ranges, which are
+        // refreshed before reclustering if the data outgrew them.
+        if FuseTable::try_from_table(table.as_ref())?
+            .refresh_cluster_key_bounds(ctx.clone())
+            .await?
+        {
+            table = catalog
+                .get_table(tenant.as_str(), &self.plan.database, &self.plan.table)
+                .await?;
+        }
+
         let mut times = 0;
         let mut block_count = 0;
         let start = SystemTime::now();
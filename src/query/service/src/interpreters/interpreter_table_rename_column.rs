@@ -29,6 +29,7 @@ use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 
 use crate::interpreters::common::check_referenced_computed_columns;
+use crate::interpreters::common::rename_constraint_columns;
 use crate::interpreters::interpreter_table_create::is_valid_column;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
@@ -68,6 +69,7 @@ impl Interpreter for RenameTableColumnInterpreter {
         if let Some(table) = &tbl {
             // check mutability
             table.check_mutable()?;
+            table.check_not_materialized_view()?;
 
             let table_info = table.get_table_info();
             let engine = table.engine();
@@ -103,6 +105,14 @@ impl Interpreter for RenameTableColumnInterpreter {
             }
 
             new_table_meta.schema = Arc::new(self.plan.schema.clone());
+            let schema: DataSchema = new_table_meta.schema.clone().into();
+            rename_constraint_columns(
+                self.ctx.clone(),
+                Arc::new(schema),
+                &mut new_table_meta.constraints,
+                self.plan.old_column.as_str(),
+                self.plan.new_column.as_str(),
+            )?;
 
             // update table options
             let opts = &mut new_table_meta.options;
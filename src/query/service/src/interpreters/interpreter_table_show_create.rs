@@ -15,6 +15,7 @@
 use std::sync::Arc;
 
 use databend_common_catalog::table::Table;
+use databend_common_catalog::table::TableExt;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
@@ -29,6 +30,7 @@ use databend_common_storages_stream::stream_table::STREAM_ENGINE;
 use databend_common_storages_view::view_table::QUERY;
 use databend_common_storages_view::view_table::VIEW_ENGINE;
 use databend_storages_common_table_meta::table::is_internal_opt_key;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
 use databend_storages_common_table_meta::table::OPT_KEY_STORAGE_PREFIX;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_DATA_URI;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ATTACHED_READ_ONLY;
@@ -68,6 +70,7 @@ impl Interpreter for ShowCreateTableInterpreter {
         match table.engine() {
             STREAM_ENGINE => self.show_create_stream(table.as_ref()),
             VIEW_ENGINE => self.show_create_view(table.as_ref()),
+            _ if table.is_materialized_view() => self.show_create_materialized_view(table.as_ref()),
             _ => match table.options().get(OPT_KEY_STORAGE_PREFIX) {
                 Some(_) => self.show_attach_table(table.as_ref()),
                 None => self.show_create_table(table.as_ref()),
@@ -137,6 +140,9 @@ impl ShowCreateTableInterpreter {
 
                 columns.push(column);
             }
+            for (name, constraint) in table.get_table_info().meta.constraints.iter() {
+                columns.push(format!("  CONSTRAINT `{}` {}", name, constraint));
+            }
             // Format is:
             //  (
             //      x,
@@ -224,6 +230,30 @@ impl ShowCreateTableInterpreter {
         }
     }
 
+    fn show_create_materialized_view(&self, table: &dyn Table) -> Result<PipelineBuildResult> {
+        let name = table.name();
+        let Some(query) = table.options().get(OPT_KEY_MATERIALIZED_VIEW_QUERY) else {
+            return Err(ErrorCode::Internal(
+                "Logical error, Materialized View must have a SelectQuery inside.",
+            ));
+        };
+        let create_sql = format!(
+            "CREATE MATERIALIZED VIEW `{}`.`{}` AS {}",
+            &self.plan.database, name, query
+        );
+        let block = DataBlock::new(
+            vec![
+                BlockEntry::new(
+                    DataType::String,
+                    Value::Scalar(Scalar::String(name.to_string())),
+                ),
+                BlockEntry::new(DataType::String, Value::Scalar(Scalar::String(create_sql))),
+            ],
+            1,
+        );
+        PipelineBuildResult::from_blocks(vec![block])
+    }
+
     fn show_create_stream(&self, table: &dyn Table) -> Result<PipelineBuildResult> {
         let stream_table = StreamTable::try_from_table(table)?;
         let mut create_sql = format!(
@@ -73,6 +73,7 @@ impl Interpreter for TruncateTableInterpreter {
 
         // check mutability
         table.check_mutable()?;
+        table.check_not_materialized_view()?;
 
         if self.proxy_to_cluster && table.broadcast_truncate_to_cluster() {
             let settings = self.ctx.get_settings();
@@ -14,6 +14,7 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_ast::ast::AlterTaskOptions;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
@@ -24,13 +25,18 @@ use databend_common_cloud_control::task_client::make_request;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal as mt;
+use databend_common_meta_app::principal::Status;
 use databend_common_sql::plans::AlterTaskPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_client_config;
 use crate::interpreters::common::make_schedule_options;
+use crate::interpreters::common::make_task_schedule_options;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
+use crate::tasks::check_task_predecessors;
 
 #[derive(Debug)]
 pub struct AlterTaskInterpreter {
@@ -123,6 +129,105 @@ impl AlterTaskInterpreter {
     }
 }
 
+impl AlterTaskInterpreter {
+    // Alters the task stored in the meta service.
+    async fn alter_local_task(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+        let user_api = UserApiProvider::instance();
+        let seq_task = match user_api.get_task(&plan.tenant, &plan.task_name).await {
+            Ok(seq_task) => seq_task,
+            Err(e) if plan.if_exists && e.code() == ErrorCode::UNKNOWN_TASK => {
+                return Ok(PipelineBuildResult::create());
+            }
+            Err(e) => return Err(e),
+        };
+
+        let mut task = seq_task.data;
+        let now = Utc::now();
+        match plan.alter_options {
+            AlterTaskOptions::Resume => {
+                task.status = Status::Started;
+                task.next_scheduled_at = task
+                    .schedule_options
+                    .as_ref()
+                    .and_then(|s| s.next_scheduled_at(now));
+            }
+            AlterTaskOptions::Suspend => {
+                task.status = Status::Suspended;
+                task.next_scheduled_at = None;
+                task.last_suspended_at = Some(now);
+            }
+            AlterTaskOptions::Set {
+                schedule,
+                comments,
+                warehouse,
+                suspend_task_after_num_failures,
+                session_parameters,
+            } => {
+                if let Some(schedule) = schedule {
+                    if !task.after.is_empty() {
+                        return Err(ErrorCode::InvalidOperation(format!(
+                            "Task '{}' runs after other tasks, can not be scheduled",
+                            task.task_name
+                        )));
+                    }
+                    let schedule = make_task_schedule_options(schedule);
+                    if task.status == Status::Started {
+                        task.next_scheduled_at = schedule.next_scheduled_at(now);
+                    }
+                    task.schedule_options = Some(schedule);
+                }
+                if let Some(comments) = comments {
+                    task.comment = Some(comments);
+                }
+                if let Some(warehouse) = warehouse {
+                    task.warehouse_options = Some(mt::WarehouseOptions {
+                        warehouse: Some(warehouse),
+                        using_warehouse_size: None,
+                    });
+                }
+                if let Some(num) = suspend_task_after_num_failures {
+                    task.suspend_task_after_num_failures = Some(num);
+                }
+                if let Some(session_parameters) = session_parameters {
+                    task.session_params = session_parameters;
+                }
+            }
+            AlterTaskOptions::Unset { warehouse } => {
+                if warehouse {
+                    task.warehouse_options = None;
+                }
+            }
+            AlterTaskOptions::ModifyAs(sql) => {
+                task.query_text = sql;
+            }
+            AlterTaskOptions::ModifyWhen(condition) => {
+                task.when_condition = Some(condition);
+            }
+            AlterTaskOptions::AddAfter(after) => {
+                let tasks = user_api.get_tasks(&plan.tenant).await?;
+                check_task_predecessors(&tasks, &task.task_name, &after)?;
+                for name in after {
+                    if !task.after.contains(&name) {
+                        task.after.push(name);
+                    }
+                }
+                // The task is run after its predecessors instead of by the schedule.
+                task.schedule_options = None;
+                task.next_scheduled_at = None;
+            }
+            AlterTaskOptions::RemoveAfter(after) => {
+                task.after.retain(|name| !after.contains(name));
+            }
+        }
+        task.updated_at = now;
+        user_api
+            .update_task(&plan.tenant, task, seq_task.seq)
+            .await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
+
 #[async_trait::async_trait]
 impl Interpreter for AlterTaskInterpreter {
     fn name(&self) -> &str {
@@ -134,9 +239,7 @@ impl Interpreter for AlterTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot alter task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            return self.alter_local_task().await;
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
@@ -14,21 +14,27 @@
 
 use std::sync::Arc;
 
+use chrono::Utc;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::CreateTaskRequest;
 use databend_common_cloud_control::task_client::make_request;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::principal::Status;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::WarehouseOptions;
 use databend_common_sql::plans::CreateTaskPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_client_config;
 use crate::interpreters::common::make_schedule_options;
+use crate::interpreters::common::make_task_schedule_options;
 use crate::interpreters::common::make_warehouse_options;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
+use crate::tasks::check_task_predecessors;
 
 #[derive(Debug)]
 pub struct CreateTaskInterpreter {
@@ -68,6 +74,49 @@ impl CreateTaskInterpreter {
     }
 }
 
+impl CreateTaskInterpreter {
+    // Stores the task in the meta service, to be run by the task scheduler of the cluster.
+    async fn create_local_task(&self) -> Result<PipelineBuildResult> {
+        let plan = self.plan.clone();
+        let user_api = UserApiProvider::instance();
+        let tasks = user_api.get_tasks(&plan.tenant).await?;
+        check_task_predecessors(&tasks, &plan.task_name, &plan.after)?;
+
+        let owner = self
+            .ctx
+            .get_current_role()
+            .unwrap_or_default()
+            .identity()
+            .to_string();
+        let now = Utc::now();
+        let task = Task {
+            task_id: 0,
+            task_name: plan.task_name,
+            query_text: plan.sql,
+            when_condition: plan.when_condition,
+            after: plan.after,
+            comment: Some(plan.comment),
+            owner,
+            schedule_options: plan.schedule_opts.map(make_task_schedule_options),
+            warehouse_options: Some(WarehouseOptions {
+                warehouse: plan.warehouse_opts.warehouse,
+                using_warehouse_size: None,
+            }),
+            next_scheduled_at: None,
+            suspend_task_after_num_failures: plan.suspend_task_after_num_failures,
+            status: Status::Suspended,
+            created_at: now,
+            updated_at: now,
+            last_suspended_at: None,
+            session_params: plan.session_parameters,
+        };
+        user_api
+            .add_task(&plan.tenant, task, plan.if_not_exists)
+            .await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
+
 #[async_trait::async_trait]
 impl Interpreter for CreateTaskInterpreter {
     fn name(&self) -> &str {
@@ -79,9 +128,7 @@ impl Interpreter for CreateTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot create task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            return self.create_local_task().await;
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
@@ -18,9 +18,9 @@ use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::DropTaskRequest;
 use databend_common_cloud_control::task_client::make_request;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::DropTaskPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_client_config;
 use crate::interpreters::Interpreter;
@@ -50,6 +50,16 @@ impl DropTaskInterpreter {
     }
 }
 
+impl DropTaskInterpreter {
+    // Drops the task stored in the meta service.
+    async fn drop_local_task(&self) -> Result<PipelineBuildResult> {
+        UserApiProvider::instance()
+            .drop_task(&self.plan.tenant, &self.plan.task_name, self.plan.if_exists)
+            .await?;
+        Ok(PipelineBuildResult::create())
+    }
+}
+
 #[async_trait::async_trait]
 impl Interpreter for DropTaskInterpreter {
     fn name(&self) -> &str {
@@ -61,9 +71,7 @@ impl Interpreter for DropTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot drop task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            return self.drop_local_task().await;
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
@@ -18,14 +18,15 @@ use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::ExecuteTaskRequest;
 use databend_common_cloud_control::task_client::make_request;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::ExecuteTaskPlan;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_client_config;
 use crate::interpreters::Interpreter;
 use crate::pipelines::PipelineBuildResult;
 use crate::sessions::QueryContext;
+use crate::tasks::TaskScheduler;
 
 #[derive(Debug)]
 pub struct ExecuteTaskInterpreter {
@@ -39,6 +40,19 @@ impl ExecuteTaskInterpreter {
     }
 }
 
+impl ExecuteTaskInterpreter {
+    // Runs the task graph starting from the task on this node, the statement returns
+    // before the task finished.
+    async fn execute_local_task(&self) -> Result<PipelineBuildResult> {
+        let task = UserApiProvider::instance()
+            .get_task(&self.plan.tenant, &self.plan.task_name)
+            .await?
+            .data;
+        TaskScheduler::instance().execute_task(task);
+        Ok(PipelineBuildResult::create())
+    }
+}
+
 #[async_trait::async_trait]
 impl Interpreter for ExecuteTaskInterpreter {
     fn name(&self) -> &str {
@@ -50,9 +64,7 @@ impl Interpreter for ExecuteTaskInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot execute task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            return self.execute_local_task().await;
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
@@ -18,10 +18,10 @@ use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::ShowTasksRequest;
 use databend_common_cloud_control::task_client::make_request;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_sql::plans::ShowTasksPlan;
 use databend_common_storages_system::parse_tasks_to_datablock;
+use databend_common_users::UserApiProvider;
 
 use crate::interpreters::common::get_client_config;
 use crate::interpreters::Interpreter;
@@ -62,6 +62,27 @@ impl ShowTasksInterpreter {
     }
 }
 
+impl ShowTasksInterpreter {
+    // Shows the tasks stored in the meta service, which are owned by the available roles.
+    async fn show_local_tasks(&self) -> Result<PipelineBuildResult> {
+        let available_roles = self
+            .ctx
+            .get_current_session()
+            .get_all_available_roles()
+            .await?;
+        let tasks = UserApiProvider::instance()
+            .get_tasks(&self.plan.tenant)
+            .await?
+            .into_iter()
+            .filter(|t| available_roles.iter().any(|r| r.identity() == t.owner))
+            .map(|t| t.into())
+            .collect();
+
+        let result = parse_tasks_to_datablock(tasks)?;
+        PipelineBuildResult::from_blocks(vec![result])
+    }
+}
+
 #[async_trait::async_trait]
 impl Interpreter for ShowTasksInterpreter {
     fn name(&self) -> &str {
@@ -73,9 +94,7 @@ impl Interpreter for ShowTasksInterpreter {
     async fn execute2(&self) -> Result<PipelineBuildResult> {
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot drop task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            return self.show_local_tasks().await;
         }
         let cloud_api = CloudControlApiProvider::instance();
         let task_client = cloud_api.get_task_client();
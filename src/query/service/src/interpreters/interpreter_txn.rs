@@ -0,0 +1,175 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_catalog::table::TableExt;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::UpdateMultiTableMetaReq;
+use databend_common_storages_fuse::FuseTable;
+use log::info;
+use log::warn;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::SessionType;
+
+pub struct BeginInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl BeginInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(BeginInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for BeginInterpreter {
+    fn name(&self) -> &str {
+        "BeginInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // The session of the http handlers does not outlive the request,
+        // so there is nowhere to keep the transaction.
+        let session_type = self.ctx.get_current_session().get_type();
+        if matches!(
+            session_type,
+            SessionType::HTTPQuery
+                | SessionType::HTTPStreamingLoad
+                | SessionType::ClickHouseHttpHandler
+                | SessionType::HTTPAPI(_)
+        ) {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Explicit transaction is not supported by the session of type {}",
+                session_type
+            )));
+        }
+
+        // BEGIN inside a transaction is a no-op, like postgres does.
+        self.ctx.txn_mgr().lock().begin();
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+pub struct CommitInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl CommitInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(CommitInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for CommitInterpreter {
+    fn name(&self) -> &str {
+        "CommitInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // The transaction ends whether the commit succeeds or not,
+        // COMMIT of a failed transaction rolls it back.
+        let (is_active, txn_id, reqs, table_infos) = {
+            let txn_mgr = self.ctx.txn_mgr();
+            let mut guard = txn_mgr.lock();
+            let txn = (
+                guard.is_active(),
+                guard.txn_id().to_string(),
+                guard.reqs(),
+                guard.table_infos(),
+            );
+            guard.clear();
+            txn
+        };
+        if is_active {
+            info!("committing transaction {}", txn_id);
+            self.commit(reqs).await?;
+            self.post_commit(table_infos).await;
+        }
+        Ok(PipelineBuildResult::create())
+    }
+}
+
+impl CommitInterpreter {
+    #[async_backtrace::framed]
+    async fn commit(&self, reqs: BTreeMap<String, UpdateMultiTableMetaReq>) -> Result<()> {
+        // The tables of a catalog are committed atomically, together with the
+        // removal of the copied files of the truncated tables. Fuse tables only
+        // live in the default catalog in practice.
+        for (catalog, req) in reqs {
+            let catalog = self.ctx.get_catalog(&catalog).await?;
+            catalog.update_multi_table_meta(req).await?;
+        }
+        Ok(())
+    }
+
+    /// The work left by the statements of the transaction for after the commit,
+    /// it is best effort since the transaction is already committed.
+    #[async_backtrace::framed]
+    async fn post_commit(&self, table_infos: Vec<TableInfo>) {
+        let ctx: Arc<dyn TableContext> = self.ctx.clone();
+        for table_info in table_infos {
+            let ret = async {
+                let catalog = ctx.get_catalog(table_info.catalog()).await?;
+                let table = catalog.get_table_by_info(&table_info)?;
+                let latest = table.refresh(ctx.as_ref()).await?;
+                if let Ok(fuse_table) = FuseTable::try_from_table(latest.as_ref()) {
+                    fuse_table.post_txn_commit(&ctx).await?;
+                }
+                Ok::<_, ErrorCode>(())
+            }
+            .await;
+            if let Err(e) = ret {
+                warn!(
+                    "post commit of table {} failed, the error: {}",
+                    table_info.desc, e
+                );
+            }
+        }
+    }
+}
+
+pub struct AbortInterpreter {
+    ctx: Arc<QueryContext>,
+}
+
+impl AbortInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>) -> Result<Self> {
+        Ok(AbortInterpreter { ctx })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AbortInterpreter {
+    fn name(&self) -> &str {
+        "AbortInterpreter"
+    }
+
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        // The uncommitted snapshots are left for `VACUUM` to purge.
+        self.ctx.txn_mgr().lock().clear();
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -145,6 +145,7 @@ impl UpdateInterpreter {
         let tbl = tbl.refresh(self.ctx.as_ref()).await?;
         // check mutability
         tbl.check_mutable()?;
+        tbl.check_not_materialized_view()?;
 
         let selection = if !self.plan.subquery_desc.is_empty() {
             let support_row_id = tbl.supported_internal_column(ROW_ID_COLUMN_ID);
@@ -0,0 +1,98 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_ast::ast::AlterWorkloadGroupAction;
+use databend_common_exception::Result;
+use databend_common_sql::plans::AlterWorkloadGroupPlan;
+use databend_common_users::UserApiProvider;
+use log::debug;
+
+use crate::interpreters::Interpreter;
+use crate::pipelines::PipelineBuildResult;
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+use crate::workload_groups::WorkloadGroupManager;
+
+#[derive(Debug)]
+pub struct AlterWorkloadGroupInterpreter {
+    ctx: Arc<QueryContext>,
+    plan: AlterWorkloadGroupPlan,
+}
+
+impl AlterWorkloadGroupInterpreter {
+    pub fn try_create(ctx: Arc<QueryContext>, plan: AlterWorkloadGroupPlan) -> Result<Self> {
+        Ok(AlterWorkloadGroupInterpreter { ctx, plan })
+    }
+}
+
+#[async_trait::async_trait]
+impl Interpreter for AlterWorkloadGroupInterpreter {
+    fn name(&self) -> &str {
+        "AlterWorkloadGroupInterpreter"
+    }
+
+    #[minitrace::trace]
+    #[async_backtrace::framed]
+    async fn execute2(&self) -> Result<PipelineBuildResult> {
+        debug!("ctx.id" = self.ctx.get_id().as_str(); "alter_workload_group_execute");
+
+        let plan = self.plan.clone();
+        let tenant = self.ctx.get_tenant();
+        let user_mgr = UserApiProvider::instance();
+
+        match plan.action {
+            AlterWorkloadGroupAction::SetOptions(set_options) => {
+                user_mgr
+                    .update_workload_group(
+                        &tenant,
+                        &plan.name,
+                        set_options.max_concurrency,
+                        set_options.max_queued,
+                        set_options.queue_timeout_secs,
+                        set_options.memory_percentage,
+                        set_options.cpu_percentage,
+                        set_options.users,
+                        set_options.roles,
+                        set_options.comment,
+                        plan.if_exists,
+                    )
+                    .await?;
+            }
+            AlterWorkloadGroupAction::UnSetOptions(unset_options) => {
+                // convert unset options to default values, which mean unlimited
+                let unset_u64 = |unset: bool| if unset { Some(0) } else { None };
+                let unset_list = |unset: bool| if unset { Some(vec![]) } else { None };
+                user_mgr
+                    .update_workload_group(
+                        &tenant,
+                        &plan.name,
+                        unset_u64(unset_options.max_concurrency),
+                        unset_u64(unset_options.max_queued),
+                        unset_u64(unset_options.queue_timeout_secs),
+                        unset_u64(unset_options.memory_percentage),
+                        unset_u64(unset_options.cpu_percentage),
+                        unset_list(unset_options.users),
+                        unset_list(unset_options.roles),
+                        unset_options.comment.then(String::new),
+                        plan.if_exists,
+                    )
+                    .await?;
+            }
+        }
+        WorkloadGroupManager::instance().invalidate(&tenant);
+
+        Ok(PipelineBuildResult::create())
+    }
+}
@@ -52,13 +52,16 @@ pub mod local;
 pub mod locks;
 pub mod metrics;
 pub mod pipelines;
+pub mod pipes;
 pub mod schedulers;
 pub mod servers;
 pub mod sessions;
 pub mod spillers;
 pub mod stream;
 pub mod table_functions;
+pub mod tasks;
 pub mod test_kits;
+pub mod workload_groups;
 
 mod global_services;
 
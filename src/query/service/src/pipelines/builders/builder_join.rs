@@ -18,6 +18,7 @@ use databend_common_base::base::tokio::sync::Barrier;
 use databend_common_exception::Result;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_sinks::Sinker;
+use databend_common_sql::executor::physical_plans::AsofJoin;
 use databend_common_sql::executor::physical_plans::HashJoin;
 use databend_common_sql::executor::physical_plans::MaterializedCte;
 use databend_common_sql::executor::physical_plans::RangeJoin;
@@ -25,6 +26,9 @@ use databend_common_sql::executor::PhysicalPlan;
 use databend_common_sql::ColumnBinding;
 use databend_common_sql::IndexType;
 
+use crate::pipelines::processors::transforms::asof_join::AsofJoinState;
+use crate::pipelines::processors::transforms::asof_join::TransformAsofJoinLeft;
+use crate::pipelines::processors::transforms::asof_join::TransformAsofJoinRight;
 use crate::pipelines::processors::transforms::range_join::RangeJoinState;
 use crate::pipelines::processors::transforms::range_join::TransformRangeJoinLeft;
 use crate::pipelines::processors::transforms::range_join::TransformRangeJoinRight;
@@ -95,6 +99,40 @@ impl PipelineBuilder {
         Ok(())
     }
 
+    pub(crate) fn build_asof_join(&mut self, asof_join: &AsofJoin) -> Result<()> {
+        let state = Arc::new(AsofJoinState::try_create(self.func_ctx.clone(), asof_join)?);
+
+        // The right side is collected as a whole before probing.
+        let right_side_context = QueryContext::create_from(self.ctx.clone());
+        let mut right_side_builder = PipelineBuilder::create(
+            self.func_ctx.clone(),
+            self.settings.clone(),
+            right_side_context,
+            self.main_pipeline.get_scopes(),
+        );
+        right_side_builder.cte_state = self.cte_state.clone();
+        let mut right_res = right_side_builder.finalize(&asof_join.right)?;
+        right_res.main_pipeline.add_sink(|input| {
+            Ok(ProcessorPtr::create(
+                Sinker::<TransformAsofJoinRight>::create(
+                    input,
+                    TransformAsofJoinRight::create(state.clone()),
+                ),
+            ))
+        })?;
+        self.pipelines.push(right_res.main_pipeline.finalize());
+        self.pipelines.extend(right_res.sources_pipelines);
+
+        self.build_pipeline(&asof_join.left)?;
+        self.main_pipeline.add_transform(|input, output| {
+            Ok(ProcessorPtr::create(TransformAsofJoinLeft::create(
+                input,
+                output,
+                state.clone(),
+            )))
+        })
+    }
+
     pub(crate) fn build_join(&mut self, join: &HashJoin) -> Result<()> {
         // for merge into target table as build side.
         let (merge_into_build_table_index, merge_into_is_distributed) =
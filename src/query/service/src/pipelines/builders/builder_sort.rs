@@ -87,7 +87,13 @@ impl PipelineBuilder {
             })
             .collect::<Result<Vec<_>>>()?;
 
-        self.build_sort_pipeline(plan_schema, sort_desc, sort.limit, sort.after_exchange)
+        self.build_sort_pipeline(
+            plan_schema,
+            sort_desc,
+            sort.limit,
+            sort.after_exchange,
+            None,
+        )
     }
 
     pub(crate) fn build_sort_pipeline(
@@ -96,6 +102,7 @@ impl PipelineBuilder {
         sort_desc: Vec<SortColumnDescription>,
         limit: Option<usize>,
         after_exchange: Option<bool>,
+        spilling_memory_usage: Option<usize>,
     ) -> Result<()> {
         let block_size = self.settings.get_max_block_size()? as usize;
         let max_threads = self.settings.get_max_threads()? as usize;
@@ -110,7 +117,8 @@ impl PipelineBuilder {
             SortPipelineBuilder::create(self.ctx.clone(), plan_schema.clone(), sort_desc.clone())
                 .with_partial_block_size(block_size)
                 .with_final_block_size(block_size)
-                .with_limit(limit);
+                .with_limit(limit)
+                .with_spilling_memory_usage(spilling_memory_usage);
 
         match after_exchange {
             Some(true) => {
@@ -156,6 +164,7 @@ pub struct SortPipelineBuilder {
     partial_block_size: usize,
     final_block_size: usize,
     remove_order_col_at_last: bool,
+    spilling_memory_usage: Option<usize>,
 }
 
 impl SortPipelineBuilder {
@@ -172,6 +181,7 @@ impl SortPipelineBuilder {
             partial_block_size: 0,
             final_block_size: 0,
             remove_order_col_at_last: false,
+            spilling_memory_usage: None,
         }
     }
 
@@ -190,6 +200,12 @@ impl SortPipelineBuilder {
         self
     }
 
+    /// Spill the sort with the memory limit instead of the sort spilling settings.
+    pub fn with_spilling_memory_usage(mut self, spilling_memory_usage: Option<usize>) -> Self {
+        self.spilling_memory_usage = spilling_memory_usage;
+        self
+    }
+
     pub fn remove_order_col_at_last(mut self) -> Self {
         self.remove_order_col_at_last = true;
         self
@@ -210,6 +226,10 @@ impl SortPipelineBuilder {
     }
 
     fn get_memory_settings(&self, num_threads: usize) -> Result<(usize, usize)> {
+        if let Some(max_memory_usage) = self.spilling_memory_usage {
+            return Ok((max_memory_usage, max_memory_usage / num_threads));
+        }
+
         let settings = self.ctx.get_settings();
         let memory_ratio = settings.get_sort_spilling_memory_ratio()?;
         let bytes_limit_per_proc = settings.get_sort_spilling_bytes_threshold_per_proc()?;
@@ -23,10 +23,12 @@ impl PipelineBuilder {
     pub(crate) fn build_udf(&mut self, udf: &Udf) -> Result<()> {
         self.build_pipeline(&udf.input)?;
 
+        let wasm_runtimes = TransformUdf::compile_wasm_runtimes(&udf.udf_funcs)?;
         self.main_pipeline.add_transform(|input, output| {
             Ok(ProcessorPtr::create(TransformUdf::try_create(
                 self.func_ctx.clone(),
                 udf.udf_funcs.clone(),
+                wasm_runtimes.clone(),
                 input,
                 output,
             )?))
@@ -19,12 +19,22 @@ use databend_common_expression::with_number_mapped_type;
 use databend_common_expression::SortColumnDescription;
 use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::query_spill_prefix;
+use databend_common_pipeline_transforms::processors::Transformer;
 use databend_common_sql::executor::physical_plans::Window;
+use databend_common_storage::DataOperator;
+use databend_common_storages_fuse::TableContext;
 
 use crate::pipelines::processors::transforms::FrameBound;
+use crate::pipelines::processors::transforms::TransformWindowPartitionCollect;
+use crate::pipelines::processors::transforms::TransformWindowPartitionScatter;
+use crate::pipelines::processors::transforms::TransformWindowPartitionShuffle;
 use crate::pipelines::processors::transforms::WindowFunctionInfo;
 use crate::pipelines::processors::TransformWindow;
 use crate::pipelines::PipelineBuilder;
+use crate::spillers::Spiller;
+use crate::spillers::SpillerConfig;
+use crate::spillers::SpillerType;
 
 impl PipelineBuilder {
     pub(crate) fn build_window(&mut self, window: &Window) -> Result<()> {
@@ -56,24 +66,75 @@ impl PipelineBuilder {
             .collect::<Result<Vec<_>>>()?;
 
         let old_output_len = self.main_pipeline.output_len();
-        if !partition_by.is_empty() || !order_by.is_empty() {
-            let mut sort_desc = Vec::with_capacity(partition_by.len() + order_by.len());
+        let mut sort_desc = Vec::with_capacity(partition_by.len() + order_by.len());
+        for offset in &partition_by {
+            sort_desc.push(SortColumnDescription {
+                offset: *offset,
+                asc: true,
+                nulls_first: true,
+                is_nullable: input_schema.field(*offset).is_nullable(),  // This information is not needed here.
+            })
+        }
+        sort_desc.extend(order_by.clone());
 
-            for offset in &partition_by {
-                sort_desc.push(SortColumnDescription {
-                    offset: *offset,
-                    asc: true,
-                    nulls_first: true,
-                    is_nullable: input_schema.field(*offset).is_nullable(),  // This information is not needed here.
-                })
+        let spilling_memory_usage = self.get_window_spilling_memory_usage()?;
+        match spilling_memory_usage {
+            Some(max_memory_usage) if !partition_by.is_empty() => {
+                // Collect the rows by the window partitions, which can be spilled to the storage,
+                // instead of sorting all the rows in memory. The rows are scattered to the
+                // processors by the window partitions, so the window can be evaluated in parallel.
+                let num_partitions = self.settings.get_max_threads()? as usize;
+                let max_block_size = self.settings.get_max_block_size()? as usize;
+                let config = SpillerConfig::create(query_spill_prefix(&self.ctx.get_tenant()));
+                let query_memory = self.ctx.get_query_memory_tracker();
+                self.main_pipeline.try_resize(num_partitions)?;
+                self.main_pipeline.add_transform(|input, output| {
+                    Ok(ProcessorPtr::create(Transformer::create(
+                        input,
+                        output,
+                        TransformWindowPartitionScatter::create(
+                            partition_by.clone(),
+                            num_partitions,
+                        ),
+                    )))
+                })?;
+                self.main_pipeline
+                    .add_pipe(TransformWindowPartitionShuffle::create(num_partitions).into_pipe());
+                self.main_pipeline.add_transform(|input, output| {
+                    let op = DataOperator::instance().operator();
+                    let spiller =
+                        Spiller::create(self.ctx.clone(), op, config.clone(), SpillerType::Window);
+                    Ok(ProcessorPtr::create(
+                        TransformWindowPartitionCollect::create(
+                            input,
+                            output,
+                            partition_by.clone(),
+                            sort_desc.clone(),
+                            max_block_size,
+                            max_memory_usage / num_partitions,
+                            max_memory_usage,
+                            query_memory.clone(),
+                            spiller,
+                        ),
+                    ))
+                })?;
+            }
+            _ => {
+                if !sort_desc.is_empty() {
+                    // Without PARTITION BY, all the rows are in one window partition,
+                    // so the sort spills with the window spilling memory limit.
+                    self.build_sort_pipeline(
+                        input_schema.clone(),
+                        sort_desc,
+                        window.limit,
+                        None,
+                        spilling_memory_usage,
+                    )?;
+                }
+                // `TransformWindow` is a pipeline breaker.
+                self.main_pipeline.try_resize(1)?;
             }
-
-            sort_desc.extend(order_by.clone());
-
-            self.build_sort_pipeline(input_schema.clone(), sort_desc, window.limit, None)?;
         }
-        // `TransformWindow` is a pipeline breaker.
-        self.main_pipeline.try_resize(1)?;
         let func = WindowFunctionInfo::try_create(&window.func, &input_schema)?;
         // Window
         self.main_pipeline.add_transform(|input, output| {
@@ -136,4 +197,15 @@ impl PipelineBuilder {
 
         self.main_pipeline.try_resize(old_output_len)
     }
+
+    /// The memory limit of the window partitions before spilling, `None` if spilling is disabled.
+    fn get_window_spilling_memory_usage(&self) -> Result<Option<usize>> {
+        let memory_ratio = self.settings.get_window_spilling_memory_ratio()?;
+        let max_memory_usage = self.settings.get_max_memory_usage()?;
+        if memory_ratio == 0 || max_memory_usage == 0 {
+            return Ok(None);
+        }
+        let memory_ratio = (memory_ratio as f64 / 100_f64).min(1_f64);
+        Ok(Some((max_memory_usage as f64 * memory_ratio) as usize))
+    }
 }
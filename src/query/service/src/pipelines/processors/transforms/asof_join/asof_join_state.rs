@@ -0,0 +1,366 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::binary::BinaryColumn;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::Column;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
+use databend_common_expression::RemoteExpr;
+use databend_common_expression::RowConverter;
+use databend_common_expression::Scalar;
+use databend_common_expression::SortField;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_sql::executor::physical_plans::AsofJoin;
+use databend_common_sql::plans::JoinType;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+
+use crate::pipelines::executor::WatchNotify;
+
+// The operator of the match condition, from the perspective of the left side.
+#[derive(Clone, Copy, Debug)]
+enum MatchOperator {
+    Gt,
+    Gte,
+    Lt,
+    Lte,
+}
+
+// The rows of the right side, grouped by the equi-keys.
+struct AsofJoinTable {
+    block: DataBlock,
+    // The memcmp-comparable values of the match condition.
+    match_rows: Option<BinaryColumn>,
+    // Row indices of each group, sorted by the values of the match condition.
+    groups: HashMap<Vec<u8>, Vec<u32>>,
+}
+
+impl AsofJoinTable {
+    fn empty() -> Self {
+        Self {
+            block: DataBlock::empty(),
+            match_rows: None,
+            groups: HashMap::new(),
+        }
+    }
+}
+
+pub struct AsofJoinState {
+    func_ctx: FunctionContext,
+    join_type: JoinType,
+    left_keys: Vec<Expr>,
+    right_keys: Vec<Expr>,
+    left_match_expr: Expr,
+    right_match_expr: Expr,
+    operator: MatchOperator,
+    right_schema: DataSchemaRef,
+
+    right_blocks: Mutex<Vec<DataBlock>>,
+    table: RwLock<AsofJoinTable>,
+    // Pipeline event related
+    right_sinker_count: Mutex<usize>,
+    build_finished: Mutex<bool>,
+    finished_notify: Arc<WatchNotify>,
+}
+
+impl AsofJoinState {
+    pub fn try_create(func_ctx: FunctionContext, asof_join: &AsofJoin) -> Result<Self> {
+        let operator = match asof_join.match_condition.operator.as_str() {
+            "gt" => MatchOperator::Gt,
+            "gte" => MatchOperator::Gte,
+            "lt" => MatchOperator::Lt,
+            "lte" => MatchOperator::Lte,
+            op => {
+                return Err(ErrorCode::Internal(format!(
+                    "Invalid operator of asof join match condition: {op}"
+                )));
+            }
+        };
+        let as_exprs = |keys: &[RemoteExpr]| {
+            keys.iter()
+                .map(|key| key.as_expr(&BUILTIN_FUNCTIONS))
+                .collect::<Vec<_>>()
+        };
+
+        Ok(Self {
+            func_ctx,
+            join_type: asof_join.join_type.clone(),
+            left_keys: as_exprs(&asof_join.left_keys),
+            right_keys: as_exprs(&asof_join.right_keys),
+            left_match_expr: asof_join
+                .match_condition
+                .left_expr
+                .as_expr(&BUILTIN_FUNCTIONS),
+            right_match_expr: asof_join
+                .match_condition
+                .right_expr
+                .as_expr(&BUILTIN_FUNCTIONS),
+            operator,
+            right_schema: asof_join.right.output_schema()?,
+            right_blocks: Mutex::new(vec![]),
+            table: RwLock::new(AsofJoinTable::empty()),
+            right_sinker_count: Mutex::new(0),
+            build_finished: Mutex::new(false),
+            finished_notify: Arc::new(WatchNotify::new()),
+        })
+    }
+
+    pub(crate) fn sink_right(&self, block: DataBlock) -> Result<()> {
+        if !block.is_empty() {
+            self.right_blocks.lock().push(block);
+        }
+        Ok(())
+    }
+
+    pub(crate) fn right_attach(&self) {
+        *self.right_sinker_count.lock() += 1;
+    }
+
+    pub(crate) fn right_detach(&self) -> Result<()> {
+        let mut right_sinker_count = self.right_sinker_count.lock();
+        *right_sinker_count -= 1;
+        if *right_sinker_count == 0 {
+            // All the rows of the right side are collected, build the table.
+            let blocks = std::mem::take(&mut *self.right_blocks.lock());
+            *self.table.write() = self.build_table(blocks)?;
+            *self.build_finished.lock() = true;
+            self.finished_notify.notify_waiters();
+        }
+        Ok(())
+    }
+
+    pub(crate) async fn wait_build_finish(&self) -> Result<()> {
+        let notified = {
+            let build_finished = self.build_finished.lock();
+
+            match *build_finished {
+                true => None,
+                false => Some(self.finished_notify.notified()),
+            }
+        };
+
+        if let Some(notified) = notified {
+            notified.await;
+        }
+        Ok(())
+    }
+
+    fn build_table(&self, blocks: Vec<DataBlock>) -> Result<AsofJoinTable> {
+        if blocks.is_empty() {
+            return Ok(AsofJoinTable::empty());
+        }
+        let block = DataBlock::concat(&blocks)?;
+        let num_rows = block.num_rows();
+
+        let (key_rows, match_rows, valid) =
+            self.convert_rows(&block, &self.right_keys, &self.right_match_expr)?;
+        let mut groups: HashMap<Vec<u8>, Vec<u32>> = HashMap::new();
+        for row in 0..num_rows {
+            if !valid.get_bit(row) {
+                continue;
+            }
+            let key = key_rows
+                .as_ref()
+                .map_or(&[][..], |keys| keys.index(row).unwrap());
+            match groups.get_mut(key) {
+                Some(rows) => rows.push(row as u32),
+                None => {
+                    groups.insert(key.to_vec(), vec![row as u32]);
+                }
+            }
+        }
+        for rows in groups.values_mut() {
+            rows.sort_by(|a, b| {
+                let a = match_rows.index(*a as usize).unwrap();
+                let b = match_rows.index(*b as usize).unwrap();
+                a.cmp(b)
+            });
+        }
+
+        Ok(AsofJoinTable {
+            block,
+            match_rows: Some(match_rows),
+            groups,
+        })
+    }
+
+    pub(crate) fn probe(&self, mut block: DataBlock) -> Result<DataBlock> {
+        let num_rows = block.num_rows();
+        let table = self.table.read();
+
+        let mut probe_indices = Vec::with_capacity(num_rows);
+        let mut build_indices = Vec::with_capacity(num_rows);
+        let mut matched = MutableBitmap::with_capacity(num_rows);
+        if let Some(build_match_rows) = table.match_rows.as_ref() {
+            let (key_rows, match_rows, valid) =
+                self.convert_rows(&block, &self.left_keys, &self.left_match_expr)?;
+            for row in 0..num_rows {
+                let key = key_rows
+                    .as_ref()
+                    .map_or(&[][..], |keys| keys.index(row).unwrap());
+                let build_row = match table.groups.get(key) {
+                    Some(rows) if valid.get_bit(row) => {
+                        let value = match_rows.index(row).unwrap();
+                        self.find_closest(rows, build_match_rows, value)
+                    }
+                    _ => None,
+                };
+                match build_row {
+                    Some(build_row) => {
+                        probe_indices.push(row as u32);
+                        build_indices.push(build_row);
+                        matched.push(true);
+                    }
+                    None if self.join_type == JoinType::LeftAsof => {
+                        probe_indices.push(row as u32);
+                        build_indices.push(0);
+                        matched.push(false);
+                    }
+                    None => (),
+                }
+            }
+        }
+
+        match self.join_type {
+            JoinType::LeftAsof => {
+                let build_block = if table.block.is_empty() {
+                    let columns = self
+                        .right_schema
+                        .fields()
+                        .iter()
+                        .map(|field| {
+                            BlockEntry::new(
+                                field.data_type().wrap_nullable(),
+                                Value::Scalar(Scalar::Null),
+                            )
+                        })
+                        .collect();
+                    DataBlock::new(columns, num_rows)
+                } else {
+                    let validity: Bitmap = matched.into();
+                    let build_block = table.block.take(&build_indices, &mut None)?;
+                    let columns = build_block
+                        .columns()
+                        .iter()
+                        .map(|entry| {
+                            let column = entry
+                                .value
+                                .convert_to_full_column(&entry.data_type, num_rows)
+                                .wrap_nullable(Some(validity.clone()));
+                            BlockEntry::new(entry.data_type.wrap_nullable(), Value::Column(column))
+                        })
+                        .collect();
+                    DataBlock::new(columns, num_rows)
+                };
+                block.merge_block(build_block);
+                Ok(block)
+            }
+            _ => {
+                if probe_indices.is_empty() {
+                    return Ok(DataBlock::empty());
+                }
+                let mut probe_block = block.take(&probe_indices, &mut None)?;
+                let build_block = table.block.take(&build_indices, &mut None)?;
+                probe_block.merge_block(build_block);
+                Ok(probe_block)
+            }
+        }
+    }
+
+    // Finds the closest row of a group satisfying the match condition,
+    // `rows` is sorted by the values of the match condition.
+    fn find_closest(&self, rows: &[u32], match_rows: &BinaryColumn, value: &[u8]) -> Option<u32> {
+        let row_value = |row: &u32| match_rows.index(*row as usize).unwrap();
+        match self.operator {
+            // The greatest row less than (or equal to) the value.
+            MatchOperator::Gt | MatchOperator::Gte => {
+                let idx = rows.partition_point(|row| match self.operator {
+                    MatchOperator::Gt => row_value(row) < value,
+                    _ => row_value(row) <= value,
+                });
+                (idx > 0).then(|| rows[idx - 1])
+            }
+            // The least row greater than (or equal to) the value.
+            MatchOperator::Lt | MatchOperator::Lte => {
+                let idx = rows.partition_point(|row| match self.operator {
+                    MatchOperator::Lt => row_value(row) <= value,
+                    _ => row_value(row) < value,
+                });
+                rows.get(idx).copied()
+            }
+        }
+    }
+
+    // Converts the equi-keys and the match condition values to memcmp-comparable rows,
+    // the rows having null keys or null match condition values never match.
+    fn convert_rows(
+        &self,
+        block: &DataBlock,
+        keys: &[Expr],
+        match_expr: &Expr,
+    ) -> Result<(Option<BinaryColumn>, BinaryColumn, Bitmap)> {
+        let num_rows = block.num_rows();
+        let evaluator = Evaluator::new(block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        let eval = |expr: &Expr| -> Result<Column> {
+            Ok(evaluator
+                .run(expr)?
+                .convert_to_full_column(expr.data_type(), num_rows))
+        };
+
+        let mut valid = MutableBitmap::from_len_set(num_rows);
+        let mut and_validity = |column: &Column| match column.validity() {
+            (true, _) => valid = MutableBitmap::from_len_zeroed(num_rows),
+            (false, Some(bitmap)) => {
+                for (row, is_valid) in bitmap.iter().enumerate() {
+                    if !is_valid {
+                        valid.set(row, false);
+                    }
+                }
+            }
+            (false, None) => (),
+        };
+
+        let key_rows = if keys.is_empty() {
+            None
+        } else {
+            let columns = keys.iter().map(eval).collect::<Result<Vec<_>>>()?;
+            columns.iter().for_each(&mut and_validity);
+            let converter = RowConverter::new(
+                keys.iter()
+                    .map(|key| SortField::new(key.data_type().clone()))
+                    .collect(),
+            )?;
+            Some(converter.convert_columns(&columns, num_rows))
+        };
+
+        let match_column = eval(match_expr)?;
+        and_validity(&match_column);
+        let converter = RowConverter::new(vec![SortField::new(match_expr.data_type().clone())])?;
+        let match_rows = converter.convert_columns(&[match_column], num_rows);
+
+        Ok((key_rows, match_rows, valid.into()))
+    }
+}
@@ -0,0 +1,150 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_sinks::Sink;
+
+use crate::pipelines::processors::transforms::asof_join::AsofJoinState;
+
+enum AsofJoinStep {
+    // Wait for the right side to be built
+    WaitBuild,
+    Probe,
+}
+
+pub struct TransformAsofJoinLeft {
+    input_port: Arc<InputPort>,
+    output_port: Arc<OutputPort>,
+    input_data: Option<DataBlock>,
+    output_data: Option<DataBlock>,
+    state: Arc<AsofJoinState>,
+    step: AsofJoinStep,
+}
+
+impl TransformAsofJoinLeft {
+    pub fn create(
+        input_port: Arc<InputPort>,
+        output_port: Arc<OutputPort>,
+        state: Arc<AsofJoinState>,
+    ) -> Box<dyn Processor> {
+        Box::new(TransformAsofJoinLeft {
+            input_port,
+            output_port,
+            input_data: None,
+            output_data: None,
+            state,
+            step: AsofJoinStep::WaitBuild,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformAsofJoinLeft {
+    fn name(&self) -> String {
+        "TransformAsofJoinLeft".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        match self.step {
+            AsofJoinStep::WaitBuild => Ok(Event::Async),
+            AsofJoinStep::Probe => {
+                if self.output_port.is_finished() {
+                    self.input_port.finish();
+                    return Ok(Event::Finished);
+                }
+
+                if !self.output_port.can_push() {
+                    self.input_port.set_not_need_data();
+                    return Ok(Event::NeedConsume);
+                }
+
+                if let Some(data) = self.output_data.take() {
+                    self.output_port.push_data(Ok(data));
+                    return Ok(Event::NeedConsume);
+                }
+
+                if self.input_data.is_some() {
+                    return Ok(Event::Sync);
+                }
+
+                if self.input_port.has_data() {
+                    self.input_data = Some(self.input_port.pull_data().unwrap()?);
+                    return Ok(Event::Sync);
+                }
+
+                if self.input_port.is_finished() {
+                    self.output_port.finish();
+                    return Ok(Event::Finished);
+                }
+
+                self.input_port.set_need_data();
+                Ok(Event::NeedData)
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(data_block) = self.input_data.take() {
+            let data_block = self.state.probe(data_block)?;
+            if !data_block.is_empty() {
+                self.output_data = Some(data_block);
+            }
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        if let AsofJoinStep::WaitBuild = self.step {
+            self.state.wait_build_finish().await?;
+            self.step = AsofJoinStep::Probe;
+        }
+        Ok(())
+    }
+}
+
+pub struct TransformAsofJoinRight {
+    state: Arc<AsofJoinState>,
+}
+
+impl TransformAsofJoinRight {
+    pub fn create(state: Arc<AsofJoinState>) -> Self {
+        state.right_attach();
+        TransformAsofJoinRight { state }
+    }
+}
+
+impl Sink for TransformAsofJoinRight {
+    const NAME: &'static str = "TransformAsofJoinRight";
+
+    fn on_finish(&mut self) -> Result<()> {
+        self.state.right_detach()
+    }
+
+    fn consume(&mut self, data_block: DataBlock) -> Result<()> {
+        self.state.sink_right(data_block)
+    }
+}
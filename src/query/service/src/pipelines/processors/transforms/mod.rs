@@ -13,6 +13,7 @@
 // limitations under the License.
 
 pub mod aggregator;
+pub(crate) mod asof_join;
 pub mod group_by;
 mod hash_join;
 mod processor_accumulate_row_number;
@@ -61,4 +62,8 @@ pub use transform_srf::TransformSRF;
 pub use transform_udf::TransformUdf;
 pub use window::FrameBound;
 pub use window::TransformWindow;
+pub use window::TransformWindowPartitionCollect;
+pub use window::TransformWindowPartitionScatter;
+pub use window::TransformWindowPartitionShuffle;
 pub use window::WindowFunctionInfo;
+pub use window::WindowPartitionMeta;
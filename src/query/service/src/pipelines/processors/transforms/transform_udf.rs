@@ -14,9 +14,12 @@
 
 use std::sync::Arc;
 
+use databend_common_base::runtime::GlobalIORuntime;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::udf_client::UDFFlightClient;
+use databend_common_expression::udf_wasm::WasmLimits;
+use databend_common_expression::udf_wasm::WasmRuntime;
 use databend_common_expression::variant_transform::contains_variant;
 use databend_common_expression::variant_transform::transform_variant;
 use databend_common_expression::BlockEntry;
@@ -27,6 +30,7 @@ use databend_common_expression::FunctionContext;
 use databend_common_pipeline_transforms::processors::AsyncTransform;
 use databend_common_pipeline_transforms::processors::AsyncTransformer;
 use databend_common_sql::executor::physical_plans::UdfFunctionDesc;
+use databend_common_sql::plans::UDFType;
 
 use crate::pipelines::processors::InputPort;
 use crate::pipelines::processors::OutputPort;
@@ -35,18 +39,38 @@ use crate::pipelines::processors::Processor;
 pub struct TransformUdf {
     func_ctx: FunctionContext,
     funcs: Vec<UdfFunctionDesc>,
+    // Compiled modules of the WebAssembly UDFs, indexed like `funcs`.
+    wasm_runtimes: Vec<Option<Arc<WasmRuntime>>>,
 }
 
 impl TransformUdf {
+    /// Compiles the modules of the WebAssembly UDFs once, they are shared by all the processors.
+    pub fn compile_wasm_runtimes(
+        funcs: &[UdfFunctionDesc],
+    ) -> Result<Vec<Option<Arc<WasmRuntime>>>> {
+        funcs
+            .iter()
+            .map(|func| match &func.udf_type {
+                UDFType::Wasm(code) => Ok(Some(Arc::new(WasmRuntime::try_create(
+                    code,
+                    &func.func_name,
+                )?))),
+                UDFType::Server(_) => Ok(None),
+            })
+            .collect()
+    }
+
     pub fn try_create(
         func_ctx: FunctionContext,
         funcs: Vec<UdfFunctionDesc>,
+        wasm_runtimes: Vec<Option<Arc<WasmRuntime>>>,
         input: Arc<InputPort>,
         output: Arc<OutputPort>,
     ) -> Result<Box<dyn Processor>> {
         Ok(AsyncTransformer::create(input, output, Self {
             func_ctx,
             funcs,
+            wasm_runtimes,
         }))
     }
 }
@@ -59,7 +83,11 @@ impl AsyncTransform for TransformUdf {
     async fn transform(&mut self, mut data_block: DataBlock) -> Result<DataBlock> {
         let connect_timeout = self.func_ctx.external_server_connect_timeout_secs;
         let request_timeout = self.func_ctx.external_server_request_timeout_secs;
-        for func in &self.funcs {
+        let wasm_limits = WasmLimits {
+            max_memory: self.func_ctx.wasm_udf_max_memory,
+            max_fuel: self.func_ctx.wasm_udf_max_fuel,
+        };
+        for (func, wasm_runtime) in self.funcs.iter().zip(self.wasm_runtimes.iter()) {
             // construct input record_batch
             let num_rows = data_block.num_rows();
             let block_entries = func
@@ -90,10 +118,27 @@ impl AsyncTransform for TransformUdf {
                 .to_record_batch(&data_schema)
                 .map_err(|err| ErrorCode::from_string(format!("{err}")))?;
 
-            let mut client =
-                UDFFlightClient::connect(&func.server_addr, connect_timeout, request_timeout)
-                    .await?;
-            let result_batch = client.do_exchange(&func.func_name, input_batch).await?;
+            let result_batch = match (&func.udf_type, wasm_runtime) {
+                (UDFType::Server(server_addr), _) => {
+                    let mut client =
+                        UDFFlightClient::connect(server_addr, connect_timeout, request_timeout)
+                            .await?;
+                    client.do_exchange(&func.func_name, input_batch).await?
+                }
+                (UDFType::Wasm(_), Some(runtime)) => {
+                    // The call is cpu bound (only bounded by fuel), keep it off the async workers.
+                    let runtime = runtime.clone();
+                    GlobalIORuntime::instance()
+                        .spawn_blocking(move || runtime.call(wasm_limits, input_batch))
+                        .await?
+                }
+                (UDFType::Wasm(_), None) => {
+                    return Err(ErrorCode::Internal(format!(
+                        "WebAssembly UDF {} is not compiled",
+                        func.func_name
+                    )));
+                }
+            };
 
             let schema = DataSchema::try_from(&(*result_batch.schema()))?;
             let (result_block, result_schema) =
@@ -14,8 +14,14 @@
 
 mod frame_bound;
 mod transform_window;
+mod transform_window_partition_collect;
+mod transform_window_partition_scatter;
 mod window_function;
 
 pub use frame_bound::FrameBound;
 pub use transform_window::TransformWindow;
+pub use transform_window_partition_collect::TransformWindowPartitionCollect;
+pub use transform_window_partition_scatter::TransformWindowPartitionScatter;
+pub use transform_window_partition_scatter::TransformWindowPartitionShuffle;
+pub use transform_window_partition_scatter::WindowPartitionMeta;
 pub use window_function::WindowFunctionInfo;
@@ -0,0 +1,431 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::cmp::Reverse;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Instant;
+
+use databend_common_base::runtime::MemStat;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::SortColumnDescription;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_core::processors::Profile;
+use databend_common_pipeline_core::processors::ProfileStatisticsName;
+use log::info;
+
+use super::transform_window_partition_scatter::window_partition_hashes;
+use crate::spillers::Spiller;
+
+/// The rows are hashed into `1 << BUCKETS_LG2` buckets by the partition by columns.
+const BUCKETS_LG2: u32 = 6;
+const NUM_BUCKETS: usize = 1 << BUCKETS_LG2;
+/// Each level of repartitioning takes the next `BUCKETS_LG2` low bits of the hashes, the high
+/// 32 bits are used by [`super::TransformWindowPartitionScatter`].
+const MAX_LEVEL: u32 = 32 / BUCKETS_LG2;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum State {
+    /// Collect the input blocks into the buckets.
+    Collect,
+    /// Spill the largest buckets in memory to the storage.
+    Spill,
+    /// Restore the buckets one by one, and output the sorted rows of each bucket.
+    Restore,
+    Finish,
+}
+
+/// A bucket to be restored.
+struct Partition {
+    level: u32,
+    blocks: Vec<DataBlock>,
+    spilled_locations: Vec<String>,
+    /// The memory size of the in-memory and spilled blocks.
+    memory_size: usize,
+}
+
+/// The rows hashed into the buckets of a level.
+struct Buckets {
+    level: u32,
+    blocks: Vec<Vec<DataBlock>>,
+    memory_sizes: Vec<usize>,
+    memory_size: usize,
+    spilled_locations: Vec<Vec<String>>,
+    spilled_sizes: Vec<usize>,
+}
+
+impl Buckets {
+    fn create(level: u32) -> Self {
+        Buckets {
+            level,
+            blocks: vec![vec![]; NUM_BUCKETS],
+            memory_sizes: vec![0; NUM_BUCKETS],
+            memory_size: 0,
+            spilled_locations: vec![vec![]; NUM_BUCKETS],
+            spilled_sizes: vec![0; NUM_BUCKETS],
+        }
+    }
+
+    fn add_block(&mut self, block: DataBlock, partition_indices: &[usize]) -> Result<()> {
+        if block.is_empty() {
+            return Ok(());
+        }
+
+        let shift = self.level * BUCKETS_LG2;
+        let indices = window_partition_hashes(&block, partition_indices)
+            .iter()
+            .map(|hash| ((hash >> shift) as usize & (NUM_BUCKETS - 1)) as u8)
+            .collect::<Vec<_>>();
+
+        let scatter_blocks = DataBlock::scatter(&block, &indices, NUM_BUCKETS)?;
+        for (bucket, block) in scatter_blocks.into_iter().enumerate() {
+            if !block.is_empty() {
+                let memory_size = block.memory_size();
+                self.memory_sizes[bucket] += memory_size;
+                self.memory_size += memory_size;
+                self.blocks[bucket].push(block);
+            }
+        }
+        Ok(())
+    }
+
+    /// Spills the largest buckets until the memory size is not greater than `target`,
+    /// each spilled bucket is written into one file.
+    async fn spill(&mut self, spiller: &mut Spiller, target: usize) -> Result<()> {
+        let ins = Instant::now();
+        let mut rows = 0;
+        let mut write_bytes = 0;
+        let mut write_count = 0;
+
+        let mut buckets = (0..NUM_BUCKETS)
+            .filter(|bucket| self.memory_sizes[*bucket] > 0)
+            .collect::<Vec<_>>();
+        buckets.sort_by_key(|bucket| Reverse(self.memory_sizes[*bucket]));
+        for bucket in buckets {
+            if self.memory_size <= target {
+                break;
+            }
+            let block =
+                DataBlock::concat(&std::mem::take(&mut self.blocks[bucket]))?.convert_to_full();
+            rows += block.num_rows();
+            let (location, bytes) = spiller.spill_block(block).await?;
+            let memory_size = std::mem::take(&mut self.memory_sizes[bucket]);
+            self.memory_size -= memory_size;
+            self.spilled_sizes[bucket] += memory_size;
+            self.spilled_locations[bucket].push(location);
+            write_bytes += bytes as usize;
+            write_count += 1;
+        }
+
+        // perf
+        {
+            Profile::record_usize_profile(ProfileStatisticsName::SpillWriteCount, write_count);
+            Profile::record_usize_profile(ProfileStatisticsName::SpillWriteBytes, write_bytes);
+            Profile::record_usize_profile(
+                ProfileStatisticsName::SpillWriteTime,
+                ins.elapsed().as_millis() as usize,
+            );
+        }
+
+        info!(
+            "Window spilled {} rows into {} files at level {}, cost: {:?}",
+            rows,
+            write_count,
+            self.level,
+            ins.elapsed()
+        );
+        Ok(())
+    }
+
+    fn into_partitions(self) -> Vec<Partition> {
+        let level = self.level;
+        self.blocks
+            .into_iter()
+            .zip(self.spilled_locations)
+            .zip(self.memory_sizes.into_iter().zip(self.spilled_sizes))
+            .filter(|((blocks, locations), _)| !blocks.is_empty() || !locations.is_empty())
+            .map(
+                |((blocks, spilled_locations), (memory_size, spilled_size))| Partition {
+                    level,
+                    blocks,
+                    spilled_locations,
+                    memory_size: memory_size + spilled_size,
+                },
+            )
+            .collect()
+    }
+}
+
+/// Collects the input of [`super::TransformWindow`] by the window partitions.
+///
+/// All the rows of a window partition are in the same bucket, so the window function can be
+/// evaluated bucket by bucket. The largest buckets are spilled to the storage if the memory
+/// usage of the processor or the query exceeds the limit. A restored bucket that is still
+/// larger than the limit is repartitioned into the buckets of the next level with the next bits
+/// of the hashes, until it fits in memory or it only contains rows of one window partition.
+pub struct TransformWindowPartitionCollect {
+    input: Arc<InputPort>,
+    output: Arc<OutputPort>,
+    state: State,
+
+    input_data: Option<DataBlock>,
+    output_data: VecDeque<DataBlock>,
+
+    partition_indices: Vec<usize>,
+    sort_desc: Vec<SortColumnDescription>,
+    max_block_size: usize,
+
+    buckets: Buckets,
+    /// The memory limit of this processor.
+    max_memory_usage: usize,
+    /// The memory limit of the window, shared by all the processors of the query.
+    query_max_memory_usage: usize,
+    query_memory: Option<Arc<MemStat>>,
+
+    spiller: Spiller,
+    /// The buckets to be restored, the last one is restored first.
+    partitions: Vec<Partition>,
+}
+
+impl TransformWindowPartitionCollect {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        partition_indices: Vec<usize>,
+        sort_desc: Vec<SortColumnDescription>,
+        max_block_size: usize,
+        max_memory_usage: usize,
+        query_max_memory_usage: usize,
+        query_memory: Option<Arc<MemStat>>,
+        spiller: Spiller,
+    ) -> Box<dyn Processor> {
+        Box::new(TransformWindowPartitionCollect {
+            input,
+            output,
+            state: State::Collect,
+            input_data: None,
+            output_data: VecDeque::new(),
+            partition_indices,
+            sort_desc,
+            max_block_size,
+            buckets: Buckets::create(0),
+            max_memory_usage,
+            query_max_memory_usage,
+            query_memory,
+            spiller,
+            partitions: vec![],
+        })
+    }
+
+    fn need_spill(&self) -> bool {
+        if self.buckets.memory_size >= self.max_memory_usage {
+            return true;
+        }
+
+        // Only spill for the memory of the query if this processor holds a fair share of it,
+        // otherwise the buckets would be spilled into tiny files.
+        self.buckets.memory_size >= self.max_memory_usage / 4
+            && self.query_memory.as_ref().map_or(false, |mem_stat| {
+                mem_stat.get_memory_usage().max(0) as usize >= self.query_max_memory_usage
+            })
+    }
+
+    fn collect(&mut self, block: DataBlock) -> Result<()> {
+        self.buckets.add_block(block, &self.partition_indices)?;
+        if self.need_spill() {
+            self.state = State::Spill;
+        }
+        Ok(())
+    }
+
+    async fn spill(&mut self) -> Result<()> {
+        let target = self.buckets.memory_size.min(self.max_memory_usage) / 2;
+        self.buckets.spill(&mut self.spiller, target).await
+    }
+
+    async fn read_spilled(&mut self, locations: &[String]) -> Result<Vec<DataBlock>> {
+        let ins = Instant::now();
+        let mut read_bytes = 0;
+        let mut blocks = Vec::with_capacity(locations.len());
+        for location in locations.iter() {
+            let (block, bytes) = self.spiller.read_spilled(location).await?;
+            self.spiller.columns_layout.remove(location);
+            read_bytes += bytes as usize;
+            blocks.push(block);
+        }
+
+        // perf
+        {
+            Profile::record_usize_profile(ProfileStatisticsName::SpillReadCount, locations.len());
+            Profile::record_usize_profile(ProfileStatisticsName::SpillReadBytes, read_bytes);
+            Profile::record_usize_profile(
+                ProfileStatisticsName::SpillReadTime,
+                ins.elapsed().as_millis() as usize,
+            );
+        }
+        Ok(blocks)
+    }
+
+    /// Hashes the rows of the partition into the buckets of the next level, the spilled files are
+    /// read one at a time, and the sub buckets are spilled when they exceed the limit.
+    async fn repartition(&mut self, partition: Partition) -> Result<()> {
+        let level = partition.level + 1;
+        let mut buckets = Buckets::create(level);
+        for block in partition.blocks {
+            buckets.add_block(block, &self.partition_indices)?;
+        }
+        for location in partition.spilled_locations.iter() {
+            let block = self
+                .read_spilled(std::slice::from_ref(location))
+                .await?
+                .pop()
+                .unwrap();
+            buckets.add_block(block, &self.partition_indices)?;
+            if buckets.memory_size >= self.max_memory_usage {
+                buckets
+                    .spill(&mut self.spiller, self.max_memory_usage / 2)
+                    .await?;
+            }
+        }
+
+        let mut partitions = buckets.into_partitions();
+        if partitions.len() == 1 {
+            // All the rows have the same hash, it is one window partition that can't be split.
+            partitions[0].level = MAX_LEVEL;
+        }
+        info!(
+            "Window repartitioned a bucket of {} bytes into {} buckets at level {}",
+            partition.memory_size,
+            partitions.len(),
+            level
+        );
+        partitions.reverse();
+        self.partitions.extend(partitions);
+        Ok(())
+    }
+
+    async fn restore(&mut self) -> Result<()> {
+        let Some(partition) = self.partitions.pop() else {
+            return Ok(());
+        };
+
+        if partition.memory_size > self.max_memory_usage
+            && partition.level + 1 < MAX_LEVEL
+            && !partition.spilled_locations.is_empty()
+        {
+            return self.repartition(partition).await;
+        }
+
+        let mut blocks = self.read_spilled(&partition.spilled_locations).await?;
+        blocks.extend(partition.blocks);
+        let block = DataBlock::concat(&blocks)?;
+        drop(blocks);
+        let block = DataBlock::sort(&block, &self.sort_desc, None)?;
+        self.output_data
+            .extend(block.split_by_rows_no_tail(self.max_block_size));
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for TransformWindowPartitionCollect {
+    fn name(&self) -> String {
+        "TransformWindowPartitionCollect".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if self.output.is_finished() {
+            self.input.finish();
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            self.input.set_not_need_data();
+            return Ok(Event::NeedConsume);
+        }
+
+        if let Some(block) = self.output_data.pop_front() {
+            self.output.push_data(Ok(block));
+            return Ok(Event::NeedConsume);
+        }
+
+        match self.state {
+            State::Collect => {
+                if self.input_data.is_some() {
+                    return Ok(Event::Sync);
+                }
+
+                if self.input.has_data() {
+                    self.input_data = Some(self.input.pull_data().unwrap()?);
+                    return Ok(Event::Sync);
+                }
+
+                if self.input.is_finished() {
+                    let buckets = std::mem::replace(&mut self.buckets, Buckets::create(0));
+                    self.partitions = buckets.into_partitions();
+                    self.partitions.reverse();
+                    self.state = State::Restore;
+                    return self.event();
+                }
+
+                self.input.set_need_data();
+                Ok(Event::NeedData)
+            }
+            State::Spill => Ok(Event::Async),
+            State::Restore => {
+                if !self.partitions.is_empty() {
+                    Ok(Event::Async)
+                } else {
+                    self.state = State::Finish;
+                    self.output.finish();
+                    Ok(Event::Finished)
+                }
+            }
+            State::Finish => {
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        if let Some(block) = self.input_data.take() {
+            self.collect(block)?;
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match self.state {
+            State::Spill => {
+                self.spill().await?;
+                self.state = State::Collect;
+            }
+            State::Restore => self.restore().await?,
+            _ => unreachable!(),
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,254 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::VecDeque;
+use std::fmt::Debug;
+use std::fmt::Formatter;
+use std::sync::Arc;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::group_hash_columns;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::BlockMetaInfoPtr;
+use databend_common_expression::DataBlock;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipe;
+use databend_common_pipeline_core::PipeItem;
+use databend_common_pipeline_transforms::processors::Transform;
+
+/// The blocks of a window input block scattered by the window partitions.
+pub struct WindowPartitionMeta {
+    pub partitioned: Vec<DataBlock>,
+}
+
+impl WindowPartitionMeta {
+    pub fn create(partitioned: Vec<DataBlock>) -> BlockMetaInfoPtr {
+        Box::new(WindowPartitionMeta { partitioned })
+    }
+}
+
+impl Debug for WindowPartitionMeta {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("WindowPartitionMeta").finish()
+    }
+}
+
+impl serde::Serialize for WindowPartitionMeta {
+    fn serialize<S>(&self, _: S) -> Result<S::Ok, S::Error>
+    where S: serde::Serializer {
+        unimplemented!("Unimplemented serialize WindowPartitionMeta")
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for WindowPartitionMeta {
+    fn deserialize<D>(_: D) -> Result<Self, D::Error>
+    where D: serde::Deserializer<'de> {
+        unimplemented!("Unimplemented deserialize WindowPartitionMeta")
+    }
+}
+
+#[typetag::serde(name = "window_partition")]
+impl BlockMetaInfo for WindowPartitionMeta {
+    fn equals(&self, _: &Box<dyn BlockMetaInfo>) -> bool {
+        unimplemented!("Unimplemented equals WindowPartitionMeta")
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        unimplemented!("Unimplemented clone WindowPartitionMeta")
+    }
+}
+
+/// Hashes the rows of the block by the partition by columns.
+///
+/// The scatter uses the high 32 bits of the hashes, and the buckets of
+/// [`super::TransformWindowPartitionCollect`] use the low bits.
+pub fn window_partition_hashes(block: &DataBlock, partition_indices: &[usize]) -> Vec<u64> {
+    let num_rows = block.num_rows();
+    let columns = partition_indices
+        .iter()
+        .map(|index| {
+            let entry = block.get_by_offset(*index);
+            entry
+                .value
+                .convert_to_full_column(&entry.data_type, num_rows)
+        })
+        .collect::<Vec<_>>();
+    let mut hashes = vec![0; num_rows];
+    group_hash_columns(&columns, &mut hashes);
+    hashes
+}
+
+/// Scatters the rows of each input block to `num_partitions` parts by the window partitions,
+/// so that all the rows of a window partition go to the same window processor.
+pub struct TransformWindowPartitionScatter {
+    partition_indices: Vec<usize>,
+    num_partitions: usize,
+}
+
+impl TransformWindowPartitionScatter {
+    pub fn create(partition_indices: Vec<usize>, num_partitions: usize) -> Self {
+        TransformWindowPartitionScatter {
+            partition_indices,
+            num_partitions,
+        }
+    }
+}
+
+impl Transform for TransformWindowPartitionScatter {
+    const NAME: &'static str = "TransformWindowPartitionScatter";
+
+    fn transform(&mut self, data: DataBlock) -> Result<DataBlock> {
+        let partitioned = if data.is_empty() {
+            vec![]
+        } else {
+            let hashes = window_partition_hashes(&data, &self.partition_indices);
+            let indices = hashes
+                .iter()
+                .map(|hash| ((hash >> 32) % self.num_partitions as u64) as u32)
+                .collect::<Vec<_>>();
+            DataBlock::scatter(&data, &indices, self.num_partitions)?
+        };
+        Ok(DataBlock::empty_with_meta(WindowPartitionMeta::create(
+            partitioned,
+        )))
+    }
+}
+
+/// The blocks buffered for an output before the shuffle stops pulling the inputs.
+const MAX_BUFFERED_BLOCKS: usize = 2;
+
+/// Routes the scattered blocks of [`TransformWindowPartitionScatter`] from `N` inputs to `N`
+/// outputs, the `i`-th part of every input block goes to the `i`-th output.
+pub struct TransformWindowPartitionShuffle {
+    inputs: Vec<Arc<InputPort>>,
+    outputs: Vec<Arc<OutputPort>>,
+    buffers: Vec<VecDeque<DataBlock>>,
+}
+
+impl TransformWindowPartitionShuffle {
+    pub fn create(num_partitions: usize) -> Self {
+        TransformWindowPartitionShuffle {
+            inputs: (0..num_partitions).map(|_| InputPort::create()).collect(),
+            outputs: (0..num_partitions).map(|_| OutputPort::create()).collect(),
+            buffers: vec![VecDeque::new(); num_partitions],
+        }
+    }
+
+    pub fn into_pipe(self) -> Pipe {
+        let num_partitions = self.inputs.len();
+        let inputs = self.inputs.clone();
+        let outputs = self.outputs.clone();
+        let item = PipeItem::create(ProcessorPtr::create(Box::new(self)), inputs, outputs);
+        Pipe::create(num_partitions, num_partitions, vec![item])
+    }
+
+    fn pull(&mut self) -> Result<bool> {
+        let mut all_inputs_finished = true;
+        for input in self.inputs.iter() {
+            if input.is_finished() {
+                continue;
+            }
+            all_inputs_finished = false;
+
+            if input.has_data() {
+                let mut block = input.pull_data().unwrap()?;
+                let meta = block
+                    .take_meta()
+                    .and_then(WindowPartitionMeta::downcast_from)
+                    .ok_or_else(|| {
+                        ErrorCode::Internal(
+                            "TransformWindowPartitionShuffle only recv partitioned blocks",
+                        )
+                    })?;
+                for (index, block) in meta.partitioned.into_iter().enumerate() {
+                    if !block.is_empty() && !self.outputs[index].is_finished() {
+                        self.buffers[index].push_back(block);
+                    }
+                }
+            }
+            input.set_need_data();
+        }
+        Ok(all_inputs_finished)
+    }
+}
+
+impl Processor for TransformWindowPartitionShuffle {
+    fn name(&self) -> String {
+        "TransformWindowPartitionShuffle".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        let mut all_outputs_finished = true;
+        for (index, output) in self.outputs.iter().enumerate() {
+            if output.is_finished() {
+                self.buffers[index].clear();
+            } else {
+                all_outputs_finished = false;
+            }
+        }
+
+        if all_outputs_finished {
+            for input in self.inputs.iter() {
+                input.finish();
+            }
+            return Ok(Event::Finished);
+        }
+
+        // Stop pulling while an output is backed up, the inputs are pulled again when it consumes.
+        let all_inputs_finished = if self
+            .buffers
+            .iter()
+            .any(|buffer| buffer.len() >= MAX_BUFFERED_BLOCKS)
+        {
+            for input in self.inputs.iter() {
+                input.set_not_need_data();
+            }
+            false
+        } else {
+            self.pull()?
+        };
+
+        for (index, output) in self.outputs.iter().enumerate() {
+            if output.can_push() {
+                if let Some(block) = self.buffers[index].pop_front() {
+                    output.push_data(Ok(block));
+                }
+            }
+        }
+
+        if self.buffers.iter().any(|buffer| !buffer.is_empty()) {
+            return Ok(Event::NeedConsume);
+        }
+
+        if all_inputs_finished {
+            for output in self.outputs.iter() {
+                output.finish();
+            }
+            return Ok(Event::Finished);
+        }
+
+        Ok(Event::NeedData)
+    }
+}
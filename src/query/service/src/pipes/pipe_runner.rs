@@ -0,0 +1,178 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_ast::ast::CopyIntoTableSource;
+use databend_common_ast::ast::CopyIntoTableStmt;
+use databend_common_ast::ast::FileLocation;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::Int32Type;
+use databend_common_expression::types::ValueType;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_meta_app::principal::PipeLoad;
+use databend_common_sql::binder::resolve_file_location;
+use databend_common_sql::Planner;
+use databend_common_storage::init_stage_operator;
+use databend_common_storage::StageFilesInfo;
+use databend_common_users::UserApiProvider;
+use futures_util::TryStreamExt;
+use log::info;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+
+/// Runs the `COPY INTO <table>` statement of the pipe, and records the run in the history
+/// of the pipe. The files loaded by the previous runs are skipped by the copied files of the
+/// table, so each file is loaded only once.
+///
+/// Only the files under `prefix` of the stage location are loaded if it is given, and only
+/// the files modified after `modified_after` if it is given.
+pub async fn run_pipe(
+    ctx: Arc<QueryContext>,
+    pipe: &PipeInfo,
+    prefix: Option<&str>,
+    modified_after: Option<&str>,
+) -> Result<()> {
+    let start_on = Utc::now();
+    let res = execute_pipe_copy(ctx.clone(), pipe, prefix, modified_after).await;
+    let end_on = Utc::now();
+
+    let (files_loaded, rows_loaded) = res.as_ref().cloned().unwrap_or_default();
+    let load = PipeLoad {
+        pipe_name: pipe.name.clone(),
+        start_on,
+        end_on,
+        files_loaded,
+        rows_loaded,
+        error: res.as_ref().err().map(|e| e.message()),
+    };
+    info!(
+        "Pipe {} loaded {} files, {} rows, error: {:?}",
+        pipe.name, files_loaded, rows_loaded, load.error
+    );
+    UserApiProvider::instance()
+        .add_pipe_load(&ctx.get_tenant(), load)
+        .await?;
+
+    res.map(|_| ())
+}
+
+/// Returns the number of files and rows loaded.
+async fn execute_pipe_copy(
+    ctx: Arc<QueryContext>,
+    pipe: &PipeInfo,
+    prefix: Option<&str>,
+    modified_after: Option<&str>,
+) -> Result<(u64, u64)> {
+    let settings = ctx.get_settings();
+    let tokens = tokenize_sql(&pipe.copy_sql)?;
+    let (stmt, _) = parse_sql(&tokens, settings.get_sql_dialect()?)?;
+    let Statement::CopyIntoTable(mut copy_stmt) = stmt else {
+        return Err(ErrorCode::IllegalPipe(format!(
+            "Pipe '{}' must have a COPY INTO <table> statement inside",
+            pipe.name
+        )));
+    };
+
+    if let Some(prefix) = prefix {
+        let location = copy_location_mut(&mut copy_stmt, "PREFIX")?;
+        let path = match location {
+            FileLocation::Stage(path) => path,
+            FileLocation::Uri(uri) => &mut uri.path,
+        };
+        *path = format!(
+            "{}/{}",
+            path.trim_end_matches('/'),
+            prefix.trim_start_matches('/')
+        );
+    }
+
+    if let Some(modified_after) = modified_after {
+        let modified_after = DateTime::parse_from_rfc3339(modified_after)
+            .map_err(|e| ErrorCode::BadArguments(format!("invalid MODIFIED_AFTER: {}", e)))?
+            .with_timezone(&Utc);
+        let location = copy_location_mut(&mut copy_stmt, "MODIFIED_AFTER")?.clone();
+        let (stage_info, path) = resolve_file_location(ctx.as_ref(), &location).await?;
+        let operator = init_stage_operator(&stage_info)?;
+        let files_info = StageFilesInfo {
+            path: path.clone(),
+            files: copy_stmt.files.clone(),
+            pattern: copy_stmt.pattern.clone(),
+        };
+        let files = files_info
+            .list(&operator, false, None)
+            .await?
+            .into_iter()
+            .filter(|file| file.last_modified > modified_after)
+            .map(|file| {
+                file.path
+                    .strip_prefix(path.as_str())
+                    .unwrap_or(&file.path)
+                    .trim_start_matches('/')
+                    .to_string()
+            })
+            .collect::<Vec<_>>();
+        if files.is_empty() {
+            return Ok((0, 0));
+        }
+        copy_stmt.files = Some(files);
+        copy_stmt.pattern = None;
+    }
+
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, extras) = planner.plan_sql(&copy_stmt.to_string()).await?;
+    ctx.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    let blocks = interpreter
+        .execute(ctx.clone())
+        .await?
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    // The result of COPY has a row for each loaded file, the second column is the loaded rows.
+    let mut files_loaded = 0;
+    let mut rows_loaded = 0;
+    for block in blocks.iter().filter(|block| block.num_columns() > 1) {
+        let entry = block.get_by_offset(1);
+        let column = entry
+            .value
+            .convert_to_full_column(&entry.data_type, block.num_rows());
+        if let Some(rows) = Int32Type::try_downcast_column(&column) {
+            rows_loaded += rows.iter().map(|n| *n as u64).sum::<u64>();
+        }
+        files_loaded += block.num_rows() as u64;
+    }
+    Ok((files_loaded, rows_loaded))
+}
+
+fn copy_location_mut<'a>(
+    copy_stmt: &'a mut CopyIntoTableStmt,
+    option: &str,
+) -> Result<&'a mut FileLocation> {
+    match &mut copy_stmt.src {
+        CopyIntoTableSource::Location(location) => Ok(location),
+        CopyIntoTableSource::Query(_) => Err(ErrorCode::SemanticError(format!(
+            "{} is not supported by the pipe that copies from a query",
+            option
+        ))),
+    }
+}
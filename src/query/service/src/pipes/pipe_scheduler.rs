@@ -0,0 +1,175 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+use std::time::Duration;
+
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_base::GLOBAL_TASK;
+use databend_common_config::InnerConfig;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_users::UserApiProvider;
+use futures::future::select;
+use futures::future::Either;
+use log::info;
+use log::warn;
+
+use crate::pipes::run_pipe;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// The interval to look for the new files of the auto ingest pipes.
+const PIPE_SCHEDULE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// The lease to run a pipe is renewed while the pipe is running, and taken over by the
+/// other nodes if it is not renewed in time.
+const PIPE_LEASE_TTL: Duration = Duration::from_secs(30);
+
+const PIPE_LEASE_RENEW_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Runs the auto ingest pipes of the tenant periodically.
+///
+/// A pipe is run by only one node of the cluster at a time, the node that holds the
+/// lease of the pipe.
+pub struct PipeScheduler {
+    tenant: String,
+    cluster_id: String,
+    node_id: String,
+}
+
+impl PipeScheduler {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        GlobalInstance::set(Arc::new(PipeScheduler {
+            tenant: conf.query.tenant_id.clone(),
+            cluster_id: conf.query.cluster_id.clone(),
+            node_id: conf.query.node_id.clone(),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<PipeScheduler> {
+        GlobalInstance::get()
+    }
+
+    pub fn start(self: &Arc<Self>) {
+        let scheduler = self.clone();
+        GlobalIORuntime::instance().spawn(GLOBAL_TASK, async move {
+            info!("Pipe scheduler started");
+            loop {
+                sleep(PIPE_SCHEDULE_INTERVAL).await;
+                if let Err(e) = scheduler.schedule().await {
+                    warn!("Pipe scheduler failed to run the pipes: {}", e);
+                }
+            }
+        });
+    }
+
+    #[async_backtrace::framed]
+    async fn schedule(&self) -> Result<()> {
+        let pipes = UserApiProvider::instance().get_pipes(&self.tenant).await?;
+        for pipe in pipes {
+            if !pipe.auto_ingest || pipe.execution_paused {
+                continue;
+            }
+            // The failure of a pipe does not keep the other pipes from running.
+            if let Err(e) = self.schedule_pipe(&pipe).await {
+                warn!("Pipe {} failed: {}", pipe.name, e);
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs the pipe as its owner role if this node gets the lease of the pipe.
+    #[async_backtrace::framed]
+    async fn schedule_pipe(&self, pipe: &PipeInfo) -> Result<()> {
+        if pipe.owner.is_empty() {
+            return Err(ErrorCode::UnknownRole(format!(
+                "Pipe {} has no owner role to run it, create the pipe again",
+                pipe.name
+            )));
+        }
+        if !UserApiProvider::instance()
+            .try_acquire_pipe_lease(&self.tenant, &pipe.name, &self.node_id, PIPE_LEASE_TTL)
+            .await?
+        {
+            return Ok(());
+        }
+
+        let session = SessionManager::instance()
+            .create_session(SessionType::HTTPAPI("PipeScheduler".to_string()))
+            .await?;
+        session
+            .set_authed_user(self.pipe_user(), Some(pipe.owner.clone()))
+            .await?;
+        let ctx = session.create_query_context().await?;
+        self.run_holding_lease(&session, ctx, pipe).await
+    }
+
+    /// Runs the pipe and renews its lease until the run finishes. The run is aborted if
+    /// the lease is lost, since another node may have started to run the pipe.
+    #[async_backtrace::framed]
+    async fn run_holding_lease(
+        &self,
+        session: &Arc<Session>,
+        ctx: Arc<QueryContext>,
+        pipe: &PipeInfo,
+    ) -> Result<()> {
+        let user_api = UserApiProvider::instance();
+        let mut run = Box::pin(run_pipe(ctx, pipe, None, None));
+        loop {
+            let renew = Box::pin(sleep(PIPE_LEASE_RENEW_INTERVAL));
+            match select(run, renew).await {
+                Either::Left((res, _)) => return res,
+                Either::Right((_, pending)) => {
+                    run = pending;
+                    match user_api
+                        .try_acquire_pipe_lease(
+                            &self.tenant,
+                            &pipe.name,
+                            &self.node_id,
+                            PIPE_LEASE_TTL,
+                        )
+                        .await
+                    {
+                        Ok(true) => {}
+                        Ok(false) => {
+                            session.force_kill_query(ErrorCode::AbortedQuery(format!(
+                                "Pipe {} is aborted, the lease to run it is lost",
+                                pipe.name
+                            )));
+                            return run.await;
+                        }
+                        // The lease is still valid for a while, retry in the next round.
+                        Err(e) => warn!("Pipe {} failed to renew its lease: {}", pipe.name, e),
+                    }
+                }
+            }
+        }
+    }
+
+    fn pipe_user(&self) -> UserInfo {
+        UserInfo::new_no_auth(
+            format!("{}-{}-pipe-svc", self.tenant, self.cluster_id).as_str(),
+            "0.0.0.0",
+        )
+    }
+}
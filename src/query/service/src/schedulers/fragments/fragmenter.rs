@@ -16,6 +16,7 @@ use std::sync::Arc;
 
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::Result;
+use databend_common_sql::executor::physical_plans::AsofJoin;
 use databend_common_sql::executor::physical_plans::CompactSource;
 use databend_common_sql::executor::physical_plans::CopyIntoTable;
 use databend_common_sql::executor::physical_plans::CopyIntoTableSource;
@@ -256,6 +257,24 @@ impl PhysicalPlanReplacer for Fragmenter {
         }))
     }
 
+    fn replace_asof_join(&mut self, plan: &AsofJoin) -> Result<PhysicalPlan> {
+        let mut fragments = vec![];
+        let right_input = self.replace(plan.right.as_ref())?;
+
+        // Consume current fragments to prevent them being consumed by `left_input`.
+        fragments.append(&mut self.fragments);
+        let left_input = self.replace(plan.left.as_ref())?;
+
+        fragments.append(&mut self.fragments);
+        self.fragments = fragments;
+
+        Ok(PhysicalPlan::AsofJoin(AsofJoin {
+            left: Box::new(left_input),
+            right: Box::new(right_input),
+            ..plan.clone()
+        }))
+    }
+
     fn replace_union(&mut self, plan: &UnionAll) -> Result<PhysicalPlan> {
         let mut fragments = vec![];
         let left_input = self.replace(plan.left.as_ref())?;
@@ -16,7 +16,10 @@ use std::sync::Arc;
 
 use arrow_array::builder::StringBuilder;
 use arrow_array::ArrayRef;
+use arrow_array::Int32Array;
 use arrow_array::RecordBatch;
+use arrow_array::StringArray;
+use arrow_array::UInt8Array;
 use arrow_flight::utils::batches_to_flight_data;
 use arrow_schema::DataType;
 use arrow_schema::Field;
@@ -25,12 +28,31 @@ use databend_common_catalog::catalog::Catalog;
 use databend_common_catalog::catalog::CatalogManager;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
+use databend_common_meta_app::schema::TableConstraint;
 use futures_util::stream;
 use log::warn;
+use regex::Regex;
 use tonic::Status;
 
 use crate::servers::flight_sql::flight_sql_service::DoGetStream;
 
+const TABLE_TYPE_TABLE: &str = "TABLE";
+const TABLE_TYPE_VIEW: &str = "VIEW";
+// `UpdateDeleteRules::NO_ACTION` of the Flight SQL protocol.
+const RULE_NO_ACTION: u8 = 3;
+
+struct ForeignKeyRow {
+    catalog_name: String,
+    pk_database: String,
+    pk_table: String,
+    pk_column: String,
+    fk_database: String,
+    fk_table: String,
+    fk_column: String,
+    key_sequence: i32,
+    fk_key_name: String,
+}
+
 pub(super) struct CatalogInfoProvider {}
 
 impl CatalogInfoProvider {
@@ -71,7 +93,6 @@ impl CatalogInfoProvider {
         let mut database_names = vec![];
         let mut table_names = vec![];
         let mut table_types = vec![];
-        let table_type = "table".to_string();
         for (catalog_name, catalog) in catalogs.into_iter() {
             let dbs = if let Some(database_name) = &database_name {
                 vec![catalog.get_database(tenant.as_str(), database_name).await?]
@@ -93,7 +114,7 @@ impl CatalogInfoProvider {
                     catalog_names.push(catalog_name.clone());
                     database_names.push(db_name.to_string());
                     table_names.push(table.name().to_string());
-                    table_types.push(table_type.clone());
+                    table_types.push(Self::table_type(table.engine()).to_string());
                 }
             }
         }
@@ -125,6 +146,316 @@ impl CatalogInfoProvider {
         Self::batch_to_get_stream(batch)
     }
 
+    pub(crate) async fn get_catalogs(ctx: Arc<dyn TableContext>) -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "catalog_name",
+            DataType::Utf8,
+            false,
+        )]));
+        let catalog_names = CatalogManager::instance()
+            .list_catalogs(&ctx.get_tenant())
+            .await
+            .map_err(|e| Status::internal(format!("{e:?}")))?
+            .iter()
+            .map(|catalog| catalog.name())
+            .collect();
+        let batch = RecordBatch::try_new(schema, vec![Self::string_array(catalog_names)])
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    async fn get_schemas_internal(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
+    ) -> databend_common_exception::Result<(Vec<String>, Vec<String>)> {
+        let tenant = ctx.get_tenant();
+        let catalog_mgr = CatalogManager::instance();
+        let catalogs: Vec<(String, Arc<dyn Catalog>)> = if let Some(catalog_name) = catalog_name {
+            vec![(
+                catalog_name.clone(),
+                catalog_mgr.get_catalog(&tenant, &catalog_name).await?,
+            )]
+        } else {
+            catalog_mgr
+                .list_catalogs(&tenant)
+                .await?
+                .iter()
+                .map(|r| (r.name(), r.clone()))
+                .collect()
+        };
+        let filter = db_schema_filter_pattern
+            .map(|pattern| Self::like_pattern_to_regex(&pattern))
+            .transpose()?;
+
+        let mut catalog_names = vec![];
+        let mut database_names = vec![];
+        for (catalog_name, catalog) in catalogs.into_iter() {
+            for db in catalog.list_databases(tenant.as_str()).await? {
+                let db_name = db.name();
+                if filter.as_ref().map_or(true, |f| f.is_match(db_name)) {
+                    catalog_names.push(catalog_name.clone());
+                    database_names.push(db_name.to_string());
+                }
+            }
+        }
+        Ok((catalog_names, database_names))
+    }
+
+    pub(crate) async fn get_schemas(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        db_schema_filter_pattern: Option<String>,
+    ) -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, false),
+        ]));
+        let (catalog_name, db_schema_name) =
+            Self::get_schemas_internal(ctx, catalog_name, db_schema_filter_pattern)
+                .await
+                .map_err(|e| Status::internal(format!("{e:?}")))?;
+        let batch = RecordBatch::try_new(schema, vec![
+            Self::string_array(catalog_name),
+            Self::string_array(db_schema_name),
+        ])
+        .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    pub(crate) fn get_table_types() -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![Field::new(
+            "table_type",
+            DataType::Utf8,
+            false,
+        )]));
+        let table_types = vec![TABLE_TYPE_TABLE.to_string(), TABLE_TYPE_VIEW.to_string()];
+        let batch = RecordBatch::try_new(schema, vec![Self::string_array(table_types)])
+            .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    async fn get_primary_keys_internal(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        database_name: Option<String>,
+        table_name: String,
+    ) -> databend_common_exception::Result<Vec<(String, String, String, String, i32)>> {
+        let tenant = ctx.get_tenant();
+        let catalog_name = catalog_name.unwrap_or_else(|| ctx.get_current_catalog());
+        let database_name = database_name.unwrap_or_else(|| ctx.get_current_database());
+        let catalog = CatalogManager::instance()
+            .get_catalog(&tenant, &catalog_name)
+            .await?;
+        let table = catalog
+            .get_table(tenant.as_str(), &database_name, &table_name)
+            .await?;
+
+        let mut rows = vec![];
+        for (name, constraint) in &table.get_table_info().meta.constraints {
+            if let TableConstraint::PrimaryKey { columns } = constraint {
+                for (i, column) in columns.iter().enumerate() {
+                    rows.push((
+                        catalog_name.clone(),
+                        database_name.clone(),
+                        column.clone(),
+                        name.clone(),
+                        i as i32 + 1,
+                    ));
+                }
+            }
+        }
+        Ok(rows)
+    }
+
+    /// Primary keys are informational, they are declared in the table meta but not enforced.
+    pub(crate) async fn get_primary_keys(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        database_name: Option<String>,
+        table_name: String,
+    ) -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("catalog_name", DataType::Utf8, true),
+            Field::new("db_schema_name", DataType::Utf8, true),
+            Field::new("table_name", DataType::Utf8, false),
+            Field::new("column_name", DataType::Utf8, false),
+            Field::new("key_name", DataType::Utf8, true),
+            Field::new("key_sequence", DataType::Int32, false),
+        ]));
+        let rows =
+            Self::get_primary_keys_internal(ctx, catalog_name, database_name, table_name.clone())
+                .await
+                .map_err(|e| Status::internal(format!("{e:?}")))?;
+        let batch = RecordBatch::try_new(schema, vec![
+            Self::string_array(rows.iter().map(|r| r.0.clone()).collect()),
+            Self::string_array(rows.iter().map(|r| r.1.clone()).collect()),
+            Self::string_array(vec![table_name; rows.len()]),
+            Self::string_array(rows.iter().map(|r| r.2.clone()).collect()),
+            Self::string_array(rows.iter().map(|r| r.3.clone()).collect()),
+            Arc::new(Int32Array::from_iter_values(rows.iter().map(|r| r.4))),
+        ])
+        .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    async fn get_foreign_keys_internal(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        pk_table: Option<(Option<String>, String)>,
+        fk_table: Option<(Option<String>, String)>,
+    ) -> databend_common_exception::Result<Vec<ForeignKeyRow>> {
+        let tenant = ctx.get_tenant();
+        let current_database = ctx.get_current_database();
+        let catalog_name = catalog_name.unwrap_or_else(|| ctx.get_current_catalog());
+        let catalog = CatalogManager::instance()
+            .get_catalog(&tenant, &catalog_name)
+            .await?;
+
+        // Foreign keys are stored on the referencing table, so without a referencing
+        // table every table of the catalog has to be inspected.
+        let fk_tables = match fk_table {
+            Some((database_name, table_name)) => {
+                let database_name = database_name.unwrap_or_else(|| current_database.clone());
+                let table = catalog
+                    .get_table(tenant.as_str(), &database_name, &table_name)
+                    .await?;
+                vec![(database_name, table)]
+            }
+            None => {
+                let mut tables = vec![];
+                for db in catalog.list_databases(tenant.as_str()).await? {
+                    let db_name = db.name().to_string();
+                    let db_tables = match catalog.list_tables(tenant.as_str(), &db_name).await {
+                        Ok(tables) => tables,
+                        Err(err) if err.code() == ErrorCode::EMPTY_SHARE_ENDPOINT_CONFIG => {
+                            warn!("list tables failed on db {}: {}", db_name, err);
+                            continue;
+                        }
+                        Err(err) => return Err(err),
+                    };
+                    for table in db_tables {
+                        tables.push((db_name.clone(), table));
+                    }
+                }
+                tables
+            }
+        };
+        let pk_table = pk_table.map(|(database_name, table_name)| {
+            (
+                database_name.unwrap_or_else(|| current_database.clone()),
+                table_name,
+            )
+        });
+
+        let mut rows = vec![];
+        for (fk_database, table) in fk_tables {
+            for (name, constraint) in &table.get_table_info().meta.constraints {
+                let TableConstraint::ForeignKey {
+                    columns,
+                    ref_database,
+                    ref_table,
+                    ref_columns,
+                } = constraint
+                else {
+                    continue;
+                };
+                if let Some((pk_database, pk_table)) = &pk_table {
+                    if pk_database != ref_database || pk_table != ref_table {
+                        continue;
+                    }
+                }
+                for (i, (fk_column, pk_column)) in columns.iter().zip(ref_columns).enumerate() {
+                    rows.push(ForeignKeyRow {
+                        catalog_name: catalog_name.clone(),
+                        pk_database: ref_database.clone(),
+                        pk_table: ref_table.clone(),
+                        pk_column: pk_column.clone(),
+                        fk_database: fk_database.clone(),
+                        fk_table: table.name().to_string(),
+                        fk_column: fk_column.clone(),
+                        key_sequence: i as i32 + 1,
+                        fk_key_name: name.clone(),
+                    });
+                }
+            }
+        }
+        Ok(rows)
+    }
+
+    /// The result of `GetImportedKeys`, `GetExportedKeys` and `GetCrossReference`.
+    /// Foreign keys are informational, so the update and delete rules are always `NO ACTION`.
+    pub(crate) async fn get_foreign_keys(
+        ctx: Arc<dyn TableContext>,
+        catalog_name: Option<String>,
+        pk_table: Option<(Option<String>, String)>,
+        fk_table: Option<(Option<String>, String)>,
+    ) -> Result<DoGetStream, Status> {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("pk_catalog_name", DataType::Utf8, true),
+            Field::new("pk_db_schema_name", DataType::Utf8, true),
+            Field::new("pk_table_name", DataType::Utf8, false),
+            Field::new("pk_column_name", DataType::Utf8, false),
+            Field::new("fk_catalog_name", DataType::Utf8, true),
+            Field::new("fk_db_schema_name", DataType::Utf8, true),
+            Field::new("fk_table_name", DataType::Utf8, false),
+            Field::new("fk_column_name", DataType::Utf8, false),
+            Field::new("key_sequence", DataType::Int32, false),
+            Field::new("fk_key_name", DataType::Utf8, true),
+            Field::new("pk_key_name", DataType::Utf8, true),
+            Field::new("update_rule", DataType::UInt8, false),
+            Field::new("delete_rule", DataType::UInt8, false),
+        ]));
+        let rows = Self::get_foreign_keys_internal(ctx, catalog_name, pk_table, fk_table)
+            .await
+            .map_err(|e| Status::internal(format!("{e:?}")))?;
+        let strings =
+            |f: &dyn Fn(&ForeignKeyRow) -> String| Self::string_array(rows.iter().map(f).collect());
+        let batch = RecordBatch::try_new(schema, vec![
+            strings(&|r| r.catalog_name.clone()),
+            strings(&|r| r.pk_database.clone()),
+            strings(&|r| r.pk_table.clone()),
+            strings(&|r| r.pk_column.clone()),
+            strings(&|r| r.catalog_name.clone()),
+            strings(&|r| r.fk_database.clone()),
+            strings(&|r| r.fk_table.clone()),
+            strings(&|r| r.fk_column.clone()),
+            Arc::new(Int32Array::from_iter_values(
+                rows.iter().map(|r| r.key_sequence),
+            )),
+            strings(&|r| r.fk_key_name.clone()),
+            Arc::new(StringArray::new_null(rows.len())),
+            Arc::new(UInt8Array::from(vec![RULE_NO_ACTION; rows.len()])),
+            Arc::new(UInt8Array::from(vec![RULE_NO_ACTION; rows.len()])),
+        ])
+        .map_err(|e| Status::internal(format!("RecordBatch::try_new fail {:?}", e)))?;
+        Self::batch_to_get_stream(batch)
+    }
+
+    fn table_type(engine: &str) -> &'static str {
+        if engine.eq_ignore_ascii_case("VIEW") {
+            TABLE_TYPE_VIEW
+        } else {
+            TABLE_TYPE_TABLE
+        }
+    }
+
+    // Flight SQL filter patterns use the `LIKE` syntax: `%` matches any sequence
+    // of characters and `_` matches exactly one character.
+    fn like_pattern_to_regex(pattern: &str) -> databend_common_exception::Result<Regex> {
+        let mut regex = String::with_capacity(pattern.len() + 2);
+        regex.push('^');
+        for c in pattern.chars() {
+            match c {
+                '%' => regex.push_str(".*"),
+                '_' => regex.push('.'),
+                c => regex.push_str(&regex::escape(c.encode_utf8(&mut [0; 4]))),
+            }
+        }
+        regex.push('$');
+        Regex::new(&regex).map_err(|e| ErrorCode::BadArguments(format!("{e}")))
+    }
+
     fn string_array(values: Vec<String>) -> ArrayRef {
         let mut builder = StringBuilder::new();
         for v in &values {
@@ -22,10 +22,13 @@ mod sql_info;
 
 use std::pin::Pin;
 use std::sync::Arc;
+use std::sync::Weak;
+use std::time::Duration;
+use std::time::Instant;
 
 use arrow_flight::FlightData;
 use catalog::CatalogInfoProvider;
-use dashmap::DashMap;
+use databend_common_catalog::table_context::TableContext;
 use databend_common_sql::plans::Plan;
 use databend_common_sql::PlanExtras;
 use futures::Stream;
@@ -34,7 +37,10 @@ use sql_info::SqlInfoProvider;
 use tonic::Status;
 use uuid::Uuid;
 
+use crate::servers::http::v1::Expirable;
 use crate::servers::http::v1::ExpiringMap;
+use crate::servers::http::v1::ExpiringState;
+use crate::sessions::QueryContext;
 use crate::sessions::Session;
 
 #[macro_export]
@@ -49,9 +55,65 @@ pub(crate) use status;
 
 type DoGetStream = Pin<Box<dyn Stream<Item = Result<FlightData, Status>> + Send + 'static>>;
 
+/// How long a statement is kept after its last use.
+const STATEMENT_MAX_IDLE_TIME: Duration = Duration::from_secs(360);
+
+/// A statement planned for a statement ticket or a prepared statement handle.
+pub(crate) struct FlightStatement {
+    plan: Plan,
+    plan_extras: PlanExtras,
+    session: Weak<Session>,
+    last_access: Mutex<Instant>,
+    /// The query executing the statement.
+    query: Mutex<Weak<QueryContext>>,
+}
+
+impl FlightStatement {
+    pub fn create(session: &Arc<Session>, plan: Plan, plan_extras: PlanExtras) -> Arc<Self> {
+        Arc::new(FlightStatement {
+            plan,
+            plan_extras,
+            session: Arc::downgrade(session),
+            last_access: Mutex::new(Instant::now()),
+            query: Mutex::new(Weak::new()),
+        })
+    }
+
+    pub fn touch(&self) {
+        *self.last_access.lock() = Instant::now();
+    }
+
+    pub fn attach_query(&self, ctx: &Arc<QueryContext>) {
+        self.touch();
+        *self.query.lock() = Arc::downgrade(ctx);
+    }
+
+    /// The query executing the statement, if it is still running.
+    pub fn running_query(&self) -> Option<Arc<QueryContext>> {
+        self.query.lock().upgrade()
+    }
+}
+
+impl Expirable for Arc<FlightStatement> {
+    fn expire_state(&self) -> ExpiringState {
+        match self.session.upgrade() {
+            Some(session) if !session.is_aborting() => match self.running_query() {
+                Some(ctx) => ExpiringState::InUse(ctx.get_id()),
+                None => ExpiringState::Idle {
+                    idle_time: Instant::now() - *self.last_access.lock(),
+                },
+            },
+            _ => ExpiringState::Aborted { need_cleanup: true },
+        }
+    }
+
+    fn on_expire(&self) {}
+}
+
 pub struct FlightSqlServiceImpl {
     pub sessions: Mutex<ExpiringMap<String, Arc<Session>>>,
-    statements: Arc<DashMap<Uuid, (Plan, PlanExtras)>>,
+    /// The statements are released by ClosePreparedStatement, or when they are idle for too long.
+    statements: Mutex<ExpiringMap<Uuid, Arc<FlightStatement>>>,
 }
 
 /// in current official JDBC driver, Statement is based on PreparedStatement too, so we impl it first.
@@ -59,7 +121,7 @@ impl FlightSqlServiceImpl {
     pub fn create() -> Self {
         FlightSqlServiceImpl {
             sessions: Mutex::new(Default::default()),
-            statements: Arc::new(Default::default()),
+            statements: Mutex::new(Default::default()),
         }
     }
 }
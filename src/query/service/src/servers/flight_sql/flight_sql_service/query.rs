@@ -15,6 +15,7 @@
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
 use std::sync::Arc;
+use std::sync::Weak;
 
 use arrow_flight::FlightData;
 use arrow_flight::SchemaAsIpc;
@@ -26,8 +27,6 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchema;
-use databend_common_sql::plans::Plan;
-use databend_common_sql::PlanExtras;
 use databend_common_sql::Planner;
 use databend_common_storages_fuse::TableContext;
 use futures::Stream;
@@ -35,10 +34,13 @@ use futures::StreamExt;
 use serde::Deserialize;
 use serde::Serialize;
 use tonic::Status;
+use uuid::Uuid;
 
 use super::status;
 use super::DoGetStream;
 use super::FlightSqlServiceImpl;
+use super::FlightStatement;
+use super::STATEMENT_MAX_IDLE_TIME;
 use crate::interpreters::InterpreterFactory;
 use crate::sessions::QueryContext;
 use crate::sessions::Session;
@@ -68,34 +70,66 @@ impl FlightSqlServiceImpl {
         Ok(encoded_batch.into())
     }
 
+    /// Get the statement of the handle, only the session which planned it can use it.
+    pub(super) fn get_statement(
+        &self,
+        session: &Arc<Session>,
+        handle: &Uuid,
+    ) -> std::result::Result<Arc<FlightStatement>, Status> {
+        let statement = self.statements.lock().get(handle);
+        match statement {
+            Some(statement) if Weak::as_ptr(&statement.session) == Arc::as_ptr(session) => {
+                statement.touch();
+                Ok(statement)
+            }
+            _ => Err(Status::not_found(format!(
+                "Prepared statement not found, handle={handle}"
+            ))),
+        }
+    }
+
+    pub(super) fn add_statement(&self, handle: Uuid, statement: Arc<FlightStatement>) {
+        self.statements
+            .lock()
+            .insert(handle, statement, Some(STATEMENT_MAX_IDLE_TIME));
+    }
+
+    pub(super) fn remove_statement(&self, session: &Arc<Session>, handle: &Uuid) {
+        if self.get_statement(session, handle).is_ok() {
+            self.statements.lock().remove(handle);
+        }
+    }
+
     #[async_backtrace::framed]
     pub async fn plan_sql(
         &self,
         session: &Arc<Session>,
         query: &str,
-    ) -> Result<(Plan, PlanExtras)> {
+    ) -> Result<Arc<FlightStatement>> {
         let context = session
             .create_query_context()
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
 
         let mut planner = Planner::new(context.clone());
-        planner.plan_sql(query).await
+        let (plan, plan_extras) = planner.plan_sql(query).await?;
+        Ok(FlightStatement::create(session, plan, plan_extras))
     }
 
     #[async_backtrace::framed]
     pub(super) async fn execute_update(
         &self,
         session: Arc<Session>,
-        plan: &Plan,
-        plan_extras: &PlanExtras,
+        statement: &FlightStatement,
     ) -> Result<i64> {
         let context = session
             .create_query_context()
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
 
-        context.attach_query_str(plan.kind(), plan_extras.statement.to_mask_sql());
+        let plan = &statement.plan;
+        context.attach_query_str(plan.kind(), statement.plan_extras.statement.to_mask_sql());
+        statement.attach_query(&context);
         let interpreter = InterpreterFactory::get(context.clone(), plan).await?;
 
         let mut blocks = interpreter.execute(context.clone()).await?;
@@ -110,8 +144,7 @@ impl FlightSqlServiceImpl {
     pub async fn execute_query(
         &self,
         session: Arc<Session>,
-        plan: &Plan,
-        plan_extras: &PlanExtras,
+        statement: &FlightStatement,
     ) -> Result<DoGetStream> {
         let is_native_client = session.get_status().read().is_native_client;
 
@@ -120,7 +153,9 @@ impl FlightSqlServiceImpl {
             .await
             .map_err(|e| status!("Could not create_query_context", e))?;
 
-        context.attach_query_str(plan.kind(), plan_extras.statement.to_mask_sql());
+        let plan = &statement.plan;
+        context.attach_query_str(plan.kind(), statement.plan_extras.statement.to_mask_sql());
+        statement.attach_query(&context);
         let interpreter = InterpreterFactory::get(context.clone(), plan).await?;
 
         let data_schema = plan.schema();
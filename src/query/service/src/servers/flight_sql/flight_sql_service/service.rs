@@ -18,6 +18,7 @@ use std::time::Duration;
 
 use arrow_flight::flight_descriptor::DescriptorType;
 use arrow_flight::flight_service_server::FlightService;
+use arrow_flight::sql::action_end_transaction_request::EndTransaction;
 use arrow_flight::sql::server::FlightSqlService;
 use arrow_flight::sql::server::PeekableFlightDataStream;
 use arrow_flight::sql::ActionBeginSavepointRequest;
@@ -33,6 +34,7 @@ use arrow_flight::sql::ActionCreatePreparedSubstraitPlanRequest;
 use arrow_flight::sql::ActionEndSavepointRequest;
 use arrow_flight::sql::ActionEndTransactionRequest;
 use arrow_flight::sql::Any;
+use arrow_flight::sql::CancelResult;
 use arrow_flight::sql::CommandGetCatalogs;
 use arrow_flight::sql::CommandGetCrossReference;
 use arrow_flight::sql::CommandGetDbSchemas;
@@ -65,6 +67,8 @@ use arrow_flight::SchemaAsIpc;
 use arrow_flight::Ticket;
 use arrow_ipc::writer::IpcWriteOptions;
 use databend_common_base::base::uuid::Uuid;
+use databend_common_catalog::txn::TxnState;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataSchema;
 use futures::Stream;
@@ -93,6 +97,21 @@ fn try_unpack_any<T: ProstMessageExt>(message: Any) -> std::result::Result<T, St
         })
 }
 
+fn flight_info_with_schema<T: ProstMessageExt>(
+    message: T,
+    schema: &DataSchema,
+) -> Result<Response<FlightInfo>, Status> {
+    let arrow_schema = schema.into();
+    let ipc_message = SchemaAsIpc::new(&arrow_schema, &IpcWriteOptions::default())
+        .try_into()
+        .map_err(|e| status!("Unable to serialize schema", e))?;
+    let IpcMessage(schema_bytes) = ipc_message;
+
+    let mut info = simple_flight_info(message).into_inner();
+    info.schema = schema_bytes;
+    Ok(Response::new(info))
+}
+
 fn simple_flight_info<T: ProstMessageExt>(message: T) -> Response<FlightInfo> {
     let loc = Location {
         uri: "location_not_used".to_string(),
@@ -122,6 +141,20 @@ fn simple_flight_info<T: ProstMessageExt>(message: T) -> Response<FlightInfo> {
     Response::new(info)
 }
 
+impl FlightSqlServiceImpl {
+    /// The statement handle of a ticket returned by get_flight_info_statement or get_flight_info_prepared_statement.
+    fn decode_ticket_handle(ticket: &[u8]) -> Option<Uuid> {
+        let any = Any::decode(ticket).ok()?;
+        if let Ok(Some(ticket)) = any.unpack::<TicketStatementQuery>() {
+            return Uuid::from_slice(ticket.statement_handle.as_ref()).ok();
+        }
+        if let Ok(Some(fetch)) = any.unpack::<FetchResults>() {
+            return Uuid::try_parse(&fetch.handle).ok();
+        }
+        None
+    }
+}
+
 impl NamedService for FlightSqlServiceImpl {
     const NAME: &'static str = "FlightSqlService";
 }
@@ -192,9 +225,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         info!("do_get_fallback with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let statement = self.get_statement(&session, &handle)?;
         let stream = self
-            .execute_query(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_query(session, &statement)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let resp = Response::new(stream);
@@ -207,9 +240,20 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: CommandStatementQuery,
         request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        info!("get_flight_info_sql_info(query={})", query.query);
-        let _session = self.get_session(&request)?;
-        Ok(simple_flight_info(query))
+        info!("get_flight_info_statement(query={})", query.query);
+        let session = self.get_session(&request)?;
+        let handle = Uuid::new_v4();
+        let statement = self
+            .plan_sql(&session, &query.query)
+            .await
+            .map_err(|e| status!("Error getting result schema", e))?;
+        let schema = statement.plan.schema();
+        self.add_statement(handle, statement);
+
+        let ticket = TicketStatementQuery {
+            statement_handle: handle.as_bytes().to_vec().into(),
+        };
+        flight_info_with_schema(ticket, &schema)
     }
 
     #[async_backtrace::framed]
@@ -218,14 +262,14 @@ impl FlightSqlService for FlightSqlServiceImpl {
         cmd: CommandPreparedStatementQuery,
         request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        let _session = self.get_session(&request);
+        let session = self.get_session(&request)?;
         let handle = Uuid::from_slice(cmd.prepared_statement_handle.as_ref())
             .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
 
         info!("get_flight_info_prepared_statement with handle={handle}");
 
-        let handle_plan_ref = self.statements.get(&handle).unwrap();
-        let schema = handle_plan_ref.value().0.schema().as_ref().into();
+        let statement = self.get_statement(&session, &handle)?;
+        let schema = statement.plan.schema().as_ref().into();
         let loc = Location {
             uri: "grpc+tcp://127.0.0.1".to_string(),
         };
@@ -321,48 +365,44 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn get_flight_info_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        info!("get_flight_info_primary_keys({query:?})",);
-        Err(Status::unimplemented(
-            "get_flight_info_primary_keys not implemented",
-        ))
+        info!("get_flight_info_primary_keys({query:?})");
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_exported_keys(
         &self,
         query: CommandGetExportedKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_exported_keys({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_exported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_imported_keys(
         &self,
         query: CommandGetImportedKeys,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_imported_keys({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_imported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     #[async_backtrace::framed]
     async fn get_flight_info_cross_reference(
         &self,
         query: CommandGetCrossReference,
-        _request: Request<FlightDescriptor>,
+        request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
         info!("get_flight_info_cross_reference({query:?})");
-        Err(Status::unimplemented(
-            "get_flight_info_imported_keys not implemented",
-        ))
+        let _session = self.get_session(&request)?;
+        Ok(simple_flight_info(query))
     }
 
     // do_get
@@ -370,42 +410,81 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_statement(
         &self,
         ticket: TicketStatementQuery,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        info!("do_get_statement({ticket:?}");
-        Err(Status::unimplemented("do_get_statement not implemented"))
+        let session = self.get_session(&request)?;
+        let handle = Uuid::from_slice(ticket.statement_handle.as_ref())
+            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+
+        info!("do_get_statement with handle={handle}");
+
+        // The statement is only planned for this ticket, it is kept while the query runs
+        // so that the query can be cancelled by the ticket, and released once it is idle.
+        let statement = self.get_statement(&session, &handle)?;
+        let stream = self
+            .execute_query(session, &statement)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(Response::new(stream))
     }
 
     #[async_backtrace::framed]
     async fn do_get_prepared_statement(
         &self,
         query: CommandPreparedStatementQuery,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        info!("do_get_prepared_statement({query:?}");
-        Err(Status::unimplemented(
-            "do_get_prepared_statement not implemented",
-        ))
+        let session = self.get_session(&request)?;
+        let handle = Uuid::from_slice(query.prepared_statement_handle.as_ref())
+            .map_err(|e| Status::internal(format!("Error decoding handle: {e}")))?;
+
+        info!("do_get_prepared_statement with handle={handle}");
+
+        let statement = self.get_statement(&session, &handle)?;
+        let stream = self
+            .execute_query(session, &statement)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(Response::new(stream))
     }
 
     #[async_backtrace::framed]
     async fn do_get_catalogs(
         &self,
         _query: CommandGetCatalogs,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_catalogs()");
-        Err(Status::unimplemented("do_get_catalogs not implemented"))
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_catalogs(context).await?,
+        ))
     }
 
     #[async_backtrace::framed]
     async fn do_get_schemas(
         &self,
         query: CommandGetDbSchemas,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        info!("do_get_schemas({query:?}");
-        Err(Status::unimplemented("do_get_schemas not implemented"))
+        info!("do_get_schemas({query:?})");
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_schemas(
+                context,
+                query.catalog,
+                query.db_schema_filter_pattern,
+            )
+            .await?,
+        ))
     }
 
     #[async_backtrace::framed]
@@ -430,10 +509,11 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_table_types(
         &self,
         _query: CommandGetTableTypes,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_table_types()");
-        Err(Status::unimplemented("do_get_table_types not implemented"))
+        let _session = self.get_session(&request)?;
+        Ok(Response::new(super::CatalogInfoProvider::get_table_types()?))
     }
 
     #[async_backtrace::framed]
@@ -450,21 +530,45 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_primary_keys(
         &self,
         query: CommandGetPrimaryKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_primary_keys({query:?})");
-        Err(Status::unimplemented("do_get_primary_keys not implemented"))
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_primary_keys(
+                context,
+                query.catalog,
+                query.db_schema,
+                query.table,
+            )
+            .await?,
+        ))
     }
 
     #[async_backtrace::framed]
     async fn do_get_exported_keys(
         &self,
         query: CommandGetExportedKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_exported_keys({query:?})");
-        Err(Status::unimplemented(
-            "do_get_exported_keys not implemented",
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_foreign_keys(
+                context,
+                query.catalog,
+                Some((query.db_schema, query.table)),
+                None,
+            )
+            .await?,
         ))
     }
 
@@ -472,11 +576,22 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_imported_keys(
         &self,
         query: CommandGetImportedKeys,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_imported_keys({query:?})");
-        Err(Status::unimplemented(
-            "do_get_imported_keys not implemented",
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_foreign_keys(
+                context,
+                query.catalog,
+                None,
+                Some((query.db_schema, query.table)),
+            )
+            .await?,
         ))
     }
 
@@ -484,11 +599,22 @@ impl FlightSqlService for FlightSqlServiceImpl {
     async fn do_get_cross_reference(
         &self,
         query: CommandGetCrossReference,
-        _request: Request<Ticket>,
+        request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
         info!("do_get_cross_reference({query:?})");
-        Err(Status::unimplemented(
-            "do_get_cross_reference not implemented",
+        let session = self.get_session(&request)?;
+        let context = session
+            .create_query_context()
+            .await
+            .map_err(|e| status!("Could not create_query_context", e))?;
+        Ok(Response::new(
+            super::CatalogInfoProvider::get_foreign_keys(
+                context,
+                query.fk_catalog,
+                Some((query.pk_db_schema, query.pk_table)),
+                Some((query.fk_db_schema, query.fk_table)),
+            )
+            .await?,
         ))
     }
 
@@ -503,12 +629,12 @@ impl FlightSqlService for FlightSqlServiceImpl {
         let query = ticket.query;
         info!("do_put_statement_update with query = {query}");
 
-        let (plan, plan_extras) = self
+        let statement = self
             .plan_sql(&session, &query)
             .await
             .map_err(|e| status!("Error getting result schema", e))?;
         let res = self
-            .execute_update(session, &plan, &plan_extras)
+            .execute_update(session, &statement)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         Ok(res)
@@ -526,9 +652,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         info!("do_put_prepared_statement_query with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let statement = self.get_statement(&session, &handle)?;
         let record_count = self
-            .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_update(session, &statement)
             .await
             .map_err(|e| status!("fail to execute", e))?;
         let result = DoPutUpdateResult { record_count };
@@ -552,9 +678,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
 
         info!("do_put_prepared_statement_update with handle={handle}");
 
-        let handle_plan = self.statements.get(&handle).unwrap();
+        let statement = self.get_statement(&session, &handle)?;
         let res = self
-            .execute_update(session, &handle_plan.value().0, &handle_plan.value().1)
+            .execute_update(session, &statement)
             .await
             .map_err(|e| status!("fail to execute", e))?;
 
@@ -571,7 +697,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         let session = self.get_session(&request)?;
         let sql = query.query.clone();
         let handle = Uuid::new_v4();
-        let plan = self
+        let statement = self
             .plan_sql(&session, &sql)
             .await
             .map_err(|e| status!("Error getting result schema", e))?;
@@ -580,8 +706,8 @@ impl FlightSqlService for FlightSqlServiceImpl {
             query.query
         );
         // JDBC client use call put when schema.fields == 0
-        let data_schema = if plan.0.has_result_set() {
-            plan.0.schema()
+        let data_schema = if statement.plan.has_result_set() {
+            statement.plan.schema()
         } else {
             Arc::new(DataSchema::empty())
         };
@@ -590,7 +716,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
             query.query
         );
         let schema = (&*data_schema).into();
-        self.statements.insert(handle, plan);
+        self.add_statement(handle, statement);
         let message = SchemaAsIpc::new(&schema, &IpcWriteOptions::default())
             .try_into()
             .map_err(|e| status!("Unable to serialize schema", e))?;
@@ -609,25 +735,12 @@ impl FlightSqlService for FlightSqlServiceImpl {
         query: ActionClosePreparedStatementRequest,
         request: Request<Action>,
     ) -> Result<(), Status> {
-        let handle = query.prepared_statement_handle.as_ref();
-        if let Ok(handle) = std::str::from_utf8(handle) {
-            info!(
-                "do_action_close_prepared_statement with handle {:?}",
-                handle
-            );
-            match Uuid::try_parse(handle) {
-                Ok(handle) => {
-                    if self.get_session(&request).is_ok() {
-                        self.statements.remove(&handle);
-                    }
-                }
-                Err(e) => {
-                    return Err(Status::internal(format!(
-                        "do_action_close_prepared_statement Error decoding handle: {e} {handle:?}"
-                    )));
-                }
-            }
-        }
+        let session = self.get_session(&request)?;
+        let handle = Uuid::from_slice(query.prepared_statement_handle.as_ref())
+            .map_err(|e| Status::invalid_argument(format!("Error decoding handle: {e}")))?;
+        info!("do_action_close_prepared_statement with handle={handle}");
+
+        self.remove_statement(&session, &handle);
         Ok(())
     }
 
@@ -642,7 +755,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: CommandGetXdbcTypeInfo,
         _request: Request<FlightDescriptor>,
     ) -> Result<Response<FlightInfo>, Status> {
-        unimplemented!()
+        Err(Status::unimplemented(
+            "get_flight_info_xdbc_type_info not implemented",
+        ))
     }
 
     /// Get a FlightDataStream containing the data related to the supported XDBC types.
@@ -651,7 +766,9 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: CommandGetXdbcTypeInfo,
         _request: Request<Ticket>,
     ) -> Result<Response<<Self as FlightService>::DoGetStream>, Status> {
-        unimplemented!()
+        Err(Status::unimplemented(
+            "do_get_xdbc_type_info not implemented",
+        ))
     }
 
     async fn get_flight_info_substrait_plan(
@@ -659,7 +776,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: CommandStatementSubstraitPlan,
         _request: Request<FlightDescriptor>,
     ) -> std::result::Result<Response<FlightInfo>, Status> {
-        unimplemented!()
+        Err(Status::unimplemented("Substrait plan is not supported"))
     }
 
     async fn do_put_substrait_plan(
@@ -667,7 +784,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: CommandStatementSubstraitPlan,
         _request: Request<PeekableFlightDataStream>,
     ) -> std::result::Result<i64, Status> {
-        unimplemented!()
+        Err(Status::unimplemented("Substrait plan is not supported"))
     }
 
     async fn do_action_create_prepared_substrait_plan(
@@ -675,23 +792,62 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: ActionCreatePreparedSubstraitPlanRequest,
         _request: Request<Action>,
     ) -> std::result::Result<ActionCreatePreparedStatementResult, Status> {
-        unimplemented!()
+        Err(Status::unimplemented("Substrait plan is not supported"))
     }
 
     async fn do_action_begin_transaction(
         &self,
         _query: ActionBeginTransactionRequest,
-        _request: Request<Action>,
+        request: Request<Action>,
     ) -> std::result::Result<ActionBeginTransactionResult, Status> {
-        unimplemented!()
+        let session = self.get_session(&request)?;
+        let txn_mgr = session.txn_mgr();
+        let mut txn_mgr = txn_mgr.lock();
+        // A session runs one transaction at a time.
+        if !matches!(txn_mgr.state(), TxnState::AutoCommit) {
+            return Err(Status::failed_precondition(format!(
+                "transaction {} is already in progress",
+                txn_mgr.txn_id()
+            )));
+        }
+        txn_mgr.begin();
+        info!("do_action_begin_transaction({})", txn_mgr.txn_id());
+        Ok(ActionBeginTransactionResult {
+            transaction_id: txn_mgr.txn_id().to_string().into(),
+        })
     }
 
     async fn do_action_end_transaction(
         &self,
-        _query: ActionEndTransactionRequest,
-        _request: Request<Action>,
+        query: ActionEndTransactionRequest,
+        request: Request<Action>,
     ) -> std::result::Result<(), Status> {
-        unimplemented!()
+        let session = self.get_session(&request)?;
+        let txn_id = String::from_utf8_lossy(&query.transaction_id).to_string();
+        info!("do_action_end_transaction({txn_id}, {:?})", query.action());
+        {
+            let txn_mgr = session.txn_mgr();
+            let txn_mgr = txn_mgr.lock();
+            if matches!(txn_mgr.state(), TxnState::AutoCommit) || txn_mgr.txn_id() != txn_id {
+                return Err(Status::not_found(format!("unknown transaction {txn_id}")));
+            }
+        }
+
+        let sql = match query.action() {
+            EndTransaction::Commit => "COMMIT",
+            EndTransaction::Rollback => "ROLLBACK",
+            EndTransaction::Unspecified => {
+                return Err(Status::invalid_argument("the end action is unspecified"));
+            }
+        };
+        let statement = self
+            .plan_sql(&session, sql)
+            .await
+            .map_err(|e| status!("Error planning the end of transaction", e))?;
+        self.execute_update(session, &statement)
+            .await
+            .map_err(|e| status!("fail to execute", e))?;
+        Ok(())
     }
 
     async fn do_action_begin_savepoint(
@@ -699,7 +855,7 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: ActionBeginSavepointRequest,
         _request: Request<Action>,
     ) -> std::result::Result<ActionBeginSavepointResult, Status> {
-        unimplemented!()
+        Err(Status::unimplemented("Savepoint is not supported"))
     }
 
     async fn do_action_end_savepoint(
@@ -707,15 +863,38 @@ impl FlightSqlService for FlightSqlServiceImpl {
         _query: ActionEndSavepointRequest,
         _request: Request<Action>,
     ) -> std::result::Result<(), Status> {
-        unimplemented!()
+        Err(Status::unimplemented("Savepoint is not supported"))
     }
 
     async fn do_action_cancel_query(
         &self,
-        _query: ActionCancelQueryRequest,
-        _request: Request<Action>,
+        query: ActionCancelQueryRequest,
+        request: Request<Action>,
     ) -> std::result::Result<ActionCancelQueryResult, Status> {
-        unimplemented!()
+        let session = self.get_session(&request)?;
+        let info = FlightInfo::decode(query.info)
+            .map_err(|e| Status::invalid_argument(format!("Error decoding FlightInfo: {e}")))?;
+        info!("do_action_cancel_query({:?})", info.flight_descriptor);
+
+        // The tickets of the FlightInfo carry the handle of the statement whose query is cancelled.
+        let mut result = CancelResult::NotCancellable;
+        for ticket in info.endpoint.iter().filter_map(|e| e.ticket.as_ref()) {
+            let Some(handle) = Self::decode_ticket_handle(&ticket.ticket) else {
+                continue;
+            };
+            let Ok(statement) = self.get_statement(&session, &handle) else {
+                continue;
+            };
+            if let Some(ctx) = statement.running_query() {
+                ctx.kill(ErrorCode::AbortedQuery(
+                    "Aborted query, because the query was cancelled by the client",
+                ));
+                result = CancelResult::Cancelled;
+            }
+        }
+        Ok(ActionCancelQueryResult {
+            result: result.into(),
+        })
     }
 }
 
@@ -25,6 +25,7 @@ pub(crate) use execute_state::ExecuteState;
 pub use execute_state::ExecuteStateKind;
 pub(crate) use execute_state::Executor;
 pub use execute_state::Progresses;
+pub use expirable::Expirable;
 pub use expirable::ExpiringState;
 pub use expiring_map::ExpiringMap;
 pub use http_query::HttpQueryRequest;
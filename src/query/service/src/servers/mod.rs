@@ -24,9 +24,14 @@ pub use self::mysql::MySQLConnection;
 pub use self::mysql::MySQLFederated;
 pub use self::mysql::MySQLHandler;
 pub use self::mysql::MySQLTlsConfig;
+pub(crate) use self::postgres::pg_type_of;
+pub use self::postgres::PostgresFederated;
+pub use self::postgres::PostgresHandler;
+pub(crate) use self::postgres::PG_BUILTIN_TYPES;
 
 pub(crate) mod federated_helper;
 pub mod flight_sql;
 pub mod http;
 mod mysql;
+mod postgres;
 pub(crate) mod server;
@@ -15,6 +15,7 @@
 mod mysql_federated;
 mod mysql_handler;
 mod mysql_interactive_worker;
+mod mysql_prepared_statement;
 mod mysql_session;
 #[allow(clippy::unused_io_amount)]
 mod reject_connection;
@@ -23,6 +24,8 @@ mod writers;
 
 pub use self::mysql_federated::MySQLFederated;
 pub use self::mysql_handler::MySQLHandler;
+pub(crate) use self::mysql_prepared_statement::decode_decimal;
+pub(crate) use self::mysql_prepared_statement::parse_values_source;
 pub use self::mysql_session::MySQLConnection;
 pub use self::tls::MySQLTlsConfig;
 
@@ -172,10 +172,6 @@ impl MySQLFederated {
     fn federated_mixed_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
         #[ctor]
         static MIXED_RULES: Vec<(Regex, Option<(TableSchemaRef, DataBlock)>)> = vec![
-            // Txn.
-            (Regex::new("(?i)^(ROLLBACK(.*))").unwrap(), None),
-            (Regex::new("(?i)^(COMMIT(.*))").unwrap(), None),
-            (Regex::new("(?i)^(START(.*))").unwrap(), None),
             (Regex::new("(?i)^(SET NAMES(.*))").unwrap(), None),
             (Regex::new("(?i)^(SET character_set_results(.*))").unwrap(), None),
             (Regex::new("(?i)^(SET net_write_timeout(.*))").unwrap(), None),
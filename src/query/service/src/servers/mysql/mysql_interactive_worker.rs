@@ -12,9 +12,12 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use databend_common_ast::ast::Statement;
 use databend_common_base::base::convert_byte_size;
 use databend_common_base::base::convert_number_size;
 use databend_common_base::base::tokio::io::AsyncWrite;
@@ -23,12 +26,16 @@ use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_exception::ToErrorCode;
+use databend_common_expression::date_helper::TzFactory;
 use databend_common_expression::DataBlock;
 use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Scalar;
 use databend_common_expression::SendableDataBlockStream;
 use databend_common_io::prelude::FormatSettings;
 use databend_common_meta_app::principal::UserIdentity;
 use databend_common_metrics::mysql::*;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::PlanExtras;
 use databend_common_sql::Planner;
 use databend_common_users::CertifiedInfo;
 use databend_common_users::UserApiProvider;
@@ -48,6 +55,9 @@ use rand::RngCore;
 use crate::interpreters::Interpreter;
 use crate::interpreters::InterpreterFactory;
 use crate::interpreters::InterpreterQueryLog;
+use crate::servers::mysql::mysql_prepared_statement::CachedPlan;
+use crate::servers::mysql::mysql_prepared_statement::PreparedStatement;
+use crate::servers::mysql::writers::convert_schema;
 use crate::servers::mysql::writers::DFInitResultWriter;
 use crate::servers::mysql::writers::DFQueryResultWriter;
 use crate::servers::mysql::writers::ProgressReporter;
@@ -61,6 +71,8 @@ use crate::stream::DataBlockStream;
 
 struct InteractiveWorkerBase {
     session: Arc<Session>,
+    next_statement_id: u32,
+    prepared_statements: HashMap<u32, PreparedStatement>,
 }
 
 pub struct InteractiveWorker {
@@ -280,38 +292,137 @@ impl InteractiveWorkerBase {
     }
 
     #[async_backtrace::framed]
-    async fn do_prepare<W: AsyncWrite + Unpin>(
-        &mut self,
-        _: &str,
-        writer: StatementMetaWriter<'_, W>,
+    async fn do_prepare<'a, W: AsyncWrite + Unpin>(
+        &'a mut self,
+        query: &'a str,
+        writer: StatementMetaWriter<'a, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Prepare is not support in Databend.".as_bytes(),
-            )
-            .await?;
+        match self.prepare_statement(query).await {
+            Ok(statement) => {
+                self.next_statement_id = self.next_statement_id.wrapping_add(1);
+                let id = self.next_statement_id;
+                self.prepared_statements.insert(id, statement);
+
+                // The metadata written by `reply` must outlive the writer, so reply
+                // with the cached statement.
+                let statement = &self.prepared_statements[&id];
+                writer
+                    .reply(id, statement.params(), statement.columns())
+                    .await?;
+            }
+            Err(error) => {
+                error!("OnPrepare Error: {:?}", error);
+                writer
+                    .error(
+                        ErrorKind::ER_UNKNOWN_ERROR,
+                        error.display_with_sql(query).to_string().as_bytes(),
+                    )
+                    .await?;
+            }
+        }
         Ok(())
     }
 
+    // Parse the statement and plan it once with `NULL` parameters to describe the
+    // result set. Statements with placeholders may not be plannable before binding
+    // (e.g. `LIMIT ?`), the result set of those is only described on execute.
     #[async_backtrace::framed]
-    async fn do_execute<W: AsyncWrite + Unpin>(
+    async fn prepare_statement(&mut self, query: &str) -> Result<PreparedStatement> {
+        if let Some((schema, _)) = self.federated_server_command_check(query) {
+            return Ok(PreparedStatement::create_federated(
+                query,
+                convert_schema(&schema)?,
+            ));
+        }
+
+        let sql_dialect = self.session.get_settings().get_sql_dialect()?;
+        let mut statement = PreparedStatement::try_create(query, sql_dialect)?;
+        let Some(stmt) = statement.statement() else {
+            return Ok(statement);
+        };
+
+        let context = self.session.create_query_context().await?;
+        context.attach_placeholder_values(statement.null_params());
+        let mut planner = Planner::new(context);
+        match planner.plan_stmt(stmt.clone()).await {
+            Ok((plan, _)) => {
+                if plan.has_result_set() {
+                    statement.set_columns(convert_schema(&plan.schema())?);
+                }
+            }
+            Err(error) if statement.params().is_empty() => return Err(error),
+            Err(error) => {
+                info!(
+                    "Cannot describe prepared statement before binding, query: {}, cause: {}",
+                    query, error
+                );
+            }
+        }
+        Ok(statement)
+    }
+
+    #[async_backtrace::framed]
+    async fn do_execute<W: AsyncWrite + Send + Unpin>(
         &mut self,
-        _: u32,
-        _: ParamParser<'_>,
+        id: u32,
+        params: ParamParser<'_>,
         writer: QueryResultWriter<'_, W>,
     ) -> Result<()> {
-        writer
-            .error(
-                ErrorKind::ER_UNKNOWN_ERROR,
-                "Execute is not support in Databend.".as_bytes(),
-            )
-            .await?;
-        Ok(())
+        let mut writer = DFQueryResultWriter::create_binary(writer);
+
+        let instant = Instant::now();
+        let format = self.session.get_format_settings();
+        let write_result = match self.bind_statement(id, params) {
+            Err(error) => writer.write(Err(error), &format).await,
+            Ok((query, stmt, values)) => {
+                let query_result = match stmt {
+                    Some(stmt) => self.do_execute_statement(id, &query, stmt, values).await,
+                    None => self.do_query(&query).await,
+                }
+                .map_err(|err| err.display_with_sql(&query));
+
+                let mut write_result = writer.write(query_result, &format).await;
+                if let Err(cause) = write_result {
+                    let suffix = format!("(while in query {})", query);
+                    write_result = Err(cause.add_message_back(suffix));
+                }
+                write_result
+            }
+        };
+        observe_mysql_process_request_duration(instant.elapsed());
+
+        write_result
+    }
+
+    // Bind the parameters of COM_STMT_EXECUTE to the prepared statement, return the
+    // query, a copy of the parsed statement to plan and the values of its placeholders.
+    #[allow(clippy::type_complexity)]
+    fn bind_statement(
+        &self,
+        id: u32,
+        params: ParamParser<'_>,
+    ) -> Result<(String, Option<Statement>, BTreeMap<usize, Scalar>)> {
+        let Some(statement) = self.prepared_statements.get(&id) else {
+            return Err(ErrorCode::BadArguments(format!(
+                "Unknown prepared statement handler ({}) given to mysqld_stmt_execute",
+                id
+            )));
+        };
+
+        let tz = self.session.get_settings().get_timezone()?;
+        let tz = TzFactory::instance().get_by_name(&tz)?.tz;
+        let values = statement.bind(params, tz)?;
+        Ok((
+            statement.query().to_string(),
+            statement.statement().cloned(),
+            values,
+        ))
     }
 
     #[async_backtrace::framed]
-    async fn do_close(&mut self, _: u32) {}
+    async fn do_close(&mut self, id: u32) {
+        self.prepared_statements.remove(&id);
+    }
 
     // Check the query is a federated or driver setup command.
     // Here we fake some values for the command which Databend not supported.
@@ -356,34 +467,85 @@ impl InteractiveWorkerBase {
 
                 let mut planner = Planner::new(context.clone());
                 let (plan, extras) = planner.plan_sql(query).await?;
+                Self::exec_plan(context, plan, extras, query).await
+            }
+        }
+    }
 
-                context.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
-                let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
-
-                let has_result_set = plan.has_result_set();
-
-                match interpreter {
-                    Ok(interpreter) => {
-                        let (blocks, extra_info) =
-                            Self::exec_query(interpreter.clone(), &context).await?;
-                        let schema = plan.schema();
-                        let format = context.get_format_settings()?;
-                        Ok((
-                            QueryResult::create(
-                                blocks,
-                                extra_info,
-                                has_result_set,
-                                schema,
-                                query.to_string(),
-                            ),
-                            Some(format),
-                        ))
-                    }
-                    Err(e) => {
-                        InterpreterQueryLog::fail_to_start(context, e.clone());
-                        Err(e)
-                    }
+    #[async_backtrace::framed]
+    #[minitrace::trace]
+    async fn do_execute_statement(
+        &mut self,
+        id: u32,
+        query: &str,
+        stmt: Statement,
+        values: BTreeMap<usize, Scalar>,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        info!("Prepared query: {}", query);
+        let context = self.session.create_query_context().await?;
+        context.attach_placeholder_values(values.clone());
+
+        let database = self.session.get_current_database();
+        let role = self.session.get_current_role().map(|role| role.name);
+        let cached_plan = self
+            .prepared_statements
+            .get(&id)
+            .and_then(|statement| statement.cached_plan());
+        let cached = match cached_plan {
+            None => None,
+            Some(cached_plan) => {
+                cached_plan
+                    .get(&context, &values, &database, role.as_deref())
+                    .await
+            }
+        };
+
+        let (plan, extras) = match cached {
+            Some((plan, extras)) => (plan, extras),
+            None => {
+                let mut planner = Planner::new(context.clone());
+                let (plan, extras) = planner.plan_stmt(stmt).await?;
+                let cached_plan = CachedPlan::try_create(values, database, role, &plan, &extras);
+                if let Some(statement) = self.prepared_statements.get_mut(&id) {
+                    statement.set_cached_plan(cached_plan);
                 }
+                (plan, extras)
+            }
+        };
+        Self::exec_plan(context, plan, extras, query).await
+    }
+
+    #[async_backtrace::framed]
+    async fn exec_plan(
+        context: Arc<QueryContext>,
+        plan: Plan,
+        extras: PlanExtras,
+        query: &str,
+    ) -> Result<(QueryResult, Option<FormatSettings>)> {
+        context.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+        let interpreter = InterpreterFactory::get(context.clone(), &plan).await;
+
+        let has_result_set = plan.has_result_set();
+
+        match interpreter {
+            Ok(interpreter) => {
+                let (blocks, extra_info) = Self::exec_query(interpreter.clone(), &context).await?;
+                let schema = plan.schema();
+                let format = context.get_format_settings()?;
+                Ok((
+                    QueryResult::create(
+                        blocks,
+                        extra_info,
+                        has_result_set,
+                        schema,
+                        query.to_string(),
+                    ),
+                    Some(format),
+                ))
+            }
+            Err(e) => {
+                InterpreterQueryLog::fail_to_start(context, e.clone());
+                Err(e)
             }
         }
     }
@@ -457,7 +619,11 @@ impl InteractiveWorker {
         }
 
         InteractiveWorker {
-            base: InteractiveWorkerBase { session },
+            base: InteractiveWorkerBase {
+                session,
+                next_statement_id: 0,
+                prepared_statements: HashMap::new(),
+            },
             salt: scramble,
             version: format!("{}-{}", MYSQL_VERSION, *DATABEND_COMMIT_VERSION),
             client_addr,
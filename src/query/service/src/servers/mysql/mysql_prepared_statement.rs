@@ -0,0 +1,441 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use chrono::NaiveDate;
+use chrono::NaiveDateTime;
+use chrono::TimeZone;
+use chrono_tz::Tz;
+use databend_common_ast::ast::InsertSource;
+use databend_common_ast::ast::Query;
+use databend_common_ast::ast::SetExpr;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::parse_values;
+use databend_common_ast::parser::token::Token;
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::Dialect;
+use databend_common_ast::Visitor;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::Span;
+use databend_common_expression::serialize::read_decimal;
+use databend_common_expression::serialize::uniform_date;
+use databend_common_expression::types::decimal::Decimal;
+use databend_common_expression::types::decimal::DecimalScalar;
+use databend_common_expression::types::decimal::DecimalSize;
+use databend_common_expression::types::decimal::MAX_DECIMAL256_PRECISION;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::Scalar;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::PlanExtras;
+use ethnum::i256;
+use opensrv_mysql::Column;
+use opensrv_mysql::ColumnFlags;
+use opensrv_mysql::ColumnType;
+use opensrv_mysql::ParamParser;
+use opensrv_mysql::ValueInner;
+
+use crate::sessions::QueryContext;
+use crate::sessions::TableContext;
+
+/// A statement prepared by COM_STMT_PREPARE.
+///
+/// The query is parsed once at prepare time and the parsed statement is kept until
+/// COM_STMT_CLOSE. COM_STMT_EXECUTE converts the parameters into typed scalars, which
+/// the binder takes as the values of the `?` placeholders. The plan of the last
+/// execution of a query is kept, see [`CachedPlan`].
+pub struct PreparedStatement {
+    query: String,
+    // `None` if the query is answered by the MySQL federated checks.
+    statement: Option<Statement>,
+    // Start positions of the `?` placeholders in the query.
+    placeholders: Vec<usize>,
+    params: Vec<Column>,
+    columns: Vec<Column>,
+    cached_plan: Option<CachedPlan>,
+}
+
+/// The plan of a prepared query, reused by the executions with the same parameters.
+///
+/// A plan pins the versions of the tables it reads, it is stale once any of them has
+/// changed, e.g. by a write or a schema change. The plan is also bound to the current
+/// database and role, which resolve the names and the privileges.
+pub struct CachedPlan {
+    values: BTreeMap<usize, Scalar>,
+    database: String,
+    role: Option<String>,
+    // The catalog, database, name and version of the tables read by the plan.
+    tables: Vec<(String, String, String, TableIdent)>,
+    plan: Plan,
+    extras: PlanExtras,
+}
+
+impl CachedPlan {
+    /// Returns `None` if the plan can not be reused, only queries reading tables of
+    /// which the version is tracked by the catalog are cached.
+    pub fn try_create(
+        values: BTreeMap<usize, Scalar>,
+        database: String,
+        role: Option<String>,
+        plan: &Plan,
+        extras: &PlanExtras,
+    ) -> Option<CachedPlan> {
+        let Plan::Query { metadata, .. } = plan else {
+            return None;
+        };
+
+        let mut tables = vec![];
+        for entry in metadata.read().tables() {
+            let table = entry.table();
+            if table.is_stage_table() || table.table_args().is_some() {
+                return None;
+            }
+            tables.push((
+                entry.catalog().to_string(),
+                entry.database().to_string(),
+                entry.name().to_string(),
+                table.get_table_info().ident,
+            ));
+        }
+
+        Some(CachedPlan {
+            values,
+            database,
+            role,
+            tables,
+            plan: plan.clone(),
+            extras: extras.clone(),
+        })
+    }
+
+    /// Returns the plan if it was planned for the same parameters, database and role,
+    /// and none of the tables it reads has changed since.
+    pub async fn get(
+        &self,
+        ctx: &Arc<QueryContext>,
+        values: &BTreeMap<usize, Scalar>,
+        database: &str,
+        role: Option<&str>,
+    ) -> Option<(Plan, PlanExtras)> {
+        if &self.values != values || self.database != database || self.role.as_deref() != role {
+            return None;
+        }
+
+        for (catalog, database, name, ident) in self.tables.iter() {
+            match ctx.get_table(catalog, database, name).await {
+                Ok(table) if table.get_table_info().ident == *ident => {}
+                _ => return None,
+            }
+        }
+        Some((self.plan.clone(), self.extras.clone()))
+    }
+}
+
+impl PreparedStatement {
+    pub fn try_create(query: &str, dialect: Dialect) -> Result<PreparedStatement> {
+        let tokens = tokenize_sql(query)?;
+        let (mut statement, _) = parse_sql(&tokens, dialect)?;
+
+        if tokens
+            .iter()
+            .any(|token| token.kind == TokenKind::Placeholder)
+        {
+            match &mut statement {
+                Statement::Insert(insert) => {
+                    parse_values_source(&mut insert.source, &tokens, query, dialect)?
+                }
+                Statement::Replace(replace) => {
+                    parse_values_source(&mut replace.source, &tokens, query, dialect)?
+                }
+                _ => {}
+            }
+        }
+
+        // `?` is also the JSON operator testing whether a key exists, so the placeholders
+        // are collected from the parsed statement rather than from the tokens.
+        let mut visitor = PlaceholderVisitor::default();
+        visitor.visit_statement(&statement);
+        let mut placeholders = visitor.placeholders;
+        placeholders.sort();
+        placeholders.dedup();
+
+        Ok(PreparedStatement {
+            query: query.to_string(),
+            statement: Some(statement),
+            params: Self::param_columns(placeholders.len()),
+            placeholders,
+            columns: vec![],
+            cached_plan: None,
+        })
+    }
+
+    /// A prepared statement of a query answered by the MySQL federated checks, which
+    /// is executed as is.
+    pub fn create_federated(query: &str, columns: Vec<Column>) -> PreparedStatement {
+        PreparedStatement {
+            query: query.to_string(),
+            statement: None,
+            placeholders: vec![],
+            params: vec![],
+            columns,
+            cached_plan: None,
+        }
+    }
+
+    // MySQL can not infer the type of a placeholder either, so every parameter is
+    // reported as a string and the client sends its own type on execute.
+    fn param_columns(num_params: usize) -> Vec<Column> {
+        (0..num_params)
+            .map(|_| Column {
+                table: "".to_string(),
+                column: "?".to_string(),
+                coltype: ColumnType::MYSQL_TYPE_VAR_STRING,
+                colflags: ColumnFlags::empty(),
+            })
+            .collect()
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn statement(&self) -> Option<&Statement> {
+        self.statement.as_ref()
+    }
+
+    pub fn params(&self) -> &[Column] {
+        &self.params
+    }
+
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    pub fn set_columns(&mut self, columns: Vec<Column>) {
+        self.columns = columns;
+    }
+
+    pub fn cached_plan(&self) -> Option<&CachedPlan> {
+        self.cached_plan.as_ref()
+    }
+
+    pub fn set_cached_plan(&mut self, cached_plan: Option<CachedPlan>) {
+        self.cached_plan = cached_plan;
+    }
+
+    /// Values of the placeholders used at prepare time to plan the statement and
+    /// describe its result set.
+    pub fn null_params(&self) -> BTreeMap<usize, Scalar> {
+        self.placeholders
+            .iter()
+            .map(|position| (*position, Scalar::Null))
+            .collect()
+    }
+
+    /// Bind the parameters of COM_STMT_EXECUTE to the placeholders, timestamps are
+    /// interpreted in the time zone `tz` of the session.
+    pub fn bind(&self, params: ParamParser<'_>, tz: Tz) -> Result<BTreeMap<usize, Scalar>> {
+        let values = params
+            .into_iter()
+            .map(|param| param_to_scalar(param.value.into_inner(), param.coltype, tz))
+            .collect::<Result<Vec<_>>>()?;
+
+        if values.len() != self.placeholders.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Prepared statement expects {} parameters, but got {}",
+                self.placeholders.len(),
+                values.len()
+            )));
+        }
+
+        Ok(self.placeholders.iter().copied().zip(values).collect())
+    }
+}
+
+#[derive(Default)]
+struct PlaceholderVisitor {
+    placeholders: Vec<usize>,
+}
+
+impl<'ast> Visitor<'ast> for PlaceholderVisitor {
+    fn visit_placeholder(&mut self, span: Span) {
+        if let Some(span) = span {
+            self.placeholders.push(span.start());
+        }
+    }
+}
+
+/// The rows of `INSERT INTO ... VALUES` are kept as raw text by the parser and decoded
+/// while inserting, parse them into a `VALUES` query so that their placeholders are bound.
+pub(crate) fn parse_values_source(
+    source: &mut InsertSource,
+    tokens: &[Token],
+    query: &str,
+    dialect: Dialect,
+) -> Result<()> {
+    if let InsertSource::Values { start, .. } = source {
+        let start = *start;
+        let values_tokens = tokens
+            .iter()
+            .skip_while(|token| token.span.start() < start)
+            .cloned()
+            .collect::<Vec<_>>();
+        let span: Span = Some((start..query.len()).into());
+        *source = InsertSource::Select {
+            query: Box::new(Query {
+                span,
+                with: None,
+                body: SetExpr::Values {
+                    span,
+                    values: parse_values(&values_tokens, dialect)?,
+                },
+                order_by: vec![],
+                limit: vec![],
+                offset: None,
+                ignore_result: false,
+            }),
+        };
+    }
+    Ok(())
+}
+
+/// Convert a parameter of COM_STMT_EXECUTE into a typed scalar.
+fn param_to_scalar(value: ValueInner<'_>, coltype: ColumnType, tz: Tz) -> Result<Scalar> {
+    match value {
+        ValueInner::NULL => Ok(Scalar::Null),
+        ValueInner::Int(v) => Ok(Scalar::Number(NumberScalar::Int64(v))),
+        ValueInner::UInt(v) => Ok(Scalar::Number(NumberScalar::UInt64(v))),
+        ValueInner::Double(v) => Ok(Scalar::Number(NumberScalar::Float64(v.into()))),
+        ValueInner::Bytes(bytes) => match coltype {
+            ColumnType::MYSQL_TYPE_DECIMAL | ColumnType::MYSQL_TYPE_NEWDECIMAL => {
+                decode_decimal(bytes)
+            }
+            _ => match std::str::from_utf8(bytes) {
+                Ok(text) => Ok(Scalar::String(text.to_string())),
+                Err(_) => Ok(Scalar::Binary(bytes.to_vec())),
+            },
+        },
+        ValueInner::Date(bytes) => {
+            let datetime = decode_datetime(bytes)?;
+            Ok(Scalar::Date(uniform_date(datetime.date())))
+        }
+        ValueInner::Datetime(bytes) => {
+            let datetime = decode_datetime(bytes)?;
+            let timestamp = tz
+                .from_local_datetime(&datetime)
+                .earliest()
+                .ok_or_else(|| {
+                    ErrorCode::BadArguments(format!(
+                        "Invalid datetime parameter {datetime} in time zone {tz}"
+                    ))
+                })?;
+            Ok(Scalar::Timestamp(timestamp.timestamp_micros()))
+        }
+        ValueInner::Time(bytes) => Ok(Scalar::String(decode_time(bytes)?)),
+    }
+}
+
+/// Decode a decimal sent as text, like `MYSQL_TYPE_NEWDECIMAL` values.
+pub(crate) fn decode_decimal(bytes: &[u8]) -> Result<Scalar> {
+    let invalid = || {
+        ErrorCode::BadArguments(format!(
+            "Invalid decimal parameter: {}",
+            String::from_utf8_lossy(bytes)
+        ))
+    };
+
+    let (n, _, exp, n_read) = read_decimal::<i256>(bytes, MAX_DECIMAL256_PRECISION as u32, true)?;
+    if n_read != bytes.len() {
+        return Err(invalid());
+    }
+    let (value, scale) = if exp >= 0 {
+        let value = n.checked_mul(i256::e(exp as u32)).ok_or_else(invalid)?;
+        (value, 0)
+    } else {
+        (n, -exp)
+    };
+    if scale > MAX_DECIMAL256_PRECISION as i32 {
+        return Err(invalid());
+    }
+
+    Ok(Scalar::Decimal(DecimalScalar::Decimal256(
+        value,
+        DecimalSize {
+            precision: MAX_DECIMAL256_PRECISION,
+            scale: scale as u8,
+        },
+    )))
+}
+
+/// Decode a binary protocol `MYSQL_TYPE_DATE` / `MYSQL_TYPE_DATETIME` value.
+///
+/// The layout is `year(2) month(1) day(1) [hour(1) minute(1) second(1) [micros(4)]]`,
+/// where zero values may be omitted.
+fn decode_datetime(bytes: &[u8]) -> Result<NaiveDateTime> {
+    if !matches!(bytes.len(), 0 | 4 | 7 | 11) {
+        return Err(ErrorCode::BadBytes(format!(
+            "Invalid length {} of datetime parameter",
+            bytes.len()
+        )));
+    }
+
+    let mut buf = [0u8; 11];
+    buf[..bytes.len()].copy_from_slice(bytes);
+    let year = u16::from_le_bytes([buf[0], buf[1]]);
+    let micros = u32::from_le_bytes([buf[7], buf[8], buf[9], buf[10]]);
+    NaiveDate::from_ymd_opt(year as i32, buf[2] as u32, buf[3] as u32)
+        .and_then(|date| {
+            date.and_hms_micro_opt(buf[4] as u32, buf[5] as u32, buf[6] as u32, micros)
+        })
+        .ok_or_else(|| {
+            ErrorCode::BadArguments(format!(
+                "Invalid datetime parameter {:04}-{:02}-{:02} {:02}:{:02}:{:02}.{:06}",
+                year, buf[2], buf[3], buf[4], buf[5], buf[6], micros
+            ))
+        })
+}
+
+/// Decode a binary protocol `MYSQL_TYPE_TIME` value.
+///
+/// The layout is `is_negative(1) days(4) hour(1) minute(1) second(1) [micros(4)]`.
+fn decode_time(bytes: &[u8]) -> Result<String> {
+    if !matches!(bytes.len(), 0 | 8 | 12) {
+        return Err(ErrorCode::BadBytes(format!(
+            "Invalid length {} of time parameter",
+            bytes.len()
+        )));
+    }
+
+    let mut buf = [0u8; 12];
+    buf[..bytes.len()].copy_from_slice(bytes);
+    let sign = if buf[0] == 1 { "-" } else { "" };
+    let days = u32::from_le_bytes([buf[1], buf[2], buf[3], buf[4]]);
+    // The range of TIME is '-838:59:59' to '838:59:59', at most 34 days.
+    if days > 34 {
+        return Err(ErrorCode::BadArguments(format!(
+            "Invalid time parameter, {days} days is out of range"
+        )));
+    }
+    let hours = days * 24 + buf[5] as u32;
+    let micros = u32::from_le_bytes([buf[8], buf[9], buf[10], buf[11]]);
+    Ok(format!(
+        "{sign}{:02}:{:02}:{:02}.{:06}",
+        hours, buf[6], buf[7], micros
+    ))
+}
@@ -0,0 +1,180 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use ctor::ctor;
+use databend_common_config::DATABEND_COMMIT_VERSION;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt32Type;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRef;
+use databend_common_expression::TableSchemaRefExt;
+use regex::Regex;
+
+use crate::servers::federated_helper::FederatedHelper;
+use crate::servers::federated_helper::LazyBlockFunc;
+use crate::servers::postgres::POSTGRES_VERSION;
+
+/// Answers the session setup and catalog queries which PostgreSQL drivers and
+/// tools send on connect, and which Databend has no equivalent for.
+pub struct PostgresFederated {
+    current_database: String,
+}
+
+impl PostgresFederated {
+    pub fn create(current_database: String) -> Self {
+        PostgresFederated { current_database }
+    }
+
+    // Build block for select function.
+    // Format:
+    // |function_name|
+    // |value|
+    fn select_function_block(name: &str, value: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        let schema = TableSchemaRefExt::create(vec![TableField::new(name, TableDataType::String)]);
+        let block =
+            DataBlock::new_from_columns(vec![StringType::from_data(vec![value.to_string()])]);
+        Some((schema, block))
+    }
+
+    // Build block for SHOW <parameter>, the column is named by the parameter.
+    fn show_parameter_block(query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        let name = query
+            .trim()
+            .trim_end_matches(';')
+            .split_whitespace()
+            .skip(1)
+            .collect::<Vec<_>>()
+            .join(" ")
+            .to_lowercase();
+        let (name, value) = match name.as_str() {
+            "transaction isolation level"
+            | "transaction_isolation"
+            | "default_transaction_isolation" => ("transaction_isolation", "read committed"),
+            "transaction_read_only" | "default_transaction_read_only" => {
+                ("transaction_read_only", "off")
+            }
+            "server_version" => ("server_version", POSTGRES_VERSION),
+            "server_version_num" => ("server_version_num", "140000"),
+            "server_encoding" => ("server_encoding", "UTF8"),
+            "client_encoding" => ("client_encoding", "UTF8"),
+            "datestyle" => ("DateStyle", "ISO, MDY"),
+            "intervalstyle" => ("IntervalStyle", "postgres"),
+            "integer_datetimes" => ("integer_datetimes", "on"),
+            "standard_conforming_strings" => ("standard_conforming_strings", "on"),
+            "search_path" => ("search_path", "\"$user\", public"),
+            "max_identifier_length" => ("max_identifier_length", "63"),
+            "lc_collate" => ("lc_collate", "C"),
+            _ => return None,
+        };
+        Self::select_function_block(name, value)
+    }
+
+    // Build an empty block of `pg_type`, no extension types are installed.
+    fn pg_type_block(columns: &[&str]) -> Option<(TableSchemaRef, DataBlock)> {
+        let schema = TableSchemaRefExt::create(
+            columns
+                .iter()
+                .map(|name| TableField::new(name, TableDataType::Number(NumberDataType::UInt32)))
+                .collect(),
+        );
+        let block = DataBlock::new_from_columns(
+            columns
+                .iter()
+                .map(|_| UInt32Type::from_data(vec![]))
+                .collect(),
+        );
+        Some((schema, block))
+    }
+
+    // Check SHOW <parameter>.
+    fn federated_show_parameter_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        #[ctor]
+        static SHOW_PARAMETER_LAZY_RULES: Vec<(Regex, LazyBlockFunc)> = vec![(
+            Regex::new("(?i)^(SHOW (transaction isolation level|transaction_isolation|default_transaction_isolation|transaction_read_only|default_transaction_read_only|server_version|server_version_num|server_encoding|client_encoding|DateStyle|IntervalStyle|integer_datetimes|standard_conforming_strings|search_path|max_identifier_length|lc_collate)\\s*;?\\s*)$").unwrap(),
+            PostgresFederated::show_parameter_block,
+        )];
+
+        FederatedHelper::lazy_block_match_rule(query, &SHOW_PARAMETER_LAZY_RULES)
+    }
+
+    // Check the select of functions which need the session.
+    fn federated_select_function_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        #[ctor]
+        static SELECT_VERSION: Regex =
+            Regex::new("(?i)^(SELECT (pg_catalog\\.)?version\\(\\)\\s*;?\\s*)$").unwrap();
+        #[ctor]
+        static SELECT_CURRENT_SCHEMA: Regex =
+            Regex::new("(?i)^(SELECT (pg_catalog\\.)?current_schema\\(\\)\\s*;?\\s*)$").unwrap();
+
+        if SELECT_VERSION.is_match(query) {
+            let version = format!(
+                "PostgreSQL {} (Databend Query {})",
+                POSTGRES_VERSION, *DATABEND_COMMIT_VERSION
+            );
+            return Self::select_function_block("version", &version);
+        }
+        if SELECT_CURRENT_SCHEMA.is_match(query) {
+            // Databend has no schemas, the database is the closest namespace.
+            return Self::select_function_block("current_schema", &self.current_database);
+        }
+        None
+    }
+
+    // Check for SET or others query, this is the final check of the federated query.
+    fn federated_mixed_check(&self, query: &str) -> Option<(TableSchemaRef, DataBlock)> {
+        #[ctor]
+        static MIXED_RULES: Vec<(Regex, Option<(TableSchemaRef, DataBlock)>)> = vec![
+            (Regex::new("(?i)^(DISCARD ALL(.*))").unwrap(), None),
+            // Session parameters of libpq based clients, JDBC and psqlODBC.
+            (Regex::new("(?i)^(SET (SESSION |LOCAL )?(client_encoding|DateStyle|IntervalStyle|extra_float_digits|application_name|search_path|standard_conforming_strings|statement_timeout|lock_timeout|idle_in_transaction_session_timeout|bytea_output|client_min_messages|TRANSACTION|SESSION CHARACTERISTICS)\\b(.*))").unwrap(), None),
+            (
+                // psqlODBC (Tableau) looks up the large object type.
+                Regex::new("(?i)^(select oid, typbasetype from pg_type where typname = 'lo'(.*))").unwrap(),
+                PostgresFederated::pg_type_block(&["oid", "typbasetype"]),
+            ),
+        ];
+
+        FederatedHelper::block_match_rule(query, &MIXED_RULES)
+    }
+
+    // Check the query is a federated or driver setup command.
+    // Here we fake some values for the command which Databend not supported.
+    pub fn check(&self, query: &str) -> Option<(DataSchemaRef, DataBlock)> {
+        let show_parameter = self
+            .federated_show_parameter_check(query)
+            .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk));
+        if show_parameter.is_some() {
+            return show_parameter;
+        }
+
+        let select_function = self
+            .federated_select_function_check(query)
+            .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk));
+        if select_function.is_some() {
+            return select_function;
+        }
+
+        // Last check.
+        self.federated_mixed_check(query)
+            .map(|(schema, chunk)| (Arc::new(DataSchema::from(schema)), chunk))
+    }
+}
@@ -0,0 +1,739 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono_tz::Tz;
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_base::base::tokio::io::AsyncRead;
+use databend_common_base::base::tokio::io::AsyncWrite;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_catalog::txn::TxnState;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_exception::ToErrorCode;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Scalar;
+use databend_common_expression::SendableDataBlockStream;
+use databend_common_meta_app::principal::AuthType;
+use databend_common_meta_app::principal::UserIdentity;
+use databend_common_sql::plans::Plan;
+use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
+use futures_util::StreamExt;
+use log::error;
+use log::info;
+use log::warn;
+use rand::Rng;
+
+use crate::auth::AuthMgr;
+use crate::auth::Credential;
+use crate::interpreters::Interpreter;
+use crate::interpreters::InterpreterFactory;
+use crate::interpreters::InterpreterQueryLog;
+use crate::servers::postgres::postgres_message::FrontendMessage;
+use crate::servers::postgres::postgres_message::MessageWriter;
+use crate::servers::postgres::postgres_message::StartupMessage;
+use crate::servers::postgres::postgres_message::MAX_STARTUP_MESSAGE_LEN;
+use crate::servers::postgres::postgres_message::PROTOCOL_VERSION_3;
+use crate::servers::postgres::postgres_prepared_statement::PreparedStatement;
+use crate::servers::postgres::postgres_types::field_description;
+use crate::servers::postgres::postgres_types::FieldDescription;
+use crate::servers::postgres::postgres_types::ValueEncoder;
+use crate::servers::postgres::postgres_types::FORMAT_TEXT;
+use crate::servers::postgres::PostgresFederated;
+use crate::servers::postgres::POSTGRES_VERSION;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::TableContext;
+use crate::stream::DataBlockStream;
+
+/// A query which is ready to execute.
+enum PlannedQuery {
+    Empty,
+    Federated(DataSchemaRef, DataBlock),
+    Plan(Arc<QueryContext>, Box<Plan>),
+}
+
+impl PlannedQuery {
+    /// The schema of the result set, None if the query has no result set.
+    fn result_schema(&self) -> Option<DataSchemaRef> {
+        match self {
+            PlannedQuery::Empty => None,
+            PlannedQuery::Federated(schema, _) if schema.fields().is_empty() => None,
+            PlannedQuery::Federated(schema, _) => Some(schema.clone()),
+            PlannedQuery::Plan(_, plan) if plan.has_result_set() => Some(plan.schema()),
+            PlannedQuery::Plan(..) => None,
+        }
+    }
+}
+
+/// A prepared statement with bound parameters, created by Bind.
+struct Portal {
+    query: String,
+    planned: Option<PlannedQuery>,
+    result_formats: Vec<i16>,
+}
+
+pub struct InteractiveWorker<R: AsyncRead + Unpin, W: AsyncWrite + Unpin> {
+    session: Arc<Session>,
+    client_ip: String,
+    reader: R,
+    writer: MessageWriter<W>,
+    // The max size of a message after the authentication.
+    max_message_size: usize,
+    statements: HashMap<String, PreparedStatement>,
+    portals: HashMap<String, Portal>,
+    // After an error of the extended query protocol, messages are discarded until Sync.
+    ignore_till_sync: bool,
+}
+
+impl<R: AsyncRead + Unpin, W: AsyncWrite + Unpin> InteractiveWorker<R, W> {
+    pub fn create(
+        session: Arc<Session>,
+        client_ip: String,
+        reader: R,
+        writer: W,
+        max_message_size: usize,
+    ) -> Self {
+        InteractiveWorker {
+            session,
+            client_ip,
+            reader,
+            writer: MessageWriter::create(writer),
+            max_message_size,
+            statements: HashMap::new(),
+            portals: HashMap::new(),
+            ignore_till_sync: false,
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn run(mut self) -> Result<()> {
+        if !self.startup().await? {
+            return Ok(());
+        }
+
+        while let Some(message) =
+            FrontendMessage::read(&mut self.reader, self.max_message_size).await?
+        {
+            if self.session.is_aborting() {
+                self.writer.error_response(
+                    "FATAL",
+                    "57P01",
+                    "Aborting this connection. because we are try aborting server.",
+                );
+                self.writer.flush().await?;
+                return Err(ErrorCode::AbortedSession(
+                    "Aborting this connection. because we are try aborting server.",
+                ));
+            }
+
+            match message {
+                FrontendMessage::Terminate => return Ok(()),
+                FrontendMessage::Sync => {
+                    self.ignore_till_sync = false;
+                    self.writer.ready_for_query(self.txn_status());
+                    self.writer.flush().await?;
+                }
+                FrontendMessage::Flush => self.writer.flush().await?,
+                _ if self.ignore_till_sync => {}
+                FrontendMessage::Query(query) => {
+                    if let Err(cause) = self.on_query(&query).await {
+                        self.write_error(&cause);
+                    }
+                    self.writer.ready_for_query(self.txn_status());
+                    self.writer.flush().await?;
+                }
+                message => {
+                    if let Err(cause) = self.on_extended_query(message).await {
+                        self.write_error(&cause);
+                        self.ignore_till_sync = true;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The transaction status reported by ReadyForQuery.
+    fn txn_status(&self) -> u8 {
+        match self.session.txn_mgr().lock().state() {
+            TxnState::AutoCommit => b'I',
+            TxnState::Active => b'T',
+            TxnState::Fail => b'E',
+        }
+    }
+
+    /// Handle the startup phase, returns false if the connection should be closed.
+    #[async_backtrace::framed]
+    async fn startup(&mut self) -> Result<bool> {
+        let params = loop {
+            match StartupMessage::read(&mut self.reader).await? {
+                // Neither SSL nor GSSAPI encryption is supported, the client may go on
+                // with an unencrypted connection.
+                StartupMessage::SslRequest | StartupMessage::GssEncRequest => {
+                    self.writer.not_supported();
+                    self.writer.flush().await?;
+                }
+                // Queries are cancelled with KILL QUERY, the connection of a cancel
+                // request is closed without a response like PostgreSQL does.
+                StartupMessage::CancelRequest { .. } => return Ok(false),
+                StartupMessage::Startup {
+                    protocol_version,
+                    params,
+                } => {
+                    if protocol_version != PROTOCOL_VERSION_3 {
+                        let message = format!(
+                            "Unsupported frontend protocol {}.{}: server supports 3.0",
+                            protocol_version >> 16,
+                            protocol_version & 0xFFFF
+                        );
+                        self.writer.error_response("FATAL", "0A000", &message);
+                        self.writer.flush().await?;
+                        return Ok(false);
+                    }
+                    break params;
+                }
+            }
+        };
+
+        let user = params.get("user").cloned().unwrap_or_default();
+        if let Err(cause) = self.authenticate(&user).await {
+            error!(
+                "PostgreSQL handler authenticate failed, \
+                    user_name: {}, \
+                    client_address: {}, \
+                    failure_cause: {}",
+                user, self.client_ip, cause
+            );
+            let message = format!("password authentication failed for user \"{user}\"");
+            self.writer.error_response("FATAL", "28P01", &message);
+            self.writer.flush().await?;
+            return Ok(false);
+        }
+
+        // libpq defaults the database to the user name, and the postgres database is
+        // where tools connect to by default, so only switch to the other databases.
+        let database = params.get("database").cloned().unwrap_or_default();
+        if !database.is_empty() && database != user && database != "postgres" {
+            let query = format!("USE `{}`", database.replace('`', "``"));
+            if let Err(cause) = self.on_query(&query).await {
+                self.writer
+                    .error_response("FATAL", "3D000", &cause.message());
+                self.writer.flush().await?;
+                return Ok(false);
+            }
+            // Drop the CommandComplete of the USE statement.
+            self.writer.discard();
+        }
+
+        let timezone = self.session.get_settings().get_timezone()?;
+        self.writer.authentication_ok();
+        for (name, value) in [
+            ("server_version", POSTGRES_VERSION),
+            ("server_encoding", "UTF8"),
+            ("client_encoding", "UTF8"),
+            ("DateStyle", "ISO, MDY"),
+            ("IntervalStyle", "postgres"),
+            ("TimeZone", timezone.as_str()),
+            ("integer_datetimes", "on"),
+            ("standard_conforming_strings", "on"),
+            ("is_superuser", "off"),
+        ] {
+            self.writer.parameter_status(name, value);
+        }
+        let (process_id, secret_key) = {
+            let mut rng = rand::thread_rng();
+            (rng.gen(), rng.gen())
+        };
+        self.writer.backend_key_data(process_id, secret_key);
+        self.writer.ready_for_query(self.txn_status());
+        self.writer.flush().await?;
+        Ok(true)
+    }
+
+    /// Map the auth type of the user onto the password exchange of PostgreSQL:
+    /// the users without password are trusted, the others are asked for a cleartext
+    /// password, which is the token for the users authenticated by JWT.
+    #[async_backtrace::framed]
+    async fn authenticate(&mut self, user: &str) -> Result<()> {
+        let tenant = self.session.get_current_tenant();
+        let identity = UserIdentity::new(user, "%");
+        let client_ip = Some(self.client_ip.clone());
+        let auth_type = match UserApiProvider::instance()
+            .get_user_with_client_ip(&tenant, identity, client_ip.as_deref())
+            .await
+        {
+            Ok(user_info) => Some(user_info.auth_info.get_type()),
+            // The user may still be created by a JWT with `ensure_user`.
+            Err(cause) if cause.code() == ErrorCode::UNKNOWN_USER => None,
+            Err(cause) => return Err(cause),
+        };
+
+        let credential = match auth_type {
+            Some(AuthType::NoPassword) => Credential::Password {
+                name: user.to_string(),
+                password: None,
+                client_ip,
+            },
+            Some(AuthType::JWT) => Credential::Jwt {
+                token: String::from_utf8(self.read_password().await?)?,
+                client_ip,
+            },
+            None => {
+                let password = self.read_password().await?;
+                match String::from_utf8(password) {
+                    Ok(token) if token.split('.').count() == 3 => {
+                        Credential::Jwt { token, client_ip }
+                    }
+                    Ok(password) => Credential::Password {
+                        name: user.to_string(),
+                        password: Some(password.into_bytes()),
+                        client_ip,
+                    },
+                    Err(cause) => Credential::Password {
+                        name: user.to_string(),
+                        password: Some(cause.into_bytes()),
+                        client_ip,
+                    },
+                }
+            }
+            Some(_) => Credential::Password {
+                name: user.to_string(),
+                password: Some(self.read_password().await?),
+                client_ip,
+            },
+        };
+
+        AuthMgr::instance()
+            .auth(self.session.clone(), &credential)
+            .await
+    }
+
+    #[async_backtrace::framed]
+    async fn read_password(&mut self) -> Result<Vec<u8>> {
+        self.writer.authentication_cleartext_password();
+        self.writer.flush().await?;
+        match FrontendMessage::read(&mut self.reader, MAX_STARTUP_MESSAGE_LEN).await? {
+            Some(FrontendMessage::Password(password)) => Ok(password),
+            _ => Err(ErrorCode::AuthenticateFailure(
+                "Expected password response from client",
+            )),
+        }
+    }
+
+    /// Simple query protocol, the statements of the query are executed one by one
+    /// until the first error.
+    #[async_backtrace::framed]
+    async fn on_query(&mut self, query: &str) -> Result<()> {
+        let statements = split_statements(query)?;
+        if statements.is_empty() {
+            self.writer.empty_query_response();
+            return Ok(());
+        }
+
+        for statement in statements {
+            let planned = self.plan_query(statement).await?;
+            let fields = match planned.result_schema() {
+                None => vec![],
+                Some(schema) => describe_fields(&schema, &[])?,
+            };
+            if !fields.is_empty() {
+                self.writer.row_description(&fields);
+            }
+            self.execute_query(statement, planned, &fields).await?;
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn on_extended_query(&mut self, message: FrontendMessage) -> Result<()> {
+        match message {
+            FrontendMessage::Parse {
+                name,
+                query,
+                param_types,
+            } => {
+                if !name.is_empty() && self.statements.contains_key(&name) {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Prepared statement \"{name}\" already exists"
+                    )));
+                }
+                let statement = match self.plan_as_is(&query)? {
+                    Some(_) => PreparedStatement::create_federated(&query),
+                    None => {
+                        let dialect = self.session.get_settings().get_sql_dialect()?;
+                        PreparedStatement::try_create(&query, param_types, dialect)?
+                    }
+                };
+                self.statements.insert(name, statement);
+                self.writer.parse_complete();
+            }
+            FrontendMessage::Bind {
+                portal,
+                statement,
+                param_formats,
+                params,
+                result_formats,
+            } => {
+                let statement = self.get_statement(&statement)?;
+                let query = statement.query().to_string();
+                let params = statement.bind(&param_formats, &params)?;
+                let planned = self.plan_prepared(statement, params).await?;
+                if let Some(schema) = planned.result_schema() {
+                    // Check the result formats early, the rows are sent by Execute.
+                    describe_fields(&schema, &result_formats)?;
+                }
+                self.portals.insert(portal, Portal {
+                    query,
+                    planned: Some(planned),
+                    result_formats,
+                });
+                self.writer.bind_complete();
+            }
+            FrontendMessage::Describe { kind: b'S', name } => {
+                let statement = self.get_statement(&name)?;
+                let param_types = statement.param_types();
+                // The result set of a statement is described by planning it with NULL
+                // parameters, which is not always a valid query.
+                let schema = match self.plan_prepared(statement, statement.null_params()).await {
+                    Ok(planned) => planned.result_schema(),
+                    Err(_) if statement.has_params() => None,
+                    Err(cause) => return Err(cause),
+                };
+                self.writer.parameter_description(&param_types);
+                match schema {
+                    None => self.writer.no_data(),
+                    Some(schema) => self.writer.row_description(&describe_fields(&schema, &[])?),
+                }
+            }
+            FrontendMessage::Describe { kind: b'P', name } => {
+                let portal = self.get_portal(&name)?;
+                let schema = portal.planned.as_ref().and_then(|p| p.result_schema());
+                let fields = match schema {
+                    None => vec![],
+                    Some(schema) => describe_fields(&schema, &portal.result_formats)?,
+                };
+                match fields.is_empty() {
+                    true => self.writer.no_data(),
+                    false => self.writer.row_description(&fields),
+                }
+            }
+            FrontendMessage::Describe { kind, .. } => {
+                return Err(ErrorCode::BadBytes(format!(
+                    "Invalid DESCRIBE message subtype {kind}"
+                )));
+            }
+            // The rows of a portal are sent all at once, `max_rows` is not supported.
+            FrontendMessage::Execute { portal, .. } => {
+                let portal = self.portals.get_mut(&portal).ok_or_else(|| {
+                    ErrorCode::BadArguments(format!("Portal \"{portal}\" does not exist"))
+                })?;
+                let query = portal.query.clone();
+                let planned = portal
+                    .planned
+                    .take()
+                    .ok_or_else(|| ErrorCode::BadArguments("Portal has already been executed"))?;
+                let fields = match planned.result_schema() {
+                    None => vec![],
+                    Some(schema) => describe_fields(&schema, &portal.result_formats)?,
+                };
+                self.execute_query(&query, planned, &fields).await?;
+            }
+            FrontendMessage::Close { kind, name } => {
+                match kind {
+                    b'S' => {
+                        self.statements.remove(&name);
+                    }
+                    b'P' => {
+                        self.portals.remove(&name);
+                    }
+                    _ => {
+                        return Err(ErrorCode::BadBytes(format!(
+                            "Invalid CLOSE message subtype {kind}"
+                        )));
+                    }
+                }
+                self.writer.close_complete();
+            }
+            FrontendMessage::Password(_) => {
+                return Err(ErrorCode::BadBytes("Unexpected password message"));
+            }
+            FrontendMessage::Unsupported(tag) => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Unsupported frontend message type '{}'",
+                    tag as char
+                )));
+            }
+            FrontendMessage::Query(_)
+            | FrontendMessage::Sync
+            | FrontendMessage::Flush
+            | FrontendMessage::Terminate => unreachable!("handled by run"),
+        }
+        Ok(())
+    }
+
+    fn get_statement(&self, name: &str) -> Result<&PreparedStatement> {
+        self.statements.get(name).ok_or_else(|| {
+            ErrorCode::BadArguments(format!("Prepared statement \"{name}\" does not exist"))
+        })
+    }
+
+    fn get_portal(&self, name: &str) -> Result<&Portal> {
+        self.portals
+            .get(name)
+            .ok_or_else(|| ErrorCode::BadArguments(format!("Portal \"{name}\" does not exist")))
+    }
+
+    /// Plan the empty queries and the queries answered by the federated checks.
+    fn plan_as_is(&self, query: &str) -> Result<Option<PlannedQuery>> {
+        if split_statements(query)?.is_empty() {
+            return Ok(Some(PlannedQuery::Empty));
+        }
+
+        let federated = PostgresFederated::create(self.session.get_current_database());
+        if let Some((schema, data_block)) = federated.check(query.trim()) {
+            info!("Federated query: {}", query);
+            return Ok(Some(PlannedQuery::Federated(schema, data_block)));
+        }
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn plan_query(&self, query: &str) -> Result<PlannedQuery> {
+        if let Some(planned) = self.plan_as_is(query)? {
+            return Ok(planned);
+        }
+
+        info!("Normal query: {}", query);
+        let context = self.session.create_query_context().await?;
+        let mut planner = Planner::new(context.clone());
+        let (plan, extras) = planner.plan_sql(query).await?;
+        context.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+        Ok(PlannedQuery::Plan(context, Box::new(plan)))
+    }
+
+    /// Plan a prepared statement with the values of its parameters.
+    #[async_backtrace::framed]
+    async fn plan_prepared(
+        &self,
+        statement: &PreparedStatement,
+        params: BTreeMap<usize, Scalar>,
+    ) -> Result<PlannedQuery> {
+        let Some(stmt) = statement.statement() else {
+            return self.plan_query(statement.query()).await;
+        };
+
+        info!("Prepared query: {}", statement.query());
+        let context = self.session.create_query_context().await?;
+        context.attach_placeholder_values(params);
+        let mut planner = Planner::new(context.clone());
+        let (plan, extras) = planner.plan_stmt(stmt.clone()).await?;
+        context.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+        Ok(PlannedQuery::Plan(context, Box::new(plan)))
+    }
+
+    /// Execute the query, and write the rows and CommandComplete of it.
+    #[async_backtrace::framed]
+    async fn execute_query(
+        &mut self,
+        query: &str,
+        planned: PlannedQuery,
+        fields: &[FieldDescription],
+    ) -> Result<()> {
+        let (context, blocks, timezone) = match planned {
+            PlannedQuery::Empty => {
+                self.writer.empty_query_response();
+                return Ok(());
+            }
+            PlannedQuery::Federated(_, data_block) => {
+                let blocks = DataBlockStream::create(None, vec![data_block]).boxed();
+                (None, blocks, Tz::UTC)
+            }
+            PlannedQuery::Plan(context, plan) => {
+                let interpreter = match InterpreterFactory::get(context.clone(), &plan).await {
+                    Ok(interpreter) => interpreter,
+                    Err(cause) => {
+                        InterpreterQueryLog::fail_to_start(context, cause.clone());
+                        return Err(cause);
+                    }
+                };
+
+                let blocks = context
+                    .try_spawn(context.get_id(), {
+                        let ctx = context.clone();
+                        async move { interpreter.execute(ctx).await }
+                    })?
+                    .await
+                    .map_err_to_code(
+                        ErrorCode::TokioError,
+                        || "Cannot join handle from context's runtime",
+                    )??;
+                let timezone = context.get_format_settings()?.timezone;
+                (Some(context), blocks, timezone)
+            }
+        };
+
+        let rows = self.write_rows(blocks, fields, timezone).await?;
+        let written_rows = context
+            .map(|context| context.get_write_progress_value().rows)
+            .unwrap_or_default();
+        self.writer
+            .command_complete(&command_tag(query, !fields.is_empty(), rows, written_rows));
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn write_rows(
+        &mut self,
+        mut blocks: SendableDataBlockStream,
+        fields: &[FieldDescription],
+        timezone: Tz,
+    ) -> Result<usize> {
+        let encoder = ValueEncoder::create(timezone);
+        let mut buf = Vec::new();
+        let mut rows = 0;
+
+        // For statements without result sets, we still need to pull the stream because
+        // errors may occur in the stream.
+        while let Some(block) = blocks.next().await {
+            let block = block?;
+            if fields.is_empty() {
+                continue;
+            }
+
+            let columns = block
+                .convert_to_full()
+                .columns()
+                .iter()
+                .map(|column| column.value.clone().into_column().unwrap())
+                .collect::<Vec<_>>();
+
+            for row_index in 0..block.num_rows() {
+                let start = self.writer.begin_data_row(columns.len());
+                for (column, field) in columns.iter().zip(fields.iter()) {
+                    buf.clear();
+                    match encoder.encode(column, row_index, field.format, &mut buf)? {
+                        true => self.writer.data_row_value(Some(&buf)),
+                        false => self.writer.data_row_value(None),
+                    }
+                }
+                self.writer.end_data_row(start);
+                self.writer.flush_if_full().await?;
+            }
+            rows += block.num_rows();
+        }
+        Ok(rows)
+    }
+
+    fn write_error(&mut self, cause: &ErrorCode) {
+        warn!("PostgreSQL handler query failed: {}", cause);
+        // Like PostgreSQL, any error aborts the transaction block, not only
+        // the errors raised while executing the statement.
+        self.session.txn_mgr().lock().set_fail();
+        self.writer
+            .error_response("ERROR", sqlstate(cause), &cause.message());
+    }
+}
+
+/// Split a query of the simple query protocol into statements.
+fn split_statements(query: &str) -> Result<Vec<&str>> {
+    let mut statements = vec![];
+    let mut start = 0;
+    for token in tokenize_sql(query)? {
+        if matches!(token.kind, TokenKind::SemiColon | TokenKind::EOI) {
+            let end = token.span.start as usize;
+            if start < end && !query[start..end].trim().is_empty() {
+                statements.push(&query[start..end]);
+            }
+            start = token.span.end as usize;
+        }
+    }
+    Ok(statements)
+}
+
+/// Describe the result columns, `formats` is the result format codes of Bind.
+fn describe_fields(schema: &DataSchemaRef, formats: &[i16]) -> Result<Vec<FieldDescription>> {
+    let fields = schema.fields();
+    if formats.len() > 1 && formats.len() != fields.len() {
+        return Err(ErrorCode::BadArguments(format!(
+            "Bind message has {} result formats but query has {} columns",
+            formats.len(),
+            fields.len()
+        )));
+    }
+
+    fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| {
+            // No format codes means text, a single one applies to all columns.
+            let format = match formats.len() {
+                0 => FORMAT_TEXT,
+                1 => formats[0],
+                _ => formats[index],
+            };
+            field_description(field, format)
+        })
+        .collect()
+}
+
+/// The tag of CommandComplete, clients such as psycopg take the affected rows from it.
+fn command_tag(query: &str, has_result_set: bool, rows: usize, written_rows: usize) -> String {
+    if has_result_set {
+        return format!("SELECT {rows}");
+    }
+
+    let words = query
+        .split_whitespace()
+        .map(|word| word.trim_end_matches(';').to_uppercase())
+        .filter(|word| !matches!(word.as_str(), "OR" | "REPLACE"))
+        .take(2)
+        .collect::<Vec<_>>();
+    match words.first().map(|word| word.as_str()).unwrap_or_default() {
+        "INSERT" | "REPLACE" => format!("INSERT 0 {written_rows}"),
+        "UPDATE" | "DELETE" | "MERGE" | "COPY" => format!("{} {written_rows}", words[0]),
+        "CREATE" | "DROP" | "ALTER" => words.join(" "),
+        "START" => "BEGIN".to_string(),
+        "END" => "COMMIT".to_string(),
+        "ABORT" => "ROLLBACK".to_string(),
+        word => word.to_string(),
+    }
+}
+
+fn sqlstate(cause: &ErrorCode) -> &'static str {
+    match cause.code() {
+        ErrorCode::SYNTAX_EXCEPTION => "42601",
+        ErrorCode::UNKNOWN_DATABASE => "3D000",
+        ErrorCode::UNKNOWN_TABLE => "42P01",
+        ErrorCode::UNKNOWN_COLUMN => "42703",
+        ErrorCode::DATABASE_ALREADY_EXISTS => "42P04",
+        ErrorCode::TABLE_ALREADY_EXISTS => "42P07",
+        ErrorCode::PERMISSION_DENIED => "42501",
+        ErrorCode::AUTHENTICATE_FAILURE => "28P01",
+        ErrorCode::ABORTED_QUERY => "57014",
+        ErrorCode::BAD_ARGUMENTS => "22023",
+        ErrorCode::UNIMPLEMENTED => "0A000",
+        ErrorCode::CURRENT_TRANSACTION_IS_ABORTED => "25P02",
+        ErrorCode::STATEMENT_NOT_ALLOWED_IN_TRANSACTION => "25001",
+        ErrorCode::TABLE_VERSION_MISMATCHED => "40001",
+        _ => "XX000",
+    }
+}
@@ -0,0 +1,430 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Messages of the PostgreSQL frontend/backend protocol version 3.0, see
+//! https://www.postgresql.org/docs/current/protocol-message-formats.html
+
+use std::collections::HashMap;
+
+use databend_common_base::base::tokio::io::AsyncRead;
+use databend_common_base::base::tokio::io::AsyncReadExt;
+use databend_common_base::base::tokio::io::AsyncWrite;
+use databend_common_base::base::tokio::io::AsyncWriteExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::servers::postgres::postgres_types::FieldDescription;
+
+pub const PROTOCOL_VERSION_3: i32 = 196608;
+const SSL_REQUEST_CODE: i32 = 80877103;
+const GSSENC_REQUEST_CODE: i32 = 80877104;
+const CANCEL_REQUEST_CODE: i32 = 80877102;
+
+// Same as the limit of PostgreSQL for messages before the authentication, the limit
+// after it is configured by `postgres_handler_max_message_size`.
+pub const MAX_STARTUP_MESSAGE_LEN: usize = 10_000;
+
+// The size of write buffer which is flushed during a result set: 100KB
+const DEFAULT_WRITE_BUFFER_SIZE: usize = 100 * 1024;
+
+pub enum StartupMessage {
+    SslRequest,
+    GssEncRequest,
+    CancelRequest {
+        process_id: i32,
+        secret_key: i32,
+    },
+    Startup {
+        protocol_version: i32,
+        params: HashMap<String, String>,
+    },
+}
+
+pub enum FrontendMessage {
+    Query(String),
+    Parse {
+        name: String,
+        query: String,
+        param_types: Vec<u32>,
+    },
+    Bind {
+        portal: String,
+        statement: String,
+        param_formats: Vec<i16>,
+        params: Vec<Option<Vec<u8>>>,
+        result_formats: Vec<i16>,
+    },
+    Describe {
+        kind: u8,
+        name: String,
+    },
+    Execute {
+        portal: String,
+        max_rows: i32,
+    },
+    Close {
+        kind: u8,
+        name: String,
+    },
+    Password(Vec<u8>),
+    Sync,
+    Flush,
+    Terminate,
+    Unsupported(u8),
+}
+
+/// A cursor over the body of a message.
+struct MessageBody {
+    data: Vec<u8>,
+    offset: usize,
+}
+
+impl MessageBody {
+    fn get_bytes(&mut self, len: usize) -> Result<&[u8]> {
+        if self.data.len() - self.offset < len {
+            return Err(ErrorCode::BadBytes("Unexpected end of message"));
+        }
+        let bytes = &self.data[self.offset..self.offset + len];
+        self.offset += len;
+        Ok(bytes)
+    }
+
+    fn get_u8(&mut self) -> Result<u8> {
+        Ok(self.get_bytes(1)?[0])
+    }
+
+    fn get_i16(&mut self) -> Result<i16> {
+        let bytes = self.get_bytes(2)?;
+        Ok(i16::from_be_bytes([bytes[0], bytes[1]]))
+    }
+
+    fn get_i32(&mut self) -> Result<i32> {
+        let bytes = self.get_bytes(4)?;
+        Ok(i32::from_be_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+    }
+
+    fn get_cstr(&mut self) -> Result<String> {
+        let rest = &self.data[self.offset..];
+        match rest.iter().position(|b| *b == 0) {
+            None => Err(ErrorCode::BadBytes("Unterminated string in message")),
+            Some(len) => {
+                let value = std::str::from_utf8(&rest[..len])?.to_string();
+                self.offset += len + 1;
+                Ok(value)
+            }
+        }
+    }
+
+    fn get_i16_array(&mut self) -> Result<Vec<i16>> {
+        let len = self.get_i16()?;
+        (0..len).map(|_| self.get_i16()).collect()
+    }
+}
+
+async fn read_body<R: AsyncRead + Unpin>(reader: &mut R, len: usize) -> Result<MessageBody> {
+    let mut data = vec![0; len];
+    reader.read_exact(&mut data).await?;
+    Ok(MessageBody { data, offset: 0 })
+}
+
+async fn read_len<R: AsyncRead + Unpin>(reader: &mut R, max_len: usize) -> Result<usize> {
+    let len = reader.read_i32().await?;
+    if len < 4 || len as usize - 4 > max_len {
+        return Err(ErrorCode::BadBytes(format!("Invalid message length {len}")));
+    }
+    Ok(len as usize - 4)
+}
+
+impl StartupMessage {
+    pub async fn read<R: AsyncRead + Unpin>(reader: &mut R) -> Result<StartupMessage> {
+        let len = read_len(reader, MAX_STARTUP_MESSAGE_LEN).await?;
+        let mut body = read_body(reader, len).await?;
+        match body.get_i32()? {
+            SSL_REQUEST_CODE => Ok(StartupMessage::SslRequest),
+            GSSENC_REQUEST_CODE => Ok(StartupMessage::GssEncRequest),
+            CANCEL_REQUEST_CODE => Ok(StartupMessage::CancelRequest {
+                process_id: body.get_i32()?,
+                secret_key: body.get_i32()?,
+            }),
+            protocol_version => {
+                let mut params = HashMap::new();
+                loop {
+                    let name = body.get_cstr()?;
+                    if name.is_empty() {
+                        break;
+                    }
+                    params.insert(name, body.get_cstr()?);
+                }
+                Ok(StartupMessage::Startup {
+                    protocol_version,
+                    params,
+                })
+            }
+        }
+    }
+}
+
+impl FrontendMessage {
+    /// Read the next message, returns None if the client has closed the connection.
+    pub async fn read<R: AsyncRead + Unpin>(
+        reader: &mut R,
+        max_len: usize,
+    ) -> Result<Option<FrontendMessage>> {
+        let tag = match reader.read_u8().await {
+            Ok(tag) => tag,
+            Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let len = read_len(reader, max_len).await?;
+        let mut body = read_body(reader, len).await?;
+
+        let message = match tag {
+            b'Q' => FrontendMessage::Query(body.get_cstr()?),
+            b'P' => {
+                let name = body.get_cstr()?;
+                let query = body.get_cstr()?;
+                let num_types = body.get_i16()?;
+                let param_types = (0..num_types)
+                    .map(|_| body.get_i32().map(|oid| oid as u32))
+                    .collect::<Result<Vec<_>>>()?;
+                FrontendMessage::Parse {
+                    name,
+                    query,
+                    param_types,
+                }
+            }
+            b'B' => {
+                let portal = body.get_cstr()?;
+                let statement = body.get_cstr()?;
+                let param_formats = body.get_i16_array()?;
+                let num_params = body.get_i16()?;
+                let mut params = Vec::with_capacity(num_params.max(0) as usize);
+                for _ in 0..num_params {
+                    let len = body.get_i32()?;
+                    match len < 0 {
+                        true => params.push(None),
+                        false => params.push(Some(body.get_bytes(len as usize)?.to_vec())),
+                    }
+                }
+                let result_formats = body.get_i16_array()?;
+                FrontendMessage::Bind {
+                    portal,
+                    statement,
+                    param_formats,
+                    params,
+                    result_formats,
+                }
+            }
+            b'D' => FrontendMessage::Describe {
+                kind: body.get_u8()?,
+                name: body.get_cstr()?,
+            },
+            b'E' => FrontendMessage::Execute {
+                portal: body.get_cstr()?,
+                max_rows: body.get_i32()?,
+            },
+            b'C' => FrontendMessage::Close {
+                kind: body.get_u8()?,
+                name: body.get_cstr()?,
+            },
+            b'p' => {
+                // The password is null-terminated.
+                let mut password = body.data;
+                if password.last() == Some(&0) {
+                    password.pop();
+                }
+                FrontendMessage::Password(password)
+            }
+            b'S' => FrontendMessage::Sync,
+            b'H' => FrontendMessage::Flush,
+            b'X' => FrontendMessage::Terminate,
+            tag => FrontendMessage::Unsupported(tag),
+        };
+        Ok(Some(message))
+    }
+}
+
+/// Buffers the backend messages and writes them to the client on flush.
+pub struct MessageWriter<W: AsyncWrite + Unpin> {
+    inner: W,
+    buf: Vec<u8>,
+}
+
+impl<W: AsyncWrite + Unpin> MessageWriter<W> {
+    pub fn create(inner: W) -> MessageWriter<W> {
+        MessageWriter {
+            inner,
+            buf: Vec::with_capacity(DEFAULT_WRITE_BUFFER_SIZE),
+        }
+    }
+
+    pub async fn flush(&mut self) -> Result<()> {
+        self.inner.write_all(&self.buf).await?;
+        self.inner.flush().await?;
+        self.buf.clear();
+        Ok(())
+    }
+
+    /// Drop the messages which are not flushed yet.
+    pub fn discard(&mut self) {
+        self.buf.clear();
+    }
+
+    /// Flush the buffer if it is full, used when writing the rows of a result set.
+    pub async fn flush_if_full(&mut self) -> Result<()> {
+        if self.buf.len() >= DEFAULT_WRITE_BUFFER_SIZE {
+            self.flush().await?;
+        }
+        Ok(())
+    }
+
+    /// Write a message, the content is filled by `f`.
+    fn message(&mut self, tag: u8, f: impl FnOnce(&mut Vec<u8>)) {
+        self.buf.push(tag);
+        let start = self.buf.len();
+        self.buf.extend_from_slice(&[0; 4]);
+        f(&mut self.buf);
+        let len = (self.buf.len() - start) as i32;
+        self.buf[start..start + 4].copy_from_slice(&len.to_be_bytes());
+    }
+
+    /// The answer to SSLRequest and GSSENCRequest, which is a single byte.
+    pub fn not_supported(&mut self) {
+        self.buf.push(b'N');
+    }
+
+    pub fn authentication_ok(&mut self) {
+        self.message(b'R', |buf| buf.extend_from_slice(&0_i32.to_be_bytes()));
+    }
+
+    pub fn authentication_cleartext_password(&mut self) {
+        self.message(b'R', |buf| buf.extend_from_slice(&3_i32.to_be_bytes()));
+    }
+
+    pub fn parameter_status(&mut self, name: &str, value: &str) {
+        self.message(b'S', |buf| {
+            put_cstr(buf, name);
+            put_cstr(buf, value);
+        });
+    }
+
+    pub fn backend_key_data(&mut self, process_id: i32, secret_key: i32) {
+        self.message(b'K', |buf| {
+            buf.extend_from_slice(&process_id.to_be_bytes());
+            buf.extend_from_slice(&secret_key.to_be_bytes());
+        });
+    }
+
+    /// ReadyForQuery, the status is one of `I` (idle), `T` (in a transaction block)
+    /// or `E` (in a failed transaction block).
+    pub fn ready_for_query(&mut self, status: u8) {
+        self.message(b'Z', |buf| buf.push(status));
+    }
+
+    pub fn row_description(&mut self, fields: &[FieldDescription]) {
+        self.message(b'T', |buf| {
+            buf.extend_from_slice(&(fields.len() as i16).to_be_bytes());
+            for field in fields {
+                put_cstr(buf, &field.name);
+                // Table OID and column attribute number.
+                buf.extend_from_slice(&0_i32.to_be_bytes());
+                buf.extend_from_slice(&0_i16.to_be_bytes());
+                buf.extend_from_slice(&field.type_oid.to_be_bytes());
+                buf.extend_from_slice(&field.type_size.to_be_bytes());
+                buf.extend_from_slice(&field.type_modifier.to_be_bytes());
+                buf.extend_from_slice(&field.format.to_be_bytes());
+            }
+        });
+    }
+
+    /// Begin a DataRow of `num_columns`, the values are appended by `data_row_value`.
+    pub fn begin_data_row(&mut self, num_columns: usize) -> usize {
+        self.buf.push(b'D');
+        let start = self.buf.len();
+        self.buf.extend_from_slice(&[0; 4]);
+        self.buf
+            .extend_from_slice(&(num_columns as i16).to_be_bytes());
+        start
+    }
+
+    /// Append a value of the current DataRow, `None` is NULL.
+    pub fn data_row_value(&mut self, value: Option<&[u8]>) {
+        match value {
+            None => self.buf.extend_from_slice(&(-1_i32).to_be_bytes()),
+            Some(value) => {
+                self.buf
+                    .extend_from_slice(&(value.len() as i32).to_be_bytes());
+                self.buf.extend_from_slice(value);
+            }
+        }
+    }
+
+    pub fn end_data_row(&mut self, start: usize) {
+        let len = (self.buf.len() - start) as i32;
+        self.buf[start..start + 4].copy_from_slice(&len.to_be_bytes());
+    }
+
+    pub fn command_complete(&mut self, tag: &str) {
+        self.message(b'C', |buf| put_cstr(buf, tag));
+    }
+
+    pub fn empty_query_response(&mut self) {
+        self.message(b'I', |_| {});
+    }
+
+    pub fn error_response(&mut self, severity: &str, sqlstate: &str, message: &str) {
+        self.message(b'E', |buf| {
+            buf.push(b'S');
+            put_cstr(buf, severity);
+            buf.push(b'V');
+            put_cstr(buf, severity);
+            buf.push(b'C');
+            put_cstr(buf, sqlstate);
+            buf.push(b'M');
+            put_cstr(buf, message);
+            buf.push(0);
+        });
+    }
+
+    pub fn parse_complete(&mut self) {
+        self.message(b'1', |_| {});
+    }
+
+    pub fn bind_complete(&mut self) {
+        self.message(b'2', |_| {});
+    }
+
+    pub fn close_complete(&mut self) {
+        self.message(b'3', |_| {});
+    }
+
+    pub fn no_data(&mut self) {
+        self.message(b'n', |_| {});
+    }
+
+    pub fn parameter_description(&mut self, param_types: &[u32]) {
+        self.message(b't', |buf| {
+            buf.extend_from_slice(&(param_types.len() as i16).to_be_bytes());
+            for oid in param_types {
+                buf.extend_from_slice(&oid.to_be_bytes());
+            }
+        });
+    }
+}
+
+fn put_cstr(buf: &mut Vec<u8>, value: &str) {
+    // Strings of the protocol are null-terminated, so they can't contain a null.
+    buf.extend(value.bytes().filter(|b| *b != 0));
+    buf.push(0);
+}
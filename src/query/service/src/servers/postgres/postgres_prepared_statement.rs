@@ -0,0 +1,183 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::token::TokenKind;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::Dialect;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::Scalar;
+
+use crate::servers::mysql::parse_values_source;
+use crate::servers::postgres::postgres_types::param_to_scalar;
+use crate::servers::postgres::postgres_types::FORMAT_TEXT;
+use crate::servers::postgres::postgres_types::TEXT_OID;
+
+/// A statement created by the Parse message of the extended query protocol.
+///
+/// The query is parsed once by Parse. Bind converts the parameters into typed scalars,
+/// which the binder takes as the values of the `$n` parameters.
+pub struct PreparedStatement {
+    query: String,
+    // `None` if the query is empty or answered by the federated checks.
+    statement: Option<Statement>,
+    // The start position and the zero-based index of every parameter reference.
+    placeholders: Vec<(usize, usize)>,
+    // The type OIDs of the parameters, 0 if the client left it unspecified.
+    param_types: Vec<u32>,
+}
+
+impl PreparedStatement {
+    pub fn try_create(
+        query: &str,
+        mut param_types: Vec<u32>,
+        dialect: Dialect,
+    ) -> Result<PreparedStatement> {
+        let tokens = tokenize_sql(query)?;
+        let mut placeholders = vec![];
+        for token in tokens.iter() {
+            if token.kind != TokenKind::ColumnPosition {
+                continue;
+            }
+
+            let index = match token.text()[1..].parse::<usize>() {
+                Ok(position) if position > 0 => position - 1,
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "There is no parameter {}",
+                        token.text()
+                    )));
+                }
+            };
+            placeholders.push((token.span.start(), index));
+        }
+
+        // Like PostgreSQL, the number of parameters is the max of the declared types
+        // and the highest parameter referenced.
+        let num_params = placeholders
+            .iter()
+            .map(|(_, index)| index + 1)
+            .max()
+            .unwrap_or_default()
+            .max(param_types.len());
+        param_types.resize(num_params, 0);
+
+        let (mut statement, _) = parse_sql(&tokens, dialect)?;
+        if !placeholders.is_empty() {
+            match &mut statement {
+                Statement::Insert(insert) => {
+                    parse_values_source(&mut insert.source, &tokens, query, dialect)?
+                }
+                Statement::Replace(replace) => {
+                    parse_values_source(&mut replace.source, &tokens, query, dialect)?
+                }
+                _ => {}
+            }
+        }
+
+        Ok(PreparedStatement {
+            query: query.to_string(),
+            statement: Some(statement),
+            placeholders,
+            param_types,
+        })
+    }
+
+    /// A prepared statement of an empty query or a query answered by the federated
+    /// checks, which is planned as is.
+    pub fn create_federated(query: &str) -> PreparedStatement {
+        PreparedStatement {
+            query: query.to_string(),
+            statement: None,
+            placeholders: vec![],
+            param_types: vec![],
+        }
+    }
+
+    pub fn query(&self) -> &str {
+        &self.query
+    }
+
+    pub fn statement(&self) -> Option<&Statement> {
+        self.statement.as_ref()
+    }
+
+    /// The parameter types reported by ParameterDescription, unspecified ones are
+    /// reported as text so that clients send them in the text format.
+    pub fn param_types(&self) -> Vec<u32> {
+        self.param_types
+            .iter()
+            .map(|oid| if *oid == 0 { TEXT_OID } else { *oid })
+            .collect()
+    }
+
+    pub fn has_params(&self) -> bool {
+        !self.param_types.is_empty()
+    }
+
+    /// Values of the parameters used by Describe to plan the statement and describe
+    /// its result set.
+    pub fn null_params(&self) -> BTreeMap<usize, Scalar> {
+        self.placeholders
+            .iter()
+            .map(|(position, _)| (*position, Scalar::Null))
+            .collect()
+    }
+
+    /// Bind the parameters of a Bind message to the parameter references.
+    pub fn bind(
+        &self,
+        formats: &[i16],
+        params: &[Option<Vec<u8>>],
+    ) -> Result<BTreeMap<usize, Scalar>> {
+        if params.len() != self.param_types.len() {
+            return Err(ErrorCode::BadArguments(format!(
+                "Bind message supplies {} parameters, but prepared statement requires {}",
+                params.len(),
+                self.param_types.len()
+            )));
+        }
+
+        let values = params
+            .iter()
+            .zip(self.param_types.iter())
+            .enumerate()
+            .map(|(index, (param, type_oid))| {
+                // No format codes means text, a single one applies to all parameters.
+                let format = match formats.len() {
+                    0 => FORMAT_TEXT,
+                    1 => formats[0],
+                    _ => formats.get(index).copied().ok_or_else(|| {
+                        ErrorCode::BadArguments(format!(
+                            "Bind message has {} parameter formats but {} parameters",
+                            formats.len(),
+                            params.len()
+                        ))
+                    })?,
+                };
+                param_to_scalar(param.as_deref(), *type_oid, format)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(self
+            .placeholders
+            .iter()
+            .map(|(position, index)| (*position, values[*index].clone()))
+            .collect())
+    }
+}
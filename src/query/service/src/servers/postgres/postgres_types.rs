@@ -0,0 +1,336 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono_tz::Tz;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::date_helper::DateConverter;
+use databend_common_expression::types::number::NumberScalar;
+use databend_common_expression::types::DataType;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::Column;
+use databend_common_expression::DataField;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_common_formats::field_encoder::FieldEncoderValues;
+
+use crate::servers::mysql::decode_decimal;
+
+// Type OIDs of the builtin types, see `pg_type.dat` of PostgreSQL.
+pub const BOOL_OID: u32 = 16;
+pub const BYTEA_OID: u32 = 17;
+pub const INT8_OID: u32 = 20;
+pub const INT2_OID: u32 = 21;
+pub const INT4_OID: u32 = 23;
+pub const TEXT_OID: u32 = 25;
+pub const FLOAT4_OID: u32 = 700;
+pub const FLOAT8_OID: u32 = 701;
+pub const UNKNOWN_OID: u32 = 705;
+pub const VARCHAR_OID: u32 = 1043;
+pub const DATE_OID: u32 = 1082;
+pub const TIMESTAMP_OID: u32 = 1114;
+pub const TIMESTAMPTZ_OID: u32 = 1184;
+pub const NUMERIC_OID: u32 = 1700;
+pub const JSON_OID: u32 = 114;
+pub const JSONB_OID: u32 = 3802;
+
+/// The OIDs, names and sizes of the builtin types listed by `pg_catalog.pg_type`.
+pub const PG_BUILTIN_TYPES: &[(u32, &str, i16)] = &[
+    (BOOL_OID, "bool", 1),
+    (BYTEA_OID, "bytea", -1),
+    (INT8_OID, "int8", 8),
+    (INT2_OID, "int2", 2),
+    (INT4_OID, "int4", 4),
+    (TEXT_OID, "text", -1),
+    (JSON_OID, "json", -1),
+    (FLOAT4_OID, "float4", 4),
+    (FLOAT8_OID, "float8", 8),
+    (UNKNOWN_OID, "unknown", -2),
+    (VARCHAR_OID, "varchar", -1),
+    (DATE_OID, "date", 4),
+    (TIMESTAMP_OID, "timestamp", 8),
+    (TIMESTAMPTZ_OID, "timestamptz", 8),
+    (NUMERIC_OID, "numeric", -1),
+    (JSONB_OID, "jsonb", -1),
+];
+
+pub const FORMAT_TEXT: i16 = 0;
+pub const FORMAT_BINARY: i16 = 1;
+
+// Days and microseconds between the unix epoch and the PostgreSQL epoch (2000-01-01).
+const PG_EPOCH_DAYS: i32 = 10_957;
+const PG_EPOCH_MICROS: i64 = 946_684_800_000_000;
+
+/// The description of a result column, as sent in RowDescription.
+pub struct FieldDescription {
+    pub name: String,
+    pub type_oid: u32,
+    pub type_size: i16,
+    pub type_modifier: i32,
+    pub format: i16,
+}
+
+/// Map a Databend type to the OID, size and modifier of the PostgreSQL type the
+/// values are reported as.
+///
+/// Unsigned integers are widened to the next signed type, and the nested types are
+/// reported as text since their text form is not the PostgreSQL array/record form.
+pub fn pg_type_of(data_type: &DataType) -> (u32, i16, i32) {
+    match data_type.remove_nullable() {
+        DataType::Boolean => (BOOL_OID, 1, -1),
+        DataType::Binary => (BYTEA_OID, -1, -1),
+        DataType::String => (VARCHAR_OID, -1, -1),
+        DataType::Number(num_ty) => match num_ty {
+            NumberDataType::Int8 | NumberDataType::Int16 | NumberDataType::UInt8 => {
+                (INT2_OID, 2, -1)
+            }
+            NumberDataType::Int32 | NumberDataType::UInt16 => (INT4_OID, 4, -1),
+            NumberDataType::Int64 | NumberDataType::UInt32 => (INT8_OID, 8, -1),
+            NumberDataType::UInt64 => (NUMERIC_OID, -1, -1),
+            NumberDataType::Float32 => (FLOAT4_OID, 4, -1),
+            NumberDataType::Float64 => (FLOAT8_OID, 8, -1),
+        },
+        DataType::Decimal(decimal) => {
+            let size = decimal.size();
+            let modifier = ((size.precision as i32) << 16 | size.scale as i32) + 4;
+            (NUMERIC_OID, -1, modifier)
+        }
+        DataType::Date => (DATE_OID, 4, -1),
+        DataType::Timestamp => (TIMESTAMP_OID, 8, -1),
+        DataType::Variant => (JSONB_OID, -1, -1),
+        _ => (TEXT_OID, -1, -1),
+    }
+}
+
+pub fn field_description(field: &DataField, format: i16) -> Result<FieldDescription> {
+    let (type_oid, type_size, type_modifier) = pg_type_of(field.data_type());
+    if format == FORMAT_BINARY && !supports_binary(type_oid) {
+        return Err(ErrorCode::Unimplemented(format!(
+            "Binary format is not supported for column '{}' of type {}",
+            field.name(),
+            field.data_type()
+        )));
+    }
+
+    Ok(FieldDescription {
+        name: field.name().to_string(),
+        type_oid,
+        type_size,
+        type_modifier,
+        format,
+    })
+}
+
+fn supports_binary(type_oid: u32) -> bool {
+    matches!(
+        type_oid,
+        BOOL_OID
+            | BYTEA_OID
+            | INT2_OID
+            | INT4_OID
+            | INT8_OID
+            | FLOAT4_OID
+            | FLOAT8_OID
+            | TEXT_OID
+            | VARCHAR_OID
+            | DATE_OID
+            | TIMESTAMP_OID
+    )
+}
+
+/// Encodes the values of result columns in the text or binary format of the
+/// PostgreSQL type reported for the column.
+pub struct ValueEncoder {
+    encoder: FieldEncoderValues,
+    timezone: Tz,
+}
+
+impl ValueEncoder {
+    pub fn create(timezone: Tz) -> ValueEncoder {
+        ValueEncoder {
+            encoder: FieldEncoderValues::create_for_postgres_handler(timezone),
+            timezone,
+        }
+    }
+
+    /// Encode the value at `row_index` into `buf`, returns false if the value is NULL.
+    pub fn encode(
+        &self,
+        column: &Column,
+        row_index: usize,
+        format: i16,
+        buf: &mut Vec<u8>,
+    ) -> Result<bool> {
+        let value = unsafe { column.index_unchecked(row_index) };
+        match value {
+            ScalarRef::Null => return Ok(false),
+            _ if format == FORMAT_BINARY => self.encode_binary(column, row_index, value, buf)?,
+            ScalarRef::Binary(v) => {
+                buf.extend_from_slice(b"\\x");
+                buf.extend_from_slice(hex::encode(v).as_bytes());
+            }
+            ScalarRef::Bitmap(_) => buf.extend_from_slice(b"<bitmap binary>"),
+            _ => self.encoder.write_field(column, row_index, buf, false),
+        }
+        Ok(true)
+    }
+
+    fn encode_binary(
+        &self,
+        column: &Column,
+        row_index: usize,
+        value: ScalarRef,
+        buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        match value {
+            ScalarRef::Boolean(v) => buf.push(v as u8),
+            ScalarRef::Number(number) => match number {
+                NumberScalar::Int8(v) => buf.extend_from_slice(&(v as i16).to_be_bytes()),
+                NumberScalar::Int16(v) => buf.extend_from_slice(&v.to_be_bytes()),
+                NumberScalar::UInt8(v) => buf.extend_from_slice(&(v as i16).to_be_bytes()),
+                NumberScalar::Int32(v) => buf.extend_from_slice(&v.to_be_bytes()),
+                NumberScalar::UInt16(v) => buf.extend_from_slice(&(v as i32).to_be_bytes()),
+                NumberScalar::Int64(v) => buf.extend_from_slice(&v.to_be_bytes()),
+                NumberScalar::UInt32(v) => buf.extend_from_slice(&(v as i64).to_be_bytes()),
+                NumberScalar::Float32(v) => buf.extend_from_slice(&v.0.to_be_bytes()),
+                NumberScalar::Float64(v) => buf.extend_from_slice(&v.0.to_be_bytes()),
+                NumberScalar::UInt64(_) => return Err(unsupported_binary(column)),
+            },
+            ScalarRef::Binary(v) => buf.extend_from_slice(v),
+            ScalarRef::String(v) => buf.extend_from_slice(v.as_bytes()),
+            ScalarRef::Date(v) => buf.extend_from_slice(&(v - PG_EPOCH_DAYS).to_be_bytes()),
+            ScalarRef::Timestamp(v) => {
+                // `timestamp` is a zone-less type, so send the wall clock time of the
+                // session timezone like the text format does.
+                let local = v.to_timestamp(self.timezone).naive_local();
+                let micros = local.timestamp_micros() - PG_EPOCH_MICROS;
+                buf.extend_from_slice(&micros.to_be_bytes());
+            }
+            // The binary format of text is the text itself.
+            _ => self.encoder.write_field(column, row_index, buf, false),
+        }
+        Ok(())
+    }
+}
+
+fn unsupported_binary(column: &Column) -> ErrorCode {
+    ErrorCode::Unimplemented(format!(
+        "Binary format is not supported for values of type {}",
+        column.data_type()
+    ))
+}
+
+/// Convert a parameter of Bind into a typed scalar, according to the type declared
+/// by Parse (or text, if the client left it unspecified).
+pub fn param_to_scalar(value: Option<&[u8]>, type_oid: u32, format: i16) -> Result<Scalar> {
+    let value = match value {
+        None => return Ok(Scalar::Null),
+        Some(value) => value,
+    };
+
+    if format == FORMAT_BINARY {
+        return binary_param_to_scalar(value, type_oid);
+    }
+
+    let text = std::str::from_utf8(value)?;
+    let invalid =
+        |type_name: &str| ErrorCode::BadArguments(format!("Invalid {type_name} parameter: {text}"));
+    match type_oid {
+        BOOL_OID => match text.trim().to_ascii_lowercase().as_str() {
+            "t" | "true" | "y" | "yes" | "on" | "1" => Ok(Scalar::Boolean(true)),
+            "f" | "false" | "n" | "no" | "off" | "0" => Ok(Scalar::Boolean(false)),
+            _ => Err(invalid("boolean")),
+        },
+        INT2_OID => match text.trim().parse::<i16>() {
+            Ok(v) => Ok(Scalar::Number(NumberScalar::Int16(v))),
+            Err(_) => Err(invalid("int2")),
+        },
+        INT4_OID => match text.trim().parse::<i32>() {
+            Ok(v) => Ok(Scalar::Number(NumberScalar::Int32(v))),
+            Err(_) => Err(invalid("int4")),
+        },
+        INT8_OID => match text.trim().parse::<i64>() {
+            Ok(v) => Ok(Scalar::Number(NumberScalar::Int64(v))),
+            Err(_) => Err(invalid("int8")),
+        },
+        NUMERIC_OID => decode_decimal(text.trim().as_bytes()),
+        // `NaN`, `Infinity` and `-Infinity` are accepted like PostgreSQL does.
+        FLOAT4_OID => match text.trim().parse::<f32>() {
+            Ok(v) => Ok(Scalar::Number(NumberScalar::Float32(v.into()))),
+            Err(_) => Err(invalid("float4")),
+        },
+        FLOAT8_OID => match text.trim().parse::<f64>() {
+            Ok(v) => Ok(Scalar::Number(NumberScalar::Float64(v.into()))),
+            Err(_) => Err(invalid("float8")),
+        },
+        BYTEA_OID => match text.strip_prefix("\\x") {
+            Some(hex) => match hex::decode(hex) {
+                Ok(bytes) => Ok(Scalar::Binary(bytes)),
+                Err(_) => Err(invalid("bytea")),
+            },
+            None => Ok(Scalar::Binary(text.as_bytes().to_vec())),
+        },
+        JSON_OID | JSONB_OID => match jsonb::parse_value(value) {
+            Ok(json) => Ok(Scalar::Variant(json.to_vec())),
+            Err(_) => Err(invalid("json")),
+        },
+        // Dates and timestamps are sent as text and cast by the binder, which
+        // interprets them in the time zone of the session.
+        _ => Ok(Scalar::String(text.to_string())),
+    }
+}
+
+fn binary_param_to_scalar(value: &[u8], type_oid: u32) -> Result<Scalar> {
+    fn be_bytes<const N: usize>(value: &[u8]) -> Result<[u8; N]> {
+        value.try_into().map_err(|_| {
+            ErrorCode::BadBytes(format!(
+                "Invalid length {} of binary parameter, expect {}",
+                value.len(),
+                N
+            ))
+        })
+    }
+
+    match type_oid {
+        BOOL_OID => Ok(Scalar::Boolean(be_bytes::<1>(value)?[0] != 0)),
+        INT2_OID => Ok(Scalar::Number(NumberScalar::Int16(i16::from_be_bytes(
+            be_bytes(value)?,
+        )))),
+        INT4_OID => Ok(Scalar::Number(NumberScalar::Int32(i32::from_be_bytes(
+            be_bytes(value)?,
+        )))),
+        INT8_OID => Ok(Scalar::Number(NumberScalar::Int64(i64::from_be_bytes(
+            be_bytes(value)?,
+        )))),
+        FLOAT4_OID => Ok(Scalar::Number(NumberScalar::Float32(
+            f32::from_be_bytes(be_bytes(value)?).into(),
+        ))),
+        FLOAT8_OID => Ok(Scalar::Number(NumberScalar::Float64(
+            f64::from_be_bytes(be_bytes(value)?).into(),
+        ))),
+        BYTEA_OID => Ok(Scalar::Binary(value.to_vec())),
+        DATE_OID => {
+            let days = i32::from_be_bytes(be_bytes(value)?);
+            days.checked_add(PG_EPOCH_DAYS)
+                .map(Scalar::Date)
+                .ok_or_else(|| ErrorCode::BadArguments(format!("Invalid date parameter: {days}")))
+        }
+        TEXT_OID | VARCHAR_OID | UNKNOWN_OID => {
+            Ok(Scalar::String(std::str::from_utf8(value)?.to_string()))
+        }
+        _ => Err(ErrorCode::Unimplemented(format!(
+            "Binary format is not supported for parameters of type oid {type_oid}"
+        ))),
+    }
+}
@@ -15,6 +15,7 @@
 use std::any::Any;
 use std::cmp::min;
 use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::collections::VecDeque;
@@ -35,6 +36,7 @@ use dashmap::DashMap;
 use databend_common_base::base::tokio::task::JoinHandle;
 use databend_common_base::base::Progress;
 use databend_common_base::base::ProgressValues;
+use databend_common_base::runtime::MemStat;
 use databend_common_base::runtime::TrySpawn;
 use databend_common_catalog::merge_into_join::MergeIntoJoin;
 use databend_common_catalog::plan::DataSourceInfo;
@@ -48,6 +50,7 @@ use databend_common_catalog::statistics::data_cache_statistics::DataCacheMetrics
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::StageAttachment;
+use databend_common_catalog::txn::TxnManagerRef;
 use databend_common_config::GlobalConfig;
 use databend_common_config::DATABEND_COMMIT_VERSION;
 use databend_common_exception::ErrorCode;
@@ -56,6 +59,7 @@ use databend_common_expression::date_helper::TzFactory;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
 use databend_common_io::prelude::FormatSettings;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::OnErrorMode;
@@ -73,6 +77,7 @@ use databend_common_pipeline_core::processors::profile::PlanProfile;
 use databend_common_pipeline_core::processors::profile::Profile;
 use databend_common_pipeline_core::processors::ProfileStatisticsName;
 use databend_common_pipeline_core::InputError;
+use databend_common_pipeline_core::LockGuard;
 use databend_common_settings::Settings;
 use databend_common_sql::IndexType;
 use databend_common_storage::CopyStatus;
@@ -99,6 +104,7 @@ use xorf::BinaryFuse16;
 use crate::api::DataExchangeManager;
 use crate::catalogs::Catalog;
 use crate::clusters::Cluster;
+use crate::locks::LockManager;
 use crate::pipelines::executor::PipelineExecutor;
 use crate::sessions::query_affect::QueryAffect;
 use crate::sessions::ProcessInfo;
@@ -276,6 +282,13 @@ impl QueryContext {
         self.shared.attach_stage(attachment);
     }
 
+    pub fn attach_placeholder_values(&self, values: BTreeMap<usize, Scalar>) {
+        // The query text is the same for every execution of a prepared statement,
+        // so its result must not be served from the result cache.
+        self.set_cacheable(false);
+        self.shared.attach_placeholder_values(values);
+    }
+
     pub fn set_ua(&self, ua: String) {
         *self.shared.user_agent.write() = ua;
     }
@@ -297,6 +310,27 @@ impl QueryContext {
         self.shared.created_time
     }
 
+    pub fn kill(&self, cause: ErrorCode) {
+        self.shared.kill(cause)
+    }
+
+    /// The memory tracker of the query runtime, which only counts the memory of this query.
+    pub fn get_query_memory_tracker(&self) -> Option<Arc<MemStat>> {
+        self.shared
+            .get_runtime()
+            .map(|runtime| runtime.get_tracker())
+    }
+
+    pub fn set_workload_group(&self, name: &str) {
+        *self.shared.workload_group.write() = Some(name.to_string());
+    }
+
+    pub fn set_workload_group_queued(&self, queued: bool) {
+        self.shared
+            .workload_group_queued
+            .store(queued, Ordering::Release);
+    }
+
     pub fn set_finish_time(&self, time: SystemTime) {
         *self.shared.finish_time.write() = Some(time)
     }
@@ -596,6 +630,8 @@ impl TableContext for QueryContext {
         let external_server_request_timeout_secs = self
             .get_settings()
             .get_external_server_request_timeout_secs()?;
+        let wasm_udf_max_memory = self.get_settings().get_wasm_udf_max_memory()?;
+        let wasm_udf_max_fuel = self.get_settings().get_wasm_udf_max_fuel()?;
 
         let tz = self.get_settings().get_timezone()?;
         let tz = TzFactory::instance().get_by_name(&tz)?;
@@ -619,6 +655,9 @@ impl TableContext for QueryContext {
 
             external_server_connect_timeout_secs,
             external_server_request_timeout_secs,
+
+            wasm_udf_max_memory,
+            wasm_udf_max_fuel,
         })
     }
 
@@ -655,6 +694,10 @@ impl TableContext for QueryContext {
         self.shared.get_stage_attachment()
     }
 
+    fn get_placeholder_value(&self, position: usize) -> Option<Scalar> {
+        self.shared.get_placeholder_value(position)
+    }
+
     fn get_last_query_id(&self, index: i32) -> String {
         self.shared.session.session_ctx.get_last_query_id(index)
     }
@@ -994,6 +1037,19 @@ impl TableContext for QueryContext {
         }
         false
     }
+
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.shared.session.txn_mgr()
+    }
+
+    #[async_backtrace::framed]
+    async fn acquire_table_lock(
+        self: Arc<Self>,
+        table_info: &TableInfo,
+    ) -> Result<Option<LockGuard>> {
+        let lock = LockManager::create_table_lock(table_info.clone())?;
+        lock.try_lock(self).await
+    }
 }
 
 impl TrySpawn for QueryContext {
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use std::collections::hash_map::Entry;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
 use std::sync::atomic::Ordering;
@@ -32,6 +33,7 @@ use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::Scalar;
 use databend_common_meta_app::principal::OnErrorMode;
 use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::principal::UserDefinedConnection;
@@ -88,6 +90,8 @@ pub struct QueryContextShared {
     pub(in crate::sessions) data_operator: DataOperator,
     pub(in crate::sessions) executor: Arc<RwLock<Weak<PipelineExecutor>>>,
     pub(in crate::sessions) stage_attachment: Arc<RwLock<Option<StageAttachment>>>,
+    // Values of the `?` placeholders of a prepared statement, keyed by their position in the query.
+    pub(in crate::sessions) placeholder_values: Arc<RwLock<BTreeMap<usize, Scalar>>>,
     pub(in crate::sessions) created_time: SystemTime,
     // now it is only set in query_log::log_query_finished
     pub(in crate::sessions) finish_time: RwLock<Option<SystemTime>>,
@@ -106,6 +110,10 @@ pub struct QueryContextShared {
     pub(in crate::sessions) auto_compact_after_write: Arc<AtomicBool>,
     // Status info.
     pub(in crate::sessions) status: Arc<RwLock<String>>,
+    // The workload group admitting the query.
+    pub(in crate::sessions) workload_group: Arc<RwLock<Option<String>>>,
+    // Whether the query is waiting in the queue of its workload group.
+    pub(in crate::sessions) workload_group_queued: Arc<AtomicBool>,
 
     // Client User-Agent
     pub(in crate::sessions) user_agent: Arc<RwLock<String>>,
@@ -147,6 +155,7 @@ impl QueryContextShared {
             affect: Arc::new(Mutex::new(None)),
             executor: Arc::new(RwLock::new(Weak::new())),
             stage_attachment: Arc::new(RwLock::new(None)),
+            placeholder_values: Arc::new(RwLock::new(BTreeMap::new())),
             created_time: SystemTime::now(),
             finish_time: Default::default(),
             on_error_map: Arc::new(RwLock::new(None)),
@@ -158,6 +167,8 @@ impl QueryContextShared {
             can_scan_from_agg_index: Arc::new(AtomicBool::new(true)),
             auto_compact_after_write: Arc::new(AtomicBool::new(true)),
             status: Arc::new(RwLock::new("null".to_string())),
+            workload_group: Arc::new(RwLock::new(None)),
+            workload_group_queued: Arc::new(AtomicBool::new(false)),
             user_agent: Arc::new(RwLock::new("null".to_string())),
             materialized_cte_tables: Arc::new(Default::default()),
             join_spill_progress: Arc::new(Progress::create()),
@@ -329,6 +340,16 @@ impl QueryContextShared {
         let table_meta_key = (catalog.to_string(), database.to_string(), table.to_string());
         let catalog = self.catalog_manager.get_catalog(&tenant, catalog).await?;
         let cache_table = catalog.get_table(tenant.as_str(), database, table).await?;
+        // the table may have been mutated by the uncommitted explicit transaction
+        let buffered_table_info = self
+            .session
+            .txn_mgr()
+            .lock()
+            .get_table_from_buffer(cache_table.get_id());
+        let cache_table = match buffered_table_info {
+            Some(table_info) => catalog.get_table_by_info(&table_info)?,
+            None => cache_table,
+        };
 
         let mut tables_refs = self.tables_refs.lock();
 
@@ -430,6 +451,14 @@ impl QueryContextShared {
         *stage_attachment = Some(attachment);
     }
 
+    pub fn get_placeholder_value(&self, position: usize) -> Option<Scalar> {
+        self.placeholder_values.read().get(&position).cloned()
+    }
+
+    pub fn attach_placeholder_values(&self, values: BTreeMap<usize, Scalar>) {
+        *self.placeholder_values.write() = values;
+    }
+
     pub fn get_created_time(&self) -> SystemTime {
         self.created_time
     }
@@ -439,6 +468,14 @@ impl QueryContextShared {
         status.clone()
     }
 
+    pub fn get_workload_group(&self) -> Option<String> {
+        self.workload_group.read().clone()
+    }
+
+    pub fn is_workload_group_queued(&self) -> bool {
+        self.workload_group_queued.load(Ordering::Acquire)
+    }
+
     pub async fn get_connection(&self, name: &str) -> Result<UserDefinedConnection> {
         let user_mgr = UserApiProvider::instance();
         let tenant = self.get_tenant();
@@ -15,6 +15,8 @@
 use std::net::SocketAddr;
 use std::sync::Arc;
 
+use databend_common_catalog::txn::TxnManager;
+use databend_common_catalog::txn::TxnManagerRef;
 use databend_common_config::GlobalConfig;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
@@ -48,6 +50,7 @@ pub struct Session {
     status: Arc<RwLock<SessionStatus>>,
     pub(in crate::sessions) mysql_connection_id: Option<u32>,
     format_settings: FormatSettings,
+    txn_mgr: TxnManagerRef,
 }
 
 impl Session {
@@ -67,6 +70,7 @@ impl Session {
             privilege_mgr,
             mysql_connection_id,
             format_settings: FormatSettings::default(),
+            txn_mgr: TxnManager::init(),
         }))
     }
 
@@ -163,6 +167,10 @@ impl Session {
         self.format_settings.clone()
     }
 
+    pub fn txn_mgr(&self) -> TxnManagerRef {
+        self.txn_mgr.clone()
+    }
+
     pub fn get_current_query_id(&self) -> Option<String> {
         self.session_ctx.get_current_query_id()
     }
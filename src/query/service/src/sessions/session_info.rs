@@ -58,6 +58,9 @@ impl Session {
             status_info: shared_query_context
                 .as_ref()
                 .map(|qry_ctx| qry_ctx.get_status_info()),
+            workload_group: shared_query_context
+                .as_ref()
+                .and_then(|qry_ctx| qry_ctx.get_workload_group()),
         }
     }
 
@@ -65,6 +68,7 @@ impl Session {
         match status.get_query_context_shared() {
             _ if status.get_abort() => ProcessInfoState::Aborting,
             None => ProcessInfoState::Idle,
+            Some(shared) if shared.is_workload_group_queued() => ProcessInfoState::Queued,
             Some(_) => ProcessInfoState::Query,
         }
     }
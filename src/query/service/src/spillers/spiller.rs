@@ -36,8 +36,9 @@ use crate::sessions::QueryContext;
 pub enum SpillerType {
     HashJoinBuild,
     HashJoinProbe,
-    OrderBy, /* Todo: Add more spillers type
-              * Aggregation */
+    OrderBy,
+    Window, /* Todo: Add more spillers type
+             * Aggregation */
 }
 
 impl Display for SpillerType {
@@ -46,6 +47,7 @@ impl Display for SpillerType {
             SpillerType::HashJoinBuild => write!(f, "HashJoinBuild"),
             SpillerType::HashJoinProbe => write!(f, "HashJoinProbe"),
             SpillerType::OrderBy => write!(f, "OrderBy"),
+            SpillerType::Window => write!(f, "Window"),
         }
     }
 }
@@ -53,6 +53,9 @@ use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sources::AsyncSource;
 use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_users::UserApiProvider;
+
+use crate::tasks::get_task_dependents;
 
 pub struct TaskDependentsTable {
     table_info: TableInfo,
@@ -251,14 +254,21 @@ impl AsyncSource for TaskDependentsSource {
             return Ok(None);
         }
         self.is_finished = true;
+        let tenant = self.ctx.get_tenant();
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot create task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            // The task itself comes first, followed by the tasks run after it.
+            let tasks = UserApiProvider::instance().get_tasks(&tenant).await?;
+            let dependents = tasks
+                .iter()
+                .filter(|t| t.task_name == self.task_name)
+                .cloned()
+                .chain(get_task_dependents(&tasks, &self.task_name, self.recursive))
+                .map(|t| t.into())
+                .collect::<Vec<Task>>();
+            return Ok(Some(self.to_block(&dependents)?));
         }
         let cloud_api = CloudControlApiProvider::instance();
-        let tenant = self.ctx.get_tenant();
         let user = self.ctx.get_current_user()?.identity().to_string();
         let query_id = self.ctx.get_id();
 
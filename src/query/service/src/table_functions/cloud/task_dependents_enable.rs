@@ -30,11 +30,11 @@ use databend_common_cloud_control::cloud_api::CloudControlApiProvider;
 use databend_common_cloud_control::pb::EnableTaskDependentsRequest;
 use databend_common_cloud_control::task_client::make_request;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 pub use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::principal::Status;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
@@ -44,6 +44,9 @@ use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sources::AsyncSource;
 use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_storages_factory::Table;
+use databend_common_users::UserApiProvider;
+
+use crate::tasks::get_task_dependents;
 
 pub struct TaskDependentsEnableTable {
     task_name: String,
@@ -150,6 +153,35 @@ impl TaskDependentsEnableSource {
             task_name,
         })
     }
+
+    // Resumes the task and all the tasks run after it, which are stored in the meta service.
+    async fn enable_local_task_dependents(&self, tenant: &str) -> Result<()> {
+        let user_api = UserApiProvider::instance();
+        let tasks = user_api.get_tasks(tenant).await?;
+        let mut names = vec![self.task_name.clone()];
+        names.extend(
+            get_task_dependents(&tasks, &self.task_name, true)
+                .into_iter()
+                .map(|t| t.task_name),
+        );
+
+        let now = Utc::now();
+        for name in names {
+            let seq_task = user_api.get_task(tenant, &name).await?;
+            let mut task = seq_task.data;
+            if task.status == Status::Started {
+                continue;
+            }
+            task.status = Status::Started;
+            task.next_scheduled_at = task
+                .schedule_options
+                .as_ref()
+                .and_then(|s| s.next_scheduled_at(now));
+            user_api.update_task(tenant, task, seq_task.seq).await?;
+        }
+        Ok(())
+    }
+
     fn build_request(&self) -> EnableTaskDependentsRequest {
         EnableTaskDependentsRequest {
             task_name: self.task_name.clone(),
@@ -165,14 +197,13 @@ impl AsyncSource for TaskDependentsEnableSource {
     #[async_trait::unboxed_simple]
     #[async_backtrace::framed]
     async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        let tenant = self.ctx.get_tenant();
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot create task without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            self.enable_local_task_dependents(&tenant).await?;
+            return Ok(None);
         }
         let cloud_api = CloudControlApiProvider::instance();
-        let tenant = self.ctx.get_tenant();
         let user = self.ctx.get_current_user()?.identity().to_string();
         let query_id = self.ctx.get_id();
 
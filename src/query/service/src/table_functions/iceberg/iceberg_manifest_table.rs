@@ -0,0 +1,259 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_storages_iceberg::IcebergTable;
+
+use super::iceberg_table_args;
+use super::parse_iceberg_table_args;
+use crate::table_functions::TableFunction;
+
+const ICEBERG_MANIFEST: &str = "iceberg_manifest";
+
+/// `iceberg_manifest(catalog, database, table)` lists the manifests of the current
+/// snapshot of an iceberg table.
+pub struct IcebergManifestTable {
+    table_info: TableInfo,
+    arg_catalog_name: String,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl IcebergManifestTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let (arg_catalog_name, arg_database_name, arg_table_name) =
+            parse_iceberg_table_args(&table_args, ICEBERG_MANIFEST)?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: schema(),
+                engine: ICEBERG_MANIFEST.to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(IcebergManifestTable {
+            table_info,
+            arg_catalog_name,
+            arg_database_name,
+            arg_table_name,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for IcebergManifestTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(iceberg_table_args(
+            &self.arg_catalog_name,
+            &self.arg_database_name,
+            &self.arg_table_name,
+        ))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| {
+                IcebergManifestSource::create(
+                    ctx.clone(),
+                    output,
+                    self.arg_catalog_name.to_owned(),
+                    self.arg_database_name.to_owned(),
+                    self.arg_table_name.to_owned(),
+                )
+            },
+            1,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl TableFunction for IcebergManifestTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct IcebergManifestSource {
+    finish: bool,
+    ctx: Arc<dyn TableContext>,
+    arg_catalog_name: String,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl IcebergManifestSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        arg_catalog_name: String,
+        arg_database_name: String,
+        arg_table_name: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, IcebergManifestSource {
+            ctx,
+            finish: false,
+            arg_catalog_name,
+            arg_database_name,
+            arg_table_name,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for IcebergManifestSource {
+    const NAME: &'static str = ICEBERG_MANIFEST;
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finish {
+            return Ok(None);
+        }
+
+        self.finish = true;
+        let tbl = self
+            .ctx
+            .get_table(
+                &self.arg_catalog_name,
+                &self.arg_database_name,
+                &self.arg_table_name,
+            )
+            .await?;
+        let tbl = IcebergTable::try_from_table(tbl.as_ref())?;
+        let manifests = match tbl.selected_snapshot().await? {
+            Some(snapshot) => tbl.read_manifest_list(&snapshot).await?.entries,
+            None => vec![],
+        };
+
+        let len = manifests.len();
+        let mut paths = Vec::with_capacity(len);
+        let mut lengths = Vec::with_capacity(len);
+        let mut added_snapshot_ids = Vec::with_capacity(len);
+        let mut sequence_numbers = Vec::with_capacity(len);
+        let mut added_files = Vec::with_capacity(len);
+        let mut existing_files = Vec::with_capacity(len);
+        let mut deleted_files = Vec::with_capacity(len);
+        let mut added_rows = Vec::with_capacity(len);
+        let mut existing_rows = Vec::with_capacity(len);
+        let mut deleted_rows = Vec::with_capacity(len);
+        for m in manifests {
+            paths.push(m.manifest_path);
+            lengths.push(m.manifest_length);
+            added_snapshot_ids.push(m.added_snapshot_id);
+            sequence_numbers.push(m.sequence_number);
+            added_files.push(m.added_data_files_count as i64);
+            existing_files.push(m.existing_data_files_count as i64);
+            deleted_files.push(m.deleted_data_files_count as i64);
+            added_rows.push(m.added_rows_count);
+            existing_rows.push(m.existing_rows_count);
+            deleted_rows.push(m.deleted_rows_count);
+        }
+
+        Ok(Some(DataBlock::new_from_columns(vec![
+            StringType::from_data(paths),
+            Int64Type::from_data(lengths),
+            Int64Type::from_data(added_snapshot_ids),
+            Int64Type::from_data(sequence_numbers),
+            Int64Type::from_data(added_files),
+            Int64Type::from_data(existing_files),
+            Int64Type::from_data(deleted_files),
+            Int64Type::from_data(added_rows),
+            Int64Type::from_data(existing_rows),
+            Int64Type::from_data(deleted_rows),
+        ])))
+    }
+}
+
+fn schema() -> Arc<TableSchema> {
+    let int64 = || TableDataType::Number(NumberDataType::Int64);
+    TableSchemaRefExt::create(vec![
+        TableField::new("manifest_path", TableDataType::String),
+        TableField::new("manifest_length", int64()),
+        TableField::new("added_snapshot_id", int64()),
+        TableField::new("sequence_number", int64()),
+        TableField::new("added_data_files_count", int64()),
+        TableField::new("existing_data_files_count", int64()),
+        TableField::new("deleted_data_files_count", int64()),
+        TableField::new("added_rows_count", int64()),
+        TableField::new("existing_rows_count", int64()),
+        TableField::new("deleted_rows_count", int64()),
+    ])
+}
@@ -0,0 +1,251 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::DataSourcePlan;
+use databend_common_catalog::plan::PartStatistics;
+use databend_common_catalog::plan::Partitions;
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::Int64Type;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::Pipeline;
+use databend_common_pipeline_sources::AsyncSource;
+use databend_common_pipeline_sources::AsyncSourcer;
+use databend_common_storages_iceberg::IcebergTable;
+
+use super::iceberg_table_args;
+use super::parse_iceberg_table_args;
+use crate::table_functions::TableFunction;
+
+const ICEBERG_SNAPSHOT: &str = "iceberg_snapshot";
+
+/// `iceberg_snapshot(catalog, database, table)` lists the snapshots of an iceberg table.
+pub struct IcebergSnapshotTable {
+    table_info: TableInfo,
+    arg_catalog_name: String,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl IcebergSnapshotTable {
+    pub fn create(
+        database_name: &str,
+        table_func_name: &str,
+        table_id: u64,
+        table_args: TableArgs,
+    ) -> Result<Arc<dyn TableFunction>> {
+        let (arg_catalog_name, arg_database_name, arg_table_name) =
+            parse_iceberg_table_args(&table_args, ICEBERG_SNAPSHOT)?;
+
+        let table_info = TableInfo {
+            ident: TableIdent::new(table_id, 0),
+            desc: format!("'{}'.'{}'", database_name, table_func_name),
+            name: table_func_name.to_string(),
+            meta: TableMeta {
+                schema: schema(),
+                engine: ICEBERG_SNAPSHOT.to_owned(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        Ok(Arc::new(IcebergSnapshotTable {
+            table_info,
+            arg_catalog_name,
+            arg_database_name,
+            arg_table_name,
+        }))
+    }
+}
+
+#[async_trait::async_trait]
+impl Table for IcebergSnapshotTable {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn read_partitions(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+        _dry_run: bool,
+    ) -> Result<(PartStatistics, Partitions)> {
+        Ok((PartStatistics::default(), Partitions::default()))
+    }
+
+    fn table_args(&self) -> Option<TableArgs> {
+        Some(iceberg_table_args(
+            &self.arg_catalog_name,
+            &self.arg_database_name,
+            &self.arg_table_name,
+        ))
+    }
+
+    fn read_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _plan: &DataSourcePlan,
+        pipeline: &mut Pipeline,
+        _put_cache: bool,
+    ) -> Result<()> {
+        pipeline.add_source(
+            |output| {
+                IcebergSnapshotSource::create(
+                    ctx.clone(),
+                    output,
+                    self.arg_catalog_name.to_owned(),
+                    self.arg_database_name.to_owned(),
+                    self.arg_table_name.to_owned(),
+                )
+            },
+            1,
+        )?;
+
+        Ok(())
+    }
+}
+
+impl TableFunction for IcebergSnapshotTable {
+    fn function_name(&self) -> &str {
+        self.name()
+    }
+
+    fn as_table<'a>(self: Arc<Self>) -> Arc<dyn Table + 'a>
+    where Self: 'a {
+        self
+    }
+}
+
+struct IcebergSnapshotSource {
+    finish: bool,
+    ctx: Arc<dyn TableContext>,
+    arg_catalog_name: String,
+    arg_database_name: String,
+    arg_table_name: String,
+}
+
+impl IcebergSnapshotSource {
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        output: Arc<OutputPort>,
+        arg_catalog_name: String,
+        arg_database_name: String,
+        arg_table_name: String,
+    ) -> Result<ProcessorPtr> {
+        AsyncSourcer::create(ctx.clone(), output, IcebergSnapshotSource {
+            ctx,
+            finish: false,
+            arg_catalog_name,
+            arg_database_name,
+            arg_table_name,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSource for IcebergSnapshotSource {
+    const NAME: &'static str = ICEBERG_SNAPSHOT;
+
+    #[async_trait::unboxed_simple]
+    #[async_backtrace::framed]
+    async fn generate(&mut self) -> Result<Option<DataBlock>> {
+        if self.finish {
+            return Ok(None);
+        }
+
+        self.finish = true;
+        let tbl = self
+            .ctx
+            .get_table(
+                &self.arg_catalog_name,
+                &self.arg_database_name,
+                &self.arg_table_name,
+            )
+            .await?;
+        let tbl = IcebergTable::try_from_table(tbl.as_ref())?;
+        let snapshots = tbl.snapshots().await?;
+
+        let len = snapshots.len();
+        let mut snapshot_ids = Vec::with_capacity(len);
+        let mut parent_ids = Vec::with_capacity(len);
+        let mut sequence_numbers = Vec::with_capacity(len);
+        let mut timestamps = Vec::with_capacity(len);
+        let mut operations = Vec::with_capacity(len);
+        let mut manifest_lists = Vec::with_capacity(len);
+        let mut summaries = Vec::with_capacity(len);
+        for s in snapshots {
+            snapshot_ids.push(s.snapshot_id);
+            parent_ids.push(s.parent_snapshot_id);
+            sequence_numbers.push(s.sequence_number);
+            timestamps.push(s.timestamp_ms * 1000);
+            operations.push(s.summary.get("operation").cloned());
+            manifest_lists.push(s.manifest_list);
+            let summary = s.summary.into_iter().collect::<BTreeMap<_, _>>();
+            summaries.push(serde_json::to_string(&summary)?);
+        }
+
+        Ok(Some(DataBlock::new_from_columns(vec![
+            Int64Type::from_data(snapshot_ids),
+            Int64Type::from_opt_data(parent_ids),
+            Int64Type::from_data(sequence_numbers),
+            TimestampType::from_data(timestamps),
+            StringType::from_opt_data(operations),
+            StringType::from_data(manifest_lists),
+            StringType::from_data(summaries),
+        ])))
+    }
+}
+
+fn schema() -> Arc<TableSchema> {
+    TableSchemaRefExt::create(vec![
+        TableField::new("snapshot_id", TableDataType::Number(NumberDataType::Int64)),
+        TableField::new(
+            "parent_snapshot_id",
+            TableDataType::Number(NumberDataType::Int64).wrap_nullable(),
+        ),
+        TableField::new(
+            "sequence_number",
+            TableDataType::Number(NumberDataType::Int64),
+        ),
+        TableField::new("timestamp", TableDataType::Timestamp),
+        TableField::new("operation", TableDataType::String.wrap_nullable()),
+        TableField::new("manifest_list", TableDataType::String),
+        TableField::new("summary", TableDataType::String),
+    ])
+}
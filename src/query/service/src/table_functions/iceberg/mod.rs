@@ -0,0 +1,44 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod iceberg_manifest_table;
+mod iceberg_snapshot_table;
+
+use databend_common_catalog::table_args::TableArgs;
+use databend_common_exception::Result;
+use databend_common_storages_fuse::table_functions::string_literal;
+use databend_common_storages_fuse::table_functions::string_value;
+pub use iceberg_manifest_table::IcebergManifestTable;
+pub use iceberg_snapshot_table::IcebergSnapshotTable;
+
+/// Parses the `(catalog, database, table)` arguments of the iceberg table functions.
+fn parse_iceberg_table_args(
+    table_args: &TableArgs,
+    func_name: &str,
+) -> Result<(String, String, String)> {
+    let args = table_args.expect_all_positioned(func_name, Some(3))?;
+    Ok((
+        string_value(&args[0])?,
+        string_value(&args[1])?,
+        string_value(&args[2])?,
+    ))
+}
+
+fn iceberg_table_args(catalog: &str, database: &str, table: &str) -> TableArgs {
+    TableArgs::new_positioned(vec![
+        string_literal(catalog),
+        string_literal(database),
+        string_literal(table),
+    ])
+}
@@ -40,12 +40,14 @@ use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
+use databend_common_orc::read_orc_metadata;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sources::AsyncSource;
 use databend_common_pipeline_sources::AsyncSourcer;
 use databend_common_sql::binder::resolve_file_location;
 use databend_common_storage::init_stage_operator;
+use databend_common_storage::read_avro_schema_async;
 use databend_common_storage::read_parquet_schema_async;
 use databend_common_storage::read_parquet_schema_async_rs;
 use databend_common_storage::StageFilesInfo;
@@ -255,9 +257,18 @@ impl AsyncSource for InferSchemaSource {
                     TableSchema::try_from(&arrow_schema)?
                 }
             }
+            StageFileFormatType::Avro => {
+                read_avro_schema_async(&operator, &first_file.path, Some(first_file.size)).await?
+            }
+            StageFileFormatType::Orc => {
+                read_orc_metadata(&operator, &first_file.path, Some(first_file.size))
+                    .await?
+                    .schema
+                    .to_table_schema()?
+            }
             _ => {
                 return Err(ErrorCode::BadArguments(
-                    "infer_schema is currently limited to format Parquet",
+                    "infer_schema is currently limited to format Parquet, Avro and ORC",
                 ));
             }
         };
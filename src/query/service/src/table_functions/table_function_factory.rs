@@ -39,6 +39,8 @@ use crate::storages::fuse::table_functions::FuseStatisticTable;
 use crate::table_functions::async_crash_me::AsyncCrashMeTable;
 use crate::table_functions::cloud::TaskDependentsEnableTable;
 use crate::table_functions::cloud::TaskDependentsTable;
+use crate::table_functions::iceberg::IcebergManifestTable;
+use crate::table_functions::iceberg::IcebergSnapshotTable;
 use crate::table_functions::infer_schema::InferSchemaTable;
 use crate::table_functions::inspect_parquet::InspectParquetTable;
 use crate::table_functions::list_stage::ListStageTable;
@@ -134,6 +136,16 @@ impl TableFunctionFactory {
             (next_id(), Arc::new(FuseStatisticTable::create)),
         );
 
+        creators.insert(
+            "iceberg_snapshot".to_string(),
+            (next_id(), Arc::new(IcebergSnapshotTable::create)),
+        );
+
+        creators.insert(
+            "iceberg_manifest".to_string(),
+            (next_id(), Arc::new(IcebergManifestTable::create)),
+        );
+
         creators.insert(
             "clustering_information".to_string(),
             (next_id(), Arc::new(ClusteringInformationTable::create)),
@@ -0,0 +1,67 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::collections::VecDeque;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::Task;
+
+/// Returns the tasks that run after the task `name`, or all the tasks in the sub graph
+/// after the task `name` if `recursive` is true, in breadth-first order.
+pub fn get_task_dependents(tasks: &[Task], name: &str, recursive: bool) -> Vec<Task> {
+    let mut dependents = vec![];
+    let mut visited = HashSet::from([name.to_string()]);
+    let mut queue = VecDeque::from([name.to_string()]);
+    while let Some(current) = queue.pop_front() {
+        for task in tasks {
+            if task.after.contains(&current) && visited.insert(task.task_name.clone()) {
+                dependents.push(task.clone());
+                if recursive {
+                    queue.push_back(task.task_name.clone());
+                }
+            }
+        }
+    }
+    dependents
+}
+
+/// Checks that the predecessors exist, and the task `name` does not run after itself
+/// through them.
+pub fn check_task_predecessors(tasks: &[Task], name: &str, after: &[String]) -> Result<()> {
+    for predecessor in after {
+        if predecessor == name {
+            return Err(ErrorCode::InvalidOperation(format!(
+                "Task '{}' can not run after itself",
+                name
+            )));
+        }
+        if !tasks.iter().any(|t| &t.task_name == predecessor) {
+            return Err(ErrorCode::UnknownTask(format!(
+                "Task '{}' does not exist.",
+                predecessor
+            )));
+        }
+    }
+
+    let dependents = get_task_dependents(tasks, name, true);
+    if let Some(task) = dependents.iter().find(|t| after.contains(&t.task_name)) {
+        return Err(ErrorCode::InvalidOperation(format!(
+            "Task '{}' can not run after '{}', which runs after it",
+            name, task.task_name
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,346 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+
+use chrono::DateTime;
+use chrono::Utc;
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::base::GlobalInstance;
+use databend_common_base::runtime::GlobalIORuntime;
+use databend_common_base::runtime::TrySpawn;
+use databend_common_base::GLOBAL_TASK;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_config::InnerConfig;
+use databend_common_exception::Result;
+use databend_common_expression::DataBlock;
+use databend_common_expression::ScalarRef;
+use databend_common_meta_app::principal::State;
+use databend_common_meta_app::principal::Status;
+use databend_common_meta_app::principal::Task;
+use databend_common_meta_app::principal::TaskRun;
+use databend_common_meta_app::principal::UserInfo;
+use databend_common_sql::Planner;
+use databend_common_users::UserApiProvider;
+use futures_util::TryStreamExt;
+use log::info;
+use log::warn;
+use parking_lot::Mutex;
+
+use crate::interpreters::InterpreterFactory;
+use crate::sessions::QueryContext;
+use crate::sessions::Session;
+use crate::sessions::SessionManager;
+use crate::sessions::SessionType;
+
+/// The interval to look for the tasks due to run.
+const TASK_SCHEDULE_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The lease to run the tasks is renewed in every round, and taken over by the other nodes
+/// if it is not renewed in time.
+const TASK_LEASE_TTL: Duration = Duration::from_secs(10);
+
+/// Runs the tasks of the tenant in the cluster, if the tasks are not managed by the cloud
+/// control.
+///
+/// The tasks are run by only one node of the cluster, the node that holds the task lease
+/// of the tenant. A run of a task graph starts from a root task when it is due by its
+/// schedule, and the tasks after it are run once all their predecessors succeeded.
+pub struct TaskScheduler {
+    tenant: String,
+    cluster_id: String,
+    node_id: String,
+    enabled: bool,
+    /// The ids of the root tasks whose graph is running on this node.
+    running: Mutex<HashSet<u64>>,
+}
+
+impl TaskScheduler {
+    pub fn init(conf: &InnerConfig) -> Result<()> {
+        GlobalInstance::set(Arc::new(TaskScheduler {
+            tenant: conf.query.tenant_id.clone(),
+            cluster_id: conf.query.cluster_id.clone(),
+            node_id: conf.query.node_id.clone(),
+            enabled: conf.query.cloud_control_grpc_server_address.is_none(),
+            running: Mutex::new(HashSet::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<TaskScheduler> {
+        GlobalInstance::get()
+    }
+
+    pub fn start(self: &Arc<Self>) {
+        if !self.enabled {
+            return;
+        }
+
+        let scheduler = self.clone();
+        GlobalIORuntime::instance().spawn(GLOBAL_TASK, async move {
+            info!("Task scheduler started");
+            loop {
+                sleep(TASK_SCHEDULE_INTERVAL).await;
+                if let Err(e) = scheduler.schedule().await {
+                    warn!("Task scheduler failed to schedule the tasks: {}", e);
+                }
+            }
+        });
+    }
+
+    /// Runs the task graph starting from the task at once, on this node.
+    pub fn execute_task(self: &Arc<Self>, task: Task) {
+        self.spawn_task_graph(task, Utc::now());
+    }
+
+    #[async_backtrace::framed]
+    async fn schedule(self: &Arc<Self>) -> Result<()> {
+        let user_api = UserApiProvider::instance();
+        if !user_api
+            .try_acquire_task_lease(&self.tenant, &self.node_id, TASK_LEASE_TTL)
+            .await?
+        {
+            return Ok(());
+        }
+
+        let now = Utc::now();
+        for task in user_api.get_tasks(&self.tenant).await? {
+            if task.status != Status::Started || !task.after.is_empty() {
+                continue;
+            }
+            let Some(schedule) = &task.schedule_options else {
+                continue;
+            };
+            let scheduled_at = match task.next_scheduled_at {
+                Some(t) if t > now => continue,
+                scheduled_at => scheduled_at,
+            };
+
+            // Move the schedule forward before the run, so that the run is not scheduled
+            // again by the next round.
+            let seq_task = user_api.get_task(&self.tenant, &task.task_name).await?;
+            if seq_task.data.next_scheduled_at != task.next_scheduled_at {
+                continue;
+            }
+            let mut next = seq_task.data.clone();
+            next.next_scheduled_at = schedule.next_scheduled_at(now);
+            if let Err(e) = user_api.update_task(&self.tenant, next, seq_task.seq).await {
+                warn!("Task {} failed to be scheduled: {}", task.task_name, e);
+                continue;
+            }
+
+            if let Some(scheduled_at) = scheduled_at {
+                self.spawn_task_graph(seq_task.data, scheduled_at);
+            }
+        }
+        Ok(())
+    }
+
+    fn spawn_task_graph(self: &Arc<Self>, root: Task, scheduled_at: DateTime<Utc>) {
+        // The task graph is not run again until the previous run finished.
+        if !self.running.lock().insert(root.task_id) {
+            info!(
+                "Task {} is skipped, the previous run is not finished",
+                root.task_name
+            );
+            return;
+        }
+
+        let scheduler = self.clone();
+        GlobalIORuntime::instance().spawn(GLOBAL_TASK, async move {
+            if let Err(e) = scheduler.run_task_graph(&root, scheduled_at).await {
+                warn!("Task graph of {} failed: {}", root.task_name, e);
+            }
+            scheduler.running.lock().remove(&root.task_id);
+        });
+    }
+
+    #[async_backtrace::framed]
+    async fn run_task_graph(&self, root: &Task, scheduled_at: DateTime<Utc>) -> Result<()> {
+        let user_api = UserApiProvider::instance();
+        let mut succeeded = HashSet::new();
+        let mut visited = HashSet::from([root.task_name.clone()]);
+        let mut ready = vec![root.clone()];
+        while !ready.is_empty() {
+            let runs = ready
+                .iter()
+                .map(|task| self.run_task(task, root.task_id, scheduled_at));
+            let results = futures::future::join_all(runs).await;
+            for (task, res) in ready.iter().zip(results) {
+                match res {
+                    Ok(true) => {
+                        succeeded.insert(task.task_name.clone());
+                    }
+                    Ok(false) => {}
+                    Err(e) => warn!("Task {} failed to run: {}", task.task_name, e),
+                }
+            }
+
+            // The tasks whose predecessors all succeeded in this run of the graph.
+            ready = user_api
+                .get_tasks(&self.tenant)
+                .await?
+                .into_iter()
+                .filter(|t| {
+                    t.status == Status::Started
+                        && !t.after.is_empty()
+                        && !visited.contains(&t.task_name)
+                        && t.after.iter().all(|p| succeeded.contains(p))
+                })
+                .collect::<Vec<_>>();
+            visited.extend(ready.iter().map(|t| t.task_name.clone()));
+        }
+        Ok(())
+    }
+
+    /// Runs the task and records the run, returns true if the task succeeded.
+    #[async_backtrace::framed]
+    async fn run_task(
+        &self,
+        task: &Task,
+        root_task_id: u64,
+        scheduled_at: DateTime<Utc>,
+    ) -> Result<bool> {
+        let user_api = UserApiProvider::instance();
+        let session = self.create_session(task).await?;
+
+        if let Some(condition) = &task.when_condition {
+            let ctx = session.create_query_context().await?;
+            let blocks = execute_sql(ctx, &format!("SELECT {}", condition)).await?;
+            let satisfied = blocks
+                .first()
+                .filter(|b| b.num_rows() > 0 && b.num_columns() > 0)
+                .map(|b| {
+                    matches!(
+                        b.get_by_offset(0).value.index(0),
+                        Some(ScalarRef::Boolean(true))
+                    )
+                })
+                .unwrap_or(false);
+            if !satisfied {
+                info!(
+                    "Task {} is skipped, the condition is not satisfied: {}",
+                    task.task_name, condition
+                );
+                return Ok(false);
+            }
+        }
+
+        let ctx = session.create_query_context().await?;
+        let mut run = TaskRun {
+            task: task.clone(),
+            run_id: Utc::now().timestamp_micros() as u64,
+            attempt_number: 1,
+            state: State::Executing,
+            scheduled_at,
+            completed_at: None,
+            error_code: 0,
+            error_message: None,
+            root_task_id,
+            query_id: ctx.get_id(),
+        };
+        user_api.add_task_run(&self.tenant, run.clone()).await?;
+
+        let res = execute_sql(ctx, &task.query_text).await;
+        run.completed_at = Some(Utc::now());
+        match &res {
+            Ok(_) => run.state = State::Succeeded,
+            Err(e) => {
+                run.state = State::Failed;
+                run.error_code = e.code() as i64;
+                run.error_message = Some(e.message());
+            }
+        }
+        user_api.add_task_run(&self.tenant, run).await?;
+
+        if let Err(e) = res {
+            warn!("Task {} failed: {}", task.task_name, e);
+            self.suspend_on_failures(task).await?;
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
+    /// Suspends the task if its recent runs failed `suspend_task_after_num_failures` times
+    /// in a row.
+    #[async_backtrace::framed]
+    async fn suspend_on_failures(&self, task: &Task) -> Result<()> {
+        let Some(max_failures) = task.suspend_task_after_num_failures.filter(|n| *n > 0) else {
+            return Ok(());
+        };
+
+        let user_api = UserApiProvider::instance();
+        let runs = user_api
+            .get_task_runs(&self.tenant, Some(&task.task_name))
+            .await?;
+        let failures = runs
+            .iter()
+            .rev()
+            .take_while(|r| r.state == State::Failed)
+            .count() as u64;
+        if failures < max_failures {
+            return Ok(());
+        }
+
+        let seq_task = user_api.get_task(&self.tenant, &task.task_name).await?;
+        let mut suspended = seq_task.data;
+        suspended.status = Status::Suspended;
+        suspended.next_scheduled_at = None;
+        suspended.last_suspended_at = Some(Utc::now());
+        user_api
+            .update_task(&self.tenant, suspended, seq_task.seq)
+            .await?;
+        info!(
+            "Task {} is suspended after {} failures in a row",
+            task.task_name, failures
+        );
+        Ok(())
+    }
+
+    /// Creates the session to run the task, as the owner role of the task.
+    async fn create_session(&self, task: &Task) -> Result<Arc<Session>> {
+        let session = SessionManager::instance()
+            .create_session(SessionType::HTTPAPI("TaskScheduler".to_string()))
+            .await?;
+        session
+            .set_authed_user(self.task_user(), Some(task.owner.clone()))
+            .await?;
+        let settings = session.get_settings();
+        for (k, v) in &task.session_params {
+            settings.set_setting(k.clone(), v.clone())?;
+        }
+        Ok(session)
+    }
+
+    fn task_user(&self) -> UserInfo {
+        UserInfo::new_no_auth(
+            format!("{}-{}-task-svc", self.tenant, self.cluster_id).as_str(),
+            "0.0.0.0",
+        )
+    }
+}
+
+async fn execute_sql(ctx: Arc<QueryContext>, sql: &str) -> Result<Vec<DataBlock>> {
+    let mut planner = Planner::new(ctx.clone());
+    let (plan, extras) = planner.plan_sql(sql).await?;
+    ctx.attach_query_str(plan.kind(), extras.statement.to_mask_sql());
+    let interpreter = InterpreterFactory::get(ctx.clone(), &plan).await?;
+    interpreter
+        .execute(ctx.clone())
+        .await?
+        .try_collect::<Vec<_>>()
+        .await
+}
@@ -315,6 +315,7 @@ impl TestFixture {
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
             cluster_key: Some("(id)".to_string()),
+            constraints: Default::default(),
         }
     }
 
@@ -340,6 +341,7 @@ impl TestFixture {
             field_comments: vec!["number".to_string(), "tuple".to_string()],
             as_select: None,
             cluster_key: None,
+            constraints: Default::default(),
         }
     }
 
@@ -376,6 +378,7 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            constraints: Default::default(),
         }
     }
 
@@ -421,6 +424,7 @@ impl TestFixture {
             field_comments: vec![],
             as_select: None,
             cluster_key: None,
+            constraints: Default::default(),
         }
     }
 
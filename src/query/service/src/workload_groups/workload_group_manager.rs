@@ -0,0 +1,245 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::cmp::max;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use std::time::Instant;
+
+use databend_common_base::base::tokio::sync::Notify;
+use databend_common_base::base::tokio::time::timeout;
+use databend_common_base::base::GlobalInstance;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_meta_app::principal::WorkloadGroup;
+use databend_common_users::UserApiProvider;
+use parking_lot::Mutex;
+use parking_lot::RwLock;
+
+use crate::sessions::QueryContext;
+
+/// How long the workload groups of a tenant are cached before they are reloaded from meta.
+const WORKLOAD_GROUPS_CACHE_TTL: Duration = Duration::from_secs(10);
+
+/// How often a queued query checks whether it is killed.
+const QUEUE_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Admits the queries of the workload groups on this node.
+///
+/// A query of a group waits in the queue of the group until fewer than `max_concurrency`
+/// queries of the group are running, and fails if the queue is full or the wait exceeds
+/// `queue_timeout_secs`.
+pub struct WorkloadGroupManager {
+    workload_groups: RwLock<HashMap<String, (Instant, Arc<Vec<WorkloadGroup>>)>>,
+    queues: Mutex<HashMap<(String, String), Arc<AdmissionQueue>>>,
+}
+
+impl WorkloadGroupManager {
+    pub fn init() -> Result<()> {
+        GlobalInstance::set(Arc::new(WorkloadGroupManager {
+            workload_groups: RwLock::new(HashMap::new()),
+            queues: Mutex::new(HashMap::new()),
+        }));
+        Ok(())
+    }
+
+    pub fn instance() -> Arc<WorkloadGroupManager> {
+        GlobalInstance::get()
+    }
+
+    /// Drops the cached workload groups of the tenant, called after they are changed.
+    pub fn invalidate(&self, tenant: &str) {
+        self.workload_groups.write().remove(tenant);
+    }
+
+    /// Finds the workload group of the query, and applies the resource shares of the group
+    /// to the settings of the query.
+    ///
+    /// A group naming the user of the query wins over a group naming one of its roles.
+    #[async_backtrace::framed]
+    pub async fn assign(&self, ctx: &Arc<QueryContext>) -> Result<Option<WorkloadGroup>> {
+        let tenant = ctx.get_tenant();
+        let workload_groups = self.get_workload_groups(&tenant).await?;
+        if workload_groups.is_empty() {
+            return Ok(None);
+        }
+
+        let Ok(user) = ctx.get_current_user() else {
+            return Ok(None);
+        };
+        let mut workload_group = workload_groups
+            .iter()
+            .find(|group| group.users.contains(&user.name));
+        if workload_group.is_none() {
+            let roles = ctx
+                .get_all_effective_roles()
+                .await?
+                .into_iter()
+                .map(|role| role.name)
+                .collect::<Vec<_>>();
+            workload_group = workload_groups
+                .iter()
+                .find(|group| group.roles.iter().any(|role| roles.contains(role)));
+        }
+        let Some(workload_group) = workload_group else {
+            return Ok(None);
+        };
+
+        let settings = ctx.get_settings();
+        if workload_group.cpu_percentage > 0 {
+            let max_threads = settings.get_max_threads()?;
+            settings.set_max_threads(max(1, max_threads * workload_group.cpu_percentage / 100))?;
+        }
+        if workload_group.memory_percentage > 0 {
+            let max_memory_usage = settings.get_max_memory_usage()?;
+            settings
+                .set_max_memory_usage(max_memory_usage * workload_group.memory_percentage / 100)?;
+        }
+
+        Ok(Some(workload_group.clone()))
+    }
+
+    /// Waits until the workload group admits the query. The query is running in the group
+    /// until the returned permit is dropped.
+    #[async_backtrace::framed]
+    pub async fn admit(
+        &self,
+        ctx: &Arc<QueryContext>,
+        workload_group: &WorkloadGroup,
+    ) -> Result<WorkloadGroupPermit> {
+        let queue = self
+            .queues
+            .lock()
+            .entry((ctx.get_tenant(), workload_group.name.clone()))
+            .or_insert_with(|| Arc::new(AdmissionQueue::default()))
+            .clone();
+
+        ctx.set_workload_group(&workload_group.name);
+        let res = queue.acquire(ctx, workload_group).await;
+        ctx.set_workload_group_queued(false);
+        res
+    }
+
+    async fn get_workload_groups(&self, tenant: &str) -> Result<Arc<Vec<WorkloadGroup>>> {
+        if let Some((loaded_at, workload_groups)) = self.workload_groups.read().get(tenant) {
+            if loaded_at.elapsed() < WORKLOAD_GROUPS_CACHE_TTL {
+                return Ok(workload_groups.clone());
+            }
+        }
+
+        let mut workload_groups = UserApiProvider::instance()
+            .get_workload_groups(tenant)
+            .await?;
+        workload_groups.sort_by(|a, b| a.name.cmp(&b.name));
+        let workload_groups = Arc::new(workload_groups);
+        self.workload_groups.write().insert(
+            tenant.to_string(),
+            (Instant::now(), workload_groups.clone()),
+        );
+        Ok(workload_groups)
+    }
+}
+
+#[derive(Default)]
+struct AdmissionQueue {
+    state: Mutex<AdmissionState>,
+    notify: Notify,
+}
+
+#[derive(Default)]
+struct AdmissionState {
+    running: u64,
+    queued: u64,
+}
+
+impl AdmissionQueue {
+    async fn acquire(
+        self: &Arc<Self>,
+        ctx: &Arc<QueryContext>,
+        workload_group: &WorkloadGroup,
+    ) -> Result<WorkloadGroupPermit> {
+        let deadline = match workload_group.queue_timeout_secs {
+            0 => None,
+            secs => Some(Instant::now() + Duration::from_secs(secs)),
+        };
+
+        let mut queued = false;
+        loop {
+            let notified = {
+                let mut state = self.state.lock();
+                if workload_group.max_concurrency == 0
+                    || state.running < workload_group.max_concurrency
+                {
+                    if queued {
+                        state.queued -= 1;
+                    }
+                    state.running += 1;
+                    return Ok(WorkloadGroupPermit {
+                        queue: self.clone(),
+                    });
+                }
+
+                if !queued {
+                    if workload_group.max_queued > 0 && state.queued >= workload_group.max_queued {
+                        return Err(ErrorCode::WorkloadGroupQueueFull(format!(
+                            "the queue of workload group `{}` is full, {} queries are waiting",
+                            workload_group.name, state.queued
+                        )));
+                    }
+                    state.queued += 1;
+                    queued = true;
+                    ctx.set_workload_group_queued(true);
+                    ctx.set_status_info(&format!(
+                        "waiting in the queue of workload group {}",
+                        workload_group.name
+                    ));
+                }
+
+                // Registered before the lock is released, so no release is missed.
+                self.notify.notified()
+            };
+
+            let _ = timeout(QUEUE_CHECK_INTERVAL, notified).await;
+
+            if let Err(cause) = ctx.check_aborting() {
+                self.state.lock().queued -= 1;
+                return Err(cause);
+            }
+            if deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+                self.state.lock().queued -= 1;
+                return Err(ErrorCode::WorkloadGroupQueueTimeout(format!(
+                    "query waited more than {} seconds in the queue of workload group `{}`",
+                    workload_group.queue_timeout_secs, workload_group.name
+                )));
+            }
+        }
+    }
+
+    fn release(&self) {
+        self.state.lock().running -= 1;
+        self.notify.notify_waiters();
+    }
+}
+
+/// A query admitted by a workload group, it is released when dropped.
+pub struct WorkloadGroupPermit {
+    queue: Arc<AdmissionQueue>,
+}
+
+impl Drop for WorkloadGroupPermit {
+    fn drop(&mut self) {
+        self.queue.release();
+    }
+}
@@ -17,12 +17,17 @@
 use std::fs;
 use std::io::Write;
 
+use arrow_array::cast::AsArray;
+use arrow_cast::cast;
 use arrow_cast::pretty::pretty_format_batches;
 use arrow_flight::flight_service_server::FlightServiceServer;
 use arrow_flight::sql::client::FlightSqlServiceClient;
+use arrow_flight::sql::CommandGetDbSchemas;
 use arrow_flight::utils::flight_data_to_batches;
 use arrow_flight::FlightData;
+use arrow_flight::FlightInfo;
 use arrow_schema::ArrowError;
+use arrow_schema::DataType as ArrowDataType;
 use databend_common_base::base::tokio;
 use databend_common_config::InnerConfig;
 use databend_common_exception::Result;
@@ -75,6 +80,25 @@ async fn run_query(
     Ok(res)
 }
 
+async fn fetch_strings(
+    client: &mut FlightSqlServiceClient<Channel>,
+    flight_info: FlightInfo,
+    column: usize,
+) -> Vec<String> {
+    let ticket = flight_info.endpoint[0].ticket.as_ref().unwrap().clone();
+    let flight_data = client.do_get(ticket).await.unwrap();
+    let flight_data: Vec<FlightData> = flight_data.try_collect().await.unwrap();
+    let batches = flight_data_to_batches(&flight_data).unwrap();
+    let mut values = vec![];
+    for batch in batches {
+        // Query results are `LargeUtf8` while metadata results are `Utf8`.
+        let array = cast(batch.column(column), &ArrowDataType::Utf8).unwrap();
+        let array = array.as_string::<i32>();
+        values.extend(array.iter().map(|v| v.unwrap_or_default().to_string()));
+    }
+    values
+}
+
 fn prepare_config() -> InnerConfig {
     let hash_method = PasswordHashMethod::DoubleSha1;
     let hash_value = hash_method.hash(TEST_PASSWORD.as_bytes());
@@ -133,6 +157,32 @@ async fn test_query() -> Result<()> {
             };
             writeln!(file, "{}", res).unwrap();
         }
+
+        let flight_info = client
+            .execute("select 'abc'".to_string(), None)
+            .await
+            .unwrap();
+        assert_eq!(fetch_strings(&mut client, flight_info, 0).await, vec![
+            "abc"
+        ]);
+
+        let flight_info = client.get_catalogs().await.unwrap();
+        let catalogs = fetch_strings(&mut client, flight_info, 0).await;
+        assert!(catalogs.contains(&"default".to_string()));
+
+        let flight_info = client
+            .get_db_schemas(CommandGetDbSchemas {
+                catalog: None,
+                db_schema_filter_pattern: Some("defaul_".to_string()),
+            })
+            .await
+            .unwrap();
+        let schemas = fetch_strings(&mut client, flight_info, 1).await;
+        assert_eq!(schemas, vec!["default"]);
+
+        let flight_info = client.get_table_types().await.unwrap();
+        let table_types = fetch_strings(&mut client, flight_info, 0).await;
+        assert_eq!(table_types, vec!["TABLE", "VIEW"]);
     };
     tokio::pin!(serve_future);
 
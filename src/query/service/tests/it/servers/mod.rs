@@ -15,3 +15,4 @@
 mod flight_sql;
 mod http;
 mod mysql;
+mod postgres;
@@ -28,6 +28,7 @@ use databend_query::servers::MySQLHandler;
 use databend_query::servers::MySQLTlsConfig;
 use databend_query::test_kits::ConfigBuilder;
 use databend_query::test_kits::TestFixture;
+use mysql_async::consts::ColumnFlags;
 use mysql_async::prelude::FromRow;
 use mysql_async::prelude::Queryable;
 use mysql_async::FromRowError;
@@ -54,6 +55,115 @@ async fn test_generic_code_with_on_query() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test(flavor = "current_thread")]
+async fn test_prepared_statement() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let tcp_keepalive_timeout_secs = 120;
+    let mut handler = MySQLHandler::create(tcp_keepalive_timeout_secs, MySQLTlsConfig::default())?;
+
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let runnable_server = handler.start(listening).await?;
+    let mut connection = create_connection(runnable_server.port(), false).await?;
+
+    let rows: Vec<(u64, String, f64)> = connection
+        .exec("SELECT ?, ?, ?", (1u64, "it's", 0.5f64))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute prepared statement")?;
+    assert_eq!(rows, vec![(1, "it's".to_string(), 0.5)]);
+
+    // The JSON operator `?` is not a placeholder.
+    let rows: Vec<u64> = connection
+        .exec(
+            r#"SELECT ? FROM numbers(1) WHERE parse_json('{"a":1}') ? 'a'"#,
+            (1u64,),
+        )
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute prepared statement")?;
+    assert_eq!(rows, vec![1]);
+
+    let statement = connection
+        .prep("SELECT number FROM numbers(10) WHERE number > ? ORDER BY number")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare statement")?;
+    for (param, expected) in [(7u64, vec![8u64, 9]), (8u64, vec![9u64])] {
+        let rows: Vec<u64> = connection
+            .exec(&statement, (param,))
+            .await
+            .map_err_to_code(ErrorCode::UnknownException, || "Execute prepared statement")?;
+        assert_eq!(rows, expected);
+    }
+    connection
+        .close(statement)
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Close prepared statement")?;
+
+    let rows: Vec<u64> = connection
+        .exec(
+            "SELECT number FROM numbers(10) ORDER BY number LIMIT ?",
+            (2u64,),
+        )
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute prepared statement")?;
+    assert_eq!(rows, vec![0, 1]);
+
+    connection
+        .query_drop("CREATE TABLE t_prepared(a INT, b VARCHAR)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Create table")?;
+    connection
+        .exec_drop(
+            "INSERT INTO t_prepared VALUES (?, ?), (?, ?)",
+            (1i32, "x", 2i32, "y"),
+        )
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute prepared statement")?;
+    let statement = connection
+        .prep("SELECT a, b FROM t_prepared WHERE a >= ? ORDER BY a")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Prepare statement")?;
+    for _ in 0..2 {
+        let rows: Vec<(i32, String)> = connection
+            .exec(&statement, (1i32,))
+            .await
+            .map_err_to_code(ErrorCode::UnknownException, || "Execute prepared statement")?;
+        assert_eq!(rows, vec![(1, "x".to_string()), (2, "y".to_string())]);
+    }
+
+    // The plan cached by the executions above is stale after a write to the table.
+    connection
+        .query_drop("INSERT INTO t_prepared VALUES (3, 'z')")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Insert")?;
+    let rows: Vec<(i32, String)> = connection
+        .exec(&statement, (1i32,))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute prepared statement")?;
+    assert_eq!(rows, vec![
+        (1, "x".to_string()),
+        (2, "y".to_string()),
+        (3, "z".to_string())
+    ]);
+    let rows: Vec<(i32, String)> = connection
+        .exec(&statement, (2i32,))
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute prepared statement")?;
+    assert_eq!(rows, vec![(2, "y".to_string()), (3, "z".to_string())]);
+
+    let result = connection
+        .query_iter("SELECT number FROM numbers(1)")
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Execute query")?;
+    let columns = result.columns().unwrap();
+    assert!(columns[0].flags().contains(ColumnFlags::UNSIGNED_FLAG));
+    result
+        .drop_result()
+        .await
+        .map_err_to_code(ErrorCode::UnknownException, || "Drop result")?;
+
+    Ok(())
+}
+
 #[tokio::test(flavor = "current_thread")]
 async fn test_connect_with_tls() -> Result<()> {
     let _fixture = TestFixture::setup().await?;
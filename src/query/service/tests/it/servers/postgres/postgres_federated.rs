@@ -0,0 +1,82 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::block_debug::assert_blocks_eq;
+use databend_query::servers::PostgresFederated;
+
+#[test]
+fn test_postgres_federated() -> Result<()> {
+    let federated = PostgresFederated::create("default".to_string());
+
+    //
+    {
+        let query = "select 1";
+        let result = federated.check(query);
+        assert!(result.is_none());
+    }
+
+    // parameters
+    {
+        let query = "SHOW TRANSACTION ISOLATION LEVEL";
+        let result = federated.check(query);
+        assert!(result.is_some());
+
+        if let Some((schema, block)) = result {
+            assert_eq!(schema.field(0).name(), "transaction_isolation");
+            let expect = vec![
+                "+------------------+",
+                "| Column 0         |",
+                "+------------------+",
+                "| 'read committed' |",
+                "+------------------+",
+            ];
+
+            assert_blocks_eq(expect, &[block]);
+        }
+    }
+
+    // session setup
+    {
+        let query = "SET extra_float_digits = 3";
+        let result = federated.check(query);
+        assert!(result.is_some());
+
+        if let Some((schema, block)) = result {
+            assert!(schema.fields().is_empty());
+            assert_eq!(block.num_rows(), 0);
+        }
+    }
+
+    // current schema
+    {
+        let query = "select current_schema()";
+        let result = federated.check(query);
+        assert!(result.is_some());
+
+        if let Some((_, block)) = result {
+            let expect = vec![
+                "+-----------+",
+                "| Column 0  |",
+                "+-----------+",
+                "| 'default' |",
+                "+-----------+",
+            ];
+
+            assert_blocks_eq(expect, &[block]);
+        }
+    }
+
+    Ok(())
+}
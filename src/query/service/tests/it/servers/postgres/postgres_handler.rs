@@ -0,0 +1,368 @@
+// Copyright 2022 Datafuse Labs.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::net::SocketAddr;
+
+use databend_common_base::base::tokio;
+use databend_common_base::base::tokio::io::AsyncReadExt;
+use databend_common_base::base::tokio::io::AsyncWriteExt;
+use databend_common_base::base::tokio::net::TcpStream;
+use databend_common_exception::Result;
+use databend_query::servers::PostgresHandler;
+use databend_query::test_kits::TestFixture;
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_simple_query() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let mut handler = PostgresHandler::create(120, 1024 * 1024)?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut stream = create_connection(listening.port()).await?;
+
+    write_message(
+        &mut stream,
+        b'Q',
+        &cstr("SELECT 1, 'a'; SELECT number FROM numbers(0)"),
+    )
+    .await?;
+    let messages = read_until_ready(&mut stream).await?;
+    assert_eq!(data_rows(&messages), vec![vec![
+        Some("1".to_string()),
+        Some("a".to_string())
+    ]]);
+    assert_eq!(command_tags(&messages), vec!["SELECT 1", "SELECT 0"]);
+
+    write_message(&mut stream, b'Q', &cstr("SELECT * FROM not_exists")).await?;
+    let messages = read_until_ready(&mut stream).await?;
+    assert!(messages.iter().any(|(tag, _)| *tag == b'E'));
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_extended_query() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let mut handler = PostgresHandler::create(120, 1024 * 1024)?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut stream = create_connection(listening.port()).await?;
+
+    // Parse, with the parameter declared as int8.
+    let mut parse = cstr("");
+    parse.extend(cstr(
+        "SELECT number FROM numbers(10) WHERE number > $1 ORDER BY number",
+    ));
+    parse.extend(1_i16.to_be_bytes());
+    parse.extend(20_i32.to_be_bytes());
+    write_message(&mut stream, b'P', &parse).await?;
+
+    for param in ["7", "8"] {
+        // Bind, with a text parameter and text results.
+        let mut bind = cstr("");
+        bind.extend(cstr(""));
+        bind.extend(0_i16.to_be_bytes());
+        bind.extend(1_i16.to_be_bytes());
+        bind.extend((param.len() as i32).to_be_bytes());
+        bind.extend(param.as_bytes());
+        bind.extend(0_i16.to_be_bytes());
+        write_message(&mut stream, b'B', &bind).await?;
+
+        let mut describe = vec![b'P'];
+        describe.extend(cstr(""));
+        write_message(&mut stream, b'D', &describe).await?;
+
+        let mut execute = cstr("");
+        execute.extend(0_i32.to_be_bytes());
+        write_message(&mut stream, b'E', &execute).await?;
+    }
+    write_message(&mut stream, b'S', &[]).await?;
+
+    let messages = read_until_ready(&mut stream).await?;
+    let tags = messages.iter().map(|(tag, _)| *tag).collect::<Vec<_>>();
+    assert_eq!(tags, b"12TDDC2TDC".to_vec());
+    let rows = data_rows(&messages);
+    assert_eq!(rows, vec![
+        vec![Some("8".to_string())],
+        vec![Some("9".to_string())],
+        vec![Some("9".to_string())],
+    ]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_extended_query_params() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let mut handler = PostgresHandler::create(120, 1024 * 1024)?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut stream = create_connection(listening.port()).await?;
+
+    // Parse, with the parameters declared as int8, float8 and float8. A negative
+    // parameter after a minus must not turn the rest of the query into a comment.
+    let mut parse = cstr("");
+    parse.extend(cstr("SELECT 1-$1, $2, $3 FROM numbers(3) WHERE number < 1"));
+    parse.extend(3_i16.to_be_bytes());
+    parse.extend(20_i32.to_be_bytes());
+    parse.extend(701_i32.to_be_bytes());
+    parse.extend(701_i32.to_be_bytes());
+    write_message(&mut stream, b'P', &parse).await?;
+
+    let mut bind = cstr("");
+    bind.extend(cstr(""));
+    bind.extend(0_i16.to_be_bytes());
+    bind.extend(3_i16.to_be_bytes());
+    for param in ["-5", "nan", "inf"] {
+        bind.extend((param.len() as i32).to_be_bytes());
+        bind.extend(param.as_bytes());
+    }
+    bind.extend(0_i16.to_be_bytes());
+    write_message(&mut stream, b'B', &bind).await?;
+
+    let mut execute = cstr("");
+    execute.extend(0_i32.to_be_bytes());
+    write_message(&mut stream, b'E', &execute).await?;
+    write_message(&mut stream, b'S', &[]).await?;
+
+    let messages = read_until_ready(&mut stream).await?;
+    let rows = data_rows(&messages);
+    assert_eq!(rows, vec![vec![
+        Some("6".to_string()),
+        Some("NaN".to_string()),
+        Some("Infinity".to_string()),
+    ]]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_transaction() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let mut handler = PostgresHandler::create(120, 1024 * 1024)?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut stream = create_connection(listening.port()).await?;
+
+    let (_, status) = simple_query(&mut stream, "CREATE TABLE t_txn(a INT)").await?;
+    assert_eq!(status, b'I');
+
+    // the writes of the transaction are visible to itself only
+    let (messages, status) =
+        simple_query(&mut stream, "BEGIN; INSERT INTO t_txn VALUES (1)").await?;
+    assert_eq!(command_tags(&messages), vec!["BEGIN", "INSERT 0 1"]);
+    assert_eq!(status, b'T');
+    let (messages, _) = simple_query(&mut stream, "SELECT count(*) FROM t_txn").await?;
+    assert_eq!(data_rows(&messages), vec![vec![Some("1".to_string())]]);
+    let (messages, status) = simple_query(&mut stream, "ROLLBACK").await?;
+    assert_eq!(command_tags(&messages), vec!["ROLLBACK"]);
+    assert_eq!(status, b'I');
+    let (messages, _) = simple_query(&mut stream, "SELECT count(*) FROM t_txn").await?;
+    assert_eq!(data_rows(&messages), vec![vec![Some("0".to_string())]]);
+
+    // an error aborts the transaction block until it ends
+    let (_, status) = simple_query(&mut stream, "BEGIN; INSERT INTO t_txn VALUES (1)").await?;
+    assert_eq!(status, b'T');
+    let (_, status) = simple_query(&mut stream, "SELECT * FROM not_exists").await?;
+    assert_eq!(status, b'E');
+    let (messages, status) = simple_query(&mut stream, "INSERT INTO t_txn VALUES (2)").await?;
+    assert!(messages.iter().any(|(tag, _)| *tag == b'E'));
+    assert_eq!(status, b'E');
+    let (_, status) = simple_query(&mut stream, "COMMIT").await?;
+    assert_eq!(status, b'I');
+    let (messages, _) = simple_query(&mut stream, "SELECT count(*) FROM t_txn").await?;
+    assert_eq!(data_rows(&messages), vec![vec![Some("0".to_string())]]);
+
+    let (_, status) =
+        simple_query(&mut stream, "BEGIN; INSERT INTO t_txn VALUES (3); COMMIT").await?;
+    assert_eq!(status, b'I');
+    let (messages, _) = simple_query(&mut stream, "SELECT a FROM t_txn").await?;
+    assert_eq!(data_rows(&messages), vec![vec![Some("3".to_string())]]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_psql_list_tables() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let mut handler = PostgresHandler::create(120, 1024 * 1024)?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+    let mut stream = create_connection(listening.port()).await?;
+
+    simple_query(&mut stream, "CREATE TABLE t_dt(a INT NOT NULL, b VARCHAR)").await?;
+    simple_query(&mut stream, "CREATE VIEW v_dt AS SELECT a FROM t_dt").await?;
+
+    // `\dt *_dt` of psql, without the functions on owners and visibility.
+    let (messages, _) = simple_query(
+        &mut stream,
+        "SELECT n.nspname AS \"Schema\", c.relname AS \"Name\", \
+         CASE c.relkind WHEN 'r' THEN 'table' WHEN 'v' THEN 'view' END AS \"Type\" \
+         FROM pg_catalog.pg_class c \
+         LEFT JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace \
+         WHERE c.relkind IN ('r', 'p', '') \
+         AND n.nspname <> 'pg_catalog' AND n.nspname <> 'information_schema' \
+         AND c.relname LIKE '%_dt' \
+         ORDER BY 1, 2",
+    )
+    .await?;
+    assert_eq!(data_rows(&messages), vec![vec![
+        Some("default".to_string()),
+        Some("t_dt".to_string()),
+        Some("table".to_string())
+    ]]);
+
+    // `\d t_dt` of psql lists the columns with their types.
+    let (messages, _) = simple_query(
+        &mut stream,
+        "SELECT a.attname, t.typname, a.attnotnull \
+         FROM pg_catalog.pg_attribute a \
+         JOIN pg_catalog.pg_class c ON c.oid = a.attrelid \
+         JOIN pg_catalog.pg_type t ON t.oid = a.atttypid \
+         WHERE c.relname = 't_dt' AND a.attnum > 0 AND NOT a.attisdropped \
+         ORDER BY a.attnum",
+    )
+    .await?;
+    assert_eq!(data_rows(&messages), vec![
+        vec![
+            Some("a".to_string()),
+            Some("int4".to_string()),
+            Some("t".to_string())
+        ],
+        vec![
+            Some("b".to_string()),
+            Some("varchar".to_string()),
+            Some("f".to_string())
+        ],
+    ]);
+
+    Ok(())
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_message_too_large() -> Result<()> {
+    let _fixture = TestFixture::setup().await?;
+
+    let mut handler = PostgresHandler::create(120, 1024 * 1024)?;
+    let listening = "127.0.0.1:0".parse::<SocketAddr>()?;
+    let listening = handler.start(listening).await?;
+
+    // A startup message larger than 10000 bytes is refused before it is read.
+    let mut stream = TcpStream::connect(("127.0.0.1", listening.port())).await?;
+    stream.write_i32(1 << 30).await?;
+    let mut buf = vec![];
+    stream.read_to_end(&mut buf).await?;
+    assert!(buf.is_empty());
+
+    // So is a message larger than the limit after the authentication.
+    let mut stream = create_connection(listening.port()).await?;
+    stream.write_u8(b'Q').await?;
+    stream.write_i32(2 * 1024 * 1024).await?;
+    let mut buf = vec![];
+    stream.read_to_end(&mut buf).await?;
+    assert!(buf.is_empty());
+
+    Ok(())
+}
+
+async fn create_connection(port: u16) -> Result<TcpStream> {
+    let mut stream = TcpStream::connect(("127.0.0.1", port)).await?;
+
+    let mut startup = 196608_i32.to_be_bytes().to_vec();
+    for value in ["user", "root", "database", "default", ""] {
+        startup.extend(cstr(value));
+    }
+    stream
+        .write_all(&(startup.len() as i32 + 4).to_be_bytes())
+        .await?;
+    stream.write_all(&startup).await?;
+
+    let messages = read_until_ready(&mut stream).await?;
+    assert_eq!(messages[0], (b'R', 0_i32.to_be_bytes().to_vec()));
+    Ok(stream)
+}
+
+async fn simple_query(stream: &mut TcpStream, sql: &str) -> Result<(Vec<(u8, Vec<u8>)>, u8)> {
+    write_message(stream, b'Q', &cstr(sql)).await?;
+    read_until_ready_with_status(stream).await
+}
+
+fn cstr(value: &str) -> Vec<u8> {
+    let mut bytes = value.as_bytes().to_vec();
+    bytes.push(0);
+    bytes
+}
+
+async fn write_message(stream: &mut TcpStream, tag: u8, body: &[u8]) -> Result<()> {
+    stream.write_u8(tag).await?;
+    stream.write_i32(body.len() as i32 + 4).await?;
+    stream.write_all(body).await?;
+    Ok(())
+}
+
+async fn read_until_ready(stream: &mut TcpStream) -> Result<Vec<(u8, Vec<u8>)>> {
+    let (messages, _) = read_until_ready_with_status(stream).await?;
+    Ok(messages)
+}
+
+/// Returns the messages before ReadyForQuery, and the transaction status it carries.
+async fn read_until_ready_with_status(stream: &mut TcpStream) -> Result<(Vec<(u8, Vec<u8>)>, u8)> {
+    let mut messages = vec![];
+    loop {
+        let tag = stream.read_u8().await?;
+        let len = stream.read_i32().await?;
+        let mut body = vec![0; len as usize - 4];
+        stream.read_exact(&mut body).await?;
+        if tag == b'Z' {
+            return Ok((messages, body[0]));
+        }
+        messages.push((tag, body));
+    }
+}
+
+fn data_rows(messages: &[(u8, Vec<u8>)]) -> Vec<Vec<Option<String>>> {
+    messages
+        .iter()
+        .filter(|(tag, _)| *tag == b'D')
+        .map(|(_, body)| {
+            let num_columns = i16::from_be_bytes([body[0], body[1]]);
+            let mut offset = 2;
+            let mut values = vec![];
+            for _ in 0..num_columns {
+                let len = i32::from_be_bytes(body[offset..offset + 4].try_into().unwrap());
+                offset += 4;
+                if len < 0 {
+                    values.push(None);
+                    continue;
+                }
+                let value = &body[offset..offset + len as usize];
+                values.push(Some(String::from_utf8(value.to_vec()).unwrap()));
+                offset += len as usize;
+            }
+            values
+        })
+        .collect()
+}
+
+fn command_tags(messages: &[(u8, Vec<u8>)]) -> Vec<String> {
+    messages
+        .iter()
+        .filter(|(tag, _)| *tag == b'C')
+        .map(|(_, body)| String::from_utf8(body[..body.len() - 1].to_vec()).unwrap())
+        .collect()
+}
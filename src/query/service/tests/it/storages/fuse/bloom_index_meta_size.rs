@@ -21,6 +21,7 @@ use chrono::Utc;
 // use databend_common_arrow::parquet::metadata::ThriftFileMetaData;
 use databend_common_base::base::tokio;
 use databend_common_cache::Cache;
+use databend_common_cache::EvictionPolicy;
 use databend_common_expression::types::Int32Type;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberScalar;
@@ -179,7 +180,10 @@ async fn test_segment_info_size() -> databend_common_exception::Result<()> {
         scenario, pid, base_memory_usage
     );
 
-    let cache = InMemoryCacheBuilder::new_item_cache::<SegmentInfo>(cache_number as u64);
+    let cache = InMemoryCacheBuilder::new_item_cache::<SegmentInfo>(
+        cache_number as u64,
+        EvictionPolicy::Lru,
+    );
     {
         let mut c = cache.write();
         for _ in 0..cache_number {
@@ -223,7 +227,8 @@ async fn test_segment_raw_bytes_size() -> databend_common_exception::Result<()>
         scenario, pid, base_memory_usage
     );
 
-    let cache = InMemoryCacheBuilder::new_item_cache::<Vec<u8>>(cache_number as u64);
+    let cache =
+        InMemoryCacheBuilder::new_item_cache::<Vec<u8>>(cache_number as u64, EvictionPolicy::Lru);
     {
         let mut c = cache.write();
         for _ in 0..cache_number {
@@ -268,7 +273,10 @@ async fn test_segment_raw_repr_bytes_size() -> databend_common_exception::Result
         scenario, pid, base_memory_usage
     );
 
-    let cache = InMemoryCacheBuilder::new_item_cache::<CompactSegmentInfo>(cache_number as u64);
+    let cache = InMemoryCacheBuilder::new_item_cache::<CompactSegmentInfo>(
+        cache_number as u64,
+        EvictionPolicy::Lru,
+    );
     {
         let mut c = cache.write();
         for _ in 0..cache_number {
@@ -342,6 +350,8 @@ fn build_test_segment_info(
         bloom_filter_index_size: 0,
         compression: Compression::Lz4,
         create_on: Some(Utc::now()),
+        deletion_vector_location: None,
+        deleted_row_count: 0,
     };
 
     let block_metas = (0..num_blocks_per_seg)
@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use databend_common_ast::ast::ClusterType;
 use databend_common_ast::ast::Engine;
 use databend_common_base::base::tokio;
 use databend_common_meta_app::schema::CreateOption;
@@ -58,6 +59,7 @@ async fn test_fuse_alter_table_cluster_key() -> databend_common_exception::Resul
         field_comments: vec![],
         as_select: None,
         cluster_key: None,
+        constraints: Default::default(),
     };
 
     // create test table
@@ -70,6 +72,7 @@ async fn test_fuse_alter_table_cluster_key() -> databend_common_exception::Resul
         catalog: fixture.default_catalog_name(),
         database: fixture.default_db_name(),
         table: fixture.default_table_name(),
+        cluster_type: ClusterType::Linear,
         cluster_keys: vec!["id".to_string()],
     };
     let interpreter =
@@ -36,11 +36,13 @@ use databend_common_catalog::table_context::MaterializedCtesBlocks;
 use databend_common_catalog::table_context::ProcessInfo;
 use databend_common_catalog::table_context::StageAttachment;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_catalog::txn::TxnManagerRef;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::DataBlock;
 use databend_common_expression::Expr;
 use databend_common_expression::FunctionContext;
+use databend_common_expression::Scalar;
 use databend_common_io::prelude::FormatSettings;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::OnErrorMode;
@@ -110,6 +112,7 @@ use databend_common_meta_types::MetaId;
 use databend_common_pipeline_core::processors::profile::PlanProfile;
 use databend_common_pipeline_core::processors::profile::Profile;
 use databend_common_pipeline_core::InputError;
+use databend_common_pipeline_core::LockGuard;
 use databend_common_settings::Settings;
 use databend_common_sql::IndexType;
 use databend_common_storage::CopyStatus;
@@ -574,6 +577,10 @@ impl TableContext for CtxDelegation {
         todo!()
     }
 
+    fn get_placeholder_value(&self, _position: usize) -> Option<Scalar> {
+        todo!()
+    }
+
     fn get_last_query_id(&self, _index: i32) -> String {
         todo!()
     }
@@ -732,6 +739,17 @@ impl TableContext for CtxDelegation {
     fn has_bloom_runtime_filters(&self, _id: usize) -> bool {
         todo!()
     }
+
+    fn txn_mgr(&self) -> TxnManagerRef {
+        self.ctx.txn_mgr()
+    }
+
+    async fn acquire_table_lock(
+        self: Arc<Self>,
+        _table_info: &TableInfo,
+    ) -> Result<Option<LockGuard>> {
+        todo!()
+    }
     fn get_data_cache_metrics(&self) -> &DataCacheMetrics {
         todo!()
     }
@@ -108,6 +108,7 @@ async fn test_block_pruner() -> Result<()> {
         field_comments: vec![],
         as_select: None,
         cluster_key: None,
+        constraints: Default::default(),
     };
 
     let interpreter = CreateTableInterpreter::try_create(ctx.clone(), create_table_plan)?;
@@ -258,3 +259,74 @@ async fn test_block_pruner() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test_block_pruner_on_hilbert_cluster_keys() -> Result<()> {
+    let fixture = TestFixture::setup().await?;
+    let ctx = fixture.new_query_ctx().await?;
+
+    fixture.create_default_database().await?;
+
+    let db = fixture.default_db_name();
+    let test_tbl_name = "test_hilbert_pruning";
+    fixture
+        .execute_command(&format!(
+            "create table {db}.{test_tbl_name}(a int not null, b int not null) \
+             cluster by hilbert(a, b) row_per_block = 4"
+        ))
+        .await?;
+
+    // The range of column a is a thousand times the one of column b, without scaling the
+    // keys to the same bit width the blocks would be ordered by column a only.
+    for b in 0..4 {
+        let values = (0..4)
+            .map(|a| format!("({}, {b})", a * 1000))
+            .collect::<Vec<_>>()
+            .join(",");
+        fixture
+            .execute_command(&format!("insert into {db}.{test_tbl_name} values {values}"))
+            .await?;
+    }
+    fixture
+        .execute_command(&format!("alter table {db}.{test_tbl_name} recluster final"))
+        .await?;
+
+    let catalog = ctx.get_catalog("default").await?;
+    let table = catalog
+        .get_table(fixture.default_tenant().as_str(), &db, test_tbl_name)
+        .await?;
+    let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+    let snapshot = fuse_table.read_table_snapshot().await?.unwrap();
+
+    // Each block of the hilbert layout holds a quadrant of the 4x4 grid, a filter on
+    // either of the cluster keys only reads two of them.
+    let extras = vec![
+        (None, 4),
+        (Some("a = 3000"), 2),
+        (Some("b = 3"), 2),
+        (Some("a = 0 and b = 0"), 1),
+    ];
+
+    for (filter, expected_blocks) in extras {
+        let push_down = match filter {
+            Some(filter) => Some(PushDownInfo {
+                filters: Some(parse_to_filters(ctx.clone(), table.clone(), filter)?),
+                ..Default::default()
+            }),
+            None => None,
+        };
+        let blocks = apply_block_pruning(
+            snapshot.clone(),
+            table.get_table_info().schema(),
+            &push_down,
+            ctx.clone(),
+            fuse_table.get_operator(),
+            fuse_table.bloom_index_cols(),
+        )
+        .await?;
+
+        assert_eq!(expected_blocks, blocks.len(), "filter: {:?}", filter);
+    }
+
+    Ok(())
+}
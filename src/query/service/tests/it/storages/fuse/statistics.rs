@@ -38,6 +38,7 @@ use databend_common_expression::TableSchema;
 use databend_common_functions::aggregates::eval_aggr;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_sql::evaluator::BlockOperator;
+use databend_common_storages_fuse::statistics::build_column_histogram;
 use databend_common_storages_fuse::statistics::reducers::reduce_block_metas;
 use databend_common_storages_fuse::statistics::Trim;
 use databend_common_storages_fuse::statistics::STATS_REPLACEMENT_CHAR;
@@ -639,3 +640,44 @@ fn test_reduce_block_meta() -> databend_common_exception::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn test_build_column_histogram() -> databend_common_exception::Result<()> {
+    let int = |v: i64| Scalar::Number(NumberScalar::Int64(v));
+
+    // 1000 distinct values and a skewed value `7`
+    let mut samples = (0..1000).map(int).collect::<Vec<_>>();
+    samples.extend(std::iter::repeat(int(7)).take(500));
+    let histogram = build_column_histogram(samples, 1500, 1000, 10, 5).unwrap();
+
+    let buckets = &histogram.buckets;
+    // the first bucket only holds the min value
+    assert_eq!(buckets[0].upper_bound, int(0));
+    assert_eq!(buckets[0].num_values, 1.0);
+    assert_eq!(buckets.last().unwrap().upper_bound, int(999));
+    assert!(buckets.len() <= 11);
+    let num_values = buckets.iter().map(|b| b.num_values).sum::<f64>();
+    assert_eq!(num_values, 1500.0);
+    let num_distinct = buckets.iter().map(|b| b.num_distinct).sum::<f64>();
+    assert_eq!(num_distinct, 1000.0);
+    for pair in buckets.windows(2) {
+        assert!(pair[0].upper_bound < pair[1].upper_bound);
+    }
+
+    assert_eq!(histogram.most_common_values.len(), 1);
+    assert_eq!(histogram.most_common_values[0].value, int(7));
+    assert_eq!(histogram.most_common_values[0].count, 501.0);
+
+    // the counts of the sample are scaled to the whole column
+    let samples = (0..100).map(int).collect::<Vec<_>>();
+    let histogram = build_column_histogram(samples, 10000, 5000, 10, 5).unwrap();
+    let num_values = histogram.buckets.iter().map(|b| b.num_values).sum::<f64>();
+    assert_eq!(num_values, 10000.0);
+    for bucket in histogram.buckets.iter() {
+        assert!(bucket.num_distinct <= bucket.num_values);
+    }
+    assert!(histogram.most_common_values.is_empty());
+
+    assert!(build_column_histogram(vec![], 0, 0, 10, 5).is_none());
+    Ok(())
+}
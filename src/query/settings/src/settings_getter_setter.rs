@@ -353,6 +353,10 @@ impl Settings {
         Ok(self.try_get_u64("sort_spilling_memory_ratio")? as usize)
     }
 
+    pub fn get_window_spilling_memory_ratio(&self) -> Result<usize> {
+        Ok(self.try_get_u64("window_spilling_memory_ratio")? as usize)
+    }
+
     pub fn get_group_by_shuffle_mode(&self) -> Result<String> {
         self.try_get_string("group_by_shuffle_mode")
     }
@@ -442,6 +446,22 @@ impl Settings {
         Ok(self.try_get_u64("enable_aggregating_index_scan")? != 0)
     }
 
+    pub fn get_enable_vector_index_scan(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_vector_index_scan")? != 0)
+    }
+
+    pub fn get_enable_inverted_index_scan(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_inverted_index_scan")? != 0)
+    }
+
+    pub fn get_enable_materialized_view_rewrite(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_materialized_view_rewrite")? != 0)
+    }
+
+    pub fn get_enable_join_elimination_by_key_constraints(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_join_elimination_by_key_constraints")? != 0)
+    }
+
     pub fn get_enable_compact_after_write(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_compact_after_write")? != 0)
     }
@@ -543,6 +563,14 @@ impl Settings {
         self.try_get_u64("external_server_request_timeout_secs")
     }
 
+    pub fn get_wasm_udf_max_memory(&self) -> Result<u64> {
+        self.try_get_u64("wasm_udf_max_memory")
+    }
+
+    pub fn get_wasm_udf_max_fuel(&self) -> Result<u64> {
+        self.try_get_u64("wasm_udf_max_fuel")
+    }
+
     pub fn get_create_query_flight_client_with_current_rt(&self) -> Result<bool> {
         Ok(self.try_get_u64("create_query_flight_client_with_current_rt")? != 0)
     }
@@ -568,6 +596,17 @@ impl Settings {
         self.try_set_u64("enable_refresh_virtual_column_after_write", u64::from(val))
     }
 
+    pub fn get_enable_refresh_materialized_view_after_write(&self) -> Result<bool> {
+        Ok(self.try_get_u64("enable_refresh_materialized_view_after_write")? != 0)
+    }
+
+    pub fn set_enable_refresh_materialized_view_after_write(&self, val: bool) -> Result<()> {
+        self.try_set_u64(
+            "enable_refresh_materialized_view_after_write",
+            u64::from(val),
+        )
+    }
+
     pub fn get_enable_refresh_aggregating_index_after_write(&self) -> Result<bool> {
         Ok(self.try_get_u64("enable_refresh_aggregating_index_after_write")? != 0)
     }
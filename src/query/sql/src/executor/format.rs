@@ -28,6 +28,7 @@ use crate::executor::physical_plans::AggregateExpand;
 use crate::executor::physical_plans::AggregateFinal;
 use crate::executor::physical_plans::AggregateFunctionDesc;
 use crate::executor::physical_plans::AggregatePartial;
+use crate::executor::physical_plans::AsofJoin;
 use crate::executor::physical_plans::CommitSink;
 use crate::executor::physical_plans::ConstantTableScan;
 use crate::executor::physical_plans::CopyIntoTable;
@@ -130,6 +131,20 @@ impl PhysicalPlan {
                     children,
                 ))
             }
+            PhysicalPlan::AsofJoin(plan) => {
+                let left_child = plan.left.format_join(metadata)?;
+                let right_child = plan.right.format_join(metadata)?;
+
+                let children = vec![
+                    FormatTreeNode::with_children("Left".to_string(), vec![left_child]),
+                    FormatTreeNode::with_children("Right".to_string(), vec![right_child]),
+                ];
+
+                Ok(FormatTreeNode::with_children(
+                    format!("AsofJoin: {}", plan.join_type),
+                    children,
+                ))
+            }
             PhysicalPlan::CteScan(cte_scan) => cte_scan_to_format_tree(cte_scan),
             PhysicalPlan::MaterializedCte(materialized_cte) => {
                 let left_child = materialized_cte.left.format_join(metadata)?;
@@ -214,6 +229,7 @@ fn to_format_tree(
         PhysicalPlan::ProjectSet(plan) => project_set_to_format_tree(plan, metadata, profs),
         PhysicalPlan::Udf(plan) => udf_to_format_tree(plan, metadata, profs),
         PhysicalPlan::RangeJoin(plan) => range_join_to_format_tree(plan, metadata, profs),
+        PhysicalPlan::AsofJoin(plan) => asof_join_to_format_tree(plan, metadata, profs),
         PhysicalPlan::CopyIntoTable(plan) => copy_into_table(plan),
         PhysicalPlan::ReplaceAsyncSourcer(_) => {
             Ok(FormatTreeNode::new("ReplaceAsyncSourcer".to_string()))
@@ -361,6 +377,33 @@ fn table_scan_to_format_tree(
         };
         children.push(FormatTreeNode::new(text));
     }
+    // Vector index
+    if let Some(vector_index) = plan
+        .source
+        .push_downs
+        .as_ref()
+        .and_then(|extras| extras.vector_index.as_ref())
+    {
+        children.push(FormatTreeNode::new(format!(
+            "vector index: [name: {}, column: {}, distance: {}, limit: {}]",
+            vector_index.index_name,
+            vector_index.column_name,
+            vector_index.distance,
+            vector_index.limit
+        )));
+    }
+    // Inverted index
+    if let Some(inverted_index) = plan
+        .source
+        .push_downs
+        .as_ref()
+        .and_then(|extras| extras.inverted_index.as_ref())
+    {
+        children.push(FormatTreeNode::new(format!(
+            "inverted index: [name: {}, column: {}, query: '{}']",
+            inverted_index.index_name, inverted_index.column_name, inverted_index.query
+        )));
+    }
 
     if let Some(info) = &plan.stat_info {
         let items = plan_stats_info_to_format_tree(info);
@@ -874,6 +917,70 @@ fn range_join_to_format_tree(
     ))
 }
 
+fn asof_join_to_format_tree(
+    plan: &AsofJoin,
+    metadata: &Metadata,
+    profs: &HashMap<u32, PlanProfile>,
+) -> Result<FormatTreeNode<String>> {
+    let left_keys = plan
+        .left_keys
+        .iter()
+        .map(|scalar| scalar.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let right_keys = plan
+        .right_keys
+        .iter()
+        .map(|scalar| scalar.as_expr(&BUILTIN_FUNCTIONS).sql_display())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let match_condition = {
+        let left = plan
+            .match_condition
+            .left_expr
+            .as_expr(&BUILTIN_FUNCTIONS)
+            .sql_display();
+        let right = plan
+            .match_condition
+            .right_expr
+            .as_expr(&BUILTIN_FUNCTIONS)
+            .sql_display();
+        format!("{left} {:?} {right}", plan.match_condition.operator)
+    };
+
+    let mut left_child = to_format_tree(&plan.left, metadata, profs)?;
+    let mut right_child = to_format_tree(&plan.right, metadata, profs)?;
+
+    left_child.payload = format!("{}(Left)", left_child.payload);
+    right_child.payload = format!("{}(Right)", right_child.payload);
+
+    let mut children = vec![
+        FormatTreeNode::new(format!(
+            "output columns: [{}]",
+            format_output_columns(plan.output_schema()?, metadata, true)
+        )),
+        FormatTreeNode::new(format!("join type: {}", plan.join_type)),
+        FormatTreeNode::new(format!("left keys: [{left_keys}]")),
+        FormatTreeNode::new(format!("right keys: [{right_keys}]")),
+        FormatTreeNode::new(format!("match condition: [{match_condition}]")),
+    ];
+
+    if let Some(info) = &plan.stat_info {
+        let items = plan_stats_info_to_format_tree(info);
+        children.extend(items);
+    }
+
+    append_profile_info(&mut children, profs, plan.plan_id);
+
+    children.push(left_child);
+    children.push(right_child);
+
+    Ok(FormatTreeNode::with_children(
+        "AsofJoin".to_string(),
+        children,
+    ))
+}
+
 fn hash_join_to_format_tree(
     plan: &HashJoin,
     metadata: &Metadata,
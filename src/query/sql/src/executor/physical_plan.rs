@@ -24,6 +24,7 @@ use itertools::Itertools;
 use crate::executor::physical_plans::AggregateExpand;
 use crate::executor::physical_plans::AggregateFinal;
 use crate::executor::physical_plans::AggregatePartial;
+use crate::executor::physical_plans::AsofJoin;
 use crate::executor::physical_plans::CommitSink;
 use crate::executor::physical_plans::CompactSource;
 use crate::executor::physical_plans::ConstantTableScan;
@@ -76,6 +77,7 @@ pub enum PhysicalPlan {
     RowFetch(RowFetch),
     HashJoin(HashJoin),
     RangeJoin(RangeJoin),
+    AsofJoin(AsofJoin),
     Exchange(Exchange),
     UnionAll(UnionAll),
     CteScan(CteScan),
@@ -139,6 +141,7 @@ impl PhysicalPlan {
             PhysicalPlan::RowFetch(v) => v.plan_id,
             PhysicalPlan::HashJoin(v) => v.plan_id,
             PhysicalPlan::RangeJoin(v) => v.plan_id,
+            PhysicalPlan::AsofJoin(v) => v.plan_id,
             PhysicalPlan::Exchange(v) => v.plan_id,
             PhysicalPlan::UnionAll(v) => v.plan_id,
             PhysicalPlan::DistributedInsertSelect(v) => v.plan_id,
@@ -185,6 +188,7 @@ impl PhysicalPlan {
             PhysicalPlan::UnionAll(plan) => plan.output_schema(),
             PhysicalPlan::ProjectSet(plan) => plan.output_schema(),
             PhysicalPlan::RangeJoin(plan) => plan.output_schema(),
+            PhysicalPlan::AsofJoin(plan) => plan.output_schema(),
             PhysicalPlan::CopyIntoTable(plan) => plan.output_schema(),
             PhysicalPlan::CteScan(plan) => plan.output_schema(),
             PhysicalPlan::MaterializedCte(plan) => plan.output_schema(),
@@ -231,6 +235,7 @@ impl PhysicalPlan {
             PhysicalPlan::DeleteSource(_) => "DeleteSource".to_string(),
             PhysicalPlan::CommitSink(_) => "CommitSink".to_string(),
             PhysicalPlan::RangeJoin(_) => "RangeJoin".to_string(),
+            PhysicalPlan::AsofJoin(_) => "AsofJoin".to_string(),
             PhysicalPlan::CopyIntoTable(_) => "CopyIntoTable".to_string(),
             PhysicalPlan::ReplaceAsyncSourcer(_) => "ReplaceAsyncSourcer".to_string(),
             PhysicalPlan::ReplaceDeduplicate(_) => "ReplaceDeduplicate".to_string(),
@@ -287,6 +292,9 @@ impl PhysicalPlan {
             PhysicalPlan::RangeJoin(plan) => Box::new(
                 std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
             ),
+            PhysicalPlan::AsofJoin(plan) => Box::new(
+                std::iter::once(plan.left.as_ref()).chain(std::iter::once(plan.right.as_ref())),
+            ),
             PhysicalPlan::ReplaceDeduplicate(plan) => {
                 Box::new(std::iter::once(plan.input.as_ref()))
             }
@@ -327,6 +335,7 @@ impl PhysicalPlan {
             | PhysicalPlan::ExchangeSource(_)
             | PhysicalPlan::HashJoin(_)
             | PhysicalPlan::RangeJoin(_)
+            | PhysicalPlan::AsofJoin(_)
             | PhysicalPlan::MaterializedCte(_)
             | PhysicalPlan::AggregateExpand(_)
             | PhysicalPlan::AggregateFinal(_)
@@ -496,6 +505,32 @@ impl PhysicalPlan {
 
                 condition.join(" AND ")
             }
+            PhysicalPlan::AsofJoin(v) => {
+                let mut conditions = v
+                    .left_keys
+                    .iter()
+                    .zip(v.right_keys.iter())
+                    .map(|(l, r)| {
+                        format!(
+                            "({} = {})",
+                            l.as_expr(&BUILTIN_FUNCTIONS).sql_display(),
+                            r.as_expr(&BUILTIN_FUNCTIONS).sql_display()
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let left = v
+                    .match_condition
+                    .left_expr
+                    .as_expr(&BUILTIN_FUNCTIONS)
+                    .sql_display();
+                let right = v
+                    .match_condition
+                    .right_expr
+                    .as_expr(&BUILTIN_FUNCTIONS)
+                    .sql_display();
+                conditions.push(format!("{left} {:?} {right}", v.match_condition.operator));
+                conditions.join(" AND ")
+            }
             PhysicalPlan::Udf(v) => v
                 .udf_funcs
                 .iter()
@@ -20,6 +20,8 @@ mod physical_aggregate_final;
 pub use physical_aggregate_final::AggregateFinal;
 mod physical_aggregate_partial;
 pub use physical_aggregate_partial::AggregatePartial;
+mod physical_asof_join;
+pub use physical_asof_join::AsofJoin;
 mod physical_commit_sink;
 pub use physical_commit_sink::CommitSink;
 mod physical_compact_source;
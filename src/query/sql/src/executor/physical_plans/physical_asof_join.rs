@@ -0,0 +1,161 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::type_check::check_cast;
+use databend_common_expression::type_check::common_super_type;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+use databend_common_expression::RemoteExpr;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use crate::executor::explain::PlanStatsInfo;
+use crate::executor::physical_plans::physical_hash_join::unify_exchange_keys;
+use crate::executor::physical_plans::physical_range_join::resolve_range_condition;
+use crate::executor::physical_plans::RangeJoinCondition;
+use crate::executor::PhysicalPlan;
+use crate::executor::PhysicalPlanBuilder;
+use crate::optimizer::ColumnSet;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::TypeCheck;
+
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct AsofJoin {
+    // A unique id of operator in a `PhysicalPlan` tree, only used for display.
+    pub plan_id: u32,
+    // The left side is streamed, each of its rows matches at most one row of the right side.
+    pub left: Box<PhysicalPlan>,
+    pub right: Box<PhysicalPlan>,
+    // The equi-conditions, rows only match the rows having the same keys.
+    pub left_keys: Vec<RemoteExpr>,
+    pub right_keys: Vec<RemoteExpr>,
+    // The closest row of the right side satisfying the match condition is matched,
+    // the operator is from the perspective of the left side.
+    pub match_condition: RangeJoinCondition,
+    // Asof or LeftAsof
+    pub join_type: JoinType,
+
+    // Only used for explain
+    pub stat_info: Option<PlanStatsInfo>,
+}
+
+impl AsofJoin {
+    pub fn output_schema(&self) -> Result<DataSchemaRef> {
+        let mut fields = self.left.output_schema()?.fields().clone();
+        let right_fields = self.right.output_schema()?.fields().clone();
+        if self.join_type == JoinType::LeftAsof {
+            fields.extend(
+                right_fields
+                    .into_iter()
+                    .map(|field| DataField::new(field.name(), field.data_type().wrap_nullable())),
+            );
+        } else {
+            fields.extend(right_fields);
+        }
+        Ok(DataSchemaRefExt::create(fields))
+    }
+}
+
+impl PhysicalPlanBuilder {
+    pub async fn build_asof_join(
+        &mut self,
+        join: &Join,
+        s_expr: &SExpr,
+        left_required: ColumnSet,
+        right_required: ColumnSet,
+        stat_info: PlanStatsInfo,
+    ) -> Result<PhysicalPlan> {
+        let left_prop = RelExpr::with_s_expr(s_expr.child(0)?).derive_relational_prop()?;
+        let right_prop = RelExpr::with_s_expr(s_expr.child(1)?).derive_relational_prop()?;
+
+        let mut left_side = self.build(s_expr.child(0)?, left_required).await?;
+        let mut right_side = self.build(s_expr.child(1)?, right_required).await?;
+        // Unify the data types of the left and right exchange keys.
+        unify_exchange_keys(&mut left_side, &mut right_side)?;
+
+        let left_schema = left_side.output_schema()?;
+        let right_schema = right_side.output_schema()?;
+
+        let mut left_keys = Vec::with_capacity(join.left_conditions.len());
+        let mut right_keys = Vec::with_capacity(join.right_conditions.len());
+        for (left_condition, right_condition) in join
+            .left_conditions
+            .iter()
+            .zip(join.right_conditions.iter())
+        {
+            let left_expr = left_condition
+                .type_check(left_schema.as_ref())?
+                .project_column_ref(|index| left_schema.index_of(&index.to_string()).unwrap());
+            let right_expr = right_condition
+                .type_check(right_schema.as_ref())?
+                .project_column_ref(|index| right_schema.index_of(&index.to_string()).unwrap());
+
+            // Unify the data types of the left and right keys.
+            let left_type = left_expr.data_type();
+            let right_type = right_expr.data_type();
+            let common_ty = common_super_type(
+                left_type.clone(),
+                right_type.clone(),
+                &BUILTIN_FUNCTIONS.default_cast_rules,
+            )
+            .ok_or_else(|| {
+                ErrorCode::IllegalDataType(format!(
+                    "Cannot find common type for {:?} and {:?}",
+                    left_type, right_type
+                ))
+            })?;
+            let left_expr = check_cast(
+                left_expr.span(),
+                false,
+                left_expr,
+                &common_ty,
+                &BUILTIN_FUNCTIONS,
+            )?;
+            let right_expr = check_cast(
+                right_expr.span(),
+                false,
+                right_expr,
+                &common_ty,
+                &BUILTIN_FUNCTIONS,
+            )?;
+            left_keys.push(left_expr.as_remote_expr());
+            right_keys.push(right_expr.as_remote_expr());
+        }
+
+        debug_assert_eq!(join.non_equi_conditions.len(), 1);
+        let match_condition = resolve_range_condition(
+            &join.non_equi_conditions[0],
+            &left_schema,
+            &right_schema,
+            &left_prop,
+            &right_prop,
+        )?;
+
+        Ok(PhysicalPlan::AsofJoin(AsofJoin {
+            plan_id: self.next_plan_id(),
+            left: Box::new(left_side),
+            right: Box::new(right_side),
+            left_keys,
+            right_keys,
+            match_condition,
+            join_type: join.join_type.clone(),
+            stat_info: Some(stat_info),
+        }))
+    }
+}
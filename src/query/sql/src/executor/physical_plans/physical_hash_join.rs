@@ -108,47 +108,7 @@ impl PhysicalPlanBuilder {
             is_broadcast = true;
         }
         // Unify the data types of the left and right exchange keys.
-        if let (
-            PhysicalPlan::Exchange(Exchange {
-                keys: probe_keys, ..
-            }),
-            PhysicalPlan::Exchange(Exchange {
-                keys: build_keys, ..
-            }),
-        ) = (probe_side.as_mut(), build_side.as_mut())
-        {
-            for (probe_key, build_key) in probe_keys.iter_mut().zip(build_keys.iter_mut()) {
-                let probe_expr = probe_key.as_expr(&BUILTIN_FUNCTIONS);
-                let build_expr = build_key.as_expr(&BUILTIN_FUNCTIONS);
-                let common_ty = common_super_type(
-                    probe_expr.data_type().clone(),
-                    build_expr.data_type().clone(),
-                    &BUILTIN_FUNCTIONS.default_cast_rules,
-                )
-                .ok_or_else(|| {
-                    ErrorCode::IllegalDataType(format!(
-                        "Cannot find common type for probe key {:?} and build key {:?}",
-                        &probe_expr, &build_expr
-                    ))
-                })?;
-                *probe_key = check_cast(
-                    probe_expr.span(),
-                    false,
-                    probe_expr,
-                    &common_ty,
-                    &BUILTIN_FUNCTIONS,
-                )?
-                .as_remote_expr();
-                *build_key = check_cast(
-                    build_expr.span(),
-                    false,
-                    build_expr,
-                    &common_ty,
-                    &BUILTIN_FUNCTIONS,
-                )?
-                .as_remote_expr();
-            }
-        }
+        unify_exchange_keys(&mut probe_side, &mut build_side)?;
 
         let build_schema = match join.join_type {
             JoinType::Left | JoinType::LeftSingle | JoinType::Full => {
@@ -383,7 +343,9 @@ impl PhysicalPlanBuilder {
             | JoinType::LeftSingle
             | JoinType::Right
             | JoinType::RightSingle
-            | JoinType::Full => {
+            | JoinType::Full
+            | JoinType::Asof
+            | JoinType::LeftAsof => {
                 probe_fields.extend(build_fields);
                 probe_fields
             }
@@ -502,3 +464,53 @@ impl PhysicalPlanBuilder {
         }))
     }
 }
+
+/// Unify the data types of the exchange keys of the probe side and the build side,
+/// so that the equal keys of both sides are shuffled to the same node.
+pub(crate) fn unify_exchange_keys(
+    probe_side: &mut PhysicalPlan,
+    build_side: &mut PhysicalPlan,
+) -> Result<()> {
+    if let (
+        PhysicalPlan::Exchange(Exchange {
+            keys: probe_keys, ..
+        }),
+        PhysicalPlan::Exchange(Exchange {
+            keys: build_keys, ..
+        }),
+    ) = (probe_side, build_side)
+    {
+        for (probe_key, build_key) in probe_keys.iter_mut().zip(build_keys.iter_mut()) {
+            let probe_expr = probe_key.as_expr(&BUILTIN_FUNCTIONS);
+            let build_expr = build_key.as_expr(&BUILTIN_FUNCTIONS);
+            let common_ty = common_super_type(
+                probe_expr.data_type().clone(),
+                build_expr.data_type().clone(),
+                &BUILTIN_FUNCTIONS.default_cast_rules,
+            )
+            .ok_or_else(|| {
+                ErrorCode::IllegalDataType(format!(
+                    "Cannot find common type for probe key {:?} and build key {:?}",
+                    &probe_expr, &build_expr
+                ))
+            })?;
+            *probe_key = check_cast(
+                probe_expr.span(),
+                false,
+                probe_expr,
+                &common_ty,
+                &BUILTIN_FUNCTIONS,
+            )?
+            .as_remote_expr();
+            *build_key = check_cast(
+                build_expr.span(),
+                false,
+                build_expr,
+                &common_ty,
+                &BUILTIN_FUNCTIONS,
+            )?
+            .as_remote_expr();
+        }
+    }
+    Ok(())
+}
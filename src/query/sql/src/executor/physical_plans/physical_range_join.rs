@@ -142,7 +142,7 @@ impl PhysicalPlanBuilder {
     }
 }
 
-fn resolve_range_condition(
+pub(crate) fn resolve_range_condition(
     expr: &ScalarExpr,
     left_schema: &DataSchemaRef,
     right_schema: &DataSchemaRef,
@@ -26,6 +26,7 @@ use crate::executor::explain::PlanStatsInfo;
 use crate::executor::PhysicalPlan;
 use crate::executor::PhysicalPlanBuilder;
 use crate::optimizer::SExpr;
+use crate::plans::UDFType;
 use crate::ColumnSet;
 use crate::IndexType;
 use crate::ScalarExpr;
@@ -57,7 +58,7 @@ impl Udf {
 #[derive(Clone, Debug, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct UdfFunctionDesc {
     pub func_name: String,
-    pub server_addr: String,
+    pub udf_type: UDFType,
     pub output_column: IndexType,
     pub arg_indices: Vec<IndexType>,
     pub arg_exprs: Vec<String>,
@@ -134,7 +135,7 @@ impl PhysicalPlanBuilder {
 
                     let udf_func = UdfFunctionDesc {
                         func_name: func.func_name.clone(),
-                        server_addr: func.server_addr.clone(),
+                        udf_type: func.udf_type.clone(),
                         output_column: item.index,
                         arg_indices,
                         arg_exprs,
@@ -142,6 +142,9 @@ pub struct BindContext {
     /// If true, the query is planning for aggregate index.
     /// It's used to avoid infinite loop.
     pub planning_agg_index: bool,
+    /// If true, the query is planning for materialized view.
+    /// The tables of the query are not checked for privileges, like views.
+    pub planning_materialized_view: bool,
 
     pub window_definitions: DashMap<String, WindowSpec>,
 }
@@ -176,6 +179,7 @@ impl BindContext {
             srfs: DashMap::new(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
+            planning_materialized_view: false,
             window_definitions: DashMap::new(),
         }
     }
@@ -195,6 +199,7 @@ impl BindContext {
             srfs: DashMap::new(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
+            planning_materialized_view: parent.planning_materialized_view,
             window_definitions: DashMap::new(),
         }
     }
@@ -178,9 +178,12 @@ impl<'a> Binder {
         bind_ctx: &BindContext,
         plan: CopyIntoTablePlan,
     ) -> Result<Plan> {
-        if let FileFormatParams::Parquet(fmt) = &plan.stage_table_info.stage_info.file_format_params
-            && fmt.missing_field_as == NullAs::Error
-        {
+        let use_query = match &plan.stage_table_info.stage_info.file_format_params {
+            FileFormatParams::Parquet(fmt) => fmt.missing_field_as == NullAs::Error,
+            FileFormatParams::Orc(fmt) => fmt.missing_field_as == NullAs::Error,
+            _ => false,
+        };
+        if use_query {
             let mut select_list = Vec::with_capacity(plan.required_source_schema.num_fields());
             for dest_field in plan.required_source_schema.fields().iter() {
                 let column = Expr::ColumnRef {
@@ -12,12 +12,19 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
 use databend_common_ast::ast::CreateIndexStmt;
+use databend_common_ast::ast::CreateInvertedIndexStmt;
+use databend_common_ast::ast::CreateVectorIndexStmt;
 use databend_common_ast::ast::DropIndexStmt;
 use databend_common_ast::ast::ExplainKind;
 use databend_common_ast::ast::Identifier;
 use databend_common_ast::ast::Query;
 use databend_common_ast::ast::RefreshIndexStmt;
+use databend_common_ast::ast::RefreshInvertedIndexStmt;
+use databend_common_ast::ast::RefreshVectorIndexStmt;
 use databend_common_ast::ast::SetExpr;
 use databend_common_ast::ast::Statement;
 use databend_common_ast::ast::TableReference;
@@ -26,22 +33,38 @@ use databend_common_ast::parser::tokenize_sql;
 use databend_common_ast::walk_statement_mut;
 use databend_common_ast::Visitor;
 use databend_common_ast::VisitorMut;
+use databend_common_catalog::catalog::Catalog;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::TableDataType;
 use databend_common_license::license::Feature::AggregateIndex;
 use databend_common_license::license_manager::get_license_manager;
+use databend_common_meta_app::schema::DatabaseType;
 use databend_common_meta_app::schema::GetIndexReq;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::IndexNameIdent;
+use databend_common_meta_app::schema::IndexType;
+use databend_common_meta_app::schema::TableInfo;
+use databend_storages_common_index::VectorIndexParams;
+use databend_storages_common_index::INVERTED_INDEX_COLUMN;
+use databend_storages_common_index::VECTOR_INDEX_COLUMN;
+use databend_storages_common_index::VECTOR_INDEX_DISTANCE;
+use databend_storages_common_index::VECTOR_INDEX_EF_CONSTRUCTION;
+use databend_storages_common_index::VECTOR_INDEX_M;
 use databend_storages_common_table_meta::meta::Location;
 
 use crate::binder::Binder;
 use crate::optimizer::optimize;
 use crate::optimizer::OptimizerContext;
 use crate::plans::CreateIndexPlan;
+use crate::plans::CreateInvertedIndexPlan;
+use crate::plans::CreateVectorIndexPlan;
 use crate::plans::DropIndexPlan;
 use crate::plans::Plan;
 use crate::plans::RefreshIndexPlan;
+use crate::plans::RefreshInvertedIndexPlan;
+use crate::plans::RefreshVectorIndexPlan;
 use crate::AggregatingIndexChecker;
 use crate::AggregatingIndexRewriter;
 use crate::BindContext;
@@ -114,6 +137,9 @@ impl Binder {
 
                     let mut s_exprs = Vec::with_capacity(indexes.len());
                     for (index_id, _, index_meta) in indexes {
+                        if index_meta.index_type != IndexType::AGGREGATING {
+                            continue;
+                        }
                         let tokens = tokenize_sql(&index_meta.query)?;
                         let (stmt, _) = parse_sql(&tokens, self.dialect)?;
                         let mut new_bind_context =
@@ -136,6 +162,47 @@ impl Binder {
                     .write()
                     .add_agg_indexes(full_table_name, agg_indexes);
             }
+
+            let settings = self.ctx.get_settings();
+            let enable_vector_index = settings.get_enable_vector_index_scan()?;
+            let enable_inverted_index = settings.get_enable_inverted_index_scan()?;
+            if (enable_vector_index || enable_inverted_index)
+                && !bind_context.planning_agg_index
+                && table.support_index()
+                && !matches!(table.engine(), "VIEW" | "STREAM")
+            {
+                let indexes = self
+                    .resolve_table_indexes(
+                        self.ctx.get_tenant().as_str(),
+                        table_entry.catalog(),
+                        table.get_id(),
+                    )
+                    .await?;
+                let (vector_indexes, inverted_indexes): (Vec<_>, Vec<_>) = indexes
+                    .into_iter()
+                    .filter(|(_, _, index_meta)| {
+                        (enable_vector_index && index_meta.index_type == IndexType::VECTOR)
+                            || (enable_inverted_index
+                                && index_meta.index_type == IndexType::INVERTED)
+                    })
+                    .partition(|(_, _, index_meta)| index_meta.index_type == IndexType::VECTOR);
+                let full_table_name = format!(
+                    "{}.{}.{}",
+                    table_entry.catalog(),
+                    table_entry.database(),
+                    table.name()
+                );
+                if !vector_indexes.is_empty() {
+                    metadata
+                        .write()
+                        .add_vector_indexes(full_table_name.clone(), vector_indexes);
+                }
+                if !inverted_indexes.is_empty() {
+                    metadata
+                        .write()
+                        .add_inverted_indexes(full_table_name, inverted_indexes);
+                }
+            }
         }
 
         Ok(())
@@ -259,6 +326,21 @@ impl Binder {
         };
 
         let res = catalog.get_index(get_index_req).await?;
+        match res.index_meta.index_type {
+            IndexType::VECTOR => {
+                return Err(ErrorCode::UnsupportedIndex(format!(
+                    "Index {} is a vector index, please use REFRESH VECTOR INDEX",
+                    index_name
+                )));
+            }
+            IndexType::INVERTED => {
+                return Err(ErrorCode::UnsupportedIndex(format!(
+                    "Index {} is an inverted index, please use REFRESH INVERTED INDEX",
+                    index_name
+                )));
+            }
+            _ => {}
+        }
 
         let index_id = res.index_id;
         let index_meta = res.index_meta;
@@ -333,6 +415,251 @@ impl Binder {
         Ok(plan)
     }
 
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_vector_index(
+        &mut self,
+        stmt: &CreateVectorIndexStmt,
+    ) -> Result<Plan> {
+        let CreateVectorIndexStmt {
+            if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            column,
+            index_options,
+            sync_creation,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+        let column = self.normalize_object_identifier(column);
+
+        let table = self.ctx.get_table(&catalog, &database, &table).await?;
+        if !table.support_index() {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Table engine {} does not support create index",
+                table.engine()
+            )));
+        }
+
+        let schema = table.schema();
+        let field = schema.field_with_name(&column).map_err(|_| {
+            ErrorCode::UnsupportedIndex(format!(
+                "Column {} does not exist in table {}",
+                column,
+                table.name()
+            ))
+        })?;
+        let is_vector = match field.data_type().remove_nullable() {
+            TableDataType::Array(box item_type) => matches!(
+                item_type.remove_nullable(),
+                TableDataType::Number(NumberDataType::Float32)
+            ),
+            _ => false,
+        };
+        if !is_vector {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Vector index only supports ARRAY(FLOAT32) column, but column {} is {}",
+                column,
+                field.data_type()
+            )));
+        }
+
+        let options = index_options
+            .iter()
+            .map(|(k, v)| (k.to_lowercase(), v.clone()))
+            .collect::<BTreeMap<_, _>>();
+        if options.contains_key(VECTOR_INDEX_COLUMN) {
+            return Err(ErrorCode::UnsupportedIndex(
+                "The indexed column should be specified after the table name",
+            ));
+        }
+        let params = VectorIndexParams::try_from_options(&options)?;
+        let options = BTreeMap::from([
+            (VECTOR_INDEX_COLUMN.to_string(), column.clone()),
+            (
+                VECTOR_INDEX_DISTANCE.to_string(),
+                params.distance.to_string(),
+            ),
+            (VECTOR_INDEX_M.to_string(), params.m.to_string()),
+            (
+                VECTOR_INDEX_EF_CONSTRUCTION.to_string(),
+                params.ef_construction.to_string(),
+            ),
+        ]);
+
+        let plan = CreateVectorIndexPlan {
+            if_not_exists: *if_not_exists,
+            index_name,
+            table_id: table.get_id(),
+            query: format!("{}.{}({})", database, table.name(), column),
+            options,
+            sync_creation: *sync_creation,
+        };
+        Ok(Plan::CreateVectorIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_vector_index(
+        &mut self,
+        stmt: &RefreshVectorIndexStmt,
+    ) -> Result<Plan> {
+        let RefreshVectorIndexStmt { index, limit } = stmt;
+
+        if limit.is_some() && limit.unwrap() < 1 {
+            return Err(ErrorCode::RefreshIndexError(format!(
+                "Invalid 'limit' value: {}. 'limit' must be greater than or equal to 1.",
+                limit.unwrap()
+            )));
+        }
+
+        let index_name = self.normalize_object_identifier(index);
+        let catalog = self
+            .ctx
+            .get_catalog(&self.ctx.get_current_catalog())
+            .await?;
+        let get_index_req = GetIndexReq {
+            name_ident: IndexNameIdent {
+                tenant: self.ctx.get_tenant(),
+                index_name: index_name.clone(),
+            },
+        };
+
+        let res = catalog.get_index(get_index_req).await?;
+        if res.index_meta.index_type != IndexType::VECTOR {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Index {} is not a vector index",
+                index_name
+            )));
+        }
+
+        let table_info = Self::get_index_table_info(&catalog, res.index_meta.table_id).await?;
+        let plan = RefreshVectorIndexPlan {
+            index_id: res.index_id,
+            index_name,
+            index_meta: res.index_meta,
+            limit: *limit,
+            table_info,
+            segment_locs: None,
+        };
+        Ok(Plan::RefreshVectorIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_inverted_index(
+        &mut self,
+        stmt: &CreateInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let CreateInvertedIndexStmt {
+            if_not_exists,
+            index_name,
+            catalog,
+            database,
+            table,
+            column,
+            sync_creation,
+        } = stmt;
+
+        let (catalog, database, table) =
+            self.normalize_object_identifier_triple(catalog, database, table);
+        let index_name = self.normalize_object_identifier(index_name);
+        let column = self.normalize_object_identifier(column);
+
+        let table = self.ctx.get_table(&catalog, &database, &table).await?;
+        if !table.support_index() {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Table engine {} does not support create index",
+                table.engine()
+            )));
+        }
+
+        let schema = table.schema();
+        let field = schema.field_with_name(&column).map_err(|_| {
+            ErrorCode::UnsupportedIndex(format!(
+                "Column {} does not exist in table {}",
+                column,
+                table.name()
+            ))
+        })?;
+        if field.data_type().remove_nullable() != TableDataType::String {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Inverted index only supports STRING column, but column {} is {}",
+                column,
+                field.data_type()
+            )));
+        }
+
+        let plan = CreateInvertedIndexPlan {
+            if_not_exists: *if_not_exists,
+            index_name,
+            table_id: table.get_id(),
+            query: format!("{}.{}({})", database, table.name(), column),
+            options: BTreeMap::from([(INVERTED_INDEX_COLUMN.to_string(), column)]),
+            sync_creation: *sync_creation,
+        };
+        Ok(Plan::CreateInvertedIndex(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_inverted_index(
+        &mut self,
+        stmt: &RefreshInvertedIndexStmt,
+    ) -> Result<Plan> {
+        let RefreshInvertedIndexStmt { index, limit } = stmt;
+
+        if limit.is_some() && limit.unwrap() < 1 {
+            return Err(ErrorCode::RefreshIndexError(format!(
+                "Invalid 'limit' value: {}. 'limit' must be greater than or equal to 1.",
+                limit.unwrap()
+            )));
+        }
+
+        let index_name = self.normalize_object_identifier(index);
+        let catalog = self
+            .ctx
+            .get_catalog(&self.ctx.get_current_catalog())
+            .await?;
+        let get_index_req = GetIndexReq {
+            name_ident: IndexNameIdent {
+                tenant: self.ctx.get_tenant(),
+                index_name: index_name.clone(),
+            },
+        };
+
+        let res = catalog.get_index(get_index_req).await?;
+        if res.index_meta.index_type != IndexType::INVERTED {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Index {} is not an inverted index",
+                index_name
+            )));
+        }
+
+        let table_info = Self::get_index_table_info(&catalog, res.index_meta.table_id).await?;
+        let plan = RefreshInvertedIndexPlan {
+            index_id: res.index_id,
+            index_name,
+            index_meta: res.index_meta,
+            limit: *limit,
+            table_info,
+            segment_locs: None,
+        };
+        Ok(Plan::RefreshInvertedIndex(Box::new(plan)))
+    }
+
+    async fn get_index_table_info(catalog: &Arc<dyn Catalog>, table_id: u64) -> Result<TableInfo> {
+        let (ident, meta) = catalog.get_table_meta_by_id(table_id).await?;
+        Ok(TableInfo {
+            ident,
+            desc: "".to_owned(),
+            name: catalog.get_table_name_by_id(table_id).await?,
+            meta: meta.as_ref().clone(),
+            tenant: "".to_owned(),
+            db_type: DatabaseType::NormalDB,
+        })
+    }
+
     fn rewrite_query_with_database(query: &mut Query, name: &str) {
         if let SetExpr::Select(stmt) = &mut query.body {
             if let TableReference::Table { database, .. } = &mut stmt.from[0] {
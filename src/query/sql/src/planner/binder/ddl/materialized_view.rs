@@ -0,0 +1,440 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::collections::HashSet;
+
+use databend_common_ast::ast::ClusterType;
+use databend_common_ast::ast::CreateMaterializedViewStmt;
+use databend_common_ast::ast::CreateTableStmt;
+use databend_common_ast::ast::DropMaterializedViewStmt;
+use databend_common_ast::ast::Engine;
+use databend_common_ast::ast::ExplainKind;
+use databend_common_ast::ast::RefreshMaterializedViewStmt;
+use databend_common_ast::ast::Statement;
+use databend_common_ast::parser::parse_sql;
+use databend_common_ast::parser::tokenize_sql;
+use databend_common_ast::VisitorMut;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table::TableExt;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_license::license::Feature;
+use databend_common_license::license_manager::get_license_manager;
+use databend_common_meta_app::schema::CreateOption;
+use databend_common_storages_view::view_table::VIEW_ENGINE;
+use databend_storages_common_table_meta::table::MATERIALIZED_VIEW_REFRESH_FULL;
+use databend_storages_common_table_meta::table::MATERIALIZED_VIEW_REFRESH_INCREMENTAL;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEWS;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_QUERY;
+use databend_storages_common_table_meta::table::OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use log::info;
+
+use crate::binder::Binder;
+use crate::binder::Visibility;
+use crate::optimizer::MaterializedViewCandidate;
+use crate::optimizer::SExpr;
+use crate::plans::materialized_view_stream_name;
+use crate::plans::CreateMaterializedViewPlan;
+use crate::plans::DropMaterializedViewPlan;
+use crate::plans::Plan;
+use crate::plans::RefreshMaterializedViewPlan;
+use crate::plans::RelOperator;
+use crate::BindContext;
+use crate::MetadataRef;
+use crate::ViewRewriter;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_materialized_view(
+        &mut self,
+        stmt: &CreateMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let CreateMaterializedViewStmt {
+            if_not_exists,
+            catalog,
+            database,
+            view,
+            query,
+        } = stmt;
+
+        let license_manager = get_license_manager();
+        license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::Stream)?;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog_name, database_name, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let mut query = *query.clone();
+        let mut visitor = ViewRewriter {
+            current_database: database_name.clone(),
+        };
+        visitor.visit_query(&mut query);
+        let subquery = format!("{}", query);
+
+        // Bind the query to find the tables it reads from.
+        let table_count = self.metadata.read().tables().len();
+        let mut bind_context = BindContext::new();
+        bind_context.planning_materialized_view = true;
+        let (s_expr, _) = self.bind_query(&mut bind_context, &query).await?;
+
+        let mut base_tables = vec![];
+        let mut base_table_ids = HashSet::new();
+        for table_entry in self.metadata.read().tables()[table_count..].iter() {
+            let table = table_entry.table();
+            if table.engine() == VIEW_ENGINE {
+                continue;
+            }
+            if table.engine() != "FUSE" || table.is_materialized_view() {
+                return Err(ErrorCode::SemanticError(format!(
+                    "Materialized view can only be defined on fuse tables, but '{}.{}' is not",
+                    table_entry.database(),
+                    table_entry.name()
+                )));
+            }
+            if table_entry.catalog() != catalog_name {
+                return Err(ErrorCode::SemanticError(format!(
+                    "The table '{}.{}' of materialized view must be in the catalog '{}'",
+                    table_entry.database(),
+                    table_entry.name(),
+                    catalog_name
+                )));
+            }
+            if base_table_ids.insert(table.get_id()) {
+                base_tables.push((
+                    table_entry.catalog().to_string(),
+                    table_entry.database().to_string(),
+                    table_entry.name().to_string(),
+                ));
+            }
+        }
+        if base_tables.is_empty() {
+            return Err(ErrorCode::SemanticError(
+                "Materialized view must read from at least one table",
+            ));
+        }
+
+        // The changes of a single table can be applied incrementally if every row of
+        // the view is derived from one row of the table.
+        let refresh_mode = if base_tables.len() == 1 && Self::is_row_wise_query(&s_expr) {
+            MATERIALIZED_VIEW_REFRESH_INCREMENTAL
+        } else {
+            MATERIALIZED_VIEW_REFRESH_FULL
+        };
+
+        let create_table_stmt = CreateTableStmt {
+            create_option: CreateOption::CreateIfNotExists(*if_not_exists),
+            catalog: catalog.clone(),
+            database: database.clone(),
+            table: view.clone(),
+            source: None,
+            engine: Some(Engine::Fuse),
+            uri_location: None,
+            cluster_type: ClusterType::Linear,
+            cluster_by: vec![],
+            table_options: BTreeMap::new(),
+            as_query: Some(Box::new(query)),
+            transient: false,
+        };
+        let Plan::CreateTable(mut create_table_plan) =
+            self.bind_create_table(&create_table_stmt).await?
+        else {
+            unreachable!()
+        };
+        // The view is populated by its refresh.
+        create_table_plan.as_select = None;
+        create_table_plan.options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_QUERY.to_string(),
+            subquery.clone(),
+        );
+        create_table_plan.options.insert(
+            OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE.to_string(),
+            refresh_mode.to_string(),
+        );
+
+        let plan = CreateMaterializedViewPlan {
+            if_not_exists: *if_not_exists,
+            tenant,
+            catalog: catalog_name,
+            database: database_name,
+            view_name,
+            subquery,
+            create_table_plan: *create_table_plan,
+            base_tables,
+        };
+        Ok(Plan::CreateMaterializedView(plan.into()))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_materialized_view(
+        &mut self,
+        stmt: &DropMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let DropMaterializedViewStmt {
+            if_exists,
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let plan = DropMaterializedViewPlan {
+            if_exists: *if_exists,
+            tenant,
+            catalog,
+            database,
+            view_name,
+        };
+        Ok(Plan::DropMaterializedView(plan.into()))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_refresh_materialized_view(
+        &mut self,
+        stmt: &RefreshMaterializedViewStmt,
+    ) -> Result<Plan> {
+        let RefreshMaterializedViewStmt {
+            catalog,
+            database,
+            view,
+        } = stmt;
+
+        let tenant = self.ctx.get_tenant();
+        let (catalog, database, view_name) =
+            self.normalize_object_identifier_triple(catalog, database, view);
+        let plan = RefreshMaterializedViewPlan {
+            tenant,
+            catalog,
+            database,
+            view_name,
+        };
+        Ok(Plan::RefreshMaterializedView(plan.into()))
+    }
+
+    /// Binds the materialized views of the tables read by the query,
+    /// which are used by the optimizer to rewrite the query.
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_query_materialized_view(
+        &mut self,
+        bind_context: &mut BindContext,
+        plan: &Plan,
+    ) -> Result<()> {
+        match plan {
+            Plan::Query { metadata, .. } => {
+                self.do_bind_query_materialized_view(bind_context, metadata)
+                    .await?;
+            }
+            Plan::Explain { kind, plan }
+                if matches!(kind, ExplainKind::Plan) && matches!(**plan, Plan::Query { .. }) =>
+            {
+                match **plan {
+                    Plan::Query { ref metadata, .. } => {
+                        self.do_bind_query_materialized_view(bind_context, metadata)
+                            .await?;
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+
+    #[async_backtrace::framed]
+    async fn do_bind_query_materialized_view(
+        &mut self,
+        bind_context: &BindContext,
+        metadata: &MetadataRef,
+    ) -> Result<()> {
+        // Avoid death loop
+        if bind_context.planning_materialized_view
+            || !self
+                .ctx
+                .get_settings()
+                .get_enable_materialized_view_rewrite()?
+        {
+            return Ok(());
+        }
+        let license_manager = get_license_manager();
+        if license_manager
+            .manager
+            .check_enterprise_enabled(self.ctx.get_license_key(), Feature::Stream)
+            .is_err()
+        {
+            return Ok(());
+        }
+
+        let mut views = BTreeSet::new();
+        for table_entry in metadata.read().tables() {
+            if table_entry.is_source_of_index() {
+                continue;
+            }
+            if let Some(view_ids) = table_entry
+                .table()
+                .options()
+                .get(OPT_KEY_MATERIALIZED_VIEWS)
+            {
+                for view_id in view_ids.split(',') {
+                    if let Ok(view_id) = view_id.parse::<u64>() {
+                        views.insert((table_entry.catalog().to_string(), view_id));
+                    }
+                }
+            }
+        }
+
+        for (catalog, view_id) in views {
+            match self
+                .bind_materialized_view_candidate(&catalog, view_id)
+                .await
+            {
+                Ok(Some(candidate)) => metadata.write().add_materialized_view(candidate),
+                Ok(None) => {}
+                // A broken view, e.g. one of its tables is dropped, is just not used.
+                Err(e) => info!("Ignore the materialized view {view_id}: {e}"),
+            }
+        }
+        Ok(())
+    }
+
+    /// Binds a materialized view which is up to date, `None` if the view is stale.
+    #[async_backtrace::framed]
+    async fn bind_materialized_view_candidate(
+        &mut self,
+        catalog_name: &str,
+        view_id: u64,
+    ) -> Result<Option<MaterializedViewCandidate>> {
+        let catalog = self.ctx.get_catalog(catalog_name).await?;
+        let (_, table_meta) = catalog.get_table_meta_by_id(view_id).await?;
+        let Some(db_id) = table_meta.options.get(OPT_KEY_DATABASE_ID) else {
+            return Ok(None);
+        };
+        let database = catalog.get_db_name_by_id(db_id.parse::<u64>()?).await?;
+        let view_name = catalog.get_table_name_by_id(view_id).await?;
+        let view = self
+            .ctx
+            .get_table(catalog_name, &database, &view_name)
+            .await?;
+        if view.get_id() != view_id || !view.is_materialized_view() {
+            return Ok(None);
+        }
+        // The view is not populated yet.
+        if snapshot_location(view.as_ref()).is_none() {
+            return Ok(None);
+        }
+        let Some(query) = view.options().get(OPT_KEY_MATERIALIZED_VIEW_QUERY) else {
+            return Ok(None);
+        };
+        let tokens = tokenize_sql(query)?;
+        let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+        let Statement::Query(query) = &stmt else {
+            return Ok(None);
+        };
+
+        let table_count = self.metadata.read().tables().len();
+        let mut query_bind_context = BindContext::new();
+        query_bind_context.planning_materialized_view = true;
+        let (query_s_expr, query_bind_context) =
+            self.bind_query(&mut query_bind_context, query).await?;
+
+        // All the changes of the tables must have been applied to the view,
+        // the internal streams are consumed to the current snapshots by the refresh.
+        let base_tables = self.metadata.read().tables()[table_count..]
+            .iter()
+            .filter(|table_entry| table_entry.table().engine() != VIEW_ENGINE)
+            .map(|table_entry| table_entry.table())
+            .collect::<Vec<_>>();
+        for base_table in base_tables {
+            let stream_name = materialized_view_stream_name(view_id, base_table.get_id());
+            let stream = self
+                .ctx
+                .get_table(catalog_name, &database, &stream_name)
+                .await?;
+            if snapshot_location(stream.as_ref()) != snapshot_location(base_table.as_ref()) {
+                return Ok(None);
+            }
+        }
+
+        let table_index = self.metadata.write().add_table(
+            catalog_name.to_string(),
+            database.clone(),
+            view.clone(),
+            None,
+            true,
+            false,
+            false,
+        );
+        let (scan, scan_bind_context) = self
+            .bind_base_table(&BindContext::new(), &database, table_index, None)
+            .await?;
+        let scan_columns = view
+            .schema()
+            .fields()
+            .iter()
+            .map(|field| {
+                scan_bind_context
+                    .columns
+                    .iter()
+                    .find(|column| {
+                        &column.column_name == field.name()
+                            && matches!(column.visibility, Visibility::Visible)
+                    })
+                    .cloned()
+            })
+            .collect::<Option<Vec<_>>>();
+        let query_columns = query_bind_context
+            .columns
+            .iter()
+            .map(|column| column.index)
+            .collect::<Vec<_>>();
+        match scan_columns {
+            Some(scan_columns) if scan_columns.len() == query_columns.len() => {
+                Ok(Some(MaterializedViewCandidate {
+                    query: query_s_expr,
+                    query_columns,
+                    scan,
+                    scan_columns,
+                }))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    fn is_row_wise_query(s_expr: &SExpr) -> bool {
+        if s_expr.contain_subquery() {
+            return false;
+        }
+        match s_expr.plan() {
+            RelOperator::Scan(_) => true,
+            RelOperator::Filter(_) | RelOperator::EvalScalar(_) => {
+                s_expr.child(0).map_or(false, Self::is_row_wise_query)
+            }
+            _ => false,
+        }
+    }
+}
+
+fn snapshot_location(table: &dyn Table) -> Option<&String> {
+    let options = table.options();
+    options
+        .get(OPT_KEY_SNAPSHOT_LOCATION)
+        // for backward compatibility, we check the legacy table option
+        .or_else(|| options.get(OPT_KEY_LEGACY_SNAPSHOT_LOC))
+}
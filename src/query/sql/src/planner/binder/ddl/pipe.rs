@@ -0,0 +1,160 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use chrono::DateTime;
+use databend_common_ast::ast::AlterPipeOptions;
+use databend_common_ast::ast::AlterPipeStmt;
+use databend_common_ast::ast::CopyIntoTableSource;
+use databend_common_ast::ast::CreatePipeStmt;
+use databend_common_ast::ast::DescribePipeStmt;
+use databend_common_ast::ast::DropPipeStmt;
+use databend_common_ast::ast::Identifier;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+
+use crate::binder::resolve_file_location;
+use crate::plans::AlterPipePlan;
+use crate::plans::CreatePipePlan;
+use crate::plans::DescribePipePlan;
+use crate::plans::DropPipePlan;
+use crate::plans::Plan;
+use crate::Binder;
+
+impl Binder {
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_create_pipe(
+        &mut self,
+        stmt: &CreatePipeStmt,
+    ) -> Result<Plan> {
+        let CreatePipeStmt {
+            if_not_exists,
+            name,
+            auto_ingest,
+            comments,
+            copy_stmt,
+        } = stmt;
+
+        // The pipe relies on the copied files of the table to load each file only once.
+        if copy_stmt.force {
+            return Err(ErrorCode::SemanticError(
+                "COPY INTO statement of a pipe can not be FORCE".to_string(),
+            ));
+        }
+        if !copy_stmt.validation_mode.is_empty() {
+            return Err(ErrorCode::SemanticError(
+                "COPY INTO statement of a pipe can not have VALIDATION_MODE".to_string(),
+            ));
+        }
+
+        // The pipe is run in other sessions, so the database of the table must be resolved.
+        let (catalog_name, database_name, table_name) = self.normalize_object_identifier_triple(
+            &copy_stmt.dst.catalog,
+            &copy_stmt.dst.database,
+            &copy_stmt.dst.table,
+        );
+        self.ctx
+            .get_table(&catalog_name, &database_name, &table_name)
+            .await?;
+        if let CopyIntoTableSource::Location(location) = &copy_stmt.src {
+            resolve_file_location(self.ctx.as_ref(), location).await?;
+        }
+        let mut copy_stmt = copy_stmt.clone();
+        if copy_stmt.dst.database.is_none() {
+            copy_stmt.dst.database =
+                Some(Identifier::from_name_with_quoted(database_name, Some('`')));
+        }
+
+        let plan = CreatePipePlan {
+            if_not_exists: *if_not_exists,
+            tenant: self.ctx.get_tenant(),
+            pipe_name: name.to_string(),
+            auto_ingest: *auto_ingest,
+            comment: comments.clone(),
+            copy_sql: copy_stmt.to_string(),
+        };
+        Ok(Plan::CreatePipe(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_alter_pipe(
+        &mut self,
+        stmt: &AlterPipeStmt,
+    ) -> Result<Plan> {
+        let AlterPipeStmt {
+            if_exists,
+            name,
+            options,
+        } = stmt;
+
+        match options {
+            AlterPipeOptions::Set {
+                execution_paused,
+                comments,
+            } => {
+                if execution_paused.is_none() && comments.is_none() {
+                    return Err(ErrorCode::SyntaxException(
+                        "alter pipe must set at least one option".to_string(),
+                    ));
+                }
+            }
+            AlterPipeOptions::Refresh { modified_after, .. } => {
+                if let Some(modified_after) = modified_after {
+                    DateTime::parse_from_rfc3339(modified_after).map_err(|e| {
+                        ErrorCode::SemanticError(format!(
+                            "invalid MODIFIED_AFTER '{}', expect a RFC 3339 timestamp: {}",
+                            modified_after, e
+                        ))
+                    })?;
+                }
+            }
+        }
+
+        let plan = AlterPipePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            pipe_name: name.to_string(),
+            options: options.clone(),
+        };
+        Ok(Plan::AlterPipe(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_drop_pipe(
+        &mut self,
+        stmt: &DropPipeStmt,
+    ) -> Result<Plan> {
+        let DropPipeStmt { if_exists, name } = stmt;
+
+        let plan = DropPipePlan {
+            if_exists: *if_exists,
+            tenant: self.ctx.get_tenant(),
+            pipe_name: name.to_string(),
+        };
+        Ok(Plan::DropPipe(Box::new(plan)))
+    }
+
+    #[async_backtrace::framed]
+    pub(in crate::planner::binder) async fn bind_describe_pipe(
+        &mut self,
+        stmt: &DescribePipeStmt,
+    ) -> Result<Plan> {
+        let DescribePipeStmt { name } = stmt;
+
+        let plan = DescribePipePlan {
+            tenant: self.ctx.get_tenant(),
+            pipe_name: name.to_string(),
+        };
+        Ok(Plan::DescribePipe(Box::new(plan)))
+    }
+}
@@ -42,6 +42,8 @@ use databend_common_ast::ast::ShowLimit;
 use databend_common_ast::ast::ShowTablesStatusStmt;
 use databend_common_ast::ast::ShowTablesStmt;
 use databend_common_ast::ast::Statement;
+use databend_common_ast::ast::TableConstraint as AstTableConstraint;
+use databend_common_ast::ast::TableConstraintKind;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::ast::TruncateTableStmt;
 use databend_common_ast::ast::UndropTableStmt;
@@ -66,6 +68,7 @@ use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableConstraint;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_storage::DataOperator;
 use databend_common_storages_delta::DeltaTable;
@@ -406,6 +409,7 @@ impl Binder {
             table,
             source,
             table_options,
+            cluster_type,
             cluster_by,
             as_query,
             transient,
@@ -622,10 +626,30 @@ impl Binder {
             if keys.is_empty() {
                 None
             } else {
-                Some(format!("({})", keys.join(", ")))
+                Some(format!("{}({})", cluster_type, keys.join(", ")))
             }
         };
 
+        let constraints = match source {
+            Some(CreateTableSource::Columns(_, constraints)) if !constraints.is_empty() => {
+                if engine != Engine::Fuse {
+                    return Err(ErrorCode::TableEngineNotSupported(format!(
+                        "Table constraints are not supported by the {} engine",
+                        engine
+                    )));
+                }
+                self.analyze_table_constraints(
+                    constraints,
+                    schema.clone(),
+                    &catalog,
+                    &database,
+                    &table,
+                )
+                .await?
+            }
+            _ => BTreeMap::new(),
+        };
+
         let plan = CreateTablePlan {
             create_option: create_option.clone(),
             tenant: self.ctx.get_tenant(),
@@ -641,6 +665,7 @@ impl Binder {
             options,
             field_comments,
             cluster_key,
+            constraints,
             as_select: if let Some(query) = as_query {
                 let mut bind_context = BindContext::new();
                 let stmt = Statement::Query(Box::new(*query.clone()));
@@ -723,6 +748,7 @@ impl Binder {
             options,
             field_comments: vec![],
             cluster_key: None,
+            constraints: Default::default(),
             as_select: None,
         })))
     }
@@ -910,7 +936,10 @@ impl Binder {
                     column: column.to_string(),
                 })))
             }
-            AlterTableAction::AlterTableClusterKey { cluster_by } => {
+            AlterTableAction::AlterTableClusterKey {
+                cluster_type,
+                cluster_by,
+            } => {
                 let schema = self
                     .ctx
                     .get_table(&catalog, &database, &table)
@@ -924,6 +953,7 @@ impl Binder {
                         catalog,
                         database,
                         table,
+                        cluster_type: *cluster_type,
                         cluster_keys,
                     },
                 )))
@@ -1433,18 +1463,7 @@ impl Binder {
         schema: TableSchemaRef,
     ) -> Result<Vec<String>> {
         // Build a temporary BindContext to resolve the expr
-        let mut bind_context = BindContext::new();
-        for (index, field) in schema.fields().iter().enumerate() {
-            let column = ColumnBindingBuilder::new(
-                field.name().clone(),
-                index,
-                Box::new(DataType::from(field.data_type())),
-                Visibility::Visible,
-            )
-            .build();
-
-            bind_context.add_column_binding(column);
-        }
+        let mut bind_context = Self::schema_bind_context(&schema);
         let mut scalar_binder = ScalarBinder::new(
             &mut bind_context,
             self.ctx.clone(),
@@ -1496,6 +1515,207 @@ impl Binder {
         Ok(cluster_keys)
     }
 
+    /// Validate the constraints of the table to be created, and resolve them into
+    /// the form saved in the table meta, keyed by constraint name.
+    #[async_backtrace::framed]
+    async fn analyze_table_constraints(
+        &mut self,
+        constraints: &[AstTableConstraint],
+        schema: TableSchemaRef,
+        catalog: &str,
+        database: &str,
+        table: &str,
+    ) -> Result<BTreeMap<String, TableConstraint>> {
+        let mut table_constraints = BTreeMap::new();
+        let mut check_count = 0;
+        for constraint in constraints {
+            let (default_name, table_constraint) = match &constraint.kind {
+                TableConstraintKind::Check(check) => {
+                    check_count += 1;
+                    let expression = self.analyze_check_constraint(check, schema.clone()).await?;
+                    (
+                        format!("{table}_chk_{check_count}"),
+                        TableConstraint::Check { expression },
+                    )
+                }
+                TableConstraintKind::PrimaryKey(columns) => {
+                    if table_constraints
+                        .values()
+                        .any(|c| matches!(c, TableConstraint::PrimaryKey { .. }))
+                    {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "Multiple primary keys for table {table} are not allowed"
+                        )));
+                    }
+                    let columns = self.resolve_constraint_columns(columns, &schema)?;
+                    (format!("{table}_pkey"), TableConstraint::PrimaryKey {
+                        columns,
+                    })
+                }
+                TableConstraintKind::Unique(columns) => {
+                    let columns = self.resolve_constraint_columns(columns, &schema)?;
+                    (
+                        format!("{table}_{}_key", columns.join("_")),
+                        TableConstraint::Unique { columns },
+                    )
+                }
+                TableConstraintKind::ForeignKey {
+                    columns,
+                    ref_database,
+                    ref_table,
+                    ref_columns,
+                } => {
+                    let columns = self.resolve_constraint_columns(columns, &schema)?;
+                    let ref_database = ref_database
+                        .as_ref()
+                        .map(|ident| normalize_identifier(ident, &self.name_resolution_ctx).name)
+                        .unwrap_or_else(|| database.to_string());
+                    let ref_table = normalize_identifier(ref_table, &self.name_resolution_ctx).name;
+                    let ref_schema = if ref_database == database && ref_table == table {
+                        schema.clone()
+                    } else {
+                        self.ctx
+                            .get_table(catalog, &ref_database, &ref_table)
+                            .await?
+                            .schema()
+                    };
+                    let ref_columns = self.resolve_constraint_columns(ref_columns, &ref_schema)?;
+                    if columns.len() != ref_columns.len() {
+                        return Err(ErrorCode::BadArguments(format!(
+                            "Number of referencing and referenced columns for foreign key of table {table} does not match"
+                        )));
+                    }
+                    (
+                        format!("{table}_{}_fkey", columns.join("_")),
+                        TableConstraint::ForeignKey {
+                            columns,
+                            ref_database,
+                            ref_table,
+                            ref_columns,
+                        },
+                    )
+                }
+            };
+
+            let name = match &constraint.name {
+                Some(name) => normalize_identifier(name, &self.name_resolution_ctx).name,
+                None => default_name,
+            };
+            if table_constraints
+                .insert(name.clone(), table_constraint)
+                .is_some()
+            {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Duplicated constraint name: {name}"
+                )));
+            }
+        }
+
+        Ok(table_constraints)
+    }
+
+    #[async_backtrace::framed]
+    async fn analyze_check_constraint(
+        &mut self,
+        check: &Expr,
+        schema: TableSchemaRef,
+    ) -> Result<String> {
+        let mut bind_context = Self::schema_bind_context(&schema);
+        let mut scalar_binder = ScalarBinder::new(
+            &mut bind_context,
+            self.ctx.clone(),
+            &self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        // check constraints are evaluated when writing blocks, udf is not available there.
+        scalar_binder.forbid_udf();
+
+        let (scalar, _) = scalar_binder.bind(check).await?;
+        if !scalar.evaluable() {
+            return Err(ErrorCode::SemanticError(format!(
+                "CHECK constraint `{:#}` can not contain subqueries, aggregate or window functions",
+                check
+            )));
+        }
+        // virtual computed columns are not materialized when writing blocks.
+        if scalar.used_columns().iter().any(|index| {
+            matches!(
+                schema.field(*index).computed_expr(),
+                Some(ComputedExpr::Virtual(_))
+            )
+        }) {
+            return Err(ErrorCode::SemanticError(format!(
+                "CHECK constraint `{:#}` can not reference virtual computed columns",
+                check
+            )));
+        }
+        let expr = scalar.as_expr()?;
+        if !expr.is_deterministic(&BUILTIN_FUNCTIONS) {
+            return Err(ErrorCode::SemanticError(format!(
+                "CHECK constraint `{:#}` is not deterministic",
+                check
+            )));
+        }
+        if expr.data_type().remove_nullable() != DataType::Boolean {
+            return Err(ErrorCode::SemanticError(format!(
+                "CHECK constraint `{:#}` must be a boolean expression, but got {}",
+                check,
+                expr.data_type()
+            )));
+        }
+
+        let mut check = check.clone();
+        walk_expr_mut(
+            &mut IdentifierNormalizer {
+                ctx: &self.name_resolution_ctx,
+            },
+            &mut check,
+        );
+        Ok(format!("{:#}", &check))
+    }
+
+    fn resolve_constraint_columns(
+        &self,
+        columns: &[Identifier],
+        schema: &TableSchemaRef,
+    ) -> Result<Vec<String>> {
+        let mut names = Vec::with_capacity(columns.len());
+        for column in columns {
+            let name = normalize_identifier(column, &self.name_resolution_ctx).name;
+            if schema.field_with_name(&name).is_err() {
+                return Err(ErrorCode::UnknownColumn(format!(
+                    "Column {name} in constraint does not exist"
+                )));
+            }
+            if names.contains(&name) {
+                return Err(ErrorCode::BadArguments(format!(
+                    "Duplicated column {name} in constraint"
+                )));
+            }
+            names.push(name);
+        }
+        Ok(names)
+    }
+
+    fn schema_bind_context(schema: &TableSchemaRef) -> BindContext {
+        let mut bind_context = BindContext::new();
+        for (index, field) in schema.fields().iter().enumerate() {
+            let column = ColumnBindingBuilder::new(
+                field.name().clone(),
+                index,
+                Box::new(DataType::from(field.data_type())),
+                Visibility::Visible,
+            )
+            .build();
+
+            bind_context.add_column_binding(column);
+        }
+        bind_context
+    }
+
     fn valid_cluster_key_type(data_type: &DataType) -> bool {
         let inner_type = data_type.remove_nullable();
         matches!(
@@ -86,6 +86,11 @@ impl Binder {
                     "cross join should not contain join conditions".to_string(),
                 ));
             }
+            JoinOperator::Asof | JoinOperator::LeftAsof if join.match_condition.is_none() => {
+                return Err(ErrorCode::SemanticError(
+                    "asof join should contain match condition".to_string(),
+                ));
+            }
             _ => (),
         };
 
@@ -115,6 +120,19 @@ impl Binder {
             )
             .await?;
 
+        if let Some(match_condition) = &join.match_condition {
+            if !non_equi_conditions.is_empty() || !other_conditions.is_empty() {
+                return Err(ErrorCode::SemanticError(
+                    "asof join only supports equi-conditions in ON clause".to_string(),
+                ));
+            }
+            // The match condition is kept as the only non-equi condition of asof joins.
+            let match_condition = join_condition_resolver
+                .resolve_match_condition(match_condition)
+                .await?;
+            non_equi_conditions.push(match_condition);
+        }
+
         let join_conditions = JoinConditions {
             left_conditions: left_join_conditions,
             right_conditions: right_join_conditions,
@@ -138,6 +156,15 @@ impl Binder {
             JoinOperator::CrossJoin => {
                 self.bind_join_with_type(JoinType::Cross, join_conditions, left_child, right_child)
             }
+            JoinOperator::Asof => {
+                self.bind_join_with_type(JoinType::Asof, join_conditions, left_child, right_child)
+            }
+            JoinOperator::LeftAsof => self.bind_join_with_type(
+                JoinType::LeftAsof,
+                join_conditions,
+                left_child,
+                right_child,
+            ),
             JoinOperator::LeftSemi => {
                 bind_context = left_context;
                 self.bind_join_with_type(
@@ -278,12 +305,16 @@ impl Binder {
                     | JoinType::LeftSemi
                     | JoinType::LeftAnti
                     | JoinType::RightSemi
-                    | JoinType::RightAnti => {
+                    | JoinType::RightAnti
+                    | JoinType::Asof => {
                         need_push_down = true;
                         left_push_down.push(predicate.clone());
                         right_push_down.push(predicate.clone());
                     }
-                    JoinType::Left | JoinType::LeftSingle | JoinType::RightMark => {
+                    JoinType::Left
+                    | JoinType::LeftSingle
+                    | JoinType::RightMark
+                    | JoinType::LeftAsof => {
                         need_push_down = true;
                         right_push_down.push(predicate.clone());
                     }
@@ -347,7 +378,7 @@ fn wrap_nullable_for_column(
     bind_context: &mut BindContext,
 ) {
     match join_type {
-        JoinOperator::LeftOuter => {
+        JoinOperator::LeftOuter | JoinOperator::LeftAsof => {
             for column in left_context.all_column_bindings() {
                 bind_context.add_column_binding(column.clone());
             }
@@ -799,6 +830,55 @@ impl<'a> JoinConditionResolver<'a> {
         Ok(false)
     }
 
+    #[async_backtrace::framed]
+    async fn resolve_match_condition(&mut self, match_condition: &Expr) -> Result<ScalarExpr> {
+        let mut join_context = (*self.join_context).clone();
+        wrap_nullable_for_column(
+            &JoinOperator::Inner,
+            self.left_context,
+            self.right_context,
+            &mut join_context,
+        );
+        let mut scalar_binder = ScalarBinder::new(
+            &mut join_context,
+            self.ctx.clone(),
+            self.name_resolution_ctx,
+            self.metadata.clone(),
+            &[],
+            self.m_cte_bound_ctx.clone(),
+            self.ctes_map.clone(),
+        );
+        let (predicate, _) = scalar_binder.bind(match_condition).await?;
+        self.check_join_allowed_scalar_expr(&vec![predicate.clone()])
+            .await?;
+
+        // The match condition must compare an expression of the left table
+        // with an expression of the right table, e.g. `t.ts >= q.ts`.
+        let (left_columns, right_columns) = self.left_right_columns()?;
+        let is_valid = match &predicate {
+            ScalarExpr::FunctionCall(func)
+                if matches!(func.func_name.as_str(), "gt" | "gte" | "lt" | "lte")
+                    && func.arguments.len() == 2 =>
+            {
+                let arg1 = func.arguments[0].used_columns();
+                let arg2 = func.arguments[1].used_columns();
+                !arg1.is_empty()
+                    && !arg2.is_empty()
+                    && ((arg1.is_subset(&left_columns) && arg2.is_subset(&right_columns))
+                        || (arg1.is_subset(&right_columns) && arg2.is_subset(&left_columns)))
+            }
+            _ => false,
+        };
+        if !is_valid {
+            return Err(ErrorCode::SemanticError(
+                "match condition of asof join should be a comparison (>, >=, < or <=) between the left table and the right table"
+                    .to_string(),
+            )
+            .set_span(match_condition.span()));
+        }
+        Ok(predicate)
+    }
+
     fn left_right_columns(&self) -> Result<(ColumnSet, ColumnSet)> {
         let left_columns: ColumnSet =
             self.left_context
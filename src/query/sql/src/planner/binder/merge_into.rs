@@ -209,6 +209,7 @@ impl Binder {
             table: table_ident.clone(),
             alias: target_alias.clone(),
             travel_point: None,
+            changes: None,
             pivot: None,
             unpivot: None,
         };
@@ -331,6 +332,7 @@ impl Binder {
         let join = Join {
             op: join_type,
             condition: JoinCondition::On(Box::new(join_expr.clone())),
+            match_condition: None,
             left: Box::new(target_table),
             // use source as build table
             right: Box::new(source_data.clone()),
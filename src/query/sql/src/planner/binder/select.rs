@@ -125,6 +125,7 @@ impl Binder {
                     join: Join {
                         op: JoinOperator::CrossJoin,
                         condition: JoinCondition::None,
+                        match_condition: None,
                         left: Box::new(left),
                         right: Box::new(right),
                     },
@@ -377,12 +378,12 @@ impl Binder {
 
         let (limit, offset) = if !query.limit.is_empty() {
             if query.limit.len() == 1 {
-                Self::analyze_limit(Some(&query.limit[0]), &query.offset)?
+                self.analyze_limit(Some(&query.limit[0]), &query.offset)?
             } else {
-                Self::analyze_limit(Some(&query.limit[1]), &Some(query.limit[0].clone()))?
+                self.analyze_limit(Some(&query.limit[1]), &Some(query.limit[0].clone()))?
             }
         } else if query.offset.is_some() {
-            Self::analyze_limit(None, &query.offset)?
+            self.analyze_limit(None, &query.offset)?
         } else {
             (None, 0)
         };
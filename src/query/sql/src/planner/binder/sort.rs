@@ -395,7 +395,7 @@ impl Binder {
                         name: udf.name.clone(),
                         func_name: udf.func_name.clone(),
                         display_name: udf.display_name.clone(),
-                        server_addr: udf.server_addr.clone(),
+                        udf_type: udf.udf_type.clone(),
                         arg_types: udf.arg_types.clone(),
                         return_type: udf.return_type.clone(),
                         arguments: new_args,
@@ -22,6 +22,7 @@ use async_recursion::async_recursion;
 use chrono::TimeZone;
 use chrono::Utc;
 use dashmap::DashMap;
+use databend_common_ast::ast::ChangesInterval;
 use databend_common_ast::ast::Connection;
 use databend_common_ast::ast::Expr;
 use databend_common_ast::ast::FileLocation;
@@ -38,6 +39,7 @@ use databend_common_ast::ast::TableAlias;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::ast::TimeTravelPoint;
 use databend_common_ast::ast::UriLocation;
+use databend_common_ast::parser::parse_expr;
 use databend_common_ast::parser::parse_sql;
 use databend_common_ast::parser::tokenize_sql;
 use databend_common_catalog::catalog_kind::CATALOG_DEFAULT;
@@ -68,6 +70,9 @@ use databend_common_meta_app::principal::StageFileFormatType;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::ListIndexesReq;
+use databend_common_meta_app::schema::TableConstraint;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_types::MetaId;
 use databend_common_storage::DataOperator;
 use databend_common_storage::StageFileInfo;
@@ -82,7 +87,14 @@ use databend_common_storages_view::view_table::QUERY;
 use databend_common_users::UserApiProvider;
 use databend_storages_common_table_meta::table::ChangeType;
 use databend_storages_common_table_meta::table::StreamMode;
+use databend_storages_common_table_meta::table::MODE_STANDARD;
+use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_NAME;
+use databend_storages_common_table_meta::table::OPT_KEY_END_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
 use databend_storages_common_table_meta::table::OPT_KEY_MODE;
+use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ID;
+use databend_storages_common_table_meta::table::OPT_KEY_TABLE_NAME;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_VER;
 use log::info;
 use parking_lot::RwLock;
@@ -175,6 +187,7 @@ impl Binder {
         table: &Identifier,
         alias: &Option<TableAlias>,
         travel_point: &Option<TimeTravelPoint>,
+        changes: &Option<ChangesInterval>,
     ) -> Result<(SExpr, BindContext)> {
         let (catalog, database, table_name) =
             self.normalize_object_identifier_triple(catalog, database, table);
@@ -205,6 +218,21 @@ impl Binder {
             }
         }
 
+        if let Some(changes) = changes {
+            return self
+                .bind_changes(
+                    bind_context,
+                    span,
+                    catalog,
+                    database,
+                    table_name,
+                    alias,
+                    travel_point,
+                    changes,
+                )
+                .await;
+        }
+
         let tenant = self.ctx.get_tenant();
 
         let navigation_point = match travel_point {
@@ -300,31 +328,14 @@ impl Binder {
                 }
             }
             "STREAM" => {
-                let mut change_type = None;
-                if let Some(table_alias) = table_alias_name.as_deref() {
-                    let alias_param = table_alias.split('$').collect::<Vec<_>>();
-                    if alias_param.len() == 2 && alias_param[1].len() == 8 {
-                        if let Ok(suffix) = i64::from_str_radix(alias_param[1], 16) {
-                            // 2023-01-01 00:00:00.
-                            let base_timestamp = 1672502400;
-                            if suffix > base_timestamp {
-                                change_type = match alias_param[0] {
-                                    "_change_append" => Some(ChangeType::Append),
-                                    "_change_insert" => Some(ChangeType::Insert),
-                                    "_change_delete" => Some(ChangeType::Delete),
-                                    _ => None,
-                                };
-                            }
-                        }
-                    }
-                }
+                let change_type = change_type_from_alias(table_alias_name.as_deref());
                 if change_type.is_some() {
                     let table_index = self.metadata.write().add_table(
                         catalog,
                         database.clone(),
                         table_meta,
                         table_alias_name,
-                        bind_context.view_info.is_some(),
+                        bind_context.view_info.is_some() || bind_context.planning_materialized_view,
                         bind_context.planning_agg_index,
                         false,
                     );
@@ -374,60 +385,12 @@ impl Binder {
                                         contains({append_alias}._base_block_ids, _origin_block_id)))",
                         )
                     }
-                    StreamMode::Standard => {
-                        let a_table_alias = format!("_change_insert${}", suffix);
-                        let a_cols = cols.join(", ");
-
-                        let d_table_alias = format!("_change_delete${}", suffix);
-                        let d_cols = cols
-                            .iter()
-                            .map(|s| format!("d_{}", s))
-                            .collect::<Vec<_>>()
-                            .join(", ");
-
-                        format!(
-                            "with _change({a_cols}, change$action, change$row_id, \
-                                          {d_cols}, d_change$action, d_change$row_id) as \
-                            ( \
-                                select * \
-                                from ( \
-                                    select *, \
-                                           _row_version, \
-                                           'INSERT' as change$action, \
-                                           if(is_not_null(_origin_block_id), \
-                                              concat(to_uuid(_origin_block_id), lpad(hex(_origin_block_row_num), 6, '0')), \
-                                              {a_table_alias}._base_row_id \
-                                           ) as change$row_id \
-                                    from {database}.{table_name} as {a_table_alias} \
-                                ) as A \
-                                FULL OUTER JOIN ( \
-                                    select *, \
-                                           _row_version, \
-                                           'DELETE' as change$action, \
-                                           if(is_not_null(_origin_block_id), \
-                                              concat(to_uuid(_origin_block_id), lpad(hex(_origin_block_row_num), 6, '0')), \
-                                              {d_table_alias}._base_row_id \
-                                           ) as change$row_id \
-                                    from {database}.{table_name} as {d_table_alias} \
-                                ) as D \
-                                on A.change$row_id = D.change$row_id \
-                                where A.change$row_id is null or D.change$row_id is null or A._row_version > D._row_version \
-                            ) \
-                            select {a_cols}, \
-                                   change$action, \
-                                   change$row_id, \
-                                   d_change$action is not null as change$is_update \
-                            from _change \
-                            where change$action is not null \
-                            union all \
-                            select {d_cols}, \
-                                   d_change$action, \
-                                   d_change$row_id, \
-                                   change$action is not null as change$is_update \
-                            from _change \
-                            where d_change$action is not null",
-                        )
-                    }
+                    StreamMode::Standard => standard_changes_query(
+                        &cols,
+                        &format!("{database}.{table_name}"),
+                        &suffix,
+                        false,
+                    ),
                 };
                 let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
                 let tokens = tokenize_sql(query.as_str())?;
@@ -459,7 +422,7 @@ impl Binder {
                     database.clone(),
                     table_meta,
                     table_alias_name,
-                    bind_context.view_info.is_some(),
+                    bind_context.view_info.is_some() || bind_context.planning_materialized_view,
                     bind_context.planning_agg_index,
                     false,
                 );
@@ -475,6 +438,195 @@ impl Binder {
         }
     }
 
+    /// Bind a table with the CHANGES clause, which reads the row level changes of the
+    /// table between two snapshots. Unlike reading a stream, no offset is consumed.
+    #[allow(clippy::too_many_arguments)]
+    #[async_backtrace::framed]
+    async fn bind_changes(
+        &mut self,
+        bind_context: &mut BindContext,
+        span: &Span,
+        catalog: String,
+        database: String,
+        table_name: String,
+        alias: &Option<TableAlias>,
+        at_point: &Option<TimeTravelPoint>,
+        changes: &ChangesInterval,
+    ) -> Result<(SExpr, BindContext)> {
+        let Some(at_point) = at_point else {
+            return Err(ErrorCode::SemanticError(
+                "The CHANGES clause requires an AT point".to_string(),
+            )
+            .set_span(*span));
+        };
+
+        let table = self
+            .ctx
+            .get_table(&catalog, &database, &table_name)
+            .await
+            .map_err(|e| e.set_span(*span))?;
+        if !table.change_tracking_enabled()
+            && changes.append_only
+            && table.support_navigate_appends()
+        {
+            // Engines such as iceberg record the files added by each snapshot, so the
+            // appended rows can be read directly without change tracking.
+            let start = self
+                .resolve_data_travel_point(bind_context, at_point)
+                .await?;
+            let end = match &changes.end_point {
+                Some(end_point) => Some(
+                    self.resolve_data_travel_point(bind_context, end_point)
+                        .await?,
+                ),
+                None => None,
+            };
+            let table = table
+                .navigate_appends(&start, end.as_ref())
+                .await
+                .map_err(|e| e.set_span(*span))?;
+            let table_alias_name = alias
+                .as_ref()
+                .map(|a| normalize_identifier(&a.name, &self.name_resolution_ctx).name);
+            let table_index = self.metadata.write().add_table(
+                catalog,
+                database.clone(),
+                table,
+                table_alias_name,
+                bind_context.view_info.is_some() || bind_context.planning_materialized_view,
+                bind_context.planning_agg_index,
+                false,
+            );
+            let (s_expr, mut bind_context) = self
+                .bind_base_table(bind_context, database.as_str(), table_index, None)
+                .await?;
+            if let Some(alias) = alias {
+                bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+            }
+            return Ok((s_expr, bind_context));
+        }
+        if !table.change_tracking_enabled() {
+            return Err(ErrorCode::IllegalStream(format!(
+                "Change tracking is not enabled for table '{database}.{table_name}'"
+            ))
+            .set_span(*span));
+        }
+
+        let at_navigation = self
+            .resolve_data_travel_point(bind_context, at_point)
+            .await?;
+        let start_location = changes_snapshot_location(&table, Some(&at_navigation)).await?;
+        let end_navigation = match &changes.end_point {
+            Some(end_point) => Some(
+                self.resolve_data_travel_point(bind_context, end_point)
+                    .await?,
+            ),
+            None => None,
+        };
+        let end_location = changes_snapshot_location(&table, end_navigation.as_ref()).await?;
+
+        let table_alias_name = if let Some(table_alias) = alias {
+            Some(normalize_identifier(&table_alias.name, &self.name_resolution_ctx).name)
+        } else {
+            None
+        };
+        if let Some(change_type) = change_type_from_alias(table_alias_name.as_deref()) {
+            // Read the blocks added or removed between the two snapshots through a
+            // read-only stream on the table.
+            let table_info = table.get_table_info();
+            let mut options = BTreeMap::new();
+            options.insert(OPT_KEY_MODE.to_string(), MODE_STANDARD.to_string());
+            options.insert(OPT_KEY_TABLE_NAME.to_string(), table_name.clone());
+            options.insert(OPT_KEY_DATABASE_NAME.to_string(), database.clone());
+            options.insert(
+                OPT_KEY_TABLE_ID.to_string(),
+                table_info.ident.table_id.to_string(),
+            );
+            options.insert(
+                OPT_KEY_TABLE_VER.to_string(),
+                table_info.ident.seq.to_string(),
+            );
+            options.insert(OPT_KEY_SNAPSHOT_LOCATION.to_string(), start_location);
+            options.insert(OPT_KEY_END_SNAPSHOT_LOCATION.to_string(), end_location);
+            let stream_info = TableInfo {
+                desc: format!("'{database}'.'{table_name}'"),
+                name: table_name.clone(),
+                meta: TableMeta {
+                    engine: "STREAM".to_string(),
+                    options,
+                    schema: table_info.schema(),
+                    catalog: table_info.meta.catalog.clone(),
+                    ..Default::default()
+                },
+                ..table_info.clone()
+            };
+            let stream = self
+                .ctx
+                .get_catalog(&catalog)
+                .await?
+                .get_table_by_info(&stream_info)?;
+
+            let table_index = self.metadata.write().add_table(
+                catalog,
+                database.clone(),
+                stream,
+                table_alias_name,
+                bind_context.view_info.is_some() || bind_context.planning_materialized_view,
+                bind_context.planning_agg_index,
+                false,
+            );
+            let (s_expr, mut bind_context) = self
+                .bind_base_table(
+                    bind_context,
+                    database.as_str(),
+                    table_index,
+                    Some(change_type),
+                )
+                .await?;
+            if let Some(alias) = alias {
+                bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+            }
+            return Ok((s_expr, bind_context));
+        }
+
+        let cols = table
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.name().clone())
+            .collect::<Vec<_>>();
+        // Pin the resolved snapshots, so that both sides of the change query read the same interval.
+        let source = format!(
+            "{database}.{table_name} CHANGES(INFORMATION => DEFAULT) \
+             AT(SNAPSHOT => '{}') END(SNAPSHOT => '{}')",
+            snapshot_id_from_location(&start_location),
+            snapshot_id_from_location(&end_location),
+        );
+        let suffix = format!("{:08x}", Utc::now().timestamp());
+        let query = standard_changes_query(&cols, &source, &suffix, changes.append_only);
+
+        let mut new_bind_context = BindContext::with_parent(Box::new(bind_context.clone()));
+        let tokens = tokenize_sql(query.as_str())?;
+        let (stmt, _) = parse_sql(&tokens, self.dialect)?;
+        let Statement::Query(query) = &stmt else {
+            unreachable!()
+        };
+        let (s_expr, mut new_bind_context) = self.bind_query(&mut new_bind_context, query).await?;
+        for (index, column_name) in cols.iter().enumerate() {
+            new_bind_context.columns[index].column_name = column_name.clone();
+        }
+        if let Some(alias) = alias {
+            new_bind_context.apply_table_alias(alias, &self.name_resolution_ctx)?;
+        } else {
+            for column in new_bind_context.columns.iter_mut() {
+                column.database_name = None;
+                column.table_name = Some(table_name.clone());
+            }
+        }
+        new_bind_context.parent = Some(Box::new(bind_context.clone()));
+        Ok((s_expr, new_bind_context))
+    }
+
     /// Extract the srf inner tuple fields as columns.
     #[async_backtrace::framed]
     async fn extract_srf_table_function_columns(
@@ -682,6 +834,12 @@ impl Binder {
     ) -> Result<(SExpr, BindContext)> {
         let func_name = normalize_identifier(name, &self.name_resolution_ctx);
 
+        if func_name.name.eq_ignore_ascii_case("table_changes") {
+            return self
+                .bind_table_changes(bind_context, span, params, named_params, alias)
+                .await;
+        }
+
         if BUILTIN_FUNCTIONS
             .get_property(&func_name.name)
             .map(|p| p.kind == FunctionKind::SRF)
@@ -831,6 +989,105 @@ impl Binder {
         }
     }
 
+    /// Bind the `table_changes` table function, a function form of the CHANGES clause:
+    ///
+    /// `table_changes('[db.]table', <start>[, <end>][, information => 'default' | 'append_only'])`
+    ///
+    /// A string point is a snapshot id, any other expression is evaluated as a timestamp.
+    #[async_backtrace::framed]
+    async fn bind_table_changes(
+        &mut self,
+        bind_context: &mut BindContext,
+        span: &Span,
+        params: &[Expr],
+        named_params: &[(String, Expr)],
+        alias: &Option<TableAlias>,
+    ) -> Result<(SExpr, BindContext)> {
+        if params.len() < 2 || params.len() > 3 {
+            return Err(ErrorCode::NumberArgumentsNotMatch(format!(
+                "table_changes needs 2 or 3 arguments, but got {}",
+                params.len()
+            ))
+            .set_span(*span));
+        }
+
+        let name = match &params[0] {
+            Expr::Literal {
+                value: Literal::String(name),
+                ..
+            } => name,
+            _ => {
+                return Err(ErrorCode::BadArguments(
+                    "The first argument of table_changes must be a table name string",
+                )
+                .set_span(*span));
+            }
+        };
+        let mut name_parts = name
+            .split('.')
+            .map(|part| Identifier::from_name(part.to_string()))
+            .collect::<Vec<_>>();
+        if name_parts.len() > 3 || name_parts.iter().any(|part| part.name.is_empty()) {
+            return Err(
+                ErrorCode::BadArguments(format!("Invalid table name '{name}'")).set_span(*span),
+            );
+        }
+        let table = name_parts.pop().unwrap();
+        let database = name_parts.pop();
+        let catalog = name_parts.pop();
+
+        let travel_point = |expr: &Expr| match expr {
+            Expr::Literal {
+                value: Literal::String(snapshot_id),
+                ..
+            } => TimeTravelPoint::Snapshot(snapshot_id.clone()),
+            _ => TimeTravelPoint::Timestamp(Box::new(expr.clone())),
+        };
+
+        let mut append_only = false;
+        for (name, value) in named_params {
+            match (name.to_lowercase().as_str(), value) {
+                (
+                    "information",
+                    Expr::Literal {
+                        value: Literal::String(information),
+                        ..
+                    },
+                ) if information.eq_ignore_ascii_case("default") => append_only = false,
+                (
+                    "information",
+                    Expr::Literal {
+                        value: Literal::String(information),
+                        ..
+                    },
+                ) if information.eq_ignore_ascii_case("append_only") => append_only = true,
+                _ => {
+                    return Err(ErrorCode::BadArguments(format!(
+                        "Invalid table_changes argument {name} => {value}, \
+                         expect information => 'default' | 'append_only'"
+                    ))
+                    .set_span(*span));
+                }
+            }
+        }
+
+        let changes = ChangesInterval {
+            append_only,
+            end_point: params.get(2).map(travel_point),
+        };
+        self.bind_table(
+            bind_context,
+            span,
+            &catalog,
+            &database,
+            &table,
+            alias,
+            &Some(travel_point(&params[1])),
+            &Some(changes),
+        )
+        .await
+    }
+
     /// Bind a subquery.
     #[async_backtrace::framed]
     async fn bind_subquery(
@@ -916,6 +1173,7 @@ impl Binder {
                 table,
                 alias,
                 travel_point,
+                changes,
                 pivot: _,
                 unpivot: _,
             } => {
@@ -927,6 +1185,7 @@ impl Binder {
                     table,
                     alias,
                     travel_point,
+                    changes,
                 )
                 .await
             }
@@ -1044,6 +1303,40 @@ impl Binder {
                 };
                 StageTable::try_create(info)?
             }
+            FileFormatParams::Orc(..) => {
+                let schema = StageTable::infer_orc_schema(
+                    &stage_info,
+                    &files_info,
+                    files_to_copy.as_deref(),
+                )
+                .await?;
+                let info = StageTableInfo {
+                    schema,
+                    stage_info,
+                    files_info,
+                    files_to_copy,
+                    is_select: true,
+                    default_values: None,
+                };
+                StageTable::try_create(info)?
+            }
+            FileFormatParams::Avro(..) => {
+                let schema = StageTable::infer_avro_schema(
+                    &stage_info,
+                    &files_info,
+                    files_to_copy.as_deref(),
+                )
+                .await?;
+                let info = StageTableInfo {
+                    schema,
+                    stage_info,
+                    files_info,
+                    files_to_copy,
+                    is_select: true,
+                    default_values: None,
+                };
+                StageTable::try_create(info)?
+            }
             FileFormatParams::Csv(..) | FileFormatParams::Tsv(..) => {
                 let max_column_position = self.metadata.read().get_max_column_position();
                 if max_column_position == 0 {
@@ -1080,7 +1373,7 @@ impl Binder {
             }
             _ => {
                 return Err(ErrorCode::Unimplemented(format!(
-                    "The file format in the query stage is not supported. Currently supported formats are: Parquet, NDJson, CSV, and TSV. Provided format: '{}'.",
+                    "The file format in the query stage is not supported. Currently supported formats are: Parquet, ORC, Avro, NDJson, CSV, and TSV. Provided format: '{}'.",
                     stage_info.file_format_params
                 )));
             }
@@ -1248,6 +1541,7 @@ impl Binder {
             srfs: Default::default(),
             expr_context: ExprContext::default(),
             planning_agg_index: false,
+            planning_materialized_view: bind_context.planning_materialized_view,
             allow_internal_columns: true,
             window_definitions: DashMap::new(),
         };
@@ -1407,6 +1701,13 @@ impl Binder {
             }
         }
 
+        let check_constraints = self.bind_check_constraints(&bind_context, &table).await;
+        if !check_constraints.is_empty() {
+            self.metadata
+                .write()
+                .add_check_constraints(table_index, check_constraints);
+        }
+
         let stat = table.table_statistics(self.ctx.clone()).await?;
 
         Ok((
@@ -1427,6 +1728,41 @@ impl Binder {
         ))
     }
 
+    /// Bind the CHECK constraints of a table, so that the optimizer can prune the
+    /// predicates contradicting them. A constraint failing to bind is ignored.
+    async fn bind_check_constraints(
+        &self,
+        bind_context: &BindContext,
+        table: &Arc<dyn Table>,
+    ) -> Vec<ScalarExpr> {
+        let mut check_constraints = vec![];
+        for constraint in table.get_table_info().meta.constraints.values() {
+            let TableConstraint::Check { expression } = constraint else {
+                continue;
+            };
+            let Ok(tokens) = tokenize_sql(expression) else {
+                continue;
+            };
+            let Ok(expr) = parse_expr(&tokens, self.dialect) else {
+                continue;
+            };
+            let mut bind_context = bind_context.clone();
+            let mut scalar_binder = ScalarBinder::new(
+                &mut bind_context,
+                self.ctx.clone(),
+                &self.name_resolution_ctx,
+                self.metadata.clone(),
+                &[],
+                self.m_cte_bound_ctx.clone(),
+                self.ctes_map.clone(),
+            );
+            if let Ok((scalar, _)) = scalar_binder.bind(&expr).await {
+                check_constraints.push(scalar);
+            }
+        }
+        check_constraints
+    }
+
     #[async_backtrace::framed]
     pub async fn resolve_data_source(
         &self,
@@ -1589,3 +1925,130 @@ fn parse_table_function_args(
         Ok(params.to_vec())
     }
 }
+
+/// Returns the location of the table snapshot at the given point, or of the latest snapshot.
+async fn changes_snapshot_location(
+    table: &Arc<dyn Table>,
+    point: Option<&NavigationPoint>,
+) -> Result<String> {
+    let table = match point {
+        Some(point) => table.navigate_to(point).await?,
+        None => table.clone(),
+    };
+    let options = table.options();
+    options
+        .get(OPT_KEY_SNAPSHOT_LOCATION)
+        .or_else(|| options.get(OPT_KEY_LEGACY_SNAPSHOT_LOC))
+        .cloned()
+        .ok_or_else(|| {
+            ErrorCode::TableHistoricalDataNotFound("No historical data found at given point")
+        })
+}
+
+/// Extracts the snapshot id from a snapshot location, e.g. `1/2/_ss/<id>_v4.mpk`.
+fn snapshot_id_from_location(location: &str) -> &str {
+    let file_name = location.rsplit('/').next().unwrap_or(location);
+    file_name.split('_').next().unwrap_or(file_name)
+}
+
+/// Recognizes the aliases of the stream reads generated by the change queries,
+/// e.g. `_change_insert$65b1a2c3`.
+fn change_type_from_alias(table_alias: Option<&str>) -> Option<ChangeType> {
+    let alias_param = table_alias?.split('$').collect::<Vec<_>>();
+    if alias_param.len() == 2 && alias_param[1].len() == 8 {
+        if let Ok(suffix) = i64::from_str_radix(alias_param[1], 16) {
+            // 2023-01-01 00:00:00.
+            let base_timestamp = 1672502400;
+            if suffix > base_timestamp {
+                return match alias_param[0] {
+                    "_change_append" => Some(ChangeType::Append),
+                    "_change_insert" => Some(ChangeType::Insert),
+                    "_change_delete" => Some(ChangeType::Delete),
+                    _ => None,
+                };
+            }
+        }
+    }
+    None
+}
+
+/// Generates the query computing the row level changes of `source`, which is either a
+/// standard stream or a table with the CHANGES clause.
+///
+/// If `append_only` is true, only the inserted rows that are not the new version of an
+/// updated row are returned.
+fn standard_changes_query(
+    cols: &[String],
+    source: &str,
+    suffix: &str,
+    append_only: bool,
+) -> String {
+    let a_table_alias = format!("_change_insert${}", suffix);
+    let a_cols = cols.join(", ");
+
+    let d_table_alias = format!("_change_delete${}", suffix);
+    let d_cols = cols
+        .iter()
+        .map(|s| format!("d_{}", s))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    let cte = format!(
+        "with _change({a_cols}, change$action, change$row_id, \
+                      {d_cols}, d_change$action, d_change$row_id) as \
+        ( \
+            select * \
+            from ( \
+                select *, \
+                       _row_version, \
+                       'INSERT' as change$action, \
+                       if(is_not_null(_origin_block_id), \
+                          concat(to_uuid(_origin_block_id), lpad(hex(_origin_block_row_num), 6, '0')), \
+                          {a_table_alias}._base_row_id \
+                       ) as change$row_id \
+                from {source} as {a_table_alias} \
+            ) as A \
+            FULL OUTER JOIN ( \
+                select *, \
+                       _row_version, \
+                       'DELETE' as change$action, \
+                       if(is_not_null(_origin_block_id), \
+                          concat(to_uuid(_origin_block_id), lpad(hex(_origin_block_row_num), 6, '0')), \
+                          {d_table_alias}._base_row_id \
+                       ) as change$row_id \
+                from {source} as {d_table_alias} \
+            ) as D \
+            on A.change$row_id = D.change$row_id \
+            where A.change$row_id is null or D.change$row_id is null or A._row_version > D._row_version \
+        )",
+    );
+
+    if append_only {
+        format!(
+            "{cte} \
+            select {a_cols}, \
+                   change$action, \
+                   change$row_id, \
+                   false as change$is_update \
+            from _change \
+            where change$action is not null and d_change$action is null",
+        )
+    } else {
+        format!(
+            "{cte} \
+            select {a_cols}, \
+                   change$action, \
+                   change$row_id, \
+                   d_change$action is not null as change$is_update \
+            from _change \
+            where change$action is not null \
+            union all \
+            select {d_cols}, \
+                   d_change$action, \
+                   d_change$row_id, \
+                   change$action is not null as change$is_update \
+            from _change \
+            where d_change$action is not null",
+        )
+    }
+}
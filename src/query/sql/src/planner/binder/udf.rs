@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use base64::prelude::BASE64_STANDARD;
+use base64::Engine;
 use chrono::Utc;
 use databend_common_ast::ast::AlterUDFStmt;
 use databend_common_ast::ast::CreateUDFStmt;
@@ -22,10 +24,13 @@ use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::types::DataType;
 use databend_common_expression::udf_client::UDFFlightClient;
+use databend_common_expression::udf_wasm::WasmLimits;
+use databend_common_expression::udf_wasm::WasmRuntime;
 use databend_common_meta_app::principal::LambdaUDF;
 use databend_common_meta_app::principal::UDFDefinition as PlanUDFDefinition;
 use databend_common_meta_app::principal::UDFServer;
 use databend_common_meta_app::principal::UserDefinedFunction;
+use databend_common_meta_app::principal::WasmUDF;
 
 use crate::planner::resolve_type_name;
 use crate::planner::udf_validator::UDFValidator;
@@ -118,6 +123,41 @@ impl Binder {
                     created_on: Utc::now(),
                 })
             }
+            UDFDefinition::WasmUDF {
+                arg_types,
+                return_type,
+                handler,
+                code,
+            } => {
+                let mut arg_datatypes = Vec::with_capacity(arg_types.len());
+                for arg_type in arg_types {
+                    arg_datatypes.push(DataType::from(&resolve_type_name(arg_type, true)?));
+                }
+                let return_type = DataType::from(&resolve_type_name(return_type, true)?);
+
+                // The module is either base64-encoded binary or WAT text.
+                let code = BASE64_STANDARD
+                    .decode(code)
+                    .unwrap_or_else(|_| code.as_bytes().to_vec());
+                let settings = self.ctx.get_settings();
+                let limits = WasmLimits {
+                    max_memory: settings.get_wasm_udf_max_memory()?,
+                    max_fuel: settings.get_wasm_udf_max_fuel()?,
+                };
+                WasmRuntime::try_create(&code, handler)?.validate(limits)?;
+
+                Ok(UserDefinedFunction {
+                    name: udf_name.to_string(),
+                    description: udf_description.clone().unwrap_or_default(),
+                    definition: PlanUDFDefinition::WasmUDF(WasmUDF {
+                        code,
+                        handler: handler.clone(),
+                        arg_types: arg_datatypes,
+                        return_type,
+                    }),
+                    created_on: Utc::now(),
+                })
+            }
         }
     }
 
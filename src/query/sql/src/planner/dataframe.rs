@@ -63,6 +63,7 @@ impl Dataframe {
             catalog: None,
             alias: None,
             travel_point: None,
+            changes: None,
             pivot: None,
             unpivot: None,
         };
@@ -477,6 +478,7 @@ impl Dataframe {
                 catalog: None,
                 alias: None,
                 travel_point: None,
+                changes: None,
                 pivot: None,
                 unpivot: None,
             };
@@ -490,6 +492,7 @@ impl Dataframe {
                 join: Join {
                     op: op.clone(),
                     condition: condition.clone(),
+                    match_condition: None,
                     left: Box::new(left),
                     right: Box::new(right),
                 },
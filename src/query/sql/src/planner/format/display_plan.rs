@@ -96,6 +96,11 @@ impl Plan {
             Plan::AlterView(_) => Ok("AlterView".to_string()),
             Plan::DropView(_) => Ok("DropView".to_string()),
 
+            // Materialized Views
+            Plan::CreateMaterializedView(_) => Ok("CreateMaterializedView".to_string()),
+            Plan::DropMaterializedView(_) => Ok("DropMaterializedView".to_string()),
+            Plan::RefreshMaterializedView(_) => Ok("RefreshMaterializedView".to_string()),
+
             // Streams
             Plan::CreateStream(_) => Ok("CreateStream".to_string()),
             Plan::DropStream(_) => Ok("DropStream".to_string()),
@@ -104,6 +109,10 @@ impl Plan {
             Plan::CreateIndex(_) => Ok("CreateIndex".to_string()),
             Plan::DropIndex(_) => Ok("DropIndex".to_string()),
             Plan::RefreshIndex(_) => Ok("RefreshIndex".to_string()),
+            Plan::CreateVectorIndex(_) => Ok("CreateVectorIndex".to_string()),
+            Plan::RefreshVectorIndex(_) => Ok("RefreshVectorIndex".to_string()),
+            Plan::CreateInvertedIndex(_) => Ok("CreateInvertedIndex".to_string()),
+            Plan::RefreshInvertedIndex(_) => Ok("RefreshInvertedIndex".to_string()),
 
             // Virtual Columns
             Plan::CreateVirtualColumn(_) => Ok("CreateVirtualColumn".to_string()),
@@ -151,6 +160,10 @@ impl Plan {
             Plan::UseDatabase(_) => Ok("UseDatabase".to_string()),
             Plan::Kill(_) => Ok("Kill".to_string()),
 
+            Plan::Begin => Ok("Begin".to_string()),
+            Plan::Commit => Ok("Commit".to_string()),
+            Plan::Abort => Ok("Abort".to_string()),
+
             Plan::CreateShareEndpoint(_) => Ok("CreateShareEndpoint".to_string()),
             Plan::ShowShareEndpoint(_) => Ok("ShowShareEndpoint".to_string()),
             Plan::DropShareEndpoint(_) => Ok("DropShareEndpoint".to_string()),
@@ -183,6 +196,9 @@ impl Plan {
             Plan::AlterPasswordPolicy(_) => Ok("AlterPasswordPolicy".to_string()),
             Plan::DropPasswordPolicy(_) => Ok("DropPasswordPolicy".to_string()),
             Plan::DescPasswordPolicy(_) => Ok("DescPasswordPolicy".to_string()),
+            Plan::CreateWorkloadGroup(_) => Ok("CreateWorkloadGroup".to_string()),
+            Plan::AlterWorkloadGroup(_) => Ok("AlterWorkloadGroup".to_string()),
+            Plan::DropWorkloadGroup(_) => Ok("DropWorkloadGroup".to_string()),
 
             // task
             Plan::CreateTask(_) => Ok("CreateTask".to_string()),
@@ -192,6 +208,12 @@ impl Plan {
             Plan::ExecuteTask(_) => Ok("ExecuteTask".to_string()),
             Plan::ShowTasks(_) => Ok("ShowTasks".to_string()),
 
+            // pipe
+            Plan::CreatePipe(_) => Ok("CreatePipe".to_string()),
+            Plan::AlterPipe(_) => Ok("AlterPipe".to_string()),
+            Plan::DropPipe(_) => Ok("DropPipe".to_string()),
+            Plan::DescribePipe(_) => Ok("DescribePipe".to_string()),
+
             // task
             Plan::CreateConnection(_) => Ok("CreateConnection".to_string()),
             Plan::DescConnection(_) => Ok("DescConnection".to_string()),
@@ -243,6 +265,8 @@ fn format_delete(delete: &DeletePlan) -> Result<String> {
             prewhere: None,
             agg_index: None,
             change_type: None,
+            vector_index: None,
+            inverted_index: None,
             statistics: Default::default(),
         });
         let scan_expr = SExpr::create_leaf(Arc::new(scan));
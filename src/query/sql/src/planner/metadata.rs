@@ -28,9 +28,12 @@ use databend_common_expression::ComputedExpr;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
+use databend_common_meta_app::schema::IndexMeta;
 use parking_lot::RwLock;
 
+use crate::optimizer::MaterializedViewCandidate;
 use crate::optimizer::SExpr;
+use crate::ScalarExpr;
 
 /// Planner use [`usize`] as it's index type.
 ///
@@ -61,6 +64,14 @@ pub struct Metadata {
     /// Mappings from table index to _row_id column index.
     table_row_id_index: HashMap<IndexType, IndexType>,
     agg_indexes: HashMap<String, Vec<(u64, String, SExpr)>>,
+    /// The vector indexes of each table, keyed by `catalog.database.table`.
+    vector_indexes: HashMap<String, Vec<(u64, String, IndexMeta)>>,
+    /// The inverted indexes of each table, keyed by `catalog.database.table`.
+    inverted_indexes: HashMap<String, Vec<(u64, String, IndexMeta)>>,
+    /// The materialized views that may answer the query.
+    materialized_views: Vec<MaterializedViewCandidate>,
+    /// The bound CHECK constraints of each table, keyed by table index.
+    check_constraints: HashMap<IndexType, Vec<ScalarExpr>>,
     max_column_position: usize, // for CSV
 }
 
@@ -274,6 +285,54 @@ impl Metadata {
         self.agg_indexes.get(table).map(|v| v.as_slice())
     }
 
+    pub fn add_vector_indexes(
+        &mut self,
+        table: String,
+        vector_indexes: Vec<(u64, String, IndexMeta)>,
+    ) {
+        self.vector_indexes
+            .entry(table)
+            .and_modify(|indexes| indexes.extend_from_slice(&vector_indexes))
+            .or_insert(vector_indexes);
+    }
+
+    pub fn get_vector_indexes(&self, table: &str) -> Option<&[(u64, String, IndexMeta)]> {
+        self.vector_indexes.get(table).map(|v| v.as_slice())
+    }
+
+    pub fn add_inverted_indexes(
+        &mut self,
+        table: String,
+        inverted_indexes: Vec<(u64, String, IndexMeta)>,
+    ) {
+        self.inverted_indexes
+            .entry(table)
+            .and_modify(|indexes| indexes.extend_from_slice(&inverted_indexes))
+            .or_insert(inverted_indexes);
+    }
+
+    pub fn get_inverted_indexes(&self, table: &str) -> Option<&[(u64, String, IndexMeta)]> {
+        self.inverted_indexes.get(table).map(|v| v.as_slice())
+    }
+
+    pub fn add_check_constraints(&mut self, table_index: IndexType, constraints: Vec<ScalarExpr>) {
+        self.check_constraints.insert(table_index, constraints);
+    }
+
+    pub fn get_check_constraints(&self, table_index: IndexType) -> Option<&[ScalarExpr]> {
+        self.check_constraints
+            .get(&table_index)
+            .map(|v| v.as_slice())
+    }
+
+    pub fn add_materialized_view(&mut self, materialized_view: MaterializedViewCandidate) {
+        self.materialized_views.push(materialized_view);
+    }
+
+    pub fn materialized_views(&self) -> &[MaterializedViewCandidate] {
+        &self.materialized_views
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn add_table(
         &mut self,
@@ -0,0 +1,169 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_meta_app::schema::TableConstraint;
+
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::Join;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::BaseTableColumn;
+use crate::ColumnEntry;
+use crate::ColumnSet;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+
+// The EliminateLeftJoinOptimizer removes a left outer join whose right side contributes
+// no columns to the result and matches at most one row for each row of the left side.
+// For example: select t1.* from t1 left join t2 on t1.id = t2.id, where `t2.id` is
+// declared as PRIMARY KEY or UNIQUE:
+//
+//    Join [t1.id = t2.id]
+//    /  \                  =>   t1
+//   t1   t2
+//
+// Key constraints are informational, the optimizer relies on the declaration without
+// checking the data.
+pub struct EliminateLeftJoinOptimizer {
+    metadata: MetadataRef,
+}
+
+impl EliminateLeftJoinOptimizer {
+    pub fn new(metadata: MetadataRef) -> Self {
+        EliminateLeftJoinOptimizer { metadata }
+    }
+
+    pub fn run(self, s_expr: &SExpr, required_columns: &ColumnSet) -> Result<SExpr> {
+        if s_expr.contain_subquery() {
+            return Ok(s_expr.clone());
+        }
+        self.eliminate(s_expr, required_columns.clone())
+    }
+
+    fn eliminate(&self, s_expr: &SExpr, mut required: ColumnSet) -> Result<SExpr> {
+        match s_expr.plan() {
+            RelOperator::Join(join) => {
+                if self.can_eliminate(s_expr, join, &required)? {
+                    return self.eliminate(s_expr.child(0)?, required);
+                }
+                required.extend(join.used_columns()?);
+            }
+            RelOperator::EvalScalar(eval_scalar) => {
+                for item in eval_scalar.items.iter() {
+                    required.extend(item.scalar.used_columns());
+                }
+            }
+            RelOperator::Filter(filter) => {
+                for predicate in filter.predicates.iter() {
+                    required.extend(predicate.used_columns());
+                }
+            }
+            RelOperator::Aggregate(aggregate) => {
+                for item in aggregate
+                    .group_items
+                    .iter()
+                    .chain(aggregate.aggregate_functions.iter())
+                {
+                    required.extend(item.scalar.used_columns());
+                }
+            }
+            RelOperator::Sort(sort) => {
+                required.extend(sort.items.iter().map(|item| item.index));
+            }
+            RelOperator::Limit(_) => {}
+            _ => {
+                // Keep all the columns of unknown operators.
+                for child in s_expr.children() {
+                    let prop = RelExpr::with_s_expr(child).derive_relational_prop()?;
+                    required.extend(prop.output_columns.iter().cloned());
+                }
+            }
+        }
+
+        let mut children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            children.push(Arc::new(self.eliminate(child, required.clone())?));
+        }
+        Ok(s_expr.replace_children(children))
+    }
+
+    fn can_eliminate(&self, s_expr: &SExpr, join: &Join, required: &ColumnSet) -> Result<bool> {
+        if join.join_type != JoinType::Left
+            || join.right_conditions.is_empty()
+            || join.from_correlated_subquery
+            || join.need_hold_hash_table
+            || join.is_lateral
+        {
+            return Ok(false);
+        }
+
+        let right = s_expr.child(1)?;
+        let right_prop = RelExpr::with_s_expr(right).derive_relational_prop()?;
+        if right_prop
+            .output_columns
+            .iter()
+            .any(|column| required.contains(column))
+        {
+            return Ok(false);
+        }
+
+        // Filters on the right side can only reduce the matched rows.
+        let mut scan = right;
+        while let RelOperator::Filter(_) = scan.plan() {
+            scan = scan.child(0)?;
+        }
+        let RelOperator::Scan(scan) = scan.plan() else {
+            return Ok(false);
+        };
+        if scan.change_type.is_some() {
+            return Ok(false);
+        }
+
+        let metadata = self.metadata.read();
+        let mut key_columns = Vec::with_capacity(join.right_conditions.len());
+        for condition in join.right_conditions.iter() {
+            let ScalarExpr::BoundColumnRef(column_ref) = condition else {
+                return Ok(false);
+            };
+            match metadata.column(column_ref.column.index) {
+                ColumnEntry::BaseTableColumn(BaseTableColumn {
+                    table_index,
+                    column_name,
+                    path_indices: None,
+                    virtual_computed_expr: None,
+                    ..
+                }) if *table_index == scan.table_index => key_columns.push(column_name.clone()),
+                _ => return Ok(false),
+            }
+        }
+
+        let table = metadata.table(scan.table_index).table();
+        let is_unique = table
+            .get_table_info()
+            .meta
+            .constraints
+            .values()
+            .any(|constraint| match constraint {
+                TableConstraint::PrimaryKey { columns } | TableConstraint::Unique { columns } => {
+                    columns.iter().all(|column| key_columns.contains(column))
+                }
+                _ => false,
+            });
+        Ok(is_unique)
+    }
+}
@@ -0,0 +1,502 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeSet;
+use std::collections::HashMap;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use databend_common_exception::Result;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+
+use crate::binder::split_conjunctions;
+use crate::optimizer::RelExpr;
+use crate::optimizer::SExpr;
+use crate::plans::AggregateMode;
+use crate::plans::BoundColumnRef;
+use crate::plans::EvalScalar;
+use crate::plans::Filter;
+use crate::plans::JoinType;
+use crate::plans::RelOperator;
+use crate::plans::ScalarItem;
+use crate::ColumnBinding;
+use crate::ColumnEntry;
+use crate::ColumnSet;
+use crate::IndexType;
+use crate::Metadata;
+use crate::MetadataRef;
+use crate::ScalarExpr;
+
+/// A materialized view that may answer the query, bound into the metadata of the query.
+#[derive(Clone, Debug)]
+pub struct MaterializedViewCandidate {
+    /// The bound query of the materialized view.
+    pub query: SExpr,
+    /// The output columns of the query, in the order of the columns of the view.
+    pub query_columns: Vec<IndexType>,
+    /// The scan of the table storing the data of the view.
+    pub scan: SExpr,
+    /// The columns of the scan, in the order of the columns of the view.
+    pub scan_columns: Vec<ColumnBinding>,
+}
+
+/// The shape of a plan used for matching.
+struct PlanShape {
+    /// The part of the plan that must be the same, including the scanned tables,
+    /// the joins, the aggregations and the predicates below them.
+    core: String,
+    /// The conjunctive predicates on top of the core, which can be compensated.
+    predicates: Vec<(String, ScalarExpr)>,
+    tables: BTreeSet<String>,
+}
+
+struct ViewShape<'a> {
+    candidate: &'a MaterializedViewCandidate,
+    shape: PlanShape,
+    /// The canonical form of the output columns -> the position of the column in the view.
+    outputs: HashMap<String, usize>,
+}
+
+/// Rewrites the sub-plans which can be answered by a materialized view to scans of the view.
+///
+/// A sub-plan can be answered by a view if they scan the same tables with the same joins
+/// and aggregations, and the predicates of the view are a subset of the predicates of
+/// the sub-plan. The other predicates are evaluated on top of the scan of the view,
+/// so the columns used by them and the columns required by the parent plans must be
+/// outputs of the view.
+pub struct MaterializedViewRewriter {
+    metadata: MetadataRef,
+    /// The canonical form of the derived columns.
+    derived_columns: HashMap<IndexType, String>,
+    /// A view is scanned at most once, the columns of its scan can't be duplicated.
+    used_views: HashSet<usize>,
+}
+
+impl MaterializedViewRewriter {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            metadata,
+            derived_columns: HashMap::new(),
+            used_views: HashSet::new(),
+        }
+    }
+
+    pub fn run(mut self, s_expr: &SExpr, required: &ColumnSet) -> Result<SExpr> {
+        let candidates = self.metadata.read().materialized_views().to_vec();
+        self.collect_derived_columns(s_expr);
+        for candidate in candidates.iter() {
+            self.collect_derived_columns(&candidate.query);
+        }
+
+        let views = {
+            let metadata = self.metadata.read();
+            candidates
+                .iter()
+                .filter_map(|candidate| {
+                    let shape = self.shape(&metadata, &candidate.query)?;
+                    let mut outputs = HashMap::new();
+                    for (position, index) in candidate.query_columns.iter().enumerate() {
+                        if let Some(column) = self.column(&metadata, *index) {
+                            outputs.entry(column).or_insert(position);
+                        }
+                    }
+                    Some(ViewShape {
+                        candidate,
+                        shape,
+                        outputs,
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+        if views.is_empty() {
+            return Ok(s_expr.clone());
+        }
+
+        self.rewrite(s_expr, required, &views)
+    }
+
+    fn rewrite(
+        &mut self,
+        s_expr: &SExpr,
+        required: &ColumnSet,
+        views: &[ViewShape],
+    ) -> Result<SExpr> {
+        let shape = self.shape(&self.metadata.read(), s_expr);
+        if let Some(shape) = shape {
+            for (idx, view) in views.iter().enumerate() {
+                if self.used_views.contains(&idx) {
+                    continue;
+                }
+                if let Some(result) = self.try_replace(s_expr, &shape, required, view)? {
+                    self.used_views.insert(idx);
+                    return Ok(result);
+                }
+            }
+        }
+
+        let mut child_required = required.clone();
+        child_required.extend(Self::used_columns(s_expr)?);
+        let mut children = Vec::with_capacity(s_expr.arity());
+        for child in s_expr.children() {
+            children.push(Arc::new(self.rewrite(child, &child_required, views)?));
+        }
+        Ok(s_expr.replace_children(children))
+    }
+
+    fn try_replace(
+        &self,
+        s_expr: &SExpr,
+        shape: &PlanShape,
+        required: &ColumnSet,
+        view: &ViewShape,
+    ) -> Result<Option<SExpr>> {
+        if shape.core != view.shape.core {
+            return Ok(None);
+        }
+        let predicates = shape
+            .predicates
+            .iter()
+            .map(|(predicate, _)| predicate.as_str())
+            .collect::<HashSet<_>>();
+        if !view
+            .shape
+            .predicates
+            .iter()
+            .all(|(predicate, _)| predicates.contains(predicate.as_str()))
+        {
+            return Ok(None);
+        }
+        let view_predicates = view
+            .shape
+            .predicates
+            .iter()
+            .map(|(predicate, _)| predicate.as_str())
+            .collect::<HashSet<_>>();
+        let residual = shape
+            .predicates
+            .iter()
+            .filter(|(predicate, _)| !view_predicates.contains(predicate.as_str()))
+            .map(|(_, scalar)| scalar.clone())
+            .collect::<Vec<_>>();
+
+        let output_columns = RelExpr::with_s_expr(s_expr)
+            .derive_relational_prop()?
+            .output_columns
+            .clone();
+        let mut needed = required
+            .iter()
+            .filter(|index| output_columns.contains(index))
+            .cloned()
+            .collect::<BTreeSet<_>>();
+        for predicate in residual.iter() {
+            needed.extend(predicate.used_columns());
+        }
+
+        let metadata = self.metadata.read();
+        let mut items = Vec::with_capacity(needed.len());
+        for index in needed {
+            let Some(column) = self.column(&metadata, index) else {
+                return Ok(None);
+            };
+            let Some(position) = view.outputs.get(&column) else {
+                return Ok(None);
+            };
+            let column_binding = &view.candidate.scan_columns[*position];
+            if *column_binding.data_type != metadata.column(index).data_type() {
+                return Ok(None);
+            }
+            items.push(ScalarItem {
+                scalar: ScalarExpr::BoundColumnRef(BoundColumnRef {
+                    span: None,
+                    column: column_binding.clone(),
+                }),
+                index,
+            });
+        }
+
+        let mut result = view.candidate.scan.clone();
+        if !items.is_empty() {
+            result = SExpr::create_unary(Arc::new(EvalScalar { items }.into()), Arc::new(result));
+        }
+        if !residual.is_empty() {
+            result = SExpr::create_unary(
+                Arc::new(
+                    Filter {
+                        predicates: residual,
+                    }
+                    .into(),
+                ),
+                Arc::new(result),
+            );
+        }
+        Ok(Some(result))
+    }
+
+    fn shape(&self, metadata: &Metadata, s_expr: &SExpr) -> Option<PlanShape> {
+        match s_expr.plan() {
+            RelOperator::Scan(scan) => {
+                if scan.change_type.is_some()
+                    || scan.agg_index.is_some()
+                    || scan.push_down_predicates.is_some()
+                    || scan.prewhere.is_some()
+                    || scan.order_by.is_some()
+                    || scan.limit.is_some()
+                {
+                    return None;
+                }
+                let table = metadata.table(scan.table_index);
+                let name = format!("{}.{}.{}", table.catalog(), table.database(), table.name());
+                Some(PlanShape {
+                    core: format!("Scan({name})"),
+                    predicates: vec![],
+                    tables: BTreeSet::from([name]),
+                })
+            }
+            RelOperator::EvalScalar(_) => self.shape(metadata, s_expr.child(0).ok()?),
+            RelOperator::Filter(filter) => {
+                let mut shape = self.shape(metadata, s_expr.child(0).ok()?)?;
+                for predicate in filter.predicates.iter() {
+                    for predicate in split_conjunctions(predicate) {
+                        let canonical = self.scalar(metadata, &predicate)?;
+                        shape.predicates.push((canonical, predicate));
+                    }
+                }
+                Some(shape)
+            }
+            RelOperator::Aggregate(aggregate) => {
+                if !matches!(aggregate.mode, AggregateMode::Initial)
+                    || aggregate.grouping_sets.is_some()
+                {
+                    return None;
+                }
+                let child = self.shape(metadata, s_expr.child(0).ok()?)?;
+                let mut group_items = aggregate
+                    .group_items
+                    .iter()
+                    .map(|item| self.scalar(metadata, &item.scalar))
+                    .collect::<Option<Vec<_>>>()?;
+                group_items.sort();
+                Some(PlanShape {
+                    core: format!(
+                        "Aggregate({}, [{}], [{}])",
+                        child.core,
+                        Self::sorted_predicates(&child),
+                        group_items.join(", ")
+                    ),
+                    predicates: vec![],
+                    tables: child.tables,
+                })
+            }
+            RelOperator::Join(join) => {
+                if join.marker_index.is_some()
+                    || join.from_correlated_subquery
+                    || join.is_lateral
+                    || !matches!(
+                        join.join_type,
+                        JoinType::Cross
+                            | JoinType::Inner
+                            | JoinType::Left
+                            | JoinType::Right
+                            | JoinType::Full
+                    )
+                {
+                    return None;
+                }
+                let left = self.shape(metadata, s_expr.child(0).ok()?)?;
+                let right = self.shape(metadata, s_expr.child(1).ok()?)?;
+                // The canonical form of the columns can't distinguish a table scanned twice.
+                if !left.tables.is_disjoint(&right.tables) {
+                    return None;
+                }
+                let mut equi_conditions = join
+                    .left_conditions
+                    .iter()
+                    .zip(join.right_conditions.iter())
+                    .map(|(left, right)| {
+                        Some(format!(
+                            "{} = {}",
+                            self.scalar(metadata, left)?,
+                            self.scalar(metadata, right)?
+                        ))
+                    })
+                    .collect::<Option<Vec<_>>>()?;
+                equi_conditions.sort();
+                let mut non_equi_conditions = join
+                    .non_equi_conditions
+                    .iter()
+                    .map(|condition| self.scalar(metadata, condition))
+                    .collect::<Option<Vec<_>>>()?;
+                non_equi_conditions.sort();
+
+                let mut tables = left.tables.clone();
+                tables.extend(right.tables.iter().cloned());
+                if matches!(join.join_type, JoinType::Cross | JoinType::Inner) {
+                    // The predicates of the children of an inner join can be evaluated on top of it.
+                    let core = format!(
+                        "Join({:?}, {}, {}, [{}], [{}])",
+                        join.join_type,
+                        left.core,
+                        right.core,
+                        equi_conditions.join(", "),
+                        non_equi_conditions.join(", ")
+                    );
+                    let mut predicates = left.predicates;
+                    predicates.extend(right.predicates);
+                    Some(PlanShape {
+                        core,
+                        predicates,
+                        tables,
+                    })
+                } else {
+                    let core = format!(
+                        "Join({:?}, {}, [{}], {}, [{}], [{}], [{}])",
+                        join.join_type,
+                        left.core,
+                        Self::sorted_predicates(&left),
+                        right.core,
+                        Self::sorted_predicates(&right),
+                        equi_conditions.join(", "),
+                        non_equi_conditions.join(", ")
+                    );
+                    Some(PlanShape {
+                        core,
+                        predicates: vec![],
+                        tables,
+                    })
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn sorted_predicates(shape: &PlanShape) -> String {
+        let predicates = shape
+            .predicates
+            .iter()
+            .map(|(predicate, _)| predicate.as_str())
+            .collect::<BTreeSet<_>>();
+        predicates.into_iter().collect::<Vec<_>>().join(", ")
+    }
+
+    fn collect_derived_columns(&mut self, s_expr: &SExpr) {
+        for child in s_expr.children() {
+            self.collect_derived_columns(child);
+        }
+        let items = match s_expr.plan() {
+            RelOperator::EvalScalar(eval_scalar) => eval_scalar.items.iter().collect::<Vec<_>>(),
+            RelOperator::Aggregate(aggregate) => aggregate
+                .group_items
+                .iter()
+                .chain(aggregate.aggregate_functions.iter())
+                .collect(),
+            _ => return,
+        };
+        let metadata = self.metadata.clone();
+        let metadata = metadata.read();
+        for item in items {
+            if self.derived_columns.contains_key(&item.index) {
+                continue;
+            }
+            if let Some(canonical) = self.scalar(&metadata, &item.scalar) {
+                self.derived_columns.insert(item.index, canonical);
+            }
+        }
+    }
+
+    fn column(&self, metadata: &Metadata, index: IndexType) -> Option<String> {
+        if let Some(canonical) = self.derived_columns.get(&index) {
+            return Some(canonical.clone());
+        }
+        match metadata.column(index) {
+            ColumnEntry::BaseTableColumn(column) => {
+                let table = metadata.table(column.table_index);
+                Some(format!(
+                    "{}.{}.{}.{}",
+                    table.catalog(),
+                    table.database(),
+                    table.name(),
+                    column.column_name
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    /// The canonical form of a scalar, `None` if the scalar can't be matched.
+    fn scalar(&self, metadata: &Metadata, scalar: &ScalarExpr) -> Option<String> {
+        match scalar {
+            ScalarExpr::BoundColumnRef(column_ref) => {
+                self.column(metadata, column_ref.column.index)
+            }
+            ScalarExpr::ConstantExpr(constant) => Some(format!("{:?}", constant.value)),
+            ScalarExpr::FunctionCall(func) => {
+                let non_deterministic = BUILTIN_FUNCTIONS
+                    .get_property(&func.func_name)
+                    .map(|property| property.non_deterministic)
+                    .unwrap_or(false);
+                if non_deterministic {
+                    return None;
+                }
+                let arguments = func
+                    .arguments
+                    .iter()
+                    .map(|arg| self.scalar(metadata, arg))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(format!(
+                    "{}{:?}({})",
+                    func.func_name,
+                    func.params,
+                    arguments.join(", ")
+                ))
+            }
+            ScalarExpr::CastExpr(cast) => {
+                let argument = self.scalar(metadata, &cast.argument)?;
+                let name = if cast.is_try { "TRY_CAST" } else { "CAST" };
+                Some(format!("{name}({argument} AS {})", cast.target_type))
+            }
+            ScalarExpr::AggregateFunction(aggregate) => {
+                let args = aggregate
+                    .args
+                    .iter()
+                    .map(|arg| self.scalar(metadata, arg))
+                    .collect::<Option<Vec<_>>>()?;
+                Some(format!(
+                    "{}{}{:?}({})",
+                    aggregate.func_name,
+                    if aggregate.distinct { "_distinct" } else { "" },
+                    aggregate.params,
+                    args.join(", ")
+                ))
+            }
+            _ => None,
+        }
+    }
+
+    fn used_columns(s_expr: &SExpr) -> Result<ColumnSet> {
+        match s_expr.plan() {
+            RelOperator::EvalScalar(eval_scalar) => eval_scalar.used_columns(),
+            RelOperator::Filter(filter) => filter.used_columns(),
+            RelOperator::Aggregate(aggregate) => aggregate.used_columns(),
+            RelOperator::Join(join) => join.used_columns(),
+            RelOperator::Window(window) => window.used_columns(),
+            RelOperator::Udf(udf) => udf.used_columns(),
+            RelOperator::UnionAll(union_all) => union_all.used_columns(),
+            RelOperator::Sort(sort) => Ok(sort.items.iter().map(|item| item.index).collect()),
+            _ => Ok(RelExpr::with_s_expr(s_expr)
+                .derive_relational_prop()?
+                .used_columns
+                .clone()),
+        }
+    }
+}
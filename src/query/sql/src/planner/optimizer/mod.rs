@@ -21,7 +21,9 @@ mod filter;
 mod format;
 mod group;
 mod hyper_dp;
+mod join;
 mod m_expr;
+mod materialized_view;
 mod memo;
 #[allow(clippy::module_inception)]
 mod optimizer;
@@ -36,6 +38,8 @@ pub use decorrelate::SubqueryRewriter;
 pub use extract::PatternExtractor;
 pub use hyper_dp::DPhpy;
 pub use m_expr::MExpr;
+pub use materialized_view::MaterializedViewCandidate;
+pub use materialized_view::MaterializedViewRewriter;
 pub use memo::Memo;
 pub use optimizer::optimize;
 pub use optimizer::optimize_query;
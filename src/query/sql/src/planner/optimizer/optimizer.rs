@@ -35,8 +35,10 @@ use crate::optimizer::distributed::SortAndLimitPushDownOptimizer;
 use crate::optimizer::filter::DeduplicateJoinConditionOptimizer;
 use crate::optimizer::filter::PullUpFilterOptimizer;
 use crate::optimizer::hyper_dp::DPhpy;
+use crate::optimizer::join::EliminateLeftJoinOptimizer;
 use crate::optimizer::rule::TransformResult;
 use crate::optimizer::util::contains_local_table_scan;
+use crate::optimizer::MaterializedViewRewriter;
 use crate::optimizer::RuleFactory;
 use crate::optimizer::RuleID;
 use crate::optimizer::SExpr;
@@ -168,14 +170,39 @@ pub fn optimize(opt_ctx: OptimizerContext, plan: Plan) -> Result<Plan> {
             rewrite_kind,
             formatted_ast,
             ignore_result,
-        } => Ok(Plan::Query {
-            s_expr: Box::new(optimize_query(opt_ctx, *s_expr)?),
-            bind_context,
-            metadata,
-            rewrite_kind,
-            formatted_ast,
-            ignore_result,
-        }),
+        } => {
+            let s_expr = if !metadata.read().materialized_views().is_empty()
+                && opt_ctx
+                    .table_ctx
+                    .get_settings()
+                    .get_enable_materialized_view_rewrite()?
+            {
+                MaterializedViewRewriter::new(metadata.clone())
+                    .run(&s_expr, &bind_context.column_set())?
+            } else {
+                *s_expr
+            };
+            // The PRIMARY KEY and UNIQUE constraints are not enforced, removing joins by them
+            // must be enabled explicitly.
+            let s_expr = if opt_ctx
+                .table_ctx
+                .get_settings()
+                .get_enable_join_elimination_by_key_constraints()?
+            {
+                EliminateLeftJoinOptimizer::new(metadata.clone())
+                    .run(&s_expr, &bind_context.column_set())?
+            } else {
+                s_expr
+            };
+            Ok(Plan::Query {
+                s_expr: Box::new(optimize_query(opt_ctx, s_expr)?),
+                bind_context,
+                metadata,
+                rewrite_kind,
+                formatted_ast,
+                ignore_result,
+            })
+        }
         Plan::Explain { kind, plan } => match kind {
             ExplainKind::Raw | ExplainKind::Ast(_) | ExplainKind::Syntax(_) => {
                 Ok(Plan::Explain { kind, plan })
@@ -17,6 +17,7 @@ use databend_common_constraint::mir::MirConstant;
 use databend_common_constraint::mir::MirDataType;
 use databend_common_constraint::mir::MirExpr;
 use databend_common_constraint::mir::MirUnaryOperator;
+use databend_common_constraint::problem::assertion_is_unsatisfiable;
 use databend_common_constraint::problem::variable_must_not_null;
 use databend_common_expression::cast_scalar;
 use databend_common_expression::types::DataType;
@@ -86,6 +87,36 @@ impl ConstraintSet {
 
         variable_must_not_null(&conjunctions, &variable.to_string())
     }
+
+    /// Check if the conjunctions can never be true, given the CHECK constraints
+    /// of a table are not violated.
+    ///
+    /// NOTICE: like `is_null_reject`, this check is false-positive, unsupported
+    /// constraints and check constraints are ignored.
+    pub fn is_unsatisfiable(&self, check_constraints: &[ScalarExpr]) -> bool {
+        let assumptions = check_constraints
+            .iter()
+            .filter_map(as_mir)
+            .collect::<Vec<_>>();
+        if assumptions.is_empty() {
+            return false;
+        }
+
+        let Some(conjunctions) =
+            self.constraints
+                .iter()
+                .map(|(_, mir)| mir.clone())
+                .reduce(|left, right| MirExpr::BinaryOperator {
+                    op: MirBinaryOperator::And,
+                    left: Box::new(left),
+                    right: Box::new(right),
+                })
+        else {
+            return false;
+        };
+
+        assertion_is_unsatisfiable(&assumptions, &conjunctions)
+    }
 }
 
 /// Transform a logical expression into a MIR expression.
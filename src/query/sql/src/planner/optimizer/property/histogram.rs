@@ -19,6 +19,7 @@ use std::fmt::Debug;
 use databend_common_exception::Result;
 use databend_common_expression::arithmetics_type::ResultTypeOfUnary;
 use databend_common_storage::Datum;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 
 pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 
@@ -33,18 +34,26 @@ pub const DEFAULT_HISTOGRAM_BUCKETS: usize = 100;
 /// it is difficult to give the exact frequency of the skew data
 /// when the skew data and other data fall into the same bucket
 ///
-/// We choose this approach because so far the histogram is originally
-/// constructed from NDV(number of distinct values) and the total number
-/// of rows instead of maintaining a real histogram for each column,
-/// which brings the assumption that the data is uniformly distributed.
+/// The histogram is either collected from the sampled data by `ANALYZE TABLE`,
+/// or constructed from NDV(number of distinct values) and the total number
+/// of rows, which brings the assumption that the data is uniformly distributed.
 #[derive(Debug, Clone)]
 pub struct Histogram {
+    /// Whether the histogram is collected from the real data.
+    pub accuracy: bool,
     pub buckets: Vec<HistogramBucket>,
+    /// The most common values and their number of occurrences,
+    /// only available in accurate histograms.
+    pub most_common_values: Vec<(Datum, f64)>,
 }
 
 impl Histogram {
     pub fn new(buckets: Vec<HistogramBucket>) -> Self {
-        Self { buckets }
+        Self {
+            accuracy: false,
+            buckets,
+            most_common_values: vec![],
+        }
     }
 
     /// Get number of buckets
@@ -71,6 +80,117 @@ impl Histogram {
     pub fn buckets_iter(&self) -> impl DoubleEndedIterator<Item = &HistogramBucket> {
         self.buckets.iter()
     }
+
+    /// Update the number of values after applying a filter with the selectivity
+    pub fn update(&mut self, selectivity: f64) {
+        for bucket in self.buckets.iter_mut() {
+            bucket.update(selectivity);
+        }
+        for (_, count) in self.most_common_values.iter_mut() {
+            *count *= selectivity;
+        }
+    }
+
+    /// Estimate the number of values equal to `value`.
+    pub fn num_values_equal(&self, value: &Datum) -> Result<f64> {
+        if let Some((_, count)) = self
+            .most_common_values
+            .iter()
+            .find(|(v, _)| v.compare(value).is_ok_and(|ord| ord == Ordering::Equal))
+        {
+            return Ok(*count);
+        }
+        for (idx, bucket) in self.buckets.iter().enumerate() {
+            match bucket.upper_bound.compare(value)? {
+                Ordering::Less => continue,
+                // The value is less than the min value.
+                Ordering::Greater if idx == 0 => return Ok(0.0),
+                _ => {}
+            }
+            // The common values falling into the bucket are excluded,
+            // as they have been estimated precisely.
+            let mut num_values = bucket.num_values;
+            let mut num_distinct = bucket.num_distinct;
+            for (mcv, count) in self.most_common_values.iter() {
+                let above_lower = idx == 0
+                    || mcv.compare(&self.buckets[idx - 1].upper_bound)? == Ordering::Greater;
+                if above_lower && mcv.compare(&bucket.upper_bound)? != Ordering::Greater {
+                    num_values -= count;
+                    num_distinct -= 1.0;
+                }
+            }
+            return Ok(num_values.max(0.0) / num_distinct.max(1.0));
+        }
+        // The value is greater than the max value.
+        Ok(0.0)
+    }
+
+    /// Estimate the number of values less than `value`, or less than or
+    /// equal to `value` if `inclusive` is true.
+    ///
+    /// The values are assumed to be uniformly distributed within a bucket.
+    pub fn num_values_less_than(&self, value: &Datum, inclusive: bool) -> Result<f64> {
+        let mut num_values = 0.0;
+        for (idx, bucket) in self.buckets.iter().enumerate() {
+            match bucket.upper_bound.compare(value)? {
+                Ordering::Less => num_values += bucket.num_values,
+                Ordering::Equal => {
+                    num_values += bucket.num_values;
+                    if !inclusive {
+                        num_values -= bucket.num_values / bucket.num_distinct.max(1.0);
+                    }
+                    break;
+                }
+                Ordering::Greater => {
+                    if idx == 0 {
+                        break;
+                    }
+                    let lower_bound = &self.buckets[idx - 1].upper_bound;
+                    let fraction = if value.is_numeric() && lower_bound.is_numeric() {
+                        let lower = lower_bound.to_double()?;
+                        let upper = bucket.upper_bound.to_double()?;
+                        (value.to_double()? - lower) / (upper - lower)
+                    } else {
+                        0.5
+                    };
+                    num_values += bucket.num_values * fraction.clamp(0.0, 1.0);
+                    break;
+                }
+            }
+        }
+        Ok(num_values)
+    }
+}
+
+/// Construct a histogram from the one collected by `ANALYZE TABLE`,
+/// returns `None` if the values can't be represented by `Datum`.
+pub fn histogram_from_column_histogram(histogram: &ColumnHistogram) -> Option<Histogram> {
+    // The first bucket only holds the min value.
+    if histogram.buckets.len() < 2 {
+        return None;
+    }
+    let buckets = histogram
+        .buckets
+        .iter()
+        .map(|bucket| {
+            let upper_bound = Datum::from_scalar(bucket.upper_bound.clone())?;
+            Some(HistogramBucket::new(
+                upper_bound,
+                bucket.num_values,
+                bucket.num_distinct,
+            ))
+        })
+        .collect::<Option<Vec<_>>>()?;
+    let most_common_values = histogram
+        .most_common_values
+        .iter()
+        .map(|mcv| Some((Datum::from_scalar(mcv.value.clone())?, mcv.count)))
+        .collect::<Option<Vec<_>>>()?;
+    Some(Histogram {
+        accuracy: true,
+        buckets,
+        most_common_values,
+    })
 }
 
 /// Construct a histogram from NDV and total number of rows.
@@ -93,7 +213,7 @@ pub fn histogram_from_ndv(
                 ndv, num_rows
             ))
         } else {
-            Ok(Histogram { buckets: vec![] })
+            Ok(Histogram::new(vec![]))
         };
     }
 
@@ -148,7 +268,7 @@ pub fn histogram_from_ndv(
         buckets.push(bucket);
     }
 
-    Ok(Histogram { buckets })
+    Ok(Histogram::new(buckets))
 }
 
 #[derive(Debug, Clone)]
@@ -31,6 +31,7 @@ use databend_common_storage::F64;
 
 use crate::optimizer::histogram_from_ndv;
 use crate::optimizer::ColumnStat;
+use crate::optimizer::Histogram;
 use crate::optimizer::Statistics;
 use crate::optimizer::DEFAULT_HISTOGRAM_BUCKETS;
 use crate::plans::ComparisonOp;
@@ -140,12 +141,29 @@ impl<'a> SelectivityEstimator<'a> {
                     return Ok(DEFAULT_SELECTIVITY);
                 };
 
+                if let Some(col_hist) = column_stat.histogram.as_ref().filter(|h| h.accuracy) {
+                    if let Some(selectivity) = evaluate_range(col_hist, &op, &const_datum) {
+                        if update {
+                            let (new_min, new_max) = match op {
+                                ComparisonOp::GT | ComparisonOp::GTE => {
+                                    (const_datum, column_stat.max.clone())
+                                }
+                                _ => (column_stat.min.clone(), const_datum),
+                            };
+                            update_statistic(column_stat, new_min, new_max, selectivity)?;
+                            self.updated_column_indexes.insert(column_ref.column.index);
+                        }
+                        return Ok(selectivity);
+                    }
+                }
+
                 return match op {
                     ComparisonOp::Equal => {
                         // For equal predicate, we just use cardinality of a single
                         // value to estimate the selectivity. This assumes that
-                        // the column is in a uniform distribution.
-                        let selectivity = evaluate_equal(column_stat, constant);
+                        // the column is in a uniform distribution, unless there
+                        // is an accurate histogram of the column.
+                        let selectivity = evaluate_equal(column_stat, constant, &const_datum);
                         if update {
                             update_statistic(
                                 column_stat,
@@ -159,7 +177,7 @@ impl<'a> SelectivityEstimator<'a> {
                     }
                     ComparisonOp::NotEqual => {
                         // For not equal predicate, we treat it as opposite of equal predicate.
-                        let selectivity = 1.0 - evaluate_equal(column_stat, constant);
+                        let selectivity = 1.0 - evaluate_equal(column_stat, constant, &const_datum);
                         if update {
                             update_statistic(
                                 column_stat,
@@ -325,9 +343,7 @@ impl<'a> SelectivityEstimator<'a> {
                     if new_ndv as u64 <= 2 {
                         column_stat.histogram = None;
                     } else {
-                        for bucket in histogram.buckets.iter_mut() {
-                            bucket.update(selectivity);
-                        }
+                        histogram.update(selectivity);
                     }
                 }
             }
@@ -347,7 +363,18 @@ fn is_true_constant_predicate(constant: &ConstantExpr) -> bool {
     }
 }
 
-fn evaluate_equal(column_stat: &ColumnStat, constant: &ConstantExpr) -> f64 {
+fn evaluate_equal(column_stat: &ColumnStat, constant: &ConstantExpr, datum: &Datum) -> f64 {
+    if let Some(histogram) = column_stat.histogram.as_ref().filter(|h| h.accuracy) {
+        let num_values = histogram.num_values();
+        if let Ok(num_equal) = histogram.num_values_equal(datum) {
+            return if num_values == 0.0 {
+                0.0
+            } else {
+                (num_equal / num_values).clamp(0.0, 1.0)
+            };
+        }
+    }
+
     let constant_datum = Datum::from_scalar(constant.value.clone());
     match constant.value.as_ref().infer_data_type() {
         DataType::Null => 0.0,
@@ -376,6 +403,22 @@ fn evaluate_equal(column_stat: &ColumnStat, constant: &ConstantExpr) -> f64 {
     }
 }
 
+// Estimate the selectivity of range predicates with an accurate histogram.
+fn evaluate_range(histogram: &Histogram, op: &ComparisonOp, datum: &Datum) -> Option<f64> {
+    let num_values = histogram.num_values();
+    if num_values == 0.0 {
+        return None;
+    }
+    let selectivity = match op {
+        ComparisonOp::LT => histogram.num_values_less_than(datum, false).ok()? / num_values,
+        ComparisonOp::LTE => histogram.num_values_less_than(datum, true).ok()? / num_values,
+        ComparisonOp::GT => 1.0 - histogram.num_values_less_than(datum, true).ok()? / num_values,
+        ComparisonOp::GTE => 1.0 - histogram.num_values_less_than(datum, false).ok()? / num_values,
+        _ => return None,
+    };
+    Some(selectivity.clamp(0.0, 1.0))
+}
+
 fn compare_equal(datum: &Option<Datum>, column_stat: &ColumnStat) -> f64 {
     let col_min = &column_stat.min;
     let col_max = &column_stat.max;
@@ -23,6 +23,7 @@ mod rule_merge_eval_scalar;
 mod rule_merge_filter;
 mod rule_normalize_aggregate;
 mod rule_normalize_scalar;
+mod rule_prune_filter_by_constraint;
 mod rule_push_down_filter_aggregate;
 mod rule_push_down_filter_eval_scalar;
 mod rule_push_down_filter_join;
@@ -43,6 +44,8 @@ mod rule_push_down_sort_scan;
 mod rule_semi_to_inner_join;
 mod rule_split_aggregate;
 mod rule_try_apply_agg_index;
+mod rule_try_apply_inverted_index;
+mod rule_try_apply_vector_index;
 
 pub use rule_commute_join::RuleCommuteJoin;
 pub use rule_eliminate_eval_scalar::RuleEliminateEvalScalar;
@@ -53,6 +56,7 @@ pub use rule_merge_eval_scalar::RuleMergeEvalScalar;
 pub use rule_merge_filter::RuleMergeFilter;
 pub use rule_normalize_aggregate::RuleNormalizeAggregate;
 pub use rule_normalize_scalar::RuleNormalizeScalarFilter;
+pub use rule_prune_filter_by_constraint::RulePruneFilterByConstraint;
 pub use rule_push_down_filter_aggregate::RulePushDownFilterAggregate;
 pub use rule_push_down_filter_eval_scalar::RulePushDownFilterEvalScalar;
 pub use rule_push_down_filter_join::try_push_down_filter_join;
@@ -74,3 +78,5 @@ pub use rule_push_down_sort_scan::RulePushDownSortScan;
 pub use rule_semi_to_inner_join::RuleSemiToInnerJoin;
 pub use rule_split_aggregate::RuleSplitAggregate;
 pub use rule_try_apply_agg_index::RuleTryApplyAggIndex;
+pub use rule_try_apply_inverted_index::RuleTryApplyInvertedIndex;
+pub use rule_try_apply_vector_index::RuleTryApplyVectorIndex;
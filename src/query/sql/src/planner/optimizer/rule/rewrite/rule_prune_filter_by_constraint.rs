@@ -0,0 +1,99 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+
+use crate::optimizer::extract::Matcher;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::RuleID;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::SExpr;
+use crate::plans::RelOp;
+use crate::MetadataRef;
+
+/// Rule to replace a Filter on a table scan with FALSE, if the predicates contradict
+/// the CHECK constraints of the table. For example, with `CHECK (a > 0)`, the filter
+/// `a < 0` can never be true.
+pub struct RulePruneFilterByConstraint {
+    id: RuleID,
+    matchers: Vec<Matcher>,
+    #[allow(dead_code)]
+    metadata: MetadataRef,
+}
+
+impl RulePruneFilterByConstraint {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::PruneFilterByConstraint,
+            // Filter
+            //  \
+            //   LogicalGet
+            matchers: vec![Matcher::MatchOp {
+                op_type: RelOp::Filter,
+                children: vec![Matcher::MatchOp {
+                    op_type: RelOp::Scan,
+                    children: vec![],
+                }],
+            }],
+            metadata,
+        }
+    }
+}
+
+impl Rule for RulePruneFilterByConstraint {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    #[cfg(feature = "z3-prove")]
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        use std::sync::Arc;
+
+        use crate::optimizer::rule::constant::false_constant;
+        use crate::optimizer::rule::constant::is_falsy;
+        use crate::optimizer::ConstraintSet;
+        use crate::plans::Filter;
+        use crate::plans::Scan;
+
+        let filter: Filter = s_expr.plan().clone().try_into()?;
+        if filter.predicates.iter().any(is_falsy) {
+            return Ok(());
+        }
+        let scan: Scan = s_expr.child(0)?.plan().clone().try_into()?;
+        let metadata = self.metadata.read();
+        let Some(check_constraints) = metadata.get_check_constraints(scan.table_index) else {
+            return Ok(());
+        };
+
+        if ConstraintSet::new(&filter.predicates).is_unsatisfiable(check_constraints) {
+            let filter = Filter {
+                predicates: vec![false_constant()],
+            };
+            state.add_result(SExpr::create_unary(
+                Arc::new(filter.into()),
+                Arc::new(s_expr.child(0)?.clone()),
+            ));
+        }
+        Ok(())
+    }
+
+    #[cfg(not(feature = "z3-prove"))]
+    fn apply(&self, _s_expr: &SExpr, _state: &mut TransformResult) -> Result<()> {
+        Ok(())
+    }
+
+    fn matchers(&self) -> &[Matcher] {
+        &self.matchers
+    }
+}
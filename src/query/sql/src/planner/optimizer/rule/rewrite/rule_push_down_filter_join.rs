@@ -156,9 +156,14 @@ pub fn try_push_down_filter_join(
                 left_push_down.push(predicate);
             }
             JoinPredicate::Right(_) => {
+                // Filtering the right side of asof joins changes the closest match.
                 if matches!(
                     join.join_type,
-                    JoinType::Left | JoinType::LeftSingle | JoinType::Full
+                    JoinType::Left
+                        | JoinType::LeftSingle
+                        | JoinType::Full
+                        | JoinType::Asof
+                        | JoinType::LeftAsof
                 ) {
                     original_predicates.push(predicate);
                     continue;
@@ -0,0 +1,188 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::cmp::Ordering;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::InvertedIndexInfo;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberScalar;
+use databend_common_expression::Scalar;
+use databend_storages_common_index::INVERTED_INDEX_COLUMN;
+
+use crate::optimizer::extract::Matcher;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::FunctionCall;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::Scan;
+use crate::BaseTableColumn;
+use crate::ColumnEntry;
+use crate::MetadataRef;
+
+/// Use an inverted index to skip the blocks which can not satisfy a `match` filter.
+///
+/// Input:  LogicalGet(push down predicates: [match(column, 'query') > const, ...])
+///
+/// Output: LogicalGet(padding inverted index)
+///
+/// `match` is zero for the rows without any term of the query, so a predicate which
+/// requires a positive score can only be true in the blocks containing one of the terms.
+/// The predicates are kept, the rows are still filtered by the `match` function.
+pub struct RuleTryApplyInvertedIndex {
+    id: RuleID,
+    metadata: MetadataRef,
+    matchers: Vec<Matcher>,
+}
+
+impl RuleTryApplyInvertedIndex {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::TryApplyInvertedIndex,
+            metadata,
+            matchers: vec![Matcher::MatchOp {
+                op_type: RelOp::Scan,
+                children: vec![],
+            }],
+        }
+    }
+
+    fn try_build_inverted_index(&self, scan: &Scan) -> Option<InvertedIndexInfo> {
+        scan.push_down_predicates
+            .as_ref()?
+            .iter()
+            .filter_map(Self::positive_match)
+            .find_map(|func| self.find_index(scan, func))
+    }
+
+    /// Returns the `match` function of the predicate if it only holds for positive scores.
+    fn positive_match(predicate: &ScalarExpr) -> Option<&FunctionCall> {
+        let ScalarExpr::FunctionCall(func) = predicate else {
+            return None;
+        };
+        let (func_name, score, value) = match func.arguments.as_slice() {
+            [score, ScalarExpr::ConstantExpr(value)] => (func.func_name.as_str(), score, value),
+            [ScalarExpr::ConstantExpr(value), score] => match func.func_name.as_str() {
+                "lt" => ("gt", score, value),
+                "lte" => ("gte", score, value),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let Scalar::Number(value) = &value.value else {
+            return None;
+        };
+        let sign = Self::sign(value)?;
+        let positive = match func_name {
+            "gt" => sign != Ordering::Less,
+            "gte" => sign == Ordering::Greater,
+            _ => false,
+        };
+        if !positive {
+            return None;
+        }
+
+        let mut score = score;
+        while let ScalarExpr::CastExpr(cast) = score {
+            score = &cast.argument;
+        }
+        match score {
+            ScalarExpr::FunctionCall(func) if func.func_name == "match" => Some(func),
+            _ => None,
+        }
+    }
+
+    fn sign(value: &NumberScalar) -> Option<Ordering> {
+        match value {
+            NumberScalar::Float32(v) => v.0.partial_cmp(&0.0),
+            NumberScalar::Float64(v) => v.0.partial_cmp(&0.0),
+            v => v.integer_to_i128().map(|v| v.cmp(&0)),
+        }
+    }
+
+    fn find_index(&self, scan: &Scan, func: &FunctionCall) -> Option<InvertedIndexInfo> {
+        let (ScalarExpr::BoundColumnRef(column), ScalarExpr::ConstantExpr(query)) =
+            (func.arguments.first()?, func.arguments.get(1)?)
+        else {
+            return None;
+        };
+        let Scalar::String(query) = &query.value else {
+            return None;
+        };
+
+        let metadata = self.metadata.read();
+        let ColumnEntry::BaseTableColumn(BaseTableColumn {
+            table_index,
+            column_name,
+            path_indices: None,
+            ..
+        }) = metadata.column(column.column.index)
+        else {
+            return None;
+        };
+        if *table_index != scan.table_index {
+            return None;
+        }
+        let table_entry = metadata.table(scan.table_index);
+        let table_name = format!(
+            "{}.{}.{}",
+            table_entry.catalog(),
+            table_entry.database(),
+            table_entry.table().name()
+        );
+        let (index_id, index_name, _) =
+            metadata
+                .get_inverted_indexes(&table_name)?
+                .iter()
+                .find(|(_, _, index_meta)| {
+                    index_meta.options.get(INVERTED_INDEX_COLUMN) == Some(column_name)
+                })?;
+
+        Some(InvertedIndexInfo {
+            index_id: *index_id,
+            index_name: index_name.clone(),
+            column_name: column_name.clone(),
+            query: query.clone(),
+        })
+    }
+}
+
+impl Rule for RuleTryApplyInvertedIndex {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let mut scan: Scan = s_expr.plan().clone().try_into()?;
+        if scan.inverted_index.is_some() || scan.agg_index.is_some() {
+            return Ok(());
+        }
+        let Some(inverted_index) = self.try_build_inverted_index(&scan) else {
+            return Ok(());
+        };
+        scan.inverted_index = Some(inverted_index);
+
+        let mut result = SExpr::create_leaf(Arc::new(RelOperator::Scan(scan)));
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    fn matchers(&self) -> &[Matcher] {
+        &self.matchers
+    }
+}
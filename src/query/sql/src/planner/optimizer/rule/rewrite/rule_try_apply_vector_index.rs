@@ -0,0 +1,195 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_catalog::plan::VectorIndexInfo;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberColumn;
+use databend_common_expression::Column;
+use databend_common_expression::Scalar;
+use databend_common_vector::VectorDistance;
+use databend_storages_common_index::VectorIndexParams;
+use databend_storages_common_index::VECTOR_INDEX_COLUMN;
+
+use crate::optimizer::extract::Matcher;
+use crate::optimizer::rule::Rule;
+use crate::optimizer::rule::TransformResult;
+use crate::optimizer::RuleID;
+use crate::optimizer::SExpr;
+use crate::plans::EvalScalar;
+use crate::plans::RelOp;
+use crate::plans::RelOperator;
+use crate::plans::ScalarExpr;
+use crate::plans::Scan;
+use crate::plans::Sort;
+use crate::BaseTableColumn;
+use crate::ColumnEntry;
+use crate::MetadataRef;
+
+/// Use a vector index to find the nearest rows of a constant vector.
+///
+/// Input:  Sort(limit: k, order by: distance(column, const))
+///           \
+///          EvalScalar
+///             \
+///             LogicalGet
+///
+/// Output:
+///         Sort
+///           \
+///          EvalScalar
+///             \
+///             LogicalGet(padding vector index)
+///
+/// The sort is kept, the index only prunes the blocks which can not contain
+/// the k nearest rows, so the result is re-ranked by the exact distance.
+pub struct RuleTryApplyVectorIndex {
+    id: RuleID,
+    metadata: MetadataRef,
+    matchers: Vec<Matcher>,
+}
+
+impl RuleTryApplyVectorIndex {
+    pub fn new(metadata: MetadataRef) -> Self {
+        Self {
+            id: RuleID::TryApplyVectorIndex,
+            metadata,
+            matchers: vec![Matcher::MatchOp {
+                op_type: RelOp::Sort,
+                children: vec![Matcher::MatchOp {
+                    op_type: RelOp::EvalScalar,
+                    children: vec![Matcher::MatchOp {
+                        op_type: RelOp::Scan,
+                        children: vec![],
+                    }],
+                }],
+            }],
+        }
+    }
+
+    fn try_build_vector_index(
+        &self,
+        sort: &Sort,
+        eval_scalar: &EvalScalar,
+        scan: &Scan,
+    ) -> Option<VectorIndexInfo> {
+        let limit = sort.limit?;
+        let item = sort.items.first()?;
+        // Rows with NULL distance are not indexed, they must be sorted last.
+        if !item.asc || item.nulls_first {
+            return None;
+        }
+        let scalar = &eval_scalar
+            .items
+            .iter()
+            .find(|eval| eval.index == item.index)?
+            .scalar;
+        let ScalarExpr::FunctionCall(func) = scalar else {
+            return None;
+        };
+        let distance = VectorDistance::from_function_name(&func.func_name)?;
+        let (column, query) = match func.arguments.as_slice() {
+            [column, ScalarExpr::ConstantExpr(query)]
+            | [ScalarExpr::ConstantExpr(query), column] => (column, &query.value),
+            _ => return None,
+        };
+        let mut column = column;
+        while let ScalarExpr::CastExpr(cast) = column {
+            column = &cast.argument;
+        }
+        let ScalarExpr::BoundColumnRef(column) = column else {
+            return None;
+        };
+        let query = match query {
+            Scalar::Array(Column::Number(NumberColumn::Float32(values))) => values.to_vec(),
+            _ => return None,
+        };
+
+        let metadata = self.metadata.read();
+        let ColumnEntry::BaseTableColumn(BaseTableColumn {
+            table_index,
+            column_name,
+            path_indices: None,
+            ..
+        }) = metadata.column(column.column.index)
+        else {
+            return None;
+        };
+        if *table_index != scan.table_index {
+            return None;
+        }
+        let table_entry = metadata.table(scan.table_index);
+        let table_name = format!(
+            "{}.{}.{}",
+            table_entry.catalog(),
+            table_entry.database(),
+            table_entry.table().name()
+        );
+        let (index_id, index_name, _) =
+            metadata
+                .get_vector_indexes(&table_name)?
+                .iter()
+                .find(|(_, _, index_meta)| {
+                    index_meta.options.get(VECTOR_INDEX_COLUMN) == Some(column_name)
+                        && VectorIndexParams::try_from_options(&index_meta.options)
+                            .is_ok_and(|params| params.distance == distance)
+                })?;
+
+        Some(VectorIndexInfo {
+            index_id: *index_id,
+            index_name: index_name.clone(),
+            column_name: column_name.clone(),
+            distance,
+            query,
+            limit,
+        })
+    }
+}
+
+impl Rule for RuleTryApplyVectorIndex {
+    fn id(&self) -> RuleID {
+        self.id
+    }
+
+    fn apply(&self, s_expr: &SExpr, state: &mut TransformResult) -> Result<()> {
+        let sort: Sort = s_expr.plan().clone().try_into()?;
+        let eval_scalar_expr = s_expr.child(0)?;
+        let eval_scalar: EvalScalar = eval_scalar_expr.plan().clone().try_into()?;
+        let mut scan: Scan = eval_scalar_expr.child(0)?.plan().clone().try_into()?;
+        // The top-k rows of the whole table are only valid if no rows are filtered.
+        if scan.vector_index.is_some()
+            || scan.agg_index.is_some()
+            || scan.change_type.is_some()
+            || scan.push_down_predicates.is_some()
+            || scan.prewhere.is_some()
+        {
+            return Ok(());
+        }
+        let Some(vector_index) = self.try_build_vector_index(&sort, &eval_scalar, &scan) else {
+            return Ok(());
+        };
+        scan.vector_index = Some(vector_index);
+
+        let scan = SExpr::create_leaf(Arc::new(RelOperator::Scan(scan)));
+        let eval_scalar = eval_scalar_expr.replace_children(vec![Arc::new(scan)]);
+        let mut result = s_expr.replace_children(vec![Arc::new(eval_scalar)]);
+        result.set_applied_rule(&self.id);
+        state.add_result(result);
+        Ok(())
+    }
+
+    fn matchers(&self) -> &[Matcher] {
+        &self.matchers
+    }
+}
@@ -50,8 +50,11 @@ pub static DEFAULT_REWRITE_RULES: LazyLock<Vec<RuleID>> = LazyLock::new(|| {
         RuleID::FoldCountAggregate,
         RuleID::TryApplyAggIndex,
         RuleID::SplitAggregate,
+        RuleID::PruneFilterByConstraint, /* PruneFilterByConstraint should be before PushDownFilterScan */
         RuleID::PushDownFilterScan,
         RuleID::PushDownPrewhere, /* PushDownPrwhere should be after all rules except PushDownFilterScan */
+        RuleID::TryApplyVectorIndex, // TryApplyVectorIndex should be after PushDownFilterScan
+        RuleID::TryApplyInvertedIndex, // TryApplyInvertedIndex should be after PushDownFilterScan
         RuleID::PushDownSortScan, // PushDownSortScan should be after PushDownPrewhere
     ]
 });
@@ -97,12 +100,15 @@ pub enum RuleID {
     EliminateEvalScalar,
     EliminateFilter,
     EliminateSort,
+    PruneFilterByConstraint,
     MergeEvalScalar,
     MergeFilter,
     SplitAggregate,
     FoldCountAggregate,
     PushDownPrewhere,
     TryApplyAggIndex,
+    TryApplyVectorIndex,
+    TryApplyInvertedIndex,
     CommuteJoin,
 
     // Exploration rules
@@ -133,6 +139,7 @@ impl Display for RuleID {
             RuleID::EliminateEvalScalar => write!(f, "EliminateEvalScalar"),
             RuleID::EliminateFilter => write!(f, "EliminateFilter"),
             RuleID::EliminateSort => write!(f, "EliminateSort"),
+            RuleID::PruneFilterByConstraint => write!(f, "PruneFilterByConstraint"),
             RuleID::MergeEvalScalar => write!(f, "MergeEvalScalar"),
             RuleID::MergeFilter => write!(f, "MergeFilter"),
             RuleID::NormalizeScalarFilter => write!(f, "NormalizeScalarFilter"),
@@ -146,6 +153,8 @@ impl Display for RuleID {
             RuleID::LeftExchangeJoin => write!(f, "LeftExchangeJoin"),
             RuleID::EagerAggregation => write!(f, "EagerAggregation"),
             RuleID::TryApplyAggIndex => write!(f, "TryApplyAggIndex"),
+            RuleID::TryApplyVectorIndex => write!(f, "TryApplyVectorIndex"),
+            RuleID::TryApplyInvertedIndex => write!(f, "TryApplyInvertedIndex"),
             RuleID::SemiToInnerJoin => write!(f, "SemiToInnerJoin"),
         }
     }
@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::collections::BTreeMap;
+
 use databend_common_ast::ast::TableIndexType;
 use databend_common_meta_app::schema::IndexMeta;
 use databend_common_meta_app::schema::TableInfo;
@@ -49,3 +51,47 @@ pub struct RefreshIndexPlan {
     pub segment_locs: Option<Vec<Location>>,
     pub user_defined_block_name: bool,
 }
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateVectorIndexPlan {
+    pub if_not_exists: bool,
+    pub index_name: String,
+    pub table_id: MetaId,
+    /// The indexed column in the form of `database.table(column)`.
+    pub query: String,
+    /// Normalized index options, including the indexed column.
+    pub options: BTreeMap<String, String>,
+    pub sync_creation: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct RefreshVectorIndexPlan {
+    pub index_id: u64,
+    pub index_name: String,
+    pub index_meta: IndexMeta,
+    pub limit: Option<u64>,
+    pub table_info: TableInfo,
+    pub segment_locs: Option<Vec<Location>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreateInvertedIndexPlan {
+    pub if_not_exists: bool,
+    pub index_name: String,
+    pub table_id: MetaId,
+    /// The indexed column in the form of `database.table(column)`.
+    pub query: String,
+    /// Normalized index options, including the indexed column.
+    pub options: BTreeMap<String, String>,
+    pub sync_creation: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct RefreshInvertedIndexPlan {
+    pub index_id: u64,
+    pub index_name: String,
+    pub index_meta: IndexMeta,
+    pub limit: Option<u64>,
+    pub table_info: TableInfo,
+    pub segment_locs: Option<Vec<Location>>,
+}
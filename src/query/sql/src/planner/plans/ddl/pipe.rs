@@ -0,0 +1,91 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_ast::ast::AlterPipeOptions;
+use databend_common_expression::types::DataType;
+use databend_common_expression::DataField;
+use databend_common_expression::DataSchema;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::DataSchemaRefExt;
+
+pub fn pipe_schema() -> DataSchemaRef {
+    Arc::new(DataSchema::new(vec![
+        DataField::new("created_on", DataType::Timestamp),
+        DataField::new("name", DataType::String),
+        DataField::new("definition", DataType::String),
+        DataField::new("auto_ingest", DataType::Boolean),
+        DataField::new("execution_state", DataType::String),
+        DataField::new("comment", DataType::String),
+        DataField::new("last_load_on", DataType::Timestamp.wrap_nullable()),
+        DataField::new("last_error", DataType::String.wrap_nullable()),
+    ]))
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CreatePipePlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub pipe_name: String,
+    pub auto_ingest: bool,
+    pub comment: String,
+    /// The `COPY INTO <table>` statement of the pipe.
+    pub copy_sql: String,
+}
+
+impl CreatePipePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AlterPipePlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub pipe_name: String,
+    pub options: AlterPipeOptions,
+}
+
+impl AlterPipePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropPipePlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub pipe_name: String,
+}
+
+impl DropPipePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        DataSchemaRefExt::create(vec![])
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DescribePipePlan {
+    pub tenant: String,
+    pub pipe_name: String,
+}
+
+impl DescribePipePlan {
+    pub fn schema(&self) -> DataSchemaRef {
+        pipe_schema()
+    }
+}
@@ -15,6 +15,7 @@
 use std::collections::BTreeMap;
 use std::sync::Arc;
 
+use databend_common_ast::ast::ClusterType;
 use databend_common_ast::ast::Engine;
 use databend_common_catalog::table::NavigationPoint;
 use databend_common_expression::types::DataType;
@@ -27,6 +28,7 @@ use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
 use databend_common_expression::TableSchemaRef;
 use databend_common_meta_app::schema::CreateOption;
+use databend_common_meta_app::schema::TableConstraint;
 use databend_common_meta_app::schema::TableNameIdent;
 use databend_common_meta_app::schema::UndropTableReq;
 use databend_common_meta_app::storage::StorageParams;
@@ -52,6 +54,7 @@ pub struct CreateTablePlan {
     pub options: TableOptions,
     pub field_comments: Vec<String>,
     pub cluster_key: Option<String>,
+    pub constraints: BTreeMap<String, TableConstraint>,
     pub as_select: Option<Box<Plan>>,
 }
 
@@ -393,6 +396,7 @@ pub struct AlterTableClusterKeyPlan {
     pub catalog: String,
     pub database: String,
     pub table: String,
+    pub cluster_type: ClusterType,
     pub cluster_keys: Vec<String>,
 }
 
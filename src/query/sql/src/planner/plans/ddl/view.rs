@@ -12,6 +12,8 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::plans::CreateTablePlan;
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct CreateViewPlan {
     pub if_not_exists: bool,
@@ -41,3 +43,44 @@ pub struct DropViewPlan {
     pub database: String,
     pub view_name: String,
 }
+
+#[derive(Clone, Debug)]
+pub struct CreateMaterializedViewPlan {
+    pub if_not_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+    pub subquery: String,
+    /// The plan of the hidden fuse table storing the data of the view.
+    pub create_table_plan: CreateTablePlan,
+    /// (catalog, database, table) of the tables the view is defined on.
+    pub base_tables: Vec<(String, String, String)>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DropMaterializedViewPlan {
+    pub if_exists: bool,
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RefreshMaterializedViewPlan {
+    pub tenant: String,
+    pub catalog: String,
+    pub database: String,
+    pub view_name: String,
+}
+
+/// The name of the internal stream that tracks the changes of a base table of a materialized view.
+pub fn materialized_view_stream_name(view_id: u64, table_id: u64) -> String {
+    format!("{}{table_id}", materialized_view_stream_prefix(view_id))
+}
+
+/// The common prefix of the names of the internal streams of a materialized view.
+pub fn materialized_view_stream_prefix(view_id: u64) -> String {
+    format!("_mv_stream_{view_id}_")
+}
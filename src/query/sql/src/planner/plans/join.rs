@@ -61,6 +61,11 @@ pub enum JoinType {
     /// Single Join is a special kind of join that is used to process correlated scalar subquery.
     LeftSingle,
     RightSingle,
+    /// Asof Join matches each row of the left side with at most one row of the right side,
+    /// which is the closest one satisfying the match condition, e.g. `t.ts >= q.ts`.
+    /// The match condition is the only non-equi condition of the join.
+    Asof,
+    LeftAsof,
 }
 
 impl JoinType {
@@ -138,6 +143,12 @@ impl Display for JoinType {
             JoinType::RightSingle => {
                 write!(f, "RIGHT SINGLE")
             }
+            JoinType::Asof => {
+                write!(f, "ASOF")
+            }
+            JoinType::LeftAsof => {
+                write!(f, "LEFT ASOF")
+            }
         }
     }
 }
@@ -486,6 +497,8 @@ impl Operator for Join {
             JoinType::RightSemi => f64::min(right_cardinality, inner_join_cardinality),
             JoinType::LeftSingle | JoinType::RightMark | JoinType::LeftAnti => left_cardinality,
             JoinType::RightSingle | JoinType::LeftMark | JoinType::RightAnti => right_cardinality,
+            JoinType::Asof => f64::min(left_cardinality, inner_join_cardinality),
+            JoinType::LeftAsof => left_cardinality,
         };
         // Derive column statistics
         let column_stats = if cardinality == 0.0 {
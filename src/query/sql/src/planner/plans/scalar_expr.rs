@@ -594,12 +594,22 @@ pub struct UDFServerCall {
     // name in handler
     pub func_name: String,
     pub display_name: String,
-    pub server_addr: String,
+    pub udf_type: UDFType,
     pub arg_types: Vec<DataType>,
     pub return_type: Box<DataType>,
     pub arguments: Vec<ScalarExpr>,
 }
 
+/// Where a [`UDFServerCall`] is executed.
+#[derive(Clone, Educe, serde::Serialize, serde::Deserialize)]
+#[educe(Debug, PartialEq, Eq, Hash)]
+pub enum UDFType {
+    /// Sent to the UDF server at this address.
+    Server(String),
+    /// Run in-process with this WebAssembly module.
+    Wasm(#[educe(Debug(ignore))] Vec<u8>),
+}
+
 #[derive(Clone, Debug, Educe)]
 #[educe(PartialEq, Eq, Hash)]
 pub struct UDFLambdaCall {
@@ -40,6 +40,7 @@ pub use type_check::resolve_type_name_by_str;
 pub use type_check::validate_function_arg;
 pub use type_check::TypeChecker;
 pub(crate) use udf_rewriter::UdfRewriter;
+pub use view_rewriter::MaterializedViewStreamRewriter;
 pub use view_rewriter::ViewRewriter;
 pub(crate) use virtual_column_rewriter::VirtualColumnRewriter;
 pub use window_check::WindowChecker;
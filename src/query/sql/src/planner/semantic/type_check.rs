@@ -73,6 +73,7 @@ use databend_common_functions::GENERAL_WINDOW_FUNCTIONS;
 use databend_common_meta_app::principal::LambdaUDF;
 use databend_common_meta_app::principal::UDFDefinition;
 use databend_common_meta_app::principal::UDFServer;
+use databend_common_meta_app::principal::WasmUDF;
 use databend_common_users::UserApiProvider;
 use indexmap::IndexMap;
 use itertools::Itertools;
@@ -111,6 +112,7 @@ use crate::plans::SubqueryExpr;
 use crate::plans::SubqueryType;
 use crate::plans::UDFLambdaCall;
 use crate::plans::UDFServerCall;
+use crate::plans::UDFType;
 use crate::plans::WindowFunc;
 use crate::plans::WindowFuncFrame;
 use crate::plans::WindowFuncFrameBound;
@@ -215,6 +217,19 @@ impl<'a> TypeChecker<'a> {
         }
 
         let box (scalar, data_type): Box<(ScalarExpr, DataType)> = match expr {
+            // `$n` is a parameter in the extended query protocol of PostgreSQL.
+            Expr::ColumnRef {
+                database: None,
+                table: None,
+                column: ColumnID::Position(pos),
+                ..
+            } if pos
+                .span
+                .and_then(|span| self.ctx.get_placeholder_value(span.start()))
+                .is_some() =>
+            {
+                self.resolve_placeholder(pos.span)?
+            }
             Expr::ColumnRef {
                 span,
                 database,
@@ -703,6 +718,8 @@ impl<'a> TypeChecker<'a> {
 
             Expr::Literal { span, lit } => self.resolve_literal(*span, lit)?,
 
+            Expr::Placeholder { span } => self.resolve_placeholder(*span)?,
+
             Expr::FunctionCall {
                 span,
                 distinct,
@@ -1201,6 +1218,20 @@ impl<'a> TypeChecker<'a> {
         Ok(Box::new((scalar_expr, data_type)))
     }
 
+    fn resolve_placeholder(&self, span: Span) -> Result<Box<(ScalarExpr, DataType)>> {
+        let value = span
+            .and_then(|span| self.ctx.get_placeholder_value(span.start()))
+            .ok_or_else(|| {
+                ErrorCode::SemanticError(
+                    "Placeholder `?` is only allowed in prepared statements".to_string(),
+                )
+                .set_span(span)
+            })?;
+        let data_type = value.as_ref().infer_data_type();
+        let scalar_expr = ScalarExpr::ConstantExpr(ConstantExpr { span, value });
+        Ok(Box::new((scalar_expr, data_type)))
+    }
+
     fn resolve_window_rows_frame(&self, frame: WindowFrame) -> Result<WindowFuncFrame> {
         let units = match frame.units {
             WindowFrameUnits::Rows => WindowFuncFrameUnits::Rows,
@@ -2941,6 +2972,10 @@ impl<'a> TypeChecker<'a> {
                 self.resolve_udf_server(span, name, arguments, udf_def)
                     .await?,
             )),
+            UDFDefinition::WasmUDF(udf_def) => Ok(Some(
+                self.resolve_wasm_udf(span, name, arguments, udf_def)
+                    .await?,
+            )),
         }
     }
 
@@ -2999,7 +3034,55 @@ impl<'a> TypeChecker<'a> {
                 name,
                 func_name: udf_definition.handler,
                 display_name,
-                server_addr: udf_definition.address,
+                udf_type: UDFType::Server(udf_definition.address),
+                arg_types: udf_definition.arg_types,
+                return_type: Box::new(udf_definition.return_type.clone()),
+                arguments: args,
+            }
+            .into(),
+            udf_definition.return_type.clone(),
+        )))
+    }
+
+    #[async_recursion::async_recursion]
+    #[async_backtrace::framed]
+    async fn resolve_wasm_udf(
+        &mut self,
+        span: Span,
+        name: String,
+        arguments: &[Expr],
+        udf_definition: WasmUDF,
+    ) -> Result<Box<(ScalarExpr, DataType)>> {
+        if arguments.len() != udf_definition.arg_types.len() {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Require {} parameters, but got: {}",
+                udf_definition.arg_types.len(),
+                arguments.len()
+            ))
+            .set_span(span));
+        }
+
+        let mut args = Vec::with_capacity(arguments.len());
+        for (argument, dest_type) in arguments.iter().zip(udf_definition.arg_types.iter()) {
+            let box (arg, ty) = self.resolve(argument).await?;
+            if ty != *dest_type {
+                args.push(wrap_cast(&arg, dest_type));
+            } else {
+                args.push(arg);
+            }
+        }
+
+        let arg_names = arguments.iter().map(|arg| format!("{}", arg)).join(", ");
+        let display_name = format!("{}({})", udf_definition.handler, arg_names);
+
+        self.ctx.set_cacheable(false);
+        Ok(Box::new((
+            UDFServerCall {
+                span,
+                name,
+                func_name: udf_definition.handler,
+                display_name,
+                udf_type: UDFType::Wasm(udf_definition.code),
                 arg_types: udf_definition.arg_types,
                 return_type: Box::new(udf_definition.return_type.clone()),
                 arguments: args,
@@ -3864,6 +3947,7 @@ pub fn resolve_type_name(type_name: &TypeName, not_null: bool) -> Result<TableDa
             data_type.wrap_nullable()
         }
         TypeName::Variant => TableDataType::Variant,
+        TypeName::Geometry => TableDataType::Geometry,
         TypeName::NotNull(inner_type) => {
             let data_type = resolve_type_name(inner_type, not_null)?;
             data_type.remove_nullable()
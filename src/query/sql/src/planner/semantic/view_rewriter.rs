@@ -13,10 +13,14 @@
 // limitations under the License.
 
 use databend_common_ast::ast::Identifier;
+use databend_common_ast::ast::TableAlias;
 use databend_common_ast::ast::TableReference;
 use databend_common_ast::walk_table_reference_mut;
 use databend_common_ast::VisitorMut;
 
+use crate::planner::semantic::normalize_identifier;
+use crate::planner::semantic::NameResolutionContext;
+
 #[derive(Debug, Clone, Default)]
 pub struct ViewRewriter {
     pub current_database: String,
@@ -32,6 +36,7 @@ impl VisitorMut for ViewRewriter {
                 table,
                 alias,
                 travel_point,
+                changes,
                 pivot,
                 unpivot,
             } => {
@@ -49,6 +54,7 @@ impl VisitorMut for ViewRewriter {
                         table: table.clone(),
                         alias: alias.clone(),
                         travel_point: travel_point.clone(),
+                        changes: changes.clone(),
                         pivot: pivot.clone(),
                         unpivot: unpivot.clone(),
                     }
@@ -58,3 +64,45 @@ impl VisitorMut for ViewRewriter {
         }
     }
 }
+
+/// Replaces the table of a materialized view with the internal stream on it,
+/// so that only the rows appended since the last refresh are read.
+pub struct MaterializedViewStreamRewriter {
+    pub name_resolution_ctx: NameResolutionContext,
+    pub database: String,
+    pub table: String,
+    pub stream_database: String,
+    pub stream_name: String,
+    /// The number of the replaced table references.
+    pub replaced: usize,
+}
+
+impl VisitorMut for MaterializedViewStreamRewriter {
+    fn visit_table_reference(&mut self, table_ref: &mut TableReference) {
+        match table_ref {
+            TableReference::Table {
+                database: Some(database),
+                table,
+                alias,
+                travel_point: None,
+                ..
+            } if normalize_identifier(database, &self.name_resolution_ctx).name
+                == self.database
+                && normalize_identifier(table, &self.name_resolution_ctx).name == self.table =>
+            {
+                // Keep the columns qualified by the table name valid.
+                if alias.is_none() {
+                    *alias = Some(TableAlias {
+                        name: table.clone(),
+                        columns: vec![],
+                    });
+                }
+                *database =
+                    Identifier::from_name_with_quoted(self.stream_database.clone(), Some('`'));
+                *table = Identifier::from_name_with_quoted(self.stream_name.clone(), Some('`'));
+                self.replaced += 1;
+            }
+            _ => walk_table_reference_mut(self, table_ref),
+        }
+    }
+}
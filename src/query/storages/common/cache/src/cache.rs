@@ -14,13 +14,18 @@
 
 use std::hash::BuildHasher;
 use std::hash::Hash;
+use std::sync::atomic::AtomicU64;
+use std::sync::atomic::Ordering;
 use std::sync::Arc;
 
 use databend_common_cache::Count;
 use databend_common_cache::CountableMeter;
 use databend_common_cache::DefaultHashBuilder;
+use databend_common_cache::EvictionPolicy;
 use databend_common_metrics::cache::*;
 
+use crate::InMemoryItemCacheHolder;
+
 // The cache accessor, crate users usually working on this interface while manipulating caches
 pub trait CacheAccessor<K, V, S = DefaultHashBuilder, M = Count>
 where
@@ -47,6 +52,7 @@ where Self: Sized
         NamedCache {
             name: name.into(),
             cache: self,
+            statistics: Arc::new(CacheStatistics::default()),
         }
     }
 }
@@ -58,6 +64,7 @@ impl<T> Named for T where T: Sized + Clone {}
 pub struct NamedCache<C> {
     name: String,
     cache: C,
+    statistics: Arc<CacheStatistics>,
 }
 
 impl<C> NamedCache<C> {
@@ -65,6 +72,50 @@ impl<C> NamedCache<C> {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    #[inline]
+    pub fn statistics(&self) -> &CacheStatistics {
+        &self.statistics
+    }
+}
+
+impl<V, S, M> NamedCache<InMemoryItemCacheHolder<V, S, M>>
+where
+    S: BuildHasher,
+    M: CountableMeter<String, Arc<V>>,
+{
+    pub fn policy(&self) -> EvictionPolicy {
+        self.cache.read().policy()
+    }
+}
+
+/// Hit statistics of a cache since the node started, shared by all the clones of the cache.
+#[derive(Default, Debug)]
+pub struct CacheStatistics {
+    hits: AtomicU64,
+    misses: AtomicU64,
+}
+
+impl CacheStatistics {
+    pub fn record_hit(&self) {
+        self.hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn record_miss(&self) {
+        self.misses.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub fn hits(&self) -> u64 {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    pub fn misses(&self) -> u64 {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    pub fn accesses(&self) -> u64 {
+        self.hits() + self.misses()
+    }
 }
 
 impl<K, V, S, M, C> CacheAccessor<K, V, S, M> for NamedCache<C>
@@ -79,10 +130,12 @@ where
         match self.cache.get(k) {
             None => {
                 metrics_inc_cache_miss_count(1, &self.name);
+                self.statistics.record_miss();
                 None
             }
             v @ Some(_) => {
                 metrics_inc_cache_hit_count(1, &self.name);
+                self.statistics.record_hit();
                 v
             }
         }
@@ -19,6 +19,7 @@ mod providers;
 mod read;
 
 pub use cache::CacheAccessor;
+pub use cache::CacheStatistics;
 pub use cache::Named;
 pub use cache::NamedCache;
 pub use providers::DiskCacheError;
@@ -21,16 +21,19 @@ use std::io::Write;
 use std::path::Path;
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::SystemTime;
 
 use bytes::Bytes;
 use databend_common_cache::Cache;
 use databend_common_cache::Count;
 use databend_common_cache::DefaultHashBuilder;
+use databend_common_cache::EvictionPolicy;
 use databend_common_cache::FileSize;
-use databend_common_cache::LruCache;
+use databend_common_cache::PolicyCache;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use log::error;
+use log::info;
 use log::warn;
 use parking_lot::RwLock;
 use siphasher::sip128;
@@ -45,6 +48,13 @@ pub struct DiskCache<C> {
 
 pub struct DiskCacheKey(String);
 
+/// Length of the hex encoded cache key.
+const DISK_CACHE_KEY_LEN: usize = 32;
+/// Length of the sub-directory name, which is the prefix of the cache key.
+const DISK_CACHE_KEY_PREFIX_LEN: usize = 3;
+/// Extension of the files that are being written.
+const DISK_CACHE_TMP_FILE_EXT: &str = "tmp";
+
 impl<S> From<S> for DiskCacheKey
 where S: AsRef<str>
 {
@@ -59,9 +69,19 @@ where S: AsRef<str>
     }
 }
 
+impl DiskCacheKey {
+    /// Returns `true` if `name` looks like a hex encoded SipHash 2-4 128 bit.
+    fn is_valid(name: &str) -> bool {
+        name.len() == DISK_CACHE_KEY_LEN
+            && name
+                .bytes()
+                .all(|b| b.is_ascii_digit() || (b'a'..=b'f').contains(&b))
+    }
+}
+
 impl From<&DiskCacheKey> for PathBuf {
     fn from(cache_key: &DiskCacheKey) -> Self {
-        let prefix = &cache_key.0[0..3];
+        let prefix = &cache_key.0[0..DISK_CACHE_KEY_PREFIX_LEN];
         let mut path_buf = PathBuf::from(prefix);
         path_buf.push(Path::new(&cache_key.0));
         path_buf
@@ -81,15 +101,48 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
     /// The cache is not observant of changes to files under `path` from external sources, it
     /// expects to have sole maintenance of the contents.
     pub fn new<T>(path: T, size: u64) -> self::result::Result<Self>
+    where PathBuf: From<T> {
+        let cache = C::with_meter_and_hasher(size, FileSize, DefaultHashBuilder::default());
+        Self::with_cache(path, cache)
+    }
+
+    /// Create an empty `DiskCache`, all the existing files in `path` will be removed.
+    pub fn new_empty<T>(path: T, size: u64) -> self::result::Result<Self>
+    where PathBuf: From<T> {
+        let root = PathBuf::from(path);
+        remove_cache_dir(&root);
+        Self::new(root, size)
+    }
+
+    fn with_cache<T>(path: T, cache: C) -> self::result::Result<Self>
     where PathBuf: From<T> {
         DiskCache {
-            cache: C::with_meter_and_hasher(size, FileSize, DefaultHashBuilder::default()),
+            cache,
             root: PathBuf::from(path),
         }
         .init()
     }
 }
 
+impl DiskCache<PolicyCache<String, u64, DefaultHashBuilder, FileSize>> {
+    /// Create a `DiskCache` like [`DiskCache::new`], which evicts the files by `policy`.
+    pub fn new_with_policy<T>(
+        path: T,
+        size: u64,
+        policy: EvictionPolicy,
+    ) -> self::result::Result<Self>
+    where
+        PathBuf: From<T>,
+    {
+        let cache = PolicyCache::with_policy(policy, size, FileSize, DefaultHashBuilder::default());
+        Self::with_cache(path, cache)
+    }
+
+    pub fn policy(&self) -> EvictionPolicy {
+        self.cache.policy()
+    }
+}
+
 impl<C> DiskCache<C>
 where C: Cache<String, u64, DefaultHashBuilder, FileSize>
 {
@@ -122,16 +175,97 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
         self.root.join(rel_path)
     }
 
-    fn init(self) -> self::result::Result<Self> {
-        // remove dir when init, ignore remove error
-        if let Err(e) = fs::remove_dir_all(&self.root) {
-            warn!("remove disk cache dir {:?} error {}", self.root, e);
-        }
+    fn init(mut self) -> self::result::Result<Self> {
         fs::create_dir_all(&self.root)?;
 
+        // rebuild the index from the files left by the previous process
+        let mut cached_files = self.scan_cached_files()?;
+        cached_files.sort_by_key(|(_, _, modified)| *modified);
+
+        let (mut num_reloaded, mut num_discarded) = (0, 0);
+        for (cache_key, size, _) in cached_files {
+            if !self.can_store(size) {
+                self.remove_cache_file(&cache_key);
+                num_discarded += 1;
+                continue;
+            }
+            // the files are sorted by last-modified time, older ones get evicted first
+            self.evict_until_fits(size);
+            self.cache.put(cache_key.0, size);
+            num_reloaded += 1;
+        }
+
+        info!(
+            "disk cache {:?} initialized, {} items ({} bytes) reloaded, {} items discarded",
+            self.root,
+            num_reloaded,
+            self.cache.size(),
+            num_discarded
+        );
         Ok(self)
     }
 
+    /// Collect the valid cache files under the root, along with their size and last-modified time.
+    ///
+    /// Files that do not follow the layout of cache keys, half-written temporary files, and empty
+    /// files are removed. The content of cached files is validated by checksum when they are read.
+    fn scan_cached_files(&self) -> self::result::Result<Vec<(DiskCacheKey, u64, SystemTime)>> {
+        let mut cached_files = vec![];
+        for prefix_entry in fs::read_dir(&self.root)? {
+            let prefix_entry = prefix_entry?;
+            let prefix_path = prefix_entry.path();
+            if !prefix_entry.file_type()?.is_dir() {
+                remove_invalid_cache_file(&prefix_path);
+                continue;
+            }
+
+            let prefix = prefix_entry.file_name();
+            for entry in fs::read_dir(&prefix_path)? {
+                let entry = entry?;
+                let path = entry.path();
+                let metadata = entry.metadata()?;
+                let file_name = entry.file_name();
+                match (prefix.to_str(), file_name.to_str()) {
+                    (Some(prefix), Some(name))
+                        if metadata.is_file()
+                            && metadata.len() > 0
+                            && DiskCacheKey::is_valid(name)
+                            && name.starts_with(prefix)
+                            && prefix.len() == DISK_CACHE_KEY_PREFIX_LEN =>
+                    {
+                        let modified = metadata.modified()?;
+                        cached_files.push((
+                            DiskCacheKey(name.to_owned()),
+                            metadata.len(),
+                            modified,
+                        ));
+                    }
+                    _ => remove_invalid_cache_file(&path),
+                }
+            }
+        }
+        Ok(cached_files)
+    }
+
+    fn evict_until_fits(&mut self, size: u64) {
+        while self.cache.size() + size > self.cache.capacity() {
+            match self.cache.pop_by_policy() {
+                Some((rel_path, _)) => self.remove_cache_file(&DiskCacheKey(rel_path)),
+                None => break,
+            }
+        }
+    }
+
+    fn remove_cache_file(&self, cache_key: &DiskCacheKey) {
+        let cached_item_path = self.abs_path_of_cache_key(cache_key);
+        fs::remove_file(&cached_item_path).unwrap_or_else(|e| {
+            error!(
+                "Error removing file from cache: `{:?}`: {}",
+                cached_item_path, e
+            )
+        });
+    }
+
     /// Returns `true` if the disk cache can store a file of `size` bytes.
     pub fn can_store(&self, size: u64) -> bool {
         size <= self.cache.capacity()
@@ -154,17 +288,7 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
         }
 
         // check eviction
-        while self.cache.size() + bytes_len > self.cache.capacity() {
-            if let Some((rel_path, _)) = self.cache.pop_by_policy() {
-                let cached_item_path = self.abs_path_of_cache_key(&DiskCacheKey(rel_path));
-                fs::remove_file(&cached_item_path).unwrap_or_else(|e| {
-                    error!(
-                        "Error removing file from cache: `{:?}`: {}",
-                        cached_item_path, e
-                    )
-                });
-            }
-        }
+        self.evict_until_fits(bytes_len);
         debug_assert!(self.cache.size() <= self.cache.capacity());
 
         let cache_key = self.cache_key(key.as_ref());
@@ -172,12 +296,19 @@ where C: Cache<String, u64, DefaultHashBuilder, FileSize>
         if let Some(parent_path) = path.parent() {
             fs::create_dir_all(parent_path)?;
         }
-        let mut f = File::create(&path)?;
-        let mut bufs = Vec::with_capacity(bytes.len());
-        for slick in bytes {
-            bufs.push(IoSlice::new(slick));
+
+        // write to a temporary file first and then rename it, so that a crash
+        // in the middle of writing will not leave a torn file behind the key
+        let tmp_path = path.with_extension(DISK_CACHE_TMP_FILE_EXT);
+        {
+            let mut f = File::create(&tmp_path)?;
+            let mut bufs = Vec::with_capacity(bytes.len());
+            for slick in bytes {
+                bufs.push(IoSlice::new(slick));
+            }
+            f.write_all_vectored(&mut bufs)?;
         }
-        f.write_all_vectored(&mut bufs)?;
+        fs::rename(&tmp_path, &path)?;
         self.cache.put(cache_key.0, bytes_len);
         Ok(())
     }
@@ -369,7 +500,25 @@ fn validate_checksum(bytes: &[u8]) -> Result<()> {
     }
 }
 
-pub type LruDiskCache = DiskCache<LruCache<String, u64, DefaultHashBuilder, FileSize>>;
+fn remove_cache_dir(root: &Path) {
+    // remove dir when init, ignore remove error
+    if let Err(e) = fs::remove_dir_all(root) {
+        warn!("remove disk cache dir {:?} error {}", root, e);
+    }
+}
+
+fn remove_invalid_cache_file(path: &Path) {
+    let r = if path.is_dir() {
+        fs::remove_dir_all(path)
+    } else {
+        fs::remove_file(path)
+    };
+    if let Err(e) = r {
+        warn!("failed to remove invalid disk cache file {:?}. {}", path, e);
+    }
+}
+
+pub type LruDiskCache = DiskCache<PolicyCache<String, u64, DefaultHashBuilder, FileSize>>;
 pub type LruDiskCacheHolder = Arc<RwLock<LruDiskCache>>;
 
 pub struct LruDiskCacheBuilder;
@@ -377,8 +526,13 @@ impl LruDiskCacheBuilder {
     pub fn new_disk_cache(
         path: &PathBuf,
         disk_cache_bytes_size: u64,
+        reset_on_start: bool,
+        policy: EvictionPolicy,
     ) -> Result<LruDiskCacheHolder> {
-        let external_cache = DiskCache::new(path, disk_cache_bytes_size)
+        if reset_on_start {
+            remove_cache_dir(path);
+        }
+        let external_cache = DiskCache::new_with_policy(path, disk_cache_bytes_size, policy)
             .map_err(|e| ErrorCode::StorageOther(format!("create disk cache failed, {e}")))?;
         Ok(Arc::new(RwLock::new(external_cache)))
     }
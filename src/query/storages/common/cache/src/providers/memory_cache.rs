@@ -21,10 +21,12 @@ use databend_common_cache::Cache;
 use databend_common_cache::Count;
 use databend_common_cache::CountableMeter;
 use databend_common_cache::DefaultHashBuilder;
+use databend_common_cache::EvictionPolicy;
 use databend_common_cache::LruCache;
+use databend_common_cache::PolicyCache;
 use parking_lot::RwLock;
 
-pub type InMemoryCache<V, S, M> = LruCache<String, Arc<V>, S, M>;
+pub type InMemoryCache<V, S, M> = PolicyCache<String, Arc<V>, S, M>;
 pub type BytesCache = LruCache<String, Arc<Bytes>, DefaultHashBuilder, BytesMeter>;
 
 pub type InMemoryItemCacheHolder<T, S = DefaultHashBuilder, M = Count> =
@@ -37,18 +39,19 @@ impl InMemoryCacheBuilder {
     pub fn new_in_memory_cache<V, M>(
         capacity: u64,
         meter: M,
+        policy: EvictionPolicy,
     ) -> InMemoryItemCacheHolder<V, DefaultHashBuilder, M>
     where
         M: CountableMeter<String, Arc<V>>,
     {
-        let cache = LruCache::with_meter_and_hasher(capacity, meter, DefaultHashBuilder::default());
+        let cache =
+            PolicyCache::with_policy(policy, capacity, meter, DefaultHashBuilder::default());
         Arc::new(RwLock::new(cache))
     }
 
     // new cache that caches `V` and meter by counting
-    pub fn new_item_cache<V>(capacity: u64) -> InMemoryItemCacheHolder<V> {
-        let cache = LruCache::new(capacity);
-        Arc::new(RwLock::new(cache))
+    pub fn new_item_cache<V>(capacity: u64, policy: EvictionPolicy) -> InMemoryItemCacheHolder<V> {
+        Self::new_in_memory_cache(capacity, Count, policy)
     }
 
     // new cache that cache `Vec<u8>`, and metered by byte size
@@ -20,6 +20,7 @@ use bytes::Bytes;
 use crossbeam_channel::TrySendError;
 use databend_common_cache::Count;
 use databend_common_cache::DefaultHashBuilder;
+use databend_common_cache::EvictionPolicy;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_metrics::cache::*;
@@ -28,6 +29,7 @@ use log::info;
 
 use crate::providers::LruDiskCacheHolder;
 use crate::CacheAccessor;
+use crate::CacheStatistics;
 use crate::LruDiskCacheBuilder;
 
 struct CacheItem {
@@ -65,6 +67,19 @@ pub struct TableDataCache<T = LruDiskCacheHolder> {
     external_cache: T,
     population_queue: crossbeam_channel::Sender<CacheItem>,
     _cache_populator: DiskCachePopulator,
+    statistics: Arc<CacheStatistics>,
+}
+
+impl<T> TableDataCache<T> {
+    pub fn statistics(&self) -> &CacheStatistics {
+        &self.statistics
+    }
+}
+
+impl TableDataCache<LruDiskCacheHolder> {
+    pub fn policy(&self) -> EvictionPolicy {
+        self.external_cache.read().policy()
+    }
 }
 
 const TABLE_DATA_CACHE_NAME: &str = "table_data";
@@ -75,14 +90,22 @@ impl TableDataCacheBuilder {
         path: &PathBuf,
         population_queue_size: u32,
         disk_cache_bytes_size: u64,
+        disk_cache_reset_on_start: bool,
+        disk_cache_policy: EvictionPolicy,
     ) -> Result<TableDataCache<LruDiskCacheHolder>> {
-        let disk_cache = LruDiskCacheBuilder::new_disk_cache(path, disk_cache_bytes_size)?;
+        let disk_cache = LruDiskCacheBuilder::new_disk_cache(
+            path,
+            disk_cache_bytes_size,
+            disk_cache_reset_on_start,
+            disk_cache_policy,
+        )?;
         let (tx, rx) = crossbeam_channel::bounded(population_queue_size as usize);
         let num_population_thread = 1;
         Ok(TableDataCache {
             external_cache: disk_cache.clone(),
             population_queue: tx,
             _cache_populator: DiskCachePopulator::new(rx, disk_cache, num_population_thread)?,
+            statistics: Arc::new(CacheStatistics::default()),
         })
     }
 }
@@ -93,10 +116,12 @@ impl CacheAccessor<String, Bytes, DefaultHashBuilder, Count> for TableDataCache
         let k = k.as_ref();
         if let Some(item) = self.external_cache.get(k) {
             metrics_inc_cache_hit_count(1, TABLE_DATA_CACHE_NAME);
+            self.statistics.record_hit();
             // Profile::record_usize_profile(ProfileStatisticsName::ScanCacheBytes, process_values.bytes);
             Some(item)
         } else {
             metrics_inc_cache_miss_count(1, TABLE_DATA_CACHE_NAME);
+            self.statistics.record_miss();
             None
         }
     }
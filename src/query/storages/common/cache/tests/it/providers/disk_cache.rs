@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use std::fs;
 use std::fs::File;
 use std::io;
 use std::io::Read;
 use std::path::Path;
 use std::path::PathBuf;
 
+use databend_common_cache::EvictionPolicy;
 use databend_storages_common_cache::DiskCacheError;
 use databend_storages_common_cache::DiskCacheKey;
 use databend_storages_common_cache::DiskCacheResult;
@@ -124,6 +126,28 @@ fn test_add_get_lru() {
     }
 }
 
+#[test]
+fn test_add_get_s3fifo() {
+    let f = TestFixture::new();
+    let mut c = DiskCache::new_with_policy(f.tmp(), 100, EvictionPolicy::S3Fifo).unwrap();
+    assert_eq!(c.policy(), EvictionPolicy::S3Fifo);
+    c.insert_single_slice("hot", &[1; 10]).unwrap();
+    assert!(c.get_cache_path("hot").is_some());
+
+    // A scan of files read only once does not flush the file read again.
+    for i in 0..30 {
+        c.insert_single_slice(&format!("scan{i}"), &[2; 10])
+            .unwrap();
+    }
+    assert_eq!(c.size(), 100);
+    assert!(c.contains_key("hot"));
+    assert!(!c.contains_key("scan0"));
+    assert_eq!(
+        read_all(&mut File::open(c.get_cache_path("hot").unwrap()).unwrap()).unwrap(),
+        vec![1u8; 10]
+    );
+}
+
 #[test]
 fn test_insert_bytes_too_large() {
     let f = TestFixture::new();
@@ -152,3 +176,94 @@ fn test_evict_until_enough_space() {
     // file3 MUST be keeped
     assert!(c.contains_key("file3"));
 }
+
+#[test]
+fn test_reload_after_restart() {
+    let f = TestFixture::new();
+    {
+        let mut c = DiskCache::new(f.tmp(), 100).unwrap();
+        c.insert_single_slice("file1", &[1; 10]).unwrap();
+        c.insert_single_slice("file2", &[2; 20]).unwrap();
+        assert_eq!(c.size(), 30);
+    }
+
+    let mut c = DiskCache::new(f.tmp(), 100).unwrap();
+    assert_eq!(c.len(), 2);
+    assert_eq!(c.size(), 30);
+    assert_eq!(
+        read_all(&mut File::open(c.get_cache_path("file1").unwrap()).unwrap()).unwrap(),
+        vec![1u8; 10]
+    );
+    assert_eq!(
+        read_all(&mut File::open(c.get_cache_path("file2").unwrap()).unwrap()).unwrap(),
+        vec![2u8; 20]
+    );
+}
+
+#[test]
+fn test_reload_discard_invalid_files() {
+    let f = TestFixture::new();
+    {
+        let mut c = DiskCache::new(f.tmp(), 100).unwrap();
+        c.insert_single_slice("file1", &[1; 10]).unwrap();
+    }
+
+    // a torn file left by an interrupted write
+    let key_path = PathBuf::from(&DiskCacheKey::from("file2"));
+    let torn_file = f.tmp().join(&key_path).with_extension("tmp");
+    fs::create_dir_all(torn_file.parent().unwrap()).unwrap();
+    fs::write(&torn_file, [2; 5]).unwrap();
+    // an empty file
+    let empty_file = f.tmp().join(&key_path);
+    fs::write(&empty_file, []).unwrap();
+    // files not created by the cache
+    let unknown_file = f.tmp().join("unknown");
+    fs::write(&unknown_file, [3; 5]).unwrap();
+    let misplaced_file = f.tmp().join("abc").join("unknown");
+    fs::create_dir_all(misplaced_file.parent().unwrap()).unwrap();
+    fs::write(&misplaced_file, [4; 5]).unwrap();
+
+    let c = DiskCache::new(f.tmp(), 100).unwrap();
+    assert_eq!(c.len(), 1);
+    assert_eq!(c.size(), 10);
+    assert!(c.contains_key("file1"));
+    assert!(!c.contains_key("file2"));
+    assert!(!torn_file.exists());
+    assert!(!empty_file.exists());
+    assert!(!unknown_file.exists());
+    assert!(!misplaced_file.exists());
+}
+
+#[test]
+fn test_reload_with_smaller_capacity() {
+    let f = TestFixture::new();
+    {
+        let mut c = DiskCache::new(f.tmp(), 100).unwrap();
+        c.insert_single_slice("file1", &[1; 10]).unwrap();
+        c.insert_single_slice("file2", &[2; 10]).unwrap();
+        c.insert_single_slice("file3", &[3; 30]).unwrap();
+    }
+
+    let c = DiskCache::new(f.tmp(), 15).unwrap();
+    // file3 is too large to be stored, and one of file1 and file2 must be evicted
+    assert_eq!(c.len(), 1);
+    assert_eq!(c.size(), 10);
+    assert!(!c.contains_key("file3"));
+    let file3_path = PathBuf::from(&DiskCacheKey::from("file3"));
+    assert!(!f.tmp().join(file3_path).exists());
+}
+
+#[test]
+fn test_new_empty() {
+    let f = TestFixture::new();
+    {
+        let mut c = DiskCache::new(f.tmp(), 100).unwrap();
+        c.insert_single_slice("file1", &[1; 10]).unwrap();
+    }
+
+    let c = DiskCache::new_empty(f.tmp(), 100).unwrap();
+    assert!(c.is_empty());
+    assert!(!c.contains_key("file1"));
+    let file1_path = PathBuf::from(&DiskCacheKey::from("file1"));
+    assert!(!f.tmp().join(file1_path).exists());
+}
@@ -18,7 +18,9 @@ use std::sync::Arc;
 use databend_common_base::base::GlobalInstance;
 use databend_common_cache::CountableMeter;
 use databend_common_cache::DefaultHashBuilder;
+use databend_common_cache::EvictionPolicy;
 use databend_common_config::CacheConfig;
+use databend_common_config::CacheEvictionPolicyInnerConfig;
 use databend_common_config::CacheStorageTypeInnerConfig;
 use databend_common_exception::Result;
 use databend_storages_common_cache::InMemoryCacheBuilder;
@@ -88,6 +90,8 @@ impl CacheManager {
                         &real_disk_cache_root,
                         queue_size,
                         config.disk_cache_config.max_bytes,
+                        config.disk_cache_config.reset_on_start,
+                        config.disk_cache_config.policy,
                     )?
                 }
             }
@@ -97,6 +101,7 @@ impl CacheManager {
         let table_column_array_cache = Self::new_in_memory_cache(
             config.table_data_deserialized_data_bytes,
             ColumnArrayMeter,
+            config.table_data_deserialized_data_policy,
             "table_data_column_array",
         );
 
@@ -114,29 +119,44 @@ impl CacheManager {
                 table_column_array_cache,
             }));
         } else {
-            let table_snapshot_cache =
-                Self::new_item_cache(config.table_meta_snapshot_count, "table_snapshot");
-            let table_statistic_cache =
-                Self::new_item_cache(config.table_meta_statistic_count, "table_statistics");
+            let table_snapshot_cache = Self::new_item_cache(
+                config.table_meta_snapshot_count,
+                config.table_meta_snapshot_policy,
+                "table_snapshot",
+            );
+            let table_statistic_cache = Self::new_item_cache(
+                config.table_meta_statistic_count,
+                config.table_meta_statistic_policy,
+                "table_statistics",
+            );
             let segment_info_cache = Self::new_in_memory_cache(
                 config.table_meta_segment_bytes,
                 CompactSegmentInfoMeter {},
+                config.table_meta_segment_policy,
                 "segment_info",
             );
             let bloom_index_filter_cache = Self::new_in_memory_cache(
                 config.table_bloom_index_filter_size,
                 BloomIndexFilterMeter {},
+                config.table_bloom_index_filter_policy,
                 "bloom_index_filter",
             );
             let bloom_index_meta_cache = Self::new_item_cache(
                 config.table_bloom_index_meta_count,
+                config.table_bloom_index_meta_policy,
                 "bloom_index_file_meta_data",
             );
-            let prune_partitions_cache =
-                Self::new_item_cache(config.table_prune_partitions_count, "prune_partitions");
+            let prune_partitions_cache = Self::new_item_cache(
+                config.table_prune_partitions_count,
+                config.table_prune_partitions_policy,
+                "prune_partitions",
+            );
 
-            let file_meta_data_cache =
-                Self::new_item_cache(DEFAULT_FILE_META_DATA_CACHE_ITEMS, "parquet_file_meta");
+            let file_meta_data_cache = Self::new_item_cache(
+                DEFAULT_FILE_META_DATA_CACHE_ITEMS,
+                config.parquet_file_meta_policy,
+                "parquet_file_meta",
+            );
             GlobalInstance::set(Arc::new(Self {
                 table_snapshot_cache,
                 segment_info_cache,
@@ -196,10 +216,14 @@ impl CacheManager {
     // create cache that meters size by `Count`
     fn new_item_cache<V>(
         capacity: u64,
+        policy: CacheEvictionPolicyInnerConfig,
         name: impl Into<String>,
     ) -> Option<NamedCache<InMemoryItemCacheHolder<V>>> {
         if capacity > 0 {
-            Some(InMemoryCacheBuilder::new_item_cache(capacity).name_with(name.into()))
+            Some(
+                InMemoryCacheBuilder::new_item_cache(capacity, Self::eviction_policy(policy))
+                    .name_with(name.into()),
+            )
         } else {
             None
         }
@@ -209,6 +233,7 @@ impl CacheManager {
     fn new_in_memory_cache<V, M>(
         capacity: u64,
         meter: M,
+        policy: CacheEvictionPolicyInnerConfig,
         name: &str,
     ) -> Option<NamedCache<InMemoryItemCacheHolder<V, DefaultHashBuilder, M>>>
     where
@@ -216,24 +241,39 @@ impl CacheManager {
     {
         if capacity > 0 {
             Some(
-                InMemoryCacheBuilder::new_in_memory_cache(capacity, meter)
-                    .name_with(name.to_owned()),
+                InMemoryCacheBuilder::new_in_memory_cache(
+                    capacity,
+                    meter,
+                    Self::eviction_policy(policy),
+                )
+                .name_with(name.to_owned()),
             )
         } else {
             None
         }
     }
 
+    fn eviction_policy(policy: CacheEvictionPolicyInnerConfig) -> EvictionPolicy {
+        match policy {
+            CacheEvictionPolicyInnerConfig::Lru => EvictionPolicy::Lru,
+            CacheEvictionPolicyInnerConfig::S3Fifo => EvictionPolicy::S3Fifo,
+        }
+    }
+
     fn new_block_data_cache(
         path: &PathBuf,
         population_queue_size: u32,
         disk_cache_bytes_size: u64,
+        disk_cache_reset_on_start: bool,
+        disk_cache_policy: CacheEvictionPolicyInnerConfig,
     ) -> Result<Option<TableDataCache>> {
         if disk_cache_bytes_size > 0 {
             let cache_holder = TableDataCacheBuilder::new_table_data_disk_cache(
                 path,
                 population_queue_size,
                 disk_cache_bytes_size,
+                disk_cache_reset_on_start,
+                Self::eviction_policy(disk_cache_policy),
             )?;
             Ok(Some(cache_holder))
         } else {
@@ -0,0 +1,160 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::BTreeMap;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::Column;
+use databend_common_functions::scalars::bm25_idf;
+use databend_common_functions::scalars::bm25_term_weight;
+use databend_common_functions::scalars::tokenize;
+use databend_common_io::prelude::bincode_deserialize_from_slice;
+use databend_common_io::prelude::bincode_serialize_into_buf;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Option key of the indexed column.
+pub const INVERTED_INDEX_COLUMN: &str = "column";
+
+/// Version of the serialized inverted index, bumped on every change of its layout.
+pub const INVERTED_INDEX_VERSION: u32 = 1;
+
+/// A full-text inverted index built over the strings of one block.
+///
+/// Each term produced by the tokenizer of the `match` function maps to a posting list of
+/// `(row, term frequency)` pairs, sorted by row. NULL rows are not indexed. The length of
+/// every row is kept too, so the rows can be ranked by BM25 as the `match` function does.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct InvertedIndex {
+    version: u32,
+    // Number of terms of each row of the block.
+    doc_lengths: Vec<u32>,
+    // Number of rows with at least one term.
+    num_docs: u32,
+    postings: BTreeMap<String, Vec<(u32, u32)>>,
+}
+
+impl InvertedIndex {
+    /// Build the index from a `String` column.
+    ///
+    /// Returns `None` if there is no term to index.
+    pub fn try_create(column: &Column) -> Result<Option<Self>> {
+        let (column, validity) = match column {
+            Column::Nullable(box c) => (&c.column, Some(&c.validity)),
+            c => (c, None),
+        };
+        let Column::String(column) = column else {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Inverted index only supports String column, but got {}",
+                column.data_type()
+            )));
+        };
+
+        let mut index = InvertedIndex {
+            version: INVERTED_INDEX_VERSION,
+            doc_lengths: Vec::with_capacity(column.len()),
+            ..Default::default()
+        };
+        for (row, text) in column.iter().enumerate() {
+            if validity.map(|v| !v.get_bit(row)).unwrap_or(false) {
+                index.doc_lengths.push(0);
+                continue;
+            }
+            let mut doc_len = 0;
+            for term in tokenize(text) {
+                doc_len += 1;
+                let postings = index.postings.entry(term).or_default();
+                match postings.last_mut() {
+                    Some((last_row, tf)) if *last_row == row as u32 => *tf += 1,
+                    _ => postings.push((row as u32, 1)),
+                }
+            }
+            if doc_len > 0 {
+                index.num_docs += 1;
+            }
+            index.doc_lengths.push(doc_len);
+        }
+        if index.postings.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(index))
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.doc_lengths.len()
+    }
+
+    pub fn num_terms(&self) -> usize {
+        self.postings.len()
+    }
+
+    /// The `(row, term frequency)` pairs of the rows containing the term.
+    pub fn postings(&self, term: &str) -> &[(u32, u32)] {
+        self.postings
+            .get(term)
+            .map(|postings| postings.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Returns false if no row of the block contains any term of the query,
+    /// i.e. `match(column, query)` is zero for every row.
+    pub fn may_match(&self, query: &str) -> bool {
+        tokenize(query).any(|term| self.postings.contains_key(&term))
+    }
+
+    /// Rank the rows matching the query by their BM25 score within the block,
+    /// the most relevant first.
+    pub fn search(&self, query: &str) -> Vec<(u32, f32)> {
+        let mut terms = tokenize(query).collect::<Vec<_>>();
+        terms.sort();
+        terms.dedup();
+
+        let total_len = self.doc_lengths.iter().map(|len| *len as u64).sum::<u64>();
+        let avg_doc_len = total_len as f32 / self.num_docs.max(1) as f32;
+        let mut scores = BTreeMap::new();
+        for term in &terms {
+            let postings = self.postings(term);
+            if postings.is_empty() {
+                continue;
+            }
+            let idf = bm25_idf(self.num_docs, postings.len() as u32);
+            for (row, tf) in postings {
+                let weight = bm25_term_weight(*tf, self.doc_lengths[*row as usize], avg_doc_len);
+                *scores.entry(*row).or_insert(0.0) += idf * weight;
+            }
+        }
+
+        let mut result = scores.into_iter().collect::<Vec<_>>();
+        result.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(&b.0)));
+        result
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        bincode_serialize_into_buf(&mut buf, self)?;
+        Ok(buf)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let index: Self = bincode_deserialize_from_slice(bytes)?;
+        if index.version != INVERTED_INDEX_VERSION {
+            return Err(ErrorCode::StorageOther(format!(
+                "Unsupported inverted index version {}, expected {}",
+                index.version, INVERTED_INDEX_VERSION
+            )));
+        }
+        Ok(index)
+    }
+}
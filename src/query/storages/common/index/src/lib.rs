@@ -18,13 +18,24 @@
 mod bloom_index;
 pub mod filters;
 mod index;
+mod inverted_index;
 mod page_index;
 mod range_index;
+mod vector_index;
 
 pub use bloom_index::BloomIndex;
 pub use bloom_index::BloomIndexMeta;
 pub use bloom_index::FilterEvalResult;
 pub use index::Index;
+pub use inverted_index::InvertedIndex;
+pub use inverted_index::INVERTED_INDEX_COLUMN;
+pub use inverted_index::INVERTED_INDEX_VERSION;
 pub use page_index::PageIndex;
 pub use range_index::statistics_to_domain;
 pub use range_index::RangeIndex;
+pub use vector_index::VectorIndex;
+pub use vector_index::VectorIndexParams;
+pub use vector_index::VECTOR_INDEX_COLUMN;
+pub use vector_index::VECTOR_INDEX_DISTANCE;
+pub use vector_index::VECTOR_INDEX_EF_CONSTRUCTION;
+pub use vector_index::VECTOR_INDEX_M;
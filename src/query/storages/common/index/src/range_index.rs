@@ -21,6 +21,7 @@ use databend_common_expression::types::decimal::Decimal128Type;
 use databend_common_expression::types::decimal::Decimal256Type;
 use databend_common_expression::types::decimal::DecimalDataType;
 use databend_common_expression::types::decimal::DecimalDomain;
+use databend_common_expression::types::geometry::GeometryDomain;
 use databend_common_expression::types::nullable::NullableDomain;
 use databend_common_expression::types::number::SimpleDomain;
 use databend_common_expression::types::string::StringDomain;
@@ -242,6 +243,12 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
                         *sz,
                     )),
                 },
+                DataType::Geometry => match (min.as_geometry(), max.as_geometry()) {
+                    (Some(min), Some(max)) => GeometryDomain::from_min_max(min, max)
+                        .map(Domain::Geometry)
+                        .unwrap_or_else(|| Domain::full(data_type)),
+                    _ => Domain::full(data_type),
+                },
                 // Unsupported data type
                 _ => Domain::full(data_type),
             })
@@ -249,4 +256,18 @@ pub fn statistics_to_domain(mut stats: Vec<&ColumnStatistics>, data_type: &DataT
     }
 }
 
-impl Index for RangeIndex {}
+impl Index for RangeIndex {
+    fn supported_type(data_type: &DataType) -> bool {
+        let inner_type = data_type.remove_nullable();
+        // The min/max statistics of geometry are the corners of the bounding box.
+        matches!(
+            inner_type,
+            DataType::Number(_)
+                | DataType::Date
+                | DataType::Timestamp
+                | DataType::String
+                | DataType::Decimal(_)
+                | DataType::Geometry
+        )
+    }
+}
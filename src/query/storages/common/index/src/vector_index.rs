@@ -0,0 +1,404 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::cmp::Ordering;
+use std::cmp::Reverse;
+use std::collections::BTreeMap;
+use std::collections::BinaryHeap;
+use std::collections::HashSet;
+
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberColumn;
+use databend_common_expression::Column;
+use databend_common_io::prelude::bincode_deserialize_from_slice;
+use databend_common_io::prelude::bincode_serialize_into_buf;
+use databend_common_vector::VectorDistance;
+use serde::Deserialize;
+use serde::Serialize;
+
+/// Option key of the indexed column.
+pub const VECTOR_INDEX_COLUMN: &str = "column";
+/// Option key of the distance function, `cosine` or `l2`.
+pub const VECTOR_INDEX_DISTANCE: &str = "distance";
+/// Option key of the max number of neighbors per node in the upper layers.
+pub const VECTOR_INDEX_M: &str = "m";
+/// Option key of the size of the candidate list used while building.
+pub const VECTOR_INDEX_EF_CONSTRUCTION: &str = "ef_construction";
+
+const DEFAULT_M: usize = 16;
+const DEFAULT_EF_CONSTRUCTION: usize = 64;
+const MAX_LEVEL: usize = 16;
+
+/// Build parameters of a vector index, parsed from the index options.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct VectorIndexParams {
+    pub distance: VectorDistance,
+    pub m: usize,
+    pub ef_construction: usize,
+}
+
+impl Default for VectorIndexParams {
+    fn default() -> Self {
+        Self {
+            distance: VectorDistance::Cosine,
+            m: DEFAULT_M,
+            ef_construction: DEFAULT_EF_CONSTRUCTION,
+        }
+    }
+}
+
+impl VectorIndexParams {
+    pub fn try_from_options(options: &BTreeMap<String, String>) -> Result<Self> {
+        let mut params = Self::default();
+        for (key, value) in options {
+            match key.as_str() {
+                VECTOR_INDEX_COLUMN => {}
+                VECTOR_INDEX_DISTANCE => {
+                    params.distance = value
+                        .parse()
+                        .map_err(|e: ErrorCode| ErrorCode::UnsupportedIndex(e.message()))?;
+                }
+                VECTOR_INDEX_M => {
+                    params.m = Self::parse_number(key, value, 2, 128)?;
+                }
+                VECTOR_INDEX_EF_CONSTRUCTION => {
+                    params.ef_construction = Self::parse_number(key, value, 1, 4096)?;
+                }
+                _ => {
+                    return Err(ErrorCode::UnsupportedIndex(format!(
+                        "Unknown vector index option '{}', expected one of: distance, m, ef_construction",
+                        key
+                    )));
+                }
+            }
+        }
+        Ok(params)
+    }
+
+    fn parse_number(key: &str, value: &str, min: usize, max: usize) -> Result<usize> {
+        match value.parse::<usize>() {
+            Ok(v) if (min..=max).contains(&v) => Ok(v),
+            _ => Err(ErrorCode::UnsupportedIndex(format!(
+                "Invalid value '{}' for vector index option '{}', expected an integer in [{}, {}]",
+                value, key, min, max
+            ))),
+        }
+    }
+
+    /// Max number of neighbors of a node on the given level.
+    fn max_neighbors(&self, level: usize) -> usize {
+        if level == 0 { self.m * 2 } else { self.m }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Candidate {
+    distance: f32,
+    node: u32,
+}
+
+impl PartialEq for Candidate {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Candidate {}
+
+impl PartialOrd for Candidate {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Candidate {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.distance
+            .total_cmp(&other.distance)
+            .then(self.node.cmp(&other.node))
+    }
+}
+
+/// A HNSW (Hierarchical Navigable Small World) graph built over the vectors of one block.
+///
+/// Rows whose vector is NULL, empty or contains NULL are not indexed. The search results are approximate,
+/// callers are expected to re-rank the rows with the exact distance.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct VectorIndex {
+    distance: VectorDistance,
+    dimension: usize,
+    // row offset in the block of each node.
+    row_ids: Vec<u32>,
+    // vectors of all nodes, `dimension` values per node.
+    vectors: Vec<f32>,
+    // neighbors of each node, indexed by node and then by level.
+    neighbors: Vec<Vec<Vec<u32>>>,
+    entry_point: u32,
+}
+
+impl VectorIndex {
+    /// Build the index from an `Array(Float32)` column.
+    ///
+    /// Returns `None` if there is nothing to index, or if the vectors do not share one dimension.
+    pub fn try_create(params: &VectorIndexParams, column: &Column) -> Result<Option<Self>> {
+        let (column, validity) = match column {
+            Column::Nullable(box c) => (&c.column, Some(&c.validity)),
+            c => (c, None),
+        };
+        let Column::Array(box array) = column else {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Vector index only supports Array(Float32) column, but got {}",
+                column.data_type()
+            )));
+        };
+        let (values, value_validity) = match &array.values {
+            Column::Nullable(box c) => (&c.column, Some(&c.validity)),
+            c => (c, None),
+        };
+        let Column::Number(NumberColumn::Float32(values)) = values else {
+            return Err(ErrorCode::UnsupportedIndex(format!(
+                "Vector index only supports Array(Float32) column, but got {}",
+                column.data_type()
+            )));
+        };
+
+        let mut dimension = None;
+        let mut row_ids = Vec::new();
+        let mut vectors = Vec::new();
+        for row in 0..array.len() {
+            if validity.map(|v| !v.get_bit(row)).unwrap_or(false) {
+                continue;
+            }
+            let start = array.offsets[row] as usize;
+            let end = array.offsets[row + 1] as usize;
+            if start == end
+                || value_validity
+                    .map(|v| (start..end).any(|i| !v.get_bit(i)))
+                    .unwrap_or(false)
+            {
+                continue;
+            }
+            match dimension {
+                None => dimension = Some(end - start),
+                Some(d) if d != end - start => return Ok(None),
+                _ => {}
+            }
+            row_ids.push(row as u32);
+            vectors.extend(values[start..end].iter().map(|v| v.0));
+        }
+        let Some(dimension) = dimension else {
+            return Ok(None);
+        };
+
+        let mut index = VectorIndex {
+            distance: params.distance,
+            dimension,
+            neighbors: Vec::with_capacity(row_ids.len()),
+            row_ids,
+            vectors,
+            entry_point: 0,
+        };
+        for node in 0..index.row_ids.len() as u32 {
+            index.insert(params, node);
+        }
+        Ok(Some(index))
+    }
+
+    pub fn dimension(&self) -> usize {
+        self.dimension
+    }
+
+    pub fn distance(&self) -> VectorDistance {
+        self.distance
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.row_ids.len()
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut buf = Vec::new();
+        bincode_serialize_into_buf(&mut buf, self)?;
+        Ok(buf)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        bincode_deserialize_from_slice(bytes)
+    }
+
+    /// Find (approximately) the `k` nearest rows of the query vector,
+    /// returns the row offsets in the block and their distances in ascending order.
+    pub fn search(&self, query: &[f32], k: usize, ef: usize) -> Result<Vec<(u32, f32)>> {
+        if query.len() != self.dimension {
+            return Err(ErrorCode::InvalidArgument(format!(
+                "Vector length not equal: {:} != {:}",
+                query.len(),
+                self.dimension,
+            )));
+        }
+        if k == 0 || self.row_ids.is_empty() {
+            return Ok(vec![]);
+        }
+
+        let mut entry_point = self.entry_point;
+        for level in (1..self.level_of(entry_point) + 1).rev() {
+            entry_point = self.search_layer(query, &[entry_point], 1, level)[0].node;
+        }
+        let candidates = self.search_layer(query, &[entry_point], ef.max(k), 0);
+        Ok(candidates
+            .into_iter()
+            .take(k)
+            .map(|c| (self.row_ids[c.node as usize], c.distance))
+            .collect())
+    }
+
+    fn insert(&mut self, params: &VectorIndexParams, node: u32) {
+        let level = random_level(node, params.m);
+        self.neighbors.push(vec![vec![]; level + 1]);
+        if node == 0 {
+            self.entry_point = node;
+            return;
+        }
+
+        let query = self.vector(node).to_vec();
+        let top_level = self.level_of(self.entry_point);
+        let mut entry_points = vec![self.entry_point];
+        for l in (level + 1..top_level + 1).rev() {
+            entry_points = vec![self.search_layer(&query, &entry_points, 1, l)[0].node];
+        }
+        for l in (0..level.min(top_level) + 1).rev() {
+            let candidates = self.search_layer(&query, &entry_points, params.ef_construction, l);
+            let max_neighbors = params.max_neighbors(l);
+            let selected = candidates
+                .iter()
+                .take(max_neighbors)
+                .map(|c| c.node)
+                .collect::<Vec<_>>();
+            for &neighbor in &selected {
+                self.connect(neighbor, node, l, max_neighbors);
+            }
+            self.neighbors[node as usize][l] = selected;
+            entry_points = candidates.into_iter().map(|c| c.node).collect();
+        }
+        if level > top_level {
+            self.entry_point = node;
+        }
+    }
+
+    // Add `node` to the neighbors of `from`, keep the closest ones if there are too many.
+    fn connect(&mut self, from: u32, node: u32, level: usize, max_neighbors: usize) {
+        let mut neighbors = std::mem::take(&mut self.neighbors[from as usize][level]);
+        neighbors.push(node);
+        if neighbors.len() > max_neighbors {
+            let base = self.vector(from);
+            let mut candidates = neighbors
+                .iter()
+                .map(|&n| Candidate {
+                    distance: self.distance.eval(base, self.vector(n)),
+                    node: n,
+                })
+                .collect::<Vec<_>>();
+            candidates.sort();
+            neighbors = candidates
+                .into_iter()
+                .take(max_neighbors)
+                .map(|c| c.node)
+                .collect();
+        }
+        self.neighbors[from as usize][level] = neighbors;
+    }
+
+    // Greedy search on one level, returns at most `ef` candidates in ascending order of distance.
+    fn search_layer(
+        &self,
+        query: &[f32],
+        entry_points: &[u32],
+        ef: usize,
+        level: usize,
+    ) -> Vec<Candidate> {
+        let mut visited = entry_points.iter().copied().collect::<HashSet<_>>();
+        let mut candidates = BinaryHeap::new();
+        let mut results = BinaryHeap::new();
+        for &node in entry_points {
+            let candidate = Candidate {
+                distance: self.distance.eval(query, self.vector(node)),
+                node,
+            };
+            candidates.push(Reverse(candidate));
+            results.push(candidate);
+            if results.len() > ef {
+                results.pop();
+            }
+        }
+
+        while let Some(Reverse(current)) = candidates.pop() {
+            if let Some(furthest) = results.peek() {
+                if results.len() >= ef && current.distance > furthest.distance {
+                    break;
+                }
+            }
+            for &neighbor in self.neighbors_of(current.node, level) {
+                if !visited.insert(neighbor) {
+                    continue;
+                }
+                let candidate = Candidate {
+                    distance: self.distance.eval(query, self.vector(neighbor)),
+                    node: neighbor,
+                };
+                let closer = match results.peek() {
+                    Some(furthest) => results.len() < ef || candidate < *furthest,
+                    None => true,
+                };
+                if closer {
+                    candidates.push(Reverse(candidate));
+                    results.push(candidate);
+                    if results.len() > ef {
+                        results.pop();
+                    }
+                }
+            }
+        }
+        results.into_sorted_vec()
+    }
+
+    fn vector(&self, node: u32) -> &[f32] {
+        let start = node as usize * self.dimension;
+        &self.vectors[start..start + self.dimension]
+    }
+
+    fn level_of(&self, node: u32) -> usize {
+        self.neighbors[node as usize].len() - 1
+    }
+
+    fn neighbors_of(&self, node: u32, level: usize) -> &[u32] {
+        self.neighbors[node as usize]
+            .get(level)
+            .map(|v| v.as_slice())
+            .unwrap_or(&[])
+    }
+}
+
+// The level of a node follows the exponential distribution with parameter `ln(m)`.
+// A hash of the node is used instead of a random number, so that the same block
+// always produces the same index.
+fn random_level(node: u32, m: usize) -> usize {
+    let mut x = (node as u64).wrapping_add(0x9E3779B97F4A7C15);
+    x = (x ^ (x >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94D049BB133111EB);
+    x ^= x >> 31;
+    // uniform in (0, 1]
+    let uniform = ((x >> 11) as f64 + 1.0) / (1u64 << 53) as f64;
+    let level = (-uniform.ln() / (m as f64).ln()) as usize;
+    level.min(MAX_LEVEL)
+}
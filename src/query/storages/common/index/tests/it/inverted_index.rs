@@ -0,0 +1,110 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_exception::Result;
+use databend_common_expression::types::StringType;
+use databend_common_expression::FromData;
+use databend_common_functions::scalars::match_scores;
+use databend_common_functions::scalars::tokenize;
+use databend_storages_common_index::InvertedIndex;
+use databend_storages_common_index::INVERTED_INDEX_VERSION;
+
+#[test]
+fn test_tokenize() {
+    let terms = tokenize("Connection timeout: retry #3, host=db-01.").collect::<Vec<_>>();
+    assert_eq!(terms, vec![
+        "connection",
+        "timeout",
+        "retry",
+        "3",
+        "host",
+        "db",
+        "01"
+    ]);
+}
+
+#[test]
+fn test_inverted_index_build() -> Result<()> {
+    let column = StringType::from_opt_data(vec![
+        Some("the quick brown fox"),
+        None,
+        Some("The lazy dog, the end"),
+        Some(""),
+    ]);
+    let index = InvertedIndex::try_create(&column)?.unwrap();
+
+    assert_eq!(index.num_rows(), 4);
+    assert_eq!(index.postings("the"), &[(0, 1), (2, 2)]);
+    assert_eq!(index.postings("fox"), &[(0, 1)]);
+    assert!(index.postings("cat").is_empty());
+
+    assert!(index.may_match("Lazy cat"));
+    assert!(!index.may_match("cat mouse"));
+    assert!(!index.may_match(""));
+
+    let column = StringType::from_opt_data(vec![None, Some("  ,. ")]);
+    assert!(InvertedIndex::try_create(&column)?.is_none());
+    Ok(())
+}
+
+#[test]
+fn test_inverted_index_search() -> Result<()> {
+    let texts = vec![
+        "error: disk full",
+        "warning: disk almost full",
+        "info: all good",
+        "error: network error, retry later",
+    ];
+    let column = StringType::from_data(texts.clone());
+    let index = InvertedIndex::try_create(&column)?.unwrap();
+
+    let rows = index
+        .search("error")
+        .into_iter()
+        .map(|(row, _)| row)
+        .collect::<Vec<_>>();
+    assert_eq!(rows, vec![3, 0]);
+
+    let result = index.search("disk full");
+    assert_eq!(result.len(), 2);
+    assert!(result.iter().all(|(row, score)| *row < 2 && *score > 0.0));
+
+    assert!(index.search("unknown").is_empty());
+
+    // The rows are scored as the `match` function scores the whole block.
+    let scores = match_scores(&texts, &["disk full"]);
+    for (row, score) in result {
+        assert!((scores[row as usize] - score).abs() < 1e-6);
+    }
+    assert_eq!(scores[2], 0.0);
+    Ok(())
+}
+
+#[test]
+fn test_inverted_index_serialization() -> Result<()> {
+    let column = StringType::from_data(vec!["hello world", "hello databend"]);
+    let index = InvertedIndex::try_create(&column)?.unwrap();
+    let bytes = index.to_bytes()?;
+    let index = InvertedIndex::from_bytes(&bytes)?;
+
+    assert_eq!(index.num_terms(), 3);
+    assert_eq!(index.postings("hello"), &[(0, 1), (1, 1)]);
+
+    // An index of another version is rejected instead of being misread,
+    // the version is the first field and a small version is encoded in one byte.
+    let mut bytes = bytes;
+    bytes[0] = (INVERTED_INDEX_VERSION + 1) as u8;
+    assert!(InvertedIndex::from_bytes(&bytes).is_err());
+    Ok(())
+}
@@ -16,3 +16,5 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod filters;
+mod inverted_index;
+mod vector_index;
@@ -0,0 +1,140 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::BTreeMap;
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_exception::Result;
+use databend_common_expression::types::array::ArrayColumn;
+use databend_common_expression::types::nullable::NullableColumn;
+use databend_common_expression::types::NumberColumn;
+use databend_common_expression::types::F32;
+use databend_common_expression::Column;
+use databend_common_vector::VectorDistance;
+use databend_storages_common_index::VectorIndex;
+use databend_storages_common_index::VectorIndexParams;
+use rand::rngs::StdRng;
+use rand::Rng;
+use rand::SeedableRng;
+
+fn vector_column(vectors: &[Vec<f32>]) -> Column {
+    let mut values = Vec::new();
+    let mut offsets = vec![0u64];
+    for v in vectors {
+        values.extend(v.iter().map(|x| F32::from(*x)));
+        offsets.push(values.len() as u64);
+    }
+    Column::Array(Box::new(ArrayColumn {
+        values: Column::Number(NumberColumn::Float32(values.into())),
+        offsets: offsets.into(),
+    }))
+}
+
+fn random_vectors(rows: usize, dimension: usize) -> Vec<Vec<f32>> {
+    let mut rng = StdRng::seed_from_u64(42);
+    (0..rows)
+        .map(|_| (0..dimension).map(|_| rng.gen_range(-1.0..1.0)).collect())
+        .collect()
+}
+
+#[test]
+fn test_vector_index_recall() -> Result<()> {
+    let vectors = random_vectors(1000, 16);
+    let column = vector_column(&vectors);
+
+    for distance in [VectorDistance::Cosine, VectorDistance::L2] {
+        let params = VectorIndexParams {
+            distance,
+            ..Default::default()
+        };
+        let index = VectorIndex::try_create(&params, &column)?.unwrap();
+        assert_eq!(index.num_rows(), 1000);
+        assert_eq!(index.dimension(), 16);
+
+        let mut hits = 0;
+        for query in vectors.iter().step_by(50) {
+            let mut exact = vectors
+                .iter()
+                .enumerate()
+                .map(|(i, v)| (i as u32, distance.eval(query, v)))
+                .collect::<Vec<_>>();
+            exact.sort_by(|a, b| a.1.total_cmp(&b.1));
+            let expected = exact.iter().take(10).map(|v| v.0).collect::<Vec<_>>();
+
+            let result = index.search(query, 10, 64)?;
+            assert_eq!(result.len(), 10);
+            assert!(result.windows(2).all(|w| w[0].1 <= w[1].1));
+            hits += result.iter().filter(|v| expected.contains(&v.0)).count();
+        }
+        // 20 queries with 10 results each.
+        assert!(hits >= 180, "recall too low: {hits}/200");
+    }
+    Ok(())
+}
+
+#[test]
+fn test_vector_index_serialization() -> Result<()> {
+    let vectors = random_vectors(100, 8);
+    let index = VectorIndex::try_create(&Default::default(), &vector_column(&vectors))?.unwrap();
+    let bytes = index.to_bytes()?;
+    let decoded = VectorIndex::from_bytes(&bytes)?;
+
+    assert_eq!(decoded.num_rows(), index.num_rows());
+    assert_eq!(decoded.distance(), index.distance());
+    assert_eq!(
+        decoded.search(&vectors[3], 5, 16)?,
+        index.search(&vectors[3], 5, 16)?
+    );
+    Ok(())
+}
+
+#[test]
+fn test_vector_index_skip_invalid_rows() -> Result<()> {
+    let vectors = vec![vec![1.0, 0.0], vec![], vec![0.0, 1.0], vec![1.0, 1.0]];
+    let column = Column::Nullable(Box::new(NullableColumn {
+        column: vector_column(&vectors),
+        validity: Bitmap::from([true, true, true, false]),
+    }));
+    let index = VectorIndex::try_create(&Default::default(), &column)?.unwrap();
+    assert_eq!(index.num_rows(), 2);
+    let result = index.search(&[0.1, 1.0], 5, 16)?;
+    assert_eq!(result.iter().map(|v| v.0).collect::<Vec<_>>(), vec![2, 0]);
+    assert!(index.search(&[1.0], 5, 16).is_err());
+
+    // vectors with different dimensions can not be indexed.
+    let column = vector_column(&[vec![1.0, 0.0], vec![1.0]]);
+    assert!(VectorIndex::try_create(&Default::default(), &column)?.is_none());
+    Ok(())
+}
+
+#[test]
+fn test_vector_index_params() -> Result<()> {
+    let mut options = BTreeMap::new();
+    options.insert("column".to_string(), "v".to_string());
+    options.insert("distance".to_string(), "L2".to_string());
+    options.insert("m".to_string(), "8".to_string());
+    let params = VectorIndexParams::try_from_options(&options)?;
+    assert_eq!(params.distance, VectorDistance::L2);
+    assert_eq!(params.m, 8);
+    assert_eq!(params.ef_construction, 64);
+
+    options.insert("m".to_string(), "1".to_string());
+    assert!(VectorIndexParams::try_from_options(&options).is_err());
+    options.remove("m");
+    options.insert("distance".to_string(), "dot".to_string());
+    assert!(VectorIndexParams::try_from_options(&options).is_err());
+    options.remove("distance");
+    options.insert("unknown".to_string(), "1".to_string());
+    assert!(VectorIndexParams::try_from_options(&options).is_err());
+    Ok(())
+}
@@ -81,6 +81,13 @@ pub struct BlockMeta {
 
     // block create_on
     pub create_on: Option<DateTime<Utc>>,
+
+    /// location of the deletion vector, a bitmap of the rows deleted by merge-on-read mutations
+    #[serde(default)]
+    pub deletion_vector_location: Option<Location>,
+    /// number of rows marked as deleted by the deletion vector
+    #[serde(default)]
+    pub deleted_row_count: u64,
 }
 
 impl BlockMeta {
@@ -110,6 +117,8 @@ impl BlockMeta {
             bloom_filter_index_size,
             compression,
             create_on,
+            deletion_vector_location: None,
+            deleted_row_count: 0,
         }
     }
 
@@ -117,6 +126,11 @@ impl BlockMeta {
         self.compression
     }
 
+    /// Number of rows which are not marked as deleted by the deletion vector.
+    pub fn live_row_count(&self) -> u64 {
+        self.row_count - self.deleted_row_count
+    }
+
     /// Get the page size of the block.
     /// - If the format is parquet, its page size is its row count.
     /// - If the format is native, its page size is the row count of each page.
@@ -256,6 +270,8 @@ impl BlockMeta {
             bloom_filter_index_size: 0,
             compression: Compression::Lz4,
             create_on: None,
+            deletion_vector_location: None,
+            deleted_row_count: 0,
         }
     }
 
@@ -288,6 +304,8 @@ impl BlockMeta {
             bloom_filter_index_size: s.bloom_filter_index_size,
             compression: s.compression,
             create_on: None,
+            deletion_vector_location: None,
+            deleted_row_count: 0,
         }
     }
 }
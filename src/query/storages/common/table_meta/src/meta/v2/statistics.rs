@@ -237,7 +237,7 @@ impl Statistics {
 /// to serialize a `Scalar` that is not supported by `IndexScalar`.
 /// Callers should ensure that all `Scalar` values used for serialization are within
 /// the supported subset of `IndexScalar`.
-fn serialize_index_scalar<S>(scalar: &Scalar, serializer: S) -> Result<S::Ok, S::Error>
+pub(crate) fn serialize_index_scalar<S>(scalar: &Scalar, serializer: S) -> Result<S::Ok, S::Error>
 where S: serde::Serializer {
     match IndexScalar::try_from(scalar.clone()) {
         Ok(index_scalar) => serde::Serialize::serialize(&index_scalar, serializer),
@@ -251,7 +251,7 @@ where S: serde::Serializer {
 ///
 /// This function first deserializes the value into `IndexScalar` and then converts it
 /// to `Scalar`.
-fn deserialize_index_scalar<'de, D>(deserializer: D) -> Result<Scalar, D::Error>
+pub(crate) fn deserialize_index_scalar<'de, D>(deserializer: D) -> Result<Scalar, D::Error>
 where D: serde::Deserializer<'de> {
     let index_scalar = <IndexScalar as serde::Deserialize>::deserialize(deserializer)?;
     Ok(Scalar::from(index_scalar))
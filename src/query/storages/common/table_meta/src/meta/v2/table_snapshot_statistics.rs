@@ -0,0 +1,116 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+
+use databend_common_expression::ColumnId;
+use databend_common_expression::Scalar;
+use serde::Deserialize;
+use serde::Serialize;
+
+use super::statistics::deserialize_index_scalar;
+use super::statistics::serialize_index_scalar;
+use crate::meta::v1;
+use crate::meta::FormatVersion;
+use crate::meta::SnapshotId;
+use crate::meta::Versioned;
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct TableSnapshotStatistics {
+    /// format version of snapshot
+    pub format_version: FormatVersion,
+
+    /// id of snapshot
+    pub snapshot_id: SnapshotId,
+
+    pub column_distinct_values: HashMap<ColumnId, u64>,
+
+    /// Histograms of columns, built from the blocks sampled by `ANALYZE TABLE`.
+    pub histograms: HashMap<ColumnId, ColumnHistogram>,
+}
+
+impl TableSnapshotStatistics {
+    pub fn new(
+        column_distinct_values: HashMap<ColumnId, u64>,
+        histograms: HashMap<ColumnId, ColumnHistogram>,
+    ) -> Self {
+        Self {
+            format_version: TableSnapshotStatistics::VERSION,
+            snapshot_id: SnapshotId::new_v4(),
+            column_distinct_values,
+            histograms,
+        }
+    }
+
+    pub fn format_version(&self) -> u64 {
+        self.format_version
+    }
+
+    pub fn get_column_distinct_values(&self) -> &HashMap<ColumnId, u64> {
+        &self.column_distinct_values
+    }
+
+    pub fn get_histograms(&self) -> &HashMap<ColumnId, ColumnHistogram> {
+        &self.histograms
+    }
+}
+
+impl From<v1::TableSnapshotStatistics> for TableSnapshotStatistics {
+    fn from(value: v1::TableSnapshotStatistics) -> Self {
+        Self {
+            // NOTE: it is important to let the format_version return from here
+            // carries the format_version of statistics being converted.
+            format_version: value.format_version,
+            snapshot_id: value.snapshot_id,
+            column_distinct_values: value.column_distinct_values,
+            histograms: HashMap::new(),
+        }
+    }
+}
+
+/// An equi-height histogram of a column, the null values are not counted.
+///
+/// The first bucket only holds the min value of the column, and the i-th bucket
+/// holds the values in `(buckets[i - 1].upper_bound, buckets[i].upper_bound]`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct ColumnHistogram {
+    pub buckets: Vec<HistogramBucket>,
+
+    /// The most common values of the column, in descending order of frequency.
+    pub most_common_values: Vec<MostCommonValue>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct HistogramBucket {
+    #[serde(
+        serialize_with = "serialize_index_scalar",
+        deserialize_with = "deserialize_index_scalar"
+    )]
+    pub upper_bound: Scalar,
+    /// Estimated number of values in the bucket.
+    pub num_values: f64,
+    /// Estimated number of distinct values in the bucket.
+    pub num_distinct: f64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct MostCommonValue {
+    #[serde(
+        serialize_with = "serialize_index_scalar",
+        deserialize_with = "deserialize_index_scalar"
+    )]
+    pub value: Scalar,
+    /// Estimated number of rows having this value.
+    pub count: f64,
+}
@@ -63,6 +63,8 @@ impl From<BlockMeta> for crate::meta::BlockMeta {
             bloom_filter_index_size: value.bloom_filter_index_size,
             compression: value.compression.into(),
             create_on: None,
+            deletion_vector_location: None,
+            deleted_row_count: 0,
         }
     }
 }
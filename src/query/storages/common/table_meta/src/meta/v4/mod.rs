@@ -15,7 +15,6 @@
 mod segment;
 mod snapshot;
 
-pub use segment::CompactSegmentInfo;
 pub use segment::SegmentInfo;
 pub use snapshot::TableSnapshot;
 pub use snapshot::TableSnapshotLite;
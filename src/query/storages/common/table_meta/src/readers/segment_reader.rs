@@ -34,7 +34,9 @@ impl VersionedReader<CompactSegmentInfo> for (SegmentInfoVersion, TableSchemaRef
         let mut buffer: Vec<u8> = vec![];
         reader.read_to_end(&mut buffer).await?;
         match &self.0 {
-            SegmentInfoVersion::V4(_) => CompactSegmentInfo::from_slice(&buffer),
+            SegmentInfoVersion::V4(_) | SegmentInfoVersion::V5(_) => {
+                CompactSegmentInfo::from_slice(&buffer)
+            }
             SegmentInfoVersion::V3(_) => {
                 let current: SegmentInfo = SegmentInfoV3::from_slice(&buffer)?.into();
                 current.try_into()
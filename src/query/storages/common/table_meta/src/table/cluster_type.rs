@@ -0,0 +1,69 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fmt::Display;
+use std::fmt::Formatter;
+
+/// The layout the blocks of a clustered table are sorted in.
+///
+/// The layout is persisted as a prefix of the cluster key string, e.g. `HILBERT(a, b)`,
+/// a linear cluster key has no prefix, e.g. `(a, b)`.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ClusterType {
+    #[default]
+    Linear,
+    Hilbert,
+    ZOrder,
+}
+
+impl ClusterType {
+    /// Splits the cluster key string into the layout and the parenthesized key list.
+    pub fn split_cluster_key(cluster_key: &str) -> (ClusterType, &str) {
+        let cluster_key = cluster_key.trim();
+        for (cluster_type, prefix) in [
+            (ClusterType::Hilbert, "HILBERT"),
+            (ClusterType::ZOrder, "ZORDER"),
+        ] {
+            if cluster_key.len() > prefix.len()
+                && cluster_key.is_char_boundary(prefix.len())
+                && cluster_key[..prefix.len()].eq_ignore_ascii_case(prefix)
+            {
+                let keys = cluster_key[prefix.len()..].trim_start();
+                if keys.starts_with('(') {
+                    return (cluster_type, keys);
+                }
+            }
+        }
+        (ClusterType::Linear, cluster_key)
+    }
+
+    /// The scalar function which maps the cluster keys to the sort key of the layout.
+    pub fn key_function(&self) -> Option<&'static str> {
+        match self {
+            ClusterType::Linear => None,
+            ClusterType::Hilbert => Some("hilbert_key"),
+            ClusterType::ZOrder => Some("zorder_key"),
+        }
+    }
+}
+
+impl Display for ClusterType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ClusterType::Linear => write!(f, "linear"),
+            ClusterType::Hilbert => write!(f, "hilbert"),
+            ClusterType::ZOrder => write!(f, "zorder"),
+        }
+    }
+}
@@ -18,6 +18,9 @@ pub const OPT_KEY_DATABASE_NAME: &str = "table_database";
 pub const OPT_KEY_TABLE_ID: &str = "table_id";
 pub const OPT_KEY_TABLE_VER: &str = "table_version";
 pub const OPT_KEY_MODE: &str = "mode";
+// The snapshot the changes are computed up to, only set on the read-only streams
+// built for the `CHANGES` clause. Such streams are never consumed.
+pub const OPT_KEY_END_SNAPSHOT_LOCATION: &str = "end_snapshot_location";
 
 pub const MODE_APPEND_ONLY: &str = "append_only";
 pub const MODE_STANDARD: &str = "standard";
@@ -23,6 +23,19 @@ pub const OPT_KEY_COMMENT: &str = "comment";
 pub const OPT_KEY_ENGINE: &str = "engine";
 pub const OPT_KEY_BLOOM_INDEX_COLUMNS: &str = "bloom_index_columns";
 pub const OPT_KEY_CHANGE_TRACKING: &str = "change_tracking";
+// The ranges of the keys of a hilbert or z-order cluster key, taken from the column statistics.
+pub const OPT_KEY_CLUSTER_KEY_BOUNDS: &str = "cluster_key_bounds";
+
+// Materialized view options.
+// The query of a materialized view, kept by the fuse table holding its data.
+pub const OPT_KEY_MATERIALIZED_VIEW_QUERY: &str = "materialized_view_query";
+// How a materialized view is refreshed, `incremental` or `full`.
+pub const OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE: &str = "materialized_view_refresh_mode";
+// The comma separated table ids of the materialized views depending on a base table.
+pub const OPT_KEY_MATERIALIZED_VIEWS: &str = "materialized_views";
+
+pub const MATERIALIZED_VIEW_REFRESH_INCREMENTAL: &str = "incremental";
+pub const MATERIALIZED_VIEW_REFRESH_FULL: &str = "full";
 
 // Attached table options.
 pub const OPT_KEY_TABLE_ATTACHED_DATA_URI: &str = "table_data_uri";
@@ -53,6 +66,10 @@ pub static RESERVED_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     let mut r = HashSet::new();
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE);
+    r.insert(OPT_KEY_MATERIALIZED_VIEWS);
+    r.insert(OPT_KEY_CLUSTER_KEY_BOUNDS);
     r
 });
 
@@ -62,6 +79,10 @@ pub static INTERNAL_TABLE_OPTION_KEYS: LazyLock<HashSet<&'static str>> = LazyLoc
     r.insert(OPT_KEY_LEGACY_SNAPSHOT_LOC);
     r.insert(OPT_KEY_DATABASE_ID);
     r.insert(OPT_KEY_ENGINE_META);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_QUERY);
+    r.insert(OPT_KEY_MATERIALIZED_VIEW_REFRESH_MODE);
+    r.insert(OPT_KEY_MATERIALIZED_VIEWS);
+    r.insert(OPT_KEY_CLUSTER_KEY_BOUNDS);
     r
 });
 
@@ -204,6 +204,8 @@ fn build_test_segment_info(
         bloom_filter_index_size: 0,
         compression: Compression::Lz4,
         create_on: Some(Utc::now()),
+        deletion_vector_location: None,
+        deleted_row_count: 0,
     };
 
     let block_metas = (0..num_blocks_per_seg)
@@ -18,6 +18,7 @@ use databend_common_catalog::statistics::BasicColumnStatistics;
 use databend_common_catalog::table::ColumnStatisticsProvider;
 use databend_common_expression::ColumnId;
 use databend_common_storage::Datum;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 use databend_storages_common_table_meta::meta::ColumnStatistics as FuseColumnStatistics;
 
 /// A column statistics provider for fuse table.
@@ -30,6 +31,7 @@ impl FuseTableColumnStatisticsProvider {
     pub fn new(
         column_stats: HashMap<ColumnId, FuseColumnStatistics>,
         column_distinct_values: Option<HashMap<ColumnId, u64>>,
+        mut histograms: HashMap<ColumnId, ColumnHistogram>,
         row_count: u64,
     ) -> Self {
         let column_stats = column_stats
@@ -43,6 +45,7 @@ impl FuseTableColumnStatisticsProvider {
                     max: Datum::from_scalar(stat.max),
                     ndv: Some(ndv),
                     null_count: stat.null_count,
+                    histogram: histograms.remove(&column_id),
                 };
                 (column_id, stat.get_useful_stat(row_count))
             })
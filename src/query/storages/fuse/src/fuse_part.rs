@@ -47,6 +47,8 @@ pub struct FusePartInfo {
 
     pub sort_min_max: Option<(Scalar, Scalar)>,
     pub block_meta_index: Option<BlockMetaIndex>,
+    /// Location of the deletion vector of the block, rows marked in it must be filtered out on read.
+    pub deletion_vector_location: Option<Location>,
 }
 
 #[typetag::serde(name = "fuse")]
@@ -79,6 +81,7 @@ impl FusePartInfo {
         sort_min_max: Option<(Scalar, Scalar)>,
         block_meta_index: Option<BlockMetaIndex>,
         create_on: Option<DateTime<Utc>>,
+        deletion_vector_location: Option<Location>,
     ) -> Arc<Box<dyn PartInfo>> {
         Arc::new(Box::new(FusePartInfo {
             location,
@@ -89,6 +92,7 @@ impl FusePartInfo {
             sort_min_max,
             block_meta_index,
             columns_stat,
+            deletion_vector_location,
         }))
     }
 
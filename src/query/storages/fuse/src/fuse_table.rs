@@ -14,6 +14,7 @@
 
 use std::any::Any;
 use std::collections::BTreeMap;
+use std::collections::HashMap;
 use std::str;
 use std::str::FromStr;
 use std::sync::Arc;
@@ -31,18 +32,23 @@ use databend_common_catalog::table::NavigationDescriptor;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::type_check::check_function;
 use databend_common_expression::BlockThresholds;
 use databend_common_expression::ColumnId;
+use databend_common_expression::Expr;
 use databend_common_expression::RemoteExpr;
+use databend_common_expression::Scalar;
 use databend_common_expression::ORIGIN_BLOCK_ID_COL_NAME;
 use databend_common_expression::ORIGIN_BLOCK_ROW_NUM_COL_NAME;
 use databend_common_expression::ORIGIN_VERSION_COL_NAME;
 use databend_common_expression::ROW_VERSION_COL_NAME;
 use databend_common_expression::SNAPSHOT_NAME_COLUMN_ID;
+use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_io::constants::DEFAULT_BLOCK_BUFFER_SIZE;
 use databend_common_io::constants::DEFAULT_BLOCK_MAX_ROWS;
 use databend_common_meta_app::schema::DatabaseType;
 use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
 use databend_common_meta_app::schema::UpdateStreamMetaReq;
 use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_pipeline_core::Pipeline;
@@ -59,13 +65,16 @@ use databend_storages_common_cache::LoadParams;
 use databend_storages_common_table_meta::meta::ClusterKey;
 use databend_storages_common_table_meta::meta::SnapshotId;
 use databend_storages_common_table_meta::meta::Statistics as FuseStatistics;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
 use databend_storages_common_table_meta::meta::Versioned;
 use databend_storages_common_table_meta::table::table_storage_prefix;
+use databend_storages_common_table_meta::table::ClusterType;
 use databend_storages_common_table_meta::table::TableCompression;
 use databend_storages_common_table_meta::table::OPT_KEY_BLOOM_INDEX_COLUMNS;
 use databend_storages_common_table_meta::table::OPT_KEY_CHANGE_TRACKING;
+use databend_storages_common_table_meta::table::OPT_KEY_CLUSTER_KEY_BOUNDS;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_ID;
 use databend_storages_common_table_meta::table::OPT_KEY_LEGACY_SNAPSHOT_LOC;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
@@ -94,9 +103,11 @@ use crate::DEFAULT_ROW_PER_PAGE;
 use crate::DEFAULT_ROW_PER_PAGE_FOR_BLOCKING;
 use crate::FUSE_OPT_KEY_BLOCK_IN_MEM_SIZE_THRESHOLD;
 use crate::FUSE_OPT_KEY_BLOCK_PER_SEGMENT;
+use crate::FUSE_OPT_KEY_MUTATION_MODE;
 use crate::FUSE_OPT_KEY_ROW_PER_BLOCK;
 use crate::FUSE_OPT_KEY_ROW_PER_PAGE;
 use crate::FUSE_TBL_LAST_SNAPSHOT_HINT;
+use crate::MUTATION_MODE_MERGE_ON_READ;
 
 #[derive(Clone)]
 pub struct FuseTable {
@@ -272,6 +283,17 @@ impl FuseTable {
         }
     }
 
+    /// Whether DELETE and UPDATE mark rows in deletion vectors instead of rewriting blocks.
+    /// Only tables of parquet storage format without change tracking support merge-on-read,
+    /// the others always fall back to copy-on-write.
+    pub fn merge_on_read_enabled(&self) -> bool {
+        !self.is_native()
+            && !self.change_tracking_enabled()
+            && self
+                .get_option(FUSE_OPT_KEY_MUTATION_MODE, String::new())
+                .eq_ignore_ascii_case(MUTATION_MODE_MERGE_ON_READ)
+    }
+
     pub fn parse_storage_prefix(table_info: &TableInfo) -> Result<String> {
         // if OPT_KE_STORAGE_PREFIX is specified, use it as storage prefix
         if let Some(prefix) = table_info.options().get(OPT_KEY_STORAGE_PREFIX) {
@@ -294,7 +316,7 @@ impl FuseTable {
     }
 
     pub fn table_snapshot_statistics_format_version(&self, location: &String) -> u64 {
-        TableMetaLocationGenerator::snapshot_version(location)
+        TableMetaLocationGenerator::snapshot_statistics_version(location)
     }
 
     #[minitrace::trace]
@@ -423,6 +445,12 @@ impl FuseTable {
         self.cluster_key_meta.as_ref().map(|(_, key)| key)
     }
 
+    pub fn cluster_type(&self) -> Option<ClusterType> {
+        self.cluster_key_meta
+            .as_ref()
+            .map(|(_, key)| ClusterType::split_cluster_key(key).0)
+    }
+
     pub fn cluster_key_id(&self) -> Option<u32> {
         self.cluster_key_meta.clone().map(|v| v.0)
     }
@@ -431,6 +459,138 @@ impl FuseTable {
         self.cluster_key_meta.clone()
     }
 
+    /// The ranges of the keys of a hilbert or z-order cluster key, `None` for a key of
+    /// which the range is unknown.
+    pub fn cluster_key_bounds(&self) -> Vec<Option<(Scalar, Scalar)>> {
+        self.table_info
+            .options()
+            .get(OPT_KEY_CLUSTER_KEY_BOUNDS)
+            .and_then(|v| serde_json::from_str(v).ok())
+            .unwrap_or_default()
+    }
+
+    // Only the keys which are columns have a range, it is taken from the column statistics.
+    fn collect_cluster_key_bounds(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        cluster_key: &str,
+        col_stats: &StatisticsOfColumns,
+    ) -> Result<Vec<Option<(Scalar, Scalar)>>> {
+        let (cluster_type, keys) = ClusterType::split_cluster_key(cluster_key);
+        if cluster_type == ClusterType::Linear {
+            return Ok(vec![]);
+        }
+
+        let schema = self.schema();
+        let exprs = parse_exprs(ctx, Arc::new(self.clone()), keys)?;
+        let exprs = if exprs.len() == 1 {
+            unwrap_tuple(&exprs[0]).unwrap_or(exprs)
+        } else {
+            exprs
+        };
+        Ok(exprs
+            .iter()
+            .map(|expr| match expr {
+                Expr::ColumnRef { id, .. } => col_stats
+                    .get(&schema.field(*id).column_id())
+                    .filter(|stat| !stat.min().is_null() && !stat.max().is_null())
+                    .map(|stat| (stat.min().clone(), stat.max().clone())),
+                _ => None,
+            })
+            .collect())
+    }
+
+    /// Refreshes the ranges of the keys of a hilbert or z-order cluster key if the data
+    /// outgrew them, returns whether they were refreshed.
+    ///
+    /// The cluster key gets a new id, so that the blocks written before, of which the keys
+    /// are scaled by the previous ranges, count as unclustered until they are reclustered.
+    #[async_backtrace::framed]
+    pub async fn refresh_cluster_key_bounds(&self, ctx: Arc<dyn TableContext>) -> Result<bool> {
+        let Some(cluster_key) = self.cluster_key_str() else {
+            return Ok(false);
+        };
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(false);
+        };
+
+        let bounds =
+            self.collect_cluster_key_bounds(ctx.clone(), cluster_key, &snapshot.summary.col_stats)?;
+        let prev_bounds = self.cluster_key_bounds();
+        let covered = bounds.len() == prev_bounds.len()
+            && bounds
+                .iter()
+                .zip(prev_bounds.iter())
+                .all(|(bound, prev)| match (bound, prev) {
+                    (None, _) => true,
+                    (Some((min, max)), Some((prev_min, prev_max))) => {
+                        prev_min <= min && max <= prev_max
+                    }
+                    (Some(_), None) => false,
+                });
+        if covered {
+            return Ok(false);
+        }
+
+        let mut new_table_meta = self.get_table_info().meta.clone();
+        new_table_meta = new_table_meta.push_cluster_key(cluster_key.clone());
+        new_table_meta.options.insert(
+            OPT_KEY_CLUSTER_KEY_BOUNDS.to_owned(),
+            serde_json::to_string(&bounds)?,
+        );
+        self.commit_table_meta(ctx, new_table_meta).await?;
+        Ok(true)
+    }
+
+    // Commits the table meta together with a new snapshot of the same data.
+    #[async_backtrace::framed]
+    async fn commit_table_meta(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        new_table_meta: TableMeta,
+    ) -> Result<()> {
+        let cluster_key_meta = new_table_meta.cluster_key();
+        let schema = self.schema().as_ref().clone();
+
+        let prev = self.read_table_snapshot().await?;
+        let prev_version = self.snapshot_format_version(None).await?;
+        let prev_timestamp = prev.as_ref().and_then(|v| v.timestamp);
+        let prev_snapshot_id = prev.as_ref().map(|v| (v.snapshot_id, prev_version));
+        let prev_statistics_location = prev
+            .as_ref()
+            .and_then(|v| v.table_statistics_location.clone());
+        let (summary, segments) = if let Some(v) = prev {
+            (v.summary.clone(), v.segments.clone())
+        } else {
+            (FuseStatistics::default(), vec![])
+        };
+
+        let new_snapshot = TableSnapshot::new(
+            Uuid::new_v4(),
+            &prev_timestamp,
+            prev_snapshot_id,
+            schema,
+            summary,
+            segments,
+            cluster_key_meta,
+            prev_statistics_location,
+        );
+
+        let mut table_info = self.table_info.clone();
+        table_info.meta = new_table_meta;
+
+        FuseTable::commit_to_meta_server(
+            ctx.as_ref(),
+            &table_info,
+            &self.meta_location_generator,
+            new_snapshot,
+            None,
+            &None,
+            &self.operator,
+        )
+        .await
+    }
+
     pub fn bloom_index_cols(&self) -> BloomIndexColumns {
         self.bloom_index_cols.clone()
     }
@@ -483,12 +643,54 @@ impl Table for FuseTable {
     fn cluster_keys(&self, ctx: Arc<dyn TableContext>) -> Vec<RemoteExpr<String>> {
         let table_meta = Arc::new(self.clone());
         if let Some((_, order)) = &self.cluster_key_meta {
-            let cluster_keys = parse_exprs(ctx, table_meta.clone(), order).unwrap();
+            let (cluster_type, keys) = ClusterType::split_cluster_key(order);
+            let cluster_keys = parse_exprs(ctx, table_meta.clone(), keys).unwrap();
             let cluster_keys = if cluster_keys.len() == 1 {
                 unwrap_tuple(&cluster_keys[0]).unwrap_or(cluster_keys)
             } else {
                 cluster_keys
             };
+            // Hilbert and Z-order layouts sort by a single key interleaving all the cluster keys,
+            // the keys of which the range is known are scaled to the same bit width first.
+            let cluster_keys = match cluster_type.key_function() {
+                Some(key_function) => {
+                    let bounds = self.cluster_key_bounds();
+                    let args = cluster_keys
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, key)| {
+                            let data_type = key.data_type().remove_nullable();
+                            match bounds.get(i) {
+                                // The bounds no longer fit the key if the type of its column
+                                // has changed, the key is then interleaved without scaling.
+                                Some(Some((min, max)))
+                                    if min.as_ref().is_value_of_type(&data_type)
+                                        && max.as_ref().is_value_of_type(&data_type) =>
+                                {
+                                    let [min, max] = [min, max].map(|scalar| Expr::Constant {
+                                        span: None,
+                                        scalar: scalar.clone(),
+                                        data_type: data_type.clone(),
+                                    });
+                                    check_function(
+                                        None,
+                                        "curve_coordinate",
+                                        &[],
+                                        &[key.clone(), min, max],
+                                        &BUILTIN_FUNCTIONS,
+                                    )
+                                    .unwrap_or(key)
+                                }
+                                _ => key,
+                            }
+                        })
+                        .collect::<Vec<_>>();
+                    vec![
+                        check_function(None, key_function, &[], &args, &BUILTIN_FUNCTIONS).unwrap(),
+                    ]
+                }
+                None => cluster_keys,
+            };
             let cluster_keys = cluster_keys
                 .iter()
                 .map(|k| {
@@ -542,47 +744,22 @@ impl Table for FuseTable {
             return Ok(());
         }
         let mut new_table_meta = self.get_table_info().meta.clone();
-        new_table_meta = new_table_meta.push_cluster_key(cluster_key_str);
-        let cluster_key_meta = new_table_meta.cluster_key();
-        let schema = self.schema().as_ref().clone();
-
-        let prev = self.read_table_snapshot().await?;
-        let prev_version = self.snapshot_format_version(None).await?;
-        let prev_timestamp = prev.as_ref().and_then(|v| v.timestamp);
-        let prev_snapshot_id = prev.as_ref().map(|v| (v.snapshot_id, prev_version));
-        let prev_statistics_location = prev
-            .as_ref()
-            .and_then(|v| v.table_statistics_location.clone());
-        let (summary, segments) = if let Some(v) = prev {
-            (v.summary.clone(), v.segments.clone())
-        } else {
-            (FuseStatistics::default(), vec![])
+        new_table_meta = new_table_meta.push_cluster_key(cluster_key_str.clone());
+        // The ranges of the keys of a hilbert or z-order layout are taken from the current data.
+        let col_stats = match self.read_table_snapshot().await? {
+            Some(snapshot) => snapshot.summary.col_stats.clone(),
+            None => StatisticsOfColumns::default(),
         };
-
-        let new_snapshot = TableSnapshot::new(
-            Uuid::new_v4(),
-            &prev_timestamp,
-            prev_snapshot_id,
-            schema,
-            summary,
-            segments,
-            cluster_key_meta,
-            prev_statistics_location,
-        );
-
-        let mut table_info = self.table_info.clone();
-        table_info.meta = new_table_meta;
-
-        FuseTable::commit_to_meta_server(
-            ctx.as_ref(),
-            &table_info,
-            &self.meta_location_generator,
-            new_snapshot,
-            None,
-            &None,
-            &self.operator,
-        )
-        .await
+        let bounds = self.collect_cluster_key_bounds(ctx.clone(), &cluster_key_str, &col_stats)?;
+        if bounds.is_empty() {
+            new_table_meta.options.remove(OPT_KEY_CLUSTER_KEY_BOUNDS);
+        } else {
+            new_table_meta.options.insert(
+                OPT_KEY_CLUSTER_KEY_BOUNDS.to_owned(),
+                serde_json::to_string(&bounds)?,
+            );
+        }
+        self.commit_table_meta(ctx, new_table_meta).await
     }
 
     #[async_backtrace::framed]
@@ -594,46 +771,8 @@ impl Table for FuseTable {
         let mut new_table_meta = self.get_table_info().meta.clone();
         new_table_meta.default_cluster_key = None;
         new_table_meta.default_cluster_key_id = None;
-
-        let schema = self.schema().as_ref().clone();
-
-        let prev = self.read_table_snapshot().await?;
-        let prev_version = self.snapshot_format_version(None).await?;
-        let prev_timestamp = prev.as_ref().and_then(|v| v.timestamp);
-        let prev_statistics_location = prev
-            .as_ref()
-            .and_then(|v| v.table_statistics_location.clone());
-        let prev_snapshot_id = prev.as_ref().map(|v| (v.snapshot_id, prev_version));
-        let (summary, segments) = if let Some(v) = prev {
-            (v.summary.clone(), v.segments.clone())
-        } else {
-            (FuseStatistics::default(), vec![])
-        };
-
-        let new_snapshot = TableSnapshot::new(
-            Uuid::new_v4(),
-            &prev_timestamp,
-            prev_snapshot_id,
-            schema,
-            summary,
-            segments,
-            None,
-            prev_statistics_location,
-        );
-
-        let mut table_info = self.table_info.clone();
-        table_info.meta = new_table_meta;
-
-        FuseTable::commit_to_meta_server(
-            ctx.as_ref(),
-            &table_info,
-            &self.meta_location_generator,
-            new_snapshot,
-            None,
-            &None,
-            &self.operator,
-        )
-        .await
+        new_table_meta.options.remove(OPT_KEY_CLUSTER_KEY_BOUNDS);
+        self.commit_table_meta(ctx, new_table_meta).await
     }
 
     #[minitrace::trace]
@@ -774,12 +913,14 @@ impl Table for FuseTable {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
                     Some(table_statistics.column_distinct_values.clone()),
+                    table_statistics.histograms.clone(),
                     snapshot.summary.row_count,
                 )
             } else {
                 FuseTableColumnStatisticsProvider::new(
                     stats.clone(),
                     None,
+                    HashMap::new(),
                     snapshot.summary.row_count,
                 )
             }
@@ -0,0 +1,88 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_arrow::arrow::bitmap::Bitmap;
+use databend_common_arrow::arrow::bitmap::MutableBitmap;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use opendal::Operator;
+use roaring::RoaringBitmap;
+
+/// Positions of the rows of a block which are deleted by merge-on-read mutations.
+///
+/// The positions are kept in a roaring bitmap, which is persisted as a standalone
+/// file and referenced by `BlockMeta::deletion_vector_location`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DeletionVector {
+    deleted_rows: RoaringBitmap,
+}
+
+impl DeletionVector {
+    pub const VERSION: u64 = 0;
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let deleted_rows = RoaringBitmap::deserialize_from(bytes).map_err(|e| {
+            ErrorCode::StorageOther(format!("failed to decode deletion vector: {}", e))
+        })?;
+        Ok(Self { deleted_rows })
+    }
+
+    pub fn to_bytes(&self) -> Result<Vec<u8>> {
+        let mut bytes = Vec::with_capacity(self.deleted_rows.serialized_size());
+        self.deleted_rows.serialize_into(&mut bytes)?;
+        Ok(bytes)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn read(operator: &Operator, location: &str) -> Result<Self> {
+        let bytes = operator.read(location).await?;
+        Self::from_bytes(&bytes)
+    }
+
+    pub fn blocking_read(operator: &Operator, location: &str) -> Result<Self> {
+        let bytes = operator.blocking().read(location)?;
+        Self::from_bytes(&bytes)
+    }
+
+    #[async_backtrace::framed]
+    pub async fn write(&self, operator: &Operator, location: &str) -> Result<()> {
+        operator.write(location, self.to_bytes()?).await?;
+        Ok(())
+    }
+
+    pub fn deleted_row_count(&self) -> u64 {
+        self.deleted_rows.len()
+    }
+
+    pub fn is_deleted(&self, row: usize) -> bool {
+        self.deleted_rows.contains(row as u32)
+    }
+
+    /// Marks the row as deleted, returns false if it has been deleted already.
+    pub fn delete(&mut self, row: usize) -> bool {
+        self.deleted_rows.insert(row as u32)
+    }
+
+    /// Returns a filter of the block rows, in which only the bits of live rows are set.
+    pub fn live_rows(&self, num_rows: usize) -> Bitmap {
+        let mut bitmap = MutableBitmap::from_len_set(num_rows);
+        for row in self.deleted_rows.iter() {
+            let row = row as usize;
+            if row < num_rows {
+                bitmap.set(row, false);
+            }
+        }
+        bitmap.into()
+    }
+}
@@ -222,6 +222,8 @@ impl BlockBuilder {
                 .unwrap_or_default(),
             compression: self.write_settings.table_compression.into(),
             create_on: Some(Utc::now()),
+            deletion_vector_location: None,
+            deleted_row_count: 0,
         };
 
         let serialized = BlockSerialization {
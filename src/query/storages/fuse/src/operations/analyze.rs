@@ -16,18 +16,33 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use std::time::Instant;
 
+use databend_common_catalog::plan::Projection;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::ColumnId;
+use databend_common_expression::Scalar;
+use databend_common_expression::ScalarRef;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
 use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::TableSnapshotStatistics;
 use log::warn;
+use rand::Rng;
 
+use crate::io::ReadSettings;
 use crate::io::SegmentsIO;
+use crate::statistics::build_column_histogram;
 use crate::statistics::reduce_block_statistics;
 use crate::statistics::reduce_cluster_statistics;
+use crate::statistics::support_histogram;
+use crate::statistics::HISTOGRAM_NUM_BUCKETS;
+use crate::statistics::HISTOGRAM_NUM_MOST_COMMON_VALUES;
+use crate::statistics::HISTOGRAM_SAMPLE_BLOCKS;
+use crate::statistics::HISTOGRAM_SAMPLE_ROWS;
 use crate::FuseTable;
 
 impl FuseTable {
@@ -59,6 +74,8 @@ impl FuseTable {
             let mut read_segment_count = 0;
             let mut col_stats = HashMap::new();
             let mut cluster_stats = None;
+            // reservoir of the blocks sampled to build histograms
+            let mut sampled_blocks: Vec<Arc<BlockMeta>> = Vec::new();
 
             let start = Instant::now();
             let segments_io = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
@@ -75,15 +92,24 @@ impl FuseTable {
                 let segments = segments_io
                     .read_segments::<SegmentInfo>(chunk, true)
                     .await?;
+                let mut rng = rand::thread_rng();
                 for segment in segments {
                     let segment = segment?;
                     stats_of_columns.push(segment.summary.col_stats.clone());
                     blocks_cluster_stats.push(segment.summary.cluster_stats.clone());
-                    segment.blocks.iter().for_each(|block| {
-                        let block = block.as_ref();
+                    segment.blocks.iter().for_each(|block_meta| {
+                        let block = block_meta.as_ref();
                         let row_count = block.row_count;
                         if row_count != 0 {
                             block_count_sum += 1;
+                            if sampled_blocks.len() < HISTOGRAM_SAMPLE_BLOCKS {
+                                sampled_blocks.push(block_meta.clone());
+                            } else {
+                                let idx = rng.gen_range(0..block_count_sum as usize);
+                                if idx < HISTOGRAM_SAMPLE_BLOCKS {
+                                    sampled_blocks[idx] = block_meta.clone();
+                                }
+                            }
                             row_count_sum += row_count;
                             for (i, col_stat) in block.col_stats.iter() {
                                 let density = col_stat
@@ -127,8 +153,13 @@ impl FuseTable {
                 ndv_map.insert(*i, (density_avg * row_count_sum as f64) as u64);
             }
 
-            // 3. Generate new table statistics
-            let table_statistics = TableSnapshotStatistics::new(ndv_map);
+            // 3. Sample blocks to build histograms
+            let histograms = self
+                .build_histograms(ctx, &sampled_blocks, &col_stats, &ndv_map, row_count_sum)
+                .await?;
+
+            // 4. Generate new table statistics
+            let table_statistics = TableSnapshotStatistics::new(ndv_map, histograms);
             let table_statistics_location = self
                 .meta_location_generator
                 .snapshot_statistics_location_from_uuid(
@@ -136,7 +167,7 @@ impl FuseTable {
                     table_statistics.format_version(),
                 )?;
 
-            // 4. Save table statistics
+            // 5. Save table statistics
             let mut new_snapshot = TableSnapshot::from_previous(&snapshot);
             new_snapshot.summary.col_stats = col_stats;
             new_snapshot.summary.cluster_stats = cluster_stats;
@@ -155,4 +186,89 @@ impl FuseTable {
 
         Ok(())
     }
+
+    #[async_backtrace::framed]
+    async fn build_histograms(
+        &self,
+        ctx: &Arc<dyn TableContext>,
+        sampled_blocks: &[Arc<BlockMeta>],
+        col_stats: &StatisticsOfColumns,
+        ndv_map: &HashMap<ColumnId, u64>,
+        row_count: u64,
+    ) -> Result<HashMap<ColumnId, ColumnHistogram>> {
+        let schema = self.schema();
+        let (field_indices, column_ids): (Vec<_>, Vec<_>) = schema
+            .fields()
+            .iter()
+            .enumerate()
+            .filter(|(_, field)| support_histogram(field.data_type()))
+            .map(|(idx, field)| (idx, field.column_id()))
+            .unzip();
+        if field_indices.is_empty() || sampled_blocks.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let block_reader = self.create_block_reader(
+            ctx.clone(),
+            Projection::Columns(field_indices),
+            false,
+            false,
+            false,
+        )?;
+        let settings = ReadSettings::from_ctx(ctx)?;
+        let rows_per_block = (HISTOGRAM_SAMPLE_ROWS / sampled_blocks.len()).max(1);
+        let mut samples: Vec<Vec<Scalar>> = vec![Vec::new(); column_ids.len()];
+
+        let chunk_size = ctx.get_settings().get_max_threads()? as usize;
+        let start = Instant::now();
+        let mut read_block_count = 0;
+        for chunk in sampled_blocks.chunks(chunk_size) {
+            let blocks = futures::future::try_join_all(chunk.iter().map(|meta| {
+                block_reader.read_by_meta(&settings, meta.as_ref(), &self.storage_format)
+            }))
+            .await?;
+
+            for block in blocks {
+                let num_rows = block.num_rows();
+                let step = (num_rows / rows_per_block).max(1);
+                for (entry, column_samples) in block.columns().iter().zip(samples.iter_mut()) {
+                    for row in (0..num_rows).step_by(step) {
+                        match entry.value.index(row) {
+                            Some(ScalarRef::Null) | None => {}
+                            Some(value) => column_samples.push(value.to_owned()),
+                        }
+                    }
+                }
+            }
+
+            // Status.
+            {
+                read_block_count += chunk.len();
+                let status = format!(
+                    "analyze: sample blocks:{}/{}, cost:{} sec",
+                    read_block_count,
+                    sampled_blocks.len(),
+                    start.elapsed().as_secs()
+                );
+                ctx.set_status_info(&status);
+            }
+        }
+
+        let mut histograms = HashMap::new();
+        for (column_id, column_samples) in column_ids.into_iter().zip(samples) {
+            let null_count = col_stats.get(&column_id).map_or(0, |s| s.null_count);
+            let num_rows = row_count.saturating_sub(null_count);
+            let ndv = ndv_map.get(&column_id).copied().unwrap_or(num_rows);
+            if let Some(histogram) = build_column_histogram(
+                column_samples,
+                num_rows,
+                ndv,
+                HISTOGRAM_NUM_BUCKETS,
+                HISTOGRAM_NUM_MOST_COMMON_VALUES,
+            ) {
+                histograms.insert(column_id, histogram);
+            }
+        }
+        Ok(histograms)
+    }
 }
@@ -223,7 +223,18 @@ impl FuseTable {
             update_stream_meta: update_stream_meta.to_vec(),
         };
 
-        // 3. let's roll
+        // 3. inside an explicit transaction, the table meta is committed at COMMIT
+        {
+            let txn_mgr = ctx.txn_mgr();
+            let mut txn_mgr = txn_mgr.lock();
+            if txn_mgr.is_active() {
+                txn_mgr.update_table_meta(table_info, req);
+                TableSnapshot::cache().put(snapshot_location, Arc::new(snapshot));
+                return Ok(());
+            }
+        }
+
+        // 4. let's roll
         let reply = catalog.update_table_meta(table_info, req).await;
         match reply {
             Ok(_) => {
@@ -250,6 +261,50 @@ impl FuseTable {
         }
     }
 
+    /// Removes the historical data of a transient table, keeping the last snapshot.
+    #[async_backtrace::framed]
+    pub async fn purge_transient_history(&self, ctx: &Arc<dyn TableContext>) -> Result<()> {
+        warn!(
+            "transient table detected, purging historical data. ({})",
+            self.table_info.ident
+        );
+
+        let keep_last_snapshot = true;
+        let snapshot_files = self.list_snapshot_files().await?;
+        if let Err(e) = self
+            .do_purge(ctx, snapshot_files, None, keep_last_snapshot, false)
+            .await
+        {
+            // Errors of GC, if any, are ignored, since GC task can be picked up
+            warn!(
+                "GC of transient table not success (this is not a permanent error). the error : {}",
+                e
+            );
+        } else {
+            info!("GC of transient table done");
+        }
+        Ok(())
+    }
+
+    /// Finishes the commit of the table by an explicit transaction, once the
+    /// transaction is committed: leaves the hint of the last snapshot and
+    /// purges the historical data of transient table.
+    #[async_backtrace::framed]
+    pub async fn post_txn_commit(&self, ctx: &Arc<dyn TableContext>) -> Result<()> {
+        if let Some(snapshot_location) = self.snapshot_loc().await? {
+            Self::write_last_snapshot_hint(
+                &self.operator,
+                &self.meta_location_generator,
+                snapshot_location,
+            )
+            .await;
+        }
+        if self.transient() {
+            self.purge_transient_history(ctx).await?;
+        }
+        Ok(())
+    }
+
     // Left a hint file which indicates the location of the latest snapshot
     #[async_backtrace::framed]
     pub async fn write_last_snapshot_hint(
@@ -23,6 +23,7 @@ use databend_common_expression::DataBlock;
 use databend_common_pipeline_transforms::processors::AccumulatingTransform;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::FormatVersion;
+use databend_storages_common_table_meta::meta::Location;
 use databend_storages_common_table_meta::meta::Statistics;
 
 use crate::operations::common::AbortOperation;
@@ -60,6 +61,14 @@ pub enum MutationLogEntry {
     CompactExtras {
         extras: CompactExtraInfo,
     },
+    /// Rows of the block are marked as deleted by a new deletion vector (merge-on-read),
+    /// the updated rows, if any, are written to the appended block.
+    DeletedRows {
+        index: BlockMetaIndex,
+        deletion_vector_location: Location,
+        deleted_row_count: u64,
+        appended_block: Option<Arc<BlockMeta>>,
+    },
     DoNothing,
 }
 
@@ -208,6 +217,12 @@ fn merge_commit_meta(
                 .into_iter()
                 .chain(r.abort_operation.bloom_filter_indexes)
                 .collect(),
+            deletion_vectors: l
+                .abort_operation
+                .deletion_vectors
+                .into_iter()
+                .chain(r.abort_operation.deletion_vectors)
+                .collect(),
         },
     }
 }
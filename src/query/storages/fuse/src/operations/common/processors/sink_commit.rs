@@ -42,8 +42,6 @@ use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::meta::Versioned;
 use log::debug;
 use log::error;
-use log::info;
-use log::warn;
 use opendal::Operator;
 
 use crate::io::TableMetaLocationGenerator;
@@ -342,36 +340,15 @@ where F: SnapshotGenerator + Send + 'static
                 .await
                 {
                     Ok(_) => {
-                        if self.transient {
+                        // Inside an explicit transaction the commit is only buffered,
+                        // the table still points to the snapshot before the transaction.
+                        // The historical data is purged once the transaction is committed.
+                        let in_txn = self.ctx.txn_mgr().lock().is_active();
+                        if self.transient && !in_txn {
                             // Removes historical data, if table is transient
                             let latest = self.table.refresh(self.ctx.as_ref()).await?;
                             let tbl = FuseTable::try_from_table(latest.as_ref())?;
-
-                            warn!(
-                                "transient table detected, purging historical data. ({})",
-                                tbl.table_info.ident
-                            );
-
-                            let keep_last_snapshot = true;
-                            let snapshot_files = tbl.list_snapshot_files().await?;
-                            if let Err(e) = tbl
-                                .do_purge(
-                                    &self.ctx,
-                                    snapshot_files,
-                                    None,
-                                    keep_last_snapshot,
-                                    false,
-                                )
-                                .await
-                            {
-                                // Errors of GC, if any, are ignored, since GC task can be picked up
-                                warn!(
-                                    "GC of transient table not success (this is not a permanent error). the error : {}",
-                                    e
-                                );
-                            } else {
-                                info!("GC of transient table done");
-                            }
+                            tbl.purge_transient_history(&self.ctx).await?;
                         }
                         metrics_inc_commit_mutation_success();
                         {
@@ -21,6 +21,7 @@ use std::time::Instant;
 use databend_common_base::runtime::execute_futures_in_parallel;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockMetaInfoPtr;
 use databend_common_expression::BlockThresholds;
@@ -70,6 +71,9 @@ pub struct TableMutationAggregator {
     removed_segment_indexes: Vec<SegmentIndex>,
     removed_statistics: Statistics,
     abort_operation: AbortOperation,
+    // blocks appended by merge-on-read update, packed into new segments on apply.
+    appended_blocks: Vec<Arc<BlockMeta>>,
+    block_per_seg: usize,
 
     kind: MutationKind,
     start_time: Instant,
@@ -122,6 +126,8 @@ impl TableMutationAggregator {
             appended_statistics: Statistics::default(),
             removed_segment_indexes: vec![],
             removed_statistics: Statistics::default(),
+            appended_blocks: vec![],
+            block_per_seg: table.get_write_settings().block_per_seg,
             kind,
             finished_tasks: 0,
             start_time: Instant::now(),
@@ -196,6 +202,7 @@ impl TableMutationAggregator {
                         v.insert(BlockMutations {
                             replaced_blocks: extras.unchanged_blocks,
                             deleted_blocks: vec![],
+                            deleted_rows: vec![],
                         });
                     }
                 }
@@ -208,10 +215,34 @@ impl TableMutationAggregator {
                     self.default_cluster_key_id,
                 );
             }
+            MutationLogEntry::DeletedRows {
+                index,
+                deletion_vector_location,
+                deleted_row_count,
+                appended_block,
+            } => {
+                self.abort_operation
+                    .add_deletion_vector(deletion_vector_location.0.clone());
+                if let Some(block_meta) = appended_block {
+                    self.abort_operation.add_block(&block_meta);
+                    self.appended_blocks.push(block_meta);
+                }
+                self.mutations
+                    .entry(index.segment_idx)
+                    .or_default()
+                    .push_deleted_rows(
+                        index.block_idx,
+                        deletion_vector_location,
+                        deleted_row_count,
+                    );
+            }
         }
     }
 
     pub async fn apply(&mut self) -> Result<CommitMeta> {
+        if !self.appended_blocks.is_empty() {
+            self.write_appended_blocks().await?;
+        }
         let appended_segments = std::mem::take(&mut self.appended_segments);
         let appended_statistics = std::mem::take(&mut self.appended_statistics);
         let conflict_resolve_context = match self.kind {
@@ -326,6 +357,14 @@ impl TableMutationAggregator {
                     for idx in segment_mutation.deleted_blocks {
                         block_editor.remove(&idx);
                     }
+                    for (idx, location, deleted_row_count) in segment_mutation.deleted_rows {
+                        if let Some(block_meta) = block_editor.get_mut(&idx) {
+                            let mut new_meta = block_meta.as_ref().clone();
+                            new_meta.deletion_vector_location = Some(location);
+                            new_meta.deleted_row_count = deleted_row_count;
+                            *block_meta = Arc::new(new_meta);
+                        }
+                    }
 
                     if block_editor.is_empty() {
                         return Ok(SegmentLite {
@@ -341,6 +380,7 @@ impl TableMutationAggregator {
                 } else {
                     // use by compact.
                     assert!(segment_mutation.deleted_blocks.is_empty());
+                    assert!(segment_mutation.deleted_rows.is_empty());
                     // There are more than 1 blocks, means that the blocks can no longer be compacted.
                     // They can be marked as perfect blocks.
                     all_perfect = segment_mutation.replaced_blocks.len() > 1;
@@ -397,12 +437,62 @@ impl TableMutationAggregator {
         .into_iter()
         .collect::<Result<Vec<_>>>()
     }
+
+    // Pack the blocks appended by merge-on-read update into new segments.
+    async fn write_appended_blocks(&mut self) -> Result<()> {
+        let appended_blocks = std::mem::take(&mut self.appended_blocks);
+        let thresholds = self.thresholds;
+        let default_cluster_key_id = self.default_cluster_key_id;
+        let mut tasks = Vec::new();
+        for chunk in &appended_blocks.into_iter().chunks(self.block_per_seg) {
+            let new_blocks = chunk.collect::<Vec<_>>();
+            let op = self.dal.clone();
+            let location_gen = self.location_gen.clone();
+            tasks.push(async move {
+                let location = location_gen.gen_segment_info_location();
+                let new_summary =
+                    reduce_block_metas(&new_blocks, thresholds, default_cluster_key_id);
+                let new_segment = SegmentInfo::new(new_blocks, new_summary.clone());
+                let serialized_segment = SerializedSegment {
+                    path: location.clone(),
+                    segment: Arc::new(new_segment),
+                };
+                SegmentsIO::write_segment(op, serialized_segment).await?;
+                Ok::<_, ErrorCode>((location, new_summary))
+            });
+        }
+
+        let threads_nums = self.ctx.get_settings().get_max_threads()? as usize;
+        let results = execute_futures_in_parallel(
+            tasks,
+            threads_nums,
+            threads_nums * 2,
+            "fuse-write-segments-worker".to_owned(),
+        )
+        .await?
+        .into_iter()
+        .collect::<Result<Vec<_>>>()?;
+
+        for (location, summary) in results {
+            self.abort_operation.add_segment(location.clone());
+            merge_statistics_mut(
+                &mut self.appended_statistics,
+                &summary,
+                self.default_cluster_key_id,
+            );
+            self.appended_segments
+                .push((location, SegmentInfo::VERSION));
+        }
+        Ok(())
+    }
 }
 
 #[derive(Default)]
 struct BlockMutations {
     replaced_blocks: Vec<(BlockIndex, Arc<BlockMeta>)>,
     deleted_blocks: Vec<BlockIndex>,
+    // blocks whose deleted rows are recorded by a new deletion vector.
+    deleted_rows: Vec<(BlockIndex, Location, u64)>,
 }
 
 impl BlockMutations {
@@ -410,6 +500,7 @@ impl BlockMutations {
         BlockMutations {
             replaced_blocks: vec![(block_idx, block_meta)],
             deleted_blocks: vec![],
+            deleted_rows: vec![],
         }
     }
 
@@ -417,6 +508,7 @@ impl BlockMutations {
         BlockMutations {
             replaced_blocks: vec![],
             deleted_blocks: vec![block_idx],
+            deleted_rows: vec![],
         }
     }
 
@@ -427,6 +519,10 @@ impl BlockMutations {
     fn push_deleted(&mut self, block_idx: BlockIndex) {
         self.deleted_blocks.push(block_idx)
     }
+
+    fn push_deleted_rows(&mut self, block_idx: BlockIndex, location: Location, row_count: u64) {
+        self.deleted_rows.push((block_idx, location, row_count))
+    }
 }
 
 struct SegmentLite {
@@ -21,10 +21,18 @@ use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_common_expression::ComputedExpr;
 use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::FunctionContext;
 use databend_common_expression::TableSchema;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_meta_app::schema::TableConstraint;
 use databend_common_metrics::storage::*;
 use databend_common_pipeline_core::processors::Event;
 use databend_common_pipeline_core::processors::InputPort;
@@ -33,6 +41,7 @@ use databend_common_pipeline_core::processors::Processor;
 use databend_common_pipeline_core::processors::ProcessorPtr;
 use databend_common_pipeline_core::PipeItem;
 use databend_common_sql::executor::physical_plans::MutationKind;
+use databend_common_sql::parse_computed_expr;
 use databend_storages_common_index::BloomIndex;
 use opendal::Operator;
 
@@ -43,6 +52,7 @@ use crate::operations::common::BlockMetaIndex;
 use crate::operations::common::MutationLogEntry;
 use crate::operations::common::MutationLogs;
 use crate::operations::mutation::ClusterStatsGenType;
+use crate::operations::mutation::DeletedRowsInfo;
 use crate::operations::mutation::SerializeDataMeta;
 use crate::statistics::ClusterStatsGenerator;
 use crate::FuseTable;
@@ -54,10 +64,12 @@ enum State {
         block: DataBlock,
         stats_type: ClusterStatsGenType,
         index: Option<BlockMetaIndex>,
+        deleted_rows: Option<DeletedRowsInfo>,
     },
     Serialized {
         serialized: BlockSerialization,
         index: Option<BlockMetaIndex>,
+        deleted_rows: Option<DeletedRowsInfo>,
     },
 }
 
@@ -69,6 +81,10 @@ pub struct TransformSerializeBlock {
 
     block_builder: BlockBuilder,
     dal: Operator,
+
+    // The CHECK constraints to be satisfied by the written rows.
+    check_constraints: Vec<(String, Expr)>,
+    func_ctx: FunctionContext,
 }
 
 impl TransformSerializeBlock {
@@ -99,6 +115,19 @@ impl TransformSerializeBlock {
             ..table.schema().as_ref().clone()
         });
 
+        let check_constraints = if matches!(
+            kind,
+            MutationKind::Insert
+                | MutationKind::Update
+                | MutationKind::Replace
+                | MutationKind::MergeInto
+        ) {
+            Self::build_check_constraints(ctx.clone(), table, &source_schema)?
+        } else {
+            vec![]
+        };
+        let func_ctx = ctx.get_function_context()?;
+
         let bloom_columns_map = table
             .bloom_index_cols
             .bloom_index_fields(source_schema.clone(), BloomIndex::supported_type)?;
@@ -117,9 +146,57 @@ impl TransformSerializeBlock {
             output_data: None,
             block_builder,
             dal: table.get_operator(),
+            check_constraints,
+            func_ctx,
         })
     }
 
+    fn build_check_constraints(
+        ctx: Arc<dyn TableContext>,
+        table: &FuseTable,
+        source_schema: &Arc<TableSchema>,
+    ) -> Result<Vec<(String, Expr)>> {
+        let schema = Arc::new(DataSchema::from(source_schema.clone()));
+        let mut check_constraints = vec![];
+        for (name, constraint) in table.get_table_info().meta.constraints.iter() {
+            if let TableConstraint::Check { expression } = constraint {
+                // A row violates the constraint only if the check is false, NULL is allowed.
+                let sql = format!("coalesce(({}), true)", expression);
+                let expr = parse_computed_expr(ctx.clone(), schema.clone(), &sql)?;
+                check_constraints.push((name.clone(), expr));
+            }
+        }
+        Ok(check_constraints)
+    }
+
+    fn check_constraints(&self, block: &DataBlock) -> Result<()> {
+        if self.check_constraints.is_empty() {
+            return Ok(());
+        }
+
+        let evaluator = Evaluator::new(block, &self.func_ctx, &BUILTIN_FUNCTIONS);
+        for (name, expr) in self.check_constraints.iter() {
+            let result = evaluator.run(expr)?.try_downcast::<BooleanType>().unwrap();
+            let violated_rows = match &result {
+                Value::Scalar(v) => {
+                    if *v {
+                        0
+                    } else {
+                        block.num_rows()
+                    }
+                }
+                Value::Column(bitmap) => bitmap.unset_bits(),
+            };
+            if violated_rows != 0 {
+                return Err(ErrorCode::CheckConstraintViolation(format!(
+                    "{} row(s) violate CHECK constraint `{}`",
+                    violated_rows, name
+                )));
+            }
+        }
+        Ok(())
+    }
+
     pub fn into_processor(self) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(self)))
     }
@@ -212,6 +289,7 @@ impl Processor for TransformSerializeBlock {
                             block: input_data,
                             stats_type: serialize_block.stats_type,
                             index: Some(serialize_block.index),
+                            deleted_rows: None,
                         };
                         Ok(Event::Sync)
                     }
@@ -224,6 +302,28 @@ impl Processor for TransformSerializeBlock {
                     self.output.push_data(Ok(data_block));
                     Ok(Event::NeedConsume)
                 }
+                SerializeDataMeta::DeletedRows(deleted_rows) => {
+                    if input_data.is_empty() {
+                        // rows deleted by deletion vector, nothing to append
+                        let data_block = Self::mutation_logs(MutationLogEntry::DeletedRows {
+                            index: deleted_rows.index,
+                            deletion_vector_location: deleted_rows.deletion_vector_location,
+                            deleted_row_count: deleted_rows.deleted_row_count,
+                            appended_block: None,
+                        });
+                        self.output.push_data(Ok(data_block));
+                        Ok(Event::NeedConsume)
+                    } else {
+                        // the updated rows are appended as a new block
+                        self.state = State::NeedSerialize {
+                            block: input_data,
+                            stats_type: ClusterStatsGenType::Generally,
+                            index: None,
+                            deleted_rows: Some(deleted_rows),
+                        };
+                        Ok(Event::Sync)
+                    }
+                }
             }
         } else if input_data.is_empty() {
             // do nothing
@@ -236,6 +336,7 @@ impl Processor for TransformSerializeBlock {
                 block: input_data,
                 stats_type: ClusterStatsGenType::Generally,
                 index: None,
+                deleted_rows: None,
             };
             Ok(Event::Sync)
         }
@@ -247,9 +348,11 @@ impl Processor for TransformSerializeBlock {
                 block,
                 stats_type,
                 index,
+                deleted_rows,
             } => {
                 // Check if the datablock is valid, this is needed to ensure data is correct
                 block.check_valid()?;
+                self.check_constraints(&block)?;
 
                 let serialized =
                     self.block_builder
@@ -262,7 +365,11 @@ impl Processor for TransformSerializeBlock {
                             }
                         })?;
 
-                self.state = State::Serialized { serialized, index };
+                self.state = State::Serialized {
+                    serialized,
+                    index,
+                    deleted_rows,
+                };
             }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
@@ -272,7 +379,11 @@ impl Processor for TransformSerializeBlock {
     #[async_backtrace::framed]
     async fn async_process(&mut self) -> Result<()> {
         match std::mem::replace(&mut self.state, State::Consume) {
-            State::Serialized { serialized, index } => {
+            State::Serialized {
+                serialized,
+                index,
+                deleted_rows,
+            } => {
                 let start = Instant::now();
                 // write block data.
                 let raw_block_data = serialized.block_raw_data;
@@ -307,7 +418,14 @@ impl Processor for TransformSerializeBlock {
                     }
                 }
 
-                let data_block = if let Some(index) = index {
+                let data_block = if let Some(deleted_rows) = deleted_rows {
+                    Self::mutation_logs(MutationLogEntry::DeletedRows {
+                        index: deleted_rows.index,
+                        deletion_vector_location: deleted_rows.deletion_vector_location,
+                        deleted_row_count: deleted_rows.deleted_row_count,
+                        appended_block: Some(Arc::new(serialized.block_meta)),
+                    })
+                } else if let Some(index) = index {
                     Self::mutation_logs(MutationLogEntry::ReplacedBlock {
                         index,
                         block_meta: Arc::new(serialized.block_meta),
@@ -223,6 +223,9 @@ impl FuseTable {
         let max_threads = (ctx.get_settings().get_max_threads()? as usize)
             .min(ctx.partition_num())
             .max(1);
+        let deletion_vector_location_gen = self
+            .merge_on_read_enabled()
+            .then(|| self.meta_location_generator().clone());
         // Add source pipe.
         pipeline.add_source(
             |output| {
@@ -236,6 +239,7 @@ impl FuseTable {
                     ops.clone(),
                     self.storage_format,
                     query_row_id_col,
+                    deletion_vector_location_gen.clone(),
                 )
             },
             max_threads,
@@ -22,6 +22,7 @@ use databend_common_catalog::table::Table;
 use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
+use databend_common_meta_app::schema::IndexType;
 use databend_common_meta_app::schema::ListIndexesByIdReq;
 use databend_storages_common_cache::CacheAccessor;
 use databend_storages_common_cache::LoadParams;
@@ -81,12 +82,15 @@ impl FuseTable {
         let mut purged_snapshot_count = 0;
 
         let catalog = ctx.get_catalog(&ctx.get_current_catalog()).await?;
-        let table_agg_index_ids = catalog
-            .list_index_ids_by_table_id(ListIndexesByIdReq {
+        let table_indexes = catalog
+            .list_indexes_by_table_id(ListIndexesByIdReq {
                 tenant: ctx.get_tenant(),
                 table_id: self.get_id(),
             })
-            .await?;
+            .await?
+            .into_iter()
+            .map(|(index_id, _, index_meta)| (index_id, index_meta.index_type))
+            .collect::<Vec<_>>();
 
         // 2. Read snapshot fields by chunk size.
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
@@ -169,7 +173,7 @@ impl FuseTable {
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
-                        &table_agg_index_ids,
+                        &table_indexes,
                     )
                     .await?;
 
@@ -184,7 +188,7 @@ impl FuseTable {
                         segments_to_be_purged,
                         ts_to_be_purged,
                         snapshots_to_be_purged,
-                        &table_agg_index_ids,
+                        &table_indexes,
                     )
                     .await?;
 
@@ -224,7 +228,7 @@ impl FuseTable {
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
-                    &table_agg_index_ids,
+                    &table_indexes,
                 )
                 .await?;
             } else {
@@ -235,7 +239,7 @@ impl FuseTable {
                     segments_to_be_purged,
                     ts_to_be_purged,
                     snapshots_to_be_purged,
-                    &table_agg_index_ids,
+                    &table_indexes,
                 )
                 .await?;
             }
@@ -253,7 +257,7 @@ impl FuseTable {
                 root_snapshot_info.snapshot_lite,
                 root_snapshot_info.referenced_locations,
                 root_snapshot_info.snapshot_location,
-                &table_agg_index_ids,
+                &table_indexes,
             )
             .await?;
         }
@@ -319,7 +323,7 @@ impl FuseTable {
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
-        table_agg_index_ids: &[u64],
+        table_indexes: &[(u64, IndexType)],
     ) -> Result<()> {
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
         // Purge segments&blocks by chunk size
@@ -335,13 +339,7 @@ impl FuseTable {
                     continue;
                 }
                 purge_files.push(loc.to_string());
-                for index_id in table_agg_index_ids {
-                    purge_files.push(
-                        TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
-                            loc, *index_id,
-                        ),
-                    )
-                }
+                purge_files.extend(index_locations_of_block(loc, table_indexes));
             }
 
             for loc in &locations.bloom_location {
@@ -351,6 +349,16 @@ impl FuseTable {
                 purge_files.push(loc.to_string())
             }
 
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                {
+                    continue;
+                }
+                purge_files.push(loc.to_string())
+            }
+
             purge_files.extend(chunk.iter().map(|loc| loc.0.clone()));
         }
         purge_files.extend(ts_to_be_purged.iter().map(|loc| loc.to_string()));
@@ -368,7 +376,7 @@ impl FuseTable {
         segments_to_be_purged: HashSet<Location>,
         ts_to_be_purged: HashSet<String>,
         snapshots_to_be_purged: HashSet<String>,
-        table_agg_index_ids: &[u64],
+        table_indexes: &[(u64, IndexType)],
     ) -> Result<()> {
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
         // Purge segments&blocks by chunk size
@@ -381,19 +389,13 @@ impl FuseTable {
                 .await?;
 
             let mut blocks_to_be_purged = HashSet::new();
-            let mut agg_indexes_to_be_purged = HashSet::new();
+            let mut indexes_to_be_purged = HashSet::new();
             for loc in &locations.block_location {
                 if locations_referenced_by_root.block_location.contains(loc) {
                     continue;
                 }
                 blocks_to_be_purged.insert(loc.to_string());
-                for index_id in table_agg_index_ids {
-                    agg_indexes_to_be_purged.insert(
-                        TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
-                            loc, *index_id,
-                        ),
-                    );
-                }
+                indexes_to_be_purged.extend(index_locations_of_block(loc, table_indexes));
             }
 
             let mut blooms_to_be_purged = HashSet::new();
@@ -404,6 +406,17 @@ impl FuseTable {
                 blooms_to_be_purged.insert(loc.to_string());
             }
 
+            // deletion vectors are purged along with the indexes.
+            for loc in &locations.deletion_vector_location {
+                if locations_referenced_by_root
+                    .deletion_vector_location
+                    .contains(loc)
+                {
+                    continue;
+                }
+                indexes_to_be_purged.insert(loc.to_string());
+            }
+
             let segment_locations_to_be_purged = HashSet::from_iter(
                 chunk
                     .iter()
@@ -427,7 +440,7 @@ impl FuseTable {
                 ctx,
                 counter,
                 blocks_to_be_purged,
-                agg_indexes_to_be_purged,
+                indexes_to_be_purged,
                 blooms_to_be_purged,
                 segment_locations_to_be_purged,
             )
@@ -445,7 +458,7 @@ impl FuseTable {
         root_snapshot: Arc<SnapshotLiteExtended>,
         root_location_tuple: LocationTuple,
         root_snapshot_location: String,
-        table_agg_index_ids: &[u64],
+        table_indexes: &[(u64, IndexType)],
     ) -> Result<()> {
         let segment_locations_to_be_purged = HashSet::from_iter(
             root_snapshot
@@ -455,20 +468,18 @@ impl FuseTable {
                 .collect::<Vec<_>>(),
         );
 
-        let mut agg_indexes_to_be_purged = HashSet::new();
-        for index_id in table_agg_index_ids {
-            agg_indexes_to_be_purged.extend(root_location_tuple.block_location.iter().map(|loc| {
-                TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
-                    loc, *index_id,
-                )
-            }));
-        }
+        let indexes_to_be_purged = root_location_tuple
+            .block_location
+            .iter()
+            .flat_map(|loc| index_locations_of_block(loc, table_indexes))
+            .chain(root_location_tuple.deletion_vector_location)
+            .collect();
 
         self.purge_block_segments(
             ctx,
             counter,
             root_location_tuple.block_location,
-            agg_indexes_to_be_purged,
+            indexes_to_be_purged,
             root_location_tuple.bloom_location,
             segment_locations_to_be_purged,
         )
@@ -492,7 +503,7 @@ impl FuseTable {
         ctx: &Arc<dyn TableContext>,
         counter: &mut PurgeCounter,
         blocks_to_be_purged: HashSet<String>,
-        agg_indexes_to_be_purged: HashSet<String>,
+        indexes_to_be_purged: HashSet<String>,
         blooms_to_be_purged: HashSet<String>,
         segments_to_be_purged: HashSet<String>,
     ) -> Result<()> {
@@ -504,10 +515,10 @@ impl FuseTable {
                 .await?;
         }
 
-        let agg_index_count = agg_indexes_to_be_purged.len();
-        if agg_index_count > 0 {
-            counter.agg_indexes += agg_index_count;
-            self.try_purge_location_files(ctx.clone(), agg_indexes_to_be_purged)
+        let index_count = indexes_to_be_purged.len();
+        if index_count > 0 {
+            counter.agg_indexes += index_count;
+            self.try_purge_location_files(ctx.clone(), indexes_to_be_purged)
                 .await?;
         }
 
@@ -623,6 +634,7 @@ impl FuseTable {
     ) -> Result<LocationTuple> {
         let mut blocks = HashSet::new();
         let mut blooms = HashSet::new();
+        let mut deletion_vectors = HashSet::new();
 
         let fuse_segments = SegmentsIO::create(ctx.clone(), self.operator.clone(), self.schema());
         let chunk_size = ctx.get_settings().get_max_threads()? as usize * 4;
@@ -646,12 +658,14 @@ impl FuseTable {
                 };
                 blocks.extend(location_tuple.block_location.into_iter());
                 blooms.extend(location_tuple.bloom_location.into_iter());
+                deletion_vectors.extend(location_tuple.deletion_vector_location.into_iter());
             }
         }
 
         Ok(LocationTuple {
             block_location: blocks,
             bloom_location: blooms,
+            deletion_vector_location: deletion_vectors,
         })
     }
 
@@ -675,6 +689,7 @@ struct RootSnapshotInfo {
 pub struct LocationTuple {
     pub block_location: HashSet<String>,
     pub bloom_location: HashSet<String>,
+    pub deletion_vector_location: HashSet<String>,
 }
 
 impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
@@ -682,16 +697,21 @@ impl TryFrom<Arc<CompactSegmentInfo>> for LocationTuple {
     fn try_from(value: Arc<CompactSegmentInfo>) -> Result<Self> {
         let mut block_location = HashSet::new();
         let mut bloom_location = HashSet::new();
+        let mut deletion_vector_location = HashSet::new();
         let block_metas = value.block_metas()?;
         for block_meta in block_metas.into_iter() {
             block_location.insert(block_meta.location.0.clone());
             if let Some(bloom_loc) = &block_meta.bloom_filter_index_location {
                 bloom_location.insert(bloom_loc.0.clone());
             }
+            if let Some(dv_loc) = &block_meta.deletion_vector_location {
+                deletion_vector_location.insert(dv_loc.0.clone());
+            }
         }
         Ok(Self {
             block_location,
             bloom_location,
+            deletion_vector_location,
         })
     }
 }
@@ -719,3 +739,30 @@ impl PurgeCounter {
         }
     }
 }
+
+// The locations of the index files built from the block.
+fn index_locations_of_block<'a>(
+    loc: &'a str,
+    table_indexes: &'a [(u64, IndexType)],
+) -> impl Iterator<Item = String> + 'a {
+    table_indexes
+        .iter()
+        .filter_map(move |(index_id, index_type)| match index_type {
+            IndexType::AGGREGATING => Some(
+                TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
+                    loc, *index_id,
+                ),
+            ),
+            IndexType::VECTOR => Some(
+                TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                    loc, *index_id,
+                ),
+            ),
+            IndexType::INVERTED => Some(
+                TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                    loc, *index_id,
+                ),
+            ),
+            IndexType::JOIN => None,
+        })
+}
@@ -0,0 +1,133 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_catalog::plan::Projection;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::DataType;
+use databend_common_meta_app::schema::IndexMeta;
+use databend_storages_common_cache::LoadParams;
+use databend_storages_common_index::InvertedIndex;
+use databend_storages_common_index::INVERTED_INDEX_COLUMN;
+use databend_storages_common_table_meta::meta::Location;
+use log::info;
+
+use crate::io::write_data;
+use crate::io::MetaReaders;
+use crate::io::ReadSettings;
+use crate::io::TableMetaLocationGenerator;
+use crate::FuseTable;
+
+impl FuseTable {
+    /// Build the inverted index files of the blocks which are not indexed yet.
+    ///
+    /// If no `segment_locs` are given, all the segments of the current snapshot are visited.
+    /// At most `limit` blocks are indexed if it is specified.
+    #[async_backtrace::framed]
+    pub async fn do_refresh_inverted_index(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        index_id: u64,
+        index_meta: &IndexMeta,
+        segment_locs: Option<Vec<Location>>,
+        limit: Option<u64>,
+    ) -> Result<u64> {
+        let Some(snapshot) = self.read_table_snapshot().await? else {
+            return Ok(0);
+        };
+
+        let column_name = index_meta
+            .options
+            .get(INVERTED_INDEX_COLUMN)
+            .ok_or_else(|| {
+                ErrorCode::UnsupportedIndex(format!(
+                    "Inverted index {} does not specify the indexed column",
+                    index_id
+                ))
+            })?;
+        let table_schema = &self.get_table_info().meta.schema;
+        let field_index = table_schema.index_of(column_name)?;
+        let data_type = DataType::from(table_schema.field(field_index).data_type());
+
+        let block_reader = self.create_block_reader(
+            ctx.clone(),
+            Projection::Columns(vec![field_index]),
+            false,
+            false,
+            false,
+        )?;
+        let segment_reader =
+            MetaReaders::segment_info_reader(self.get_operator(), table_schema.clone());
+        let settings = ReadSettings::from_ctx(&ctx)?;
+        let storage_format = self.get_write_settings().storage_format;
+        let operator = self.get_operator_ref();
+
+        let segment_locs = match segment_locs {
+            Some(segment_locs) if !segment_locs.is_empty() => segment_locs,
+            _ => snapshot.segments.clone(),
+        };
+        let limit = limit.unwrap_or(u64::MAX);
+        let mut num_indexed = 0;
+        'segments: for (location, ver) in segment_locs {
+            let segment_info = segment_reader
+                .read(&LoadParams {
+                    location: location.to_string(),
+                    len_hint: None,
+                    ver,
+                    put_cache: false,
+                })
+                .await?;
+
+            for block_meta in segment_info.block_metas()? {
+                if num_indexed >= limit {
+                    break 'segments;
+                }
+                let index_location =
+                    TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                        &block_meta.location.0,
+                        index_id,
+                    );
+                if operator.is_exist(&index_location).await? {
+                    continue;
+                }
+
+                let block = block_reader
+                    .read_by_meta(&settings, &block_meta, &storage_format)
+                    .await?;
+                let column = block
+                    .get_by_offset(0)
+                    .value
+                    .convert_to_full_column(&data_type, block.num_rows());
+                // Blocks with nothing to index are left without an index file,
+                // they are always read while querying.
+                let Some(index) = InvertedIndex::try_create(&column)? else {
+                    continue;
+                };
+                write_data(index.to_bytes()?, operator, &index_location).await?;
+                num_indexed += 1;
+            }
+        }
+
+        info!(
+            "refreshed inverted index {} of table {}, {} blocks indexed",
+            index_id,
+            self.get_table_info().name,
+            num_indexed
+        );
+        Ok(num_indexed)
+    }
+}
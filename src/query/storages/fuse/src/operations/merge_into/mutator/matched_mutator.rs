@@ -50,6 +50,7 @@ use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockReader;
 use crate::io::CompactSegmentInfoReader;
+use crate::io::DeletionVector;
 use crate::io::MetaReaders;
 use crate::io::ReadSettings;
 use crate::io::WriteSettings;
@@ -380,10 +381,16 @@ impl AggregationContext {
         )
         .await?;
         let origin_num_rows = origin_data_block.num_rows();
-        // apply delete
+        let deletion_vector = match &block_meta.deletion_vector_location {
+            Some((location, _)) => Some(DeletionVector::read(&self.data_accessor, location).await?),
+            None => None,
+        };
+        // apply delete, the rows deleted by the deletion vector are dropped as well.
         let mut bitmap = MutableBitmap::new();
         for row in 0..origin_num_rows {
-            if modified_offsets.contains(&row) {
+            if modified_offsets.contains(&row)
+                || deletion_vector.as_ref().is_some_and(|v| v.is_deleted(row))
+            {
                 bitmap.push(false);
             } else {
                 bitmap.push(true);
@@ -15,6 +15,7 @@
 use databend_common_expression::BlockMetaInfo;
 use databend_common_expression::BlockMetaInfoDowncast;
 use databend_storages_common_table_meta::meta::ClusterStatistics;
+use databend_storages_common_table_meta::meta::Location;
 
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::CompactExtraInfo;
@@ -25,6 +26,7 @@ pub enum SerializeDataMeta {
     SerializeBlock(SerializeBlock),
     DeletedSegment(DeletedSegmentInfo),
     CompactExtras(CompactExtraInfo),
+    DeletedRows(DeletedRowsInfo),
 }
 
 #[typetag::serde(name = "serialize_data_meta")]
@@ -55,3 +57,12 @@ impl SerializeBlock {
         SerializeBlock { index, stats_type }
     }
 }
+
+/// Rows of a block marked as deleted by a newly written deletion vector.
+#[derive(serde::Serialize, serde::Deserialize, Clone, Debug, PartialEq)]
+pub struct DeletedRowsInfo {
+    pub index: BlockMetaIndex,
+    pub deletion_vector_location: Location,
+    /// total number of deleted rows recorded in the deletion vector.
+    pub deleted_row_count: u64,
+}
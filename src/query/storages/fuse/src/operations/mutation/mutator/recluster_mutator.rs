@@ -143,7 +143,7 @@ impl ReclusterMutator {
                         .and_modify(|v| v.1.push(i))
                         .or_insert((vec![], vec![i]));
                 }
-                total_rows += meta.row_count;
+                total_rows += meta.live_row_count();
                 total_bytes += meta.block_size;
             }
 
@@ -189,7 +189,7 @@ impl ReclusterMutator {
                 }
 
                 let block_size = block_meta.block_size as usize;
-                let row_count = block_meta.row_count as usize;
+                let row_count = block_meta.live_row_count() as usize;
                 if task_bytes + block_size > memory_threshold && selected_blocks.len() > 1 {
                     self.generate_task(
                         &selected_blocks,
@@ -34,6 +34,7 @@ use databend_common_sql::evaluator::BlockOperator;
 use databend_storages_common_table_meta::meta::BlockMeta;
 
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::io::ReadSettings;
 use crate::operations::mutation::ClusterStatsGenType;
 use crate::operations::mutation::CompactPartInfo;
@@ -46,7 +47,7 @@ use crate::MergeIOReadResult;
 enum State {
     ReadData(Option<PartInfoPtr>),
     Concat {
-        read_res: Vec<MergeIOReadResult>,
+        read_res: Vec<(MergeIOReadResult, Option<DeletionVector>)>,
         metas: Vec<Arc<BlockMeta>>,
         index: BlockMetaIndex,
     },
@@ -146,12 +147,17 @@ impl Processor for CompactSource {
                 let blocks = read_res
                     .into_iter()
                     .zip(metas.into_iter())
-                    .map(|(data, meta)| {
+                    .map(|((data, deletion_vector), meta)| {
                         let mut block = self.block_reader.deserialize_chunks_with_meta(
                             &meta,
                             &self.storage_format,
                             data,
                         )?;
+                        // the deleted rows are dropped while compacting.
+                        if let Some(deletion_vector) = deletion_vector {
+                            let live_rows = deletion_vector.live_rows(block.num_rows());
+                            block = block.filter_with_bitmap(&live_rows)?;
+                        }
 
                         if self.block_reader.update_stream_columns() {
                             let num_rows = block.num_rows();
@@ -222,14 +228,22 @@ impl Processor for CompactSource {
                                     metrics_inc_compact_block_read_bytes(block.block_size);
                                 }
 
-                                block_reader
+                                let read_res = block_reader
                                     .read_columns_data_by_merge_io(
                                         &settings,
                                         &block.location.0,
                                         &block.col_metas,
                                         &None,
                                     )
-                                    .await
+                                    .await?;
+                                let deletion_vector = match &block.deletion_vector_location {
+                                    Some((location, _)) => Some(
+                                        DeletionVector::read(&block_reader.operator, location)
+                                            .await?,
+                                    ),
+                                    None => None,
+                                };
+                                Ok::<_, ErrorCode>((read_res, deletion_vector))
                             });
                         }
 
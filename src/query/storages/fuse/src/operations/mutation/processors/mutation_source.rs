@@ -16,6 +16,7 @@ use std::any::Any;
 use std::ops::Not;
 use std::sync::Arc;
 
+use databend_common_arrow::arrow::bitmap::Bitmap;
 use databend_common_base::base::ProgressValues;
 use databend_common_catalog::plan::build_origin_block_row_num;
 use databend_common_catalog::plan::gen_mutation_stream_meta;
@@ -45,9 +46,12 @@ use databend_common_sql::evaluator::BlockOperator;
 
 use crate::fuse_part::FusePartInfo;
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::io::ReadSettings;
+use crate::io::TableMetaLocationGenerator;
 use crate::operations::common::BlockMetaIndex;
 use crate::operations::mutation::ClusterStatsGenType;
+use crate::operations::mutation::DeletedRowsInfo;
 use crate::operations::mutation::Mutation;
 use crate::operations::mutation::SerializeBlock;
 use crate::operations::mutation::SerializeDataMeta;
@@ -74,6 +78,7 @@ enum State {
         filter: Option<Value<BooleanType>>,
     },
     PerformOperator(DataBlock, String),
+    WriteDeletionVector(DataBlock),
     Output(Option<PartInfoPtr>, DataBlock),
     Finish,
 }
@@ -90,9 +95,15 @@ pub struct MutationSource {
     storage_format: FuseStorageFormat,
     action: MutationAction,
     query_row_id_col: bool,
+    // Some if the table mutates in merge-on-read mode.
+    deletion_vector_location_gen: Option<TableMetaLocationGenerator>,
 
     index: BlockMetaIndex,
     stats_type: ClusterStatsGenType,
+    deletion_vector: Option<DeletionVector>,
+    // the live rows of the block, if it has a deletion vector.
+    live_rows: Option<Bitmap>,
+    write_deletion_vector: bool,
 }
 
 impl MutationSource {
@@ -107,6 +118,7 @@ impl MutationSource {
         operators: Vec<BlockOperator>,
         storage_format: FuseStorageFormat,
         query_row_id_col: bool,
+        deletion_vector_location_gen: Option<TableMetaLocationGenerator>,
     ) -> Result<ProcessorPtr> {
         Ok(ProcessorPtr::create(Box::new(MutationSource {
             state: State::ReadData(None),
@@ -119,8 +131,12 @@ impl MutationSource {
             storage_format,
             action,
             query_row_id_col,
+            deletion_vector_location_gen,
             index: BlockMetaIndex::default(),
             stats_type: ClusterStatsGenType::Generally,
+            deletion_vector: None,
+            live_rows: None,
+            write_deletion_vector: false,
         })))
     }
 }
@@ -167,7 +183,10 @@ impl Processor for MutationSource {
             }
         }
 
-        if matches!(self.state, State::ReadData(_) | State::ReadRemain { .. }) {
+        if matches!(
+            self.state,
+            State::ReadData(_) | State::ReadRemain { .. } | State::WriteDeletionVector(_)
+        ) {
             Ok(Event::Async)
         } else {
             Ok(Event::Sync)
@@ -183,6 +202,21 @@ impl Processor for MutationSource {
                     chunks,
                     &self.storage_format,
                 )?;
+                // filter out the rows deleted by the deletion vector, the offsets of
+                // the live rows are kept to generate the row ids.
+                let mut offsets = None;
+                if let Some(deletion_vector) = &self.deletion_vector {
+                    let live_rows = deletion_vector.live_rows(data_block.num_rows());
+                    offsets = Some(
+                        live_rows
+                            .iter()
+                            .enumerate()
+                            .filter_map(|(i, live)| live.then_some(i))
+                            .collect::<Vec<_>>(),
+                    );
+                    data_block = data_block.filter_with_bitmap(&live_rows)?;
+                    self.live_rows = Some(live_rows);
+                }
                 let num_rows = data_block.num_rows();
 
                 let fuse_part = FusePartInfo::from_part(&part)?;
@@ -196,7 +230,7 @@ impl Processor for MutationSource {
                             block_location: block_meta.block_location.clone(),
                             segment_location: block_meta.segment_location.clone(),
                             snapshot_location: None,
-                            offsets: None,
+                            offsets: offsets.clone(),
                             base_block_ids: None,
                         };
                         let internal_col = InternalColumn {
@@ -255,6 +289,11 @@ impl Processor for MutationSource {
                                         self.ctx.get_partition(),
                                         DataBlock::empty_with_meta(meta),
                                     );
+                                } else if self.deletion_vector_location_gen.is_some() {
+                                    // mark the rows as deleted.
+                                    let predicate_col = predicates.into_column().unwrap();
+                                    self.mark_deleted_rows(&predicate_col, &offsets);
+                                    self.state = State::WriteDeletionVector(DataBlock::empty());
                                 } else {
                                     if self.block_reader.update_stream_columns {
                                         let row_num = BlockEntry::new(
@@ -285,10 +324,27 @@ impl Processor for MutationSource {
                             }
 
                             MutationAction::Update => {
-                                data_block.add_column(BlockEntry::new(
-                                    DataType::Boolean,
-                                    Value::upcast(predicates),
-                                ));
+                                let mut remain_filter = None;
+                                if self.deletion_vector_location_gen.is_some()
+                                    && affect_rows != num_rows
+                                {
+                                    // mark the updated rows as deleted, and append them as
+                                    // a new block.
+                                    let predicate_col = predicates.into_column().unwrap();
+                                    self.mark_deleted_rows(&predicate_col, &offsets);
+                                    data_block = data_block.filter_with_bitmap(&predicate_col)?;
+                                    data_block.add_column(BlockEntry::new(
+                                        DataType::Boolean,
+                                        Value::upcast(Value::<BooleanType>::Scalar(true)),
+                                    ));
+                                    remain_filter = Some(Value::Column(predicate_col));
+                                    self.write_deletion_vector = true;
+                                } else {
+                                    data_block.add_column(BlockEntry::new(
+                                        DataType::Boolean,
+                                        Value::upcast(predicates),
+                                    ));
+                                }
                                 if self.remain_reader.is_none() {
                                     self.state = State::PerformOperator(
                                         data_block,
@@ -298,7 +354,7 @@ impl Processor for MutationSource {
                                     self.state = State::ReadRemain {
                                         part,
                                         data_block,
-                                        filter: None,
+                                        filter: remain_filter,
                                     };
                                 }
                             }
@@ -332,6 +388,11 @@ impl Processor for MutationSource {
                         &self.storage_format,
                     )?;
 
+                    let remain_block = if let Some(live_rows) = &self.live_rows {
+                        remain_block.filter_with_bitmap(live_rows)?
+                    } else {
+                        remain_block
+                    };
                     let remain_block = if let Some(filter) = filter {
                         // for deletion.
                         remain_block.filter_boolean_value(&filter)?
@@ -354,6 +415,10 @@ impl Processor for MutationSource {
                     .operators
                     .iter()
                     .try_fold(data_block, |input, op| op.execute(&func_ctx, input))?;
+                if self.write_deletion_vector {
+                    self.state = State::WriteDeletionVector(block);
+                    return Ok(());
+                }
                 let inner_meta = Box::new(SerializeDataMeta::SerializeBlock(
                     SerializeBlock::create(self.index.clone(), self.stats_type.clone()),
                 ));
@@ -402,12 +467,25 @@ impl Processor for MutationSource {
                         let inner_part = part.inner_part.clone();
                         let fuse_part = FusePartInfo::from_part(&inner_part)?;
 
+                        self.live_rows = None;
+                        self.write_deletion_vector = false;
+                        self.deletion_vector = match &fuse_part.deletion_vector_location {
+                            Some((location, _)) => Some(
+                                DeletionVector::read(&self.block_reader.operator, location).await?,
+                            ),
+                            None => None,
+                        };
+
                         if part.whole_block_mutation
                             && matches!(self.action, MutationAction::Deletion)
                         {
                             // whole block deletion.
+                            let deleted_rows = self
+                                .deletion_vector
+                                .as_ref()
+                                .map_or(0, |v| v.deleted_row_count() as usize);
                             let progress_values = ProgressValues {
-                                rows: fuse_part.nums_rows,
+                                rows: fuse_part.nums_rows - deleted_rows,
                                 bytes: 0,
                             };
                             self.ctx.get_write_progress().incr(&progress_values);
@@ -460,8 +538,41 @@ impl Processor for MutationSource {
                     return Err(ErrorCode::Internal("It's a bug. No remain reader"));
                 }
             }
+            State::WriteDeletionVector(data_block) => {
+                let location = self
+                    .deletion_vector_location_gen
+                    .as_ref()
+                    .ok_or_else(|| ErrorCode::Internal("It's a bug. No location generator"))?
+                    .gen_deletion_vector_location();
+                let deletion_vector = self.deletion_vector.take().unwrap_or_default();
+                deletion_vector
+                    .write(&self.block_reader.operator, &location.0)
+                    .await?;
+                let meta = Box::new(SerializeDataMeta::DeletedRows(DeletedRowsInfo {
+                    index: self.index.clone(),
+                    deletion_vector_location: location,
+                    deleted_row_count: deletion_vector.deleted_row_count(),
+                }));
+                self.state =
+                    State::Output(self.ctx.get_partition(), data_block.add_meta(Some(meta))?);
+            }
             _ => return Err(ErrorCode::Internal("It's a bug.")),
         }
         Ok(())
     }
 }
+
+impl MutationSource {
+    // Mark the rows selected by the predicate as deleted in the deletion vector.
+    fn mark_deleted_rows(&mut self, predicate: &Bitmap, offsets: &Option<Vec<usize>>) {
+        let deletion_vector = self
+            .deletion_vector
+            .get_or_insert_with(DeletionVector::default);
+        for (i, selected) in predicate.iter().enumerate() {
+            if selected {
+                let row = offsets.as_ref().map_or(i, |offsets| offsets[i]);
+                deletion_vector.delete(row);
+            }
+        }
+    }
+}
@@ -15,13 +15,20 @@
 use databend_common_catalog::plan::PartInfoPtr;
 use databend_common_expression::BlockMetaInfo;
 
+use crate::io::DeletionVector;
 use crate::io::MergeIOReadResult;
 use crate::io::VirtualMergeIOReadResult;
 use crate::operations::read::data_source_with_meta::DataSourceWithMeta;
 
 pub enum ParquetDataSource {
     AggIndex((PartInfoPtr, MergeIOReadResult)),
-    Normal((MergeIOReadResult, Option<VirtualMergeIOReadResult>)),
+    Normal(
+        (
+            MergeIOReadResult,
+            Option<VirtualMergeIOReadResult>,
+            Option<DeletionVector>,
+        ),
+    ),
 }
 
 #[typetag::serde(name = "fuse_data_source")]
@@ -260,7 +260,7 @@ impl Processor for DeserializeDataTransform {
 
                     self.output_data = Some(block);
                 }
-                ParquetDataSource::Normal((data, virtual_data)) => {
+                ParquetDataSource::Normal((data, virtual_data, deletion_vector)) => {
                     let start = Instant::now();
                     let columns_chunks = data.columns_chunks()?;
                     let part = FusePartInfo::from_part(&part)?;
@@ -276,13 +276,19 @@ impl Processor for DeserializeDataTransform {
 
                     let origin_num_rows = data_block.num_rows();
 
-                    let mut filter = None;
+                    // filter out the rows marked in the deletion vector.
+                    let mut filter = deletion_vector.map(|v| v.live_rows(origin_num_rows));
                     if self.ctx.has_bloom_runtime_filters(self.table_index) {
                         if let Some(bitmap) = self.runtime_filter(data_block.clone())? {
-                            data_block = data_block.filter_with_bitmap(&bitmap)?;
-                            filter = Some(bitmap);
+                            filter = Some(match filter {
+                                Some(live_rows) => (&live_rows).bitand(&bitmap),
+                                None => bitmap,
+                            });
                         }
                     }
+                    if let Some(bitmap) = &filter {
+                        data_block = data_block.filter_with_bitmap(bitmap)?;
+                    }
 
                     // Add optional virtual columns
                     if let Some(virtual_reader) = self.virtual_reader.as_ref() {
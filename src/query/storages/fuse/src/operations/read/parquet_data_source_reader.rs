@@ -36,6 +36,7 @@ use super::parquet_data_source::ParquetDataSource;
 use crate::fuse_part::FusePartInfo;
 use crate::io::AggIndexReader;
 use crate::io::BlockReader;
+use crate::io::DeletionVector;
 use crate::io::ReadSettings;
 use crate::io::TableMetaLocationGenerator;
 use crate::io::VirtualColumnReader;
@@ -136,8 +137,16 @@ impl SyncSource for ReadParquetDataSource<true> {
                     return Ok(Some(DataBlock::empty()));
                 }
 
-                if let Some(index_reader) = self.index_reader.as_ref() {
-                    let fuse_part = FusePartInfo::from_part(&part)?;
+                let fuse_part = FusePartInfo::from_part(&part)?;
+                // The aggregating index and virtual columns are not used
+                // if some rows of the block are deleted.
+                let has_deleted_rows = fuse_part.deletion_vector_location.is_some();
+                if let Some(index_reader) = self
+                    .index_reader
+                    .as_ref()
+                    .as_ref()
+                    .filter(|_| !has_deleted_rows)
+                {
                     let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &fuse_part.location,
@@ -157,8 +166,12 @@ impl SyncSource for ReadParquetDataSource<true> {
                 }
 
                 // If virtual column file exists, read the data from the virtual columns directly.
-                let virtual_source = if let Some(virtual_reader) = self.virtual_reader.as_ref() {
-                    let fuse_part = FusePartInfo::from_part(&part)?;
+                let virtual_source = if let Some(virtual_reader) = self
+                    .virtual_reader
+                    .as_ref()
+                    .as_ref()
+                    .filter(|_| !has_deleted_rows)
+                {
                     let loc =
                         TableMetaLocationGenerator::gen_virtual_block_location(&fuse_part.location);
 
@@ -176,6 +189,14 @@ impl SyncSource for ReadParquetDataSource<true> {
                     &None
                 };
 
+                let deletion_vector = match &fuse_part.deletion_vector_location {
+                    Some((location, _)) => Some(DeletionVector::blocking_read(
+                        &self.block_reader.operator,
+                        location,
+                    )?),
+                    None => None,
+                };
+
                 let source = self.block_reader.sync_read_columns_data_by_merge_io(
                     &ReadSettings::from_ctx(&self.partitions.ctx)?,
                     &part,
@@ -186,6 +207,7 @@ impl SyncSource for ReadParquetDataSource<true> {
                     DataSourceWithMeta::create(vec![part], vec![ParquetDataSource::Normal((
                         source,
                         virtual_source,
+                        deletion_vector,
                     ))]),
                 )))
             }
@@ -263,7 +285,12 @@ impl Processor for ReadParquetDataSource<false> {
                     databend_common_base::runtime::spawn(async move {
                         let part = FusePartInfo::from_part(&part)?;
 
-                        if let Some(index_reader) = index_reader.as_ref() {
+                        // The aggregating index and virtual columns are not used
+                        // if some rows of the block are deleted.
+                        let has_deleted_rows = part.deletion_vector_location.is_some();
+                        if let Some(index_reader) =
+                            index_reader.as_ref().as_ref().filter(|_| !has_deleted_rows)
+                        {
                             let loc =
                         TableMetaLocationGenerator::gen_agg_index_location_from_block_location(
                             &part.location,
@@ -279,7 +306,11 @@ impl Processor for ReadParquetDataSource<false> {
                         }
 
                         // If virtual column file exists, read the data from the virtual columns directly.
-                        let virtual_source = if let Some(virtual_reader) = virtual_reader.as_ref() {
+                        let virtual_source = if let Some(virtual_reader) = virtual_reader
+                            .as_ref()
+                            .as_ref()
+                            .filter(|_| !has_deleted_rows)
+                        {
                             let loc = TableMetaLocationGenerator::gen_virtual_block_location(
                                 &part.location,
                             );
@@ -297,6 +328,13 @@ impl Processor for ReadParquetDataSource<false> {
                             &None
                         };
 
+                        let deletion_vector = match &part.deletion_vector_location {
+                            Some((location, _)) => {
+                                Some(DeletionVector::read(&block_reader.operator, location).await?)
+                            }
+                            None => None,
+                        };
+
                         let source = block_reader
                             .read_columns_data_by_merge_io(
                                 &settings,
@@ -306,7 +344,11 @@ impl Processor for ReadParquetDataSource<false> {
                             )
                             .await?;
 
-                        Ok(ParquetDataSource::Normal((source, virtual_source)))
+                        Ok(ParquetDataSource::Normal((
+                            source,
+                            virtual_source,
+                            deletion_vector,
+                        )))
                     })
                     .await
                     .unwrap()
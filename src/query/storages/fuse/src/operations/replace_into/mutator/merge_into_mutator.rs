@@ -53,6 +53,7 @@ use crate::io::write_data;
 use crate::io::BlockBuilder;
 use crate::io::BlockReader;
 use crate::io::CompactSegmentInfoReader;
+use crate::io::DeletionVector;
 use crate::io::MetaReaders;
 use crate::io::ReadSettings;
 use crate::io::WriteSettings;
@@ -413,9 +414,17 @@ impl AggregationContext {
                 .value);
         }
 
+        let deletion_vector = match &block_meta.deletion_vector_location {
+            Some((location, _)) => Some(DeletionVector::read(&self.data_accessor, location).await?),
+            None => None,
+        };
+
         let mut bitmap = MutableBitmap::new();
         for row in 0..num_rows {
-            if let Some(hash) = row_hash_of_columns(&columns, row)? {
+            if deletion_vector.as_ref().is_some_and(|v| v.is_deleted(row)) {
+                // the row has been deleted by the deletion vector
+                bitmap.push(false);
+            } else if let Some(hash) = row_hash_of_columns(&columns, row)? {
                 // some row hash means on-conflict columns of this row contains non-null values
                 // let's check it out
                 bitmap.push(!deleted_key_hashes.contains(&hash));
@@ -425,7 +434,10 @@ impl AggregationContext {
             }
         }
 
-        let delete_nums = bitmap.unset_bits();
+        let deleted_rows = deletion_vector
+            .as_ref()
+            .map_or(0, |v| v.deleted_row_count() as usize);
+        let delete_nums = bitmap.unset_bits() - deleted_rows;
         info!("number of row deleted: {}", delete_nums);
 
         // shortcut: nothing to be deleted
@@ -448,7 +460,7 @@ impl AggregationContext {
             .incr(&progress_values);
 
         // shortcut: whole block deletion
-        if delete_nums == block_meta.row_count as usize {
+        if delete_nums == block_meta.live_row_count() as usize {
             info!("whole block deletion");
             metrics_inc_replace_whole_block_deletion(1);
             metrics_inc_replace_deleted_blocks_rows(num_rows as u64);
@@ -228,6 +228,9 @@ impl FuseTable {
         let max_threads = (ctx.get_settings().get_max_threads()? as usize)
             .min(ctx.partition_num())
             .max(1);
+        let deletion_vector_location_gen = self
+            .merge_on_read_enabled()
+            .then(|| self.meta_location_generator().clone());
         // Add source pipe.
         pipeline.add_source(
             |output| {
@@ -241,6 +244,7 @@ impl FuseTable {
                     ops.clone(),
                     self.storage_format,
                     true,
+                    deletion_vector_location_gen.clone(),
                 )
             },
             max_threads,
@@ -50,7 +50,9 @@ use crate::pruning::BlockPruner;
 use crate::pruning::BloomPruner;
 use crate::pruning::BloomPrunerCreator;
 use crate::pruning::FusePruningStatistics;
+use crate::pruning::InvertedIndexPruner;
 use crate::pruning::SegmentLocation;
+use crate::pruning::VectorIndexPruner;
 
 pub struct PruningContext {
     pub ctx: Arc<dyn TableContext>,
@@ -261,7 +263,7 @@ impl FusePruner {
         self.pruning(segment_locs, true).await
     }
     // Pruning chain:
-    // segment pruner -> block pruner -> topn pruner
+    // segment pruner -> block pruner -> topn pruner -> vector index pruner
     #[async_backtrace::framed]
     pub async fn pruning(
         &mut self,
@@ -375,8 +377,12 @@ impl FusePruner {
                 } else {
                     // Todo:: for now, all operation (contains other mutation other than delete, like select,update etc.)
                     // will get here, we can prevent other mutations like update and so on.
+                    // Inverted index pruner.
+                    let metas = self.inverted_index_pruning(metas).await?;
                     // TopN pruner.
-                    self.topn_pruning(metas)
+                    let metas = self.topn_pruning(metas)?;
+                    // Vector index pruner.
+                    self.vector_index_pruning(metas).await
                 }
             }
         }
@@ -404,6 +410,40 @@ impl FusePruner {
         Ok(metas)
     }
 
+    // vector index pruner:
+    // if the nearest rows of a constant vector are required, only read the blocks containing them.
+    #[async_backtrace::framed]
+    async fn vector_index_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let Some(info) = self.push_down.as_ref().and_then(|p| p.vector_index.clone()) else {
+            return Ok(metas);
+        };
+        let pruner =
+            VectorIndexPruner::create(self.pruning_ctx.dal.clone(), info, self.max_concurrency);
+        pruner.prune(metas).await
+    }
+
+    // inverted index pruner:
+    // if a `match` filter requires a positive score, only read the blocks containing any query term.
+    #[async_backtrace::framed]
+    async fn inverted_index_pruning(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let Some(info) = self
+            .push_down
+            .as_ref()
+            .and_then(|p| p.inverted_index.clone())
+        else {
+            return Ok(metas);
+        };
+        let pruner =
+            InvertedIndexPruner::create(self.pruning_ctx.dal.clone(), info, self.max_concurrency);
+        pruner.prune(metas).await
+    }
+
     // Pruning stats.
     pub fn pruning_stats(&self) -> databend_common_catalog::plan::PruningStatistics {
         let stats = self.pruning_ctx.pruning_stats.clone();
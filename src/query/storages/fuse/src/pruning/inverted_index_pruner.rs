@@ -0,0 +1,85 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_catalog::plan::InvertedIndexInfo;
+use databend_common_exception::Result;
+use databend_storages_common_index::InvertedIndex;
+use databend_storages_common_pruner::BlockMetaIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use futures::StreamExt;
+use log::debug;
+use opendal::Operator;
+
+use crate::io::TableMetaLocationGenerator;
+
+/// Prunes the blocks which do not contain any term of a `match` query.
+///
+/// Blocks without an index file, e.g. blocks written before the index is refreshed,
+/// are always kept.
+pub struct InvertedIndexPruner {
+    dal: Operator,
+    info: InvertedIndexInfo,
+    max_concurrency: usize,
+}
+
+impl InvertedIndexPruner {
+    pub fn create(dal: Operator, info: InvertedIndexInfo, max_concurrency: usize) -> Self {
+        Self {
+            dal,
+            info,
+            max_concurrency,
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn prune(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let keep = futures::stream::iter(metas.iter().map(|(_, meta)| async move {
+            let location =
+                TableMetaLocationGenerator::gen_inverted_index_location_from_block_location(
+                    &meta.location.0,
+                    self.info.index_id,
+                );
+            let data = match self.dal.read(&location).await {
+                Ok(data) => data,
+                Err(e) => {
+                    if e.kind() != opendal::ErrorKind::NotFound {
+                        debug!("Read inverted index `{location}` failed: {e}");
+                    }
+                    return true;
+                }
+            };
+            match InvertedIndex::from_bytes(&data) {
+                Ok(index) => index.may_match(&self.info.query),
+                Err(e) => {
+                    debug!("Decode inverted index `{location}` failed: {e}");
+                    true
+                }
+            }
+        }))
+        .buffered(self.max_concurrency)
+        .collect::<Vec<_>>()
+        .await;
+
+        Ok(metas
+            .into_iter()
+            .zip(keep)
+            .filter(|(_, keep)| *keep)
+            .map(|(meta, _)| meta)
+            .collect())
+    }
+}
@@ -0,0 +1,122 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::sync::Arc;
+
+use databend_common_catalog::plan::VectorIndexInfo;
+use databend_common_exception::Result;
+use databend_storages_common_index::VectorIndex;
+use databend_storages_common_pruner::BlockMetaIndex;
+use databend_storages_common_table_meta::meta::BlockMeta;
+use futures::StreamExt;
+use log::debug;
+use opendal::Operator;
+
+use crate::io::TableMetaLocationGenerator;
+
+// The minimal size of the dynamic candidate list while searching the index.
+const MIN_EF_SEARCH: usize = 64;
+
+/// Prunes the blocks which do not contain the approximate top-k nearest rows.
+///
+/// Blocks without an index file, e.g. blocks written before the index is refreshed,
+/// are always kept. So are blocks with a deletion vector, their index still holds the
+/// deleted rows.
+pub struct VectorIndexPruner {
+    dal: Operator,
+    info: VectorIndexInfo,
+    max_concurrency: usize,
+}
+
+impl VectorIndexPruner {
+    pub fn create(dal: Operator, info: VectorIndexInfo, max_concurrency: usize) -> Self {
+        Self {
+            dal,
+            info,
+            max_concurrency,
+        }
+    }
+
+    #[async_backtrace::framed]
+    pub async fn prune(
+        &self,
+        metas: Vec<(BlockMetaIndex, Arc<BlockMeta>)>,
+    ) -> Result<Vec<(BlockMetaIndex, Arc<BlockMeta>)>> {
+        let query = self.info.query.iter().map(|v| v.0).collect::<Vec<_>>();
+        let limit = self.info.limit;
+        let ef = limit.max(MIN_EF_SEARCH);
+
+        // The distances of the candidates in each block, None if the block is not indexed.
+        let results = futures::stream::iter(metas.iter().map(|(_, meta)| {
+            let query = &query;
+            async move {
+                if meta.deleted_row_count > 0 {
+                    return None;
+                }
+                let location =
+                    TableMetaLocationGenerator::gen_vector_index_location_from_block_location(
+                        &meta.location.0,
+                        self.info.index_id,
+                    );
+                let index = match self.dal.read(&location).await {
+                    Ok(data) => VectorIndex::from_bytes(&data),
+                    Err(e) => {
+                        if e.kind() != opendal::ErrorKind::NotFound {
+                            debug!("Read vector index `{location}` failed: {e}");
+                        }
+                        return None;
+                    }
+                };
+                match index.and_then(|index| index.search(query, limit, ef)) {
+                    Ok(candidates) => Some(
+                        candidates
+                            .into_iter()
+                            .map(|(_, distance)| distance)
+                            .collect::<Vec<_>>(),
+                    ),
+                    Err(e) => {
+                        debug!("Search vector index `{location}` failed: {e}");
+                        None
+                    }
+                }
+            }
+        }))
+        .buffered(self.max_concurrency)
+        .collect::<Vec<_>>()
+        .await;
+
+        let mut candidates = results
+            .iter()
+            .enumerate()
+            .filter_map(|(block, distances)| distances.as_ref().map(|d| (block, d)))
+            .flat_map(|(block, distances)| distances.iter().map(move |d| (*d, block)))
+            .collect::<Vec<_>>();
+        // Not enough rows are indexed, NULLs may be part of the result.
+        if candidates.len() < limit {
+            return Ok(metas);
+        }
+        candidates.sort_by(|a, b| a.0.total_cmp(&b.0));
+        let mut keep = vec![false; metas.len()];
+        for (_, block) in candidates.into_iter().take(limit) {
+            keep[block] = true;
+        }
+
+        Ok(metas
+            .into_iter()
+            .zip(results)
+            .enumerate()
+            .filter(|(block, (_, distances))| distances.is_none() || keep[*block])
+            .map(|(_, (meta, _))| meta)
+            .collect())
+    }
+}
@@ -15,6 +15,7 @@
 use std::collections::HashMap;
 
 use databend_common_exception::Result;
+use databend_common_expression::types::geometry::GeometryDomain;
 use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::ValueType;
@@ -66,30 +67,41 @@ pub fn gen_columns_statistics(
         let mut min = Scalar::Null;
         let mut max = Scalar::Null;
 
-        let (mins, _) = eval_aggr("min", vec![], &[col.clone()], rows)?;
-        let (maxs, _) = eval_aggr("max", vec![], &[col.clone()], rows)?;
+        if data_type.remove_nullable() == DataType::Geometry {
+            // The bounding box is kept as min/max, so that blocks can be pruned by spatial predicates.
+            match geometry_min_max(col) {
+                Some((bbox_min, bbox_max)) => {
+                    min = bbox_min;
+                    max = bbox_max;
+                }
+                None => continue,
+            }
+        } else {
+            let (mins, _) = eval_aggr("min", vec![], &[col.clone()], rows)?;
+            let (maxs, _) = eval_aggr("max", vec![], &[col.clone()], rows)?;
 
-        if mins.len() > 0 {
-            min = if let Some(v) = mins.index(0) {
-                if let Some(v) = v.to_owned().trim_min(STATS_STRING_PREFIX_LEN) {
-                    v
+            if mins.len() > 0 {
+                min = if let Some(v) = mins.index(0) {
+                    if let Some(v) = v.to_owned().trim_min(STATS_STRING_PREFIX_LEN) {
+                        v
+                    } else {
+                        continue;
+                    }
                 } else {
                     continue;
                 }
-            } else {
-                continue;
             }
-        }
 
-        if maxs.len() > 0 {
-            max = if let Some(v) = maxs.index(0) {
-                if let Some(v) = v.to_owned().trim_max(STATS_STRING_PREFIX_LEN) {
-                    v
+            if maxs.len() > 0 {
+                max = if let Some(v) = maxs.index(0) {
+                    if let Some(v) = v.to_owned().trim_max(STATS_STRING_PREFIX_LEN) {
+                        v
+                    } else {
+                        continue;
+                    }
                 } else {
                     continue;
                 }
-            } else {
-                continue;
             }
         }
 
@@ -133,6 +145,9 @@ pub fn gen_columns_statistics(
 
 pub fn scalar_min_max(data_type: &DataType, scalar: Scalar) -> Option<(Scalar, Scalar)> {
     if RangeIndex::supported_type(data_type) {
+        if let Scalar::Geometry(buf) = &scalar {
+            return GeometryDomain::from_ewkb(buf).to_min_max();
+        }
         if let Some((min, Some(max))) = scalar
             .clone()
             .trim_min(STATS_STRING_PREFIX_LEN)
@@ -144,6 +159,13 @@ pub fn scalar_min_max(data_type: &DataType, scalar: Scalar) -> Option<(Scalar, S
     None
 }
 
+fn geometry_min_max(column: &Column) -> Option<(Scalar, Scalar)> {
+    // The inner values of NULLs are empty, which are not covered by the bounding box.
+    let column = column.remove_nullable();
+    let column = column.as_geometry()?;
+    GeometryDomain::from_column(column).to_min_max()
+}
+
 pub mod traverse {
     use databend_common_expression::types::map::KvPair;
     use databend_common_expression::types::AnyType;
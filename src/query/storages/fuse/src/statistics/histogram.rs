@@ -0,0 +1,118 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use databend_common_expression::Scalar;
+use databend_common_expression::TableDataType;
+use databend_storages_common_table_meta::meta::ColumnHistogram;
+use databend_storages_common_table_meta::meta::HistogramBucket;
+use databend_storages_common_table_meta::meta::MostCommonValue;
+
+/// Max number of blocks sampled by `ANALYZE TABLE` to build the histograms.
+pub const HISTOGRAM_SAMPLE_BLOCKS: usize = 64;
+/// Max number of rows sampled by `ANALYZE TABLE` to build the histograms.
+pub const HISTOGRAM_SAMPLE_ROWS: usize = 30_000;
+pub const HISTOGRAM_NUM_BUCKETS: usize = 100;
+pub const HISTOGRAM_NUM_MOST_COMMON_VALUES: usize = 20;
+
+/// Whether the optimizer is able to make use of the histogram of the type.
+pub fn support_histogram(data_type: &TableDataType) -> bool {
+    matches!(
+        data_type.remove_nullable(),
+        TableDataType::Number(_)
+            | TableDataType::String
+            | TableDataType::Date
+            | TableDataType::Timestamp
+    )
+}
+
+/// Builds an equi-height histogram from the sampled non-null values of a column.
+///
+/// The counts of the sample are scaled to `num_rows` and `ndv`, which are the
+/// estimated number of non-null rows and distinct values of the whole column.
+pub fn build_column_histogram(
+    mut samples: Vec<Scalar>,
+    num_rows: u64,
+    ndv: u64,
+    num_buckets: usize,
+    num_most_common_values: usize,
+) -> Option<ColumnHistogram> {
+    if samples.is_empty() || num_buckets == 0 {
+        return None;
+    }
+    samples.sort_unstable();
+
+    // group the sorted samples into (value, count)
+    let mut groups: Vec<(Scalar, usize)> = Vec::new();
+    for value in samples.iter() {
+        match groups.last_mut() {
+            Some((last, count)) if last == value => *count += 1,
+            _ => groups.push((value.clone(), 1)),
+        }
+    }
+
+    let num_samples = samples.len();
+    let row_scale = (num_rows as f64 / num_samples as f64).max(1.0);
+    let ndv_scale = (ndv as f64 / groups.len() as f64).max(1.0);
+
+    // A value is regarded as a common value only if it occurs noticeably
+    // more frequently than the average.
+    let avg_count = num_samples as f64 / groups.len() as f64;
+    let mut common_values = groups
+        .iter()
+        .filter(|(_, count)| *count > 1 && *count as f64 > avg_count * 1.25)
+        .collect::<Vec<_>>();
+    common_values.sort_by(|a, b| b.1.cmp(&a.1));
+    let most_common_values = common_values
+        .into_iter()
+        .take(num_most_common_values)
+        .map(|(value, count)| MostCommonValue {
+            value: value.clone(),
+            count: *count as f64 * row_scale,
+        })
+        .collect();
+
+    // the first bucket only holds the min value
+    let (min, min_count) = &groups[0];
+    let mut buckets = Vec::with_capacity(num_buckets + 1);
+    buckets.push(HistogramBucket {
+        upper_bound: min.clone(),
+        num_values: *min_count as f64 * row_scale,
+        num_distinct: 1.0,
+    });
+
+    let remain = num_samples - min_count;
+    let bucket_height = remain.div_ceil(num_buckets).max(1);
+    let mut num_values = 0;
+    let mut num_distinct = 0;
+    for (idx, (value, count)) in groups.iter().enumerate().skip(1) {
+        num_values += count;
+        num_distinct += 1;
+        // values of the same group can not be split into different buckets
+        if num_values >= bucket_height || idx == groups.len() - 1 {
+            let num_values_scaled = num_values as f64 * row_scale;
+            buckets.push(HistogramBucket {
+                upper_bound: value.clone(),
+                num_values: num_values_scaled,
+                num_distinct: (num_distinct as f64 * ndv_scale).min(num_values_scaled),
+            });
+            num_values = 0;
+            num_distinct = 0;
+        }
+    }
+
+    Some(ColumnHistogram {
+        buckets,
+        most_common_values,
+    })
+}
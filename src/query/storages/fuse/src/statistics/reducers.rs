@@ -15,6 +15,7 @@
 use std::borrow::Borrow;
 use std::collections::HashMap;
 
+use databend_common_expression::types::geometry::GeometryDomain;
 use databend_common_expression::BlockThresholds;
 use databend_common_expression::ColumnId;
 use databend_common_expression::Scalar;
@@ -62,6 +63,14 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
                 in_memory_size += col_stats.in_memory_size;
             }
 
+            if let Some((min, max)) = reduce_geometry_min_max(&min_stats, &max_stats) {
+                acc.insert(
+                    *id,
+                    ColumnStatistics::new(min, max, null_count, in_memory_size, None),
+                );
+                return acc;
+            }
+
             let min = min_stats
                 .into_iter()
                 .filter(|s| !s.is_null())
@@ -82,6 +91,21 @@ pub fn reduce_block_statistics<T: Borrow<StatisticsOfColumns>>(
         })
 }
 
+/// The min/max statistics of geometry columns are the corners of bounding boxes,
+/// which are merged by coordinates instead of being compared as scalars.
+fn reduce_geometry_min_max(mins: &[Scalar], maxs: &[Scalar]) -> Option<(Scalar, Scalar)> {
+    if !mins.iter().any(|min| matches!(min, Scalar::Geometry(_))) {
+        return None;
+    }
+    mins.iter()
+        .zip(maxs.iter())
+        .filter_map(|(min, max)| {
+            GeometryDomain::from_min_max(min.as_geometry()?, max.as_geometry()?)
+        })
+        .reduce(|acc, domain| acc.merge(&domain))
+        .and_then(|domain| domain.to_min_max())
+}
+
 pub fn reduce_cluster_statistics<T: Borrow<Option<ClusterStatistics>>>(
     blocks_cluster_stats: &[T],
     default_cluster_key_id: Option<u32>,
@@ -206,13 +230,15 @@ pub fn reduce_block_metas<T: Borrow<BlockMeta>>(
 
     block_metas.iter().for_each(|b| {
         let b = b.borrow();
-        row_count += b.row_count;
+        row_count += b.live_row_count();
         block_count += 1;
         uncompressed_byte_size += b.block_size;
         compressed_byte_size += b.file_size;
         index_size += b.bloom_filter_index_size;
-        if thresholds.check_large_enough(b.row_count as usize, b.block_size as usize)
-            || b.cluster_stats.as_ref().is_some_and(|v| v.level != 0)
+        // blocks with deleted rows are always candidates of compaction.
+        if b.deleted_row_count == 0
+            && (thresholds.check_large_enough(b.row_count as usize, b.block_size as usize)
+                || b.cluster_stats.as_ref().is_some_and(|v| v.level != 0))
         {
             perfect_block_count += 1;
         }
@@ -214,6 +214,7 @@ impl<'a> ClusteringInformation<'a> {
             .table
             .cluster_key_str()
             .ok_or_else(|| ErrorCode::Internal("It's a bug"))?;
+        let cluster_type = self.table.cluster_type().unwrap_or_default();
         Ok(DataBlock::new(
             vec![
                 BlockEntry::new(
@@ -254,6 +255,10 @@ impl<'a> ClusteringInformation<'a> {
                         JsonbValue::from(&info.block_depth_histogram).to_vec(),
                     )),
                 ),
+                BlockEntry::new(
+                    DataType::String,
+                    Value::Scalar(Scalar::String(cluster_type.to_string())),
+                ),
             ],
             1,
         ))
@@ -283,6 +288,7 @@ impl<'a> ClusteringInformation<'a> {
                 TableDataType::Number(NumberDataType::Float64),
             ),
             TableField::new("block_depth_histogram", TableDataType::Variant),
+            TableField::new("cluster_type", TableDataType::String),
         ])
     }
 }
@@ -23,6 +23,7 @@ use databend_common_arrow::parquet::statistics::Statistics;
 use databend_common_expression::types::number::F32;
 use databend_common_expression::types::number::F64;
 use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::DataType;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::NumberType;
 use databend_common_expression::types::StringType;
@@ -31,6 +32,7 @@ use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
+use databend_common_orc::OrcFileMeta;
 use databend_storages_common_index::RangeIndex;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
@@ -96,25 +98,82 @@ impl HiveBlockFilter {
                 }
             }
 
-            for (p_key, p_value) in part_columns {
-                if let Some((idx, _)) = self.data_schema.column_with_name(&p_key) {
-                    let mut null_count = 0;
-                    let v = if p_value == HIVE_DEFAULT_PARTITION {
-                        null_count = row_group.num_rows();
-                        Scalar::Null
-                    } else {
-                        Scalar::String(p_value)
-                    };
+            return self.apply(
+                filter,
+                statistics,
+                part_columns,
+                row_group.num_rows() as u64,
+            );
+        }
+        false
+    }
 
-                    let col_stats = ColumnStatistics::new(v.clone(), v, null_count as u64, 0, None);
-                    statistics.insert(idx as u32, col_stats);
+    // true: stripe is filtered by predict
+    pub fn filter_orc_stripe(
+        &self,
+        file_meta: &OrcFileMeta,
+        stripe_index: usize,
+        part_columns: HashMap<String, String>,
+    ) -> bool {
+        if let Some(filter) = &self.range_filter {
+            let mut statistics = StatisticsOfColumns::new();
+            let field_names = &file_meta.schema.root().field_names;
+            for col in self.projections.iter() {
+                // partition columns are not stored in the file
+                let Some(field_index) = field_names
+                    .iter()
+                    .position(|name| name.eq_ignore_ascii_case(col.name()))
+                else {
+                    continue;
+                };
+                // if stats is none, we couldn't make a decision whether the stripe should be filtered
+                let Some(stats) = file_meta.stripe_field_statistics(stripe_index, field_index)
+                else {
+                    return false;
+                };
+                // the hive schema may declare a type the file was not written with
+                let data_type = DataType::from(&col.data_type().remove_nullable());
+                if stats.min.as_ref().infer_data_type() != data_type {
+                    continue;
+                }
+                let col_stats =
+                    ColumnStatistics::new(stats.min, stats.max, stats.null_count, 0, None);
+                if let Some((index, _)) = self.data_schema.column_with_name(col.name()) {
+                    statistics.insert(index as u32, col_stats);
                 }
             }
 
-            if let Ok(ret) = filter.apply(&statistics, |_| false) {
-                if !ret {
-                    return true;
-                }
+            let num_rows = file_meta.stripes[stripe_index].num_rows;
+            return self.apply(filter, statistics, part_columns, num_rows);
+        }
+        false
+    }
+
+    fn apply(
+        &self,
+        filter: &RangeIndex,
+        mut statistics: StatisticsOfColumns,
+        part_columns: HashMap<String, String>,
+        num_rows: u64,
+    ) -> bool {
+        for (p_key, p_value) in part_columns {
+            if let Some((idx, _)) = self.data_schema.column_with_name(&p_key) {
+                let mut null_count = 0;
+                let v = if p_value == HIVE_DEFAULT_PARTITION {
+                    null_count = num_rows;
+                    Scalar::Null
+                } else {
+                    Scalar::String(p_value)
+                };
+
+                let col_stats = ColumnStatistics::new(v.clone(), v, null_count, 0, None);
+                statistics.insert(idx as u32, col_stats);
+            }
+        }
+
+        if let Ok(ret) = filter.apply(&statistics, |_| false) {
+            if !ret {
+                return true;
             }
         }
         false
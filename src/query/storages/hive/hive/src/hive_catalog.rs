@@ -98,6 +98,7 @@ use volo_thrift::transport::pool;
 
 use super::hive_database::HiveDatabase;
 use crate::hive_table::HiveTable;
+use crate::hive_table_options::HiveFileFormat;
 
 pub const HIVE_CATALOG: &str = "hive";
 
@@ -226,12 +227,7 @@ impl HiveCatalog {
     fn handle_table_meta(table_meta: &hive_metastore::Table) -> Result<()> {
         if let Some(sd) = table_meta.sd.as_ref() {
             if let Some(input_format) = sd.input_format.as_ref() {
-                if input_format != "org.apache.hadoop.hive.ql.io.parquet.MapredParquetInputFormat" {
-                    return Err(ErrorCode::Unimplemented(format!(
-                        "only support parquet, {} not support",
-                        input_format
-                    )));
-                }
+                HiveFileFormat::try_from_input_format(input_format)?;
             }
         }
 
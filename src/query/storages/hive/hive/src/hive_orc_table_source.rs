@@ -0,0 +1,364 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::any::Any;
+use std::sync::Arc;
+
+use databend_common_base::base::tokio::time::sleep;
+use databend_common_base::base::tokio::time::Duration;
+use databend_common_base::base::Progress;
+use databend_common_base::base::ProgressValues;
+use databend_common_catalog::plan::PartInfoPtr;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::DataType;
+use databend_common_expression::BlockEntry;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchemaRef;
+use databend_common_expression::Evaluator;
+use databend_common_expression::Expr;
+use databend_common_expression::Scalar;
+use databend_common_expression::Value;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_orc::adapt_column;
+use databend_common_orc::read_orc_metadata;
+use databend_common_orc::OrcFileMeta;
+use databend_common_orc::OrcStripeReader;
+use databend_common_pipeline_core::processors::Event;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::Processor;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_core::processors::Profile;
+use databend_common_pipeline_core::processors::ProfileStatisticsName;
+use log::debug;
+use opendal::Operator;
+
+use crate::utils::str_field_to_scalar;
+use crate::HiveBlockFilter;
+use crate::HivePartInfo;
+
+// the stripes of an orc file which belong to a hive part
+struct HiveOrcStripes {
+    file_meta: Arc<OrcFileMeta>,
+    part: HivePartInfo,
+    valid_stripes: Vec<usize>,
+    current_index: usize,
+}
+
+impl HiveOrcStripes {
+    // there are some conditions to filter invalid stripes:
+    // 1. the stripe doesn't belong to the partition
+    // 2. filtered by predict pushdown
+    fn create(
+        file_meta: Arc<OrcFileMeta>,
+        part: HivePartInfo,
+        hive_block_filter: &HiveBlockFilter,
+    ) -> Self {
+        let mut valid_stripes = vec![];
+        let mut pruned_stripe_cnt = 0;
+        for (idx, stripe) in file_meta.stripes.iter().enumerate() {
+            let mid = stripe.offset + stripe.length() / 2;
+            if !part.range.contains(&mid) {
+                continue;
+            }
+            if hive_block_filter.filter_orc_stripe(&file_meta, idx, part.get_partition_map()) {
+                pruned_stripe_cnt += 1;
+            } else {
+                valid_stripes.push(idx);
+            }
+        }
+        debug!(
+            "hive orc predict pushdown have pruned {} stripes",
+            pruned_stripe_cnt
+        );
+        Self {
+            file_meta,
+            part,
+            valid_stripes,
+            current_index: 0,
+        }
+    }
+
+    fn current_stripe(&self) -> usize {
+        self.valid_stripes[self.current_index]
+    }
+
+    fn advance(&mut self) {
+        self.current_index += 1;
+    }
+
+    fn has_stripes(&self) -> bool {
+        self.current_index < self.valid_stripes.len()
+    }
+}
+
+enum State {
+    /// Read orc file tail
+    /// IO bound
+    ReadMeta(Option<PartInfoPtr>),
+
+    /// Read the whole current stripe
+    /// IO bound
+    ReadStripe(HiveOrcStripes),
+
+    /// Decode the projected columns of the stripe and apply the prewhere filter
+    /// CPU bound
+    Deserialize(HiveOrcStripes, Vec<u8>),
+
+    /// indicates that data blocks are ready, and needs to be consumed
+    Generated(HiveOrcStripes, Vec<DataBlock>),
+    Finish,
+}
+
+/// Reads the hive tables stored as orc files, one stripe at a time.
+///
+/// Stripes are read as a whole, so the prewhere filter only saves the
+/// decoding of the remain columns instead of their io.
+pub struct HiveOrcTableSource {
+    state: State,
+    ctx: Arc<dyn TableContext>,
+    dal: Operator,
+    scan_progress: Arc<Progress>,
+    partition_keys: Vec<String>,
+    prewhere_filter: Arc<Option<Expr>>,
+    output: Arc<OutputPort>,
+    delay: usize,
+    hive_block_filter: Arc<HiveBlockFilter>,
+
+    /// The schema of the decoded blocks, prewhere columns go first.
+    source_schema: DataSchemaRef,
+    /// The final output schema
+    output_schema: DataSchemaRef,
+}
+
+impl HiveOrcTableSource {
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        ctx: Arc<dyn TableContext>,
+        dal: Operator,
+        output: Arc<OutputPort>,
+        partition_keys: Vec<String>,
+        prewhere_filter: Arc<Option<Expr>>,
+        delay: usize,
+        hive_block_filter: Arc<HiveBlockFilter>,
+        source_schema: DataSchemaRef,
+        output_schema: DataSchemaRef,
+    ) -> Result<ProcessorPtr> {
+        let scan_progress = ctx.get_scan_progress();
+        Ok(ProcessorPtr::create(Box::new(HiveOrcTableSource {
+            ctx,
+            dal,
+            output,
+            partition_keys,
+            prewhere_filter,
+            hive_block_filter,
+            scan_progress,
+            state: State::ReadMeta(None),
+            delay,
+            source_schema,
+            output_schema,
+        })))
+    }
+
+    fn try_get_partitions(&mut self) {
+        self.state = self
+            .ctx
+            .get_partition()
+            .map_or(State::Finish, |part_info| State::ReadMeta(Some(part_info)));
+    }
+
+    fn read_stripe(&self, stripes: &HiveOrcStripes, data: &[u8]) -> Result<DataBlock> {
+        let file_meta = &stripes.file_meta;
+        let stripe = &file_meta.stripes[stripes.current_stripe()];
+        let reader =
+            OrcStripeReader::try_create(file_meta.compression, &file_meta.schema, stripe, data)?;
+        let num_rows = reader.num_rows();
+        let partition_map = stripes.part.get_partition_map();
+        let orc_fields = &file_meta.schema.root().field_names;
+
+        let mut columns = Vec::with_capacity(self.source_schema.num_fields());
+        for field in self.source_schema.fields() {
+            let data_type = field.data_type();
+            if self.partition_keys.contains(field.name()) {
+                let value = partition_map.get(field.name()).ok_or_else(|| {
+                    ErrorCode::TableInfoError(format!(
+                        "couldn't find hive partition info :{}, hive partition maps:{:?}",
+                        field.name(),
+                        partition_map
+                    ))
+                })?;
+                let value = str_field_to_scalar(value, data_type)?;
+                columns.push(BlockEntry::new(data_type.clone(), Value::Scalar(value)));
+                continue;
+            }
+
+            let position = orc_fields
+                .iter()
+                .position(|name| name.eq_ignore_ascii_case(field.name()));
+            let value = match position {
+                Some(position) => {
+                    let column = reader.read_field(position)?;
+                    Value::Column(adapt_column(column, data_type)?)
+                }
+                // columns added to the table after the file was written
+                None if matches!(data_type, DataType::Nullable(_)) => Value::Scalar(Scalar::Null),
+                None => {
+                    return Err(ErrorCode::TableSchemaMismatch(format!(
+                        "couldn't find column:{} in orc file",
+                        field.name()
+                    )));
+                }
+            };
+            columns.push(BlockEntry::new(data_type.clone(), value));
+        }
+        Ok(DataBlock::new(columns, num_rows))
+    }
+
+    fn do_deserialize(&mut self, stripes: HiveOrcStripes, data: Vec<u8>) -> Result<()> {
+        let block = self.read_stripe(&stripes, &data).map_err(|e| {
+            e.add_message(format!(" filename of hive part {}", stripes.part.filename))
+        })?;
+
+        let progress_values = ProgressValues {
+            rows: block.num_rows(),
+            bytes: block.memory_size(),
+        };
+        Profile::record_usize_profile(ProfileStatisticsName::ScanBytes, progress_values.bytes);
+        self.scan_progress.incr(&progress_values);
+
+        let block = if let Some(filter) = self.prewhere_filter.as_ref() {
+            assert_eq!(filter.data_type(), &DataType::Boolean);
+            let func_ctx = self.ctx.get_function_context()?;
+            let evaluator = Evaluator::new(&block, &func_ctx, &BUILTIN_FUNCTIONS);
+            let predicates = evaluator
+                .run(filter)
+                .map_err(|e| e.add_message("eval prewhere filter failed:"))?
+                .try_downcast::<BooleanType>()
+                .unwrap();
+            DataBlock::filter_boolean_value(block, &predicates)?
+        } else {
+            block
+        };
+
+        let blocks = if block.is_empty() {
+            vec![]
+        } else {
+            vec![block.resort(&self.source_schema, &self.output_schema)?]
+        };
+        self.state = State::Generated(stripes, blocks);
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl Processor for HiveOrcTableSource {
+    fn name(&self) -> String {
+        "HiveEngineOrcSource".to_string()
+    }
+
+    fn as_any(&mut self) -> &mut dyn Any {
+        self
+    }
+
+    fn event(&mut self) -> Result<Event> {
+        if matches!(self.state, State::ReadMeta(None)) {
+            self.try_get_partitions();
+        }
+
+        if self.output.is_finished() {
+            return Ok(Event::Finished);
+        }
+
+        if !self.output.can_push() {
+            return Ok(Event::NeedConsume);
+        }
+
+        if matches!(self.state, State::Generated(_, _)) {
+            if let State::Generated(mut stripes, mut data_blocks) =
+                std::mem::replace(&mut self.state, State::Finish)
+            {
+                if let Some(data_block) = data_blocks.pop() {
+                    self.output.push_data(Ok(data_block));
+                    self.state = State::Generated(stripes, data_blocks);
+                    return Ok(Event::NeedConsume);
+                }
+
+                stripes.advance();
+                if stripes.has_stripes() {
+                    self.state = State::ReadStripe(stripes);
+                } else {
+                    self.try_get_partitions();
+                }
+            }
+        }
+
+        match self.state {
+            State::Finish => {
+                self.output.finish();
+                Ok(Event::Finished)
+            }
+            State::ReadMeta(_) => Ok(Event::Async),
+            State::ReadStripe(_) => Ok(Event::Async),
+            State::Deserialize(_, _) => Ok(Event::Sync),
+            State::Generated(_, _) => Err(ErrorCode::Internal("It's a bug.")),
+        }
+    }
+
+    fn process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Finish) {
+            State::Deserialize(stripes, data) => self.do_deserialize(stripes, data),
+            _ => Err(ErrorCode::Internal("It's a bug.")),
+        }
+    }
+
+    #[async_backtrace::framed]
+    async fn async_process(&mut self) -> Result<()> {
+        match std::mem::replace(&mut self.state, State::Finish) {
+            State::ReadMeta(Some(part)) => {
+                if self.delay > 0 {
+                    sleep(Duration::from_millis(self.delay as u64)).await;
+                    debug!("sleep for {}ms", self.delay);
+                    self.delay = 0;
+                }
+                let part = HivePartInfo::from_part(&part)?;
+                let file_meta =
+                    read_orc_metadata(&self.dal, &part.filename, Some(part.filesize)).await?;
+                let stripes = HiveOrcStripes::create(
+                    Arc::new(file_meta),
+                    part.clone(),
+                    &self.hive_block_filter,
+                );
+                if stripes.has_stripes() {
+                    self.state = State::ReadStripe(stripes);
+                } else {
+                    self.try_get_partitions();
+                }
+                Ok(())
+            }
+            State::ReadStripe(stripes) => {
+                let stripe = &stripes.file_meta.stripes[stripes.current_stripe()];
+                let data = self
+                    .dal
+                    .read_with(&stripes.part.filename)
+                    .range(stripe.offset..stripe.offset + stripe.length())
+                    .await?;
+                self.state = State::Deserialize(stripes, data);
+                Ok(())
+            }
+            _ => Err(ErrorCode::Internal("It's a bug.")),
+        }
+    }
+}
@@ -63,8 +63,10 @@ use opendal::Operator;
 
 use super::hive_catalog::HiveCatalog;
 use super::hive_partition_pruner::HivePartitionPruner;
+use super::hive_table_options::HiveFileFormat;
 use super::hive_table_options::HiveTableOptions;
 use crate::filter_hive_partition_from_partition_keys;
+use crate::hive_orc_table_source::HiveOrcTableSource;
 use crate::hive_parquet_block_reader::HiveBlockReader;
 use crate::hive_table_source::HiveTableSource;
 use crate::HiveBlockFilter;
@@ -213,6 +215,33 @@ impl HiveTable {
         }
         let src_schema = DataSchemaRefExt::create(src_fields);
 
+        if self.table_options.file_format == HiveFileFormat::Orc {
+            let partition_keys = self
+                .table_options
+                .partition_keys
+                .clone()
+                .unwrap_or_default();
+            for index in 0..std::cmp::max(1, max_threads) {
+                let output = OutputPort::create();
+                source_builder.add_source(
+                    output.clone(),
+                    HiveOrcTableSource::create(
+                        ctx.clone(),
+                        self.dal.clone(),
+                        output,
+                        partition_keys.clone(),
+                        prewhere_filter.clone(),
+                        delay_timer(index),
+                        hive_block_filter.clone(),
+                        src_schema.clone(),
+                        output_schema.clone(),
+                    )?,
+                );
+            }
+            pipeline.add_pipe(source_builder.finalize());
+            return Ok(());
+        }
+
         for index in 0..std::cmp::max(1, max_threads) {
             let output = OutputPort::create();
             source_builder.add_source(
@@ -0,0 +1,351 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::make_array;
+use arrow_array::Array;
+use arrow_array::RecordBatch;
+use arrow_data::ArrayData;
+use arrow_schema::DataType;
+use arrow_schema::Field as ArrowField;
+use arrow_schema::Schema as ArrowSchema;
+use arrow_schema::SchemaRef as ArrowSchemaRef;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::converts::arrow::table_schema_to_arrow_schema_ignore_inside_nullable;
+use databend_common_expression::BlockMetaInfo;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_expression::DataSchema;
+use databend_common_expression::TableSchema;
+use databend_common_expression::TableSchemaRef;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::OutputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransform;
+use databend_common_pipeline_transforms::processors::AsyncAccumulatingTransformer;
+use opendal::Operator;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::basic::ZstdLevel;
+use parquet::file::properties::WriterProperties;
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+use tokio::sync::OnceCell;
+
+use crate::commit::current_metadata_view;
+use crate::stats::gen_stats_of_block;
+use crate::stats::DataFileStatistics;
+
+/// The uncompressed size of data buffered before a data file is written out.
+const MAX_BUFFER_SIZE: usize = 256 * 1024 * 1024;
+
+/// A parquet data file written by [`IcebergDataWriter`], which is not committed yet.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IcebergDataFile {
+    /// The path relative to the table root.
+    pub path: String,
+    pub file_size_in_bytes: u64,
+    pub record_count: u64,
+    /// The id of the iceberg schema the file is written with.
+    pub schema_id: Option<i64>,
+    pub stats: DataFileStatistics,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IcebergDataFileMeta {
+    pub data_file: IcebergDataFile,
+}
+
+impl IcebergDataFileMeta {
+    pub fn create_block(data_file: IcebergDataFile) -> DataBlock {
+        DataBlock::empty_with_meta(Box::new(IcebergDataFileMeta { data_file }))
+    }
+}
+
+#[typetag::serde(name = "iceberg_data_file_meta")]
+impl BlockMetaInfo for IcebergDataFileMeta {
+    fn equals(&self, info: &Box<dyn BlockMetaInfo>) -> bool {
+        Self::downcast_ref_from(info).is_some_and(|other| self == other)
+    }
+
+    fn clone_self(&self) -> Box<dyn BlockMetaInfo> {
+        Box::new(self.clone())
+    }
+}
+
+/// The layout of the written data files, resolved once from the current iceberg
+/// schema and shared by all the writers of the query.
+pub struct DataFileLayout {
+    schema_id: Option<i64>,
+    /// The iceberg field id of each top level column.
+    field_ids: Vec<i32>,
+    /// Iceberg readers resolve parquet columns by field id, the id of every field,
+    /// nested ones included, is kept in the `PARQUET:field_id` metadata.
+    arrow_schema: ArrowSchemaRef,
+}
+
+impl DataFileLayout {
+    async fn load(
+        operator: &Operator,
+        table_name: &str,
+        schema: &TableSchema,
+    ) -> Result<DataFileLayout> {
+        // Fails before any data file is written if the table can not be appended to.
+        let view = current_metadata_view(operator, table_name).await?;
+        let mismatch = |msg: String| {
+            ErrorCode::TableSchemaMismatch(format!(
+                "Cannot write to iceberg table {table_name}: {msg}"
+            ))
+        };
+
+        let arrow_schema = table_schema_to_arrow_schema_ignore_inside_nullable(schema);
+        let mut field_ids = Vec::with_capacity(arrow_schema.fields().len());
+        let mut fields = Vec::with_capacity(arrow_schema.fields().len());
+        for field in arrow_schema.fields().iter() {
+            let iceberg_field = find_iceberg_field(&view.schema, field.name())
+                .ok_or_else(|| mismatch(format!("column {} is not found", field.name())))?;
+            let field = with_field_ids(field, iceberg_field).map_err(mismatch)?;
+            field_ids.push(iceberg_field["id"].as_i64().unwrap_or_default() as i32);
+            fields.push(field);
+        }
+        Ok(DataFileLayout {
+            schema_id: view.schema_id,
+            field_ids,
+            arrow_schema: Arc::new(ArrowSchema::new(fields)),
+        })
+    }
+}
+
+/// Finds the field named `name` of an iceberg struct type.
+fn find_iceberg_field<'a>(struct_type: &'a JsonValue, name: &str) -> Option<&'a JsonValue> {
+    struct_type["fields"]
+        .as_array()?
+        .iter()
+        .find(|f| f["name"].as_str() == Some(name))
+}
+
+/// Sets the `PARQUET:field_id` of `field` and its nested fields from the iceberg field,
+/// given as the json of a struct field: `{"id": .., "type": ..}`.
+fn with_field_ids(
+    field: &ArrowField,
+    iceberg_field: &JsonValue,
+) -> std::result::Result<ArrowField, String> {
+    let id = iceberg_field["id"]
+        .as_i64()
+        .ok_or_else(|| format!("field {} has no id", field.name()))?;
+    let ty = &iceberg_field["type"];
+    let mismatch = || format!("field {} does not match iceberg type {ty}", field.name());
+    let nested = |id: &JsonValue, ty: &JsonValue| json!({"id": id, "type": ty});
+
+    let data_type = match (field.data_type(), ty["type"].as_str()) {
+        (DataType::Struct(children), Some("struct")) => DataType::Struct(
+            children
+                .iter()
+                .map(|child| {
+                    let iceberg_child =
+                        find_iceberg_field(ty, child.name()).ok_or_else(mismatch)?;
+                    with_field_ids(child, iceberg_child)
+                })
+                .collect::<std::result::Result<Vec<_>, _>>()?
+                .into(),
+        ),
+        (DataType::List(item), Some("list")) => DataType::List(Arc::new(with_field_ids(
+            item,
+            &nested(&ty["element-id"], &ty["element"]),
+        )?)),
+        (DataType::LargeList(item), Some("list")) => DataType::LargeList(Arc::new(with_field_ids(
+            item,
+            &nested(&ty["element-id"], &ty["element"]),
+        )?)),
+        (DataType::Map(entries, sorted), Some("map")) => {
+            let DataType::Struct(kv) = entries.data_type() else {
+                return Err(mismatch());
+            };
+            if kv.len() != 2 {
+                return Err(mismatch());
+            }
+            let key = with_field_ids(&kv[0], &nested(&ty["key-id"], &ty["key"]))?;
+            let value = with_field_ids(&kv[1], &nested(&ty["value-id"], &ty["value"]))?;
+            let entries = entries
+                .as_ref()
+                .clone()
+                .with_data_type(DataType::Struct(vec![key, value].into()));
+            DataType::Map(Arc::new(entries), *sorted)
+        }
+        // Primitive iceberg types are plain strings.
+        (data_type, _) if ty.is_string() => data_type.clone(),
+        _ => return Err(mismatch()),
+    };
+    Ok(field
+        .clone()
+        .with_data_type(data_type)
+        .with_metadata(HashMap::from([(
+            "PARQUET:field_id".to_string(),
+            id.to_string(),
+        )])))
+}
+
+/// Rebuilds the array data with `data_type`, which only differs in the metadata
+/// of the nested fields.
+fn with_data_type(data: ArrayData, data_type: &DataType) -> Result<ArrayData> {
+    let child_types = match data_type {
+        DataType::List(item) | DataType::LargeList(item) | DataType::Map(item, _) => {
+            vec![item.data_type().clone()]
+        }
+        DataType::Struct(fields) => fields.iter().map(|f| f.data_type().clone()).collect(),
+        _ => vec![],
+    };
+    let children = if child_types.is_empty() {
+        data.child_data().to_vec()
+    } else {
+        data.child_data()
+            .iter()
+            .zip(child_types.iter())
+            .map(|(child, ty)| with_data_type(child.clone(), ty))
+            .collect::<Result<Vec<_>>>()?
+    };
+    Ok(data
+        .into_builder()
+        .data_type(data_type.clone())
+        .child_data(children)
+        .build()?)
+}
+
+/// Buffers the appended blocks and writes them out as parquet data files under `data/`,
+/// emitting an [`IcebergDataFileMeta`] for each file.
+pub struct IcebergDataWriter {
+    schema: TableSchemaRef,
+    table_name: String,
+    layout: Arc<OnceCell<DataFileLayout>>,
+    operator: Operator,
+    file_prefix: String,
+    file_count: usize,
+    blocks: Vec<DataBlock>,
+    buffered_size: usize,
+}
+
+impl IcebergDataWriter {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        output: Arc<OutputPort>,
+        schema: TableSchemaRef,
+        table_name: String,
+        layout: Arc<OnceCell<DataFileLayout>>,
+        operator: Operator,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(AsyncAccumulatingTransformer::create(
+            input,
+            output,
+            IcebergDataWriter {
+                schema,
+                table_name,
+                layout,
+                operator,
+                file_prefix: uuid::Uuid::new_v4().simple().to_string(),
+                file_count: 0,
+                blocks: vec![],
+                buffered_size: 0,
+            },
+        )))
+    }
+
+    async fn layout(&self) -> Result<&DataFileLayout> {
+        self.layout
+            .get_or_try_init(|| {
+                DataFileLayout::load(&self.operator, &self.table_name, &self.schema)
+            })
+            .await
+    }
+
+    fn serialize(&self, layout: &DataFileLayout, block: &DataBlock) -> Result<Vec<u8>> {
+        let props = WriterProperties::builder()
+            .set_compression(Compression::ZSTD(ZstdLevel::default()))
+            .build();
+        let batch = block
+            .clone()
+            .to_record_batch(&DataSchema::from(&self.schema))?;
+        let columns = batch
+            .columns()
+            .iter()
+            .zip(layout.arrow_schema.fields().iter())
+            .map(|(column, field)| {
+                Ok(make_array(with_data_type(
+                    column.to_data(),
+                    field.data_type(),
+                )?))
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let batch = RecordBatch::try_new(layout.arrow_schema.clone(), columns)?;
+
+        let mut buf = Vec::with_capacity(self.buffered_size);
+        let mut writer = ArrowWriter::try_new(&mut buf, layout.arrow_schema.clone(), Some(props))?;
+        writer.write(&batch)?;
+        writer.close()?;
+        Ok(buf)
+    }
+
+    async fn flush(&mut self) -> Result<Option<DataBlock>> {
+        if self.blocks.is_empty() {
+            return Ok(None);
+        }
+        let block = DataBlock::concat(&std::mem::take(&mut self.blocks))?;
+        self.buffered_size = 0;
+        if block.num_rows() == 0 {
+            return Ok(None);
+        }
+
+        let layout = self.layout().await?;
+        let stats = gen_stats_of_block(&self.schema, &layout.field_ids, &block)?;
+        let data = self.serialize(layout, &block)?;
+        let schema_id = layout.schema_id;
+        let path = format!("data/{}-{:05}.parquet", self.file_prefix, self.file_count);
+        self.file_count += 1;
+
+        let data_file = IcebergDataFile {
+            path: path.clone(),
+            file_size_in_bytes: data.len() as u64,
+            record_count: block.num_rows() as u64,
+            schema_id,
+            stats,
+        };
+        self.operator.write(&path, data).await?;
+        Ok(Some(IcebergDataFileMeta::create_block(data_file)))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncAccumulatingTransform for IcebergDataWriter {
+    const NAME: &'static str = "IcebergDataWriter";
+
+    #[async_backtrace::framed]
+    async fn transform(&mut self, data: DataBlock) -> Result<Option<DataBlock>> {
+        self.layout().await?;
+        self.buffered_size += data.memory_size();
+        self.blocks.push(data);
+        if self.buffered_size >= MAX_BUFFER_SIZE {
+            return self.flush().await;
+        }
+        Ok(None)
+    }
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self, _output: bool) -> Result<Option<DataBlock>> {
+        self.flush().await
+    }
+}
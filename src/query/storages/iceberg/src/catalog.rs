@@ -367,7 +367,9 @@ impl Catalog for IcebergCatalog {
         _db_name: &str,
         _req: GetTableCopiedFileReq,
     ) -> Result<GetTableCopiedFileReply> {
-        unimplemented!()
+        Err(ErrorCode::Unimplemented(
+            "COPY INTO iceberg table requires FORCE = TRUE, the loaded files are not recorded",
+        ))
     }
 
     #[async_backtrace::framed]
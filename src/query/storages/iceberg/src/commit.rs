@@ -0,0 +1,975 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Commits the data files written by [`crate::append::IcebergDataWriter`] as a new
+//! iceberg snapshot.
+//!
+//! The commit follows the filesystem (hadoop) catalog protocol: a new manifest and
+//! manifest list are written next to the table metadata, then a new version of the
+//! table metadata file is published. The metadata file is only created if its version
+//! is not taken yet: by a hard link on the local file system, which fails if the path
+//! exists, and under the table lock on object stores, which can not do that. A writer
+//! losing the version rebases the append onto the latest snapshot and retries.
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+
+use apache_avro::types::Value as AvroValue;
+use apache_avro::Codec;
+use apache_avro::Schema as AvroSchema;
+use apache_avro::Writer as AvroWriter;
+use async_trait::unboxed_simple;
+use chrono::Utc;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_expression::BlockMetaInfoDowncast;
+use databend_common_expression::DataBlock;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::storage::StorageParams;
+use databend_common_pipeline_core::processors::InputPort;
+use databend_common_pipeline_core::processors::ProcessorPtr;
+use databend_common_pipeline_sinks::AsyncSink;
+use databend_common_pipeline_sinks::AsyncSinker;
+use icelake::types::ManifestListEntry;
+use log::info;
+use log::warn;
+use opendal::Operator;
+use serde_json::json;
+use serde_json::Value as JsonValue;
+
+use crate::append::IcebergDataFile;
+use crate::append::IcebergDataFileMeta;
+
+const MAX_COMMIT_RETRIES: usize = 10;
+const METADATA_DIR: &str = "metadata/";
+const VERSION_HINT: &str = "metadata/version-hint.text";
+/// `block_size_in_bytes` is required by format v1, readers do not use it anymore.
+const DEFAULT_BLOCK_SIZE: i64 = 64 * 1024 * 1024;
+
+/// Collects the written data files and commits them once the pipeline finishes.
+pub struct IcebergCommitSink {
+    ctx: Arc<dyn TableContext>,
+    operator: Operator,
+    table_info: TableInfo,
+    data_files: Vec<IcebergDataFile>,
+}
+
+impl IcebergCommitSink {
+    pub fn try_create(
+        input: Arc<InputPort>,
+        ctx: Arc<dyn TableContext>,
+        operator: Operator,
+        table_info: TableInfo,
+    ) -> Result<ProcessorPtr> {
+        Ok(ProcessorPtr::create(AsyncSinker::create(
+            input,
+            ctx.clone(),
+            IcebergCommitSink {
+                ctx,
+                operator,
+                table_info,
+                data_files: vec![],
+            },
+        )))
+    }
+}
+
+#[async_trait::async_trait]
+impl AsyncSink for IcebergCommitSink {
+    const NAME: &'static str = "IcebergCommitSink";
+
+    #[async_backtrace::framed]
+    async fn on_finish(&mut self) -> Result<()> {
+        let data_files = std::mem::take(&mut self.data_files);
+        if data_files.is_empty() {
+            return Ok(());
+        }
+
+        let publisher = match self.table_info.meta.storage_params.as_ref() {
+            Some(StorageParams::Fs(cfg)) => Publisher::Link(PathBuf::from(&cfg.root)),
+            _ => Publisher::Locked,
+        };
+        // Object stores can not create a file only if it is absent, the commits
+        // are serialized by the table lock instead. The lock lives in the meta
+        // service, so only the tables known by it can be written.
+        let _guard = match publisher {
+            Publisher::Link(_) => None,
+            Publisher::Locked if self.table_info.ident.table_id != 0 => {
+                self.ctx
+                    .clone()
+                    .acquire_table_lock(&self.table_info)
+                    .await?
+            }
+            Publisher::Locked => {
+                return Err(ErrorCode::Unimplemented(format!(
+                    "Writing to iceberg table {} of catalog {} is only supported on the local file system",
+                    self.table_info.name,
+                    self.table_info.catalog()
+                )));
+            }
+        };
+
+        IcebergCommitter::new(&self.operator, &self.table_info.name, publisher, data_files)
+            .commit()
+            .await
+    }
+
+    #[unboxed_simple]
+    #[async_backtrace::framed]
+    async fn consume(&mut self, data_block: DataBlock) -> Result<bool> {
+        if let Some(meta) = data_block
+            .get_owned_meta()
+            .and_then(IcebergDataFileMeta::downcast_from)
+        {
+            self.data_files.push(meta.data_file);
+        }
+        Ok(false)
+    }
+}
+
+/// A table metadata file, identified by its version.
+struct MetadataFile {
+    version: i64,
+    name: String,
+}
+
+impl MetadataFile {
+    fn path(&self) -> String {
+        format!("{METADATA_DIR}{}", self.name)
+    }
+
+    /// Both `v<N>.metadata.json` (hadoop catalog) and `<N>-<uuid>.metadata.json`
+    /// are accepted, the next version keeps the naming of the current one.
+    fn parse(name: &str) -> Option<MetadataFile> {
+        let stem = name.strip_suffix(".metadata.json")?;
+        let version = match stem.strip_prefix('v') {
+            Some(version) => version,
+            None => stem.split('-').next()?,
+        };
+        Some(MetadataFile {
+            version: version.parse().ok()?,
+            name: name.to_string(),
+        })
+    }
+
+    fn next(&self) -> MetadataFile {
+        let version = self.version + 1;
+        let name = if self.name.starts_with('v') {
+            format!("v{version}.metadata.json")
+        } else {
+            format!("{version:05}-{}.metadata.json", uuid::Uuid::new_v4())
+        };
+        MetadataFile { version, name }
+    }
+}
+
+/// Lists all the table metadata files, the latest version first.
+async fn list_metadata_files(op: &Operator) -> Result<Vec<MetadataFile>> {
+    let mut files = op
+        .list(METADATA_DIR)
+        .await?
+        .into_iter()
+        .filter_map(|entry| MetadataFile::parse(entry.name()))
+        .collect::<Vec<_>>();
+    files.sort_by(|a, b| b.version.cmp(&a.version));
+    Ok(files)
+}
+
+/// Reads the latest table metadata.
+async fn read_latest_metadata(
+    op: &Operator,
+    table_name: &str,
+) -> Result<(MetadataFile, JsonValue)> {
+    let latest = list_metadata_files(op)
+        .await?
+        .into_iter()
+        .next()
+        .ok_or_else(|| {
+            ErrorCode::ReadTableDataError(format!(
+                "No metadata found for iceberg table {table_name}"
+            ))
+        })?;
+    let metadata = serde_json::from_slice(&op.read(&latest.path()).await?)?;
+    Ok((latest, metadata))
+}
+
+/// Reads the parts of the latest table metadata an append depends on,
+/// fails if the table can not be appended to.
+pub(crate) async fn current_metadata_view(
+    op: &Operator,
+    table_name: &str,
+) -> Result<TableMetadataView> {
+    let (_, metadata) = read_latest_metadata(op, table_name).await?;
+    TableMetadataView::try_create(table_name, &metadata)
+}
+
+/// How a new metadata version is published without overwriting the one
+/// published by a concurrent writer.
+enum Publisher {
+    /// Hard links the written file to the metadata path, which fails if the
+    /// path exists. The path is the root of the table on the local file system.
+    Link(PathBuf),
+    /// Creates the metadata file while holding the table lock.
+    Locked,
+}
+
+impl Publisher {
+    /// Publishes the metadata, returns false if the version is already taken.
+    async fn publish(&self, op: &Operator, path: &str, content: Vec<u8>) -> Result<bool> {
+        match self {
+            Publisher::Link(root) => {
+                let tmp = format!("{METADATA_DIR}.{}.tmp", uuid::Uuid::new_v4());
+                op.write(&tmp, content).await?;
+                let linked = std::fs::hard_link(root.join(&tmp), root.join(path));
+                op.delete(&tmp).await?;
+                match linked {
+                    Ok(_) => Ok(true),
+                    Err(e) if e.kind() == std::io::ErrorKind::AlreadyExists => Ok(false),
+                    Err(e) => Err(e.into()),
+                }
+            }
+            Publisher::Locked => {
+                if op.is_exist(path).await? {
+                    return Ok(false);
+                }
+                op.write(path, content).await?;
+                Ok(true)
+            }
+        }
+    }
+}
+
+fn avro_error(e: apache_avro::Error) -> ErrorCode {
+    ErrorCode::StorageOther(format!("Failed to write iceberg manifest: {e}"))
+}
+
+fn optional(value: Option<AvroValue>) -> AvroValue {
+    match value {
+        Some(value) => AvroValue::Union(1, Box::new(value)),
+        None => AvroValue::Union(0, Box::new(AvroValue::Null)),
+    }
+}
+
+/// Avro schema of an optional `map<int, value_type>`, stored as an array of key-value records.
+fn int_map_schema(
+    name: &str,
+    field_id: i32,
+    key_id: i32,
+    value_id: i32,
+    value_type: &str,
+) -> JsonValue {
+    json!({
+        "name": name,
+        "type": ["null", {
+            "type": "array",
+            "logicalType": "map",
+            "items": {
+                "type": "record",
+                "name": format!("k{key_id}_v{value_id}"),
+                "fields": [
+                    {"name": "key", "type": "int", "field-id": key_id},
+                    {"name": "value", "type": value_type, "field-id": value_id},
+                ],
+            },
+        }],
+        "default": null,
+        "field-id": field_id,
+    })
+}
+
+fn int_map_value<V>(map: &HashMap<i32, V>, f: impl Fn(&V) -> AvroValue) -> AvroValue {
+    let mut keys = map.keys().copied().collect::<Vec<_>>();
+    keys.sort();
+    optional(Some(AvroValue::Array(
+        keys.into_iter()
+            .map(|k| {
+                AvroValue::Record(vec![
+                    ("key".to_string(), AvroValue::Int(k)),
+                    ("value".to_string(), f(&map[&k])),
+                ])
+            })
+            .collect(),
+    )))
+}
+
+fn manifest_entry_schema(format_version: i64) -> JsonValue {
+    let mut data_file_fields = vec![];
+    if format_version >= 2 {
+        data_file_fields.push(json!({"name": "content", "type": "int", "field-id": 134}));
+    }
+    data_file_fields.extend([
+        json!({"name": "file_path", "type": "string", "field-id": 100}),
+        json!({"name": "file_format", "type": "string", "field-id": 101}),
+        json!({"name": "partition", "type": {"type": "record", "name": "r102", "fields": []}, "field-id": 102}),
+        json!({"name": "record_count", "type": "long", "field-id": 103}),
+        json!({"name": "file_size_in_bytes", "type": "long", "field-id": 104}),
+    ]);
+    if format_version == 1 {
+        data_file_fields
+            .push(json!({"name": "block_size_in_bytes", "type": "long", "field-id": 105}));
+    }
+    data_file_fields.extend([
+        int_map_schema("value_counts", 109, 119, 120, "long"),
+        int_map_schema("null_value_counts", 110, 121, 122, "long"),
+        int_map_schema("lower_bounds", 125, 126, 127, "bytes"),
+        int_map_schema("upper_bounds", 128, 129, 130, "bytes"),
+    ]);
+
+    let mut fields = vec![json!({"name": "status", "type": "int", "field-id": 0})];
+    if format_version == 1 {
+        fields.push(json!({"name": "snapshot_id", "type": "long", "field-id": 1}));
+    } else {
+        fields.extend([
+            json!({"name": "snapshot_id", "type": ["null", "long"], "default": null, "field-id": 1}),
+            json!({"name": "sequence_number", "type": ["null", "long"], "default": null, "field-id": 3}),
+            json!({"name": "file_sequence_number", "type": ["null", "long"], "default": null, "field-id": 4}),
+        ]);
+    }
+    fields.push(json!({
+        "name": "data_file",
+        "type": {"type": "record", "name": "r2", "fields": data_file_fields},
+        "field-id": 2,
+    }));
+    json!({"type": "record", "name": "manifest_entry", "fields": fields})
+}
+
+fn manifest_file_schema(format_version: i64) -> JsonValue {
+    // Counts are optional in format v1 and required since v2.
+    let count = |name: &str, ty: &str, field_id: i32| {
+        if format_version == 1 {
+            json!({"name": name, "type": ["null", ty], "default": null, "field-id": field_id})
+        } else {
+            json!({"name": name, "type": ty, "field-id": field_id})
+        }
+    };
+    let mut fields = vec![
+        json!({"name": "manifest_path", "type": "string", "field-id": 500}),
+        json!({"name": "manifest_length", "type": "long", "field-id": 501}),
+        json!({"name": "partition_spec_id", "type": "int", "field-id": 502}),
+    ];
+    if format_version >= 2 {
+        fields.extend([
+            json!({"name": "content", "type": "int", "field-id": 517}),
+            json!({"name": "sequence_number", "type": "long", "field-id": 515}),
+            json!({"name": "min_sequence_number", "type": "long", "field-id": 516}),
+        ]);
+    }
+    fields.extend([
+        count("added_snapshot_id", "long", 503),
+        count("added_data_files_count", "int", 504),
+        count("existing_data_files_count", "int", 505),
+        count("deleted_data_files_count", "int", 506),
+        count("added_rows_count", "long", 512),
+        count("existing_rows_count", "long", 513),
+        count("deleted_rows_count", "long", 514),
+    ]);
+    json!({"type": "record", "name": "manifest_file", "fields": fields})
+}
+
+/// The parts of the table metadata json an append depends on.
+pub(crate) struct TableMetadataView {
+    format_version: i64,
+    location: String,
+    pub(crate) schema: JsonValue,
+    pub(crate) schema_id: Option<i64>,
+    partition_spec_id: i64,
+    last_sequence_number: i64,
+    current_snapshot: Option<JsonValue>,
+}
+
+impl TableMetadataView {
+    fn try_create(table_name: &str, metadata: &JsonValue) -> Result<TableMetadataView> {
+        let invalid = |msg: &str| {
+            ErrorCode::ReadTableDataError(format!(
+                "Invalid metadata of iceberg table {table_name}: {msg}"
+            ))
+        };
+        let format_version = metadata["format-version"].as_i64().unwrap_or(1);
+        let location = metadata["location"]
+            .as_str()
+            .ok_or_else(|| invalid("missing location"))?
+            .trim_end_matches('/')
+            .to_string();
+
+        let schema_id = metadata["current-schema-id"].as_i64();
+        let schema = match metadata["schemas"].as_array() {
+            Some(schemas) => schemas
+                .iter()
+                .find(|s| s["schema-id"].as_i64() == schema_id)
+                .cloned(),
+            None => None,
+        }
+        .or_else(|| metadata.get("schema").cloned())
+        .ok_or_else(|| invalid("missing current schema"))?;
+
+        let partition_spec_id = metadata["default-spec-id"].as_i64().unwrap_or(0);
+        let partition_fields = match metadata["partition-specs"].as_array() {
+            Some(specs) => specs
+                .iter()
+                .find(|s| s["spec-id"].as_i64() == Some(partition_spec_id))
+                .map(|s| s["fields"].clone()),
+            None => metadata.get("partition-spec").cloned(),
+        };
+        if partition_fields
+            .as_ref()
+            .and_then(|f| f.as_array())
+            .is_some_and(|f| !f.is_empty())
+        {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Writing to partitioned iceberg table {table_name} is not supported"
+            )));
+        }
+
+        let current_snapshot = match metadata["current-snapshot-id"].as_i64() {
+            Some(id) if id >= 0 => metadata["snapshots"]
+                .as_array()
+                .and_then(|snapshots| {
+                    snapshots
+                        .iter()
+                        .find(|s| s["snapshot-id"].as_i64() == Some(id))
+                })
+                .cloned(),
+            _ => None,
+        };
+
+        Ok(TableMetadataView {
+            format_version,
+            location,
+            schema,
+            schema_id,
+            partition_spec_id,
+            last_sequence_number: metadata["last-sequence-number"].as_i64().unwrap_or(0),
+            current_snapshot,
+        })
+    }
+
+    /// Turns a path relative to the table root into the absolute path stored in metadata.
+    fn absolute_path(&self, path: &str) -> String {
+        format!("{}/{path}", self.location)
+    }
+
+    /// Turns an absolute path stored in metadata into a path relative to the table root.
+    fn relative_path(&self, path: &str) -> Result<String> {
+        path.strip_prefix(&self.location)
+            .map(|p| p.trim_start_matches('/').to_string())
+            .ok_or_else(|| {
+                ErrorCode::ReadTableDataError(format!(
+                    "Path {path} is not in the iceberg table location {}",
+                    self.location
+                ))
+            })
+    }
+}
+
+/// The manifest holding the appended data files, written once and reused by all attempts.
+struct WrittenManifest {
+    path: String,
+    length: i64,
+}
+
+struct IcebergCommitter<'a> {
+    op: &'a Operator,
+    table_name: &'a str,
+    publisher: Publisher,
+    data_files: Vec<IcebergDataFile>,
+    snapshot_id: i64,
+    manifest: Option<WrittenManifest>,
+}
+
+impl<'a> IcebergCommitter<'a> {
+    fn new(
+        op: &'a Operator,
+        table_name: &'a str,
+        publisher: Publisher,
+        data_files: Vec<IcebergDataFile>,
+    ) -> Self {
+        let (hi, lo) = uuid::Uuid::new_v4().as_u64_pair();
+        IcebergCommitter {
+            op,
+            table_name,
+            publisher,
+            data_files,
+            snapshot_id: ((hi ^ lo) & i64::MAX as u64) as i64,
+            manifest: None,
+        }
+    }
+
+    async fn commit(mut self) -> Result<()> {
+        let mut backoff = Duration::from_millis(50);
+        for retry in 0..MAX_COMMIT_RETRIES {
+            match self.try_commit().await {
+                Err(e) if e.code() == ErrorCode::TABLE_VERSION_MISMATCHED => {
+                    warn!(
+                        "commit to iceberg table {} conflicted, retry {}: {}",
+                        self.table_name, retry, e
+                    );
+                    databend_common_base::base::tokio::time::sleep(backoff).await;
+                    backoff = (backoff * 2).min(Duration::from_secs(5));
+                }
+                res => return res,
+            }
+        }
+        Err(ErrorCode::OCCRetryFailure(format!(
+            "Can not commit to iceberg table {} after {} retries",
+            self.table_name, MAX_COMMIT_RETRIES
+        )))
+    }
+
+    fn conflict(&self) -> ErrorCode {
+        ErrorCode::TableVersionMismatched(format!(
+            "iceberg table {} has been updated concurrently",
+            self.table_name
+        ))
+    }
+
+    async fn try_commit(&mut self) -> Result<()> {
+        let (base, mut metadata) = read_latest_metadata(self.op, self.table_name).await?;
+        let view = TableMetadataView::try_create(self.table_name, &metadata)?;
+        // The field ids of the data files are the ones of the schema they were written with.
+        if self
+            .data_files
+            .iter()
+            .any(|data_file| data_file.schema_id != view.schema_id)
+        {
+            return Err(ErrorCode::TableSchemaMismatch(format!(
+                "The schema of iceberg table {} has changed while writing to it",
+                self.table_name
+            )));
+        }
+
+        if self.manifest.is_none() {
+            self.manifest = Some(self.write_manifest(&view).await?);
+        }
+        let sequence_number = view.last_sequence_number + 1;
+        let manifest_list = self.write_manifest_list(&view, sequence_number).await?;
+        self.update_metadata(&view, &base, &mut metadata, &manifest_list, sequence_number);
+
+        // Publish the new metadata version, unless another writer has done so first.
+        let next = base.next();
+        let latest = list_metadata_files(self.op).await?;
+        if latest.first().map(|f| f.version) != Some(base.version)
+            || !self
+                .publisher
+                .publish(self.op, &next.path(), serde_json::to_vec_pretty(&metadata)?)
+                .await?
+        {
+            self.op.delete(&manifest_list).await?;
+            return Err(self.conflict());
+        }
+        let published = list_metadata_files(self.op).await?;
+        if published
+            .iter()
+            .filter(|f| f.version == next.version)
+            .count()
+            > 1
+        {
+            // Another writer published the same version with a different name.
+            self.op.delete(&next.path()).await?;
+            return Err(self.conflict());
+        }
+
+        if self.op.is_exist(VERSION_HINT).await? {
+            self.op
+                .write(VERSION_HINT, next.version.to_string())
+                .await?;
+        }
+        info!(
+            "committed snapshot {} of iceberg table {} as {}",
+            self.snapshot_id,
+            self.table_name,
+            next.path()
+        );
+        Ok(())
+    }
+
+    async fn write_manifest(&self, view: &TableMetadataView) -> Result<WrittenManifest> {
+        let schema_json = manifest_entry_schema(view.format_version);
+        let schema = AvroSchema::parse(&schema_json).map_err(avro_error)?;
+        let mut writer = AvroWriter::with_codec(&schema, vec![], Codec::Deflate);
+        let mut headers = vec![
+            ("schema", serde_json::to_string(&view.schema)?),
+            ("partition-spec", "[]".to_string()),
+            ("partition-spec-id", view.partition_spec_id.to_string()),
+            ("format-version", view.format_version.to_string()),
+        ];
+        if let Some(schema_id) = view.schema_id {
+            headers.push(("schema-id", schema_id.to_string()));
+        }
+        if view.format_version >= 2 {
+            headers.push(("content", "data".to_string()));
+        }
+        for (key, value) in headers {
+            writer
+                .add_user_metadata(key.to_string(), value)
+                .map_err(avro_error)?;
+        }
+
+        for data_file in self.data_files.iter() {
+            let mut file_fields = vec![];
+            if view.format_version >= 2 {
+                file_fields.push(("content".to_string(), AvroValue::Int(0)));
+            }
+            file_fields.extend([
+                (
+                    "file_path".to_string(),
+                    AvroValue::String(view.absolute_path(&data_file.path)),
+                ),
+                (
+                    "file_format".to_string(),
+                    AvroValue::String("PARQUET".to_string()),
+                ),
+                ("partition".to_string(), AvroValue::Record(vec![])),
+                (
+                    "record_count".to_string(),
+                    AvroValue::Long(data_file.record_count as i64),
+                ),
+                (
+                    "file_size_in_bytes".to_string(),
+                    AvroValue::Long(data_file.file_size_in_bytes as i64),
+                ),
+            ]);
+            if view.format_version == 1 {
+                file_fields.push((
+                    "block_size_in_bytes".to_string(),
+                    AvroValue::Long(DEFAULT_BLOCK_SIZE),
+                ));
+            }
+            let stats = &data_file.stats;
+            let long = |v: &i64| AvroValue::Long(*v);
+            let bytes = |v: &Vec<u8>| AvroValue::Bytes(v.clone());
+            file_fields.extend([
+                (
+                    "value_counts".to_string(),
+                    int_map_value(&stats.value_counts, long),
+                ),
+                (
+                    "null_value_counts".to_string(),
+                    int_map_value(&stats.null_value_counts, long),
+                ),
+                (
+                    "lower_bounds".to_string(),
+                    int_map_value(&stats.lower_bounds, bytes),
+                ),
+                (
+                    "upper_bounds".to_string(),
+                    int_map_value(&stats.upper_bounds, bytes),
+                ),
+            ]);
+
+            // Status 1 is ADDED.
+            let mut entry = vec![("status".to_string(), AvroValue::Int(1))];
+            if view.format_version == 1 {
+                entry.push(("snapshot_id".to_string(), AvroValue::Long(self.snapshot_id)));
+            } else {
+                // Sequence numbers are inherited from the manifest list entry.
+                entry.extend([
+                    (
+                        "snapshot_id".to_string(),
+                        optional(Some(AvroValue::Long(self.snapshot_id))),
+                    ),
+                    ("sequence_number".to_string(), optional(None)),
+                    ("file_sequence_number".to_string(), optional(None)),
+                ]);
+            }
+            entry.push(("data_file".to_string(), AvroValue::Record(file_fields)));
+            writer
+                .append(AvroValue::Record(entry))
+                .map_err(avro_error)?;
+        }
+
+        let content = writer.into_inner().map_err(avro_error)?;
+        let length = content.len() as i64;
+        let path = format!("{METADATA_DIR}{}-m0.avro", uuid::Uuid::new_v4());
+        self.op.write(&path, content).await?;
+        Ok(WrittenManifest { path, length })
+    }
+
+    /// Writes the manifest list of the new snapshot: the manifests of the current
+    /// snapshot followed by the manifest of the appended files.
+    async fn write_manifest_list(
+        &self,
+        view: &TableMetadataView,
+        sequence_number: i64,
+    ) -> Result<String> {
+        let mut entries = vec![];
+        if let Some(manifest_list) = view
+            .current_snapshot
+            .as_ref()
+            .and_then(|s| s["manifest-list"].as_str())
+        {
+            let content = self.op.read(&view.relative_path(manifest_list)?).await?;
+            let parent = icelake::types::parse_manifest_list(&content).map_err(|e| {
+                ErrorCode::ReadTableDataError(format!(
+                    "Cannot parse manifest list {manifest_list}: {e:?}"
+                ))
+            })?;
+            entries.extend(
+                parent
+                    .entries
+                    .iter()
+                    .map(|e| self.manifest_list_record(view, e)),
+            );
+        }
+
+        let manifest = self.manifest.as_ref().expect("manifest must be written");
+        let added_rows = self.data_files.iter().map(|f| f.record_count as i64).sum();
+        entries.push(self.new_manifest_list_record(view, manifest, sequence_number, added_rows));
+
+        let schema =
+            AvroSchema::parse(&manifest_file_schema(view.format_version)).map_err(avro_error)?;
+        let mut writer = AvroWriter::with_codec(&schema, vec![], Codec::Deflate);
+        let parent_snapshot_id = view
+            .current_snapshot
+            .as_ref()
+            .and_then(|s| s["snapshot-id"].as_i64())
+            .map_or("null".to_string(), |id| id.to_string());
+        let mut headers = vec![
+            ("snapshot-id", self.snapshot_id.to_string()),
+            ("parent-snapshot-id", parent_snapshot_id),
+            ("format-version", view.format_version.to_string()),
+        ];
+        if view.format_version >= 2 {
+            headers.push(("sequence-number", sequence_number.to_string()));
+        }
+        for (key, value) in headers {
+            writer
+                .add_user_metadata(key.to_string(), value)
+                .map_err(avro_error)?;
+        }
+        for entry in entries {
+            writer.append(entry).map_err(avro_error)?;
+        }
+
+        let path = format!(
+            "{METADATA_DIR}snap-{}-1-{}.avro",
+            self.snapshot_id,
+            uuid::Uuid::new_v4()
+        );
+        self.op
+            .write(&path, writer.into_inner().map_err(avro_error)?)
+            .await?;
+        Ok(path)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn manifest_list_fields(
+        &self,
+        view: &TableMetadataView,
+        path: String,
+        length: i64,
+        partition_spec_id: i32,
+        content: i32,
+        sequence_numbers: (i64, i64),
+        added_snapshot_id: i64,
+        file_counts: [i32; 3],
+        row_counts: [i64; 3],
+    ) -> AvroValue {
+        let v1 = view.format_version == 1;
+        let count = |value: AvroValue| if v1 { optional(Some(value)) } else { value };
+        let mut fields = vec![
+            ("manifest_path".to_string(), AvroValue::String(path)),
+            ("manifest_length".to_string(), AvroValue::Long(length)),
+            (
+                "partition_spec_id".to_string(),
+                AvroValue::Int(partition_spec_id),
+            ),
+        ];
+        if !v1 {
+            fields.extend([
+                ("content".to_string(), AvroValue::Int(content)),
+                (
+                    "sequence_number".to_string(),
+                    AvroValue::Long(sequence_numbers.0),
+                ),
+                (
+                    "min_sequence_number".to_string(),
+                    AvroValue::Long(sequence_numbers.1),
+                ),
+            ]);
+        }
+        fields.extend([
+            (
+                "added_snapshot_id".to_string(),
+                count(AvroValue::Long(added_snapshot_id)),
+            ),
+            (
+                "added_data_files_count".to_string(),
+                count(AvroValue::Int(file_counts[0])),
+            ),
+            (
+                "existing_data_files_count".to_string(),
+                count(AvroValue::Int(file_counts[1])),
+            ),
+            (
+                "deleted_data_files_count".to_string(),
+                count(AvroValue::Int(file_counts[2])),
+            ),
+            (
+                "added_rows_count".to_string(),
+                count(AvroValue::Long(row_counts[0])),
+            ),
+            (
+                "existing_rows_count".to_string(),
+                count(AvroValue::Long(row_counts[1])),
+            ),
+            (
+                "deleted_rows_count".to_string(),
+                count(AvroValue::Long(row_counts[2])),
+            ),
+        ]);
+        AvroValue::Record(fields)
+    }
+
+    fn manifest_list_record(&self, view: &TableMetadataView, e: &ManifestListEntry) -> AvroValue {
+        self.manifest_list_fields(
+            view,
+            e.manifest_path.clone(),
+            e.manifest_length as i64,
+            e.partition_spec_id as i32,
+            e.content as i32,
+            (e.sequence_number as i64, e.min_sequence_number as i64),
+            e.added_snapshot_id as i64,
+            [
+                e.added_data_files_count as i32,
+                e.existing_data_files_count as i32,
+                e.deleted_data_files_count as i32,
+            ],
+            [
+                e.added_rows_count as i64,
+                e.existing_rows_count as i64,
+                e.deleted_rows_count as i64,
+            ],
+        )
+    }
+
+    fn new_manifest_list_record(
+        &self,
+        view: &TableMetadataView,
+        manifest: &WrittenManifest,
+        sequence_number: i64,
+        added_rows: i64,
+    ) -> AvroValue {
+        self.manifest_list_fields(
+            view,
+            view.absolute_path(&manifest.path),
+            manifest.length,
+            view.partition_spec_id as i32,
+            0,
+            (sequence_number, sequence_number),
+            self.snapshot_id,
+            [self.data_files.len() as i32, 0, 0],
+            [added_rows, 0, 0],
+        )
+    }
+
+    /// Adds the new snapshot to the table metadata and makes it current.
+    fn update_metadata(
+        &self,
+        view: &TableMetadataView,
+        base: &MetadataFile,
+        metadata: &mut JsonValue,
+        manifest_list: &str,
+        sequence_number: i64,
+    ) {
+        let now = Utc::now().timestamp_millis();
+        let added_records: i64 = self.data_files.iter().map(|f| f.record_count as i64).sum();
+        let added_size: i64 = self
+            .data_files
+            .iter()
+            .map(|f| f.file_size_in_bytes as i64)
+            .sum();
+
+        let mut summary = serde_json::Map::new();
+        summary.insert("operation".to_string(), json!("append"));
+        let added = [
+            ("added-data-files", self.data_files.len() as i64),
+            ("added-records", added_records),
+            ("added-files-size", added_size),
+        ];
+        for (key, value) in added {
+            summary.insert(key.to_string(), json!(value.to_string()));
+        }
+        // Totals are carried over from the parent summary when it has them.
+        let parent_summary = view.current_snapshot.as_ref().map(|s| &s["summary"]);
+        let totals = [
+            ("total-data-files", self.data_files.len() as i64),
+            ("total-records", added_records),
+            ("total-files-size", added_size),
+            ("total-delete-files", 0),
+            ("total-position-deletes", 0),
+            ("total-equality-deletes", 0),
+        ];
+        for (key, value) in totals {
+            let total = match parent_summary {
+                None => Some(value),
+                Some(parent) => parent[key]
+                    .as_str()
+                    .and_then(|v| v.parse::<i64>().ok())
+                    .map(|v| v + value),
+            };
+            if let Some(total) = total {
+                summary.insert(key.to_string(), json!(total.to_string()));
+            }
+        }
+
+        let mut snapshot = json!({
+            "snapshot-id": self.snapshot_id,
+            "timestamp-ms": now,
+            "summary": summary,
+            "manifest-list": view.absolute_path(manifest_list),
+        });
+        if let Some(parent) = view
+            .current_snapshot
+            .as_ref()
+            .and_then(|s| s["snapshot-id"].as_i64())
+        {
+            snapshot["parent-snapshot-id"] = json!(parent);
+        }
+        if view.format_version >= 2 {
+            snapshot["sequence-number"] = json!(sequence_number);
+            metadata["last-sequence-number"] = json!(sequence_number);
+        }
+        if let Some(schema_id) = view.schema_id {
+            snapshot["schema-id"] = json!(schema_id);
+        }
+
+        let append = |metadata: &mut JsonValue, key: &str, value: JsonValue| match metadata[key]
+            .as_array_mut()
+        {
+            Some(values) => values.push(value),
+            None => metadata[key] = json!([value]),
+        };
+        append(metadata, "snapshots", snapshot);
+        append(
+            metadata,
+            "snapshot-log",
+            json!({"timestamp-ms": now, "snapshot-id": self.snapshot_id}),
+        );
+        let previous_updated = metadata["last-updated-ms"].as_i64().unwrap_or(now);
+        append(
+            metadata,
+            "metadata-log",
+            json!({
+                "timestamp-ms": previous_updated,
+                "metadata-file": view.absolute_path(&base.path()),
+            }),
+        );
+        metadata["current-snapshot-id"] = json!(self.snapshot_id);
+        metadata["refs"]["main"] = json!({"snapshot-id": self.snapshot_id, "type": "branch"});
+        metadata["last-updated-ms"] = json!(now);
+    }
+}
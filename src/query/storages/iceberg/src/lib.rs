@@ -91,7 +91,9 @@
 #![feature(impl_trait_in_assoc_type)]
 #![allow(clippy::diverging_sub_expression)]
 
+mod append;
 mod catalog;
+mod commit;
 mod database;
 mod partition;
 mod stats;
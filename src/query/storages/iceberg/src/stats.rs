@@ -14,18 +14,24 @@
 
 use std::collections::HashMap;
 
+use databend_common_exception::Result;
+use databend_common_expression::types::number::NumberScalar;
 use databend_common_expression::types::Number;
 use databend_common_expression::types::NumberDataType;
 use databend_common_expression::types::F32;
 use databend_common_expression::types::F64;
 use databend_common_expression::with_integer_mapped_type;
+use databend_common_expression::DataBlock;
 use databend_common_expression::Scalar;
 use databend_common_expression::TableDataType;
 use databend_common_expression::TableField;
 use databend_common_expression::TableSchema;
+use databend_common_functions::aggregates::eval_aggr;
 use databend_storages_common_table_meta::meta::ColumnStatistics;
 use databend_storages_common_table_meta::meta::StatisticsOfColumns;
 use icelake::types::DataFile;
+use serde::Deserialize;
+use serde::Serialize;
 
 /// Try to convert statistics in [`DataFile`] to [`StatisticsOfColumns`].
 pub fn get_stats_of_data_file(schema: &TableSchema, df: &DataFile) -> Option<StatisticsOfColumns> {
@@ -112,3 +118,79 @@ fn parse_binary_value(ty: &TableDataType, data: &[u8]) -> Option<Scalar> {
         _ => None, // Not supported.
     }
 }
+
+/// Serialize [`Scalar`] according to [Binary single-value serialization](https://iceberg.apache.org/spec/#binary-single-value-serialization),
+/// the reverse of [`parse_binary_value`].
+fn to_binary_value(value: &Scalar) -> Option<Vec<u8>> {
+    match value {
+        Scalar::Boolean(v) => Some(vec![*v as u8]),
+        Scalar::Number(num) => with_integer_mapped_type!(|NUM_TYPE| match num {
+            NumberScalar::NUM_TYPE(v) => Some(v.to_le_bytes().to_vec()),
+            NumberScalar::Float32(v) => Some(v.0.to_le_bytes().to_vec()),
+            NumberScalar::Float64(v) => Some(v.0.to_le_bytes().to_vec()),
+        }),
+        Scalar::Date(v) => Some(v.to_le_bytes().to_vec()),
+        Scalar::Timestamp(v) => Some(v.to_le_bytes().to_vec()),
+        Scalar::String(v) => Some(v.as_bytes().to_vec()),
+        // TODO: support Decimal.
+        _ => None, // Not supported.
+    }
+}
+
+/// Column statistics of a written data file, keyed by iceberg field id.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DataFileStatistics {
+    pub value_counts: HashMap<i32, i64>,
+    pub null_value_counts: HashMap<i32, i64>,
+    pub lower_bounds: HashMap<i32, Vec<u8>>,
+    pub upper_bounds: HashMap<i32, Vec<u8>>,
+}
+
+/// Generate the statistics of the top level columns of `block`, keyed by the iceberg field
+/// ids of the columns given in `field_ids`.
+pub fn gen_stats_of_block(
+    schema: &TableSchema,
+    field_ids: &[i32],
+    block: &DataBlock,
+) -> Result<DataFileStatistics> {
+    let mut stats = DataFileStatistics::default();
+    let rows = block.num_rows();
+    let columns = block.columns().iter().zip(schema.fields.iter());
+    for ((entry, field), iceberg_col_id) in columns.zip(field_ids.iter().copied()) {
+        let column = entry.value.convert_to_full_column(&entry.data_type, rows);
+
+        let (is_all_null, bitmap) = column.validity();
+        let null_count = match (is_all_null, bitmap) {
+            (true, _) => rows,
+            (false, Some(bitmap)) => bitmap.unset_bits(),
+            (false, None) => 0,
+        };
+        stats.value_counts.insert(iceberg_col_id, rows as i64);
+        stats
+            .null_value_counts
+            .insert(iceberg_col_id, null_count as i64);
+
+        let supported = matches!(
+            field.data_type.remove_nullable(),
+            TableDataType::Boolean
+                | TableDataType::Number(_)
+                | TableDataType::Date
+                | TableDataType::Timestamp
+                | TableDataType::String
+        );
+        if !supported || null_count == rows {
+            continue;
+        }
+        let (mins, _) = eval_aggr("min", vec![], &[column.clone()], rows)?;
+        let (maxs, _) = eval_aggr("max", vec![], &[column], rows)?;
+        let bounds = (
+            mins.index(0).and_then(|v| to_binary_value(&v.to_owned())),
+            maxs.index(0).and_then(|v| to_binary_value(&v.to_owned())),
+        );
+        if let (Some(lower), Some(upper)) = bounds {
+            stats.lower_bounds.insert(iceberg_col_id, lower);
+            stats.upper_bounds.insert(iceberg_col_id, upper);
+        }
+    }
+    Ok(stats)
+}
@@ -28,6 +28,8 @@ use databend_common_catalog::plan::PartStatistics;
 use databend_common_catalog::plan::Partitions;
 use databend_common_catalog::plan::PartitionsShuffleKind;
 use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::AppendMode;
+use databend_common_catalog::table::NavigationPoint;
 use databend_common_catalog::table::Table;
 use databend_common_catalog::table_args::TableArgs;
 use databend_common_catalog::table_context::TableContext;
@@ -39,6 +41,8 @@ use databend_common_functions::BUILTIN_FUNCTIONS;
 use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
+use databend_common_meta_app::schema::UpdateStreamMetaReq;
+use databend_common_meta_app::schema::UpsertTableCopiedFileReq;
 use databend_common_meta_app::storage::StorageParams;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_storage::init_operator;
@@ -48,16 +52,29 @@ use databend_common_storages_parquet::ParquetPart;
 use databend_common_storages_parquet::ParquetRSPruner;
 use databend_common_storages_parquet::ParquetRSReaderBuilder;
 use databend_storages_common_pruner::RangePrunerCreator;
+use databend_storages_common_table_meta::meta::SnapshotId;
 use icelake::catalog::Catalog;
+use icelake::types::DataFile;
+use icelake::types::ManifestFile;
+use icelake::types::ManifestList;
+use icelake::types::ManifestStatus;
+use icelake::types::Snapshot;
 use opendal::Operator;
 use tokio::sync::OnceCell;
 
+use crate::append::IcebergDataWriter;
+use crate::commit::IcebergCommitSink;
 use crate::partition::IcebergPartInfo;
 use crate::stats::get_stats_of_data_file;
 use crate::table_source::IcebergTableSource;
 
 pub const ICEBERG_ENGINE: &str = "ICEBERG";
 
+/// Table option pinning the snapshot to read, set by time travel.
+pub const OPT_KEY_SNAPSHOT_ID: &str = "snapshot_id";
+/// Table option of the snapshot after which appended rows are read, set by incremental reads.
+pub const OPT_KEY_SINCE_SNAPSHOT_ID: &str = "since_snapshot_id";
+
 /// accessor wrapper as a table
 ///
 /// TODO: we should use icelake Table instead.
@@ -76,6 +93,15 @@ impl IcebergTable {
         }))
     }
 
+    pub fn try_from_table(tbl: &dyn Table) -> Result<&IcebergTable> {
+        tbl.as_any().downcast_ref::<IcebergTable>().ok_or_else(|| {
+            ErrorCode::TableEngineNotSupported(format!(
+                "expects table of engine ICEBERG, but got {}",
+                tbl.engine()
+            ))
+        })
+    }
+
     pub fn description() -> StorageDescription {
         StorageDescription {
             engine_name: ICEBERG_ENGINE.to_string(),
@@ -108,13 +134,21 @@ impl IcebergTable {
 
     pub async fn get_schema(table: &icelake::Table) -> Result<TableSchema> {
         let meta = table.current_table_metadata();
+        Self::get_schema_by_id(table, meta.current_schema_id as i64)
+    }
+
+    /// Returns the schema with id `schema_id`, a snapshot is read with the schema it
+    /// was written with rather than the current one.
+    pub fn get_schema_by_id(table: &icelake::Table, schema_id: i64) -> Result<TableSchema> {
+        let meta = table.current_table_metadata();
 
         // Build arrow schema from iceberg metadata.
         let arrow_schema: ArrowSchema = meta
             .schemas
-            .last()
+            .iter()
+            .find(|schema| schema.schema_id as i64 == schema_id)
             .ok_or_else(|| {
-                ErrorCode::ReadTableDataError("Iceberg table schema is empty".to_string())
+                ErrorCode::ReadTableDataError(format!("Iceberg table schema {schema_id} not found"))
             })?
             .clone()
             .try_into()
@@ -249,6 +283,232 @@ impl IcebergTable {
         )
     }
 
+    fn snapshot_option(&self, key: &str) -> Result<Option<i64>> {
+        self.info
+            .options()
+            .get(key)
+            .map(|v| {
+                v.parse::<i64>().map_err(|_| {
+                    ErrorCode::BadArguments(format!("Invalid iceberg snapshot id '{v}'"))
+                })
+            })
+            .transpose()
+    }
+
+    /// All snapshots of the table, ordered by commit time.
+    pub async fn snapshots(&self) -> Result<Vec<Snapshot>> {
+        let table = self.table().await?;
+        let mut snapshots = table
+            .current_table_metadata()
+            .snapshots
+            .clone()
+            .unwrap_or_default();
+        snapshots.sort_by_key(|s| s.timestamp_ms);
+        Ok(snapshots)
+    }
+
+    /// The snapshot this table reads, `None` if the table has no snapshot yet.
+    pub async fn selected_snapshot(&self) -> Result<Option<Snapshot>> {
+        let table = self.table().await?;
+        let meta = table.current_table_metadata();
+        let snapshot_id = match self.snapshot_option(OPT_KEY_SNAPSHOT_ID)? {
+            Some(id) => id,
+            None => match meta.current_snapshot_id {
+                Some(id) if id >= 0 => id,
+                _ => return Ok(None),
+            },
+        };
+        self.find_snapshot(snapshot_id).await.map(Some)
+    }
+
+    async fn find_snapshot(&self, snapshot_id: i64) -> Result<Snapshot> {
+        self.snapshots()
+            .await?
+            .into_iter()
+            .find(|s| s.snapshot_id == snapshot_id)
+            .ok_or_else(|| {
+                ErrorCode::TableHistoricalDataNotFound(format!(
+                    "No snapshot with id {snapshot_id} found in iceberg table {}",
+                    self.info.name
+                ))
+            })
+    }
+
+    async fn resolve_snapshot(&self, point: &NavigationPoint) -> Result<Snapshot> {
+        match point {
+            NavigationPoint::SnapshotID(id) => {
+                let snapshot_id = id.parse::<i64>().map_err(|_| {
+                    ErrorCode::BadArguments(format!("Invalid iceberg snapshot id '{id}'"))
+                })?;
+                self.find_snapshot(snapshot_id).await
+            }
+            NavigationPoint::TimePoint(time_point) => {
+                // Commit times are not monotonic across branches or rollbacks, the
+                // history of the table is the ancestry of the current snapshot.
+                let ancestors = match self.selected_snapshot().await? {
+                    Some(current) => self.ancestors(&current).await?,
+                    None => vec![],
+                };
+                ancestors
+                    .into_iter()
+                    .find(|s| s.timestamp_ms <= time_point.timestamp_millis())
+                    .ok_or_else(|| {
+                        ErrorCode::TableHistoricalDataNotFound(format!(
+                            "No snapshot of iceberg table {} found at or before {time_point}",
+                            self.info.name
+                        ))
+                    })
+            }
+        }
+    }
+
+    /// Returns `snapshot` followed by its ancestors, the latest first.
+    async fn ancestors(&self, snapshot: &Snapshot) -> Result<Vec<Snapshot>> {
+        let snapshots = self.snapshots().await?;
+        let mut ancestors = vec![snapshot.clone()];
+        let mut parent = snapshot.parent_snapshot_id;
+        while let Some(parent_id) = parent {
+            // The history before an expired snapshot is gone.
+            let Some(s) = snapshots.iter().find(|s| s.snapshot_id == parent_id) else {
+                break;
+            };
+            parent = s.parent_snapshot_id;
+            ancestors.push(s.clone());
+        }
+        Ok(ancestors)
+    }
+
+    /// Returns the snapshots from `end` back to (excluding) `start`,
+    /// failing if `start` is not an ancestor of `end`.
+    async fn snapshots_between(&self, start: i64, end: &Snapshot) -> Result<Vec<Snapshot>> {
+        let mut ancestors = self.ancestors(end).await?;
+        match ancestors.iter().position(|s| s.snapshot_id == start) {
+            Some(pos) => {
+                ancestors.truncate(pos);
+                Ok(ancestors)
+            }
+            None => Err(ErrorCode::TableHistoricalDataNotFound(format!(
+                "Snapshot {start} is not an ancestor of snapshot {} in iceberg table {}",
+                end.snapshot_id, self.info.name
+            ))),
+        }
+    }
+
+    pub async fn read_manifest_list(&self, snapshot: &Snapshot) -> Result<ManifestList> {
+        let table = self.table().await?;
+        let op = init_operator(self.get_storage_params()?)?;
+        let path = table.rel_path(&snapshot.manifest_list).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Invalid manifest list path: {e:?}"))
+        })?;
+        let content = op.read(&path).await?;
+        icelake::types::parse_manifest_list(&content).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Cannot parse manifest list {path}: {e:?}"))
+        })
+    }
+
+    pub async fn read_manifest(&self, manifest_path: &str) -> Result<ManifestFile> {
+        let table = self.table().await?;
+        let op = init_operator(self.get_storage_params()?)?;
+        let path = table
+            .rel_path(manifest_path)
+            .map_err(|e| ErrorCode::ReadTableDataError(format!("Invalid manifest path: {e:?}")))?;
+        let content = op.read(&path).await?;
+        icelake::types::parse_manifest_file(&content).map_err(|e| {
+            ErrorCode::ReadTableDataError(format!("Cannot parse manifest {path}: {e:?}"))
+        })
+    }
+
+    /// Lists the data files to read.
+    ///
+    /// Without options this is the current snapshot. With `snapshot_id` it is the
+    /// given snapshot, and with `since_snapshot_id` as well only the files added by
+    /// the append snapshots after `since_snapshot_id`.
+    async fn data_files(&self) -> Result<Vec<DataFile>> {
+        let Some(snapshot) = self.selected_snapshot().await? else {
+            return Ok(vec![]);
+        };
+        if let Some(since) = self.snapshot_option(OPT_KEY_SINCE_SNAPSHOT_ID)? {
+            return self.appended_data_files(since, &snapshot).await;
+        }
+
+        let manifest_list = self.read_manifest_list(&snapshot).await?;
+        let mut data_files = vec![];
+        for manifest in manifest_list.entries {
+            let manifest_file = self.read_manifest(&manifest.manifest_path).await?;
+            for entry in manifest_file.entries {
+                if entry.status != ManifestStatus::Deleted {
+                    data_files.push(entry.data_file);
+                }
+            }
+        }
+        Ok(data_files)
+    }
+
+    /// Lists the data files added by the append snapshots after `since` up to `end`,
+    /// the replace snapshots are skipped, overwrites and deletes can not be read
+    /// incrementally.
+    ///
+    /// Later commits may rewrite the manifests (spark merges them by default), turning
+    /// the added entries into existing ones. So the files added by a snapshot are taken
+    /// from the manifests the snapshot itself has written, listed by its own manifest list.
+    async fn appended_data_files(&self, since: i64, end: &Snapshot) -> Result<Vec<DataFile>> {
+        let range = self.snapshots_between(since, end).await?;
+        let mut data_files = vec![];
+        for snapshot in range.iter() {
+            match snapshot.summary.get("operation").map(|v| v.as_str()) {
+                Some("append") => {}
+                // Compactions rewrite the existing rows into new files, without
+                // adding any row.
+                Some("replace") => continue,
+                op => {
+                    return Err(ErrorCode::Unimplemented(format!(
+                        "Incremental read of iceberg table {} does not support snapshots which change existing rows, but snapshot {} is '{}'",
+                        self.info.name,
+                        snapshot.snapshot_id,
+                        op.unwrap_or("unknown")
+                    )));
+                }
+            }
+
+            let manifest_list = self.read_manifest_list(snapshot).await?;
+            for manifest in manifest_list.entries {
+                if manifest.added_snapshot_id != snapshot.snapshot_id {
+                    continue;
+                }
+                let manifest_file = self.read_manifest(&manifest.manifest_path).await?;
+                for entry in manifest_file.entries {
+                    let added_by = entry.snapshot_id.unwrap_or(manifest.added_snapshot_id);
+                    if entry.status == ManifestStatus::Added && added_by == snapshot.snapshot_id {
+                        data_files.push(entry.data_file);
+                    }
+                }
+            }
+        }
+        Ok(data_files)
+    }
+
+    /// The schema of the table as of `snapshot`, snapshots written by old versions
+    /// of the format have no schema id and are read with the current schema.
+    async fn snapshot_schema(&self, snapshot: &Snapshot) -> Result<TableSchema> {
+        let table = self.table().await?;
+        match snapshot.schema_id {
+            Some(schema_id) => Self::get_schema_by_id(table, schema_id),
+            None => Self::get_schema(table).await,
+        }
+    }
+
+    fn with_snapshot(&self, schema: TableSchema, options: &[(&str, i64)]) -> Arc<dyn Table> {
+        let mut info = self.info.clone();
+        info.meta.schema = Arc::new(schema);
+        for (key, value) in options {
+            info.meta.options.insert(key.to_string(), value.to_string());
+        }
+        Arc::new(IcebergTable {
+            info,
+            table: OnceCell::new(),
+        })
+    }
+
     #[minitrace::trace]
     #[async_backtrace::framed]
     async fn do_read_partitions(
@@ -257,10 +517,7 @@ impl IcebergTable {
         push_downs: Option<PushDownInfo>,
     ) -> Result<(PartStatistics, Partitions)> {
         let table = self.table().await?;
-
-        let data_files = table.current_data_files().await.map_err(|e| {
-            ErrorCode::ReadTableDataError(format!("Cannot get current data files: {e:?}"))
-        })?;
+        let data_files = self.data_files().await?;
 
         let filter = push_downs.as_ref().and_then(|extra| {
             extra
@@ -359,6 +616,66 @@ impl Table for IcebergTable {
         self.do_read_data(ctx, plan, pipeline)
     }
 
+    fn append_data(
+        &self,
+        _ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        _append_mode: AppendMode,
+    ) -> Result<()> {
+        let op = init_operator(self.get_storage_params()?)?;
+        let schema = self.schema();
+        // Resolved from the table metadata by the first writer.
+        let layout = Arc::new(OnceCell::new());
+        pipeline.add_transform(|input, output| {
+            IcebergDataWriter::try_create(
+                input,
+                output,
+                schema.clone(),
+                self.info.name.clone(),
+                layout.clone(),
+                op.clone(),
+            )
+        })
+    }
+
+    fn commit_insertion(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        pipeline: &mut Pipeline,
+        copied_files: Option<UpsertTableCopiedFileReq>,
+        _update_stream_meta: Vec<UpdateStreamMetaReq>,
+        overwrite: bool,
+        _prev_snapshot_id: Option<SnapshotId>,
+        deduplicated_label: Option<String>,
+    ) -> Result<()> {
+        if overwrite {
+            return Err(ErrorCode::Unimplemented(format!(
+                "INSERT OVERWRITE is not supported for iceberg table {}, only appends are",
+                self.info.name
+            )));
+        }
+        // The loaded files are not recorded with the iceberg snapshot, a COPY which
+        // skips the loaded files would load them again on every run.
+        if copied_files.is_some_and(|req| req.fail_if_duplicated) {
+            return Err(ErrorCode::Unimplemented(format!(
+                "COPY INTO iceberg table {} requires FORCE = TRUE, the loaded files are not recorded",
+                self.info.name
+            )));
+        }
+        if deduplicated_label.is_some() {
+            return Err(ErrorCode::Unimplemented(format!(
+                "Deduplicate label is not supported for iceberg table {}",
+                self.info.name
+            )));
+        }
+
+        let op = init_operator(self.get_storage_params()?)?;
+        pipeline.try_resize(1)?;
+        pipeline.add_sink(|input| {
+            IcebergCommitSink::try_create(input, ctx.clone(), op.clone(), self.info.clone())
+        })
+    }
+
     fn table_args(&self) -> Option<TableArgs> {
         None
     }
@@ -370,6 +687,42 @@ impl Table for IcebergTable {
     fn support_prewhere(&self) -> bool {
         true
     }
+
+    #[async_backtrace::framed]
+    async fn navigate_to(&self, point: &NavigationPoint) -> Result<Arc<dyn Table>> {
+        let snapshot = self.resolve_snapshot(point).await?;
+        let schema = self.snapshot_schema(&snapshot).await?;
+        Ok(self.with_snapshot(schema, &[(OPT_KEY_SNAPSHOT_ID, snapshot.snapshot_id)]))
+    }
+
+    fn support_navigate_appends(&self) -> bool {
+        true
+    }
+
+    #[async_backtrace::framed]
+    async fn navigate_appends(
+        &self,
+        start: &NavigationPoint,
+        end: Option<&NavigationPoint>,
+    ) -> Result<Arc<dyn Table>> {
+        let start = self.resolve_snapshot(start).await?;
+        let end = match end {
+            Some(end) => self.resolve_snapshot(end).await?,
+            None => self.selected_snapshot().await?.ok_or_else(|| {
+                ErrorCode::TableHistoricalDataNotFound(format!(
+                    "Iceberg table {} has no snapshot",
+                    self.info.name
+                ))
+            })?,
+        };
+        // Validate the range eagerly so that a bad interval fails at bind time.
+        self.snapshots_between(start.snapshot_id, &end).await?;
+        let schema = self.snapshot_schema(&end).await?;
+        Ok(self.with_snapshot(schema, &[
+            (OPT_KEY_SNAPSHOT_ID, end.snapshot_id),
+            (OPT_KEY_SINCE_SNAPSHOT_ID, start.snapshot_id),
+        ]))
+    }
 }
 
 struct OperatorCreatorWrapper(DataOperator);
@@ -27,18 +27,20 @@ pub struct KeyColumnUsageTable {}
 impl KeyColumnUsageTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let query = "SELECT \
-        NULL as constraint_catalog, \
-        NULL as constraint_schema, \
-        NULL as constraint_name, \
-        NULL as table_catalog, \
-        NULL as table_schema, \
-        NULL as table_name, \
-        NULL as column_name, \
-        NULL as ordinal_position, \
-        NULL as position_in_unique_constraint, \
-        NULL as referenced_table_schema, \
-        NULL as referenced_table_name, \
-        NULL as referenced_column_name"
+        'default' AS constraint_catalog, \
+        database AS constraint_schema, \
+        name AS constraint_name, \
+        'default' AS table_catalog, \
+        database AS table_schema, \
+        table AS table_name, \
+        column_name AS column_name, \
+        ordinal_position AS ordinal_position, \
+        if(constraint_type = 'FOREIGN KEY', ordinal_position, NULL) AS position_in_unique_constraint, \
+        referenced_database AS referenced_table_schema, \
+        referenced_table AS referenced_table_name, \
+        referenced_column AS referenced_column_name \
+        FROM system.constraints \
+        WHERE constraint_type <> 'CHECK'"
             .to_string();
 
         let mut options = BTreeMap::new();
@@ -12,18 +12,22 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+mod check_constraints_table;
 mod columns_table;
 mod key_column_usage_table;
 mod keywords_table;
 mod schemata_table;
 mod statistics_table;
+mod table_constraints_table;
 mod tables_table;
 mod views_table;
 
+pub use check_constraints_table::CheckConstraintsTable;
 pub use columns_table::ColumnsTable;
 pub use key_column_usage_table::KeyColumnUsageTable;
 pub use keywords_table::KeywordsTable;
 pub use schemata_table::SchemataTable;
 pub use statistics_table::StatisticsTable;
+pub use table_constraints_table::TableConstraintsTable;
 pub use tables_table::TablesTable;
 pub use views_table::ViewsTable;
@@ -54,16 +54,18 @@ impl StageTable {
         let uuid = uuid::Uuid::new_v4().to_string();
         let group_id = AtomicUsize::new(0);
         match fmt {
-            FileFormatParams::Parquet(_) => append_data_to_parquet_files(
-                pipeline,
-                ctx.clone(),
-                self.table_info.clone(),
-                op,
-                max_file_size,
-                max_threads,
-                uuid,
-                &group_id,
-            )?,
+            FileFormatParams::Parquet(_) | FileFormatParams::Orc(_) => {
+                append_data_to_parquet_files(
+                    pipeline,
+                    ctx.clone(),
+                    self.table_info.clone(),
+                    op,
+                    max_file_size,
+                    max_threads,
+                    uuid,
+                    &group_id,
+                )?
+            }
             _ => append_data_to_row_based_files(
                 pipeline,
                 ctx.clone(),
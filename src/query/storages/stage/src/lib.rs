@@ -15,6 +15,7 @@
 #![allow(clippy::uninlined_format_args)]
 
 mod append;
+mod orc_pruner;
 mod stage_table;
 
 pub use stage_table::StageTable;
@@ -0,0 +1,77 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::DataType;
+use databend_common_expression::TableSchemaRef;
+use databend_common_functions::BUILTIN_FUNCTIONS;
+use databend_common_orc::OrcFileMeta;
+use databend_storages_common_pruner::RangePruner;
+use databend_storages_common_pruner::RangePrunerCreator;
+use databend_storages_common_table_meta::meta::ColumnStatistics;
+use databend_storages_common_table_meta::meta::StatisticsOfColumns;
+
+/// Prunes ORC stripes with the pushed down filter and the stripe statistics.
+pub(crate) struct OrcStripePruner {
+    schema: TableSchemaRef,
+    pruner: Arc<dyn RangePruner + Send + Sync>,
+}
+
+impl OrcStripePruner {
+    /// Returns `None` if there is no filter to prune with.
+    pub fn try_create(
+        ctx: &Arc<dyn TableContext>,
+        schema: TableSchemaRef,
+        push_downs: &Option<PushDownInfo>,
+    ) -> Result<Option<Self>> {
+        let Some(filters) = push_downs.as_ref().and_then(|p| p.filters.as_ref()) else {
+            return Ok(None);
+        };
+        let filter = filters.filter.as_expr(&BUILTIN_FUNCTIONS);
+        let pruner =
+            RangePrunerCreator::try_create(ctx.get_function_context()?, &schema, Some(&filter))?;
+        Ok(Some(Self { schema, pruner }))
+    }
+
+    pub fn should_keep(&self, meta: &OrcFileMeta, stripe_index: usize) -> bool {
+        let mut stats = StatisticsOfColumns::new();
+        for (field_index, name) in meta.schema.root().field_names.iter().enumerate() {
+            let Ok(field) = self.schema.field_with_name(name) else {
+                continue;
+            };
+            // Only primitive columns have a single leaf to attach the statistics to.
+            let column_ids = self.schema.leaf_columns_of(name);
+            if column_ids.len() != 1 {
+                continue;
+            }
+            let Some(stat) = meta.stripe_field_statistics(stripe_index, field_index) else {
+                continue;
+            };
+            // The file may not match the schema the query was bound with.
+            let data_type = DataType::from(field.data_type()).remove_nullable();
+            if stat.min.as_ref().infer_data_type() != data_type {
+                continue;
+            }
+            stats.insert(
+                column_ids[0],
+                ColumnStatistics::new(stat.min, stat.max, stat.null_count, 0, None),
+            );
+        }
+        self.pruner.should_keep(&stats, None)
+    }
+}
@@ -33,15 +33,20 @@ use databend_common_catalog::table_context::TableContext;
 use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::BlockThresholds;
+use databend_common_expression::TableSchemaRef;
 use databend_common_expression::TableSchemaRefExt;
 use databend_common_meta_app::principal::FileFormatParams;
 use databend_common_meta_app::principal::StageInfo;
 use databend_common_meta_app::schema::TableInfo;
+use databend_common_orc::read_orc_metadata;
 use databend_common_pipeline_core::Pipeline;
 use databend_common_pipeline_sources::input_formats::InputContext;
+use databend_common_pipeline_sources::input_formats::InputFormatOrc;
 use databend_common_pipeline_sources::input_formats::SplitInfo;
 use databend_common_storage::init_stage_operator;
+use databend_common_storage::read_avro_schema_async;
 use databend_common_storage::StageFileInfo;
+use databend_common_storage::StageFilesInfo;
 use databend_common_storage::STDIN_FD;
 use databend_common_storages_parquet::ParquetTableForCopy;
 use log::debug;
@@ -49,6 +54,8 @@ use opendal::Operator;
 use opendal::Scheme;
 use parking_lot::Mutex;
 
+use crate::orc_pruner::OrcStripePruner;
+
 /// TODO: we need to track the data metrics in stage table.
 pub struct StageTable {
     pub(crate) table_info: StageTableInfo,
@@ -92,6 +99,39 @@ impl StageTable {
         stage_info.list_files(max_files).await
     }
 
+    /// Infer the schema of ORC files from the footer of the first one.
+    #[async_backtrace::framed]
+    pub async fn infer_orc_schema(
+        stage_info: &StageInfo,
+        files_info: &StageFilesInfo,
+        files_to_copy: Option<&[StageFileInfo]>,
+    ) -> Result<TableSchemaRef> {
+        let operator = Self::get_op(stage_info)?;
+        let first_file = match files_to_copy.and_then(|files| files.first()) {
+            Some(file) => file.clone(),
+            None => files_info.first_file(&operator).await?,
+        };
+        let meta = read_orc_metadata(&operator, &first_file.path, Some(first_file.size)).await?;
+        Ok(Arc::new(meta.schema.to_table_schema()?))
+    }
+
+    /// Infer the schema of Avro files from the writer schema of the first one.
+    #[async_backtrace::framed]
+    pub async fn infer_avro_schema(
+        stage_info: &StageInfo,
+        files_info: &StageFilesInfo,
+        files_to_copy: Option<&[StageFileInfo]>,
+    ) -> Result<TableSchemaRef> {
+        let operator = Self::get_op(stage_info)?;
+        let first_file = match files_to_copy.and_then(|files| files.first()) {
+            Some(file) => file.clone(),
+            None => files_info.first_file(&operator).await?,
+        };
+        let schema =
+            read_avro_schema_async(&operator, &first_file.path, Some(first_file.size)).await?;
+        Ok(Arc::new(schema))
+    }
+
     fn get_block_compact_thresholds_with_default(&self) -> BlockThresholds {
         let guard = self.block_compact_threshold.lock();
         guard.deref().unwrap_or_default()
@@ -117,7 +157,7 @@ impl Table for StageTable {
     async fn read_partitions(
         &self,
         ctx: Arc<dyn TableContext>,
-        _push_downs: Option<PushDownInfo>,
+        push_downs: Option<PushDownInfo>,
         _dry_run: bool,
     ) -> Result<(PartStatistics, Partitions)> {
         let stage_info = &self.table_info;
@@ -125,7 +165,7 @@ impl Table for StageTable {
             stage_info.stage_info.file_format_params,
             FileFormatParams::Parquet(_)
         ) {
-            return ParquetTableForCopy::do_read_partitions(stage_info, ctx, _push_downs).await;
+            return ParquetTableForCopy::do_read_partitions(stage_info, ctx, push_downs).await;
         }
         // User set the files.
         let files = if let Some(files) = &stage_info.files_to_copy {
@@ -133,16 +173,29 @@ impl Table for StageTable {
         } else {
             StageTable::list_files(stage_info, None).await?
         };
-        let format = InputContext::get_input_format(&stage_info.stage_info.file_format_params)?;
         let operator = StageTable::get_op(&stage_info.stage_info)?;
-        let splits = format
-            .get_splits(
-                files,
-                &stage_info.stage_info,
-                &operator,
-                &ctx.get_settings(),
-            )
-            .await?;
+        let splits = if matches!(
+            stage_info.stage_info.file_format_params,
+            FileFormatParams::Orc(_)
+        ) {
+            let pruner = OrcStripePruner::try_create(&ctx, stage_info.schema.clone(), &push_downs)?;
+            InputFormatOrc::get_stripe_splits(files, &operator, |meta, stripe_index| {
+                pruner
+                    .as_ref()
+                    .map_or(true, |p| p.should_keep(meta, stripe_index))
+            })
+            .await?
+        } else {
+            let format = InputContext::get_input_format(&stage_info.stage_info.file_format_params)?;
+            format
+                .get_splits(
+                    files,
+                    &stage_info.stage_info,
+                    &operator,
+                    &ctx.get_settings(),
+                )
+                .await?
+        };
 
         let partitions = splits
             .into_iter()
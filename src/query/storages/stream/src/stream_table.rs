@@ -55,9 +55,11 @@ use databend_common_storages_fuse::io::SnapshotsIO;
 use databend_common_storages_fuse::FuseTable;
 use databend_storages_common_table_meta::meta::BlockMeta;
 use databend_storages_common_table_meta::meta::SegmentInfo;
+use databend_storages_common_table_meta::meta::TableSnapshot;
 use databend_storages_common_table_meta::table::ChangeType;
 use databend_storages_common_table_meta::table::StreamMode;
 use databend_storages_common_table_meta::table::OPT_KEY_DATABASE_NAME;
+use databend_storages_common_table_meta::table::OPT_KEY_END_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_MODE;
 use databend_storages_common_table_meta::table::OPT_KEY_SNAPSHOT_LOCATION;
 use databend_storages_common_table_meta::table::OPT_KEY_TABLE_ID;
@@ -82,6 +84,7 @@ pub struct StreamTable {
     table_version: u64,
     mode: StreamMode,
     snapshot_location: Option<String>,
+    end_snapshot_location: Option<String>,
 }
 
 impl StreamTable {
@@ -108,6 +111,7 @@ impl StreamTable {
             .and_then(|s| s.parse::<StreamMode>().ok())
             .unwrap_or(StreamMode::AppendOnly);
         let snapshot_location = options.get(OPT_KEY_SNAPSHOT_LOCATION).cloned();
+        let end_snapshot_location = options.get(OPT_KEY_END_SNAPSHOT_LOCATION).cloned();
         Ok(Box::new(StreamTable {
             stream_info: table_info,
             table_name,
@@ -116,6 +120,7 @@ impl StreamTable {
             table_version,
             mode,
             snapshot_location,
+            end_snapshot_location,
         }))
     }
 
@@ -175,6 +180,18 @@ impl StreamTable {
         self.snapshot_location.clone()
     }
 
+    /// The snapshot up to which the changes are read, `None` means the latest snapshot
+    /// of the source table.
+    pub fn end_snapshot_loc(&self) -> Option<String> {
+        self.end_snapshot_location.clone()
+    }
+
+    /// Returns true if reading the stream moves its offset, which is false for the
+    /// streams built for the `CHANGES` clause.
+    pub fn is_consumable(&self) -> bool {
+        self.end_snapshot_location.is_none()
+    }
+
     pub fn source_table_name(&self) -> &str {
         &self.table_name
     }
@@ -187,13 +204,23 @@ impl StreamTable {
         &self.table_database
     }
 
+    /// Returns true if some blocks of the offset snapshot were removed from the source table,
+    /// i.e. the rows of the source table were updated or deleted since the offset.
+    #[async_backtrace::framed]
+    pub async fn has_removed_blocks(&self, ctx: Arc<dyn TableContext>) -> Result<bool> {
+        let table = self.source_table(ctx.clone()).await?;
+        let fuse_table = FuseTable::try_from_table(table.as_ref())?;
+        let (del_blocks, _) = self.collect_incremental_blocks(ctx, fuse_table).await?;
+        Ok(!del_blocks.is_empty())
+    }
+
     async fn collect_incremental_blocks(
         &self,
         ctx: Arc<dyn TableContext>,
         fuse_table: &FuseTable,
     ) -> Result<(Vec<Arc<BlockMeta>>, Vec<Arc<BlockMeta>>)> {
         let operator = fuse_table.get_operator();
-        let latest_segments = if let Some(snapshot) = fuse_table.read_table_snapshot().await? {
+        let latest_segments = if let Some(snapshot) = self.read_end_snapshot(fuse_table).await? {
             HashSet::from_iter(snapshot.segments.clone())
         } else {
             HashSet::new()
@@ -254,6 +281,21 @@ impl StreamTable {
         Ok((del_blocks, add_blocks))
     }
 
+    /// Reads the snapshot the changes end at, by default the latest snapshot of the source table.
+    async fn read_end_snapshot(
+        &self,
+        fuse_table: &FuseTable,
+    ) -> Result<Option<Arc<TableSnapshot>>> {
+        match &self.end_snapshot_location {
+            Some(location) => {
+                let (snapshot, _) =
+                    SnapshotsIO::read_snapshot(location.clone(), fuse_table.get_operator()).await?;
+                Ok(Some(snapshot))
+            }
+            None => fuse_table.read_table_snapshot().await,
+        }
+    }
+
     #[async_backtrace::framed]
     async fn do_read_partitions(
         &self,
@@ -413,7 +455,7 @@ impl Table for StreamTable {
         let table = self.source_table(ctx.clone()).await?;
         let fuse_table = FuseTable::try_from_table(table.as_ref())?;
 
-        let latest_summary = if let Some(snapshot) = fuse_table.read_table_snapshot().await? {
+        let latest_summary = if let Some(snapshot) = self.read_end_snapshot(fuse_table).await? {
             snapshot.summary.clone()
         } else {
             return Ok(None);
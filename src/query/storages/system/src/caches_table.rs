@@ -29,6 +29,7 @@ use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_storages_fuse::TableContext;
 use databend_storages_common_cache::CacheAccessor;
+use databend_storages_common_cache::CacheStatistics;
 use databend_storages_common_cache_manager::CacheManager;
 
 use crate::SyncOneBlockSystemTable;
@@ -50,10 +51,7 @@ impl SyncSystemTable for CachesTable {
 
     fn get_full_data(&self, ctx: Arc<dyn TableContext>) -> Result<DataBlock> {
         let local_node = ctx.get_cluster().local_id.clone();
-        let mut nodes = Vec::new();
-        let mut names = Vec::new();
-        let mut num_items = Vec::new();
-        let mut size = Vec::new();
+        let mut columns = CachesTableColumns::default();
 
         let cache_manager = CacheManager::instance();
 
@@ -68,76 +66,150 @@ impl SyncSystemTable for CachesTable {
         let table_column_array_cache = cache_manager.get_table_data_array_cache();
 
         if let Some(table_snapshot_cache) = table_snapshot_cache {
-            nodes.push(local_node.clone());
-            names.push("table_snapshot_cache".to_string());
-            num_items.push(table_snapshot_cache.len() as u64);
-            size.push(table_snapshot_cache.size());
+            columns.append_row(
+                &local_node,
+                "table_snapshot_cache",
+                table_snapshot_cache.len(),
+                table_snapshot_cache.size(),
+                table_snapshot_cache.policy(),
+                table_snapshot_cache.statistics(),
+            );
         }
+
         if let Some(table_snapshot_statistic_cache) = table_snapshot_statistic_cache {
-            nodes.push(local_node.clone());
-            names.push("table_snapshot_statistic_cache".to_string());
-            num_items.push(table_snapshot_statistic_cache.len() as u64);
-            size.push(table_snapshot_statistic_cache.size());
+            columns.append_row(
+                &local_node,
+                "table_snapshot_statistic_cache",
+                table_snapshot_statistic_cache.len(),
+                table_snapshot_statistic_cache.size(),
+                table_snapshot_statistic_cache.policy(),
+                table_snapshot_statistic_cache.statistics(),
+            );
         }
 
         if let Some(segment_info_cache) = segment_info_cache {
-            nodes.push(local_node.clone());
-            names.push("segment_info_cache".to_string());
-            num_items.push(segment_info_cache.len() as u64);
-            size.push(segment_info_cache.size());
+            columns.append_row(
+                &local_node,
+                "segment_info_cache",
+                segment_info_cache.len(),
+                segment_info_cache.size(),
+                segment_info_cache.policy(),
+                segment_info_cache.statistics(),
+            );
         }
 
         if let Some(bloom_index_filter_cache) = bloom_index_filter_cache {
-            nodes.push(local_node.clone());
-            names.push("bloom_index_filter_cache".to_string());
-            num_items.push(bloom_index_filter_cache.len() as u64);
-            size.push(bloom_index_filter_cache.size());
+            columns.append_row(
+                &local_node,
+                "bloom_index_filter_cache",
+                bloom_index_filter_cache.len(),
+                bloom_index_filter_cache.size(),
+                bloom_index_filter_cache.policy(),
+                bloom_index_filter_cache.statistics(),
+            );
         }
 
         if let Some(bloom_index_meta_cache) = bloom_index_meta_cache {
-            nodes.push(local_node.clone());
-            names.push("bloom_index_meta_cache".to_string());
-            num_items.push(bloom_index_meta_cache.len() as u64);
-            size.push(bloom_index_meta_cache.size());
+            columns.append_row(
+                &local_node,
+                "bloom_index_meta_cache",
+                bloom_index_meta_cache.len(),
+                bloom_index_meta_cache.size(),
+                bloom_index_meta_cache.policy(),
+                bloom_index_meta_cache.statistics(),
+            );
         }
 
         if let Some(prune_partitions_cache) = prune_partitions_cache {
-            nodes.push(local_node.clone());
-            names.push("prune_partitions_cache".to_string());
-            num_items.push(prune_partitions_cache.len() as u64);
-            size.push(prune_partitions_cache.size());
+            columns.append_row(
+                &local_node,
+                "prune_partitions_cache",
+                prune_partitions_cache.len(),
+                prune_partitions_cache.size(),
+                prune_partitions_cache.policy(),
+                prune_partitions_cache.statistics(),
+            );
         }
 
         if let Some(file_meta_data_cache) = file_meta_data_cache {
-            nodes.push(local_node.clone());
-            names.push("file_meta_data_cache".to_string());
-            num_items.push(file_meta_data_cache.len() as u64);
-            size.push(file_meta_data_cache.size());
+            columns.append_row(
+                &local_node,
+                "file_meta_data_cache",
+                file_meta_data_cache.len(),
+                file_meta_data_cache.size(),
+                file_meta_data_cache.policy(),
+                file_meta_data_cache.statistics(),
+            );
         }
 
         if let Some(table_data_cache) = table_data_cache {
-            nodes.push(local_node.clone());
-            names.push("table_data_cache".to_string());
-            num_items.push(table_data_cache.len() as u64);
-            size.push(table_data_cache.size());
+            columns.append_row(
+                &local_node,
+                "table_data_cache",
+                table_data_cache.len(),
+                table_data_cache.size(),
+                table_data_cache.policy(),
+                table_data_cache.statistics(),
+            );
         }
 
         if let Some(table_column_array_cache) = table_column_array_cache {
-            nodes.push(local_node.clone());
-            names.push("table_column_array_cache".to_string());
-            num_items.push(table_column_array_cache.len() as u64);
-            size.push(table_column_array_cache.size());
+            columns.append_row(
+                &local_node,
+                "table_column_array_cache",
+                table_column_array_cache.len(),
+                table_column_array_cache.size(),
+                table_column_array_cache.policy(),
+                table_column_array_cache.statistics(),
+            );
         }
 
         Ok(DataBlock::new_from_columns(vec![
-            StringType::from_data(nodes),
-            StringType::from_data(names),
-            UInt64Type::from_data(num_items),
-            UInt64Type::from_data(size),
+            StringType::from_data(columns.nodes),
+            StringType::from_data(columns.names),
+            UInt64Type::from_data(columns.num_items),
+            UInt64Type::from_data(columns.size),
+            StringType::from_data(columns.policy),
+            UInt64Type::from_data(columns.access),
+            UInt64Type::from_data(columns.hit),
+            UInt64Type::from_data(columns.miss),
         ]))
     }
 }
 
+#[derive(Default)]
+struct CachesTableColumns {
+    nodes: Vec<String>,
+    names: Vec<String>,
+    num_items: Vec<u64>,
+    size: Vec<u64>,
+    policy: Vec<String>,
+    access: Vec<u64>,
+    hit: Vec<u64>,
+    miss: Vec<u64>,
+}
+
+impl CachesTableColumns {
+    fn append_row(
+        &mut self,
+        node: &str,
+        name: &str,
+        num_items: usize,
+        size: u64,
+        policy: impl ToString,
+        statistics: &CacheStatistics,
+    ) {
+        self.nodes.push(node.to_string());
+        self.names.push(name.to_string());
+        self.num_items.push(num_items as u64);
+        self.size.push(size);
+        self.policy.push(policy.to_string());
+        self.access.push(statistics.accesses());
+        self.hit.push(statistics.hits());
+        self.miss.push(statistics.misses());
+    }
+}
+
 impl CachesTable {
     pub fn create(table_id: u64) -> Arc<dyn Table> {
         let schema = TableSchemaRefExt::create(vec![
@@ -145,6 +217,10 @@ impl CachesTable {
             TableField::new("name", TableDataType::String),
             TableField::new("num_items", TableDataType::Number(NumberDataType::UInt64)),
             TableField::new("size", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("policy", TableDataType::String),
+            TableField::new("access", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("hit", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("miss", TableDataType::Number(NumberDataType::UInt64)),
         ]);
 
         let table_info = TableInfo {
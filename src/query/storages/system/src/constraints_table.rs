@@ -0,0 +1,177 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::DataBlock;
+use databend_common_expression::FromData;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableConstraint;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_storages_fuse::TableContext;
+
+use crate::columns_table::dump_tables;
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// Lists the constraints of tables, one row for each column of a key constraint,
+/// and one row for each CHECK constraint.
+pub struct ConstraintsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for ConstraintsTable {
+    const NAME: &'static str = "system.constraints";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let mut databases = vec![];
+        let mut tables = vec![];
+        let mut names = vec![];
+        let mut constraint_types = vec![];
+        let mut column_names = vec![];
+        let mut ordinal_positions = vec![];
+        let mut expressions = vec![];
+        let mut referenced_databases = vec![];
+        let mut referenced_tables = vec![];
+        let mut referenced_columns = vec![];
+
+        let database_and_tables = dump_tables(&ctx, push_downs).await?;
+        for (database, database_tables) in database_and_tables {
+            for table in database_tables {
+                for (name, constraint) in table.get_table_info().meta.constraints.iter() {
+                    let mut push_row =
+                        |column: Option<String>,
+                         position: Option<u64>,
+                         expression: Option<String>,
+                         referenced: Option<(String, String, String)>| {
+                            databases.push(database.clone());
+                            tables.push(table.name().to_string());
+                            names.push(name.clone());
+                            constraint_types.push(constraint.constraint_type().to_string());
+                            column_names.push(column);
+                            ordinal_positions.push(position);
+                            expressions.push(expression);
+                            let (ref_database, ref_table, ref_column) = match referenced {
+                                Some((db, table, column)) => (Some(db), Some(table), Some(column)),
+                                None => (None, None, None),
+                            };
+                            referenced_databases.push(ref_database);
+                            referenced_tables.push(ref_table);
+                            referenced_columns.push(ref_column);
+                        };
+
+                    match constraint {
+                        TableConstraint::Check { expression } => {
+                            push_row(None, None, Some(expression.clone()), None);
+                        }
+                        TableConstraint::PrimaryKey { columns }
+                        | TableConstraint::Unique { columns } => {
+                            for (i, column) in columns.iter().enumerate() {
+                                push_row(Some(column.clone()), Some(i as u64 + 1), None, None);
+                            }
+                        }
+                        TableConstraint::ForeignKey {
+                            columns,
+                            ref_database,
+                            ref_table,
+                            ref_columns,
+                        } => {
+                            for (i, (column, ref_column)) in
+                                columns.iter().zip(ref_columns.iter()).enumerate()
+                            {
+                                push_row(
+                                    Some(column.clone()),
+                                    Some(i as u64 + 1),
+                                    None,
+                                    Some((
+                                        ref_database.clone(),
+                                        ref_table.clone(),
+                                        ref_column.clone(),
+                                    )),
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(databases),
+            StringType::from_data(tables),
+            StringType::from_data(names),
+            StringType::from_data(constraint_types),
+            StringType::from_opt_data(column_names),
+            UInt64Type::from_opt_data(ordinal_positions),
+            StringType::from_opt_data(expressions),
+            StringType::from_opt_data(referenced_databases),
+            StringType::from_opt_data(referenced_tables),
+            StringType::from_opt_data(referenced_columns),
+        ]))
+    }
+}
+
+impl ConstraintsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let nullable_string = TableDataType::Nullable(Box::new(TableDataType::String));
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("database", TableDataType::String),
+            TableField::new("table", TableDataType::String),
+            TableField::new("name", TableDataType::String),
+            TableField::new("constraint_type", TableDataType::String),
+            TableField::new("column_name", nullable_string.clone()),
+            TableField::new(
+                "ordinal_position",
+                TableDataType::Nullable(Box::new(TableDataType::Number(NumberDataType::UInt64))),
+            ),
+            TableField::new("expression", nullable_string.clone()),
+            TableField::new("referenced_database", nullable_string.clone()),
+            TableField::new("referenced_table", nullable_string.clone()),
+            TableField::new("referenced_column", nullable_string),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'constraints'".to_string(),
+            name: "constraints".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemConstraints".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        AsyncOneBlockSystemTable::create(Self { table_info })
+    }
+}
@@ -30,6 +30,7 @@ mod clustering_history_table;
 mod clusters_table;
 mod columns_table;
 mod configs_table;
+mod constraints_table;
 mod contributors_table;
 mod credits_table;
 mod databases_table;
@@ -43,6 +44,8 @@ mod malloc_stats_totals_table;
 mod metrics_table;
 mod one_table;
 mod password_policies_table;
+mod pipe_history_table;
+mod pipes_table;
 mod processes_table;
 mod processor_profile_table;
 mod query_cache_table;
@@ -62,6 +65,7 @@ mod user_functions_table;
 mod users_table;
 mod util;
 mod virtual_columns_table;
+mod workload_groups_table;
 
 pub use background_jobs_table::BackgroundJobTable;
 pub use background_tasks_table::BackgroundTaskTable;
@@ -75,6 +79,7 @@ pub use clustering_history_table::ClusteringHistoryTable;
 pub use clusters_table::ClustersTable;
 pub use columns_table::ColumnsTable;
 pub use configs_table::ConfigsTable;
+pub use constraints_table::ConstraintsTable;
 pub use contributors_table::ContributorsTable;
 pub use credits_table::CreditsTable;
 pub use databases_table::DatabasesTable;
@@ -90,6 +95,9 @@ pub use malloc_stats_totals_table::MallocStatsTotalsTable;
 pub use metrics_table::MetricsTable;
 pub use one_table::OneTable;
 pub use password_policies_table::PasswordPoliciesTable;
+pub use pipe_history_table::PipeHistoryTable;
+pub use pipes_table::parse_pipes_to_datablock;
+pub use pipes_table::PipesTable;
 pub use processes_table::ProcessesTable;
 pub use processor_profile_table::ProcessorProfileTable;
 pub use query_cache_table::QueryCacheTable;
@@ -116,3 +124,4 @@ pub use tracing_table::TracingTable;
 pub use user_functions_table::UserFunctionsTable;
 pub use users_table::UsersTable;
 pub use virtual_columns_table::VirtualColumnsTable;
+pub use workload_groups_table::WorkloadGroupsTable;
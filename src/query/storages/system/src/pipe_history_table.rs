@@ -0,0 +1,126 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+/// The runs of the pipes in the last 14 days.
+pub struct PipeHistoryTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for PipeHistoryTable {
+    const NAME: &'static str = "system.pipe_history";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let loads = UserApiProvider::instance()
+            .get_pipe_loads(&tenant, None)
+            .await?;
+
+        let mut pipe_name = Vec::with_capacity(loads.len());
+        let mut start_time = Vec::with_capacity(loads.len());
+        let mut end_time = Vec::with_capacity(loads.len());
+        let mut files_loaded = Vec::with_capacity(loads.len());
+        let mut rows_loaded = Vec::with_capacity(loads.len());
+        let mut status = Vec::with_capacity(loads.len());
+        let mut error = Vec::with_capacity(loads.len());
+        for load in loads {
+            pipe_name.push(load.pipe_name);
+            start_time.push(load.start_on.timestamp_micros());
+            end_time.push(load.end_on.timestamp_micros());
+            files_loaded.push(load.files_loaded);
+            rows_loaded.push(load.rows_loaded);
+            status.push(if load.error.is_some() {
+                "FAILED".to_string()
+            } else {
+                "SUCCEEDED".to_string()
+            });
+            error.push(load.error);
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(pipe_name),
+            TimestampType::from_data(start_time),
+            TimestampType::from_data(end_time),
+            UInt64Type::from_data(files_loaded),
+            UInt64Type::from_data(rows_loaded),
+            StringType::from_data(status),
+            StringType::from_opt_data(error),
+        ]))
+    }
+}
+
+impl PipeHistoryTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("pipe_name", TableDataType::String),
+            TableField::new("start_time", TableDataType::Timestamp),
+            TableField::new("end_time", TableDataType::Timestamp),
+            TableField::new(
+                "files_loaded",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("rows_loaded", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("status", TableDataType::String),
+            TableField::new(
+                "error",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'pipe_history'".to_string(),
+            name: "pipe_history".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPipeHistory".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(PipeHistoryTable { table_info })
+    }
+}
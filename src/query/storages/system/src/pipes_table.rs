@@ -0,0 +1,130 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::BooleanType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::principal::PipeInfo;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub fn parse_pipes_to_datablock(pipes: Vec<PipeInfo>) -> DataBlock {
+    let mut created_on = Vec::with_capacity(pipes.len());
+    let mut name = Vec::with_capacity(pipes.len());
+    let mut definition = Vec::with_capacity(pipes.len());
+    let mut auto_ingest = Vec::with_capacity(pipes.len());
+    let mut execution_state = Vec::with_capacity(pipes.len());
+    let mut comment = Vec::with_capacity(pipes.len());
+    let mut last_load_on = Vec::with_capacity(pipes.len());
+    let mut last_error = Vec::with_capacity(pipes.len());
+    for pipe in pipes {
+        created_on.push(pipe.create_on.timestamp_micros());
+        name.push(pipe.name);
+        definition.push(pipe.copy_sql);
+        auto_ingest.push(pipe.auto_ingest);
+        execution_state.push(if pipe.execution_paused {
+            "PAUSED".to_string()
+        } else {
+            "RUNNING".to_string()
+        });
+        comment.push(pipe.comment);
+        last_load_on.push(pipe.last_load_on.map(|t| t.timestamp_micros()));
+        last_error.push(pipe.last_error);
+    }
+
+    DataBlock::new_from_columns(vec![
+        TimestampType::from_data(created_on),
+        StringType::from_data(name),
+        StringType::from_data(definition),
+        BooleanType::from_data(auto_ingest),
+        StringType::from_data(execution_state),
+        StringType::from_data(comment),
+        TimestampType::from_opt_data(last_load_on),
+        StringType::from_opt_data(last_error),
+    ])
+}
+
+pub struct PipesTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for PipesTable {
+    const NAME: &'static str = "system.pipes";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let pipes = UserApiProvider::instance().get_pipes(&tenant).await?;
+        Ok(parse_pipes_to_datablock(pipes))
+    }
+}
+
+impl PipesTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new("name", TableDataType::String),
+            TableField::new("definition", TableDataType::String),
+            TableField::new("auto_ingest", TableDataType::Boolean),
+            TableField::new("execution_state", TableDataType::String),
+            TableField::new("comment", TableDataType::String),
+            TableField::new(
+                "last_load_on",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+            TableField::new(
+                "last_error",
+                TableDataType::Nullable(Box::new(TableDataType::String)),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'pipes'".to_string(),
+            name: "pipes".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemPipes".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(PipesTable { table_info })
+    }
+}
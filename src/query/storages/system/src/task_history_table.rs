@@ -23,7 +23,6 @@ use databend_common_cloud_control::pb::ShowTaskRunsRequest;
 use databend_common_cloud_control::pb::TaskRun;
 use databend_common_cloud_control::task_client::make_request;
 use databend_common_config::GlobalConfig;
-use databend_common_exception::ErrorCode;
 use databend_common_exception::Result;
 use databend_common_expression::infer_table_schema;
 use databend_common_expression::types::Int32Type;
@@ -38,6 +37,7 @@ use databend_common_meta_app::schema::TableIdent;
 use databend_common_meta_app::schema::TableInfo;
 use databend_common_meta_app::schema::TableMeta;
 use databend_common_sql::plans::task_run_schema;
+use databend_common_users::UserApiProvider;
 
 use crate::table::AsyncOneBlockSystemTable;
 use crate::table::AsyncSystemTable;
@@ -124,17 +124,27 @@ impl AsyncSystemTable for TaskHistoryTable {
         ctx: Arc<dyn TableContext>,
         _push_downs: Option<PushDownInfo>,
     ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let available_roles = ctx.get_available_roles().await?;
         let config = GlobalConfig::instance();
         if config.query.cloud_control_grpc_server_address.is_none() {
-            return Err(ErrorCode::CloudControlNotEnabled(
-                "cannot view system.task_history table without cloud control enabled, please set cloud_control_grpc_server_address in config",
-            ));
+            // The task runs are stored in the meta service if the cloud control is not enabled.
+            let mut runs = UserApiProvider::instance()
+                .get_task_runs(&tenant, None)
+                .await?
+                .into_iter()
+                .filter(|r| {
+                    available_roles
+                        .iter()
+                        .any(|role| role.identity() == r.task.owner)
+                })
+                .collect::<Vec<_>>();
+            runs.sort_by(|a, b| b.scheduled_at.cmp(&a.scheduled_at));
+            return parse_task_runs_to_datablock(runs.into_iter().map(|r| r.into()).collect());
         }
 
-        let tenant = ctx.get_tenant();
         let query_id = ctx.get_id();
         let user = ctx.get_current_user()?.identity().to_string();
-        let available_roles = ctx.get_available_roles().await?;
         let req = ShowTaskRunsRequest {
             tenant_id: tenant.clone(),
             scheduled_time_start: "".to_string(),
@@ -0,0 +1,182 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use databend_common_catalog::plan::PushDownInfo;
+use databend_common_catalog::table::Table;
+use databend_common_catalog::table_context::ProcessInfoState;
+use databend_common_catalog::table_context::TableContext;
+use databend_common_exception::Result;
+use databend_common_expression::types::NumberDataType;
+use databend_common_expression::types::StringType;
+use databend_common_expression::types::TimestampType;
+use databend_common_expression::types::UInt64Type;
+use databend_common_expression::utils::FromData;
+use databend_common_expression::DataBlock;
+use databend_common_expression::TableDataType;
+use databend_common_expression::TableField;
+use databend_common_expression::TableSchemaRefExt;
+use databend_common_meta_app::schema::TableIdent;
+use databend_common_meta_app::schema::TableInfo;
+use databend_common_meta_app::schema::TableMeta;
+use databend_common_users::UserApiProvider;
+
+use crate::table::AsyncOneBlockSystemTable;
+use crate::table::AsyncSystemTable;
+
+pub struct WorkloadGroupsTable {
+    table_info: TableInfo,
+}
+
+#[async_trait::async_trait]
+impl AsyncSystemTable for WorkloadGroupsTable {
+    const NAME: &'static str = "system.workload_groups";
+
+    fn get_table_info(&self) -> &TableInfo {
+        &self.table_info
+    }
+
+    #[async_backtrace::framed]
+    async fn get_full_data(
+        &self,
+        ctx: Arc<dyn TableContext>,
+        _push_downs: Option<PushDownInfo>,
+    ) -> Result<DataBlock> {
+        let tenant = ctx.get_tenant();
+        let workload_groups = UserApiProvider::instance()
+            .get_workload_groups(&tenant)
+            .await?;
+
+        // The running and queued queries of the groups on this node.
+        let mut running_queries: HashMap<String, u64> = HashMap::new();
+        let mut queued_queries: HashMap<String, u64> = HashMap::new();
+        for process_info in ctx.get_processes_info() {
+            if let Some(workload_group) = process_info.workload_group {
+                match process_info.state {
+                    ProcessInfoState::Query => {
+                        *running_queries.entry(workload_group).or_default() += 1
+                    }
+                    ProcessInfoState::Queued => {
+                        *queued_queries.entry(workload_group).or_default() += 1
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        let mut names = Vec::with_capacity(workload_groups.len());
+        let mut max_concurrencies = Vec::with_capacity(workload_groups.len());
+        let mut max_queueds = Vec::with_capacity(workload_groups.len());
+        let mut queue_timeouts = Vec::with_capacity(workload_groups.len());
+        let mut memory_percentages = Vec::with_capacity(workload_groups.len());
+        let mut cpu_percentages = Vec::with_capacity(workload_groups.len());
+        let mut users = Vec::with_capacity(workload_groups.len());
+        let mut roles = Vec::with_capacity(workload_groups.len());
+        let mut running = Vec::with_capacity(workload_groups.len());
+        let mut queued = Vec::with_capacity(workload_groups.len());
+        let mut comments = Vec::with_capacity(workload_groups.len());
+        let mut created_on_columns = Vec::with_capacity(workload_groups.len());
+        let mut updated_on_columns = Vec::with_capacity(workload_groups.len());
+        for workload_group in workload_groups {
+            running.push(
+                running_queries
+                    .get(&workload_group.name)
+                    .copied()
+                    .unwrap_or(0),
+            );
+            queued.push(
+                queued_queries
+                    .get(&workload_group.name)
+                    .copied()
+                    .unwrap_or(0),
+            );
+            names.push(workload_group.name);
+            max_concurrencies.push(workload_group.max_concurrency);
+            max_queueds.push(workload_group.max_queued);
+            queue_timeouts.push(workload_group.queue_timeout_secs);
+            memory_percentages.push(workload_group.memory_percentage);
+            cpu_percentages.push(workload_group.cpu_percentage);
+            users.push(workload_group.users.join(", "));
+            roles.push(workload_group.roles.join(", "));
+            comments.push(workload_group.comment);
+            created_on_columns.push(workload_group.create_on.timestamp_micros());
+            updated_on_columns.push(workload_group.update_on.map(|u| u.timestamp_micros()));
+        }
+
+        Ok(DataBlock::new_from_columns(vec![
+            StringType::from_data(names),
+            UInt64Type::from_data(max_concurrencies),
+            UInt64Type::from_data(max_queueds),
+            UInt64Type::from_data(queue_timeouts),
+            UInt64Type::from_data(memory_percentages),
+            UInt64Type::from_data(cpu_percentages),
+            StringType::from_data(users),
+            StringType::from_data(roles),
+            UInt64Type::from_data(running),
+            UInt64Type::from_data(queued),
+            StringType::from_data(comments),
+            TimestampType::from_data(created_on_columns),
+            TimestampType::from_opt_data(updated_on_columns),
+        ]))
+    }
+}
+
+impl WorkloadGroupsTable {
+    pub fn create(table_id: u64) -> Arc<dyn Table> {
+        let schema = TableSchemaRefExt::create(vec![
+            TableField::new("name", TableDataType::String),
+            TableField::new(
+                "max_concurrency",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("max_queued", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new(
+                "queue_timeout_secs",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "memory_percentage",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new(
+                "cpu_percentage",
+                TableDataType::Number(NumberDataType::UInt64),
+            ),
+            TableField::new("users", TableDataType::String),
+            TableField::new("roles", TableDataType::String),
+            TableField::new("running", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("queued", TableDataType::Number(NumberDataType::UInt64)),
+            TableField::new("comment", TableDataType::String),
+            TableField::new("created_on", TableDataType::Timestamp),
+            TableField::new(
+                "updated_on",
+                TableDataType::Nullable(Box::new(TableDataType::Timestamp)),
+            ),
+        ]);
+
+        let table_info = TableInfo {
+            desc: "'system'.'workload_groups'".to_string(),
+            name: "workload_groups".to_string(),
+            ident: TableIdent::new(table_id, 0),
+            meta: TableMeta {
+                schema,
+                engine: "SystemWorkloadGroups".to_string(),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        AsyncOneBlockSystemTable::create(WorkloadGroupsTable { table_info })
+    }
+}
@@ -20,7 +20,9 @@ extern crate core;
 mod jwt;
 mod network_policy;
 mod password_policy;
+mod pipe;
 mod role_mgr;
+mod task;
 mod user;
 mod user_api;
 mod user_mgr;
@@ -28,6 +30,7 @@ mod user_setting;
 mod user_stage;
 mod user_udf;
 mod visibility_checker;
+mod workload_group;
 
 pub mod connection;
 pub mod file_format;
@@ -37,9 +40,11 @@ pub mod role_util;
 
 pub use jwt::*;
 pub use password_policy::*;
+pub use pipe::PIPE_LOAD_HISTORY_TTL;
 pub use role_cache_mgr::RoleCacheManager;
 pub use role_mgr::BUILTIN_ROLE_ACCOUNT_ADMIN;
 pub use role_mgr::BUILTIN_ROLE_PUBLIC;
+pub use task::TASK_RUN_HISTORY_TTL;
 pub use user::CertifiedInfo;
 pub use user_api::UserApiProvider;
 pub use visibility_checker::GrantObjectVisibilityChecker;
@@ -26,6 +26,8 @@ use databend_common_management::NetworkPolicyApi;
 use databend_common_management::NetworkPolicyMgr;
 use databend_common_management::PasswordPolicyApi;
 use databend_common_management::PasswordPolicyMgr;
+use databend_common_management::PipeApi;
+use databend_common_management::PipeMgr;
 use databend_common_management::QuotaApi;
 use databend_common_management::QuotaMgr;
 use databend_common_management::RoleApi;
@@ -34,10 +36,14 @@ use databend_common_management::SettingApi;
 use databend_common_management::SettingMgr;
 use databend_common_management::StageApi;
 use databend_common_management::StageMgr;
+use databend_common_management::TaskApi;
+use databend_common_management::TaskMgr;
 use databend_common_management::UdfApi;
 use databend_common_management::UdfMgr;
 use databend_common_management::UserApi;
 use databend_common_management::UserMgr;
+use databend_common_management::WorkloadGroupApi;
+use databend_common_management::WorkloadGroupMgr;
 use databend_common_meta_app::principal::AuthInfo;
 use databend_common_meta_app::principal::RoleInfo;
 use databend_common_meta_app::tenant::TenantQuota;
@@ -175,6 +181,24 @@ impl UserApiProvider {
         )?))
     }
 
+    pub fn get_pipe_api_client(&self, tenant: &str) -> Result<Arc<impl PipeApi>> {
+        Ok(Arc::new(PipeMgr::create(self.client.clone(), tenant)?))
+    }
+
+    pub fn get_task_api_client(&self, tenant: &str) -> Result<Arc<impl TaskApi>> {
+        Ok(Arc::new(TaskMgr::create(self.client.clone(), tenant)?))
+    }
+
+    pub fn get_workload_group_api_client(
+        &self,
+        tenant: &str,
+    ) -> Result<Arc<impl WorkloadGroupApi>> {
+        Ok(Arc::new(WorkloadGroupMgr::create(
+            self.client.clone(),
+            tenant,
+        )?))
+    }
+
     pub fn get_meta_store_client(&self) -> Arc<MetaStore> {
         Arc::new(self.meta.clone())
     }
@@ -150,8 +150,11 @@ impl GrantObjectVisibilityChecker {
     }
 
     pub fn check_database_visibility(&self, catalog: &str, db: &str, db_id: u64) -> bool {
-        // skip information_schema privilege check
-        if db.to_lowercase() == "information_schema" {
+        // skip information_schema and pg_catalog privilege check
+        if matches!(
+            db.to_lowercase().as_str(),
+            "information_schema" | "pg_catalog"
+        ) {
             return true;
         }
 
@@ -199,8 +202,11 @@ impl GrantObjectVisibilityChecker {
         db_id: u64,
         table_id: u64,
     ) -> bool {
-        // skip information_schema privilege check
-        if database.to_lowercase() == "information_schema" {
+        // skip information_schema and pg_catalog privilege check
+        if matches!(
+            database.to_lowercase().as_str(),
+            "information_schema" | "pg_catalog"
+        ) {
             return true;
         }
 
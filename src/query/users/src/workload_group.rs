@@ -0,0 +1,170 @@
+// Copyright 2021 Datafuse Labs
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+use chrono::Utc;
+use databend_common_exception::ErrorCode;
+use databend_common_exception::Result;
+use databend_common_management::WorkloadGroupApi;
+use databend_common_meta_app::principal::WorkloadGroup;
+use databend_common_meta_types::MatchSeq;
+
+use crate::UserApiProvider;
+
+impl UserApiProvider {
+    // Add a new workload group.
+    #[async_backtrace::framed]
+    pub async fn add_workload_group(
+        &self,
+        tenant: &str,
+        workload_group: WorkloadGroup,
+        if_not_exists: bool,
+    ) -> Result<u64> {
+        check_workload_group(&workload_group)?;
+
+        let client = self.get_workload_group_api_client(tenant)?;
+        match client.add_workload_group(workload_group).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_not_exists && e.code() == ErrorCode::WORKLOAD_GROUP_ALREADY_EXISTS {
+                    Ok(0)
+                } else {
+                    Err(e.add_message_back(" (while add workload group)"))
+                }
+            }
+        }
+    }
+
+    // Update workload group.
+    #[async_backtrace::framed]
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_workload_group(
+        &self,
+        tenant: &str,
+        name: &str,
+        max_concurrency: Option<u64>,
+        max_queued: Option<u64>,
+        queue_timeout_secs: Option<u64>,
+        memory_percentage: Option<u64>,
+        cpu_percentage: Option<u64>,
+        users: Option<Vec<String>>,
+        roles: Option<Vec<String>>,
+        comment: Option<String>,
+        if_exists: bool,
+    ) -> Result<Option<u64>> {
+        let client = self.get_workload_group_api_client(tenant)?;
+        let seq_workload_group = match client.get_workload_group(name, MatchSeq::GE(0)).await {
+            Ok(seq_workload_group) => seq_workload_group,
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_WORKLOAD_GROUP {
+                    return Ok(None);
+                } else {
+                    return Err(e.add_message_back(" (while alter workload group)"));
+                }
+            }
+        };
+
+        let seq = seq_workload_group.seq;
+        let mut workload_group = seq_workload_group.data;
+        if let Some(max_concurrency) = max_concurrency {
+            workload_group.max_concurrency = max_concurrency;
+        }
+        if let Some(max_queued) = max_queued {
+            workload_group.max_queued = max_queued;
+        }
+        if let Some(queue_timeout_secs) = queue_timeout_secs {
+            workload_group.queue_timeout_secs = queue_timeout_secs;
+        }
+        if let Some(memory_percentage) = memory_percentage {
+            workload_group.memory_percentage = memory_percentage;
+        }
+        if let Some(cpu_percentage) = cpu_percentage {
+            workload_group.cpu_percentage = cpu_percentage;
+        }
+        if let Some(users) = users {
+            workload_group.users = users;
+        }
+        if let Some(roles) = roles {
+            workload_group.roles = roles;
+        }
+        if let Some(comment) = comment {
+            workload_group.comment = comment;
+        }
+        check_workload_group(&workload_group)?;
+
+        workload_group.update_on = Some(Utc::now());
+
+        match client
+            .update_workload_group(workload_group, MatchSeq::Exact(seq))
+            .await
+        {
+            Ok(res) => Ok(Some(res)),
+            Err(e) => Err(e.add_message_back(" (while alter workload group).")),
+        }
+    }
+
+    // Drop a workload group by name.
+    #[async_backtrace::framed]
+    pub async fn drop_workload_group(
+        &self,
+        tenant: &str,
+        name: &str,
+        if_exists: bool,
+    ) -> Result<()> {
+        let client = self.get_workload_group_api_client(tenant)?;
+        match client.drop_workload_group(name, MatchSeq::GE(1)).await {
+            Ok(res) => Ok(res),
+            Err(e) => {
+                if if_exists && e.code() == ErrorCode::UNKNOWN_WORKLOAD_GROUP {
+                    Ok(())
+                } else {
+                    Err(e.add_message_back(" (while drop workload group)"))
+                }
+            }
+        }
+    }
+
+    // Get a workload group by name.
+    #[async_backtrace::framed]
+    pub async fn get_workload_group(&self, tenant: &str, name: &str) -> Result<WorkloadGroup> {
+        let client = self.get_workload_group_api_client(tenant)?;
+        let workload_group = client.get_workload_group(name, MatchSeq::GE(0)).await?.data;
+        Ok(workload_group)
+    }
+
+    // Get all workload groups by tenant.
+    #[async_backtrace::framed]
+    pub async fn get_workload_groups(&self, tenant: &str) -> Result<Vec<WorkloadGroup>> {
+        let client = self.get_workload_group_api_client(tenant)?;
+        let workload_groups = client
+            .get_workload_groups()
+            .await
+            .map_err(|e| e.add_message_back(" (while get workload groups)."))?;
+        Ok(workload_groups)
+    }
+}
+
+fn check_workload_group(workload_group: &WorkloadGroup) -> Result<()> {
+    if workload_group.memory_percentage > 100 {
+        return Err(ErrorCode::IllegalWorkloadGroup(format!(
+            "invalid memory_percentage: {}, must be in range 0 to 100",
+            workload_group.memory_percentage
+        )));
+    }
+    if workload_group.cpu_percentage > 100 {
+        return Err(ErrorCode::IllegalWorkloadGroup(format!(
+            "invalid cpu_percentage: {}, must be in range 0 to 100",
+            workload_group.cpu_percentage
+        )));
+    }
+    Ok(())
+}
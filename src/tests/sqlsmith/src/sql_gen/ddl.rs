@@ -14,6 +14,7 @@
 
 use std::collections::BTreeMap;
 
+use databend_common_ast::ast::ClusterType;
 use databend_common_ast::ast::ColumnDefinition;
 use databend_common_ast::ast::ColumnExpr;
 use databend_common_ast::ast::CreateTableSource;
@@ -80,6 +81,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
                 source: Some(source),
                 engine: Some(Engine::Fuse),
                 uri_location: None,
+                cluster_type: ClusterType::Linear,
                 cluster_by: vec![],
                 table_options: BTreeMap::new(),
                 as_query: None,
@@ -193,7 +195,7 @@ impl<'a, R: Rng> SqlGenerator<'a, R> {
             };
             column_defs.push(column_def);
         }
-        CreateTableSource::Columns(column_defs)
+        CreateTableSource::Columns(column_defs, vec![])
     }
 }
 
@@ -262,6 +264,10 @@ fn gen_default_expr(type_name: &TypeName) -> Expr {
             span: None,
             lit: Literal::String("null".to_string()),
         },
+        TypeName::Geometry => Expr::Literal {
+            span: None,
+            lit: Literal::String("POINT(0 0)".to_string()),
+        },
         TypeName::Nullable(_) => Expr::Literal {
             span: None,
             lit: Literal::Null,
@@ -0,0 +1,36 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+echo "drop table if exists t12_0005" | $BENDSQL_CLIENT_CONNECT
+echo "create table t12_0005(a int, b string) change_tracking = true" | $BENDSQL_CLIENT_CONNECT
+echo "insert into t12_0005 values(1, 'a'), (2, 'b')" | $BENDSQL_CLIENT_CONNECT
+echo "insert into t12_0005 values(3, 'c')" | $BENDSQL_CLIENT_CONNECT
+echo "update t12_0005 set b = 'bb' where a = 2" | $BENDSQL_CLIENT_CONNECT
+echo "delete from t12_0005 where a = 1" | $BENDSQL_CLIENT_CONNECT
+
+## Get the snapshot ids of the first two insertions
+FIRST_SNAPSHOT_ID=$(echo "select snapshot_id from fuse_snapshot('default', 't12_0005') order by timestamp limit 1" | $BENDSQL_CLIENT_CONNECT)
+SECOND_SNAPSHOT_ID=$(echo "select snapshot_id from fuse_snapshot('default', 't12_0005') order by timestamp limit 1 offset 1" | $BENDSQL_CLIENT_CONNECT)
+
+echo "changes since the first insertion"
+echo "select a, b, change\$action, change\$is_update from t12_0005 changes(information => default) at (snapshot => '$FIRST_SNAPSHOT_ID') order by a, change\$action" | $BENDSQL_CLIENT_CONNECT
+
+echo "reading the changes does not consume them"
+echo "select count(*) from t12_0005 changes(information => default) at (snapshot => '$FIRST_SNAPSHOT_ID')" | $BENDSQL_CLIENT_CONNECT
+
+echo "append only changes since the first insertion"
+echo "select a, b, change\$action, change\$is_update from t12_0005 changes(information => append_only) at (snapshot => '$FIRST_SNAPSHOT_ID') order by a" | $BENDSQL_CLIENT_CONNECT
+
+echo "changes between the first and the second insertion"
+echo "select a, b, change\$action, change\$is_update from t12_0005 changes(information => default) at (snapshot => '$FIRST_SNAPSHOT_ID') end (snapshot => '$SECOND_SNAPSHOT_ID') order by a" | $BENDSQL_CLIENT_CONNECT
+
+echo "changes between the first and the second insertion by table_changes"
+echo "select a, b, change\$action from table_changes('default.t12_0005', '$FIRST_SNAPSHOT_ID', '$SECOND_SNAPSHOT_ID') order by a" | $BENDSQL_CLIENT_CONNECT
+
+echo "append only changes since the first insertion by table_changes"
+echo "select a, b from table_changes('t12_0005', '$FIRST_SNAPSHOT_ID', information => 'append_only') order by a" | $BENDSQL_CLIENT_CONNECT
+
+## Drop table.
+echo "drop table t12_0005" | $BENDSQL_CLIENT_CONNECT
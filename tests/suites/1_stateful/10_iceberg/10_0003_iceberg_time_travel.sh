@@ -0,0 +1,24 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+ROOT=$(realpath "$CURDIR"/../../../data/iceberg/iceberg_ctl/iceberg_db/iceberg_tbl/)
+
+stmt "drop table if exists test_iceberg;"
+
+echo ">>>> create table test_iceberg engine = iceberg location = 'fs://\${ROOT}/';"
+echo "create table test_iceberg engine = iceberg location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+
+query "select snapshot_id, parent_snapshot_id, operation from iceberg_snapshot('default', 'default', 'test_iceberg') order by timestamp;"
+query "select added_snapshot_id, added_data_files_count, added_rows_count from iceberg_manifest('default', 'default', 'test_iceberg') order by added_snapshot_id;"
+
+query "select * from test_iceberg at (snapshot => '1620235913653295893') order by id;"
+query "select count(*) from test_iceberg at (timestamp => '2023-08-08 01:35:02'::timestamp);"
+query "select count(*) from test_iceberg at (timestamp => '2023-08-08 01:35:04'::timestamp);"
+
+query "select * from test_iceberg changes(information => append_only) at (snapshot => '1620235913653295893') order by id;"
+query "select * from test_iceberg changes(information => append_only) at (snapshot => '1620235913653295893') end (snapshot => '3631613356126113181') where data = 'd' order by id;"
+query "select count(*) from test_iceberg changes(information => append_only) at (snapshot => '3631613356126113181');"
+
+stmt "drop table test_iceberg;"
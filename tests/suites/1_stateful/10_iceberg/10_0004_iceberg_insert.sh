@@ -0,0 +1,27 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+# Write to a copy, the test data must stay untouched.
+ROOT=$(mktemp -d)
+cp -r "$CURDIR"/../../../data/iceberg/iceberg_ctl/iceberg_db/iceberg_tbl/. "$ROOT"
+
+stmt "drop table if exists test_iceberg;"
+
+echo ">>>> create table test_iceberg engine = iceberg location = 'fs://\${ROOT}/';"
+echo "create table test_iceberg engine = iceberg location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+
+stmt "insert into test_iceberg values (7, 'f'), (8, 'g');"
+stmt "insert into test_iceberg select number + 9, 'h' from numbers(3);"
+
+query "select * from test_iceberg order by id;"
+query "select * from test_iceberg where id > 8 order by id;"
+query "select count(*), operation from iceberg_snapshot('default', 'default', 'test_iceberg') group by operation;"
+query "select sum(added_rows_count) from iceberg_manifest('default', 'default', 'test_iceberg');"
+query "select * from test_iceberg changes(information => append_only) at (snapshot => '3631613356126113181') order by id;"
+
+stmt "insert overwrite test_iceberg values (1, 'a');"
+
+stmt "drop table test_iceberg;"
+rm -rf "$ROOT"
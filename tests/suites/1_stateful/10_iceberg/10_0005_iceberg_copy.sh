@@ -0,0 +1,34 @@
+#!/usr/bin/env bash
+
+CURDIR=$(cd "$(dirname "${BASH_SOURCE[0]}")" && pwd)
+. "$CURDIR"/../../../shell_env.sh
+
+# Write to a copy, the test data must stay untouched.
+ROOT=$(mktemp -d)
+cp -r "$CURDIR"/../../../data/iceberg/iceberg_ctl/iceberg_db/iceberg_tbl/. "$ROOT"
+
+STAGE_DIR=$(mktemp -d)
+printf '20,x\n21,y\n' > "$STAGE_DIR"/data.csv
+
+stmt "drop table if exists test_iceberg;"
+stmt "drop stage if exists iceberg_copy_stage;"
+
+echo ">>>> create table test_iceberg engine = iceberg location = 'fs://\${ROOT}/';"
+echo "create table test_iceberg engine = iceberg location = 'fs://${ROOT}/';" | $BENDSQL_CLIENT_CONNECT
+
+echo ">>>> create stage iceberg_copy_stage url = 'fs://\${STAGE_DIR}/' file_format = (type = CSV);"
+echo "create stage iceberg_copy_stage url = 'fs://${STAGE_DIR}/' file_format = (type = CSV);" | $BENDSQL_CLIENT_CONNECT
+
+# The loaded files are not recorded, running the same COPY twice must not load them twice.
+stmt "copy into test_iceberg from @iceberg_copy_stage;"
+stmt "copy into test_iceberg from @iceberg_copy_stage;"
+query "select count(*) from test_iceberg where id >= 20;"
+
+# With FORCE every run loads the files, like for fuse tables.
+stmt "copy into test_iceberg from @iceberg_copy_stage force = true;"
+stmt "copy into test_iceberg from @iceberg_copy_stage force = true;"
+query "select id, count(*) from test_iceberg where id >= 20 group by id order by id;"
+
+stmt "drop stage iceberg_copy_stage;"
+stmt "drop table test_iceberg;"
+rm -rf "$ROOT" "$STAGE_DIR"